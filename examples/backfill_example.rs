@@ -0,0 +1,41 @@
+use solana_streamer_sdk::streaming::{
+    event_parser::{protocols::pumpswap::parser::PUMPSWAP_PROGRAM_ID, DexEvent, Protocol},
+    BackfillConfig, BackfillRange, Backfiller,
+};
+
+/// Backfill PumpSwap events for a slot range through RPC.
+///
+/// `START_SLOT=<slot> END_SLOT=<slot> cargo run --example backfill_example --release`
+/// Re-running resumes after the last processed transaction recorded in `backfill.checkpoint`.
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rpc_url = std::env::var("RPC_URL")
+        .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string());
+    let start_slot: u64 = std::env::var("START_SLOT")?.parse()?;
+    let end_slot: u64 = std::env::var("END_SLOT")?.parse()?;
+
+    let config = BackfillConfig {
+        addresses: vec![PUMPSWAP_PROGRAM_ID],
+        range: BackfillRange::slots(start_slot, end_slot),
+        protocols: vec![Protocol::PumpSwap],
+        max_concurrency: 4,
+        requests_per_second: Some(10),
+        checkpoint_path: Some("backfill.checkpoint".into()),
+        ..Default::default()
+    };
+
+    let backfiller = Backfiller::from_url(rpc_url, config);
+    let stats = backfiller
+        .run(|event: DexEvent| {
+            println!(
+                "slot {} {:?} {}",
+                event.metadata().slot,
+                event.metadata().event_type,
+                event.metadata().signature
+            );
+        })
+        .await?;
+    println!("Backfill finished: {stats:?}");
+
+    Ok(())
+}
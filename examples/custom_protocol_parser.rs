@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use solana_sdk::{pubkey, pubkey::Pubkey};
+use solana_streamer_sdk::streaming::{
+    event_parser::{
+        common::EventMetadata,
+        core::{
+            custom_parser::{CustomEvent, ProtocolParser},
+            EventDispatcher,
+        },
+        DexEvent,
+    },
+    yellowstone_grpc::TransactionFilter,
+    YellowstoneGrpc,
+};
+
+const DBC_PROGRAM_ID: Pubkey = pubkey!("dbcij3LWUppWqq96dh6gJWwBifmcGfLSB5D4DuSMaqN");
+const SWAP_DISCRIMINATOR: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DbcSwap {
+    pool: Pubkey,
+    payer: Pubkey,
+    amount_in: u64,
+    min_amount_out: u64,
+}
+
+/// A minimal third-party parser: decodes Meteora Dynamic Bonding Curve `swap` instructions
+struct DbcParser;
+
+impl ProtocolParser for DbcParser {
+    fn name(&self) -> &str {
+        "meteora-dbc"
+    }
+
+    fn program_ids(&self) -> Vec<Pubkey> {
+        vec![DBC_PROGRAM_ID]
+    }
+
+    fn parse_instruction(
+        &self,
+        discriminator: &[u8],
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<CustomEvent> {
+        if discriminator != SWAP_DISCRIMINATOR || data.len() < 16 || accounts.len() < 10 {
+            return None;
+        }
+        let swap = DbcSwap {
+            pool: accounts[2],
+            payer: accounts[9],
+            amount_in: u64::from_le_bytes(data[0..8].try_into().ok()?),
+            min_amount_out: u64::from_le_bytes(data[8..16].try_into().ok()?),
+        };
+        Some(CustomEvent::new(metadata, "Swap", &swap))
+    }
+}
+
+/// Stream a protocol the SDK does not support natively through a registered parser
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let protocol = EventDispatcher::register_protocol_parser(Arc::new(DbcParser));
+
+    let grpc = YellowstoneGrpc::new(
+        "https://solana-yellowstone-grpc.publicnode.com:443".to_string(),
+        None,
+    )?;
+    let transaction_filter = TransactionFilter {
+        account_include: vec![DBC_PROGRAM_ID.to_string()],
+        account_exclude: vec![],
+        account_required: vec![],
+    };
+
+    grpc.subscribe_events_immediate(
+        vec![protocol],
+        None,
+        vec![transaction_filter],
+        vec![],
+        None,
+        None,
+        |event: DexEvent| {
+            if let DexEvent::CustomEvent(e) = event {
+                if let Some(swap) = e.data_as::<DbcSwap>() {
+                    println!("[{}] {} {:?}", e.protocol, e.metadata.signature, swap);
+                }
+            }
+        },
+    )
+    .await?;
+
+    println!("Waiting for Ctrl+C to stop...");
+    tokio::signal::ctrl_c().await?;
+    grpc.stop().await;
+
+    Ok(())
+}
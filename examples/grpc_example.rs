@@ -1,7 +1,11 @@
 use solana_streamer_sdk::streaming::{
     event_parser::{
         protocols::{
-            bonk::parser::BONK_PROGRAM_ID, meteora_damm_v2::parser::METEORA_DAMM_V2_PROGRAM_ID,
+            bonk::parser::BONK_PROGRAM_ID,
+            meteora_damm_v1::parser::METEORA_DAMM_V1_PROGRAM_ID,
+            meteora_damm_v2::parser::METEORA_DAMM_V2_PROGRAM_ID,
+            meteora_dlmm::parser::METEORA_DLMM_PROGRAM_ID,
+            orca_whirlpool::parser::ORCA_WHIRLPOOL_PROGRAM_ID,
             pumpfun::parser::PUMPFUN_PROGRAM_ID, pumpswap::parser::PUMPSWAP_PROGRAM_ID,
             raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID,
             raydium_clmm::parser::RAYDIUM_CLMM_PROGRAM_ID,
@@ -46,7 +50,10 @@ async fn test_grpc() -> Result<(), Box<dyn std::error::Error>> {
         Protocol::RaydiumCpmm,
         Protocol::RaydiumClmm,
         Protocol::RaydiumAmmV4,
+        Protocol::MeteoraDammV1,
         Protocol::MeteoraDammV2,
+        Protocol::MeteoraDlmm,
+        Protocol::OrcaWhirlpool,
     ];
 
     println!("Protocols to monitor: {:?}", protocols);
@@ -59,7 +66,10 @@ async fn test_grpc() -> Result<(), Box<dyn std::error::Error>> {
         RAYDIUM_CPMM_PROGRAM_ID.to_string(),    // Listen to raydium_cpmm program ID
         RAYDIUM_CLMM_PROGRAM_ID.to_string(),    // Listen to raydium_clmm program ID
         RAYDIUM_AMM_V4_PROGRAM_ID.to_string(),  // Listen to raydium_amm_v4 program ID
+        METEORA_DAMM_V1_PROGRAM_ID.to_string(), // Listen to meteora_damm_v1 program ID
         METEORA_DAMM_V2_PROGRAM_ID.to_string(), // Listen to meteora_damm_v2 program ID
+        METEORA_DLMM_PROGRAM_ID.to_string(),    // Listen to meteora_dlmm program ID
+        ORCA_WHIRLPOOL_PROGRAM_ID.to_string(),  // Listen to orca_whirlpool program ID
     ];
     let account_exclude = vec![];
     let account_required = vec![];
@@ -0,0 +1,33 @@
+use std::path::Path;
+
+use solana_streamer_sdk::streaming::event_parser::idl::codegen;
+
+/// Regenerate the typed modules in `src/streaming/event_parser/idl/generated/` from `idl/*.json`:
+///   `cargo run --example idl_codegen`
+/// Or generate a single IDL to stdout:
+///   `cargo run --example idl_codegen -- path/to/program.json`
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(path) = std::env::args().nth(1) {
+        print!("{}", codegen::generate(&std::fs::read_to_string(&path)?, &path)?);
+        return Ok(());
+    }
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out_dir = root.join("src/streaming/event_parser/idl/generated");
+    let mut modules = Vec::new();
+    for entry in std::fs::read_dir(root.join("idl"))? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let name = path.file_stem().and_then(|s| s.to_str()).ok_or("invalid file name")?;
+        let source = format!("idl/{name}.json");
+        let code = codegen::generate(&std::fs::read_to_string(&path)?, &source)?;
+        std::fs::write(out_dir.join(format!("{name}.rs")), code)?;
+        println!("{source} -> generated/{name}.rs");
+        modules.push(name.to_string());
+    }
+    modules.sort();
+    std::fs::write(out_dir.join("mod.rs"), codegen::generate_mod(&modules))?;
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use solana_sdk::{pubkey, pubkey::Pubkey};
+use solana_streamer_sdk::streaming::{
+    event_parser::{core::EventDispatcher, idl::IdlProgram, DexEvent},
+    yellowstone_grpc::TransactionFilter,
+    YellowstoneGrpc,
+};
+
+const WHIRLPOOL_PROGRAM_ID: Pubkey = pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+
+/// Stream any Anchor program from its IDL, here Orca Whirlpool:
+///   `cargo run --example idl_decoder -- idl/orca_whirlpool.json`
+///
+/// A registered IDL takes precedence over the built-in `Protocol::OrcaWhirlpool` parser.
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::args().nth(1).unwrap_or_else(|| "idl/orca_whirlpool.json".to_string());
+    // Older IDLs carry no address, so the program ID is set explicitly
+    let program = IdlProgram::from_file(&path)?.with_program_id(WHIRLPOOL_PROGRAM_ID);
+    println!("swap discriminator: {:?}", program.instruction_discriminator("swap"));
+    let protocol = EventDispatcher::register_idl(program);
+
+    let grpc = YellowstoneGrpc::new(
+        "https://solana-yellowstone-grpc.publicnode.com:443".to_string(),
+        None,
+    )?;
+    let transaction_filter = TransactionFilter {
+        account_include: vec![WHIRLPOOL_PROGRAM_ID.to_string()],
+        account_exclude: vec![],
+        account_required: vec![],
+    };
+
+    grpc.subscribe_events_immediate(
+        vec![protocol],
+        None,
+        vec![transaction_filter],
+        vec![],
+        None,
+        None,
+        |event: DexEvent| {
+            if let DexEvent::IdlEvent(e) = event {
+                println!(
+                    "[{}] {} {} pool={:?} args={}",
+                    e.program,
+                    e.name,
+                    e.metadata.signature,
+                    e.account("whirlpool"),
+                    e.args.to_json()
+                );
+            }
+        },
+    )
+    .await?;
+
+    println!("Waiting for Ctrl+C to stop...");
+    tokio::signal::ctrl_c().await?;
+    grpc.stop().await;
+
+    Ok(())
+}
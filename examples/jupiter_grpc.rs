@@ -0,0 +1,87 @@
+//! Jupiter v6 route subscription via gRPC, linking each AMM swap to its parent route.
+//!
+//! Usage: cargo run --example jupiter_grpc --release
+
+use solana_streamer_sdk::streaming::event_parser::protocols::jupiter::parser::JUPITER_PROGRAM_ID;
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::grpc::ClientConfig;
+use solana_streamer_sdk::streaming::yellowstone_grpc::{TransactionFilter, YellowstoneGrpc};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+
+    println!("Jupiter gRPC (solana-streamer)\n");
+
+    let grpc = YellowstoneGrpc::new_with_config(
+        std::env::var("GRPC_ENDPOINT")
+            .unwrap_or_else(|_| "https://solana-yellowstone-grpc.publicnode.com:443".to_string()),
+        std::env::var("GRPC_AUTH_TOKEN").ok(),
+        ClientConfig::default(),
+    )?;
+
+    let transaction_filter = TransactionFilter {
+        account_include: vec![JUPITER_PROGRAM_ID.to_string()],
+        account_exclude: vec![],
+        account_required: vec![],
+    };
+
+    let callback = |event: DexEvent| match event {
+        DexEvent::JupiterRouteEvent(e) => {
+            println!(
+                "Route {} {:?} {} {} -> {} {} hops={}",
+                e.metadata.signature,
+                e.metadata.event_type,
+                e.in_amount,
+                e.source_mint,
+                e.out_amount,
+                e.destination_mint,
+                e.hops.len()
+            );
+            for hop in &e.hops {
+                println!(
+                    "  hop amm={} {} {} -> {} {}",
+                    hop.amm, hop.input_amount, hop.input_mint, hop.output_amount, hop.output_mint
+                );
+            }
+        }
+        _ => {
+            if let Some(parent) = event.metadata().parent_route {
+                println!(
+                    "  child {:?} of route at instruction {}/{:?}",
+                    event.metadata().event_type,
+                    parent.outer_index,
+                    parent.inner_index
+                );
+            }
+        }
+    };
+
+    // AMM protocols are needed for the child swaps; without them only the routes are emitted.
+    // parent_route is only set while Protocol::Jupiter is subscribed, since the route event is
+    // what the child swaps are linked to
+    grpc.subscribe_events_immediate(
+        vec![
+            Protocol::Jupiter,
+            Protocol::PumpFun,
+            Protocol::PumpSwap,
+            Protocol::RaydiumCpmm,
+            Protocol::RaydiumClmm,
+            Protocol::RaydiumAmmV4,
+            Protocol::MeteoraDlmm,
+            Protocol::OrcaWhirlpool,
+        ],
+        None,
+        vec![transaction_filter],
+        vec![],
+        None,
+        None,
+        callback,
+    )
+    .await?;
+
+    println!("Press Ctrl+C to stop...\n");
+    tokio::signal::ctrl_c().await?;
+    grpc.stop().await;
+    Ok(())
+}
@@ -30,8 +30,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         filters: vec![],
     };
 
-    let callback = |event: DexEvent| {
-        println!("Event: {:?}", event.metadata().event_type);
+    let callback = |event: DexEvent| match event {
+        DexEvent::MeteoraDammV2AddLiquidityEvent(e) => {
+            println!("AddLiquidity {} pool={} a={} b={}", e.metadata.signature, e.pool, e.token_a_amount, e.token_b_amount);
+        }
+        DexEvent::MeteoraDammV2RemoveLiquidityEvent(e) => {
+            println!("RemoveLiquidity {} pool={} a={} b={}", e.metadata.signature, e.pool, e.token_a_amount, e.token_b_amount);
+        }
+        DexEvent::MeteoraDammV2ClaimPositionFeeEvent(e) => {
+            println!("ClaimPositionFee {} position={} a={} b={}", e.metadata.signature, e.position, e.fee_a_claimed, e.fee_b_claimed);
+        }
+        DexEvent::MeteoraDammV2PoolAccountEvent(e) => {
+            println!("Pool {} liquidity={} sqrt_price={}", e.pubkey, e.pool.liquidity, e.pool.sqrt_price);
+        }
+        _ => println!("Event: {:?}", event.metadata().event_type),
     };
 
     grpc.subscribe_events_immediate(
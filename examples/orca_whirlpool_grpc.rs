@@ -0,0 +1,89 @@
+//! Orca Whirlpool subscription via gRPC.
+//!
+//! Usage: cargo run --example orca_whirlpool_grpc --release
+
+use solana_sdk::{pubkey, pubkey::Pubkey};
+use solana_streamer_sdk::streaming::event_parser::protocols::orca_whirlpool::{
+    parser::ORCA_WHIRLPOOL_PROGRAM_ID,
+    types::{whirlpool_account_filter, whirlpool_by_token_mint_a_filter},
+};
+use solana_streamer_sdk::streaming::event_parser::{DexEvent, Protocol};
+use solana_streamer_sdk::streaming::grpc::ClientConfig;
+use solana_streamer_sdk::streaming::yellowstone_grpc::{
+    AccountFilter, TransactionFilter, YellowstoneGrpc,
+};
+
+const WSOL_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+
+    println!("Orca Whirlpool gRPC (solana-streamer)\n");
+
+    let grpc = YellowstoneGrpc::new_with_config(
+        std::env::var("GRPC_ENDPOINT")
+            .unwrap_or_else(|_| "https://solana-yellowstone-grpc.publicnode.com:443".to_string()),
+        std::env::var("GRPC_AUTH_TOKEN").ok(),
+        ClientConfig::default(),
+    )?;
+
+    let transaction_filter = TransactionFilter {
+        account_include: vec![ORCA_WHIRLPOOL_PROGRAM_ID.to_string()],
+        account_exclude: vec![],
+        account_required: vec![],
+    };
+    // Only Whirlpool accounts whose token A is WSOL
+    let account_filter = AccountFilter {
+        account: vec![],
+        owner: vec![ORCA_WHIRLPOOL_PROGRAM_ID.to_string()],
+        filters: vec![whirlpool_account_filter(), whirlpool_by_token_mint_a_filter(&WSOL_MINT)],
+    };
+
+    let callback = |event: DexEvent| match event {
+        DexEvent::OrcaWhirlpoolSwapEvent(e) => {
+            println!(
+                "Swap {} pool={} a_to_b={} in={} out={} swap_data={:?}",
+                e.metadata.signature,
+                e.whirlpool,
+                e.a_to_b,
+                e.input_amount,
+                e.output_amount,
+                e.metadata.swap_data
+            );
+        }
+        DexEvent::OrcaWhirlpoolTwoHopSwapEvent(e) => {
+            println!(
+                "TwoHopSwap {} {} -> {} amount={} swap_data={:?}",
+                e.metadata.signature,
+                e.leg_one.whirlpool,
+                e.leg_two.whirlpool,
+                e.amount,
+                e.metadata.swap_data
+            );
+        }
+        DexEvent::OrcaWhirlpoolWhirlpoolAccountEvent(e) => {
+            println!(
+                "Whirlpool {} tick={} sqrt_price={}",
+                e.pubkey, e.whirlpool.tick_current_index, e.whirlpool.sqrt_price
+            );
+        }
+        _ => println!("Event: {:?}", event.metadata().event_type),
+    };
+
+    grpc.subscribe_events_immediate(
+        vec![Protocol::OrcaWhirlpool],
+        None,
+        vec![transaction_filter],
+        vec![account_filter],
+        None,
+        None,
+        callback,
+    )
+    .await?;
+
+    println!("Press Ctrl+C to stop...\n");
+    tokio::signal::ctrl_c().await?;
+    grpc.stop().await;
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcBlockConfig};
+use solana_streamer_sdk::streaming::event_parser::{
+    core::event_parser::EventParser, DexEvent, Protocol,
+};
+use solana_transaction_status::{TransactionDetails, UiTransactionEncoding};
+
+/// Re-index archived `getBlock` JSON files (file, directory or `*` pattern):
+///   `cargo run --example parse_block_files -- 'blocks/*.json'`
+/// Or fetch a single block through RPC:
+///   `cargo run --example parse_block_files -- --slot <slot>`
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    let protocols = vec![Protocol::PumpFun, Protocol::PumpSwap, Protocol::RaydiumCpmm];
+
+    if args.get(1).map(String::as_str) == Some("--slot") {
+        let slot: u64 = args.get(2).ok_or("missing slot")?.parse()?;
+        let rpc_url = std::env::var("RPC_URL")
+            .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string());
+        let block = RpcClient::new(rpc_url)
+            .get_block_with_config(
+                slot,
+                RpcBlockConfig {
+                    encoding: Some(UiTransactionEncoding::Base64),
+                    transaction_details: Some(TransactionDetails::Full),
+                    rewards: Some(false),
+                    commitment: None,
+                    max_supported_transaction_version: Some(0),
+                },
+            )
+            .await?;
+        let events = EventParser::parse_confirmed_block(&protocols, None, block, slot, None)?;
+        print_events(slot, &events);
+        return Ok(());
+    }
+
+    let path = args.get(1).cloned().unwrap_or_else(|| "blocks".to_string());
+    let count =
+        EventParser::parse_confirmed_block_files(&protocols, None, &path, None, |slot, events| {
+            print_events(slot, &events)
+        })?;
+    println!("Processed {count} blocks");
+    Ok(())
+}
+
+fn print_events(slot: u64, events: &[DexEvent]) {
+    println!("slot {slot}: {} events", events.len());
+    for event in events {
+        let metadata = event.metadata();
+        println!("  #{:?} {:?} {}", metadata.tx_index, metadata.event_type, metadata.signature);
+    }
+}
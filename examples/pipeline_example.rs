@@ -0,0 +1,45 @@
+use solana_streamer_sdk::streaming::{
+    event_parser::{
+        common::EventType, protocols::pumpswap::parser::PUMPSWAP_PROGRAM_ID, DexEvent, Protocol,
+    },
+    pipeline::YellowstoneGrpcSource,
+    yellowstone_grpc::TransactionFilter,
+    PipelineBuilder, ShredStreamGrpc, YellowstoneGrpc,
+};
+
+/// Feeds Yellowstone gRPC and ShredStream into one pipeline, drops the duplicates seen on
+/// both sources and keeps only PumpSwap buys.
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let grpc = YellowstoneGrpc::new(
+        "https://solana-yellowstone-grpc.publicnode.com:443".to_string(),
+        None,
+    )?;
+    let shred = ShredStreamGrpc::new("http://127.0.0.1:10800".to_string()).await?;
+
+    let transaction_filter = TransactionFilter {
+        account_include: vec![PUMPSWAP_PROGRAM_ID.to_string()],
+        account_exclude: vec![],
+        account_required: vec![],
+    };
+
+    let pipeline = PipelineBuilder::new(vec![Protocol::PumpSwap])
+        .source(YellowstoneGrpcSource::new(grpc, vec![transaction_filter], vec![], None, None))
+        .source(shred)
+        .dedupe(100_000)
+        .filter(|event| event.metadata().event_type == EventType::PumpSwapBuy)
+        .sink(|event: DexEvent| {
+            if let DexEvent::PumpSwapBuyEvent(e) = event {
+                println!("PumpSwap buy {} from slot {}", e.metadata.signature, e.metadata.slot);
+            }
+        })
+        .build();
+
+    pipeline.start().await?;
+
+    println!("Waiting for Ctrl+C to stop...");
+    tokio::signal::ctrl_c().await?;
+    pipeline.stop().await;
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use std::str::FromStr;
+
+use solana_sdk::pubkey::Pubkey;
+use solana_streamer_sdk::streaming::{
+    event_parser::{
+        common::{filter::EventTypeFilter, EventType},
+        protocols::{
+            pumpfun::{self, parser::PUMPFUN_PROGRAM_ID},
+            pumpswap::{self, parser::PUMPSWAP_PROGRAM_ID},
+        },
+        DexEvent, Protocol,
+    },
+    grpc::ClientConfig,
+    yellowstone_grpc::{AccountFilter, TransactionFilter},
+    YellowstoneGrpc,
+};
+
+/// Watch one wallet's PumpFun / PumpSwap volume accumulators (token incentives, cashback)
+/// together with both FeeConfig accounts (tiered fees).
+///
+/// Usage: `cargo run --example pump_volume_accumulator_listen_example -- <WALLET>`
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let user = std::env::args()
+        .nth(1)
+        .map(|s| Pubkey::from_str(&s))
+        .transpose()?
+        .ok_or("usage: pump_volume_accumulator_listen_example <WALLET>")?;
+
+    let grpc = YellowstoneGrpc::new_with_config(
+        "https://solana-yellowstone-grpc.publicnode.com:443".to_string(),
+        None,
+        ClientConfig::default(),
+    )?;
+
+    let protocols = vec![Protocol::PumpFun, Protocol::PumpSwap];
+
+    // The user's accumulators, matched by owner program + discriminator + user field
+    let user_accumulator_filter = AccountFilter {
+        account: vec![],
+        owner: vec![PUMPFUN_PROGRAM_ID.to_string(), PUMPSWAP_PROGRAM_ID.to_string()],
+        filters: vec![
+            pumpfun::types::user_volume_accumulator_account_filter(),
+            pumpfun::types::user_volume_accumulator_by_user_filter(&user),
+        ],
+    };
+    // FeeConfig accounts are owned by the Pump fee program; subscribe by address
+    let fee_config_filter = AccountFilter {
+        account: vec![
+            pumpfun::types::FEE_CONFIG_ADDRESS.to_string(),
+            pumpswap::types::FEE_CONFIG_ADDRESS.to_string(),
+        ],
+        owner: vec![],
+        filters: vec![],
+    };
+
+    let event_type_filter = Some(EventTypeFilter {
+        include: vec![
+            EventType::AccountPumpFunUserVolumeAccumulator,
+            EventType::AccountPumpSwapUserVolumeAccumulator,
+            EventType::AccountPumpFunFeeConfig,
+            EventType::AccountPumpSwapFeeConfig,
+        ],
+    });
+
+    println!("Watching accumulators for {user}");
+    println!("  PumpFun:  {}", pumpfun::types::user_volume_accumulator_pda(&user));
+    println!("  PumpSwap: {}", pumpswap::types::user_volume_accumulator_pda(&user));
+
+    grpc.subscribe_events_immediate(
+        protocols,
+        None,
+        vec![TransactionFilter {
+            account_include: vec![],
+            account_exclude: vec![],
+            account_required: vec![],
+        }],
+        vec![user_accumulator_filter, fee_config_filter],
+        event_type_filter,
+        None,
+        create_event_callback(),
+    )
+    .await?;
+
+    println!("Waiting for Ctrl+C to stop...");
+    tokio::signal::ctrl_c().await?;
+    grpc.stop().await;
+
+    Ok(())
+}
+
+fn create_event_callback() -> impl Fn(DexEvent) {
+    |event: DexEvent| match event {
+        DexEvent::PumpFunUserVolumeAccumulatorAccountEvent(e) => {
+            let a = e.user_volume_accumulator;
+            println!(
+                "PumpFun accumulator: sol_volume={} unclaimed={} claimed={} cashback={}/{}",
+                a.current_sol_volume,
+                a.total_unclaimed_tokens,
+                a.total_claimed_tokens,
+                a.total_cashback_claimed,
+                a.cashback_earned
+            );
+        }
+        DexEvent::PumpSwapUserVolumeAccumulatorAccountEvent(e) => {
+            let a = e.user_volume_accumulator;
+            println!(
+                "PumpSwap accumulator: sol_volume={} unclaimed={} claimed={} cashback={}/{}",
+                a.current_sol_volume,
+                a.total_unclaimed_tokens,
+                a.total_claimed_tokens,
+                a.total_cashback_claimed,
+                a.cashback_earned
+            );
+        }
+        DexEvent::PumpFunFeeConfigAccountEvent(e) => {
+            println!("PumpFun fee tiers: {}", e.fee_config.fee_tiers.len());
+        }
+        DexEvent::PumpSwapFeeConfigAccountEvent(e) => {
+            println!("PumpSwap fee tiers: {}", e.fee_config.fee_tiers.len());
+        }
+        _ => {}
+    }
+}
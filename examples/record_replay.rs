@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use solana_streamer_sdk::streaming::{
+    event_parser::{protocols::pumpfun::parser::PUMPFUN_PROGRAM_ID, DexEvent, Protocol},
+    record::{ReplayPacing, ReplaySource, Recorder, RecorderConfig},
+    yellowstone_grpc::TransactionFilter,
+    PipelineBuilder, YellowstoneGrpc,
+};
+
+/// Record raw gRPC traffic:  `cargo run --example record_replay -- record recordings`
+/// Replay it offline:        `cargo run --example record_replay -- replay recordings [--original]`
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    let mode = args.get(1).map(String::as_str).unwrap_or("record");
+    let dir = args.get(2).cloned().unwrap_or_else(|| "recordings".to_string());
+
+    match mode {
+        "replay" => replay(&dir, args.iter().any(|a| a == "--original")).await,
+        _ => record(&dir).await,
+    }
+}
+
+async fn record(dir: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let recorder =
+        Arc::new(Recorder::new(RecorderConfig { dir: dir.into(), ..Default::default() })?);
+    let mut grpc = YellowstoneGrpc::new(
+        "https://solana-yellowstone-grpc.publicnode.com:443".to_string(),
+        None,
+    )?;
+    grpc.set_recorder(Some(recorder.clone()));
+
+    let transaction_filter = TransactionFilter {
+        account_include: vec![PUMPFUN_PROGRAM_ID.to_string()],
+        account_exclude: vec![],
+        account_required: vec![],
+    };
+    grpc.subscribe_events_immediate(
+        vec![Protocol::PumpFun],
+        None,
+        vec![transaction_filter],
+        vec![],
+        None,
+        None,
+        |_event: DexEvent| {},
+    )
+    .await?;
+
+    println!("Recording to {dir}, press Ctrl+C to stop...");
+    tokio::signal::ctrl_c().await?;
+    grpc.stop().await;
+    // close() waits for the writer thread, so keep it off the async worker
+    let closing = recorder.clone();
+    tokio::task::spawn_blocking(move || closing.close()).await?;
+    println!(
+        "Recorded {} messages ({} dropped)",
+        recorder.recorded_count(),
+        recorder.dropped_count()
+    );
+    Ok(())
+}
+
+async fn replay(dir: &str, original_pacing: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let pacing =
+        if original_pacing { ReplayPacing::Original } else { ReplayPacing::AsFastAsPossible };
+    let pipeline = PipelineBuilder::new(vec![Protocol::PumpFun])
+        .source(ReplaySource::new(dir)?.with_pacing(pacing))
+        .sink(|event: DexEvent| {
+            println!("{:?} {}", event.metadata().event_type, event.metadata().signature);
+        })
+        .build();
+
+    pipeline.start().await?;
+    pipeline.join().await;
+    Ok(())
+}
@@ -0,0 +1,56 @@
+//! System Program and Address Lookup Table subscription via gRPC.
+//!
+//! Usage: cargo run --example system_grpc --release -- [WALLET...]
+
+use solana_streamer_sdk::streaming::event_parser::DexEvent;
+use solana_streamer_sdk::streaming::grpc::ClientConfig;
+use solana_streamer_sdk::streaming::yellowstone_grpc::YellowstoneGrpc;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+
+    println!("System Program gRPC (solana-streamer)\n");
+
+    let grpc = YellowstoneGrpc::new_with_config(
+        std::env::var("GRPC_ENDPOINT")
+            .unwrap_or_else(|_| "https://solana-yellowstone-grpc.publicnode.com:443".to_string()),
+        std::env::var("GRPC_AUTH_TOKEN").ok(),
+        ClientConfig::default(),
+    )?;
+
+    // Wallets passed on the command line narrow the subscription; otherwise every
+    // transaction that calls the System or Address Lookup Table program is streamed.
+    let wallets: Vec<String> = std::env::args().skip(1).collect();
+
+    let callback = |event: DexEvent| match event {
+        DexEvent::SolTransferEvent(e) => {
+            println!(
+                "Transfer {} {} -> {} lamports={} inner={:?}",
+                e.metadata.signature, e.from, e.to, e.lamports, e.metadata.inner_index
+            );
+        }
+        DexEvent::CreateAccountEvent(e) => {
+            println!(
+                "CreateAccount {} {} owner={} space={} lamports={}",
+                e.metadata.signature, e.new_account, e.owner, e.space, e.lamports
+            );
+        }
+        DexEvent::AddressLookupTableExtendEvent(e) => {
+            println!(
+                "ExtendLookupTable {} table={} +{} addresses",
+                e.metadata.signature,
+                e.lookup_table,
+                e.new_addresses.len()
+            );
+        }
+        _ => println!("Event: {:?}", event.metadata().event_type),
+    };
+
+    grpc.subscribe_system(Some(wallets), None, None, None, callback).await?;
+
+    println!("Press Ctrl+C to stop...\n");
+    tokio::signal::ctrl_c().await?;
+    grpc.stop().await;
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use solana_streamer_sdk::streaming::{
+    event_parser::{
+        protocols::{pumpfun::parser::PUMPFUN_PROGRAM_ID, pumpswap::parser::PUMPSWAP_PROGRAM_ID},
+        Protocol,
+    },
+    yellowstone_grpc::TransactionFilter,
+    WsBroadcastServer, WsServerConfig, YellowstoneGrpc,
+};
+
+/// Pushes parsed events to WebSocket clients as JSON.
+///
+/// Connect with any WebSocket client (e.g. `websocat ws://127.0.0.1:9001`) and send:
+/// `{"op":"subscribe","event_types":["PumpSwapBuy","PumpSwapSell"]}`
+/// `{"op":"subscribe","mints":["<mint base58>"]}`
+/// `{"op":"unsubscribe"}`
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let server = WsBroadcastServer::new_with_config(WsServerConfig {
+        bind_addr: "127.0.0.1:9001".to_string(),
+        ..Default::default()
+    });
+    let addr = server.start().await?;
+    println!("WebSocket server listening on ws://{addr}");
+
+    let grpc = YellowstoneGrpc::new(
+        "https://solana-yellowstone-grpc.publicnode.com:443".to_string(),
+        None,
+    )?;
+
+    let transaction_filter = TransactionFilter {
+        account_include: vec![PUMPFUN_PROGRAM_ID.to_string(), PUMPSWAP_PROGRAM_ID.to_string()],
+        account_exclude: vec![],
+        account_required: vec![],
+    };
+
+    grpc.subscribe_events_immediate(
+        vec![Protocol::PumpFun, Protocol::PumpSwap],
+        None,
+        vec![transaction_filter],
+        vec![],
+        None,
+        None,
+        server.sink(),
+    )
+    .await?;
+
+    println!("Waiting for Ctrl+C to stop...");
+    tokio::signal::ctrl_c().await?;
+    grpc.stop().await;
+    server.stop().await;
+
+    Ok(())
+}
@@ -0,0 +1,611 @@
+use std::collections::HashSet;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use solana_client::client_error::{ClientError, ClientErrorKind};
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_client::rpc_request::RpcError;
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, UiTransactionEncoding};
+use tokio::sync::Mutex;
+
+use crate::common::{AnyResult, SolanaRpcClient};
+use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::core::event_parser::EventParser;
+use crate::streaming::event_parser::core::rpc_transaction::RpcTransactionParts;
+use crate::streaming::event_parser::{DexEvent, Protocol};
+
+/// 回填范围，所有边界均为闭区间，`None` 表示不限制
+///
+/// 没有任何下界（`start_slot` / `start_time`）时会一直向前翻页直到地址的第一笔交易
+#[derive(Debug, Clone, Default)]
+pub struct BackfillRange {
+    pub start_slot: Option<u64>,
+    pub end_slot: Option<u64>,
+    /// Unix 时间戳（秒）
+    pub start_time: Option<i64>,
+    /// Unix 时间戳（秒）
+    pub end_time: Option<i64>,
+}
+
+impl BackfillRange {
+    pub fn slots(start_slot: u64, end_slot: u64) -> Self {
+        Self { start_slot: Some(start_slot), end_slot: Some(end_slot), ..Default::default() }
+    }
+
+    pub fn time(start_time: i64, end_time: i64) -> Self {
+        Self { start_time: Some(start_time), end_time: Some(end_time), ..Default::default() }
+    }
+
+    /// 是否已早于范围下界（签名按时间倒序返回，遇到后即可停止翻页）
+    fn is_before_start(&self, slot: u64, block_time: Option<i64>) -> bool {
+        self.start_slot.is_some_and(|start| slot < start)
+            || matches!((self.start_time, block_time), (Some(start), Some(t)) if t < start)
+    }
+
+    fn is_after_end(&self, slot: u64, block_time: Option<i64>) -> bool {
+        self.end_slot.is_some_and(|end| slot > end)
+            || matches!((self.end_time, block_time), (Some(end), Some(t)) if t > end)
+    }
+}
+
+/// 回填配置
+#[derive(Debug, Clone)]
+pub struct BackfillConfig {
+    /// 程序 ID 或普通账户，签名按地址分别翻页后合并去重
+    pub addresses: Vec<Pubkey>,
+    pub range: BackfillRange,
+    pub protocols: Vec<Protocol>,
+    pub event_type_filter: Option<EventTypeFilter>,
+    pub bot_wallet: Option<Pubkey>,
+    pub commitment: CommitmentConfig,
+    /// `getSignaturesForAddress` 每页数量，最大 1000
+    pub page_size: usize,
+    /// 同时进行中的 `getTransaction` 请求数
+    pub max_concurrency: usize,
+    /// 每秒最多发送的 RPC 请求数
+    pub requests_per_second: Option<u32>,
+    /// 单个请求失败后的最大重试次数
+    pub max_retries: u32,
+    /// 首次重试等待时间，之后指数增长
+    pub retry_backoff: Duration,
+    /// 是否解析执行失败的交易
+    pub include_failed: bool,
+    /// 断点文件，每处理完一笔交易更新一次，再次运行时从断点之后继续
+    pub checkpoint_path: Option<PathBuf>,
+}
+
+impl Default for BackfillConfig {
+    fn default() -> Self {
+        Self {
+            addresses: Vec::new(),
+            range: BackfillRange::default(),
+            protocols: Vec::new(),
+            event_type_filter: None,
+            bot_wallet: None,
+            commitment: CommitmentConfig::finalized(),
+            page_size: 1000,
+            max_concurrency: 8,
+            requests_per_second: None,
+            max_retries: 5,
+            retry_backoff: Duration::from_millis(500),
+            include_failed: false,
+            checkpoint_path: None,
+        }
+    }
+}
+
+/// 断点，记录最后一笔已处理的交易
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackfillCheckpoint {
+    pub slot: u64,
+    pub signature: String,
+}
+
+/// 回填统计
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BackfillStats {
+    /// 范围内的签名数量（去重、跳过断点之前的签名后）
+    pub signatures: usize,
+    /// 成功获取并解析的交易数量
+    pub transactions: usize,
+    /// 执行失败被跳过或无法解码的交易数量
+    pub skipped: usize,
+    /// 重试后仍获取失败的签名，断点不会越过其中第一笔，再次运行时会重新获取
+    pub fetch_failures: Vec<Signature>,
+    pub events: usize,
+}
+
+/// 待回填的签名
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackfillSignature {
+    pub signature: Signature,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub failed: bool,
+}
+
+/// 简单的请求间隔限速器
+struct RateLimiter {
+    interval: Option<Duration>,
+    next: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    fn new(requests_per_second: Option<u32>) -> Self {
+        let interval = requests_per_second
+            .filter(|rps| *rps > 0)
+            .map(|rps| Duration::from_secs_f64(1.0 / rps as f64));
+        Self { interval, next: Mutex::new(Instant::now()) }
+    }
+
+    async fn acquire(&self) {
+        let Some(interval) = self.interval else {
+            return;
+        };
+        let wait = {
+            let mut next = self.next.lock().await;
+            let now = Instant::now();
+            let slot = (*next).max(now);
+            *next = slot + interval;
+            slot - now
+        };
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// 通过 Solana RPC 回填历史交易事件
+///
+/// 按地址翻页 `getSignaturesForAddress`，以有限并发获取交易，并严格按时间顺序
+/// 连同交易日志一起交给 `EventParser` 解析。
+pub struct Backfiller {
+    rpc: Arc<SolanaRpcClient>,
+    config: BackfillConfig,
+    limiter: RateLimiter,
+}
+
+impl Backfiller {
+    pub fn new(rpc: Arc<SolanaRpcClient>, config: BackfillConfig) -> Self {
+        let limiter = RateLimiter::new(config.requests_per_second);
+        Self { rpc, config, limiter }
+    }
+
+    /// 使用 RPC 地址创建
+    pub fn from_url(rpc_url: impl Into<String>, config: BackfillConfig) -> Self {
+        let rpc = SolanaRpcClient::new_with_commitment(rpc_url.into(), config.commitment);
+        Self::new(Arc::new(rpc), config)
+    }
+
+    pub fn get_config(&self) -> &BackfillConfig {
+        &self.config
+    }
+
+    /// 读取断点文件
+    pub fn load_checkpoint(&self) -> AnyResult<Option<BackfillCheckpoint>> {
+        let Some(path) = &self.config.checkpoint_path else {
+            return Ok(None);
+        };
+        if !path.exists() {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_slice(&std::fs::read(path)?)?))
+    }
+
+    /// 获取范围内的所有签名，按时间正序排列，并跳过断点及之前的签名
+    pub async fn collect_signatures(&self) -> AnyResult<Vec<BackfillSignature>> {
+        let checkpoint = self.load_checkpoint()?;
+        let mut seen = HashSet::new();
+        let mut all = Vec::new();
+        for address in &self.config.addresses {
+            let mut signatures =
+                self.collect_address_signatures(address, checkpoint.as_ref()).await?;
+            // 接口按时间倒序返回，反转后为正序
+            signatures.reverse();
+            all.extend(signatures.into_iter().filter(|s| seen.insert(s.signature)));
+        }
+        // 稳定排序，同一 slot 内保持接口返回的相对顺序
+        all.sort_by_key(|s| s.slot);
+
+        if let Some(checkpoint) = checkpoint {
+            let resume_at = Signature::from_str(&checkpoint.signature)
+                .ok()
+                .and_then(|sig| all.iter().position(|s| s.signature == sig))
+                .map(|pos| pos + 1)
+                .unwrap_or_else(|| all.iter().take_while(|s| s.slot <= checkpoint.slot).count());
+            all.drain(..resume_at);
+        }
+        Ok(all)
+    }
+
+    async fn collect_address_signatures(
+        &self,
+        address: &Pubkey,
+        checkpoint: Option<&BackfillCheckpoint>,
+    ) -> AnyResult<Vec<BackfillSignature>> {
+        let page_size = self.config.page_size.clamp(1, 1000);
+        let mut before: Option<Signature> = None;
+        let mut signatures = Vec::new();
+        loop {
+            let page = self
+                .with_retry(|| {
+                    self.rpc.get_signatures_for_address_with_config(
+                        address,
+                        GetConfirmedSignaturesForAddress2Config {
+                            before,
+                            until: None,
+                            limit: Some(page_size),
+                            commitment: Some(self.config.commitment),
+                        },
+                    )
+                })
+                .await?;
+            let page_len = page.len();
+            let mut reached_start = false;
+            for item in page {
+                let signature = Signature::from_str(&item.signature)?;
+                before = Some(signature);
+                if self.config.range.is_before_start(item.slot, item.block_time)
+                    || checkpoint.is_some_and(|c| item.slot < c.slot)
+                {
+                    reached_start = true;
+                    break;
+                }
+                if self.config.range.is_after_end(item.slot, item.block_time) {
+                    continue;
+                }
+                signatures.push(BackfillSignature {
+                    signature,
+                    slot: item.slot,
+                    block_time: item.block_time,
+                    failed: item.err.is_some(),
+                });
+            }
+            if reached_start || page_len < page_size {
+                break;
+            }
+        }
+        info!("Collected {} signatures for {}", signatures.len(), address);
+        Ok(signatures)
+    }
+
+    /// 执行回填，事件按交易时间顺序交给 `callback`
+    pub async fn run<F>(&self, callback: F) -> AnyResult<BackfillStats>
+    where
+        F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        let signatures = self.collect_signatures().await?;
+        let mut stats = BackfillStats { signatures: signatures.len(), ..Default::default() };
+
+        let event_count = Arc::new(AtomicUsize::new(0));
+        let counter = event_count.clone();
+        let callback: Arc<dyn Fn(DexEvent) + Send + Sync> = Arc::new(move |event: DexEvent| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            callback(event);
+        });
+
+        let include_failed = self.config.include_failed;
+        // buffered 保证结果按输入顺序返回，请求本身并发进行
+        let mut results = futures::stream::iter(signatures.into_iter().map(|info| async move {
+            if info.failed && !include_failed {
+                return (info, None);
+            }
+            let result = self.fetch_transaction(&info.signature).await;
+            (info, Some(result))
+        }))
+        .buffered(self.config.max_concurrency.max(1));
+
+        while let Some((info, result)) = results.next().await {
+            let parts = match result {
+                None => None,
+                Some(Ok(tx)) => {
+                    let parts = RpcTransactionParts::from_encoded(&tx);
+                    if parts.is_none() {
+                        warn!("Failed to decode transaction {}", info.signature);
+                    }
+                    parts
+                }
+                Some(Err(e)) => {
+                    warn!("Failed to fetch transaction {}: {e}", info.signature);
+                    stats.fetch_failures.push(info.signature);
+                    continue;
+                }
+            };
+            match parts {
+                Some(parts) => {
+                    EventParser::parse_rpc_transaction_parts_with(
+                        &self.config.protocols,
+                        self.config.event_type_filter.as_ref(),
+                        &parts,
+                        self.config.bot_wallet,
+                        None,
+                        &mut |event| callback(event),
+                    );
+                    stats.transactions += 1;
+                }
+                None => stats.skipped += 1,
+            }
+            // 出现获取失败后断点停在它之前，之后的交易在下次运行时会重新处理
+            if stats.fetch_failures.is_empty() {
+                self.save_checkpoint(&BackfillCheckpoint {
+                    slot: info.slot,
+                    signature: info.signature.to_string(),
+                })?;
+            }
+        }
+
+        if let Some(first) = stats.fetch_failures.first() {
+            warn!(
+                "{} transactions could not be fetched, checkpoint kept before {first}",
+                stats.fetch_failures.len()
+            );
+        }
+        stats.events = event_count.load(Ordering::Relaxed);
+        Ok(stats)
+    }
+
+    async fn fetch_transaction(
+        &self,
+        signature: &Signature,
+    ) -> Result<EncodedConfirmedTransactionWithStatusMeta, ClientError> {
+        self.with_retry(|| {
+            self.rpc.get_transaction_with_config(
+                signature,
+                RpcTransactionConfig {
+                    encoding: Some(UiTransactionEncoding::Base64),
+                    commitment: Some(self.config.commitment),
+                    max_supported_transaction_version: Some(0),
+                },
+            )
+        })
+        .await
+    }
+
+    async fn with_retry<T, F, Fut>(&self, op: F) -> Result<T, ClientError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, ClientError>>,
+    {
+        let mut attempt = 0;
+        loop {
+            self.limiter.acquire().await;
+            match op().await {
+                Ok(value) => return Ok(value),
+                Err(e) if attempt < self.config.max_retries => {
+                    let mut backoff = self.config.retry_backoff * 2u32.saturating_pow(attempt);
+                    if is_rate_limited(&e) {
+                        // 被限流时额外等待，给服务端恢复时间
+                        backoff *= 2;
+                        warn!("RPC rate limited, retrying in {backoff:?}");
+                    }
+                    tokio::time::sleep(backoff).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn save_checkpoint(&self, checkpoint: &BackfillCheckpoint) -> AnyResult<()> {
+        let Some(path) = &self.config.checkpoint_path else {
+            return Ok(());
+        };
+        write_atomically(path, &serde_json::to_vec(checkpoint)?)
+    }
+}
+
+fn write_atomically(path: &Path, data: &[u8]) -> AnyResult<()> {
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, data)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// 是否为限流错误（HTTP 429 或节点返回的 429 错误码）
+fn is_rate_limited(error: &ClientError) -> bool {
+    match error.kind() {
+        ClientErrorKind::Reqwest(e) => e.status().is_some_and(|status| status.as_u16() == 429),
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. }) => *code == 429,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::core::transaction_input::fixtures::transaction;
+    use serde_json::{json, Value};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    const COMPUTE_BUDGET: Pubkey =
+        solana_sdk::pubkey!("ComputeBudget111111111111111111111111111111");
+
+    fn signature_for(slot: u64) -> Signature {
+        Signature::from([slot as u8; 64])
+    }
+
+    fn encoded_transaction(slot: u64) -> String {
+        let mut data = vec![3u8];
+        data.extend_from_slice(&slot.to_le_bytes());
+        let mut tx = transaction(COMPUTE_BUDGET, &data, &[]);
+        tx.signatures = vec![signature_for(slot)];
+        solana_sdk::bs58::encode(bincode::serialize(&tx).unwrap()).into_string()
+    }
+
+    /// 极简 JSON-RPC 服务：三笔交易分别位于 slot 30 / 20 / 10，每页 2 条；
+    /// `failing_slot` 上的交易 `getTransaction` 始终返回错误
+    fn handle_rpc(request: &Value, failing_slot: Option<u64>) -> Value {
+        let params = &request["params"];
+        let result = match request["method"].as_str().unwrap_or_default() {
+            "getSignaturesForAddress" => {
+                let before = params[1]["before"].as_str().map(str::to_string);
+                let slots: &[u64] = if before.is_none() { &[30, 20] } else { &[10] };
+                json!(slots
+                    .iter()
+                    .map(|slot| json!({
+                        "signature": signature_for(*slot).to_string(),
+                        "slot": slot,
+                        "err": null,
+                        "memo": null,
+                        "blockTime": 1_700_000_000 + *slot as i64,
+                        "confirmationStatus": "finalized",
+                    }))
+                    .collect::<Vec<_>>())
+            }
+            "getTransaction" => {
+                let sig = params[0].as_str().unwrap_or_default();
+                let slot = [10u64, 20, 30]
+                    .into_iter()
+                    .find(|slot| signature_for(*slot).to_string() == sig)
+                    .unwrap_or_default();
+                if failing_slot == Some(slot) {
+                    return json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "error": {"code": -32000, "message": "transaction unavailable"},
+                    });
+                }
+                json!({
+                    "slot": slot,
+                    "blockTime": 1_700_000_000 + slot as i64,
+                    "transaction": [encoded_transaction(slot), "base58"],
+                    "meta": {
+                        "err": null,
+                        "status": {"Ok": null},
+                        "fee": 5000,
+                        "preBalances": [1, 1],
+                        "postBalances": [1, 1],
+                    },
+                })
+            }
+            _ => Value::Null,
+        };
+        json!({"jsonrpc": "2.0", "id": request["id"], "result": result})
+    }
+
+    async fn spawn_mock_rpc(failing_slot: Option<u64>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut buf = Vec::new();
+                    let mut chunk = [0u8; 4096];
+                    loop {
+                        let Some(header_end) = buf.windows(4).position(|w| w == b"\r\n\r\n") else {
+                            match stream.read(&mut chunk).await {
+                                Ok(0) | Err(_) => return,
+                                Ok(n) => buf.extend_from_slice(&chunk[..n]),
+                            }
+                            continue;
+                        };
+                        let headers = String::from_utf8_lossy(&buf[..header_end]).to_lowercase();
+                        let content_length = headers
+                            .lines()
+                            .find_map(|l| l.strip_prefix("content-length:"))
+                            .and_then(|v| v.trim().parse::<usize>().ok())
+                            .unwrap_or(0);
+                        let body_start = header_end + 4;
+                        while buf.len() < body_start + content_length {
+                            match stream.read(&mut chunk).await {
+                                Ok(0) | Err(_) => return,
+                                Ok(n) => buf.extend_from_slice(&chunk[..n]),
+                            }
+                        }
+                        let request: Value =
+                            serde_json::from_slice(&buf[body_start..body_start + content_length])
+                                .unwrap();
+                        buf.drain(..body_start + content_length);
+                        let body = handle_rpc(&request, failing_slot).to_string();
+                        let response = format!(
+                            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                            body.len(),
+                            body
+                        );
+                        if stream.write_all(response.as_bytes()).await.is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+        format!("http://{addr}")
+    }
+
+    #[tokio::test]
+    async fn backfill_in_order_and_resume() {
+        let url = spawn_mock_rpc(None).await;
+        let checkpoint_path =
+            std::env::temp_dir().join(format!("backfill-checkpoint-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&checkpoint_path);
+        let config = BackfillConfig {
+            addresses: vec![COMPUTE_BUDGET],
+            range: BackfillRange { start_slot: Some(15), ..Default::default() },
+            page_size: 2,
+            max_concurrency: 4,
+            retry_backoff: Duration::from_millis(1),
+            checkpoint_path: Some(checkpoint_path.clone()),
+            ..Default::default()
+        };
+
+        let slots = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = slots.clone();
+        let backfiller = Backfiller::from_url(url.clone(), config.clone());
+        let stats = backfiller
+            .run(move |event: DexEvent| seen.lock().unwrap().push(event.metadata().slot))
+            .await
+            .unwrap();
+        assert_eq!(stats.signatures, 2);
+        assert_eq!(stats.transactions, 2);
+        assert_eq!(*slots.lock().unwrap(), vec![20, 30]);
+        assert_eq!(backfiller.load_checkpoint().unwrap().map(|c| c.slot), Some(30));
+
+        let resumed = Backfiller::from_url(url, config).run(|_event: DexEvent| {}).await.unwrap();
+        assert_eq!(resumed, BackfillStats::default());
+        let _ = std::fs::remove_file(&checkpoint_path);
+    }
+
+    #[tokio::test]
+    async fn backfill_keeps_checkpoint_before_fetch_failures() {
+        let checkpoint_path = std::env::temp_dir()
+            .join(format!("backfill-fetch-failure-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&checkpoint_path);
+        let config = BackfillConfig {
+            addresses: vec![COMPUTE_BUDGET],
+            range: BackfillRange { start_slot: Some(15), ..Default::default() },
+            page_size: 2,
+            max_retries: 0,
+            checkpoint_path: Some(checkpoint_path.clone()),
+            ..Default::default()
+        };
+
+        // slot 20 获取失败，slot 30 仍然解析，但断点不越过 slot 20
+        let failing = Backfiller::from_url(spawn_mock_rpc(Some(20)).await, config.clone());
+        let stats = failing.run(|_event: DexEvent| {}).await.unwrap();
+        assert_eq!((stats.transactions, stats.skipped), (1, 0));
+        assert_eq!(stats.fetch_failures, vec![signature_for(20)]);
+        assert_eq!(failing.load_checkpoint().unwrap(), None);
+
+        let slots = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = slots.clone();
+        let resumed = Backfiller::from_url(spawn_mock_rpc(None).await, config);
+        resumed
+            .run(move |event: DexEvent| seen.lock().unwrap().push(event.metadata().slot))
+            .await
+            .unwrap();
+        assert_eq!(*slots.lock().unwrap(), vec![20, 30]);
+        let _ = std::fs::remove_file(&checkpoint_path);
+    }
+}
@@ -51,7 +51,15 @@ pub enum ProtocolType {
     RaydiumClmm,
     RaydiumAmmV4,
     MeteoraDammV2,
+    MeteoraDammV1,
+    MeteoraDlmm,
+    OrcaWhirlpool,
+    SplToken,
+    System,
+    Jupiter,
     Common,
+    /// 第三方协议，具体名称见 `CustomEvent::protocol`
+    Custom,
 }
 
 /// Event type enumeration
@@ -66,6 +74,14 @@ pub enum EventType {
     PumpSwapCreatePool,
     PumpSwapDeposit,
     PumpSwapWithdraw,
+    PumpSwapCollectCoinCreatorFee,
+    PumpSwapClaimCashback,
+    PumpSwapClaimTokenIncentives,
+    PumpSwapSetCoinCreator,
+    PumpSwapMigratePoolCoinCreator,
+    PumpSwapTransferCreatorFeesToPump,
+    PumpSwapToggleMayhemMode,
+    PumpSwapToggleCashbackEnabled,
 
     // PumpFun events
     PumpFunCreateToken,
@@ -73,6 +89,17 @@ pub enum EventType {
     PumpFunBuy,
     PumpFunSell,
     PumpFunMigrate,
+    PumpFunComplete,
+    PumpFunCollectCreatorFee,
+    PumpFunDistributeCreatorFees,
+    PumpFunClaimCashback,
+    PumpFunSetCreator,
+    PumpFunSetMetaplexCreator,
+    PumpFunMigrateBondingCurveCreator,
+    PumpFunInitUserVolumeAccumulator,
+    PumpFunSyncUserVolumeAccumulator,
+    PumpFunCloseUserVolumeAccumulator,
+    PumpFunClaimTokenIncentives,
 
     // Bonk events
     BonkBuyExactIn,
@@ -84,6 +111,18 @@ pub enum EventType {
     BonkInitializeWithToken2022,
     BonkMigrateToAmm,
     BonkMigrateToCpswap,
+    BonkClaimVestedToken,
+    BonkCreateVestingAccount,
+    BonkCreatePlatformVestingAccount,
+    BonkClaimCreatorFee,
+    BonkClaimPlatformFee,
+    BonkClaimPlatformFeeFromVault,
+    BonkCollectFee,
+    BonkCollectMigrateFee,
+    BonkUpdateConfig,
+    BonkUpdatePlatformConfig,
+    BonkUpdatePlatformCurveParam,
+    BonkRemovePlatformCurveParam,
 
     // Raydium CPMM events
     RaydiumCpmmSwapBaseInput,
@@ -91,6 +130,9 @@ pub enum EventType {
     RaydiumCpmmDeposit,
     RaydiumCpmmInitialize,
     RaydiumCpmmWithdraw,
+    RaydiumCpmmCollectCreatorFee,
+    RaydiumCpmmCollectProtocolFee,
+    RaydiumCpmmCollectFundFee,
 
     // Raydium CLMM events
     RaydiumClmmSwap,
@@ -101,6 +143,14 @@ pub enum EventType {
     RaydiumClmmCreatePool,
     RaydiumClmmOpenPositionWithToken22Nft,
     RaydiumClmmOpenPositionV2,
+    RaydiumClmmOpenPosition,
+    RaydiumClmmIncreaseLiquidity,
+    RaydiumClmmDecreaseLiquidity,
+    RaydiumClmmSwapRouterBaseIn,
+    RaydiumClmmCollectPersonalFee,
+    RaydiumClmmCollectRemainingRewards,
+    RaydiumClmmCollectProtocolFee,
+    RaydiumClmmCollectFundFee,
 
     // Raydium AMM V4 events
     RaydiumAmmV4SwapBaseIn,
@@ -116,22 +166,137 @@ pub enum EventType {
     MeteoraDammV2InitializePool,
     MeteoraDammV2InitializeCustomizablePool,
     MeteoraDammV2InitializePoolWithDynamicConfig,
+    MeteoraDammV2AddLiquidity,
+    MeteoraDammV2RemoveLiquidity,
+    MeteoraDammV2RemoveAllLiquidity,
+    MeteoraDammV2CreatePosition,
+    MeteoraDammV2ClosePosition,
+    MeteoraDammV2SplitPosition,
+    MeteoraDammV2SplitPosition2,
+    MeteoraDammV2LockPosition,
+    MeteoraDammV2PermanentLockPosition,
+    MeteoraDammV2ClaimPositionFee,
+    MeteoraDammV2ClaimReward,
+
+    // Meteora DAMM v1 events
+    MeteoraDammV1Swap,
+    MeteoraDammV1AddBalanceLiquidity,
+    MeteoraDammV1AddImbalanceLiquidity,
+    MeteoraDammV1RemoveBalanceLiquidity,
+    MeteoraDammV1RemoveLiquiditySingleSide,
+    MeteoraDammV1BootstrapLiquidity,
+    MeteoraDammV1InitializePermissionedPool,
+    MeteoraDammV1InitializePermissionlessPool,
+    MeteoraDammV1InitializePermissionlessPoolWithFeeTier,
+    MeteoraDammV1Lock,
+    MeteoraDammV1ClaimFee,
+
+    // Meteora DLMM events
+    MeteoraDlmmSwap,
+    MeteoraDlmmSwapExactOut,
+    MeteoraDlmmSwapWithPriceImpact,
+    MeteoraDlmmAddLiquidity,
+    MeteoraDlmmAddLiquidityOneSide,
+    MeteoraDlmmRemoveLiquidity,
+    MeteoraDlmmPositionCreate,
+    MeteoraDlmmPositionClose,
+    MeteoraDlmmClaimFee,
+    MeteoraDlmmClaimReward,
+
+    // Orca Whirlpool events
+    OrcaWhirlpoolSwap,
+    OrcaWhirlpoolTwoHopSwap,
+    OrcaWhirlpoolIncreaseLiquidity,
+    OrcaWhirlpoolDecreaseLiquidity,
+    OrcaWhirlpoolOpenPosition,
+    OrcaWhirlpoolOpenPositionWithTokenExtensions,
+    OrcaWhirlpoolClosePosition,
+    OrcaWhirlpoolClosePositionWithTokenExtensions,
+    OrcaWhirlpoolCollectFees,
+    OrcaWhirlpoolCollectReward,
+
+    // SPL Token events
+    SplTokenTransfer,
+    SplTokenTransferChecked,
+    SplTokenTransferCheckedWithFee,
+    SplTokenMintTo,
+    SplTokenMintToChecked,
+    SplTokenBurn,
+    SplTokenBurnChecked,
+    SplTokenCloseAccount,
+    SplTokenApprove,
+    SplTokenApproveChecked,
+    SplTokenSetAuthority,
+    SplTokenInitializeAccount,
+    SplTokenInitializeAccount2,
+    SplTokenInitializeAccount3,
+
+    // System Program events
+    SystemTransfer,
+    SystemTransferWithSeed,
+    SystemCreateAccount,
+    SystemCreateAccountWithSeed,
+    SystemAdvanceNonce,
+    SystemAuthorizeNonce,
+
+    // Address Lookup Table events
+    AddressLookupTableCreate,
+    AddressLookupTableExtend,
+    AddressLookupTableFreeze,
+    AddressLookupTableDeactivate,
+    AddressLookupTableClose,
+
+    // Jupiter events
+    JupiterRoute,
+    JupiterRouteWithTokenLedger,
+    JupiterSharedAccountsRoute,
+    JupiterSharedAccountsRouteWithTokenLedger,
+    JupiterExactOutRoute,
+    JupiterSharedAccountsExactOutRoute,
+    JupiterRouteV2,
+    JupiterExactOutRouteV2,
+    JupiterSharedAccountsRouteV2,
+    JupiterSharedAccountsExactOutRouteV2,
+    JupiterSwap,
 
     // Account events
     AccountRaydiumAmmV4AmmInfo,
     AccountPumpSwapGlobalConfig,
     AccountPumpSwapPool,
+    AccountPumpSwapFeeConfig,
+    AccountPumpSwapSharingConfig,
+    AccountPumpSwapGlobalVolumeAccumulator,
+    AccountPumpSwapUserVolumeAccumulator,
     AccountBonkPoolState,
     AccountBonkGlobalConfig,
     AccountBonkPlatformConfig,
     AccountBonkVestingRecord,
     AccountPumpFunBondingCurve,
     AccountPumpFunGlobal,
+    AccountPumpFunFeeConfig,
+    AccountPumpFunSharingConfig,
+    AccountPumpFunGlobalVolumeAccumulator,
+    AccountPumpFunUserVolumeAccumulator,
     AccountRaydiumClmmAmmConfig,
     AccountRaydiumClmmPoolState,
     AccountRaydiumClmmTickArrayState,
+    AccountRaydiumClmmPersonalPositionState,
+    AccountRaydiumClmmObservationState,
+    AccountRaydiumClmmTickArrayBitmapExtension,
     AccountRaydiumCpmmAmmConfig,
     AccountRaydiumCpmmPoolState,
+    AccountRaydiumCpmmObservationState,
+    AccountMeteoraDammV2Pool,
+    AccountMeteoraDammV2Position,
+    AccountMeteoraDammV2Config,
+    AccountMeteoraDammV1Pool,
+    AccountMeteoraDammV1LockEscrow,
+    AccountMeteoraDlmmLbPair,
+    AccountMeteoraDlmmBinArray,
+    AccountMeteoraDlmmPositionV2,
+    AccountOrcaWhirlpoolWhirlpool,
+    AccountOrcaWhirlpoolTickArray,
+    AccountOrcaWhirlpoolPosition,
 
     NonceAccount,
     TokenAccount,
@@ -140,6 +305,10 @@ pub enum EventType {
     BlockMeta,
     SetComputeUnitLimit,
     SetComputeUnitPrice,
+    /// 第三方协议事件，具体名称见 `CustomEvent::name`
+    Custom,
+    /// IDL 解码事件，具体名称见 `IdlEvent::name`
+    Idl,
     Unknown,
 }
 
@@ -147,17 +316,40 @@ pub const ACCOUNT_EVENT_TYPES: &[EventType] = &[
     EventType::AccountRaydiumAmmV4AmmInfo,
     EventType::AccountPumpSwapGlobalConfig,
     EventType::AccountPumpSwapPool,
+    EventType::AccountPumpSwapFeeConfig,
+    EventType::AccountPumpSwapSharingConfig,
+    EventType::AccountPumpSwapGlobalVolumeAccumulator,
+    EventType::AccountPumpSwapUserVolumeAccumulator,
     EventType::AccountBonkPoolState,
     EventType::AccountBonkGlobalConfig,
     EventType::AccountBonkPlatformConfig,
     EventType::AccountBonkVestingRecord,
     EventType::AccountPumpFunBondingCurve,
     EventType::AccountPumpFunGlobal,
+    EventType::AccountPumpFunFeeConfig,
+    EventType::AccountPumpFunSharingConfig,
+    EventType::AccountPumpFunGlobalVolumeAccumulator,
+    EventType::AccountPumpFunUserVolumeAccumulator,
     EventType::AccountRaydiumClmmAmmConfig,
     EventType::AccountRaydiumClmmPoolState,
     EventType::AccountRaydiumClmmTickArrayState,
+    EventType::AccountRaydiumClmmPersonalPositionState,
+    EventType::AccountRaydiumClmmObservationState,
+    EventType::AccountRaydiumClmmTickArrayBitmapExtension,
     EventType::AccountRaydiumCpmmAmmConfig,
     EventType::AccountRaydiumCpmmPoolState,
+    EventType::AccountRaydiumCpmmObservationState,
+    EventType::AccountMeteoraDammV2Pool,
+    EventType::AccountMeteoraDammV2Position,
+    EventType::AccountMeteoraDammV2Config,
+    EventType::AccountMeteoraDammV1Pool,
+    EventType::AccountMeteoraDammV1LockEscrow,
+    EventType::AccountMeteoraDlmmLbPair,
+    EventType::AccountMeteoraDlmmBinArray,
+    EventType::AccountMeteoraDlmmPositionV2,
+    EventType::AccountOrcaWhirlpoolWhirlpool,
+    EventType::AccountOrcaWhirlpoolTickArray,
+    EventType::AccountOrcaWhirlpoolPosition,
     EventType::TokenAccount,
     EventType::NonceAccount,
 ];
@@ -180,6 +372,14 @@ pub struct SwapData {
     pub description: Option<Cow<'static, str>>,
 }
 
+/// 聚合器路由指令的位置，子事件通过它关联到所属路由
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RouteRef {
+    pub program_id: Pubkey,
+    pub outer_index: i64,
+    pub inner_index: Option<i64>,
+}
+
 /// Event metadata
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EventMetadata {
@@ -199,6 +399,9 @@ pub struct EventMetadata {
     /// Transaction message recent blockhash as base58 string (same encoding as signature), when available.
     #[serde(default)]
     pub recent_blockhash: Option<String>,
+    /// 由聚合器路由（如 Jupiter）CPI 调用时，指向所属的路由指令
+    #[serde(default)]
+    pub parent_route: Option<RouteRef>,
 }
 
 impl EventMetadata {
@@ -232,6 +435,7 @@ impl EventMetadata {
             inner_index,
             tx_index,
             recent_blockhash,
+            parent_route: None,
         }
     }
 
@@ -349,6 +553,42 @@ fn extract_swap_context(event: &DexEvent) -> (
             from_vault = Some(e.pool_pc_token_account);
             to_vault = Some(e.pool_coin_token_account);
         }
+        DexEvent::OrcaWhirlpoolSwapEvent(e) => {
+            let (user_from, from_v, user_to, to_v) = if e.a_to_b {
+                (e.token_owner_account_a, e.token_vault_a, e.token_owner_account_b, e.token_vault_b)
+            } else {
+                (e.token_owner_account_b, e.token_vault_b, e.token_owner_account_a, e.token_vault_a)
+            };
+            // 只有 swapV2 指令带 mint 账户
+            if e.token_mint_a == Pubkey::default() {
+                swap_data.description =
+                    Some("Unable to get from_mint and to_mint from OrcaWhirlpoolSwapEvent".into());
+            } else if e.a_to_b {
+                from_mint = Some(e.token_mint_a);
+                to_mint = Some(e.token_mint_b);
+            } else {
+                from_mint = Some(e.token_mint_b);
+                to_mint = Some(e.token_mint_a);
+            }
+            user_from_token = Some(user_from);
+            user_to_token = Some(user_to);
+            from_vault = Some(from_v);
+            to_vault = Some(to_v);
+        }
+        DexEvent::OrcaWhirlpoolTwoHopSwapEvent(e) => {
+            if e.token_mint_input == Pubkey::default() {
+                swap_data.description = Some(
+                    "Unable to get from_mint and to_mint from OrcaWhirlpoolTwoHopSwapEvent".into(),
+                );
+            } else {
+                from_mint = Some(e.token_mint_input);
+                to_mint = Some(e.token_mint_output);
+            }
+            user_from_token = Some(e.token_owner_account_input);
+            user_to_token = Some(e.token_owner_account_output);
+            from_vault = Some(e.token_vault_one_input);
+            to_vault = Some(e.token_vault_two_output);
+        }
         _ => {}
     }
 
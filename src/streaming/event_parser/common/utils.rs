@@ -1,3 +1,4 @@
+use base64::Engine;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// 获取当前时间戳
@@ -24,6 +25,11 @@ pub fn extract_program_log<'a>(log: &'a str, prefix: &str) -> Option<&'a str> {
     log.strip_prefix(prefix)
 }
 
+/// 解码 `Program data:` 日志（Anchor `emit!` 事件，base64 编码）
+pub fn decode_program_data(log: &str) -> Option<Vec<u8>> {
+    base64::engine::general_purpose::STANDARD.decode(extract_program_data(log)?).ok()
+}
+
 /// 安全地从字节数组中读取 i64
 pub fn read_i64_le(data: &[u8], offset: usize) -> Option<i64> {
     if data.len() < offset + 8 {
@@ -104,7 +104,35 @@ impl AccountEventParser {
             }
         }
 
-        // 2. 尝试解析特殊账户类型（Token、Nonce等）
+        // 2. 尝试通过 AccountEventParseConfig 注册的解析器（fee program 账户、交易量累计账户等）
+        if account.data.len() >= 8 {
+            use crate::streaming::event_parser::core::parser_cache::find_account_event_parse_configs;
+
+            let discriminator = &account.data[0..8];
+            for config in find_account_event_parse_configs(protocols, &account.owner, discriminator)
+            {
+                if let Some(filter) = event_type_filter {
+                    if !filter.include.contains(&config.event_type) {
+                        continue;
+                    }
+                }
+                let metadata = EventMetadata {
+                    slot: account.slot,
+                    signature: account.signature,
+                    protocol: config.protocol_type.clone(),
+                    event_type: config.event_type.clone(),
+                    program_id: account.owner,
+                    recv_us: account.recv_us,
+                    handle_us: elapsed_micros_since(account.recv_us),
+                    ..Default::default()
+                };
+                if let Some(event) = (config.account_parser)(&account, metadata) {
+                    return Some(event);
+                }
+            }
+        }
+
+        // 3. 尝试解析特殊账户类型（Token、Nonce等）
         // 这些是通用的，不属于特定协议
         let metadata = EventMetadata {
             slot: account.slot,
@@ -0,0 +1,256 @@
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context};
+use flate2::read::GzDecoder;
+use serde_json::Value;
+use solana_transaction_status::{EncodedConfirmedBlock, UiConfirmedBlock};
+
+use crate::common::AnyResult;
+
+/// 从文件加载的区块
+#[derive(Debug)]
+pub struct ConfirmedBlockFile {
+    pub path: PathBuf,
+    pub slot: u64,
+    pub block: EncodedConfirmedBlock,
+}
+
+/// 加载 `getBlock` 返回结果保存的 JSON 文件（支持 `.json.gz`）
+///
+/// 文件内容可以是区块对象本身，也可以是完整的 JSON-RPC 响应（`{"result": {...}}`）。
+/// `getBlock` 的结果中不包含 slot，因此按以下顺序确定：
+/// 区块对象或响应中的 `slot` 字段 -> 文件名中的最后一段数字
+pub fn load_block_file(path: impl AsRef<Path>) -> AnyResult<ConfirmedBlockFile> {
+    let path = path.as_ref();
+    let file = BufReader::new(File::open(path)?);
+    let mut reader: Box<dyn Read> = if path.extension().is_some_and(|ext| ext == "gz") {
+        Box::new(GzDecoder::new(file))
+    } else {
+        Box::new(file)
+    };
+    let mut content = Vec::new();
+    reader.read_to_end(&mut content)?;
+
+    let mut value: Value = serde_json::from_slice(&content)
+        .with_context(|| format!("invalid block json: {}", path.display()))?;
+    let mut slot = value.get("slot").and_then(Value::as_u64);
+    if let Some(result) = value.get_mut("result").map(Value::take) {
+        slot = slot.or_else(|| result.get("slot").and_then(Value::as_u64));
+        value = result;
+    }
+    let slot = slot
+        .or_else(|| slot_from_file_name(path))
+        .ok_or_else(|| anyhow!("cannot determine slot of block file: {}", path.display()))?;
+    let block: UiConfirmedBlock = serde_json::from_value(value)
+        .with_context(|| format!("invalid block json: {}", path.display()))?;
+
+    Ok(ConfirmedBlockFile { path: path.to_path_buf(), slot, block: block.into() })
+}
+
+/// 区块文件的 slot，用于在加载前排序
+///
+/// 优先取文件名中的最后一段数字；文件名不含 slot 时才加载文件读取，读取后立即释放
+pub fn block_file_slot(path: impl AsRef<Path>) -> AnyResult<u64> {
+    let path = path.as_ref();
+    match slot_from_file_name(path) {
+        Some(slot) => Ok(slot),
+        None => Ok(load_block_file(path)?.slot),
+    }
+}
+
+/// 列出区块文件，支持单个文件、目录（其中所有 `.json` / `.json.gz` 文件）
+/// 以及文件名中带 `*` / `?` 通配符的路径（如 `blocks/3000*.json`）
+///
+/// 结果按路径排序
+pub fn list_block_files(pattern: impl AsRef<Path>) -> AnyResult<Vec<PathBuf>> {
+    let pattern = pattern.as_ref();
+    let file_pattern = pattern.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+
+    let mut files = if file_pattern.contains(['*', '?']) {
+        let dir = match pattern.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        read_dir_files(dir, |name| wildcard_match(file_pattern, name))?
+    } else if pattern.is_dir() {
+        read_dir_files(pattern, |name| name.ends_with(".json") || name.ends_with(".json.gz"))?
+    } else if pattern.is_file() {
+        vec![pattern.to_path_buf()]
+    } else {
+        return Err(anyhow!("block file not found: {}", pattern.display()));
+    };
+    files.sort();
+    Ok(files)
+}
+
+fn read_dir_files(dir: &Path, filter: impl Fn(&str) -> bool) -> AnyResult<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.file_name().and_then(|n| n.to_str()).is_some_and(&filter) {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// 简单通配符匹配，`*` 匹配任意长度字符，`?` 匹配单个字符
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = backtrack {
+            p = star_p + 1;
+            n = star_n + 1;
+            backtrack = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// 取文件名中的最后一段数字作为 slot，如 `block-312345678.json`
+fn slot_from_file_name(path: &Path) -> Option<u64> {
+    let name = path.file_name()?.to_str()?;
+    name.split(|c: char| !c.is_ascii_digit()).rfind(|s| !s.is_empty())?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        core::{event_parser::EventParser, transaction_input::fixtures::transaction},
+        Protocol,
+    };
+    use serde_json::json;
+    use solana_sdk::{pubkey::Pubkey, signature::Signature, transaction::VersionedTransaction};
+
+    const COMPUTE_BUDGET: Pubkey =
+        solana_sdk::pubkey!("ComputeBudget111111111111111111111111111111");
+
+    fn compute_price_transaction(price: u64) -> VersionedTransaction {
+        let mut data = vec![3u8];
+        data.extend_from_slice(&price.to_le_bytes());
+        let mut tx = transaction(COMPUTE_BUDGET, &data, &[]);
+        tx.signatures = vec![Signature::from([price as u8; 64])];
+        tx
+    }
+
+    fn meta(failed: bool) -> Value {
+        let err =
+            if failed { json!({"InstructionError": [0, "InvalidArgument"]}) } else { json!(null) };
+        json!({"err": err, "status": {"Ok": null}, "fee": 5000, "preBalances": [], "postBalances": []})
+    }
+
+    #[test]
+    fn test_parse_block_file_in_tx_order() {
+        // tx0: json 编码，tx1: 失败交易，tx2: base58 编码
+        let tx0 = compute_price_transaction(7);
+        let message = &tx0.message;
+        let json_tx = json!({
+            "signatures": [tx0.signatures[0].to_string()],
+            "message": {
+                "header": message.header(),
+                "accountKeys": message.static_account_keys().iter().map(|k| k.to_string()).collect::<Vec<_>>(),
+                "recentBlockhash": message.recent_blockhash().to_string(),
+                "instructions": [{
+                    "programIdIndex": message.instructions()[0].program_id_index,
+                    "accounts": [],
+                    "data": solana_sdk::bs58::encode(&message.instructions()[0].data).into_string(),
+                }],
+            },
+        });
+        let binary = |tx: &VersionedTransaction| {
+            let bytes = bincode::serialize(tx).unwrap();
+            json!([solana_sdk::bs58::encode(bytes).into_string(), "base58"])
+        };
+        let block = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "previousBlockhash": "11111111111111111111111111111111",
+                "blockhash": "11111111111111111111111111111111",
+                "parentSlot": 99,
+                "blockTime": 1_700_000_000,
+                "transactions": [
+                    {"transaction": json_tx, "meta": meta(false)},
+                    {"transaction": binary(&compute_price_transaction(8)), "meta": meta(true)},
+                    {"transaction": binary(&compute_price_transaction(9)), "meta": meta(false)},
+                ],
+            },
+        });
+
+        let dir = std::env::temp_dir().join(format!("block-files-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("block-100.json"), block.to_string()).unwrap();
+        // 按 slot 而不是文件名排序：block-99 排在 block-100 之前
+        let mut earlier = block.clone();
+        earlier["result"]["transactions"] = json!([]);
+        std::fs::write(dir.join("block-99.json"), earlier.to_string()).unwrap();
+
+        let mut blocks = Vec::new();
+        let count = EventParser::parse_confirmed_block_files(
+            &[Protocol::PumpFun],
+            None,
+            dir.join("block-*.json"),
+            None,
+            |slot, events| blocks.push((slot, events)),
+        )
+        .unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(count, 2);
+        assert_eq!((blocks[0].0, blocks[0].1.len()), (99, 0));
+        let (slot, events) = &blocks[1];
+        assert_eq!(*slot, 100);
+        let parsed: Vec<_> = events
+            .iter()
+            .map(|e| {
+                let metadata = e.metadata();
+                (metadata.signature, metadata.tx_index, metadata.slot, metadata.block_time)
+            })
+            .collect();
+        assert_eq!(
+            parsed,
+            vec![
+                (Signature::from([7u8; 64]), Some(0), 100, 1_700_000_000),
+                (Signature::from([9u8; 64]), Some(2), 100, 1_700_000_000),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_undecodable_transaction_fails_the_block() {
+        let block: UiConfirmedBlock = serde_json::from_value(json!({
+            "previousBlockhash": "11111111111111111111111111111111",
+            "blockhash": "11111111111111111111111111111111",
+            "parentSlot": 99,
+            "blockTime": 1_700_000_000,
+            "transactions": [{"transaction": ["not base58!", "base58"], "meta": meta(false)}],
+        }))
+        .unwrap();
+        let err = EventParser::parse_confirmed_block(&[Protocol::PumpFun], None, block, 100, None)
+            .unwrap_err();
+        assert!(err.to_string().contains("slot 100, tx_index 0"), "{err}");
+    }
+
+    #[test]
+    fn test_wildcard_and_slot_from_name() {
+        assert!(wildcard_match("block-*.json", "block-123.json"));
+        assert!(wildcard_match("block-12?.json*", "block-123.json.gz"));
+        assert!(!wildcard_match("block-*.json", "block-123.json.gz"));
+        assert_eq!(slot_from_file_name(Path::new("dir/block-312345678.json.gz")), Some(312345678));
+        assert_eq!(slot_from_file_name(Path::new("block.json")), None);
+    }
+}
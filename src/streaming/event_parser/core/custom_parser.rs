@@ -0,0 +1,403 @@
+//! 第三方协议解析器注册
+//!
+//! 无需修改 `Protocol` / `DexEvent` 等枚举即可接入新的程序：实现 [`ProtocolParser`]，
+//! 通过 `EventDispatcher::register_protocol_parser` 注册后，将返回的 `Protocol::Custom`
+//! 加入订阅的协议列表，解析出的事件以 `DexEvent::CustomEvent` 返回。
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock};
+
+use dashmap::DashMap;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::common::{
+    decode_program_data, extract_discriminator, EventMetadata, EventType, ProtocolType,
+};
+use crate::streaming::event_parser::core::program_logs::parse_program_data_logs;
+use crate::streaming::event_parser::idl::{parser as idl_parser, IdlProgram, EVENT_IX_TAG};
+use crate::streaming::event_parser::DexEvent;
+use crate::streaming::grpc::AccountPretty;
+
+/// 第三方协议产生的事件
+///
+/// 事件数据以 JSON 形式保存，可通过 [`CustomEvent::data_as`] 还原为解析器中的类型
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CustomEvent {
+    pub metadata: EventMetadata,
+    /// 产生事件的解析器名称（`ProtocolParser::name`）
+    pub protocol: String,
+    /// 事件名称，如 `Swap`
+    pub name: String,
+    pub data: Value,
+}
+
+impl CustomEvent {
+    pub fn new(metadata: EventMetadata, name: impl Into<String>, data: &impl Serialize) -> Self {
+        Self {
+            metadata,
+            protocol: String::new(),
+            name: name.into(),
+            data: serde_json::to_value(data).unwrap_or_default(),
+        }
+    }
+
+    /// 将事件数据反序列化为指定类型
+    pub fn data_as<T: DeserializeOwned>(&self) -> Option<T> {
+        T::deserialize(&self.data).ok()
+    }
+}
+
+/// 第三方协议解析器
+///
+/// 各方法的参数与内置协议的解析函数一致：
+/// - 指令：8 字节 discriminator + 剩余数据
+/// - CPI log（inner instruction）：16 字节 discriminator + 剩余数据
+/// - 指令日志：归属到该指令的原始日志行
+/// - 账户：8 字节 discriminator + 账户信息
+///
+/// 返回事件的 `metadata.protocol` / `metadata.event_type` 会被统一设置为 `Custom`
+pub trait ProtocolParser: Send + Sync {
+    /// 解析器名称，同时作为 `Protocol::Custom` 的名称
+    fn name(&self) -> &str;
+
+    /// 需要处理的程序 ID
+    fn program_ids(&self) -> Vec<Pubkey>;
+
+    /// 解析指令
+    fn parse_instruction(
+        &self,
+        discriminator: &[u8],
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<CustomEvent>;
+
+    /// 解析 CPI log 事件
+    fn parse_cpi_log(
+        &self,
+        _discriminator: &[u8],
+        _data: &[u8],
+        _metadata: EventMetadata,
+    ) -> Option<CustomEvent> {
+        None
+    }
+
+    /// 解析指令自身输出的日志（`Program log:` / `Program data:`）
+    ///
+    /// `logs` 为按 invoke 深度归属到该指令的原始日志行。默认将 Anchor `emit!` 的
+    /// `Program data:` 事件拼上 [`EVENT_IX_TAG`] 后交给 [`Self::parse_cpi_log`]，
+    /// 解析结果与 CPI log 事件一样按日志顺序逐个通过 [`Self::merge`] 合并到指令事件
+    fn parse_log(&self, logs: &[&str], metadata: EventMetadata) -> Vec<CustomEvent> {
+        logs.iter()
+            .filter_map(|log| decode_program_data(log))
+            .filter_map(|data| {
+                let (discriminator, data) = extract_discriminator(8, &data)?;
+                let discriminator = [EVENT_IX_TAG.as_slice(), discriminator].concat();
+                self.parse_cpi_log(&discriminator, data, metadata.clone())
+            })
+            .collect()
+    }
+
+    /// 将 CPI log 事件合并到指令事件
+    ///
+    /// 默认将 CPI log 数据中的字段覆盖到指令事件数据中
+    fn merge(&self, instruction_event: &mut CustomEvent, cpi_log_event: CustomEvent) {
+        match (&mut instruction_event.data, cpi_log_event.data) {
+            (Value::Object(target), Value::Object(source)) => target.extend(source),
+            (target, source) => *target = source,
+        }
+    }
+
+    /// 解析账户数据
+    fn parse_account(
+        &self,
+        _discriminator: &[u8],
+        _account: &AccountPretty,
+        _metadata: EventMetadata,
+    ) -> Option<CustomEvent> {
+        None
+    }
+}
+
+/// 已注册的解析器：自定义解析器或 IDL 程序
+#[derive(Clone)]
+pub(crate) enum RegisteredParser {
+    Custom(Arc<dyn ProtocolParser>),
+    Idl(Arc<IdlProgram>),
+}
+
+impl RegisteredParser {
+    pub(crate) fn name(&self) -> &str {
+        match self {
+            RegisteredParser::Custom(parser) => parser.name(),
+            RegisteredParser::Idl(program) => program.name(),
+        }
+    }
+
+    pub(crate) fn program_ids(&self) -> Vec<Pubkey> {
+        match self {
+            RegisteredParser::Custom(parser) => parser.program_ids(),
+            RegisteredParser::Idl(program) => vec![program.program_id()],
+        }
+    }
+
+    pub(crate) fn parse_instruction(
+        &self,
+        discriminator: &[u8],
+        data: &[u8],
+        accounts: &[Pubkey],
+        metadata: EventMetadata,
+    ) -> Option<DexEvent> {
+        match self {
+            RegisteredParser::Custom(parser) => {
+                let event = parser.parse_instruction(discriminator, data, accounts, metadata)?;
+                Some(custom_event(parser.name(), event))
+            }
+            RegisteredParser::Idl(program) => idl_parser::parse_idl_instruction_data(
+                program,
+                discriminator,
+                data,
+                accounts,
+                metadata,
+            ),
+        }
+    }
+
+    pub(crate) fn parse_cpi_log(
+        &self,
+        discriminator: &[u8],
+        data: &[u8],
+        metadata: EventMetadata,
+    ) -> Option<DexEvent> {
+        match self {
+            RegisteredParser::Custom(parser) => {
+                let event = parser.parse_cpi_log(discriminator, data, metadata)?;
+                Some(custom_event(parser.name(), event))
+            }
+            RegisteredParser::Idl(program) => {
+                idl_parser::parse_idl_inner_instruction_data(program, discriminator, data, metadata)
+            }
+        }
+    }
+
+    pub(crate) fn parse_log(&self, logs: &[&str], metadata: EventMetadata) -> Vec<DexEvent> {
+        match self {
+            RegisteredParser::Custom(parser) => parser
+                .parse_log(logs, metadata)
+                .into_iter()
+                .map(|event| custom_event(parser.name(), event))
+                .collect(),
+            RegisteredParser::Idl(program) => {
+                parse_program_data_logs(logs, &metadata, |data, metadata| {
+                    idl_parser::parse_idl_log_data(program, data, metadata)
+                })
+            }
+        }
+    }
+
+    pub(crate) fn parse_account(
+        &self,
+        discriminator: &[u8],
+        account: &AccountPretty,
+        metadata: EventMetadata,
+    ) -> Option<DexEvent> {
+        match self {
+            RegisteredParser::Custom(parser) => {
+                let event = parser.parse_account(discriminator, account, metadata)?;
+                Some(custom_event(parser.name(), event))
+            }
+            RegisteredParser::Idl(program) => {
+                idl_parser::parse_idl_account_data(program, account, metadata)
+            }
+        }
+    }
+
+    fn ptr_eq(&self, other: &RegisteredParser) -> bool {
+        match (self, other) {
+            (RegisteredParser::Custom(a), RegisteredParser::Custom(b)) => Arc::ptr_eq(a, b),
+            (RegisteredParser::Idl(a), RegisteredParser::Idl(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// 将第三方解析器返回的事件包装为 `DexEvent`
+fn custom_event(name: &str, mut event: CustomEvent) -> DexEvent {
+    event.protocol = name.to_string();
+    event.metadata.protocol = ProtocolType::Custom;
+    event.metadata.event_type = EventType::Custom;
+    DexEvent::CustomEvent(event)
+}
+
+/// 已注册的解析器：名称 -> 解析器，程序 ID -> 解析器
+static PARSERS_BY_NAME: LazyLock<DashMap<String, RegisteredParser>> = LazyLock::new(DashMap::new);
+static PARSERS_BY_PROGRAM: LazyLock<DashMap<Pubkey, RegisteredParser>> =
+    LazyLock::new(DashMap::new);
+/// 注册数量，未注册任何解析器时跳过查表
+static PARSER_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// 注册解析器，同名解析器会被替换
+pub(crate) fn register(parser: RegisteredParser) {
+    unregister(parser.name());
+    for program_id in parser.program_ids() {
+        PARSERS_BY_PROGRAM.insert(program_id, parser.clone());
+    }
+    PARSERS_BY_NAME.insert(parser.name().to_string(), parser);
+    PARSER_COUNT.fetch_add(1, Ordering::Release);
+}
+
+/// 注销解析器，返回是否存在
+pub(crate) fn unregister(name: &str) -> bool {
+    let Some((_, parser)) = PARSERS_BY_NAME.remove(name) else {
+        return false;
+    };
+    PARSERS_BY_PROGRAM.retain(|_, registered| !registered.ptr_eq(&parser));
+    PARSER_COUNT.fetch_sub(1, Ordering::Release);
+    true
+}
+
+#[inline]
+pub(crate) fn find_by_program_id(program_id: &Pubkey) -> Option<RegisteredParser> {
+    if PARSER_COUNT.load(Ordering::Acquire) == 0 {
+        return None;
+    }
+    PARSERS_BY_PROGRAM.get(program_id).map(|parser| parser.clone())
+}
+
+#[inline]
+pub(crate) fn find_by_name(name: &str) -> Option<RegisteredParser> {
+    if PARSER_COUNT.load(Ordering::Acquire) == 0 {
+        return None;
+    }
+    PARSERS_BY_NAME.get(name).map(|parser| parser.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        common::{EventType, ProtocolType},
+        core::{
+            dispatcher::EventDispatcher,
+            transaction_input::fixtures::{
+                account_index, inner_instruction, inner_instructions_meta, parse,
+                program_data_meta, transaction,
+            },
+        },
+        DexEvent,
+    };
+
+    const SWAP_IX: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// EVENT_IX_TAG + 事件 discriminator
+    const SWAP_LOG: [u8; 16] = [228, 69, 165, 46, 81, 203, 154, 29, 9, 9, 9, 9, 9, 9, 9, 9];
+    const FEE_LOG: [u8; 16] = [228, 69, 165, 46, 81, 203, 154, 29, 8, 8, 8, 8, 8, 8, 8, 8];
+
+    #[derive(Serialize, Deserialize)]
+    struct Swap {
+        pool: Pubkey,
+        amount_in: u64,
+        #[serde(default)]
+        amount_out: u64,
+    }
+
+    struct TestParser(&'static str, Pubkey);
+
+    impl ProtocolParser for TestParser {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn program_ids(&self) -> Vec<Pubkey> {
+            vec![self.1]
+        }
+
+        fn parse_instruction(
+            &self,
+            discriminator: &[u8],
+            data: &[u8],
+            accounts: &[Pubkey],
+            metadata: EventMetadata,
+        ) -> Option<CustomEvent> {
+            if discriminator != SWAP_IX {
+                return None;
+            }
+            let amount_in = u64::from_le_bytes(data.get(..8)?.try_into().ok()?);
+            let swap = Swap { pool: *accounts.first()?, amount_in, amount_out: 0 };
+            Some(CustomEvent::new(metadata, "Swap", &swap))
+        }
+
+        fn parse_cpi_log(
+            &self,
+            discriminator: &[u8],
+            data: &[u8],
+            metadata: EventMetadata,
+        ) -> Option<CustomEvent> {
+            let value = u64::from_le_bytes(data.get(..8)?.try_into().ok()?);
+            let data = match discriminator {
+                d if d == SWAP_LOG => serde_json::json!({ "amount_out": value }),
+                d if d == FEE_LOG => serde_json::json!({ "fee": value }),
+                _ => return None,
+            };
+            Some(CustomEvent::new(metadata, "Swap", &data))
+        }
+    }
+
+    #[test]
+    fn test_registered_parser_emits_merged_custom_event() {
+        let program_id = Pubkey::new_unique();
+        let pool = Pubkey::new_unique();
+        let parser = Arc::new(TestParser("test-dex", program_id));
+        let protocol = EventDispatcher::register_protocol_parser(parser);
+
+        let mut data = SWAP_IX.to_vec();
+        data.extend_from_slice(&100u64.to_le_bytes());
+        let tx = transaction(program_id, &data, &[pool]);
+
+        let mut log = SWAP_LOG.to_vec();
+        log.extend_from_slice(&90u64.to_le_bytes());
+        let program_index = account_index(&tx, &program_id);
+        let meta = inner_instructions_meta(vec![inner_instruction(program_index, log, vec![])]);
+
+        let events = parse(&[protocol], &tx, &meta);
+        EventDispatcher::unregister_protocol_parser("test-dex");
+
+        assert_eq!(events.len(), 1);
+        let DexEvent::CustomEvent(event) = &events[0] else { panic!("expected custom event") };
+        assert_eq!(event.protocol, "test-dex");
+        assert_eq!(event.name, "Swap");
+        assert_eq!(event.metadata.protocol, ProtocolType::Custom);
+        assert_eq!(event.metadata.event_type, EventType::Custom);
+        let swap: Swap = event.data_as().unwrap();
+        assert_eq!((swap.pool, swap.amount_in, swap.amount_out), (pool, 100, 90));
+    }
+
+    #[test]
+    fn test_default_parse_log_merges_every_anchor_emit() {
+        let program_id = Pubkey::new_unique();
+        let pool = Pubkey::new_unique();
+        let parser = Arc::new(TestParser("test-emit", program_id));
+        let protocol = EventDispatcher::register_protocol_parser(parser);
+
+        let mut data = SWAP_IX.to_vec();
+        data.extend_from_slice(&100u64.to_le_bytes());
+        let tx = transaction(program_id, &data, &[pool]);
+
+        let emitted = |log: &[u8], value: u64| {
+            let mut data = log[8..].to_vec();
+            data.extend_from_slice(&value.to_le_bytes());
+            data
+        };
+        let meta = program_data_meta(program_id, &[emitted(&SWAP_LOG, 70), emitted(&FEE_LOG, 3)]);
+
+        let events = parse(&[protocol], &tx, &meta);
+        EventDispatcher::unregister_protocol_parser("test-emit");
+
+        assert_eq!(events.len(), 1);
+        let DexEvent::CustomEvent(event) = &events[0] else { panic!("expected custom event") };
+        assert_eq!(event.data["amount_in"], 100);
+        assert_eq!(event.data["amount_out"], 70);
+        assert_eq!(event.data["fee"], 3);
+    }
+}
@@ -8,16 +8,26 @@
 //! - **可测试性**: 每个函数都可以独立测试
 
 use crate::streaming::event_parser::{
-    common::EventMetadata,
+    common::{EventMetadata, ProtocolType},
     core::common_event_parser::{CommonEventParser, COMPUTE_BUDGET_PROGRAM_ID},
+    core::custom_parser::{self, ProtocolParser, RegisteredParser},
+    core::program_logs::parse_program_data_logs,
+    idl::IdlProgram,
     protocols::{
-        bonk::parser as bonk, meteora_damm_v2::parser as meteora_damm_v2, pumpfun::parser as pumpfun,
+        bonk::parser as bonk, jupiter::parser as jupiter,
+        meteora_damm_v1::parser as meteora_damm_v1,
+        meteora_damm_v2::parser as meteora_damm_v2,
+        meteora_dlmm::parser as meteora_dlmm, orca_whirlpool::parser as orca_whirlpool,
+        pumpfun::parser as pumpfun,
         pumpswap::parser as pumpswap, raydium_amm_v4::parser as raydium_amm_v4,
         raydium_clmm::parser as raydium_clmm, raydium_cpmm::parser as raydium_cpmm,
+        spl_token::parser as spl_token, system::parser as system,
     },
     DexEvent, Protocol,
 };
+use log::warn;
 use solana_sdk::pubkey::Pubkey;
+use std::sync::Arc;
 
 /// 中心事件解析调度器
 ///
@@ -44,17 +54,7 @@ impl EventDispatcher {
         accounts: &[Pubkey],
         mut metadata: EventMetadata,
     ) -> Option<DexEvent> {
-        // 根据协议类型设置 metadata.protocol
-        use crate::streaming::event_parser::common::ProtocolType;
-        metadata.protocol = match protocol {
-            Protocol::PumpFun => ProtocolType::PumpFun,
-            Protocol::PumpSwap => ProtocolType::PumpSwap,
-            Protocol::Bonk => ProtocolType::Bonk,
-            Protocol::RaydiumCpmm => ProtocolType::RaydiumCpmm,
-            Protocol::RaydiumClmm => ProtocolType::RaydiumClmm,
-            Protocol::RaydiumAmmV4 => ProtocolType::RaydiumAmmV4,
-            Protocol::MeteoraDammV2 => ProtocolType::MeteoraDammV2,
-        };
+        metadata.protocol = ProtocolType::from(&protocol);
 
         match protocol {
             Protocol::PumpFun => pumpfun::parse_pumpfun_instruction_data(
@@ -99,6 +99,57 @@ impl EventDispatcher {
                 accounts,
                 metadata,
             ),
+            Protocol::MeteoraDammV1 => meteora_damm_v1::parse_meteora_damm_v1_instruction_data(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
+            Protocol::MeteoraDlmm => meteora_dlmm::parse_meteora_dlmm_instruction_data(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
+            Protocol::OrcaWhirlpool => orca_whirlpool::parse_orca_whirlpool_instruction_data(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
+            Protocol::SplToken => spl_token::parse_spl_token_instruction_data(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
+            // System 与 Address Lookup Table 共用 System，按 program_id 区分
+            Protocol::System if metadata.program_id == system::ADDRESS_LOOKUP_TABLE_PROGRAM_ID => {
+                system::parse_address_lookup_table_instruction_data(
+                    instruction_discriminator,
+                    instruction_data,
+                    accounts,
+                    metadata,
+                )
+            }
+            Protocol::System => system::parse_system_instruction_data(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
+            Protocol::Jupiter => jupiter::parse_jupiter_instruction_data(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
+            Protocol::Custom(name) => custom_parser::find_by_name(&name)?.parse_instruction(
+                instruction_discriminator,
+                instruction_data,
+                accounts,
+                metadata,
+            ),
         }
     }
 
@@ -119,17 +170,7 @@ impl EventDispatcher {
         inner_instruction_data: &[u8],
         mut metadata: EventMetadata,
     ) -> Option<DexEvent> {
-        // 根据协议类型设置 metadata.protocol
-        use crate::streaming::event_parser::common::ProtocolType;
-        metadata.protocol = match protocol {
-            Protocol::PumpFun => ProtocolType::PumpFun,
-            Protocol::PumpSwap => ProtocolType::PumpSwap,
-            Protocol::Bonk => ProtocolType::Bonk,
-            Protocol::RaydiumCpmm => ProtocolType::RaydiumCpmm,
-            Protocol::RaydiumClmm => ProtocolType::RaydiumClmm,
-            Protocol::RaydiumAmmV4 => ProtocolType::RaydiumAmmV4,
-            Protocol::MeteoraDammV2 => ProtocolType::MeteoraDammV2,
-        };
+        metadata.protocol = ProtocolType::from(&protocol);
 
         match protocol {
             Protocol::PumpFun => pumpfun::parse_pumpfun_inner_instruction_data(
@@ -167,6 +208,95 @@ impl EventDispatcher {
                 inner_instruction_data,
                 metadata,
             ),
+            Protocol::MeteoraDammV1 => {
+                meteora_damm_v1::parse_meteora_damm_v1_inner_instruction_data(
+                    inner_instruction_discriminator,
+                    inner_instruction_data,
+                    metadata,
+                )
+            }
+            Protocol::MeteoraDlmm => meteora_dlmm::parse_meteora_dlmm_inner_instruction_data(
+                inner_instruction_discriminator,
+                inner_instruction_data,
+                metadata,
+            ),
+            Protocol::OrcaWhirlpool => orca_whirlpool::parse_orca_whirlpool_inner_instruction_data(
+                inner_instruction_discriminator,
+                inner_instruction_data,
+                metadata,
+            ),
+            // 仅解析指令，没有 CPI 事件
+            Protocol::SplToken | Protocol::System => None,
+            Protocol::Jupiter => jupiter::parse_jupiter_inner_instruction_data(
+                inner_instruction_discriminator,
+                inner_instruction_data,
+                metadata,
+            ),
+            Protocol::Custom(name) => custom_parser::find_by_name(&name)?.parse_cpi_log(
+                inner_instruction_discriminator,
+                inner_instruction_data,
+                metadata,
+            ),
+        }
+    }
+
+    /// 解析指令自身输出的日志事件（只解析，不合并）
+    ///
+    /// 覆盖不发出 CPI 自调用事件的程序：Raydium AMM V4 的 `ray_log`，以及通过 Anchor
+    /// `emit!` 输出 `Program data:` 的程序
+    ///
+    /// # 参数
+    /// - `protocol`: 协议类型
+    /// - `logs`: 按 invoke 深度归属到该指令的日志行
+    /// - `metadata`: 事件元数据
+    ///
+    /// # 返回
+    /// 按日志顺序返回全部解析成功的事件
+    pub fn dispatch_instruction_logs(
+        protocol: Protocol,
+        logs: &[&str],
+        mut metadata: EventMetadata,
+    ) -> Vec<DexEvent> {
+        match protocol {
+            Protocol::RaydiumAmmV4 => {
+                metadata.protocol = ProtocolType::RaydiumAmmV4;
+                raydium_amm_v4::parse_raydium_amm_v4_instruction_logs(logs, metadata)
+                    .into_iter()
+                    .collect()
+            }
+            // Anchor emit! 事件，与 emit_cpi! 共用同一套事件解析
+            Protocol::Bonk => {
+                metadata.protocol = ProtocolType::Bonk;
+                parse_program_data_logs(logs, &metadata, bonk::parse_bonk_log_data)
+            }
+            Protocol::RaydiumCpmm => {
+                metadata.protocol = ProtocolType::RaydiumCpmm;
+                parse_program_data_logs(logs, &metadata, raydium_cpmm::parse_raydium_cpmm_log_data)
+            }
+            Protocol::RaydiumClmm => {
+                metadata.protocol = ProtocolType::RaydiumClmm;
+                parse_program_data_logs(logs, &metadata, raydium_clmm::parse_raydium_clmm_log_data)
+            }
+            Protocol::MeteoraDammV1 => {
+                metadata.protocol = ProtocolType::MeteoraDammV1;
+                parse_program_data_logs(
+                    logs,
+                    &metadata,
+                    meteora_damm_v1::parse_meteora_damm_v1_log_data,
+                )
+            }
+            Protocol::OrcaWhirlpool => {
+                metadata.protocol = ProtocolType::OrcaWhirlpool;
+                parse_program_data_logs(
+                    logs,
+                    &metadata,
+                    orca_whirlpool::parse_orca_whirlpool_log_data,
+                )
+            }
+            Protocol::Custom(name) => custom_parser::find_by_name(&name)
+                .map(|parser| parser.parse_log(logs, metadata))
+                .unwrap_or_default(),
+            _ => Vec::new(),
         }
     }
 
@@ -187,8 +317,25 @@ impl EventDispatcher {
             Some(Protocol::RaydiumAmmV4)
         } else if program_id == &meteora_damm_v2::METEORA_DAMM_V2_PROGRAM_ID {
             Some(Protocol::MeteoraDammV2)
+        } else if program_id == &meteora_damm_v1::METEORA_DAMM_V1_PROGRAM_ID {
+            Some(Protocol::MeteoraDammV1)
+        } else if program_id == &meteora_dlmm::METEORA_DLMM_PROGRAM_ID {
+            Some(Protocol::MeteoraDlmm)
+        } else if program_id == &orca_whirlpool::ORCA_WHIRLPOOL_PROGRAM_ID {
+            Some(Protocol::OrcaWhirlpool)
+        } else if program_id == &spl_token::SPL_TOKEN_PROGRAM_ID
+            || program_id == &spl_token::SPL_TOKEN_2022_PROGRAM_ID
+        {
+            Some(Protocol::SplToken)
+        } else if program_id == &system::SYSTEM_PROGRAM_ID
+            || program_id == &system::ADDRESS_LOOKUP_TABLE_PROGRAM_ID
+        {
+            Some(Protocol::System)
+        } else if program_id == &jupiter::JUPITER_PROGRAM_ID {
+            Some(Protocol::Jupiter)
         } else {
-            None
+            custom_parser::find_by_program_id(program_id)
+                .map(|parser| Protocol::Custom(parser.name().to_string()))
         }
     }
 
@@ -225,12 +372,65 @@ impl EventDispatcher {
             Protocol::RaydiumClmm => raydium_clmm::RAYDIUM_CLMM_PROGRAM_ID,
             Protocol::RaydiumAmmV4 => raydium_amm_v4::RAYDIUM_AMM_V4_PROGRAM_ID,
             Protocol::MeteoraDammV2 => meteora_damm_v2::METEORA_DAMM_V2_PROGRAM_ID,
+            Protocol::MeteoraDammV1 => meteora_damm_v1::METEORA_DAMM_V1_PROGRAM_ID,
+            Protocol::MeteoraDlmm => meteora_dlmm::METEORA_DLMM_PROGRAM_ID,
+            Protocol::OrcaWhirlpool => orca_whirlpool::ORCA_WHIRLPOOL_PROGRAM_ID,
+            // Token 与 Token-2022 共用 SplToken，这里返回 Token Program
+            Protocol::SplToken => spl_token::SPL_TOKEN_PROGRAM_ID,
+            // System 与 Address Lookup Table 共用 System，这里返回 System Program
+            Protocol::System => system::SYSTEM_PROGRAM_ID,
+            Protocol::Jupiter => jupiter::JUPITER_PROGRAM_ID,
+            // 第三方协议可能有多个程序 ID，这里返回第一个
+            Protocol::Custom(_) => protocol.get_program_id().first().copied().unwrap_or_default(),
         }
     }
 
     /// 批量获取 program_ids
     pub fn get_program_ids(protocols: &[Protocol]) -> Vec<Pubkey> {
-        protocols.iter().map(|p| Self::get_program_id(p.clone())).collect()
+        protocols.iter().flat_map(|p| p.get_program_id()).collect()
+    }
+
+    /// 注册第三方协议解析器
+    ///
+    /// 返回对应的 `Protocol::Custom`，加入订阅的协议列表后即可解析该协议的事件。
+    /// 同名解析器会被替换；应在开始订阅前注册。
+    /// 程序 ID 与内置协议相同时仍由内置协议解析，注册时会输出警告。
+    pub fn register_protocol_parser(parser: Arc<dyn ProtocolParser>) -> Protocol {
+        let protocol = Protocol::Custom(parser.name().to_string());
+        Self::warn_builtin_program_ids(parser.name(), &parser.program_ids());
+        custom_parser::register(RegisteredParser::Custom(parser));
+        protocol
+    }
+
+    /// 注册 Anchor IDL 程序
+    ///
+    /// 返回对应的 `Protocol::Custom`，该程序的指令、`emit_cpi!` 事件与账户以
+    /// `DexEvent::IdlEvent` 返回。与第三方解析器共用名称空间，同名会被替换；
+    /// 程序 ID 与内置协议相同时仍由内置协议解析，注册时会输出警告。
+    pub fn register_idl(program: IdlProgram) -> Protocol {
+        let protocol = Protocol::Custom(program.name().to_string());
+        Self::warn_builtin_program_ids(program.name(), &[program.program_id()]);
+        custom_parser::register(RegisteredParser::Idl(Arc::new(program)));
+        protocol
+    }
+
+    /// 内置协议的程序 ID 优先匹配，注册到这些程序 ID 的解析器不会被调用
+    fn warn_builtin_program_ids(name: &str, program_ids: &[Pubkey]) {
+        for program_id in program_ids {
+            if let Some(builtin) = Self::match_protocol_by_program_id(program_id)
+                .filter(|protocol| !matches!(protocol, Protocol::Custom(_)))
+            {
+                warn!(
+                    "Parser {name} registers {program_id}, which is parsed by built-in \
+                     {builtin:?}; it will not be called for this program"
+                );
+            }
+        }
+    }
+
+    /// 注销第三方协议解析器或 IDL 程序，返回是否存在
+    pub fn unregister_protocol_parser(name: &str) -> bool {
+        custom_parser::unregister(name)
     }
 
     /// 解析账户数据
@@ -251,17 +451,7 @@ impl EventDispatcher {
         account: &crate::streaming::grpc::AccountPretty,
         mut metadata: crate::streaming::event_parser::common::EventMetadata,
     ) -> Option<DexEvent> {
-        // 根据协议类型设置 metadata.protocol
-        use crate::streaming::event_parser::common::ProtocolType;
-        metadata.protocol = match protocol {
-            Protocol::PumpFun => ProtocolType::PumpFun,
-            Protocol::PumpSwap => ProtocolType::PumpSwap,
-            Protocol::Bonk => ProtocolType::Bonk,
-            Protocol::RaydiumCpmm => ProtocolType::RaydiumCpmm,
-            Protocol::RaydiumClmm => ProtocolType::RaydiumClmm,
-            Protocol::RaydiumAmmV4 => ProtocolType::RaydiumAmmV4,
-            Protocol::MeteoraDammV2 => ProtocolType::MeteoraDammV2,
-        };
+        metadata.protocol = ProtocolType::from(&protocol);
 
         match protocol {
             Protocol::PumpFun => {
@@ -281,8 +471,21 @@ impl EventDispatcher {
                 raydium_amm_v4::parse_raydium_amm_v4_account_data(discriminator, account, metadata)
             }
             Protocol::MeteoraDammV2 => {
-                // Meteora DAMM 目前不需要解析账户数据，返回 None
-                None
+                meteora_damm_v2::parse_meteora_damm_v2_account_data(discriminator, account, metadata)
+            }
+            Protocol::MeteoraDammV1 => {
+                meteora_damm_v1::parse_meteora_damm_v1_account_data(discriminator, account, metadata)
+            }
+            Protocol::MeteoraDlmm => {
+                meteora_dlmm::parse_meteora_dlmm_account_data(discriminator, account, metadata)
+            }
+            Protocol::OrcaWhirlpool => {
+                orca_whirlpool::parse_orca_whirlpool_account_data(discriminator, account, metadata)
+            }
+            // token 账户与 nonce 账户由 AccountEventParser 的通用解析处理
+            Protocol::SplToken | Protocol::System | Protocol::Jupiter => None,
+            Protocol::Custom(name) => {
+                custom_parser::find_by_name(&name)?.parse_account(discriminator, account, metadata)
             }
         }
     }
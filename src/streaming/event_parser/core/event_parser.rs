@@ -1,21 +1,35 @@
 use crate::streaming::event_parser::{
     DexEvent, Protocol, common::{
-        EventMetadata, filter::EventTypeFilter, high_performance_clock::elapsed_micros_since, parse_swap_data_from_next_grpc_instructions, parse_swap_data_from_next_instructions
+        EventMetadata, RouteRef, filter::EventTypeFilter, high_performance_clock::{elapsed_micros_since, get_high_perf_clock}, parse_swap_data_from_next_grpc_instructions, parse_swap_data_from_next_instructions
     }, core::{
         dispatcher::EventDispatcher,
         global_state::{
             add_bonk_dev_address, add_dev_address, is_bonk_dev_address_in_signature,
             is_dev_address_in_signature,
         },
+        confirmed_block::{block_file_slot, list_block_files, load_block_file, ConfirmedBlockFile},
         merger_event::merge,
-    }, protocols::raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID
+        program_logs::ProgramLogs,
+        rpc_transaction::RpcTransactionParts,
+        token_accounts::TokenAccounts,
+        transaction_input::{ParseContext, ParseError, TransactionInput},
+    }, protocols::{
+        raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID,
+        spl_token::parser::{
+            fill_spl_token_accounts, SPL_TOKEN_2022_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID,
+        },
+        system::parser::{ADDRESS_LOOKUP_TABLE_PROGRAM_ID, SYSTEM_PROGRAM_ID},
+    }
 };
 use prost_types::Timestamp;
 use solana_sdk::{
     message::compiled_instruction::CompiledInstruction, pubkey::Pubkey, signature::Signature,
     transaction::VersionedTransaction,
 };
-use solana_transaction_status::InnerInstructions;
+use solana_transaction_status::{
+    EncodedConfirmedBlock, EncodedConfirmedTransactionWithStatusMeta, InnerInstructions,
+};
+use std::path::Path;
 use std::sync::Arc;
 use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
 
@@ -30,6 +44,7 @@ impl EventParser {
     ///
     /// This is the main entry point for parsing transactions received from gRPC streams.
     /// It extracts account keys, inner instructions, and delegates to instruction parsing.
+    #[allow(clippy::too_many_arguments)]
     pub async fn parse_grpc_transaction(
         protocols: &[Protocol],
         event_type_filter: Option<&EventTypeFilter>,
@@ -42,77 +57,202 @@ impl EventParser {
         tx_index: Option<u64>,
         callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
     ) -> anyhow::Result<()> {
-        // 创建适配器回调，将所有权回调转换为引用回调
-        let adapter_callback = Arc::new(move |event: &DexEvent| {
-            callback(event.clone());
-        });
-        if let Some(transition) = grpc_tx.transaction {
-            if let Some(message) = &transition.message {
-                let mut address_table_lookups: Vec<Vec<u8>> = vec![];
-                let mut inner_instructions: Vec<
-                    yellowstone_grpc_proto::solana::storage::confirmed_block::InnerInstructions,
-                > = vec![];
-
-                if let Some(meta) = grpc_tx.meta {
-                    inner_instructions = meta.inner_instructions;
-                    address_table_lookups.reserve(
-                        meta.loaded_writable_addresses.len() + meta.loaded_readonly_addresses.len(),
-                    );
-                    let loaded_writable_addresses = meta.loaded_writable_addresses;
-                    let loaded_readonly_addresses = meta.loaded_readonly_addresses;
-                    address_table_lookups.extend(
-                        loaded_writable_addresses.into_iter().chain(loaded_readonly_addresses),
-                    );
-                }
+        Self::parse_grpc_transaction_with(
+            protocols,
+            event_type_filter,
+            &grpc_tx,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            bot_wallet,
+            tx_index,
+            &mut |event| callback(event),
+        );
+        Ok(())
+    }
 
-                let mut accounts_bytes: Vec<Vec<u8>> =
-                    Vec::with_capacity(message.account_keys.len() + address_table_lookups.len());
-                accounts_bytes.extend_from_slice(&message.account_keys);
-                accounts_bytes.extend(address_table_lookups);
-                // 转换为 Pubkey
-                let accounts: Vec<Pubkey> = accounts_bytes
-                    .iter()
-                    .filter_map(|account| {
-                        if account.len() == 32 {
-                            Some(Pubkey::try_from(account.as_slice()).unwrap_or_default())
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-                // 解析指令事件
-                let instructions = &message.instructions;
-                let recent_blockhash = if message.recent_blockhash.len() != 32 {
-                    None
-                } else {
-                    Some(solana_sdk::bs58::encode(&message.recent_blockhash).into_string())
-                };
-                Self::parse_instruction_events_from_grpc_transaction(
+    /// Parse a transaction synchronously and return its events
+    ///
+    /// Accepts a `VersionedTransaction` (optionally with its `TransactionStatusMeta`) or a
+    /// Yellowstone `SubscribeUpdateTransactionInfo`. Does not require an async runtime, so it can
+    /// be called from offline tools, tests and rayon worker pools.
+    pub fn parse_transaction<'a>(
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+        transaction: impl Into<TransactionInput<'a>>,
+        context: &ParseContext,
+    ) -> Result<Vec<DexEvent>, ParseError> {
+        let recv_us = context.recv_us.unwrap_or_else(get_high_perf_clock);
+        let mut events = Vec::new();
+        match transaction.into() {
+            TransactionInput::Versioned { transaction, meta } => {
+                let signature =
+                    *transaction.signatures.first().ok_or(ParseError::MissingSignature)?;
+                let mut accounts = transaction.message.static_account_keys().to_vec();
+                if let Some(meta) = meta {
+                    accounts.extend(&meta.loaded_addresses.writable);
+                    accounts.extend(&meta.loaded_addresses.readonly);
+                }
+                let inner_instructions =
+                    meta.and_then(|m| m.inner_instructions.as_deref()).unwrap_or_default();
+                let log_messages = meta.and_then(|m| m.log_messages.as_deref()).unwrap_or_default();
+                let token_accounts = meta
+                    .filter(|_| protocols.contains(&Protocol::SplToken))
+                    .map(|m| TokenAccounts::from_meta(&accounts, m))
+                    .unwrap_or_default();
+                Self::parse_versioned_transaction_with(
                     protocols,
                     event_type_filter,
-                    &instructions,
+                    transaction,
                     signature,
-                    slot,
-                    block_time,
+                    context.slot,
+                    context.block_time,
                     recv_us,
                     &accounts,
-                    &inner_instructions,
-                    bot_wallet,
-                    tx_index,
-                    recent_blockhash,
-                    adapter_callback,
-                )
-                .await?;
+                    inner_instructions,
+                    &ProgramLogs::new(
+                        log_messages,
+                        outer_program_ids(
+                            transaction
+                                .message
+                                .instructions()
+                                .iter()
+                                .map(|ix| ix.program_id_index as usize),
+                            &accounts,
+                        ),
+                    ),
+                    &token_accounts,
+                    context.bot_wallet,
+                    context.tx_index,
+                    &mut |event| events.push(event),
+                );
+            }
+            TransactionInput::Grpc(grpc_tx) => {
+                if grpc_tx.transaction.as_ref().and_then(|tx| tx.message.as_ref()).is_none() {
+                    return Err(ParseError::MissingMessage);
+                }
+                let signature = Signature::try_from(grpc_tx.signature.as_slice())
+                    .map_err(|_| ParseError::InvalidSignature)?;
+                Self::parse_grpc_transaction_with(
+                    protocols,
+                    event_type_filter,
+                    grpc_tx,
+                    signature,
+                    context.slot,
+                    context.block_time,
+                    recv_us,
+                    context.bot_wallet,
+                    context.tx_index.or(Some(grpc_tx.index)),
+                    &mut |event| events.push(event),
+                );
             }
         }
+        Ok(events)
+    }
 
-        Ok(())
+    /// Parse a transaction returned by RPC `getTransaction`
+    ///
+    /// Supports json, jsonParsed, base58 and base64 encodings; slot and block time are taken
+    /// from the response. Failed transactions are parsed as well, check the meta if needed.
+    pub fn parse_rpc_transaction(
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+        transaction: &EncodedConfirmedTransactionWithStatusMeta,
+        bot_wallet: Option<Pubkey>,
+    ) -> Result<Vec<DexEvent>, ParseError> {
+        let parts = RpcTransactionParts::from_encoded(transaction)
+            .ok_or(ParseError::UndecodableTransaction)?;
+        let mut events = Vec::new();
+        Self::parse_rpc_transaction_parts_with(
+            protocols,
+            event_type_filter,
+            &parts,
+            bot_wallet,
+            None,
+            &mut |event| events.push(event),
+        );
+        Ok(events)
+    }
+
+    /// Parse all transactions of a confirmed block
+    ///
+    /// Accepts the result of RPC `getBlock` (`EncodedConfirmedBlock` or `UiConfirmedBlock`)
+    /// in base58 / base64 / json encoding. `getBlock` does not return the slot, so it must be
+    /// passed in. Failed transactions are skipped; events are returned in `tx_index` order.
+    /// Returns an error naming the slot and `tx_index` when a transaction cannot be decoded
+    /// (e.g. `accounts` encoding), so re-indexing never silently loses a transaction.
+    pub fn parse_confirmed_block(
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+        block: impl Into<EncodedConfirmedBlock>,
+        slot: u64,
+        bot_wallet: Option<Pubkey>,
+    ) -> anyhow::Result<Vec<DexEvent>> {
+        let block: EncodedConfirmedBlock = block.into();
+        let mut events = Vec::new();
+        for (tx_index, tx) in block.transactions.iter().enumerate() {
+            let parts = RpcTransactionParts::from_transaction_with_meta(tx, slot, block.block_time)
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "{} (slot {slot}, tx_index {tx_index})",
+                        ParseError::UndecodableTransaction
+                    )
+                })?;
+            if parts.is_failed {
+                continue;
+            }
+            Self::parse_rpc_transaction_parts_with(
+                protocols,
+                event_type_filter,
+                &parts,
+                bot_wallet,
+                Some(tx_index as u64),
+                &mut |event| events.push(event),
+            );
+        }
+        Ok(events)
+    }
+
+    /// Parse block JSON files saved from `getBlock` for batch reprocessing
+    ///
+    /// `path` may be a single file, a directory or a file name with `*` / `?` wildcards,
+    /// see [`list_block_files`]. Files are sorted by [`block_file_slot`] and loaded one at a
+    /// time, so only one block is held in memory; `on_block` receives the slot and events of
+    /// each block. Returns the number of blocks processed.
+    pub fn parse_confirmed_block_files<F>(
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+        path: impl AsRef<Path>,
+        bot_wallet: Option<Pubkey>,
+        mut on_block: F,
+    ) -> anyhow::Result<usize>
+    where
+        F: FnMut(u64, Vec<DexEvent>),
+    {
+        // 先按 slot 排序文件，再逐个加载、解析并释放，内存占用不随文件数量增长
+        let mut files = list_block_files(path)?
+            .into_iter()
+            .map(|file| Ok((block_file_slot(&file)?, file)))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        files.sort_by_key(|(slot, _)| *slot);
+
+        let count = files.len();
+        for (_, file) in files {
+            let ConfirmedBlockFile { slot, block, .. } = load_block_file(file)?;
+            let events =
+                Self::parse_confirmed_block(protocols, event_type_filter, block, slot, bot_wallet)?;
+            on_block(slot, events);
+        }
+        Ok(count)
     }
 
     /// Parse transaction from VersionedTransaction
     ///
     /// This is the entry point for parsing VersionedTransaction objects.
     /// It's used when working with RPC responses or historical data.
+    /// No log messages are available here, so log-derived fields (e.g. `ray_log`) stay default;
+    /// use [`Self::parse_transaction`] with the meta to get them.
     #[allow(clippy::too_many_arguments)]
     pub async fn parse_instruction_events_from_versioned_transaction(
         protocols: &[Protocol],
@@ -128,10 +268,160 @@ impl EventParser {
         tx_index: Option<u64>,
         callback: Arc<dyn Fn(DexEvent) + Send + Sync>,
     ) -> anyhow::Result<()> {
-        // 创建适配器回调，将所有权回调转换为引用回调
-        let adapter_callback = Arc::new(move |event: &DexEvent| {
-            callback(event.clone());
-        });
+        Self::parse_versioned_transaction_with(
+            protocols,
+            event_type_filter,
+            transaction,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            accounts,
+            inner_instructions,
+            &ProgramLogs::default(),
+            &TokenAccounts::default(),
+            bot_wallet,
+            tx_index,
+            &mut |event| callback(event),
+        );
+        Ok(())
+    }
+
+    // ================================================================================================
+    // Callback-based Core
+    // ================================================================================================
+
+    /// Parse the decoded parts of an RPC transaction, passing each event to `callback` by value
+    pub(crate) fn parse_rpc_transaction_parts_with(
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+        parts: &RpcTransactionParts,
+        bot_wallet: Option<Pubkey>,
+        tx_index: Option<u64>,
+        callback: &mut dyn FnMut(DexEvent),
+    ) {
+        Self::parse_versioned_transaction_with(
+            protocols,
+            event_type_filter,
+            &parts.transaction,
+            parts.signature,
+            Some(parts.slot),
+            parts.block_time,
+            get_high_perf_clock(),
+            &parts.accounts,
+            &parts.inner_instructions,
+            &ProgramLogs::new(
+                &parts.log_messages,
+                outer_program_ids(
+                    parts
+                        .transaction
+                        .message
+                        .instructions()
+                        .iter()
+                        .map(|ix| ix.program_id_index as usize),
+                    &parts.accounts,
+                ),
+            ),
+            &parts.token_accounts,
+            bot_wallet,
+            tx_index,
+            callback,
+        );
+    }
+
+    /// Parse a gRPC transaction, passing each event to `callback` by value
+    #[allow(clippy::too_many_arguments)]
+    fn parse_grpc_transaction_with(
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+        grpc_tx: &SubscribeUpdateTransactionInfo,
+        signature: Signature,
+        slot: Option<u64>,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        bot_wallet: Option<Pubkey>,
+        tx_index: Option<u64>,
+        callback: &mut dyn FnMut(DexEvent),
+    ) {
+        let Some(message) = grpc_tx.transaction.as_ref().and_then(|tx| tx.message.as_ref())
+        else {
+            return;
+        };
+        let meta = grpc_tx.meta.as_ref();
+        let inner_instructions = meta.map(|m| m.inner_instructions.as_slice()).unwrap_or_default();
+        // 静态账户 + 地址查找表加载的可写账户 + 只读账户，转换为 Pubkey
+        let loaded_addresses = meta
+            .into_iter()
+            .flat_map(|m| m.loaded_writable_addresses.iter().chain(&m.loaded_readonly_addresses));
+        let accounts: Vec<Pubkey> = message
+            .account_keys
+            .iter()
+            .chain(loaded_addresses)
+            .filter_map(|account| {
+                if account.len() == 32 {
+                    Some(Pubkey::try_from(account.as_slice()).unwrap_or_default())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let program_logs = ProgramLogs::new(
+            meta.map(|m| m.log_messages.as_slice()).unwrap_or_default(),
+            outer_program_ids(
+                message.instructions.iter().map(|ix| ix.program_id_index as usize),
+                &accounts,
+            ),
+        );
+        let token_accounts = meta
+            .filter(|_| protocols.contains(&Protocol::SplToken))
+            .map(|m| TokenAccounts::from_grpc_meta(&accounts, m))
+            .unwrap_or_default();
+        let callback = &mut Self::fill_token_accounts(&token_accounts, callback);
+        let callback = &mut Self::link_parent_routes(callback);
+        // 解析指令事件
+        let recent_blockhash = if message.recent_blockhash.len() != 32 {
+            None
+        } else {
+            Some(solana_sdk::bs58::encode(&message.recent_blockhash).into_string())
+        };
+        Self::parse_instruction_events_from_grpc_transaction(
+            protocols,
+            event_type_filter,
+            &message.instructions,
+            signature,
+            slot,
+            block_time,
+            recv_us,
+            &accounts,
+            inner_instructions,
+            &program_logs,
+            bot_wallet,
+            tx_index,
+            recent_blockhash,
+            callback,
+        );
+    }
+
+    /// Parse a VersionedTransaction, passing each event to `callback` by value
+    #[allow(clippy::too_many_arguments)]
+    fn parse_versioned_transaction_with(
+        protocols: &[Protocol],
+        event_type_filter: Option<&EventTypeFilter>,
+        transaction: &VersionedTransaction,
+        signature: Signature,
+        slot: Option<u64>,
+        block_time: Option<Timestamp>,
+        recv_us: i64,
+        accounts: &[Pubkey],
+        inner_instructions: &[InnerInstructions],
+        program_logs: &ProgramLogs,
+        token_accounts: &TokenAccounts,
+        bot_wallet: Option<Pubkey>,
+        tx_index: Option<u64>,
+        callback: &mut dyn FnMut(DexEvent),
+    ) {
+        let callback = &mut Self::fill_token_accounts(token_accounts, callback);
+        let callback = &mut Self::link_parent_routes(callback);
         // 获取交易的指令和账户
         let compiled_instructions = transaction.message.instructions();
         let recent_blockhash = Some(transaction.message.recent_blockhash().to_string());
@@ -169,8 +459,9 @@ impl EventParser {
                             tx_index,
                             recent_blockhash.as_deref(),
                             inner_instructions,
-                            adapter_callback.clone(),
-                        )?;
+                            program_logs,
+                            callback,
+                        );
                     }
                     // Immediately process inner instructions for correct ordering
                     if let Some(inner_instructions) = inner_instructions {
@@ -192,14 +483,14 @@ impl EventParser {
                                 tx_index,
                                 recent_blockhash.as_deref(),
                                 Some(&inner_instructions),
-                                adapter_callback.clone(),
-                            )?;
+                                program_logs,
+                                callback,
+                            );
                         }
                     }
                 }
             }
         }
-        Ok(())
     }
 
     // ================================================================================================
@@ -211,7 +502,7 @@ impl EventParser {
     /// Iterates through all instructions in a gRPC transaction, checks if they should be handled,
     /// and delegates to instruction-level parsing for both outer and inner instructions.
     #[allow(clippy::too_many_arguments)]
-    async fn parse_instruction_events_from_grpc_transaction(
+    fn parse_instruction_events_from_grpc_transaction(
         protocols: &[Protocol],
         event_type_filter: Option<&EventTypeFilter>,
         compiled_instructions: &[yellowstone_grpc_proto::prelude::CompiledInstruction],
@@ -221,11 +512,12 @@ impl EventParser {
         recv_us: i64,
         accounts: &[Pubkey],
         inner_instructions: &[yellowstone_grpc_proto::prelude::InnerInstructions],
+        program_logs: &ProgramLogs,
         bot_wallet: Option<Pubkey>,
         tx_index: Option<u64>,
         recent_blockhash: Option<String>,
-        callback: Arc<dyn for<'a> Fn(&'a DexEvent) + Send + Sync>,
-    ) -> anyhow::Result<()> {
+        callback: &mut dyn FnMut(DexEvent),
+    ) {
         // 获取交易的指令和账户
         let mut accounts = accounts.to_vec();
         // 检查交易中是否包含程序
@@ -261,8 +553,9 @@ impl EventParser {
                             tx_index,
                             recent_blockhash.as_deref(),
                             inner_instructions,
-                            callback.clone(),
-                        )?;
+                            program_logs,
+                            callback,
+                        );
                     }
                     // Immediately process inner instructions for correct ordering
                     if let Some(inner_instructions) = inner_instructions {
@@ -292,14 +585,14 @@ impl EventParser {
                                 tx_index,
                                 recent_blockhash.as_deref(),
                                 Some(&inner_instructions),
-                                callback.clone(),
-                            )?;
+                                program_logs,
+                                callback,
+                            );
                         }
                     }
                 }
             }
         }
-        Ok(())
     }
 
     /// Parse events from gRPC instruction
@@ -322,28 +615,30 @@ impl EventParser {
         tx_index: Option<u64>,
         recent_blockhash: Option<&str>,
         inner_instructions: Option<&yellowstone_grpc_proto::prelude::InnerInstructions>,
-        callback: Arc<dyn for<'a> Fn(&'a DexEvent) + Send + Sync>,
-    ) -> anyhow::Result<()> {
+        program_logs: &ProgramLogs,
+        callback: &mut dyn FnMut(DexEvent),
+    ) {
         // 添加边界检查以防止越界访问
         let program_id_index = instruction.program_id_index as usize;
         if program_id_index >= accounts.len() {
-            return Ok(());
+            return;
         }
         let program_id = accounts[program_id_index];
         if !Self::should_handle(protocols, event_type_filter, &program_id) {
-            return Ok(());
+            return;
         }
 
         let is_cu_program = EventDispatcher::is_compute_budget_program(&program_id);
 
         let disc_len = match program_id {
-            RAYDIUM_AMM_V4_PROGRAM_ID => 1,
+            RAYDIUM_AMM_V4_PROGRAM_ID | SPL_TOKEN_PROGRAM_ID | SPL_TOKEN_2022_PROGRAM_ID => 1,
+            SYSTEM_PROGRAM_ID | ADDRESS_LOOKUP_TABLE_PROGRAM_ID => 4,
             _ => 8,
         };
 
         // 检查指令数据长度（至少需要 disc_len 字节的 discriminator）
         if !is_cu_program && instruction.data.len() < disc_len {
-            return Ok(());
+            return;
         }
         // 创建元数据
         let timestamp = block_time.unwrap_or(Timestamp { seconds: 0, nanos: 0 });
@@ -368,15 +663,15 @@ impl EventParser {
                 &instruction.data,
                 metadata.clone(),
             ) {
-                callback(&event);
+                callback(event);
             }
-            return Ok(());
+            return;
         }
 
         // 使用 EventDispatcher 匹配协议
         let protocol = match EventDispatcher::match_protocol_by_program_id(&program_id) {
             Some(p) => p,
-            None => return Ok(()),
+            None => return,
         };
 
         // 提取 discriminator 和数据
@@ -399,9 +694,16 @@ impl EventParser {
             metadata.clone(),
         ) {
             Some(e) => e,
-            None => return Ok(()),
+            None => return,
         };
 
+        // SPL Token 等仅指令协议没有 CPI 事件与日志，直接发出
+        if protocol.is_instruction_only() {
+            event.metadata_mut().handle_us = elapsed_micros_since(recv_us);
+            callback(Self::process_event(event, bot_wallet));
+            return;
+        }
+
         // 处理 inner instructions - 查找对应的 CPI log 进行 merge
         // 当 inner_index 有值时，只查找索引大于当前 inner_index 的 CPI log
         // 超低延迟：顺序执行，避免 thread::scope 的 spawn/join 开销
@@ -410,22 +712,38 @@ impl EventParser {
             let raw = inner_index.unwrap_or(-1);
             let current_inner_idx = raw.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
 
+            let merge_all = protocol.has_multiple_cpi_events();
+            let stack_height = inner_index
+                .and_then(|_| inner_instructions_ref.instructions.get(current_inner_idx as usize))
+                .and_then(|inner_instruction| inner_instruction.stack_height);
+
             for (idx, inner_instruction) in inner_instructions_ref.instructions.iter().enumerate() {
                 if (idx as i32) <= current_inner_idx {
                     continue;
                 }
+                // 多事件协议只合并本指令内的 CPI：调用深度回到本指令及以上时结束
+                if merge_all
+                    && stack_height.zip(inner_instruction.stack_height).is_some_and(|(h, s)| s <= h)
+                {
+                    break;
+                }
                 let inner_data = &inner_instruction.data;
                 if inner_data.len() < 16 {
                     continue;
                 }
                 let inner_discriminator = &inner_data[..16];
                 let inner_instruction_data = &inner_data[16..];
-                if let Some(inner_event) = EventDispatcher::dispatch_inner_instruction(
+                if let Some(mut inner_event) = EventDispatcher::dispatch_inner_instruction(
                     protocol.clone(),
                     inner_discriminator,
                     inner_instruction_data,
                     metadata.clone(),
                 ) {
+                    if merge_all {
+                        inner_event.metadata_mut().inner_index = Some(idx as i64);
+                        merge(&mut event, inner_event);
+                        continue;
+                    }
                     inner_instruction_event = Some(inner_event);
                     break;
                 }
@@ -445,6 +763,17 @@ impl EventParser {
 
         // PumpFun MIGRATE: 有 CPI 时合并 log；无 CPI 时仍发出仅含指令数据的事件。
 
+        // 没有 CPI log 时，使用归属到该指令的程序日志（ray_log、Anchor emit! 等），逐个合并
+        if inner_instruction_event.is_none() {
+            if let Some(logs) = program_logs.get(&program_id, outer_index, inner_index) {
+                let log_events =
+                    EventDispatcher::dispatch_instruction_logs(protocol, logs, metadata);
+                for log_event in log_events {
+                    merge(&mut event, log_event);
+                }
+            }
+        }
+
         // 合并事件
         if let Some(inner_instruction_event) = inner_instruction_event {
             merge(&mut event, inner_instruction_event);
@@ -453,9 +782,7 @@ impl EventParser {
         // 设置处理时间（使用高性能时钟）
         event.metadata_mut().handle_us = elapsed_micros_since(recv_us);
         event = Self::process_event(event, bot_wallet);
-        callback(&event);
-
-        Ok(())
+        callback(event);
     }
 
     // ================================================================================================
@@ -482,28 +809,30 @@ impl EventParser {
         tx_index: Option<u64>,
         recent_blockhash: Option<&str>,
         inner_instructions: Option<&InnerInstructions>,
-        callback: Arc<dyn for<'a> Fn(&'a DexEvent) + Send + Sync>,
-    ) -> anyhow::Result<()> {
+        program_logs: &ProgramLogs,
+        callback: &mut dyn FnMut(DexEvent),
+    ) {
         // 添加边界检查以防止越界访问
         let program_id_index = instruction.program_id_index as usize;
         if program_id_index >= accounts.len() {
-            return Ok(());
+            return;
         }
         let program_id = accounts[program_id_index];
         if !Self::should_handle(protocols, event_type_filter, &program_id) {
-            return Ok(());
+            return;
         }
 
         let is_cu_program = EventDispatcher::is_compute_budget_program(&program_id);
 
         let disc_len = match program_id {
-            RAYDIUM_AMM_V4_PROGRAM_ID => 1,
+            RAYDIUM_AMM_V4_PROGRAM_ID | SPL_TOKEN_PROGRAM_ID | SPL_TOKEN_2022_PROGRAM_ID => 1,
+            SYSTEM_PROGRAM_ID | ADDRESS_LOOKUP_TABLE_PROGRAM_ID => 4,
             _ => 8,
         };
 
         // 检查指令数据长度（至少需要 8 字节的 discriminator）
         if !is_cu_program && instruction.data.len() < disc_len {
-            return Ok(());
+            return;
         }
 
         // 创建元数据
@@ -529,15 +858,15 @@ impl EventParser {
                 &instruction.data,
                 metadata.clone(),
             ) {
-                callback(&event);
+                callback(event);
             }
-            return Ok(());
+            return;
         }
 
         // 使用 EventDispatcher 匹配协议
         let protocol = match EventDispatcher::match_protocol_by_program_id(&program_id) {
             Some(p) => p,
-            None => return Ok(()),
+            None => return,
         };
 
         // 提取 discriminator 和数据
@@ -560,9 +889,16 @@ impl EventParser {
             metadata.clone(),
         ) {
             Some(e) => e,
-            None => return Ok(()),
+            None => return,
         };
 
+        // SPL Token 等仅指令协议没有 CPI 事件与日志，直接发出
+        if protocol.is_instruction_only() {
+            event.metadata_mut().handle_us = elapsed_micros_since(recv_us);
+            callback(Self::process_event(event, bot_wallet));
+            return;
+        }
+
         // 处理 inner instructions - 查找对应的 CPI log 进行 merge
         // 当 inner_index 有值时，只查找索引大于当前 inner_index 的 CPI log
         let mut inner_instruction_event: Option<DexEvent> = None;
@@ -570,60 +906,71 @@ impl EventParser {
             let raw = inner_index.unwrap_or(-1);
             let current_inner_idx = raw.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
 
-            // 并行执行两个任务: 解析 inner event 和提取 swap_data
-            let (inner_event_result, swap_data_result) = std::thread::scope(|s| {
-                let inner_event_handle = s.spawn(|| {
-                    for (idx, inner_instruction) in inner_instructions_ref.instructions.iter().enumerate() {
-                        // 只查找索引大于当前 inner_index 的 CPI log
-                        if (idx as i32) <= current_inner_idx {
-                            continue;
-                        }
-                        
-                        let inner_data = &inner_instruction.instruction.data;
-                        // 检查长度（需要 16 字节的 discriminator）
-                        if inner_data.len() < 16 {
-                            continue;
-                        }
-                        let inner_discriminator = &inner_data[..16];
-                        let inner_instruction_data = &inner_data[16..];
-
-                        if let Some(inner_event) = EventDispatcher::dispatch_inner_instruction(
-                            protocol.clone(),
-                            inner_discriminator,
-                            inner_instruction_data,
-                            metadata.clone(),
-                        ) {
-                            return Some(inner_event);
-                        }
-                    }
-                    None
-                });
+            // 顺序执行：避免 thread::scope 的 spawn/join 开销，也便于在 rayon 等线程池中调用
+            let merge_all = protocol.has_multiple_cpi_events();
+            let stack_height = inner_index
+                .and_then(|_| inner_instructions_ref.instructions.get(current_inner_idx as usize))
+                .and_then(|inner_instruction| inner_instruction.stack_height);
 
-                let swap_data_handle = s.spawn(|| {
-                    if event.metadata().swap_data.is_none() {
-                        parse_swap_data_from_next_instructions(
-                            &event,
-                            inner_instructions_ref,
-                            current_inner_idx,
-                            accounts,
-                        )
-                    } else {
-                        None
+            for (idx, inner_instruction) in inner_instructions_ref.instructions.iter().enumerate() {
+                // 只查找索引大于当前 inner_index 的 CPI log
+                if (idx as i32) <= current_inner_idx {
+                    continue;
+                }
+                // 多事件协议只合并本指令内的 CPI：调用深度回到本指令及以上时结束
+                if merge_all
+                    && stack_height.zip(inner_instruction.stack_height).is_some_and(|(h, s)| s <= h)
+                {
+                    break;
+                }
+                let inner_data = &inner_instruction.instruction.data;
+                // 检查长度（需要 16 字节的 discriminator）
+                if inner_data.len() < 16 {
+                    continue;
+                }
+                let inner_discriminator = &inner_data[..16];
+                let inner_instruction_data = &inner_data[16..];
+                if let Some(mut inner_event) = EventDispatcher::dispatch_inner_instruction(
+                    protocol.clone(),
+                    inner_discriminator,
+                    inner_instruction_data,
+                    metadata.clone(),
+                ) {
+                    if merge_all {
+                        inner_event.metadata_mut().inner_index = Some(idx as i64);
+                        merge(&mut event, inner_event);
+                        continue;
                     }
-                });
-
-                // 等待两个任务完成
-                (inner_event_handle.join().unwrap(), swap_data_handle.join().unwrap())
-            });
+                    inner_instruction_event = Some(inner_event);
+                    break;
+                }
+            }
 
-            inner_instruction_event = inner_event_result;
-            if let Some(swap_data) = swap_data_result {
-                event.metadata_mut().set_swap_data(swap_data);
+            if event.metadata().swap_data.is_none() {
+                if let Some(swap_data) = parse_swap_data_from_next_instructions(
+                    &event,
+                    inner_instructions_ref,
+                    current_inner_idx,
+                    accounts,
+                ) {
+                    event.metadata_mut().set_swap_data(swap_data);
+                }
             }
         }
 
         // PumpFun MIGRATE: 有 CPI 时合并 log；无 CPI（如 shred）仍发出仅含指令数据的事件。
 
+        // 没有 CPI log 时，使用归属到该指令的程序日志（ray_log、Anchor emit! 等），逐个合并
+        if inner_instruction_event.is_none() {
+            if let Some(logs) = program_logs.get(&program_id, outer_index, inner_index) {
+                let log_events =
+                    EventDispatcher::dispatch_instruction_logs(protocol, logs, metadata);
+                for log_event in log_events {
+                    merge(&mut event, log_event);
+                }
+            }
+        }
+
         // 合并事件
         if let Some(inner_instruction_event) = inner_instruction_event {
             merge(&mut event, inner_instruction_event);
@@ -632,15 +979,56 @@ impl EventParser {
         // 设置处理时间（使用高性能时钟）
         event.metadata_mut().handle_us = elapsed_micros_since(recv_us);
         event = Self::process_event(event, bot_wallet);
-        callback(&event);
-
-        Ok(())
+        callback(event);
     }
 
     // ================================================================================================
     // Helper Functions
     // ================================================================================================
 
+    /// Wrap the callback so events emitted inside an aggregator route point back to it
+    ///
+    /// 路由事件先于其 inner instructions 发出；每一跳的子事件都位于该跳 SwapEvent 之前，
+    /// 因此同一外层指令中、位于路由与最后一跳之间的事件都属于该路由
+    fn link_parent_routes<'a>(callback: &'a mut dyn FnMut(DexEvent)) -> impl FnMut(DexEvent) + 'a {
+        let mut route: Option<(RouteRef, i64)> = None;
+        move |mut event| {
+            if let DexEvent::JupiterRouteEvent(e) = &event {
+                route = e.hops.last().and_then(|hop| hop.inner_index).map(|last_hop| {
+                    let parent = RouteRef {
+                        program_id: e.metadata.program_id,
+                        outer_index: e.metadata.outer_index,
+                        inner_index: e.metadata.inner_index,
+                    };
+                    (parent, last_hop)
+                });
+            } else if let Some((parent, last_hop)) = route {
+                let metadata = event.metadata_mut();
+                let in_route = metadata.inner_index.is_some_and(|index| {
+                    index < last_hop && parent.inner_index.is_none_or(|parent| index > parent)
+                });
+                if metadata.outer_index == parent.outer_index && in_route {
+                    metadata.parent_route = Some(parent);
+                }
+            }
+            callback(event)
+        }
+    }
+
+    /// Wrap the callback so SPL Token events get mint and owner from the transaction's
+    /// token balances
+    fn fill_token_accounts<'a>(
+        token_accounts: &'a TokenAccounts,
+        callback: &'a mut dyn FnMut(DexEvent),
+    ) -> impl FnMut(DexEvent) + 'a {
+        move |mut event| {
+            if !token_accounts.is_empty() {
+                fill_spl_token_accounts(&mut event, token_accounts);
+            }
+            callback(event)
+        }
+    }
+
     /// Check if instruction should be processed based on protocol filter
     ///
     /// Determines whether a program_id matches any of the protocols we're interested in.
@@ -738,3 +1126,10 @@ impl EventParser {
     }
 }
 
+/// 按指令顺序取外层指令的程序 ID
+fn outer_program_ids<'a>(
+    program_id_indexes: impl Iterator<Item = usize> + 'a,
+    accounts: &'a [Pubkey],
+) -> impl Iterator<Item = Pubkey> + 'a {
+    program_id_indexes.map(|index| accounts.get(index).copied().unwrap_or_default())
+}
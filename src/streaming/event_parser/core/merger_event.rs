@@ -1,4 +1,8 @@
+use crate::streaming::event_parser::common::{EventType, SwapData};
+use crate::streaming::event_parser::core::custom_parser::{self, RegisteredParser};
+use crate::streaming::event_parser::idl::parser::merge_idl_cpi_event;
 use crate::streaming::event_parser::DexEvent;
+use solana_sdk::pubkey::Pubkey;
 
 pub fn merge(instruction_event: &mut DexEvent, cpi_log_event: DexEvent) {
     match instruction_event {
@@ -124,6 +128,95 @@ pub fn merge(instruction_event: &mut DexEvent, cpi_log_event: DexEvent) {
             }
             _ => {}
         },
+        DexEvent::PumpFunCollectCreatorFeeEvent(e) => {
+            if let DexEvent::PumpFunCollectCreatorFeeEvent(cpie) = cpi_log_event {
+                e.timestamp = cpie.timestamp;
+                e.creator = cpie.creator;
+                e.creator_fee = cpie.creator_fee;
+            }
+        }
+        DexEvent::PumpFunDistributeCreatorFeesEvent(e) => {
+            if let DexEvent::PumpFunDistributeCreatorFeesEvent(cpie) = cpi_log_event {
+                e.timestamp = cpie.timestamp;
+                e.mint = cpie.mint;
+                e.bonding_curve = cpie.bonding_curve;
+                e.sharing_config = cpie.sharing_config;
+                e.admin = cpie.admin;
+                e.shareholders = cpie.shareholders;
+                e.distributed = cpie.distributed;
+            }
+        }
+        DexEvent::PumpFunClaimCashbackEvent(e) => {
+            if let DexEvent::PumpFunClaimCashbackEvent(cpie) = cpi_log_event {
+                e.user = cpie.user;
+                e.amount = cpie.amount;
+                e.timestamp = cpie.timestamp;
+                e.total_claimed = cpie.total_claimed;
+                e.total_cashback_earned = cpie.total_cashback_earned;
+            }
+        }
+        DexEvent::PumpFunSetCreatorEvent(e) => {
+            if let DexEvent::PumpFunSetCreatorEvent(cpie) = cpi_log_event {
+                e.timestamp = cpie.timestamp;
+                e.mint = cpie.mint;
+                e.bonding_curve = cpie.bonding_curve;
+                e.creator = cpie.creator;
+            }
+        }
+        DexEvent::PumpFunSetMetaplexCreatorEvent(e) => {
+            if let DexEvent::PumpFunSetMetaplexCreatorEvent(cpie) = cpi_log_event {
+                e.timestamp = cpie.timestamp;
+                e.mint = cpie.mint;
+                e.bonding_curve = cpie.bonding_curve;
+                e.metadata_account = cpie.metadata_account;
+                e.creator = cpie.creator;
+            }
+        }
+        DexEvent::PumpFunMigrateBondingCurveCreatorEvent(e) => {
+            if let DexEvent::PumpFunMigrateBondingCurveCreatorEvent(cpie) = cpi_log_event {
+                e.timestamp = cpie.timestamp;
+                e.mint = cpie.mint;
+                e.bonding_curve = cpie.bonding_curve;
+                e.sharing_config = cpie.sharing_config;
+                e.old_creator = cpie.old_creator;
+                e.new_creator = cpie.new_creator;
+            }
+        }
+        DexEvent::PumpFunInitUserVolumeAccumulatorEvent(e) => {
+            if let DexEvent::PumpFunInitUserVolumeAccumulatorEvent(cpie) = cpi_log_event {
+                e.payer = cpie.payer;
+                e.user = cpie.user;
+                e.timestamp = cpie.timestamp;
+            }
+        }
+        DexEvent::PumpFunSyncUserVolumeAccumulatorEvent(e) => {
+            if let DexEvent::PumpFunSyncUserVolumeAccumulatorEvent(cpie) = cpi_log_event {
+                e.user = cpie.user;
+                e.total_claimed_tokens_before = cpie.total_claimed_tokens_before;
+                e.total_claimed_tokens_after = cpie.total_claimed_tokens_after;
+                e.timestamp = cpie.timestamp;
+            }
+        }
+        DexEvent::PumpFunCloseUserVolumeAccumulatorEvent(e) => {
+            if let DexEvent::PumpFunCloseUserVolumeAccumulatorEvent(cpie) = cpi_log_event {
+                e.user = cpie.user;
+                e.timestamp = cpie.timestamp;
+                e.total_unclaimed_tokens = cpie.total_unclaimed_tokens;
+                e.total_claimed_tokens = cpie.total_claimed_tokens;
+                e.current_sol_volume = cpie.current_sol_volume;
+                e.last_update_timestamp = cpie.last_update_timestamp;
+            }
+        }
+        DexEvent::PumpFunClaimTokenIncentivesEvent(e) => {
+            if let DexEvent::PumpFunClaimTokenIncentivesEvent(cpie) = cpi_log_event {
+                e.user = cpie.user;
+                e.mint = cpie.mint;
+                e.amount = cpie.amount;
+                e.timestamp = cpie.timestamp;
+                e.total_claimed_tokens = cpie.total_claimed_tokens;
+                e.current_sol_volume = cpie.current_sol_volume;
+            }
+        }
 
         // Bonk events
         DexEvent::BonkTradeEvent(e) => match cpi_log_event {
@@ -168,6 +261,16 @@ pub fn merge(instruction_event: &mut DexEvent, cpi_log_event: DexEvent) {
             }
             _ => {}
         },
+        DexEvent::BonkClaimVestedEvent(e) => {
+            if let DexEvent::BonkClaimVestedEvent(cpie) = cpi_log_event {
+                e.claim_amount = cpie.claim_amount;
+            }
+        }
+        DexEvent::BonkCreateVestingEvent(e) => {
+            if let DexEvent::BonkCreateVestingEvent(cpie) = cpi_log_event {
+                e.share_amount = cpie.share_amount;
+            }
+        }
 
         // PumpSwap events
         DexEvent::PumpSwapBuyEvent(e) => match cpi_log_event {
@@ -306,6 +409,53 @@ pub fn merge(instruction_event: &mut DexEvent, cpi_log_event: DexEvent) {
             }
             _ => {}
         },
+        DexEvent::PumpSwapCollectCoinCreatorFeeEvent(e) => {
+            if let DexEvent::PumpSwapCollectCoinCreatorFeeEvent(cpie) = cpi_log_event {
+                e.timestamp = cpie.timestamp;
+                e.coin_creator = cpie.coin_creator;
+                e.coin_creator_fee = cpie.coin_creator_fee;
+                e.coin_creator_vault_ata = cpie.coin_creator_vault_ata;
+                e.coin_creator_token_account = cpie.coin_creator_token_account;
+            }
+        }
+        DexEvent::PumpSwapClaimCashbackEvent(e) => {
+            if let DexEvent::PumpSwapClaimCashbackEvent(cpie) = cpi_log_event {
+                e.user = cpie.user;
+                e.amount = cpie.amount;
+                e.timestamp = cpie.timestamp;
+                e.total_claimed = cpie.total_claimed;
+                e.total_cashback_earned = cpie.total_cashback_earned;
+            }
+        }
+        DexEvent::PumpSwapClaimTokenIncentivesEvent(e) => {
+            if let DexEvent::PumpSwapClaimTokenIncentivesEvent(cpie) = cpi_log_event {
+                e.user = cpie.user;
+                e.mint = cpie.mint;
+                e.amount = cpie.amount;
+                e.timestamp = cpie.timestamp;
+                e.total_claimed_tokens = cpie.total_claimed_tokens;
+                e.current_sol_volume = cpie.current_sol_volume;
+            }
+        }
+        DexEvent::PumpSwapSetCoinCreatorEvent(e) => {
+            if let DexEvent::PumpSwapSetCoinCreatorEvent(cpie) = cpi_log_event {
+                e.timestamp = cpie.timestamp;
+                e.base_mint = cpie.base_mint;
+                e.pool = cpie.pool;
+                e.coin_creator = cpie.coin_creator;
+                e.from_metaplex = cpie.from_metaplex;
+            }
+        }
+        DexEvent::PumpSwapMigratePoolCoinCreatorEvent(e) => {
+            if let DexEvent::PumpSwapMigratePoolCoinCreatorEvent(cpie) = cpi_log_event {
+                e.timestamp = cpie.timestamp;
+                e.base_mint = cpie.base_mint;
+                e.pool = cpie.pool;
+                e.sharing_config = cpie.sharing_config;
+                e.old_coin_creator = cpie.old_coin_creator;
+                e.new_coin_creator = cpie.new_coin_creator;
+            }
+        }
         DexEvent::MeteoraDammV2SwapEvent(e) => match cpi_log_event {
             DexEvent::MeteoraDammV2SwapEvent(cpie) => {
                 e.pool = cpie.pool;
@@ -436,6 +586,532 @@ pub fn merge(instruction_event: &mut DexEvent, cpi_log_event: DexEvent) {
             }
             _ => {}
         },
+        DexEvent::MeteoraDammV2AddLiquidityEvent(e) => {
+            if let DexEvent::MeteoraDammV2AddLiquidityEvent(cpie) = cpi_log_event {
+                e.pool = cpie.pool;
+                e.position = cpie.position;
+                e.owner = cpie.owner;
+                e.token_a_amount = cpie.token_a_amount;
+                e.token_b_amount = cpie.token_b_amount;
+                e.transfer_fee_included_token_a_amount = cpie.transfer_fee_included_token_a_amount;
+                e.transfer_fee_included_token_b_amount = cpie.transfer_fee_included_token_b_amount;
+                e.reserve_a_amount = cpie.reserve_a_amount;
+                e.reserve_b_amount = cpie.reserve_b_amount;
+                e.liquidity_delta = cpie.liquidity_delta;
+                e.change_type = cpie.change_type;
+            }
+        }
+        DexEvent::MeteoraDammV2RemoveLiquidityEvent(e) => {
+            if let DexEvent::MeteoraDammV2RemoveLiquidityEvent(cpie) = cpi_log_event {
+                e.pool = cpie.pool;
+                e.position = cpie.position;
+                e.owner = cpie.owner;
+                e.token_a_amount = cpie.token_a_amount;
+                e.token_b_amount = cpie.token_b_amount;
+                e.transfer_fee_included_token_a_amount = cpie.transfer_fee_included_token_a_amount;
+                e.transfer_fee_included_token_b_amount = cpie.transfer_fee_included_token_b_amount;
+                e.reserve_a_amount = cpie.reserve_a_amount;
+                e.reserve_b_amount = cpie.reserve_b_amount;
+                e.liquidity_delta = cpie.liquidity_delta;
+                e.change_type = cpie.change_type;
+            }
+        }
+        DexEvent::MeteoraDammV2ClaimPositionFeeEvent(e) => {
+            if let DexEvent::MeteoraDammV2ClaimPositionFeeEvent(cpie) = cpi_log_event {
+                e.pool = cpie.pool;
+                e.position = cpie.position;
+                e.owner = cpie.owner;
+                e.fee_a_claimed = cpie.fee_a_claimed;
+                e.fee_b_claimed = cpie.fee_b_claimed;
+            }
+        }
+
+        // Meteora DAMM v1 events
+        DexEvent::MeteoraDammV1SwapEvent(e) => {
+            if let DexEvent::MeteoraDammV1SwapEvent(cpie) = cpi_log_event {
+                e.in_amount = cpie.in_amount;
+                e.out_amount = cpie.out_amount;
+                e.trade_fee = cpie.trade_fee;
+                e.admin_fee = cpie.admin_fee;
+                e.host_fee = cpie.host_fee;
+                // 资金经由 vault 程序转入转出，指令账户中没有 mint
+                e.metadata.set_swap_data(SwapData {
+                    from_mint: Default::default(),
+                    to_mint: Default::default(),
+                    from_amount: e.in_amount,
+                    to_amount: e.out_amount,
+                    description: Some(
+                        "Unable to get from_mint and to_mint from MeteoraDammV1SwapEvent".into(),
+                    ),
+                });
+            }
+        }
+        DexEvent::MeteoraDammV1AddLiquidityEvent(e) => {
+            if let DexEvent::MeteoraDammV1AddLiquidityEvent(cpie) = cpi_log_event {
+                e.lp_mint_amount = cpie.lp_mint_amount;
+                e.token_a_amount = cpie.token_a_amount;
+                e.token_b_amount = cpie.token_b_amount;
+            }
+        }
+        DexEvent::MeteoraDammV1RemoveLiquidityEvent(e) => {
+            if let DexEvent::MeteoraDammV1RemoveLiquidityEvent(cpie) = cpi_log_event {
+                e.lp_unmint_amount = cpie.lp_unmint_amount;
+                e.token_a_out_amount = cpie.token_a_out_amount;
+                e.token_b_out_amount = cpie.token_b_out_amount;
+            }
+        }
+        DexEvent::MeteoraDammV1BootstrapLiquidityEvent(e) => {
+            if let DexEvent::MeteoraDammV1BootstrapLiquidityEvent(cpie) = cpi_log_event {
+                e.lp_mint_amount = cpie.lp_mint_amount;
+                e.token_a_amount = cpie.token_a_amount;
+                e.token_b_amount = cpie.token_b_amount;
+            }
+        }
+        DexEvent::MeteoraDammV1InitializePoolEvent(e) => {
+            if let DexEvent::MeteoraDammV1InitializePoolEvent(cpie) = cpi_log_event {
+                e.lp_mint = cpie.lp_mint;
+                e.token_a_mint = cpie.token_a_mint;
+                e.token_b_mint = cpie.token_b_mint;
+                e.pool_type = cpie.pool_type;
+                e.pool = cpie.pool;
+            }
+        }
+        DexEvent::MeteoraDammV1LockEvent(e) => {
+            if let DexEvent::MeteoraDammV1LockEvent(cpie) = cpi_log_event {
+                e.amount = cpie.amount;
+            }
+        }
+        DexEvent::MeteoraDammV1ClaimFeeEvent(e) => {
+            if let DexEvent::MeteoraDammV1ClaimFeeEvent(cpie) = cpi_log_event {
+                e.amount = cpie.amount;
+                e.a_fee = cpie.a_fee;
+                e.b_fee = cpie.b_fee;
+            }
+        }
+
+        // Meteora DLMM events
+        DexEvent::MeteoraDlmmSwapEvent(e) => {
+            if let DexEvent::MeteoraDlmmSwapEvent(cpie) = cpi_log_event {
+                e.lb_pair = cpie.lb_pair;
+                e.from = cpie.from;
+                e.start_bin_id = cpie.start_bin_id;
+                e.end_bin_id = cpie.end_bin_id;
+                e.amount_in = cpie.amount_in;
+                e.amount_out = cpie.amount_out;
+                e.swap_for_y = cpie.swap_for_y;
+                e.fee = cpie.fee;
+                e.protocol_fee = cpie.protocol_fee;
+                e.fee_bps = cpie.fee_bps;
+                e.host_fee = cpie.host_fee;
+                // swap_for_y 为 true 时 X -> Y
+                let (from_mint, to_mint) = if e.swap_for_y {
+                    (e.token_x_mint, e.token_y_mint)
+                } else {
+                    (e.token_y_mint, e.token_x_mint)
+                };
+                e.metadata.set_swap_data(SwapData {
+                    from_mint,
+                    to_mint,
+                    from_amount: e.amount_in,
+                    to_amount: e.amount_out,
+                    description: None,
+                });
+            }
+        }
+        DexEvent::MeteoraDlmmAddLiquidityEvent(e) => {
+            if let DexEvent::MeteoraDlmmAddLiquidityEvent(cpie) = cpi_log_event {
+                e.lb_pair = cpie.lb_pair;
+                e.from = cpie.from;
+                e.position = cpie.position;
+                e.amounts = cpie.amounts;
+                e.active_bin_id = cpie.active_bin_id;
+            }
+        }
+        DexEvent::MeteoraDlmmAddLiquidityOneSideEvent(e) => {
+            if let DexEvent::MeteoraDlmmAddLiquidityEvent(cpie) = cpi_log_event {
+                e.lb_pair = cpie.lb_pair;
+                e.from = cpie.from;
+                e.position = cpie.position;
+                e.amounts = cpie.amounts;
+                e.active_bin_id = cpie.active_bin_id;
+            }
+        }
+        DexEvent::MeteoraDlmmRemoveLiquidityEvent(e) => {
+            if let DexEvent::MeteoraDlmmRemoveLiquidityEvent(cpie) = cpi_log_event {
+                e.lb_pair = cpie.lb_pair;
+                e.from = cpie.from;
+                e.position = cpie.position;
+                e.amounts = cpie.amounts;
+                e.active_bin_id = cpie.active_bin_id;
+            }
+        }
+        DexEvent::MeteoraDlmmPositionCreateEvent(e) => {
+            if let DexEvent::MeteoraDlmmPositionCreateEvent(cpie) = cpi_log_event {
+                e.lb_pair = cpie.lb_pair;
+                e.position = cpie.position;
+                e.owner = cpie.owner;
+            }
+        }
+        DexEvent::MeteoraDlmmPositionCloseEvent(e) => {
+            if let DexEvent::MeteoraDlmmPositionCloseEvent(cpie) = cpi_log_event {
+                e.position = cpie.position;
+                e.owner = cpie.owner;
+            }
+        }
+        DexEvent::MeteoraDlmmClaimFeeEvent(e) => {
+            if let DexEvent::MeteoraDlmmClaimFeeEvent(cpie) = cpi_log_event {
+                e.lb_pair = cpie.lb_pair;
+                e.position = cpie.position;
+                e.owner = cpie.owner;
+                e.fee_x = cpie.fee_x;
+                e.fee_y = cpie.fee_y;
+            }
+        }
+        DexEvent::MeteoraDlmmClaimRewardEvent(e) => {
+            if let DexEvent::MeteoraDlmmClaimRewardEvent(cpie) = cpi_log_event {
+                e.lb_pair = cpie.lb_pair;
+                e.position = cpie.position;
+                e.owner = cpie.owner;
+                e.reward_index = cpie.reward_index;
+                e.total_reward = cpie.total_reward;
+            }
+        }
+
+        // Orca Whirlpool events
+        DexEvent::OrcaWhirlpoolSwapEvent(e) => {
+            if let DexEvent::OrcaWhirlpoolSwapEvent(cpie) = cpi_log_event {
+                e.whirlpool = cpie.whirlpool;
+                e.a_to_b = cpie.a_to_b;
+                e.pre_sqrt_price = cpie.pre_sqrt_price;
+                e.post_sqrt_price = cpie.post_sqrt_price;
+                e.input_amount = cpie.input_amount;
+                e.output_amount = cpie.output_amount;
+                e.input_transfer_fee = cpie.input_transfer_fee;
+                e.output_transfer_fee = cpie.output_transfer_fee;
+                e.lp_fee = cpie.lp_fee;
+                e.protocol_fee = cpie.protocol_fee;
+                // swap 指令只有 swapV2 带 mint 账户
+                if e.token_mint_a != Default::default() {
+                    let (from_mint, to_mint) = if e.a_to_b {
+                        (e.token_mint_a, e.token_mint_b)
+                    } else {
+                        (e.token_mint_b, e.token_mint_a)
+                    };
+                    e.metadata.set_swap_data(SwapData {
+                        from_mint,
+                        to_mint,
+                        from_amount: e.input_amount,
+                        to_amount: e.output_amount,
+                        description: None,
+                    });
+                }
+            }
+        }
+        DexEvent::OrcaWhirlpoolTwoHopSwapEvent(e) => {
+            if let DexEvent::OrcaWhirlpoolSwapEvent(cpie) = cpi_log_event {
+                // 按池子地址把 Traded 事件归属到对应的一跳
+                let leg = if cpie.whirlpool == e.leg_two.whirlpool {
+                    &mut e.leg_two
+                } else if cpie.whirlpool == e.leg_one.whirlpool {
+                    &mut e.leg_one
+                } else {
+                    return;
+                };
+                leg.pre_sqrt_price = cpie.pre_sqrt_price;
+                leg.post_sqrt_price = cpie.post_sqrt_price;
+                leg.input_amount = cpie.input_amount;
+                leg.output_amount = cpie.output_amount;
+                leg.input_transfer_fee = cpie.input_transfer_fee;
+                leg.output_transfer_fee = cpie.output_transfer_fee;
+                leg.lp_fee = cpie.lp_fee;
+                leg.protocol_fee = cpie.protocol_fee;
+            }
+        }
+        DexEvent::OrcaWhirlpoolIncreaseLiquidityEvent(e) => {
+            if let DexEvent::OrcaWhirlpoolIncreaseLiquidityEvent(cpie) = cpi_log_event {
+                e.whirlpool = cpie.whirlpool;
+                e.position = cpie.position;
+                e.tick_lower_index = cpie.tick_lower_index;
+                e.tick_upper_index = cpie.tick_upper_index;
+                e.liquidity = cpie.liquidity;
+                e.token_a_amount = cpie.token_a_amount;
+                e.token_b_amount = cpie.token_b_amount;
+                e.token_a_transfer_fee = cpie.token_a_transfer_fee;
+                e.token_b_transfer_fee = cpie.token_b_transfer_fee;
+            }
+        }
+        DexEvent::OrcaWhirlpoolDecreaseLiquidityEvent(e) => {
+            if let DexEvent::OrcaWhirlpoolDecreaseLiquidityEvent(cpie) = cpi_log_event {
+                e.whirlpool = cpie.whirlpool;
+                e.position = cpie.position;
+                e.tick_lower_index = cpie.tick_lower_index;
+                e.tick_upper_index = cpie.tick_upper_index;
+                e.liquidity = cpie.liquidity;
+                e.token_a_amount = cpie.token_a_amount;
+                e.token_b_amount = cpie.token_b_amount;
+                e.token_a_transfer_fee = cpie.token_a_transfer_fee;
+                e.token_b_transfer_fee = cpie.token_b_transfer_fee;
+            }
+        }
+
+        // Raydium CPMM events
+        DexEvent::RaydiumCpmmSwapEvent(e) => {
+            if let DexEvent::RaydiumCpmmSwapEvent(cpie) = cpi_log_event {
+                e.input_vault_before = cpie.input_vault_before;
+                e.output_vault_before = cpie.output_vault_before;
+                e.input_amount = cpie.input_amount;
+                e.output_amount = cpie.output_amount;
+                e.input_transfer_fee = cpie.input_transfer_fee;
+                e.output_transfer_fee = cpie.output_transfer_fee;
+                e.base_input = cpie.base_input;
+                e.trade_fee = cpie.trade_fee;
+                e.creator_fee = cpie.creator_fee;
+                e.creator_fee_on_input = cpie.creator_fee_on_input;
+                e.metadata.set_swap_data(SwapData {
+                    from_mint: e.input_token_mint,
+                    to_mint: e.output_token_mint,
+                    from_amount: e.input_amount,
+                    to_amount: e.output_amount,
+                    description: None,
+                });
+            }
+        }
+        DexEvent::RaydiumCpmmDepositEvent(e) => {
+            if let DexEvent::RaydiumCpmmDepositEvent(cpie) = cpi_log_event {
+                e.lp_amount_before = cpie.lp_amount_before;
+                e.token_0_vault_before = cpie.token_0_vault_before;
+                e.token_1_vault_before = cpie.token_1_vault_before;
+                e.token_0_amount = cpie.token_0_amount;
+                e.token_1_amount = cpie.token_1_amount;
+                e.token_0_transfer_fee = cpie.token_0_transfer_fee;
+                e.token_1_transfer_fee = cpie.token_1_transfer_fee;
+            }
+        }
+        DexEvent::RaydiumCpmmWithdrawEvent(e) => {
+            if let DexEvent::RaydiumCpmmWithdrawEvent(cpie) = cpi_log_event {
+                e.lp_amount_before = cpie.lp_amount_before;
+                e.token_0_vault_before = cpie.token_0_vault_before;
+                e.token_1_vault_before = cpie.token_1_vault_before;
+                e.token_0_amount = cpie.token_0_amount;
+                e.token_1_amount = cpie.token_1_amount;
+                e.token_0_transfer_fee = cpie.token_0_transfer_fee;
+                e.token_1_transfer_fee = cpie.token_1_transfer_fee;
+            }
+        }
+
+        // Raydium CLMM events
+        DexEvent::RaydiumClmmSwapEvent(e) => {
+            if let DexEvent::RaydiumClmmSwapEvent(cpie) = cpi_log_event {
+                e.amount0 = cpie.amount0;
+                e.transfer_fee0 = cpie.transfer_fee0;
+                e.amount1 = cpie.amount1;
+                e.transfer_fee1 = cpie.transfer_fee1;
+                e.zero_for_one = cpie.zero_for_one;
+                e.sqrt_price_x64 = cpie.sqrt_price_x64;
+                e.liquidity = cpie.liquidity;
+                e.tick = cpie.tick;
+            }
+        }
+        DexEvent::RaydiumClmmSwapV2Event(e) => {
+            if let DexEvent::RaydiumClmmSwapEvent(cpie) = cpi_log_event {
+                e.amount0 = cpie.amount0;
+                e.transfer_fee0 = cpie.transfer_fee0;
+                e.amount1 = cpie.amount1;
+                e.transfer_fee1 = cpie.transfer_fee1;
+                e.zero_for_one = cpie.zero_for_one;
+                e.sqrt_price_x64 = cpie.sqrt_price_x64;
+                e.liquidity = cpie.liquidity;
+                e.tick = cpie.tick;
+                let (from_amount, to_amount) =
+                    if e.zero_for_one { (e.amount0, e.amount1) } else { (e.amount1, e.amount0) };
+                e.metadata.set_swap_data(SwapData {
+                    from_mint: e.input_vault_mint,
+                    to_mint: e.output_vault_mint,
+                    from_amount,
+                    to_amount,
+                    description: None,
+                });
+            }
+        }
+        DexEvent::RaydiumClmmIncreaseLiquidityEvent(e) => {
+            if let DexEvent::RaydiumClmmIncreaseLiquidityEvent(cpie) = cpi_log_event {
+                e.position_nft_mint = cpie.position_nft_mint;
+                e.liquidity = cpie.liquidity;
+                e.amount0 = cpie.amount0;
+                e.amount1 = cpie.amount1;
+                e.amount0_transfer_fee = cpie.amount0_transfer_fee;
+                e.amount1_transfer_fee = cpie.amount1_transfer_fee;
+            }
+        }
+        DexEvent::RaydiumClmmIncreaseLiquidityV2Event(e) => {
+            if let DexEvent::RaydiumClmmIncreaseLiquidityEvent(cpie) = cpi_log_event {
+                e.position_nft_mint = cpie.position_nft_mint;
+                e.liquidity = cpie.liquidity;
+                e.amount0 = cpie.amount0;
+                e.amount1 = cpie.amount1;
+                e.amount0_transfer_fee = cpie.amount0_transfer_fee;
+                e.amount1_transfer_fee = cpie.amount1_transfer_fee;
+            }
+        }
+        // 减少流动性时先领取手续费，CollectPersonalFeeEvent 与 DecreaseLiquidityEvent 依次合并，
+        // 两者的先后顺序不固定
+        DexEvent::RaydiumClmmDecreaseLiquidityEvent(e) => match cpi_log_event {
+            DexEvent::RaydiumClmmDecreaseLiquidityEvent(cpie) => {
+                e.position_nft_mint = cpie.position_nft_mint;
+                e.decrease_amount0 = cpie.decrease_amount0;
+                e.decrease_amount1 = cpie.decrease_amount1;
+                e.fee_amount0 = cpie.fee_amount0;
+                e.fee_amount1 = cpie.fee_amount1;
+                e.reward_amounts = cpie.reward_amounts;
+                e.transfer_fee0 = cpie.transfer_fee0;
+                e.transfer_fee1 = cpie.transfer_fee1;
+            }
+            DexEvent::RaydiumClmmCollectPersonalFeeEvent(cpie) => {
+                e.position_nft_mint = cpie.position_nft_mint;
+                e.fee_amount0 = cpie.amount0;
+                e.fee_amount1 = cpie.amount1;
+            }
+            _ => {}
+        },
+        DexEvent::RaydiumClmmDecreaseLiquidityV2Event(e) => match cpi_log_event {
+            DexEvent::RaydiumClmmDecreaseLiquidityEvent(cpie) => {
+                e.position_nft_mint = cpie.position_nft_mint;
+                e.decrease_amount0 = cpie.decrease_amount0;
+                e.decrease_amount1 = cpie.decrease_amount1;
+                e.fee_amount0 = cpie.fee_amount0;
+                e.fee_amount1 = cpie.fee_amount1;
+                e.reward_amounts = cpie.reward_amounts;
+                e.transfer_fee0 = cpie.transfer_fee0;
+                e.transfer_fee1 = cpie.transfer_fee1;
+            }
+            DexEvent::RaydiumClmmCollectPersonalFeeEvent(cpie) => {
+                e.position_nft_mint = cpie.position_nft_mint;
+                e.fee_amount0 = cpie.amount0;
+                e.fee_amount1 = cpie.amount1;
+            }
+            _ => {}
+        },
+        DexEvent::RaydiumClmmSwapRouterBaseInEvent(e) => {
+            if let DexEvent::RaydiumClmmSwapEvent(cpie) = cpi_log_event {
+                e.hops.push(cpie.into());
+            }
+        }
+
+        // Raydium AMM V4 events (ray_log)
+        DexEvent::RaydiumAmmV4SwapEvent(e) => {
+            if let DexEvent::RaydiumAmmV4SwapEvent(cpie) = cpi_log_event {
+                e.direction = cpie.direction;
+                e.user_source_amount = cpie.user_source_amount;
+                e.pool_coin_amount = cpie.pool_coin_amount;
+                e.pool_pc_amount = cpie.pool_pc_amount;
+                e.out_amount = cpie.out_amount;
+                e.deduct_in = cpie.deduct_in;
+                if let Some(swap_data) = e.metadata.swap_data.as_mut() {
+                    // base in 输出以 out_amount 为准，base out 输入以 deduct_in 为准
+                    if e.amount_in > 0 {
+                        swap_data.from_amount = e.amount_in;
+                        swap_data.to_amount = e.out_amount;
+                    } else {
+                        swap_data.from_amount = e.deduct_in;
+                        swap_data.to_amount = e.amount_out;
+                    }
+                }
+            }
+        }
+        DexEvent::RaydiumAmmV4DepositEvent(e) => {
+            if let DexEvent::RaydiumAmmV4DepositEvent(cpie) = cpi_log_event {
+                e.pool_coin_amount = cpie.pool_coin_amount;
+                e.pool_pc_amount = cpie.pool_pc_amount;
+                e.pool_lp_amount = cpie.pool_lp_amount;
+                e.calc_pnl_x = cpie.calc_pnl_x;
+                e.calc_pnl_y = cpie.calc_pnl_y;
+                e.deduct_coin = cpie.deduct_coin;
+                e.deduct_pc = cpie.deduct_pc;
+                e.mint_lp = cpie.mint_lp;
+            }
+        }
+        DexEvent::RaydiumAmmV4WithdrawEvent(e) => {
+            if let DexEvent::RaydiumAmmV4WithdrawEvent(cpie) = cpi_log_event {
+                e.user_lp_amount = cpie.user_lp_amount;
+                e.pool_coin_amount = cpie.pool_coin_amount;
+                e.pool_pc_amount = cpie.pool_pc_amount;
+                e.pool_lp_amount = cpie.pool_lp_amount;
+                e.calc_pnl_x = cpie.calc_pnl_x;
+                e.calc_pnl_y = cpie.calc_pnl_y;
+                e.out_coin = cpie.out_coin;
+                e.out_pc = cpie.out_pc;
+            }
+        }
+        DexEvent::RaydiumAmmV4Initialize2Event(e) => {
+            if let DexEvent::RaydiumAmmV4Initialize2Event(cpie) = cpi_log_event {
+                e.pc_decimals = cpie.pc_decimals;
+                e.coin_decimals = cpie.coin_decimals;
+                e.pc_lot_size = cpie.pc_lot_size;
+                e.coin_lot_size = cpie.coin_lot_size;
+            }
+        }
+
+        // Jupiter events
+        DexEvent::JupiterRouteEvent(e) => {
+            if let DexEvent::JupiterSwapEvent(cpie) = cpi_log_event {
+                if e.source_mint == Pubkey::default() {
+                    e.source_mint = cpie.input_mint;
+                }
+                e.hops.push(cpie.into());
+                // 指令中已给出的一侧（exact in 的输入、exact out 的输出）保持不变；
+                // 另一侧在拆单时有多条从源 mint 出发 / 到达目标 mint 的跳，分别累加
+                let (exact_in, exact_out) = match e.metadata.event_type {
+                    EventType::JupiterRoute
+                    | EventType::JupiterSharedAccountsRoute
+                    | EventType::JupiterRouteV2
+                    | EventType::JupiterSharedAccountsRouteV2 => (true, false),
+                    EventType::JupiterExactOutRoute
+                    | EventType::JupiterSharedAccountsExactOutRoute
+                    | EventType::JupiterExactOutRouteV2
+                    | EventType::JupiterSharedAccountsExactOutRouteV2 => (false, true),
+                    _ => (false, false),
+                };
+                if !exact_in {
+                    e.in_amount = e
+                        .hops
+                        .iter()
+                        .filter(|hop| hop.input_mint == e.source_mint)
+                        .map(|hop| hop.input_amount)
+                        .sum();
+                }
+                if !exact_out {
+                    e.out_amount = e
+                        .hops
+                        .iter()
+                        .filter(|hop| hop.output_mint == e.destination_mint)
+                        .map(|hop| hop.output_amount)
+                        .sum();
+                }
+                e.metadata.set_swap_data(SwapData {
+                    from_mint: e.source_mint,
+                    to_mint: e.destination_mint,
+                    from_amount: e.in_amount,
+                    to_amount: e.out_amount,
+                    description: None,
+                });
+            }
+        }
+
+        // Third-party protocol events
+        DexEvent::CustomEvent(e) => {
+            if let DexEvent::CustomEvent(cpie) = cpi_log_event {
+                if let Some(RegisteredParser::Custom(parser)) =
+                    custom_parser::find_by_name(&e.protocol)
+                {
+                    parser.merge(e, cpie);
+                }
+            }
+        }
+        DexEvent::IdlEvent(e) => {
+            if let DexEvent::IdlEvent(cpie) = cpi_log_event {
+                merge_idl_cpi_event(e, cpie);
+            }
+        }
 
         _ => {}
     }
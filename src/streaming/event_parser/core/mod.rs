@@ -1,9 +1,15 @@
 pub mod account_event_parser;
 pub mod common_event_parser;
+pub mod confirmed_block;
+pub mod custom_parser;
 pub mod dispatcher;
 pub mod global_state;
 pub mod parser_cache;
+pub mod program_logs;
+pub mod rpc_transaction;
+pub mod token_accounts;
 pub mod traits;
+pub mod transaction_input;
 
 pub use traits::DexEvent;
 pub use dispatcher::EventDispatcher;
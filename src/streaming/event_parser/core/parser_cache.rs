@@ -13,6 +13,10 @@ use crate::streaming::{
     event_parser::{
         common::{filter::EventTypeFilter, EventMetadata, EventType, ProtocolType},
         core::dispatcher::EventDispatcher,
+        protocols::{
+            pumpfun::parser::pumpfun_account_event_parse_configs,
+            pumpswap::parser::pumpswap_account_event_parse_configs,
+        },
         Protocol, DexEvent,
     },
     grpc::AccountPretty,
@@ -202,3 +206,41 @@ pub struct AccountEventParseConfig {
     pub account_parser: AccountEventParserFn,
 }
 
+/// 全局账户事件解析器配置
+///
+/// 收录不走协议判别器路由的账户：owner 不是协议程序本身（如 Pump fee program
+/// 持有的 FeeConfig / SharingConfig），或同一布局被多个协议共用。
+/// 同一 owner + 判别器可对应多个协议的配置，查找时按订阅顺序选取
+static ACCOUNT_EVENT_PARSE_CONFIGS: LazyLock<Vec<AccountEventParseConfig>> = LazyLock::new(|| {
+    let mut configs = pumpfun_account_event_parse_configs();
+    configs.extend(pumpswap_account_event_parse_configs());
+    configs
+});
+
+/// 获取全部已注册的账户事件解析器配置
+pub fn get_account_event_parse_configs() -> &'static [AccountEventParseConfig] {
+    &ACCOUNT_EVENT_PARSE_CONFIGS
+}
+
+/// 查找匹配 owner 与判别器、且协议在订阅列表中的账户事件解析器配置
+///
+/// 按订阅列表顺序返回，每个订阅协议至多一个配置；多个协议共用同一账户时
+/// （如同时订阅 PumpFun 与 PumpSwap 的 SharingConfig），事件归属排在前面的协议
+pub fn find_account_event_parse_configs<'a>(
+    protocols: &'a [Protocol],
+    owner: &'a Pubkey,
+    discriminator: &'a [u8],
+) -> impl Iterator<Item = &'static AccountEventParseConfig> + 'a {
+    protocols
+        .iter()
+        .enumerate()
+        .filter(move |(i, protocol)| !protocols[..*i].contains(protocol))
+        .filter_map(move |(_, protocol)| {
+            let protocol_type = ProtocolType::from(protocol);
+            ACCOUNT_EVENT_PARSE_CONFIGS.iter().find(|config| {
+                config.program_id == *owner
+                    && config.account_discriminator == discriminator
+                    && config.protocol_type == protocol_type
+            })
+        })
+}
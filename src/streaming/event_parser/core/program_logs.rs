@@ -0,0 +1,197 @@
+use crate::streaming::event_parser::{
+    common::{decode_program_data, EventMetadata},
+    DexEvent,
+};
+use solana_sdk::pubkey::Pubkey;
+use std::{collections::HashMap, str::FromStr};
+
+/// 单条指令执行期间输出的日志
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InstructionLogs<'a> {
+    pub program_id: Pubkey,
+    pub outer_index: i64,
+    pub inner_index: Option<i64>,
+    /// 原始日志行，仅保留 `Program log: ` 与 `Program data: `
+    pub logs: Vec<&'a str>,
+}
+
+/// 预编译程序：运行时直接校验，不输出 `invoke [1]` 日志
+const PRECOMPILE_PROGRAM_IDS: [Pubkey; 3] = [
+    solana_sdk::ed25519_program::ID,
+    solana_sdk::secp256k1_program::ID,
+    solana_sdk::pubkey!("Secp256r1SigVerify1111111111111111111111111"),
+];
+
+/// 按 invoke 深度归属到各指令的交易日志
+///
+/// `invoke [1]` 对应外层指令（跳过不输出日志的预编译指令），更深的 invoke 按出现顺序
+/// 对应 inner instruction，与 `inner_instructions` 的展开顺序一致。
+/// 日志被截断（`Log truncated`）后的指令不再记录。
+#[derive(Clone, Debug, Default)]
+pub struct ProgramLogs<'a> {
+    instructions: HashMap<(i64, Option<i64>), InstructionLogs<'a>>,
+}
+
+impl<'a> ProgramLogs<'a> {
+    /// `outer_program_ids` 为消息中外层指令的程序 ID，按指令顺序排列
+    pub fn new(
+        log_messages: &'a [String],
+        outer_program_ids: impl IntoIterator<Item = Pubkey>,
+    ) -> Self {
+        let outer_program_ids: Vec<Pubkey> = outer_program_ids.into_iter().collect();
+        let is_precompile = |index: i64| {
+            outer_program_ids
+                .get(index as usize)
+                .is_some_and(|program_id| PRECOMPILE_PROGRAM_IDS.contains(program_id))
+        };
+        let mut instructions: HashMap<(i64, Option<i64>), InstructionLogs<'a>> = HashMap::new();
+        // 当前调用栈，元素为 instructions 的键
+        let mut stack: Vec<(i64, Option<i64>)> = Vec::new();
+        let mut outer_index: i64 = -1;
+        let mut inner_count: i64 = 0;
+
+        for log in log_messages {
+            let log = log.as_str();
+            if log.starts_with("Program log: ") || log.starts_with("Program data: ") {
+                if let Some(instruction) = stack.last().and_then(|key| instructions.get_mut(key)) {
+                    instruction.logs.push(log);
+                }
+                continue;
+            }
+            if log == "Log truncated" {
+                break;
+            }
+            let mut parts = log.split_whitespace();
+            if parts.next() != Some("Program") {
+                continue;
+            }
+            let Some(program_id) = parts.next().and_then(|s| Pubkey::from_str(s).ok()) else {
+                continue;
+            };
+            match parts.next() {
+                Some("invoke") => {
+                    let depth = parts
+                        .next()
+                        .and_then(|s| s.strip_prefix('[')?.strip_suffix(']')?.parse::<u32>().ok())
+                        .unwrap_or(0);
+                    let inner_index = if depth <= 1 {
+                        outer_index += 1;
+                        while is_precompile(outer_index) {
+                            outer_index += 1;
+                        }
+                        inner_count = 0;
+                        stack.clear();
+                        None
+                    } else {
+                        inner_count += 1;
+                        Some(inner_count - 1)
+                    };
+                    stack.push((outer_index, inner_index));
+                    instructions.insert(
+                        (outer_index, inner_index),
+                        InstructionLogs { program_id, outer_index, inner_index, logs: Vec::new() },
+                    );
+                }
+                Some("success") | Some("failed:") => {
+                    stack.pop();
+                }
+                _ => {}
+            }
+        }
+
+        Self { instructions }
+    }
+
+    /// 获取指定指令的日志，program_id 不一致时视为无法归属
+    pub fn get(
+        &self,
+        program_id: &Pubkey,
+        outer_index: i64,
+        inner_index: Option<i64>,
+    ) -> Option<&[&'a str]> {
+        self.instructions
+            .get(&(outer_index, inner_index))
+            .filter(|i| &i.program_id == program_id)
+            .map(|i| i.logs.as_slice())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+}
+
+/// 依次解码指令的 `Program data:` 日志，返回全部解析成功的事件
+///
+/// 用于 Anchor `emit!` 事件：数据为 8 字节事件 discriminator + borsh 数据
+pub fn parse_program_data_logs(
+    logs: &[&str],
+    metadata: &EventMetadata,
+    parse: impl Fn(&[u8], EventMetadata) -> Option<DexEvent>,
+) -> Vec<DexEvent> {
+    logs.iter()
+        .filter_map(|log| decode_program_data(log))
+        .filter_map(|data| parse(&data, metadata.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_logs_attributed_by_invoke_depth() {
+        let amm = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+        let token = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+        let budget = "ComputeBudget111111111111111111111111111111";
+        let logs: Vec<String> = [
+            format!("Program {budget} invoke [1]"),
+            format!("Program {budget} success"),
+            format!("Program {amm} invoke [1]"),
+            "Program log: ray_log: outer".to_string(),
+            format!("Program {token} invoke [2]"),
+            "Program log: Instruction: Transfer".to_string(),
+            format!("Program {token} consumed 4645 of 180000 compute units"),
+            format!("Program {token} success"),
+            format!("Program {amm} invoke [2]"),
+            "Program log: ray_log: inner".to_string(),
+            format!("Program {amm} failed: custom program error: 0x1e"),
+        ]
+        .into_iter()
+        .collect();
+
+        let program_logs = ProgramLogs::new(&logs, []);
+        let amm = Pubkey::from_str(amm).unwrap();
+        let token = Pubkey::from_str(token).unwrap();
+
+        assert_eq!(program_logs.get(&amm, 1, None), Some(&["Program log: ray_log: outer"][..]));
+        assert_eq!(
+            program_logs.get(&token, 1, Some(0)),
+            Some(&["Program log: Instruction: Transfer"][..])
+        );
+        assert_eq!(program_logs.get(&amm, 1, Some(1)), Some(&["Program log: ray_log: inner"][..]));
+        // program_id 与指令不一致时不归属
+        assert_eq!(program_logs.get(&token, 1, None), None);
+        assert_eq!(program_logs.get(&amm, 2, None), None);
+    }
+
+    #[test]
+    fn test_precompile_instructions_are_skipped() {
+        let amm = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+        let logs: Vec<String> = vec![
+            format!("Program {amm} invoke [1]"),
+            "Program log: ray_log: first".to_string(),
+            format!("Program {amm} success"),
+            format!("Program {amm} invoke [1]"),
+            "Program log: ray_log: second".to_string(),
+            format!("Program {amm} success"),
+        ];
+        let amm = Pubkey::from_str(amm).unwrap();
+        // Ed25519 校验指令位于 0 号，不输出日志
+        let outer_program_ids = [solana_sdk::ed25519_program::ID, amm, amm];
+
+        let program_logs = ProgramLogs::new(&logs, outer_program_ids);
+        assert_eq!(program_logs.get(&amm, 0, None), None);
+        assert_eq!(program_logs.get(&amm, 1, None), Some(&["Program log: ray_log: first"][..]));
+        assert_eq!(program_logs.get(&amm, 2, None), Some(&["Program log: ray_log: second"][..]));
+    }
+}
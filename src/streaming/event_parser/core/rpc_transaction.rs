@@ -0,0 +1,966 @@
+use prost_types::Timestamp;
+use serde_json::Value;
+use solana_sdk::{
+    hash::Hash,
+    message::{
+        compiled_instruction::CompiledInstruction, v0, v0::MessageAddressTableLookup,
+        Message as LegacyMessage, MessageHeader, VersionedMessage,
+    },
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::VersionedTransaction,
+};
+use solana_transaction_status::{
+    option_serializer::OptionSerializer, parse_accounts::ParsedAccountSource,
+    parse_instruction::ParsedInstruction, EncodedConfirmedTransactionWithStatusMeta,
+    EncodedTransaction, EncodedTransactionWithStatusMeta, InnerInstruction, InnerInstructions,
+    UiAddressTableLookup, UiCompiledInstruction, UiInnerInstructions, UiInstruction,
+    UiLoadedAddresses, UiMessage, UiParsedInstruction, UiParsedMessage, UiRawMessage,
+    UiTransaction,
+};
+use std::str::FromStr;
+
+use crate::streaming::event_parser::{
+    core::token_accounts::TokenAccounts,
+    protocols::{spl_token::discriminators as token, system::discriminators as system},
+};
+
+/// 从 RPC `getTransaction` 结果中提取出的解析输入
+///
+/// 字段与 `EventParser::parse_instruction_events_from_versioned_transaction` 的参数一一对应
+#[derive(Debug, Clone)]
+pub struct RpcTransactionParts {
+    pub transaction: VersionedTransaction,
+    pub signature: Signature,
+    pub slot: u64,
+    pub block_time: Option<Timestamp>,
+    /// 静态账户 + 地址查找表加载的可写账户 + 只读账户
+    pub accounts: Vec<Pubkey>,
+    pub inner_instructions: Vec<InnerInstructions>,
+    pub log_messages: Vec<String>,
+    /// 来自 pre/post token balances，用于补全 SPL Token 事件的 mint 与 owner
+    pub token_accounts: TokenAccounts,
+    pub is_failed: bool,
+}
+
+impl RpcTransactionParts {
+    /// 转换 `getTransaction` 返回的交易，无法解码时返回 `None`
+    pub fn from_encoded(tx: &EncodedConfirmedTransactionWithStatusMeta) -> Option<Self> {
+        Self::from_transaction_with_meta(&tx.transaction, tx.slot, tx.block_time)
+    }
+
+    /// 转换单笔带 meta 的交易（`getTransaction` / `getBlock` 中的交易项）
+    ///
+    /// 支持 base58 / base64 / json / jsonParsed 编码，无法解码时返回 `None`
+    pub fn from_transaction_with_meta(
+        tx: &EncodedTransactionWithStatusMeta,
+        slot: u64,
+        block_time: Option<i64>,
+    ) -> Option<Self> {
+        let transaction = decode_transaction(&tx.transaction)?;
+        let signature = *transaction.signatures.first()?;
+        let meta = tx.meta.as_ref();
+
+        let accounts = if let EncodedTransaction::Json(UiTransaction {
+            message: UiMessage::Parsed(parsed),
+            ..
+        }) = &tx.transaction
+        {
+            // jsonParsed 的账户列表已包含地址查找表加载的账户，meta 中不再返回 loadedAddresses
+            parsed_account_keys(parsed)?
+        } else {
+            let mut accounts = transaction.message.static_account_keys().to_vec();
+            if let Some(OptionSerializer::Some(loaded)) = meta.map(|m| &m.loaded_addresses) {
+                accounts.extend(loaded_addresses(loaded));
+            }
+            accounts
+        };
+
+        let inner_instructions = match meta.map(|m| &m.inner_instructions) {
+            Some(OptionSerializer::Some(ui_inner)) => {
+                convert_inner_instructions(ui_inner, &accounts)
+            }
+            _ => Vec::new(),
+        };
+        let log_messages = match meta.map(|m| &m.log_messages) {
+            Some(OptionSerializer::Some(logs)) => logs.clone(),
+            _ => Vec::new(),
+        };
+        let token_accounts = TokenAccounts::new(
+            &accounts,
+            meta.into_iter()
+                .flat_map(|m| [&m.pre_token_balances, &m.post_token_balances])
+                .filter_map(|balances| match balances {
+                    OptionSerializer::Some(balances) => Some(balances),
+                    _ => None,
+                })
+                .flatten()
+                .map(|b| {
+                    let owner = match &b.owner {
+                        OptionSerializer::Some(owner) => owner.as_str(),
+                        _ => "",
+                    };
+                    (b.account_index as usize, b.mint.as_str(), owner)
+                }),
+        );
+
+        Some(Self {
+            transaction,
+            signature,
+            slot,
+            block_time: block_time.map(|t| Timestamp { seconds: t, nanos: 0 }),
+            accounts,
+            inner_instructions,
+            log_messages,
+            token_accounts,
+            is_failed: meta.is_some_and(|m| m.err.is_some()),
+        })
+    }
+}
+
+/// 解码交易，支持 base58 / base64 二进制编码与 json / jsonParsed 编码
+pub fn decode_transaction(encoded: &EncodedTransaction) -> Option<VersionedTransaction> {
+    match encoded {
+        EncodedTransaction::Json(ui_tx) => {
+            let message = match &ui_tx.message {
+                UiMessage::Raw(raw) => convert_raw_message(raw)?,
+                UiMessage::Parsed(parsed) => convert_parsed_message(parsed)?,
+            };
+            let signatures = ui_tx
+                .signatures
+                .iter()
+                .map(|s| Signature::from_str(s).ok())
+                .collect::<Option<Vec<_>>>()?;
+            Some(VersionedTransaction { signatures, message })
+        }
+        _ => encoded.decode(),
+    }
+}
+
+/// 地址查找表加载的账户，顺序为可写账户在前、只读账户在后
+pub fn loaded_addresses(loaded: &UiLoadedAddresses) -> impl Iterator<Item = Pubkey> + '_ {
+    loaded.writable.iter().chain(loaded.readonly.iter()).filter_map(|s| Pubkey::from_str(s).ok())
+}
+
+/// 将 RPC 返回的 inner instructions 转换为解析器使用的格式
+///
+/// `accounts` 为交易的完整账户列表，用于将 jsonParsed 格式中的账户地址还原为索引
+pub fn convert_inner_instructions(
+    ui_inner: &[UiInnerInstructions],
+    accounts: &[Pubkey],
+) -> Vec<InnerInstructions> {
+    ui_inner
+        .iter()
+        .map(|inner| InnerInstructions {
+            index: inner.index,
+            instructions: inner
+                .instructions
+                .iter()
+                .filter_map(|instruction| {
+                    Some(InnerInstruction {
+                        instruction: convert_instruction(instruction, accounts)?,
+                        stack_height: instruction_stack_height(instruction),
+                    })
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// 将 json 编码的 raw message 还原为 `VersionedMessage`
+///
+/// 带有 `addressTableLookups` 字段的消息视为 v0 消息，否则为 legacy 消息
+fn convert_raw_message(raw: &UiRawMessage) -> Option<VersionedMessage> {
+    let account_keys = raw
+        .account_keys
+        .iter()
+        .map(|key| Pubkey::from_str(key).ok())
+        .collect::<Option<Vec<_>>>()?;
+    let instructions =
+        raw.instructions.iter().map(convert_compiled_instruction).collect::<Option<Vec<_>>>()?;
+    build_message(
+        raw.header,
+        account_keys,
+        &raw.recent_blockhash,
+        instructions,
+        raw.address_table_lookups.as_deref(),
+    )
+}
+
+/// 将 jsonParsed 编码的 message 还原为 `VersionedMessage`
+///
+/// 消息头根据账户的 signer / writable 标记重新计算；已被 RPC 完整解析的指令只保留程序索引，
+/// 其中 SPL Token 转账和 System 转账会重新编码，以便提取 swap_data
+fn convert_parsed_message(parsed: &UiParsedMessage) -> Option<VersionedMessage> {
+    let accounts = parsed_account_keys(parsed)?;
+    let static_keys: Vec<_> = parsed
+        .account_keys
+        .iter()
+        .zip(&accounts)
+        .filter(|(account, _)| account.source != Some(ParsedAccountSource::LookupTable))
+        .collect();
+    let header = MessageHeader {
+        num_required_signatures: static_keys.iter().filter(|(a, _)| a.signer).count() as u8,
+        num_readonly_signed_accounts: static_keys
+            .iter()
+            .filter(|(a, _)| a.signer && !a.writable)
+            .count() as u8,
+        num_readonly_unsigned_accounts: static_keys
+            .iter()
+            .filter(|(a, _)| !a.signer && !a.writable)
+            .count() as u8,
+    };
+    let instructions = parsed
+        .instructions
+        .iter()
+        .map(|instruction| convert_instruction(instruction, &accounts))
+        .collect::<Option<Vec<_>>>()?;
+    build_message(
+        header,
+        static_keys.into_iter().map(|(_, key)| *key).collect(),
+        &parsed.recent_blockhash,
+        instructions,
+        parsed.address_table_lookups.as_deref(),
+    )
+}
+
+fn build_message(
+    header: MessageHeader,
+    account_keys: Vec<Pubkey>,
+    recent_blockhash: &str,
+    instructions: Vec<CompiledInstruction>,
+    address_table_lookups: Option<&[UiAddressTableLookup]>,
+) -> Option<VersionedMessage> {
+    let recent_blockhash = Hash::from_str(recent_blockhash).ok()?;
+    Some(match address_table_lookups {
+        Some(lookups) => VersionedMessage::V0(v0::Message {
+            header,
+            account_keys,
+            recent_blockhash,
+            instructions,
+            address_table_lookups: lookups
+                .iter()
+                .map(|lookup| {
+                    Some(MessageAddressTableLookup {
+                        account_key: Pubkey::from_str(&lookup.account_key).ok()?,
+                        writable_indexes: lookup.writable_indexes.clone(),
+                        readonly_indexes: lookup.readonly_indexes.clone(),
+                    })
+                })
+                .collect::<Option<Vec<_>>>()?,
+        }),
+        None => VersionedMessage::Legacy(LegacyMessage {
+            header,
+            account_keys,
+            recent_blockhash,
+            instructions,
+        }),
+    })
+}
+
+/// jsonParsed 消息的完整账户列表（静态账户 + 地址查找表账户）
+fn parsed_account_keys(parsed: &UiParsedMessage) -> Option<Vec<Pubkey>> {
+    parsed.account_keys.iter().map(|account| Pubkey::from_str(&account.pubkey).ok()).collect()
+}
+
+fn convert_compiled_instruction(compiled: &UiCompiledInstruction) -> Option<CompiledInstruction> {
+    Some(CompiledInstruction {
+        program_id_index: compiled.program_id_index,
+        accounts: compiled.accounts.clone(),
+        data: solana_sdk::bs58::decode(&compiled.data).into_vec().ok()?,
+    })
+}
+
+fn convert_instruction(
+    instruction: &UiInstruction,
+    accounts: &[Pubkey],
+) -> Option<CompiledInstruction> {
+    match instruction {
+        UiInstruction::Compiled(compiled) => convert_compiled_instruction(compiled),
+        UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(decoded)) => {
+            Some(CompiledInstruction {
+                program_id_index: account_index(accounts, &decoded.program_id)?,
+                accounts: decoded
+                    .accounts
+                    .iter()
+                    .map(|key| account_index(accounts, key))
+                    .collect::<Option<Vec<_>>>()?,
+                data: solana_sdk::bs58::decode(&decoded.data).into_vec().ok()?,
+            })
+        }
+        UiInstruction::Parsed(UiParsedInstruction::Parsed(parsed)) => {
+            let (instruction_accounts, data) =
+                encode_parsed_instruction(parsed, accounts).unwrap_or_default();
+            Some(CompiledInstruction {
+                program_id_index: account_index(accounts, &parsed.program_id)?,
+                accounts: instruction_accounts,
+                data,
+            })
+        }
+    }
+}
+
+fn instruction_stack_height(instruction: &UiInstruction) -> Option<u32> {
+    match instruction {
+        UiInstruction::Compiled(compiled) => compiled.stack_height,
+        UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(decoded)) => {
+            decoded.stack_height
+        }
+        UiInstruction::Parsed(UiParsedInstruction::Parsed(parsed)) => parsed.stack_height,
+    }
+}
+
+fn account_index(accounts: &[Pubkey], key: &str) -> Option<u8> {
+    let key = Pubkey::from_str(key).ok()?;
+    accounts.iter().position(|account| *account == key).map(|index| index as u8)
+}
+
+/// 将 RPC 已解析（jsonParsed）的指令重新编码为原始指令（账户索引, 指令数据）
+///
+/// 覆盖 `Protocol::SplToken` 与 `Protocol::System` 解析的全部指令，其余指令返回 `None`
+fn encode_parsed_instruction(
+    parsed: &ParsedInstruction,
+    accounts: &[Pubkey],
+) -> Option<(Vec<u8>, Vec<u8>)> {
+    let instruction_type = parsed.parsed.get("type")?.as_str()?;
+    let info = ParsedInfo { info: parsed.parsed.get("info")?, accounts };
+    match parsed.program.as_str() {
+        "spl-token" | "spl-token-2022" => encode_parsed_token_instruction(instruction_type, &info),
+        "system" => encode_parsed_system_instruction(instruction_type, &info),
+        "address-lookup-table" => encode_parsed_lookup_table_instruction(instruction_type, &info),
+        _ => None,
+    }
+}
+
+/// jsonParsed 指令的 `info` 字段
+struct ParsedInfo<'a> {
+    info: &'a Value,
+    accounts: &'a [Pubkey],
+}
+
+impl ParsedInfo<'_> {
+    fn str(&self, field: &str) -> Option<&str> {
+        self.info.get(field)?.as_str()
+    }
+
+    fn pubkey(&self, field: &str) -> Option<Pubkey> {
+        Pubkey::from_str(self.str(field)?).ok()
+    }
+
+    /// 账户在交易账户列表中的索引
+    fn index(&self, field: &str) -> Option<u8> {
+        account_index(self.accounts, self.str(field)?)
+    }
+
+    /// 数字或字符串形式的整数（token 数量为字符串）
+    fn u64(&self, field: &str) -> Option<u64> {
+        parse_u64(self.info.get(field)?)
+    }
+
+    /// `UiTokenAmount` 中的原始数量与精度
+    fn token_amount(&self, field: &str) -> Option<(u64, u8)> {
+        let amount = self.info.get(field)?;
+        Some((parse_u64(amount.get("amount")?)?, amount.get("decimals")?.as_u64()? as u8))
+    }
+
+    /// 签名账户：单签为 `owner_field`，多签为 `multisig_field` 与 `signers`
+    fn signers(&self, owner_field: &str, multisig_field: &str) -> Option<Vec<u8>> {
+        if let Some(owner) = self.index(owner_field) {
+            return Some(vec![owner]);
+        }
+        let mut indexes = vec![self.index(multisig_field)?];
+        for signer in self.info.get("signers")?.as_array()? {
+            indexes.push(account_index(self.accounts, signer.as_str()?)?);
+        }
+        Some(indexes)
+    }
+
+    /// 多个账户字段的索引，`optional` 中缺失的字段被跳过
+    fn indexes(&self, required: &[&str], optional: &[&str]) -> Option<Vec<u8>> {
+        let mut indexes =
+            required.iter().map(|field| self.index(field)).collect::<Option<Vec<_>>>()?;
+        indexes.extend(optional.iter().filter_map(|field| self.index(field)));
+        Some(indexes)
+    }
+}
+
+fn parse_u64(value: &Value) -> Option<u64> {
+    value.as_u64().or_else(|| value.as_str()?.parse().ok())
+}
+
+/// bincode 字符串：u64 长度 + UTF-8 字节
+fn push_string(data: &mut Vec<u8>, value: &str) {
+    data.extend_from_slice(&(value.len() as u64).to_le_bytes());
+    data.extend_from_slice(value.as_bytes());
+}
+
+fn encode_parsed_token_instruction(
+    instruction_type: &str,
+    info: &ParsedInfo,
+) -> Option<(Vec<u8>, Vec<u8>)> {
+    let with_signers = |mut accounts: Vec<u8>, owner: &str, multisig: &str| {
+        accounts.extend(info.signers(owner, multisig)?);
+        Some(accounts)
+    };
+    let amount_data = |discriminator: &[u8], amount: u64| {
+        let mut data = discriminator.to_vec();
+        data.extend_from_slice(&amount.to_le_bytes());
+        data
+    };
+    let checked_data = |discriminator: &[u8]| {
+        let (amount, decimals) = info.token_amount("tokenAmount")?;
+        let mut data = amount_data(discriminator, amount);
+        data.push(decimals);
+        Some(data)
+    };
+    let authority = |accounts: &[&str]| {
+        with_signers(info.indexes(accounts, &[])?, "authority", "multisigAuthority")
+    };
+    let owner =
+        |accounts: &[&str]| with_signers(info.indexes(accounts, &[])?, "owner", "multisigOwner");
+
+    match instruction_type {
+        "transfer" => Some((
+            authority(&["source", "destination"])?,
+            amount_data(token::TRANSFER, info.u64("amount")?),
+        )),
+        "transferChecked" => Some((
+            authority(&["source", "mint", "destination"])?,
+            checked_data(token::TRANSFER_CHECKED)?,
+        )),
+        "transferCheckedWithFee" => {
+            let mut data = token::TRANSFER_FEE_EXTENSION.to_vec();
+            data.push(token::TRANSFER_CHECKED_WITH_FEE);
+            data.extend(checked_data(&[])?);
+            data.extend_from_slice(&info.token_amount("feeAmount")?.0.to_le_bytes());
+            Some((authority(&["source", "mint", "destination"])?, data))
+        }
+        "mintTo" | "mintToChecked" => {
+            let accounts = with_signers(
+                info.indexes(&["mint", "account"], &[])?,
+                "mintAuthority",
+                "multisigMintAuthority",
+            )?;
+            let data = match instruction_type {
+                "mintTo" => amount_data(token::MINT_TO, info.u64("amount")?),
+                _ => checked_data(token::MINT_TO_CHECKED)?,
+            };
+            Some((accounts, data))
+        }
+        "burn" => {
+            Some((authority(&["account", "mint"])?, amount_data(token::BURN, info.u64("amount")?)))
+        }
+        "burnChecked" => {
+            Some((authority(&["account", "mint"])?, checked_data(token::BURN_CHECKED)?))
+        }
+        "closeAccount" => {
+            Some((owner(&["account", "destination"])?, token::CLOSE_ACCOUNT.to_vec()))
+        }
+        "approve" => Some((
+            owner(&["source", "delegate"])?,
+            amount_data(token::APPROVE, info.u64("amount")?),
+        )),
+        "approveChecked" => {
+            Some((owner(&["source", "mint", "delegate"])?, checked_data(token::APPROVE_CHECKED)?))
+        }
+        "setAuthority" => {
+            // mint 类权限的账户字段为 `mint`，token 账户类为 `account`
+            let account = info.index("mint").or_else(|| info.index("account"))?;
+            let mut data = token::SET_AUTHORITY.to_vec();
+            data.push(authority_type(info.str("authorityType")?)?);
+            match info.info.get("newAuthority").and_then(Value::as_str) {
+                Some(new_authority) => {
+                    data.push(1);
+                    data.extend_from_slice(Pubkey::from_str(new_authority).ok()?.as_ref());
+                }
+                None => data.push(0),
+            }
+            Some((with_signers(vec![account], "authority", "multisigAuthority")?, data))
+        }
+        "initializeAccount" => Some((
+            info.indexes(&["account", "mint", "owner"], &["rentSysvar"])?,
+            token::INITIALIZE_ACCOUNT.to_vec(),
+        )),
+        "initializeAccount2" | "initializeAccount3" => {
+            let mut data = match instruction_type {
+                "initializeAccount2" => token::INITIALIZE_ACCOUNT2.to_vec(),
+                _ => token::INITIALIZE_ACCOUNT3.to_vec(),
+            };
+            data.extend_from_slice(info.pubkey("owner")?.as_ref());
+            Some((info.indexes(&["account", "mint"], &["rentSysvar"])?, data))
+        }
+        _ => None,
+    }
+}
+
+/// `UiAuthorityType` 对应的 `AuthorityType` 编号
+fn authority_type(name: &str) -> Option<u8> {
+    const AUTHORITY_TYPES: [&str; 17] = [
+        "mintTokens",
+        "freezeAccount",
+        "accountOwner",
+        "closeAccount",
+        "transferFeeConfig",
+        "withheldWithdraw",
+        "closeMint",
+        "interestRate",
+        "permanentDelegate",
+        "confidentialTransferMint",
+        "transferHookProgramId",
+        "confidentialTransferFeeConfig",
+        "metadataPointer",
+        "groupPointer",
+        "groupMemberPointer",
+        "scaledUiAmount",
+        "pause",
+    ];
+    AUTHORITY_TYPES.iter().position(|t| *t == name).map(|index| index as u8)
+}
+
+fn encode_parsed_system_instruction(
+    instruction_type: &str,
+    info: &ParsedInfo,
+) -> Option<(Vec<u8>, Vec<u8>)> {
+    let lamports_space_owner = |data: &mut Vec<u8>| {
+        data.extend_from_slice(&info.u64("lamports")?.to_le_bytes());
+        data.extend_from_slice(&info.u64("space")?.to_le_bytes());
+        data.extend_from_slice(info.pubkey("owner")?.as_ref());
+        Some(())
+    };
+
+    match instruction_type {
+        "transfer" => {
+            let mut data = system::TRANSFER.to_vec();
+            data.extend_from_slice(&info.u64("lamports")?.to_le_bytes());
+            Some((info.indexes(&["source", "destination"], &[])?, data))
+        }
+        "createAccount" => {
+            let mut data = system::CREATE_ACCOUNT.to_vec();
+            lamports_space_owner(&mut data)?;
+            Some((info.indexes(&["source", "newAccount"], &[])?, data))
+        }
+        "createAccountWithSeed" => {
+            let mut data = system::CREATE_ACCOUNT_WITH_SEED.to_vec();
+            data.extend_from_slice(info.pubkey("base")?.as_ref());
+            push_string(&mut data, info.str("seed")?);
+            lamports_space_owner(&mut data)?;
+            let mut accounts = info.indexes(&["source", "newAccount"], &[])?;
+            // base 与出资账户不同时作为第三个签名账户
+            if info.str("base") != info.str("source") {
+                accounts.extend(info.index("base"));
+            }
+            Some((accounts, data))
+        }
+        "transferWithSeed" => {
+            let mut data = system::TRANSFER_WITH_SEED.to_vec();
+            data.extend_from_slice(&info.u64("lamports")?.to_le_bytes());
+            push_string(&mut data, info.str("sourceSeed")?);
+            data.extend_from_slice(info.pubkey("sourceOwner")?.as_ref());
+            Some((info.indexes(&["source", "sourceBase", "destination"], &[])?, data))
+        }
+        "advanceNonce" => Some((
+            info.indexes(&["nonceAccount", "recentBlockhashesSysvar", "nonceAuthority"], &[])?,
+            system::ADVANCE_NONCE_ACCOUNT.to_vec(),
+        )),
+        "authorizeNonce" => {
+            let mut data = system::AUTHORIZE_NONCE_ACCOUNT.to_vec();
+            data.extend_from_slice(info.pubkey("newAuthorized")?.as_ref());
+            Some((info.indexes(&["nonceAccount", "nonceAuthority"], &[])?, data))
+        }
+        _ => None,
+    }
+}
+
+fn encode_parsed_lookup_table_instruction(
+    instruction_type: &str,
+    info: &ParsedInfo,
+) -> Option<(Vec<u8>, Vec<u8>)> {
+    const TABLE: [&str; 2] = ["lookupTableAccount", "lookupTableAuthority"];
+    match instruction_type {
+        "createLookupTable" => {
+            let mut data = system::CREATE_LOOKUP_TABLE.to_vec();
+            data.extend_from_slice(&info.u64("recentSlot")?.to_le_bytes());
+            data.push(info.u64("bumpSeed")? as u8);
+            Some((info.indexes(&[TABLE[0], TABLE[1], "payerAccount"], &["systemProgram"])?, data))
+        }
+        "extendLookupTable" => {
+            let new_addresses = info.info.get("newAddresses")?.as_array()?;
+            let mut data = system::EXTEND_LOOKUP_TABLE.to_vec();
+            data.extend_from_slice(&(new_addresses.len() as u64).to_le_bytes());
+            for address in new_addresses {
+                data.extend_from_slice(Pubkey::from_str(address.as_str()?).ok()?.as_ref());
+            }
+            Some((info.indexes(&TABLE, &["payerAccount", "systemProgram"])?, data))
+        }
+        "freezeLookupTable" => {
+            Some((info.indexes(&TABLE, &[])?, system::FREEZE_LOOKUP_TABLE.to_vec()))
+        }
+        "deactivateLookupTable" => {
+            Some((info.indexes(&TABLE, &[])?, system::DEACTIVATE_LOOKUP_TABLE.to_vec()))
+        }
+        "closeLookupTable" => Some((
+            info.indexes(&[TABLE[0], TABLE[1], "recipient"], &[])?,
+            system::CLOSE_LOOKUP_TABLE.to_vec(),
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        common::EventType,
+        core::event_parser::EventParser,
+        protocols::{
+            spl_token::parser::{SPL_TOKEN_2022_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID},
+            system::parser::{ADDRESS_LOOKUP_TABLE_PROGRAM_ID, SYSTEM_PROGRAM_ID},
+        },
+        DexEvent, Protocol,
+    };
+    use serde_json::json;
+
+    /// 由 jsonParsed 指令构造交易，`accounts[0]` 为付款签名账户
+    fn parsed_transaction(
+        accounts: &[String],
+        instructions: Vec<Value>,
+    ) -> EncodedConfirmedTransactionWithStatusMeta {
+        let account_keys: Vec<Value> = accounts
+            .iter()
+            .enumerate()
+            .map(|(index, key)| {
+                json!({"pubkey": key, "writable": true, "signer": index == 0, "source": "transaction"})
+            })
+            .collect();
+        serde_json::from_value(json!({
+            "slot": 7,
+            "blockTime": null,
+            "transaction": {
+                "signatures": [Signature::from([5u8; 64]).to_string()],
+                "message": {
+                    "accountKeys": account_keys,
+                    "recentBlockhash": Hash::default().to_string(),
+                    "instructions": instructions,
+                },
+            },
+            "meta": {"err": null, "status": {"Ok": null}, "fee": 5000,
+                     "preBalances": [], "postBalances": []},
+        }))
+        .unwrap()
+    }
+
+    fn parsed_instruction(program: &str, program_id: &str, kind: &str, info: Value) -> Value {
+        json!({
+            "program": program,
+            "programId": program_id,
+            "parsed": {"type": kind, "info": info},
+            "stackHeight": null,
+        })
+    }
+
+    /// 重新编码后的（账户索引, 指令数据）
+    fn compiled_instructions(
+        tx: &EncodedConfirmedTransactionWithStatusMeta,
+    ) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let parts = RpcTransactionParts::from_encoded(tx).unwrap();
+        parts
+            .transaction
+            .message
+            .instructions()
+            .iter()
+            .map(|instruction| (instruction.accounts.clone(), instruction.data.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_json_parsed_token_instructions() {
+        let keys: Vec<Pubkey> = (0..7).map(|_| Pubkey::new_unique()).collect();
+        let (payer, mint, account, destination, multisig, signer, new_owner) =
+            (keys[0], keys[1], keys[2], keys[3], keys[4], keys[5], keys[6]);
+        let mut accounts: Vec<String> = keys.iter().map(Pubkey::to_string).collect();
+        let (token_program, token_2022_program) =
+            (SPL_TOKEN_PROGRAM_ID.to_string(), SPL_TOKEN_2022_PROGRAM_ID.to_string());
+        accounts.extend([token_program.clone(), token_2022_program.clone()]);
+        let token =
+            |kind: &str, info: Value| parsed_instruction("spl-token", &token_program, kind, info);
+        let token_amount =
+            |amount: &str, decimals: u8| json!({"amount": amount, "decimals": decimals});
+
+        let tx = parsed_transaction(
+            &accounts,
+            vec![
+                token(
+                    "mintTo",
+                    json!({"mint": mint.to_string(), "account": account.to_string(), "amount": "1000",
+                           "multisigMintAuthority": multisig.to_string(),
+                           "signers": [payer.to_string(), signer.to_string()]}),
+                ),
+                token(
+                    "burnChecked",
+                    json!({"account": account.to_string(), "mint": mint.to_string(),
+                           "tokenAmount": token_amount("25", 6), "authority": payer.to_string()}),
+                ),
+                token(
+                    "closeAccount",
+                    json!({"account": account.to_string(), "destination": destination.to_string(),
+                           "owner": payer.to_string()}),
+                ),
+                token(
+                    "setAuthority",
+                    json!({"account": account.to_string(), "authorityType": "accountOwner",
+                           "newAuthority": new_owner.to_string(), "authority": payer.to_string()}),
+                ),
+                token(
+                    "initializeAccount3",
+                    json!({"account": account.to_string(), "mint": mint.to_string(),
+                           "owner": new_owner.to_string()}),
+                ),
+                parsed_instruction(
+                    "spl-token",
+                    &token_2022_program,
+                    "transferCheckedWithFee",
+                    json!({"source": account.to_string(), "mint": mint.to_string(),
+                           "destination": destination.to_string(), "tokenAmount": token_amount("500", 6),
+                           "feeAmount": token_amount("5", 6), "authority": payer.to_string()}),
+                ),
+            ],
+        );
+
+        let le = |value: u64| value.to_le_bytes().to_vec();
+        let set_authority = [vec![6, 2, 1], new_owner.to_bytes().to_vec()].concat();
+        let initialize = [vec![18], new_owner.to_bytes().to_vec()].concat();
+        let transfer_with_fee = [vec![26, 1], le(500), vec![6], le(5)].concat();
+        assert_eq!(
+            compiled_instructions(&tx),
+            vec![
+                (vec![1, 2, 4, 0, 5], [vec![7], le(1000)].concat()),
+                (vec![2, 1, 0], [vec![15], le(25), vec![6]].concat()),
+                (vec![2, 3, 0], vec![9]),
+                (vec![2, 0], set_authority),
+                (vec![2, 1], initialize),
+                (vec![2, 1, 3, 0], transfer_with_fee),
+            ]
+        );
+
+        let events =
+            EventParser::parse_rpc_transaction(&[Protocol::SplToken], None, &tx, None).unwrap();
+        let event_types: Vec<_> = events.iter().map(|e| e.metadata().event_type.clone()).collect();
+        assert_eq!(
+            event_types,
+            vec![
+                EventType::SplTokenMintTo,
+                EventType::SplTokenBurnChecked,
+                EventType::SplTokenCloseAccount,
+                EventType::SplTokenSetAuthority,
+                EventType::SplTokenInitializeAccount3,
+                EventType::SplTokenTransferCheckedWithFee,
+            ]
+        );
+        let DexEvent::SplTokenMintToEvent(mint_to) = &events[0] else { panic!("expected mintTo") };
+        assert_eq!((mint_to.mint_authority, mint_to.amount), (multisig, 1000));
+    }
+
+    #[test]
+    fn test_json_parsed_system_instructions() {
+        let keys: Vec<Pubkey> = (0..9).map(|_| Pubkey::new_unique()).collect();
+        let (payer, new_account, base, destination, nonce, sysvar, new_authority, table, owner) =
+            (keys[0], keys[1], keys[2], keys[3], keys[4], keys[5], keys[6], keys[7], keys[8]);
+        let mut accounts: Vec<String> = keys.iter().map(Pubkey::to_string).collect();
+        let (system_program, lookup_table_program) =
+            (SYSTEM_PROGRAM_ID.to_string(), ADDRESS_LOOKUP_TABLE_PROGRAM_ID.to_string());
+        accounts.extend([system_program.clone(), lookup_table_program.clone()]);
+        let system =
+            |kind: &str, info: Value| parsed_instruction("system", &system_program, kind, info);
+        let lookup_table = |kind: &str, info: Value| {
+            parsed_instruction("address-lookup-table", &lookup_table_program, kind, info)
+        };
+
+        let tx = parsed_transaction(
+            &accounts,
+            vec![
+                system(
+                    "createAccount",
+                    json!({"source": payer.to_string(), "newAccount": new_account.to_string(),
+                           "lamports": 2_000_000, "space": 165, "owner": owner.to_string()}),
+                ),
+                system(
+                    "createAccountWithSeed",
+                    json!({"source": payer.to_string(), "newAccount": new_account.to_string(),
+                           "base": base.to_string(), "seed": "vault", "lamports": 1_000,
+                           "space": 0, "owner": owner.to_string()}),
+                ),
+                system(
+                    "transferWithSeed",
+                    json!({"source": new_account.to_string(), "sourceBase": base.to_string(),
+                           "destination": destination.to_string(), "lamports": 500,
+                           "sourceSeed": "vault", "sourceOwner": owner.to_string()}),
+                ),
+                system(
+                    "advanceNonce",
+                    json!({"nonceAccount": nonce.to_string(),
+                           "recentBlockhashesSysvar": sysvar.to_string(),
+                           "nonceAuthority": payer.to_string()}),
+                ),
+                system(
+                    "authorizeNonce",
+                    json!({"nonceAccount": nonce.to_string(), "nonceAuthority": payer.to_string(),
+                           "newAuthorized": new_authority.to_string()}),
+                ),
+                lookup_table(
+                    "createLookupTable",
+                    json!({"lookupTableAccount": table.to_string(),
+                           "lookupTableAuthority": payer.to_string(),
+                           "payerAccount": payer.to_string(), "systemProgram": system_program,
+                           "recentSlot": 99, "bumpSeed": 254}),
+                ),
+                lookup_table(
+                    "extendLookupTable",
+                    json!({"lookupTableAccount": table.to_string(),
+                           "lookupTableAuthority": payer.to_string(),
+                           "payerAccount": payer.to_string(), "systemProgram": system_program,
+                           "newAddresses": [owner.to_string(), destination.to_string()]}),
+                ),
+                lookup_table(
+                    "closeLookupTable",
+                    json!({"lookupTableAccount": table.to_string(),
+                           "lookupTableAuthority": payer.to_string(),
+                           "recipient": destination.to_string()}),
+                ),
+            ],
+        );
+
+        let le = |value: u64| value.to_le_bytes().to_vec();
+        let tag = |value: u32| value.to_le_bytes().to_vec();
+        let seed = [le(5), b"vault".to_vec()].concat();
+        let key = |key: Pubkey| key.to_bytes().to_vec();
+        assert_eq!(
+            compiled_instructions(&tx),
+            vec![
+                (vec![0, 1], [tag(0), le(2_000_000), le(165), key(owner)].concat()),
+                (
+                    vec![0, 1, 2],
+                    [tag(3), key(base), seed.clone(), le(1_000), le(0), key(owner)].concat()
+                ),
+                (vec![1, 2, 3], [tag(11), le(500), seed, key(owner)].concat()),
+                (vec![4, 5, 0], tag(4)),
+                (vec![4, 0], [tag(7), key(new_authority)].concat()),
+                (vec![7, 0, 0, 9], [tag(0), le(99), vec![254]].concat()),
+                (vec![7, 0, 0, 9], [tag(2), le(2), key(owner), key(destination)].concat()),
+                (vec![7, 0, 3], tag(4)),
+            ]
+        );
+
+        let events =
+            EventParser::parse_rpc_transaction(&[Protocol::System], None, &tx, None).unwrap();
+        let event_types: Vec<_> = events.iter().map(|e| e.metadata().event_type.clone()).collect();
+        assert_eq!(
+            event_types,
+            vec![
+                EventType::SystemCreateAccount,
+                EventType::SystemCreateAccountWithSeed,
+                EventType::SystemTransferWithSeed,
+                EventType::SystemAdvanceNonce,
+                EventType::SystemAuthorizeNonce,
+                EventType::AddressLookupTableCreate,
+                EventType::AddressLookupTableExtend,
+                EventType::AddressLookupTableClose,
+            ]
+        );
+        let DexEvent::AddressLookupTableExtendEvent(extend) = &events[6] else {
+            panic!("expected extendLookupTable")
+        };
+        assert_eq!(extend.new_addresses, vec![owner, destination]);
+    }
+
+    #[test]
+    fn test_json_parsed_transaction() {
+        let payer = Pubkey::new_unique();
+        let (source, destination, lookup_table, loaded) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let compute_budget = "ComputeBudget111111111111111111111111111111";
+        let token_program = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+        let account = |key: String, writable: bool, signer: bool, source: &str| json!({"pubkey": key, "writable": writable, "signer": signer, "source": source});
+        let transfer = |from: Pubkey, to: Pubkey, amount: &str| {
+            json!({
+                "program": "spl-token",
+                "programId": token_program,
+                "parsed": {
+                    "type": "transfer",
+                    "info": {"source": from.to_string(), "destination": to.to_string(),
+                             "authority": payer.to_string(), "amount": amount},
+                },
+                "stackHeight": null,
+            })
+        };
+        let mut price = vec![3u8];
+        price.extend_from_slice(&42u64.to_le_bytes());
+
+        let tx: EncodedConfirmedTransactionWithStatusMeta = serde_json::from_value(json!({
+            "slot": 123,
+            "blockTime": 1_700_000_000,
+            "version": 0,
+            "transaction": {
+                "signatures": [Signature::from([9u8; 64]).to_string()],
+                "message": {
+                    "accountKeys": [
+                        account(payer.to_string(), true, true, "transaction"),
+                        account(source.to_string(), true, false, "transaction"),
+                        account(destination.to_string(), true, false, "transaction"),
+                        account(compute_budget.to_string(), false, false, "transaction"),
+                        account(token_program.to_string(), false, false, "transaction"),
+                        account(loaded.to_string(), true, false, "lookupTable"),
+                    ],
+                    "recentBlockhash": Hash::default().to_string(),
+                    "instructions": [
+                        {"programId": compute_budget, "accounts": [],
+                         "data": solana_sdk::bs58::encode(&price).into_string(), "stackHeight": null},
+                        transfer(source, destination, "500"),
+                    ],
+                    "addressTableLookups": [{
+                        "accountKey": lookup_table.to_string(),
+                        "writableIndexes": [0],
+                        "readonlyIndexes": [],
+                    }],
+                },
+            },
+            "meta": {
+                "err": null,
+                "status": {"Ok": null},
+                "fee": 5000,
+                "preBalances": [],
+                "postBalances": [],
+                "innerInstructions": [{"index": 1, "instructions": [transfer(loaded, source, "7")]}],
+                "logMessages": ["Program log: hello"],
+            },
+        }))
+        .unwrap();
+
+        let parts = RpcTransactionParts::from_encoded(&tx).unwrap();
+        assert_eq!(parts.accounts.len(), 6);
+        assert_eq!(parts.accounts[5], loaded);
+        assert_eq!(parts.log_messages, vec!["Program log: hello".to_string()]);
+        let VersionedMessage::V0(message) = &parts.transaction.message else {
+            panic!("expected v0 message")
+        };
+        assert_eq!(message.account_keys.len(), 5);
+        assert_eq!(
+            (message.header.num_required_signatures, message.header.num_readonly_unsigned_accounts),
+            (1, 2)
+        );
+        let mut transfer_data = vec![3u8];
+        transfer_data.extend_from_slice(&500u64.to_le_bytes());
+        assert_eq!(message.instructions[1].data, transfer_data);
+        assert_eq!(message.instructions[1].accounts, vec![1, 2, 0]);
+        assert_eq!(parts.inner_instructions[0].instructions[0].instruction.accounts, vec![5, 1, 0]);
+
+        let events =
+            EventParser::parse_rpc_transaction(&[Protocol::PumpFun], None, &tx, None).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].metadata().slot, 123);
+        assert_eq!(events[0].metadata().block_time, 1_700_000_000);
+    }
+}
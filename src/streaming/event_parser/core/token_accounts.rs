@@ -0,0 +1,75 @@
+use solana_sdk::pubkey::Pubkey;
+use std::{collections::HashMap, str::FromStr};
+
+/// token 账户的 mint 与 owner
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TokenAccountInfo {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+}
+
+/// 交易涉及的 token 账户，来自交易 meta 的 pre/post token balances
+///
+/// 用于补全 SPL Token 指令中不携带的 mint 与 owner（如 Transfer、Approve、CloseAccount）
+#[derive(Clone, Debug, Default)]
+pub struct TokenAccounts {
+    accounts: HashMap<Pubkey, TokenAccountInfo>,
+}
+
+impl TokenAccounts {
+    /// `balances` 为 (账户下标, mint, owner)，下标对应静态账户 + 地址查找表加载的账户
+    ///
+    /// owner 缺失（旧版本节点）时记为默认值
+    pub fn new<'a>(
+        accounts: &[Pubkey],
+        balances: impl IntoIterator<Item = (usize, &'a str, &'a str)>,
+    ) -> Self {
+        let accounts = balances
+            .into_iter()
+            .filter_map(|(index, mint, owner)| {
+                let account = *accounts.get(index)?;
+                let mint = Pubkey::from_str(mint).ok()?;
+                let owner = Pubkey::from_str(owner).unwrap_or_default();
+                Some((account, TokenAccountInfo { mint, owner }))
+            })
+            .collect();
+        Self { accounts }
+    }
+
+    /// 从 gRPC 交易 meta 构建
+    pub fn from_grpc_meta(
+        accounts: &[Pubkey],
+        meta: &yellowstone_grpc_proto::prelude::TransactionStatusMeta,
+    ) -> Self {
+        Self::new(
+            accounts,
+            meta.pre_token_balances
+                .iter()
+                .chain(&meta.post_token_balances)
+                .map(|b| (b.account_index as usize, b.mint.as_str(), b.owner.as_str())),
+        )
+    }
+
+    /// 从 `TransactionStatusMeta` 构建
+    pub fn from_meta(
+        accounts: &[Pubkey],
+        meta: &solana_transaction_status::TransactionStatusMeta,
+    ) -> Self {
+        Self::new(
+            accounts,
+            meta.pre_token_balances
+                .iter()
+                .chain(&meta.post_token_balances)
+                .flatten()
+                .map(|b| (b.account_index as usize, b.mint.as_str(), b.owner.as_str())),
+        )
+    }
+
+    pub fn get(&self, account: &Pubkey) -> Option<&TokenAccountInfo> {
+        self.accounts.get(account)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+}
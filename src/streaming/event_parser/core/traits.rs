@@ -2,17 +2,25 @@ use crate::streaming::event_parser::common::EventMetadata;
 use crate::streaming::event_parser::core::account_event_parser::{
     NonceAccountEvent, TokenAccountEvent, TokenInfoEvent,
 };
+use crate::streaming::event_parser::core::custom_parser::CustomEvent;
+use crate::streaming::event_parser::idl::IdlEvent;
 use crate::streaming::event_parser::core::common_event_parser::{
     SetComputeUnitLimitEvent, SetComputeUnitPriceEvent,
 };
 use crate::streaming::event_parser::protocols::block::block_meta_event::BlockMetaEvent;
 use crate::streaming::event_parser::protocols::bonk::events::*;
+use crate::streaming::event_parser::protocols::jupiter::events::*;
+use crate::streaming::event_parser::protocols::meteora_damm_v1::events::*;
 use crate::streaming::event_parser::protocols::meteora_damm_v2::events::*;
+use crate::streaming::event_parser::protocols::meteora_dlmm::events::*;
+use crate::streaming::event_parser::protocols::orca_whirlpool::events::*;
 use crate::streaming::event_parser::protocols::pumpfun::events::*;
 use crate::streaming::event_parser::protocols::pumpswap::events::*;
 use crate::streaming::event_parser::protocols::raydium_amm_v4::events::*;
 use crate::streaming::event_parser::protocols::raydium_clmm::events::*;
 use crate::streaming::event_parser::protocols::raydium_cpmm::events::*;
+use crate::streaming::event_parser::protocols::spl_token::events::*;
+use crate::streaming::event_parser::protocols::system::events::*;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
@@ -24,17 +32,43 @@ pub enum DexEvent {
     BonkPoolCreateEvent(BonkPoolCreateEvent),
     BonkMigrateToAmmEvent(BonkMigrateToAmmEvent),
     BonkMigrateToCpswapEvent(BonkMigrateToCpswapEvent),
+    BonkClaimVestedEvent(BonkClaimVestedEvent),
+    BonkCreateVestingEvent(BonkCreateVestingEvent),
+    BonkClaimCreatorFeeEvent(BonkClaimCreatorFeeEvent),
+    BonkClaimPlatformFeeEvent(BonkClaimPlatformFeeEvent),
+    BonkClaimPlatformFeeFromVaultEvent(BonkClaimPlatformFeeFromVaultEvent),
+    BonkCollectFeeEvent(BonkCollectFeeEvent),
+    BonkUpdateConfigEvent(BonkUpdateConfigEvent),
+    BonkUpdatePlatformConfigEvent(BonkUpdatePlatformConfigEvent),
+    BonkUpdatePlatformCurveParamEvent(BonkUpdatePlatformCurveParamEvent),
+    BonkRemovePlatformCurveParamEvent(BonkRemovePlatformCurveParamEvent),
     BonkPoolStateAccountEvent(BonkPoolStateAccountEvent),
     BonkGlobalConfigAccountEvent(BonkGlobalConfigAccountEvent),
     BonkPlatformConfigAccountEvent(BonkPlatformConfigAccountEvent),
+    BonkVestingRecordAccountEvent(BonkVestingRecordAccountEvent),
 
     // PumpFun events
     PumpFunCreateTokenEvent(PumpFunCreateTokenEvent),
     PumpFunCreateV2TokenEvent(PumpFunCreateV2TokenEvent),
     PumpFunTradeEvent(PumpFunTradeEvent),
     PumpFunMigrateEvent(PumpFunMigrateEvent),
+    PumpFunCompleteEvent(PumpFunCompleteEvent),
+    PumpFunCollectCreatorFeeEvent(PumpFunCollectCreatorFeeEvent),
+    PumpFunDistributeCreatorFeesEvent(PumpFunDistributeCreatorFeesEvent),
+    PumpFunClaimCashbackEvent(PumpFunClaimCashbackEvent),
+    PumpFunSetCreatorEvent(PumpFunSetCreatorEvent),
+    PumpFunSetMetaplexCreatorEvent(PumpFunSetMetaplexCreatorEvent),
+    PumpFunMigrateBondingCurveCreatorEvent(PumpFunMigrateBondingCurveCreatorEvent),
+    PumpFunInitUserVolumeAccumulatorEvent(PumpFunInitUserVolumeAccumulatorEvent),
+    PumpFunSyncUserVolumeAccumulatorEvent(PumpFunSyncUserVolumeAccumulatorEvent),
+    PumpFunCloseUserVolumeAccumulatorEvent(PumpFunCloseUserVolumeAccumulatorEvent),
+    PumpFunClaimTokenIncentivesEvent(PumpFunClaimTokenIncentivesEvent),
     PumpFunBondingCurveAccountEvent(PumpFunBondingCurveAccountEvent),
     PumpFunGlobalAccountEvent(PumpFunGlobalAccountEvent),
+    PumpFunFeeConfigAccountEvent(PumpFunFeeConfigAccountEvent),
+    PumpFunSharingConfigAccountEvent(PumpFunSharingConfigAccountEvent),
+    PumpFunGlobalVolumeAccumulatorAccountEvent(PumpFunGlobalVolumeAccumulatorAccountEvent),
+    PumpFunUserVolumeAccumulatorAccountEvent(PumpFunUserVolumeAccumulatorAccountEvent),
 
     // PumpSwap events
     PumpSwapBuyEvent(PumpSwapBuyEvent),
@@ -42,8 +76,20 @@ pub enum DexEvent {
     PumpSwapCreatePoolEvent(PumpSwapCreatePoolEvent),
     PumpSwapDepositEvent(PumpSwapDepositEvent),
     PumpSwapWithdrawEvent(PumpSwapWithdrawEvent),
+    PumpSwapCollectCoinCreatorFeeEvent(PumpSwapCollectCoinCreatorFeeEvent),
+    PumpSwapClaimCashbackEvent(PumpSwapClaimCashbackEvent),
+    PumpSwapClaimTokenIncentivesEvent(PumpSwapClaimTokenIncentivesEvent),
+    PumpSwapSetCoinCreatorEvent(PumpSwapSetCoinCreatorEvent),
+    PumpSwapMigratePoolCoinCreatorEvent(PumpSwapMigratePoolCoinCreatorEvent),
+    PumpSwapTransferCreatorFeesToPumpEvent(PumpSwapTransferCreatorFeesToPumpEvent),
+    PumpSwapToggleMayhemModeEvent(PumpSwapToggleMayhemModeEvent),
+    PumpSwapToggleCashbackEnabledEvent(PumpSwapToggleCashbackEnabledEvent),
     PumpSwapGlobalConfigAccountEvent(PumpSwapGlobalConfigAccountEvent),
     PumpSwapPoolAccountEvent(PumpSwapPoolAccountEvent),
+    PumpSwapFeeConfigAccountEvent(PumpSwapFeeConfigAccountEvent),
+    PumpSwapSharingConfigAccountEvent(PumpSwapSharingConfigAccountEvent),
+    PumpSwapGlobalVolumeAccumulatorAccountEvent(PumpSwapGlobalVolumeAccumulatorAccountEvent),
+    PumpSwapUserVolumeAccumulatorAccountEvent(PumpSwapUserVolumeAccumulatorAccountEvent),
 
     // Raydium AMM V4 events
     RaydiumAmmV4SwapEvent(RaydiumAmmV4SwapEvent),
@@ -62,17 +108,34 @@ pub enum DexEvent {
     RaydiumClmmCreatePoolEvent(RaydiumClmmCreatePoolEvent),
     RaydiumClmmOpenPositionWithToken22NftEvent(RaydiumClmmOpenPositionWithToken22NftEvent),
     RaydiumClmmOpenPositionV2Event(RaydiumClmmOpenPositionV2Event),
+    RaydiumClmmOpenPositionEvent(RaydiumClmmOpenPositionEvent),
+    RaydiumClmmIncreaseLiquidityEvent(RaydiumClmmIncreaseLiquidityEvent),
+    RaydiumClmmDecreaseLiquidityEvent(RaydiumClmmDecreaseLiquidityEvent),
+    RaydiumClmmSwapRouterBaseInEvent(RaydiumClmmSwapRouterBaseInEvent),
+    RaydiumClmmCollectPersonalFeeEvent(RaydiumClmmCollectPersonalFeeEvent),
+    RaydiumClmmCollectRemainingRewardsEvent(RaydiumClmmCollectRemainingRewardsEvent),
+    RaydiumClmmCollectProtocolFeeEvent(RaydiumClmmCollectProtocolFeeEvent),
+    RaydiumClmmCollectFundFeeEvent(RaydiumClmmCollectFundFeeEvent),
     RaydiumClmmAmmConfigAccountEvent(RaydiumClmmAmmConfigAccountEvent),
     RaydiumClmmPoolStateAccountEvent(RaydiumClmmPoolStateAccountEvent),
     RaydiumClmmTickArrayStateAccountEvent(RaydiumClmmTickArrayStateAccountEvent),
+    RaydiumClmmPersonalPositionStateAccountEvent(RaydiumClmmPersonalPositionStateAccountEvent),
+    RaydiumClmmObservationStateAccountEvent(RaydiumClmmObservationStateAccountEvent),
+    RaydiumClmmTickArrayBitmapExtensionAccountEvent(
+        RaydiumClmmTickArrayBitmapExtensionAccountEvent,
+    ),
 
     // Raydium CPMM events
     RaydiumCpmmSwapEvent(RaydiumCpmmSwapEvent),
     RaydiumCpmmDepositEvent(RaydiumCpmmDepositEvent),
     RaydiumCpmmWithdrawEvent(RaydiumCpmmWithdrawEvent),
     RaydiumCpmmInitializeEvent(RaydiumCpmmInitializeEvent),
+    RaydiumCpmmCollectCreatorFeeEvent(RaydiumCpmmCollectCreatorFeeEvent),
+    RaydiumCpmmCollectProtocolFeeEvent(RaydiumCpmmCollectProtocolFeeEvent),
+    RaydiumCpmmCollectFundFeeEvent(RaydiumCpmmCollectFundFeeEvent),
     RaydiumCpmmAmmConfigAccountEvent(RaydiumCpmmAmmConfigAccountEvent),
     RaydiumCpmmPoolStateAccountEvent(RaydiumCpmmPoolStateAccountEvent),
+    RaydiumCpmmObservationStateAccountEvent(RaydiumCpmmObservationStateAccountEvent),
 
     // Meteora DAMM v2 events
     MeteoraDammV2SwapEvent(MeteoraDammV2SwapEvent),
@@ -80,6 +143,74 @@ pub enum DexEvent {
     MeteoraDammV2InitializePoolEvent(MeteoraDammV2InitializePoolEvent),
     MeteoraDammV2InitializeCustomizablePoolEvent(MeteoraDammV2InitializeCustomizablePoolEvent),
     MeteoraDammV2InitializePoolWithDynamicConfigEvent(MeteoraDammV2InitializePoolWithDynamicConfigEvent),
+    MeteoraDammV2AddLiquidityEvent(MeteoraDammV2AddLiquidityEvent),
+    MeteoraDammV2RemoveLiquidityEvent(MeteoraDammV2RemoveLiquidityEvent),
+    MeteoraDammV2CreatePositionEvent(MeteoraDammV2CreatePositionEvent),
+    MeteoraDammV2ClosePositionEvent(MeteoraDammV2ClosePositionEvent),
+    MeteoraDammV2SplitPositionEvent(MeteoraDammV2SplitPositionEvent),
+    MeteoraDammV2LockPositionEvent(MeteoraDammV2LockPositionEvent),
+    MeteoraDammV2PermanentLockPositionEvent(MeteoraDammV2PermanentLockPositionEvent),
+    MeteoraDammV2ClaimPositionFeeEvent(MeteoraDammV2ClaimPositionFeeEvent),
+    MeteoraDammV2ClaimRewardEvent(MeteoraDammV2ClaimRewardEvent),
+    MeteoraDammV2PoolAccountEvent(MeteoraDammV2PoolAccountEvent),
+    MeteoraDammV2PositionAccountEvent(MeteoraDammV2PositionAccountEvent),
+    MeteoraDammV2ConfigAccountEvent(MeteoraDammV2ConfigAccountEvent),
+    MeteoraDammV1SwapEvent(MeteoraDammV1SwapEvent),
+    MeteoraDammV1AddLiquidityEvent(MeteoraDammV1AddLiquidityEvent),
+    MeteoraDammV1RemoveLiquidityEvent(MeteoraDammV1RemoveLiquidityEvent),
+    MeteoraDammV1BootstrapLiquidityEvent(MeteoraDammV1BootstrapLiquidityEvent),
+    MeteoraDammV1InitializePoolEvent(MeteoraDammV1InitializePoolEvent),
+    MeteoraDammV1LockEvent(MeteoraDammV1LockEvent),
+    MeteoraDammV1ClaimFeeEvent(MeteoraDammV1ClaimFeeEvent),
+    MeteoraDammV1PoolAccountEvent(MeteoraDammV1PoolAccountEvent),
+    MeteoraDammV1LockEscrowAccountEvent(MeteoraDammV1LockEscrowAccountEvent),
+
+    // Meteora DLMM events
+    MeteoraDlmmSwapEvent(MeteoraDlmmSwapEvent),
+    MeteoraDlmmAddLiquidityEvent(MeteoraDlmmAddLiquidityEvent),
+    MeteoraDlmmAddLiquidityOneSideEvent(MeteoraDlmmAddLiquidityOneSideEvent),
+    MeteoraDlmmRemoveLiquidityEvent(MeteoraDlmmRemoveLiquidityEvent),
+    MeteoraDlmmPositionCreateEvent(MeteoraDlmmPositionCreateEvent),
+    MeteoraDlmmPositionCloseEvent(MeteoraDlmmPositionCloseEvent),
+    MeteoraDlmmClaimFeeEvent(MeteoraDlmmClaimFeeEvent),
+    MeteoraDlmmClaimRewardEvent(MeteoraDlmmClaimRewardEvent),
+    MeteoraDlmmLbPairAccountEvent(MeteoraDlmmLbPairAccountEvent),
+    MeteoraDlmmBinArrayAccountEvent(MeteoraDlmmBinArrayAccountEvent),
+    MeteoraDlmmPositionV2AccountEvent(MeteoraDlmmPositionV2AccountEvent),
+    OrcaWhirlpoolSwapEvent(OrcaWhirlpoolSwapEvent),
+    OrcaWhirlpoolTwoHopSwapEvent(OrcaWhirlpoolTwoHopSwapEvent),
+    OrcaWhirlpoolIncreaseLiquidityEvent(OrcaWhirlpoolIncreaseLiquidityEvent),
+    OrcaWhirlpoolDecreaseLiquidityEvent(OrcaWhirlpoolDecreaseLiquidityEvent),
+    OrcaWhirlpoolOpenPositionEvent(OrcaWhirlpoolOpenPositionEvent),
+    OrcaWhirlpoolClosePositionEvent(OrcaWhirlpoolClosePositionEvent),
+    OrcaWhirlpoolCollectFeesEvent(OrcaWhirlpoolCollectFeesEvent),
+    OrcaWhirlpoolCollectRewardEvent(OrcaWhirlpoolCollectRewardEvent),
+    OrcaWhirlpoolWhirlpoolAccountEvent(OrcaWhirlpoolWhirlpoolAccountEvent),
+    OrcaWhirlpoolTickArrayAccountEvent(OrcaWhirlpoolTickArrayAccountEvent),
+    OrcaWhirlpoolPositionAccountEvent(OrcaWhirlpoolPositionAccountEvent),
+
+    // SPL Token events
+    SplTokenTransferEvent(SplTokenTransferEvent),
+    SplTokenMintToEvent(SplTokenMintToEvent),
+    SplTokenBurnEvent(SplTokenBurnEvent),
+    SplTokenCloseAccountEvent(SplTokenCloseAccountEvent),
+    SplTokenApproveEvent(SplTokenApproveEvent),
+    SplTokenSetAuthorityEvent(SplTokenSetAuthorityEvent),
+    SplTokenInitializeAccountEvent(SplTokenInitializeAccountEvent),
+
+    // System Program / Address Lookup Table events
+    SolTransferEvent(SolTransferEvent),
+    CreateAccountEvent(CreateAccountEvent),
+    NonceAdvanceEvent(NonceAdvanceEvent),
+    NonceAuthorizeEvent(NonceAuthorizeEvent),
+    AddressLookupTableCreateEvent(AddressLookupTableCreateEvent),
+    AddressLookupTableExtendEvent(AddressLookupTableExtendEvent),
+    AddressLookupTableDeactivateEvent(AddressLookupTableDeactivateEvent),
+    AddressLookupTableCloseEvent(AddressLookupTableCloseEvent),
+
+    // Jupiter events
+    JupiterRouteEvent(JupiterRouteEvent),
+    JupiterSwapEvent(JupiterSwapEvent),
 
     // Common events
     TokenAccountEvent(TokenAccountEvent),
@@ -88,6 +219,10 @@ pub enum DexEvent {
     BlockMetaEvent(BlockMetaEvent),
     SetComputeUnitLimitEvent(SetComputeUnitLimitEvent),
     SetComputeUnitPriceEvent(SetComputeUnitPriceEvent),
+
+    // Third-party protocol events
+    CustomEvent(CustomEvent),
+    IdlEvent(IdlEvent),
 }
 
 /// Macro to generate metadata accessors for all DexEvent variants
@@ -115,24 +250,62 @@ impl_dex_event_metadata!(
     BonkPoolCreateEvent,
     BonkMigrateToAmmEvent,
     BonkMigrateToCpswapEvent,
+    BonkClaimVestedEvent,
+    BonkCreateVestingEvent,
+    BonkClaimCreatorFeeEvent,
+    BonkClaimPlatformFeeEvent,
+    BonkClaimPlatformFeeFromVaultEvent,
+    BonkCollectFeeEvent,
+    BonkUpdateConfigEvent,
+    BonkUpdatePlatformConfigEvent,
+    BonkUpdatePlatformCurveParamEvent,
+    BonkRemovePlatformCurveParamEvent,
     BonkPoolStateAccountEvent,
     BonkGlobalConfigAccountEvent,
     BonkPlatformConfigAccountEvent,
+    BonkVestingRecordAccountEvent,
     // PumpFun events
     PumpFunCreateTokenEvent,
     PumpFunCreateV2TokenEvent,
     PumpFunTradeEvent,
     PumpFunMigrateEvent,
+    PumpFunCompleteEvent,
+    PumpFunCollectCreatorFeeEvent,
+    PumpFunDistributeCreatorFeesEvent,
+    PumpFunClaimCashbackEvent,
+    PumpFunSetCreatorEvent,
+    PumpFunSetMetaplexCreatorEvent,
+    PumpFunMigrateBondingCurveCreatorEvent,
+    PumpFunInitUserVolumeAccumulatorEvent,
+    PumpFunSyncUserVolumeAccumulatorEvent,
+    PumpFunCloseUserVolumeAccumulatorEvent,
+    PumpFunClaimTokenIncentivesEvent,
     PumpFunBondingCurveAccountEvent,
     PumpFunGlobalAccountEvent,
+    PumpFunFeeConfigAccountEvent,
+    PumpFunSharingConfigAccountEvent,
+    PumpFunGlobalVolumeAccumulatorAccountEvent,
+    PumpFunUserVolumeAccumulatorAccountEvent,
     // PumpSwap events
     PumpSwapBuyEvent,
     PumpSwapSellEvent,
     PumpSwapCreatePoolEvent,
     PumpSwapDepositEvent,
     PumpSwapWithdrawEvent,
+    PumpSwapCollectCoinCreatorFeeEvent,
+    PumpSwapClaimCashbackEvent,
+    PumpSwapClaimTokenIncentivesEvent,
+    PumpSwapSetCoinCreatorEvent,
+    PumpSwapMigratePoolCoinCreatorEvent,
+    PumpSwapTransferCreatorFeesToPumpEvent,
+    PumpSwapToggleMayhemModeEvent,
+    PumpSwapToggleCashbackEnabledEvent,
     PumpSwapGlobalConfigAccountEvent,
     PumpSwapPoolAccountEvent,
+    PumpSwapFeeConfigAccountEvent,
+    PumpSwapSharingConfigAccountEvent,
+    PumpSwapGlobalVolumeAccumulatorAccountEvent,
+    PumpSwapUserVolumeAccumulatorAccountEvent,
     // Raydium AMM V4 events
     RaydiumAmmV4SwapEvent,
     RaydiumAmmV4DepositEvent,
@@ -149,22 +322,101 @@ impl_dex_event_metadata!(
     RaydiumClmmCreatePoolEvent,
     RaydiumClmmOpenPositionWithToken22NftEvent,
     RaydiumClmmOpenPositionV2Event,
+    RaydiumClmmOpenPositionEvent,
+    RaydiumClmmIncreaseLiquidityEvent,
+    RaydiumClmmDecreaseLiquidityEvent,
+    RaydiumClmmSwapRouterBaseInEvent,
+    RaydiumClmmCollectPersonalFeeEvent,
+    RaydiumClmmCollectRemainingRewardsEvent,
+    RaydiumClmmCollectProtocolFeeEvent,
+    RaydiumClmmCollectFundFeeEvent,
     RaydiumClmmAmmConfigAccountEvent,
     RaydiumClmmPoolStateAccountEvent,
     RaydiumClmmTickArrayStateAccountEvent,
+    RaydiumClmmPersonalPositionStateAccountEvent,
+    RaydiumClmmObservationStateAccountEvent,
+    RaydiumClmmTickArrayBitmapExtensionAccountEvent,
     // Raydium CPMM events
     RaydiumCpmmSwapEvent,
     RaydiumCpmmDepositEvent,
     RaydiumCpmmWithdrawEvent,
     RaydiumCpmmInitializeEvent,
+    RaydiumCpmmCollectCreatorFeeEvent,
+    RaydiumCpmmCollectProtocolFeeEvent,
+    RaydiumCpmmCollectFundFeeEvent,
     RaydiumCpmmAmmConfigAccountEvent,
     RaydiumCpmmPoolStateAccountEvent,
+    RaydiumCpmmObservationStateAccountEvent,
     // Meteora DAMM v2 events
     MeteoraDammV2SwapEvent,
     MeteoraDammV2Swap2Event,
     MeteoraDammV2InitializePoolEvent,
     MeteoraDammV2InitializeCustomizablePoolEvent,
     MeteoraDammV2InitializePoolWithDynamicConfigEvent,
+    MeteoraDammV2AddLiquidityEvent,
+    MeteoraDammV2RemoveLiquidityEvent,
+    MeteoraDammV2CreatePositionEvent,
+    MeteoraDammV2ClosePositionEvent,
+    MeteoraDammV2SplitPositionEvent,
+    MeteoraDammV2LockPositionEvent,
+    MeteoraDammV2PermanentLockPositionEvent,
+    MeteoraDammV2ClaimPositionFeeEvent,
+    MeteoraDammV2ClaimRewardEvent,
+    MeteoraDammV2PoolAccountEvent,
+    MeteoraDammV2PositionAccountEvent,
+    MeteoraDammV2ConfigAccountEvent,
+    MeteoraDammV1SwapEvent,
+    MeteoraDammV1AddLiquidityEvent,
+    MeteoraDammV1RemoveLiquidityEvent,
+    MeteoraDammV1BootstrapLiquidityEvent,
+    MeteoraDammV1InitializePoolEvent,
+    MeteoraDammV1LockEvent,
+    MeteoraDammV1ClaimFeeEvent,
+    MeteoraDammV1PoolAccountEvent,
+    MeteoraDammV1LockEscrowAccountEvent,
+    // Meteora DLMM events
+    MeteoraDlmmSwapEvent,
+    MeteoraDlmmAddLiquidityEvent,
+    MeteoraDlmmAddLiquidityOneSideEvent,
+    MeteoraDlmmRemoveLiquidityEvent,
+    MeteoraDlmmPositionCreateEvent,
+    MeteoraDlmmPositionCloseEvent,
+    MeteoraDlmmClaimFeeEvent,
+    MeteoraDlmmClaimRewardEvent,
+    MeteoraDlmmLbPairAccountEvent,
+    MeteoraDlmmBinArrayAccountEvent,
+    MeteoraDlmmPositionV2AccountEvent,
+    OrcaWhirlpoolSwapEvent,
+    OrcaWhirlpoolTwoHopSwapEvent,
+    OrcaWhirlpoolIncreaseLiquidityEvent,
+    OrcaWhirlpoolDecreaseLiquidityEvent,
+    OrcaWhirlpoolOpenPositionEvent,
+    OrcaWhirlpoolClosePositionEvent,
+    OrcaWhirlpoolCollectFeesEvent,
+    OrcaWhirlpoolCollectRewardEvent,
+    OrcaWhirlpoolWhirlpoolAccountEvent,
+    OrcaWhirlpoolTickArrayAccountEvent,
+    OrcaWhirlpoolPositionAccountEvent,
+    // SPL Token events
+    SplTokenTransferEvent,
+    SplTokenMintToEvent,
+    SplTokenBurnEvent,
+    SplTokenCloseAccountEvent,
+    SplTokenApproveEvent,
+    SplTokenSetAuthorityEvent,
+    SplTokenInitializeAccountEvent,
+    // System Program / Address Lookup Table events
+    SolTransferEvent,
+    CreateAccountEvent,
+    NonceAdvanceEvent,
+    NonceAuthorizeEvent,
+    AddressLookupTableCreateEvent,
+    AddressLookupTableExtendEvent,
+    AddressLookupTableDeactivateEvent,
+    AddressLookupTableCloseEvent,
+    // Jupiter events
+    JupiterRouteEvent,
+    JupiterSwapEvent,
     // Common events
     TokenAccountEvent,
     NonceAccountEvent,
@@ -172,4 +424,7 @@ impl_dex_event_metadata!(
     BlockMetaEvent,
     SetComputeUnitLimitEvent,
     SetComputeUnitPriceEvent,
+    // Third-party protocol events
+    CustomEvent,
+    IdlEvent,
 );
@@ -0,0 +1,193 @@
+use std::fmt;
+
+use prost_types::Timestamp;
+use solana_sdk::{pubkey::Pubkey, transaction::VersionedTransaction};
+use solana_transaction_status::TransactionStatusMeta;
+use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
+
+/// 同步解析接口 `EventParser::parse_transaction` 的错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// gRPC 交易中缺少 transaction 或 message
+    MissingMessage,
+    /// 交易中没有签名
+    MissingSignature,
+    /// 签名字节长度不是 64
+    InvalidSignature,
+    /// RPC 返回的交易无法解码（如 `accounts` 编码）
+    UndecodableTransaction,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingMessage => write!(f, "transaction message is missing"),
+            ParseError::MissingSignature => write!(f, "transaction has no signature"),
+            ParseError::InvalidSignature => write!(f, "invalid transaction signature"),
+            ParseError::UndecodableTransaction => write!(f, "transaction could not be decoded"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// 同步解析接口的交易输入
+#[derive(Debug, Clone, Copy)]
+pub enum TransactionInput<'a> {
+    /// 交易及其执行结果，meta 用于补充地址查找表账户和 inner instructions
+    Versioned { transaction: &'a VersionedTransaction, meta: Option<&'a TransactionStatusMeta> },
+    /// Yellowstone gRPC 推送的交易
+    Grpc(&'a SubscribeUpdateTransactionInfo),
+}
+
+impl<'a> From<&'a VersionedTransaction> for TransactionInput<'a> {
+    fn from(transaction: &'a VersionedTransaction) -> Self {
+        TransactionInput::Versioned { transaction, meta: None }
+    }
+}
+
+impl<'a> From<(&'a VersionedTransaction, &'a TransactionStatusMeta)> for TransactionInput<'a> {
+    fn from((transaction, meta): (&'a VersionedTransaction, &'a TransactionStatusMeta)) -> Self {
+        TransactionInput::Versioned { transaction, meta: Some(meta) }
+    }
+}
+
+impl<'a> From<&'a SubscribeUpdateTransactionInfo> for TransactionInput<'a> {
+    fn from(grpc_tx: &'a SubscribeUpdateTransactionInfo) -> Self {
+        TransactionInput::Grpc(grpc_tx)
+    }
+}
+
+/// 交易本身不包含的上下文信息
+#[derive(Debug, Clone, Default)]
+pub struct ParseContext {
+    pub slot: Option<u64>,
+    pub block_time: Option<Timestamp>,
+    /// 接收时间（微秒），为 `None` 时使用当前时间
+    pub recv_us: Option<i64>,
+    /// 交易在 slot 中的索引，gRPC 输入为 `None` 时使用交易自带的索引
+    pub tx_index: Option<u64>,
+    pub bot_wallet: Option<Pubkey>,
+}
+
+/// 单元测试共用的交易构造工具
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use base64::Engine;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        message::{compiled_instruction::CompiledInstruction, Message},
+        pubkey::Pubkey,
+        signature::Signature,
+        transaction::{Transaction, VersionedTransaction},
+    };
+    use solana_transaction_status::{InnerInstruction, InnerInstructions, TransactionStatusMeta};
+
+    use super::ParseContext;
+    use crate::streaming::event_parser::{core::event_parser::EventParser, DexEvent, Protocol};
+
+    /// `n` 个互不相同的账户
+    pub(crate) fn unique_pubkeys(n: usize) -> Vec<Pubkey> {
+        (0..n).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    /// 只包含一条指令的交易，`accounts` 依次作为指令账户
+    pub(crate) fn transaction(
+        program_id: Pubkey,
+        data: &[u8],
+        accounts: &[Pubkey],
+    ) -> VersionedTransaction {
+        let metas = accounts.iter().map(|k| AccountMeta::new(*k, false)).collect();
+        let message = Message::new(
+            &[Instruction::new_with_bytes(program_id, data, metas)],
+            Some(&Pubkey::new_unique()),
+        );
+        let mut tx = Transaction::new_unsigned(message);
+        tx.signatures = vec![Signature::from([1u8; 64])];
+        VersionedTransaction::from(tx)
+    }
+
+    /// 账户在交易中的索引，用于构造 inner instruction
+    pub(crate) fn account_index(tx: &VersionedTransaction, key: &Pubkey) -> u8 {
+        tx.message.static_account_keys().iter().position(|k| k == key).unwrap() as u8
+    }
+
+    /// 第一层 CPI 调用
+    pub(crate) fn inner_instruction(
+        program_index: u8,
+        data: Vec<u8>,
+        accounts: Vec<u8>,
+    ) -> InnerInstruction {
+        InnerInstruction {
+            instruction: CompiledInstruction::new_from_raw_parts(program_index, data, accounts),
+            stack_height: Some(2),
+        }
+    }
+
+    /// 第 0 条指令的 inner instructions
+    pub(crate) fn inner_instructions_meta(
+        instructions: Vec<InnerInstruction>,
+    ) -> TransactionStatusMeta {
+        TransactionStatusMeta {
+            inner_instructions: Some(vec![InnerInstructions { index: 0, instructions }]),
+            ..Default::default()
+        }
+    }
+
+    /// 第 0 条指令通过 emit! 输出的 `Program data:` 日志
+    pub(crate) fn program_data_meta(program_id: Pubkey, logs: &[Vec<u8>]) -> TransactionStatusMeta {
+        let mut log_messages = vec![format!("Program {program_id} invoke [1]")];
+        log_messages.extend(logs.iter().map(|log| {
+            format!("Program data: {}", base64::engine::general_purpose::STANDARD.encode(log))
+        }));
+        log_messages.push(format!("Program {program_id} success"));
+        TransactionStatusMeta { log_messages: Some(log_messages), ..Default::default() }
+    }
+
+    pub(crate) fn parse(
+        protocols: &[Protocol],
+        tx: &VersionedTransaction,
+        meta: &TransactionStatusMeta,
+    ) -> Vec<DexEvent> {
+        EventParser::parse_transaction(protocols, None, (tx, meta), &ParseContext::default())
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fixtures::transaction, *};
+    use crate::streaming::event_parser::{
+        common::EventType, core::event_parser::EventParser, Protocol,
+    };
+
+    #[test]
+    fn test_parse_transaction_without_runtime() {
+        let compute_budget = solana_sdk::pubkey!("ComputeBudget111111111111111111111111111111");
+        let mut data = vec![3u8];
+        data.extend_from_slice(&42u64.to_le_bytes());
+        let tx = transaction(compute_budget, &data, &[]);
+        let meta = TransactionStatusMeta::default();
+        let context = ParseContext { slot: Some(7), tx_index: Some(3), ..Default::default() };
+
+        let events = std::thread::spawn(move || {
+            EventParser::parse_transaction(&[Protocol::PumpFun], None, (&tx, &meta), &context)
+        })
+        .join()
+        .unwrap()
+        .unwrap();
+        assert_eq!(events.len(), 1);
+        let metadata = events[0].metadata();
+        assert_eq!(metadata.event_type, EventType::SetComputeUnitPrice);
+        assert_eq!((metadata.slot, metadata.tx_index), (7, Some(3)));
+
+        let empty = SubscribeUpdateTransactionInfo::default();
+        let result = EventParser::parse_transaction(
+            &[Protocol::PumpFun],
+            None,
+            &empty,
+            &ParseContext::default(),
+        );
+        assert_eq!(result.unwrap_err(), ParseError::MissingMessage);
+    }
+}
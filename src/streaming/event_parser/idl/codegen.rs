@@ -0,0 +1,393 @@
+//! 由 IDL 生成 Rust 代码
+//!
+//! 为 `idl/*.json` 生成带类型的指令参数、事件、账户结构体，borsh 解码实现
+//! （[`IdlDecode`](super::IdlDecode)）以及与手写解析器一致的 `discriminators` 常量模块。
+//! 生成的模块位于 `idl/generated/`，IDL 更新后运行 `cargo run --example idl_codegen` 重新生成。
+//!
+//! `serialization: bytemuck` 的类型按字段顺序解码，与 borsh 布局一致的前提是结构体没有填充字节。
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+
+use anyhow::{anyhow, bail};
+
+use crate::common::AnyResult;
+use crate::streaming::event_parser::idl::program::{
+    flatten_accounts, sighash, to_snake_case, EVENT_IX_TAG,
+};
+use crate::streaming::event_parser::idl::types::{
+    Idl, IdlDefinedFields, IdlField, IdlType, IdlTypeDefTy,
+};
+
+/// serde 只为长度不超过 32 的数组实现了序列化，更长的数组需要 `serde_big_array`
+const SERDE_MAX_ARRAY_LEN: usize = 32;
+
+const DERIVE: &str =
+    "#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]\n";
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "try",
+    "type", "unsafe", "use", "where", "while", "yield",
+];
+
+/// 由 IDL JSON 生成 Rust 模块源码，`source` 为写入文件头注释的 IDL 路径
+pub fn generate(idl_json: &str, source: &str) -> AnyResult<String> {
+    let idl: Idl = serde_json::from_str(idl_json)?;
+    Generator::new(&idl).generate(source)
+}
+
+struct Generator<'a> {
+    idl: &'a Idl,
+    /// 旧版 IDL 的账户、事件内联定义，与 `types` 合并后按名称排序输出
+    types: BTreeMap<String, IdlTypeDefTy>,
+    uses_pubkey: bool,
+}
+
+impl<'a> Generator<'a> {
+    fn new(idl: &'a Idl) -> Self {
+        let mut types: BTreeMap<String, IdlTypeDefTy> =
+            idl.types.iter().map(|t| (t.name.clone(), t.ty.clone())).collect();
+        for account in &idl.accounts {
+            if let Some(ty) = &account.ty {
+                types.insert(account.name.clone(), ty.clone());
+            }
+        }
+        for event in &idl.events {
+            if let Some(fields) = &event.fields {
+                let fields = IdlDefinedFields::Named(fields.clone());
+                types.insert(event.name.clone(), IdlTypeDefTy::Struct { fields });
+            }
+        }
+        Self { idl, types, uses_pubkey: false }
+    }
+
+    fn generate(mut self, source: &str) -> AnyResult<String> {
+        let metadata = self.idl.metadata.clone().unwrap_or_default();
+        let index_selector = metadata.instruction_selector_type.as_deref() == Some("index");
+        let address = self.idl.address.clone().or(metadata.address);
+
+        let mut body = String::new();
+        let mut discriminators = String::new();
+
+        if let Some(address) = &address {
+            self.uses_pubkey = true;
+            writeln!(body, "pub const PROGRAM_ID: Pubkey = solana_sdk::pubkey!(\"{address}\");\n")?;
+        }
+
+        // 类型
+        for (name, ty) in &self.types.clone() {
+            body.push_str(&self.type_def(name, ty)?);
+            body.push('\n');
+        }
+
+        // 指令
+        writeln!(discriminators, "    // Instruction discriminators")?;
+        let mut names = HashSet::new();
+        for (index, ix) in self.idl.instructions.iter().enumerate() {
+            let discriminator = match &ix.discriminator {
+                Some(discriminator) => discriminator.clone(),
+                None if index_selector => vec![index as u8],
+                None => sighash("global", &to_snake_case(&ix.name)).to_vec(),
+            };
+            let struct_name = format!("{}Instruction", pascal_case(&ix.name));
+            if !names.insert(struct_name.clone()) {
+                bail!("duplicate instruction {}", ix.name);
+            }
+            let mut accounts = Vec::new();
+            flatten_accounts(&ix.accounts, "", &mut accounts);
+
+            body.push_str(DERIVE);
+            body.push_str(
+                &self.struct_body(&struct_name, &IdlDefinedFields::Named(ix.args.clone()))?,
+            );
+            writeln!(body, "\nimpl {struct_name} {{")?;
+            writeln!(body, "    pub const ACCOUNTS: &'static [&'static str] = &[")?;
+            for account in &accounts {
+                writeln!(body, "        \"{account}\",")?;
+            }
+            writeln!(body, "    ];\n}}\n")?;
+            writeln!(body, "impl IdlDecode for {struct_name} {{")?;
+            writeln!(body, "    const DISCRIMINATOR: &'static [u8] = &{discriminator:?};\n}}\n")?;
+
+            let const_name = screaming_case(&ix.name);
+            writeln!(discriminators, "    pub const {const_name}_IX: &[u8] = &{discriminator:?};")?;
+        }
+
+        // 事件（CPI 事件的 16 字节 discriminator，与手写解析器一致）
+        if !self.idl.events.is_empty() {
+            writeln!(discriminators, "\n    // Event discriminators")?;
+        }
+        for event in &self.idl.events {
+            if !self.types.contains_key(&event.name) {
+                bail!("event {} has no type definition", event.name);
+            }
+            let discriminator = event
+                .discriminator
+                .clone()
+                .unwrap_or_else(|| sighash("event", &event.name).to_vec());
+            writeln!(body, "impl IdlDecode for {} {{", event.name)?;
+            writeln!(body, "    const DISCRIMINATOR: &'static [u8] = &{discriminator:?};\n}}\n")?;
+            let cpi: Vec<u8> = EVENT_IX_TAG.iter().chain(&discriminator).copied().collect();
+            let const_name = screaming_case(event.name.trim_end_matches("Event"));
+            writeln!(discriminators, "    pub const {const_name}_EVENT: &[u8] = &{cpi:?};")?;
+        }
+
+        // 账户
+        if !self.idl.accounts.is_empty() {
+            writeln!(discriminators, "\n    // Account discriminators")?;
+        }
+        for account in &self.idl.accounts {
+            if !self.types.contains_key(&account.name) {
+                bail!("account {} has no type definition", account.name);
+            }
+            let discriminator = account
+                .discriminator
+                .clone()
+                .unwrap_or_else(|| sighash("account", &account.name).to_vec());
+            writeln!(body, "impl IdlDecode for {} {{", account.name)?;
+            writeln!(body, "    const DISCRIMINATOR: &'static [u8] = &{discriminator:?};\n}}\n")?;
+            let const_name = screaming_case(&account.name);
+            writeln!(
+                discriminators,
+                "    pub const {const_name}_ACCOUNT: &[u8] = &{discriminator:?};"
+            )?;
+        }
+
+        let mut out = String::new();
+        writeln!(out, "//! Generated from `{source}` by `idl::codegen`, do not edit.")?;
+        writeln!(out, "//!")?;
+        writeln!(out, "//! Regenerate with `cargo run --example idl_codegen`.\n")?;
+        writeln!(out, "use borsh::BorshDeserialize;")?;
+        writeln!(out, "use serde::{{Deserialize, Serialize}};")?;
+        if self.uses_pubkey {
+            writeln!(out, "use solana_sdk::pubkey::Pubkey;")?;
+        }
+        writeln!(out, "\nuse crate::streaming::event_parser::idl::IdlDecode;\n")?;
+        out.push_str(&body);
+        writeln!(out, "/// Discriminator constants")?;
+        writeln!(out, "pub mod discriminators {{")?;
+        out.push_str(&discriminators);
+        writeln!(out, "}}")?;
+        Ok(out)
+    }
+
+    fn type_def(&mut self, name: &str, ty: &IdlTypeDefTy) -> AnyResult<String> {
+        let mut out = String::new();
+        match ty {
+            IdlTypeDefTy::Struct { fields } => {
+                out.push_str(DERIVE);
+                out.push_str(&self.struct_body(name, fields)?);
+            }
+            IdlTypeDefTy::Enum { variants } => {
+                out.push_str(DERIVE);
+                writeln!(out, "pub enum {name} {{")?;
+                for variant in variants {
+                    match &variant.fields {
+                        None => writeln!(out, "    {},", variant.name)?,
+                        Some(IdlDefinedFields::Named(fields)) => {
+                            writeln!(out, "    {} {{", variant.name)?;
+                            for field in fields {
+                                out.push_str(&self.field(field, "        ", false)?);
+                            }
+                            writeln!(out, "    }},")?;
+                        }
+                        Some(IdlDefinedFields::Tuple(types)) => {
+                            let types = types
+                                .iter()
+                                .map(|ty| self.tuple_field(ty, false))
+                                .collect::<AnyResult<Vec<_>>>()?;
+                            writeln!(out, "    {}({}),", variant.name, types.join(", "))?;
+                        }
+                    }
+                }
+                writeln!(out, "}}")?;
+            }
+            IdlTypeDefTy::Alias { value } | IdlTypeDefTy::Type { alias: value } => {
+                writeln!(out, "pub type {name} = {};", self.rust_type(value)?)?;
+            }
+        }
+        Ok(out)
+    }
+
+    fn struct_body(&mut self, name: &str, fields: &IdlDefinedFields) -> AnyResult<String> {
+        let mut out = String::new();
+        match fields {
+            IdlDefinedFields::Named(fields) if fields.is_empty() => {
+                writeln!(out, "pub struct {name} {{}}")?;
+            }
+            IdlDefinedFields::Named(fields) => {
+                writeln!(out, "pub struct {name} {{")?;
+                for field in fields {
+                    out.push_str(&self.field(field, "    ", true)?);
+                }
+                writeln!(out, "}}")?;
+            }
+            IdlDefinedFields::Tuple(types) => {
+                let types = types
+                    .iter()
+                    .map(|ty| self.tuple_field(ty, true))
+                    .collect::<AnyResult<Vec<_>>>()?;
+                writeln!(out, "pub struct {name}({});", types.join(", "))?;
+            }
+        }
+        Ok(out)
+    }
+
+    fn field(&mut self, field: &IdlField, indent: &str, public: bool) -> AnyResult<String> {
+        let mut out = String::new();
+        let ident = field_ident(&field.name);
+        if ident.trim_start_matches("r#") != field.name {
+            writeln!(out, "{indent}#[serde(rename = \"{}\")]", field.name)?;
+        }
+        if is_big_array(&field.ty) {
+            writeln!(out, "{indent}#[serde(with = \"serde_big_array::BigArray\")]")?;
+        }
+        let vis = if public { "pub " } else { "" };
+        writeln!(out, "{indent}{vis}{ident}: {},", self.rust_type(&field.ty)?)?;
+        Ok(out)
+    }
+
+    fn tuple_field(&mut self, ty: &IdlType, public: bool) -> AnyResult<String> {
+        let big_array =
+            if is_big_array(ty) { "#[serde(with = \"serde_big_array::BigArray\")] " } else { "" };
+        let vis = if public { "pub " } else { "" };
+        Ok(format!("{big_array}{vis}{}", self.rust_type(ty)?))
+    }
+
+    fn rust_type(&mut self, ty: &IdlType) -> AnyResult<String> {
+        Ok(match ty {
+            IdlType::Bool => "bool".into(),
+            IdlType::U8 => "u8".into(),
+            IdlType::I8 => "i8".into(),
+            IdlType::U16 => "u16".into(),
+            IdlType::I16 => "i16".into(),
+            IdlType::U32 => "u32".into(),
+            IdlType::I32 => "i32".into(),
+            IdlType::F32 => "f32".into(),
+            IdlType::U64 => "u64".into(),
+            IdlType::I64 => "i64".into(),
+            IdlType::F64 => "f64".into(),
+            IdlType::U128 => "u128".into(),
+            IdlType::I128 => "i128".into(),
+            IdlType::String => "String".into(),
+            IdlType::Bytes => "Vec<u8>".into(),
+            IdlType::Pubkey => {
+                self.uses_pubkey = true;
+                "Pubkey".into()
+            }
+            IdlType::Option(inner) => format!("Option<{}>", self.rust_type(inner)?),
+            IdlType::Vec(inner) => {
+                if is_big_array(inner) {
+                    bail!("arrays longer than {SERDE_MAX_ARRAY_LEN} are only supported as fields");
+                }
+                format!("Vec<{}>", self.rust_type(inner)?)
+            }
+            IdlType::Array(inner, len) => {
+                if is_big_array(inner) {
+                    bail!("arrays longer than {SERDE_MAX_ARRAY_LEN} are only supported as fields");
+                }
+                format!("[{}; {len}]", self.rust_type(inner)?)
+            }
+            IdlType::COption(_) => return Err(anyhow!("coption is not supported by codegen")),
+            IdlType::Defined(name) => {
+                if !self.types.contains_key(name) {
+                    bail!("undefined type {name}");
+                }
+                name.clone()
+            }
+        })
+    }
+}
+
+fn is_big_array(ty: &IdlType) -> bool {
+    matches!(ty, IdlType::Array(_, len) if *len > SERDE_MAX_ARRAY_LEN)
+}
+
+fn field_ident(name: &str) -> String {
+    let snake = to_snake_case(name);
+    if RUST_KEYWORDS.contains(&snake.as_str()) {
+        format!("r#{snake}")
+    } else {
+        snake
+    }
+}
+
+fn pascal_case(name: &str) -> String {
+    to_snake_case(name)
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn screaming_case(name: &str) -> String {
+    to_snake_case(name).to_ascii_uppercase()
+}
+
+/// 生成 `generated/mod.rs`
+pub fn generate_mod(modules: &[String]) -> String {
+    let mut out = String::from(
+        "//! Typed modules generated from `idl/*.json` by `idl::codegen`, do not edit.\n//!\n\
+         //! Regenerate with `cargo run --example idl_codegen`.\n\n",
+    );
+    for module in modules {
+        // 枚举变体大小由 IDL 决定
+        out.push_str(&format!(
+            "#[rustfmt::skip]\n#[allow(clippy::large_enum_variant)]\npub mod {module};\n"
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `idl/` 更新后需要重新生成 `generated/`
+    #[test]
+    fn test_generated_modules_are_up_to_date() {
+        let root = env!("CARGO_MANIFEST_DIR");
+        let mut modules = Vec::new();
+        for entry in std::fs::read_dir(format!("{root}/idl")).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_stem().unwrap().to_str().unwrap().to_string();
+            let source = format!("idl/{name}.json");
+            let code = generate(&std::fs::read_to_string(&path).unwrap(), &source).unwrap();
+            let generated = std::fs::read_to_string(format!(
+                "{root}/src/streaming/event_parser/idl/generated/{name}.rs"
+            ))
+            .unwrap_or_default();
+            assert!(code == generated, "{source} changed, run `cargo run --example idl_codegen`");
+            modules.push(name);
+        }
+        modules.sort();
+        let generated_mod = std::fs::read_to_string(format!(
+            "{root}/src/streaming/event_parser/idl/generated/mod.rs"
+        ))
+        .unwrap();
+        assert_eq!(generate_mod(&modules), generated_mod);
+    }
+
+    #[test]
+    fn test_generated_types_decode() {
+        use crate::streaming::event_parser::idl::{generated::pumpfun, IdlDecode};
+
+        let mut data = pumpfun::discriminators::BUY_IX.to_vec();
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&2_000u64.to_le_bytes());
+        data.push(1);
+        data.push(0xff); // 程序升级后追加的参数
+        let buy = pumpfun::BuyInstruction::decode(&data).unwrap();
+        assert_eq!(buy.track_volume, pumpfun::OptionBool(true));
+        assert_eq!((buy.amount, buy.max_sol_cost), (1_000, 2_000));
+        assert_eq!(pumpfun::BuyInstruction::ACCOUNTS[6], "user");
+    }
+}
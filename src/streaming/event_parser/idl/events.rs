@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::idl::value::IdlValue;
+
+/// IDL 事件来源
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IdlEventKind {
+    #[default]
+    Instruction,
+    /// `emit_cpi!` 事件（未能合并到指令时单独返回）
+    Event,
+    Account,
+}
+
+/// 具名账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdlAccount {
+    pub name: String,
+    pub pubkey: Pubkey,
+}
+
+/// 指令执行时通过 `emit_cpi!` 发出的事件
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct IdlCpiEvent {
+    pub name: String,
+    pub data: IdlValue,
+}
+
+/// 由已注册的 IDL 解码出的事件
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct IdlEvent {
+    pub metadata: EventMetadata,
+    /// IDL 程序名称（`IdlProgram::name`）
+    pub program: String,
+    pub kind: IdlEventKind,
+    /// 指令 / 事件 / 账户类型名称
+    pub name: String,
+    /// 指令参数、事件数据或账户数据
+    pub args: IdlValue,
+    /// 指令账户；账户事件为该账户本身
+    pub accounts: Vec<IdlAccount>,
+    pub cpi_event: Option<IdlCpiEvent>,
+}
+
+impl IdlEvent {
+    /// 按 IDL 中的名称获取账户地址
+    pub fn account(&self, name: &str) -> Option<Pubkey> {
+        self.accounts.iter().find(|a| a.name == name).map(|a| a.pubkey)
+    }
+}
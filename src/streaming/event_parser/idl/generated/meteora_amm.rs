@@ -0,0 +1,967 @@
+//! Generated from `idl/meteora_amm.json` by `idl::codegen`, do not edit.
+//!
+//! Regenerate with `cargo run --example idl_codegen`.
+
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::idl::IdlDecode;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquidity {
+    #[serde(rename = "lpMintAmount")]
+    pub lp_mint_amount: u64,
+    #[serde(rename = "tokenAAmount")]
+    pub token_aamount: u64,
+    #[serde(rename = "tokenBAmount")]
+    pub token_bamount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BootstrapLiquidity {
+    #[serde(rename = "lpMintAmount")]
+    pub lp_mint_amount: u64,
+    #[serde(rename = "tokenAAmount")]
+    pub token_aamount: u64,
+    #[serde(rename = "tokenBAmount")]
+    pub token_bamount: u64,
+    pub pool: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimFee {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    #[serde(rename = "aFee")]
+    pub a_fee: u64,
+    #[serde(rename = "bFee")]
+    pub b_fee: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateLockEscrow {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum CurveType {
+    ConstantProduct,
+    Stable {
+        amp: u64,
+        token_multiplier: TokenMultiplier,
+        depeg: Depeg,
+        last_amp_updated_timestamp: u64,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Depeg {
+    #[serde(rename = "baseVirtualPrice")]
+    pub base_virtual_price: u64,
+    #[serde(rename = "baseCacheUpdated")]
+    pub base_cache_updated: u64,
+    #[serde(rename = "depegType")]
+    pub depeg_type: DepegType,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum DepegType {
+    None,
+    Marinade,
+    Lido,
+    SplStake,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Lock {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LockEscrow {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    #[serde(rename = "escrowVault")]
+    pub escrow_vault: Pubkey,
+    pub bump: u8,
+    #[serde(rename = "totalLockedAmount")]
+    pub total_locked_amount: u64,
+    #[serde(rename = "lpPerToken")]
+    pub lp_per_token: u128,
+    #[serde(rename = "unclaimedFeePending")]
+    pub unclaimed_fee_pending: u64,
+    #[serde(rename = "aFee")]
+    pub a_fee: u64,
+    #[serde(rename = "bFee")]
+    pub b_fee: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MigrateFeeAccount {
+    pub pool: Pubkey,
+    #[serde(rename = "newAdminTokenAFee")]
+    pub new_admin_token_afee: Pubkey,
+    #[serde(rename = "newAdminTokenBFee")]
+    pub new_admin_token_bfee: Pubkey,
+    #[serde(rename = "tokenAAmount")]
+    pub token_aamount: u64,
+    #[serde(rename = "tokenBAmount")]
+    pub token_bamount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum NewCurveType {
+    ConstantProduct,
+    Stable {
+        amp: u64,
+        token_multiplier: TokenMultiplier,
+        depeg: Depeg,
+        last_amp_updated_timestamp: u64,
+    },
+    NewCurve {
+        field_one: u64,
+        field_two: u64,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OverrideCurveParam {
+    #[serde(rename = "newAmp")]
+    pub new_amp: u64,
+    #[serde(rename = "updatedTimestamp")]
+    pub updated_timestamp: u64,
+    pub pool: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Padding {
+    pub padding0: [u8; 7],
+    pub padding: [u128; 29],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Pool {
+    #[serde(rename = "lpMint")]
+    pub lp_mint: Pubkey,
+    #[serde(rename = "tokenAMint")]
+    pub token_amint: Pubkey,
+    #[serde(rename = "tokenBMint")]
+    pub token_bmint: Pubkey,
+    #[serde(rename = "aVault")]
+    pub a_vault: Pubkey,
+    #[serde(rename = "bVault")]
+    pub b_vault: Pubkey,
+    #[serde(rename = "aVaultLp")]
+    pub a_vault_lp: Pubkey,
+    #[serde(rename = "bVaultLp")]
+    pub b_vault_lp: Pubkey,
+    #[serde(rename = "aVaultLpBump")]
+    pub a_vault_lp_bump: u8,
+    pub enabled: bool,
+    #[serde(rename = "adminTokenAFee")]
+    pub admin_token_afee: Pubkey,
+    #[serde(rename = "adminTokenBFee")]
+    pub admin_token_bfee: Pubkey,
+    pub admin: Pubkey,
+    pub fees: PoolFees,
+    #[serde(rename = "poolType")]
+    pub pool_type: PoolType,
+    pub stake: Pubkey,
+    #[serde(rename = "totalLockedLp")]
+    pub total_locked_lp: u64,
+    pub padding: Padding,
+    #[serde(rename = "curveType")]
+    pub curve_type: CurveType,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolCreated {
+    #[serde(rename = "lpMint")]
+    pub lp_mint: Pubkey,
+    #[serde(rename = "tokenAMint")]
+    pub token_amint: Pubkey,
+    #[serde(rename = "tokenBMint")]
+    pub token_bmint: Pubkey,
+    #[serde(rename = "poolType")]
+    pub pool_type: PoolType,
+    pub pool: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolEnabled {
+    pub pool: Pubkey,
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolFees {
+    #[serde(rename = "tradeFeeNumerator")]
+    pub trade_fee_numerator: u64,
+    #[serde(rename = "tradeFeeDenominator")]
+    pub trade_fee_denominator: u64,
+    #[serde(rename = "ownerTradeFeeNumerator")]
+    pub owner_trade_fee_numerator: u64,
+    #[serde(rename = "ownerTradeFeeDenominator")]
+    pub owner_trade_fee_denominator: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolInfo {
+    #[serde(rename = "tokenAAmount")]
+    pub token_aamount: u64,
+    #[serde(rename = "tokenBAmount")]
+    pub token_bamount: u64,
+    #[serde(rename = "virtualPrice")]
+    pub virtual_price: f64,
+    #[serde(rename = "currentTimestamp")]
+    pub current_timestamp: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum PoolType {
+    Permissioned,
+    Permissionless,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemoveLiquidity {
+    #[serde(rename = "lpUnmintAmount")]
+    pub lp_unmint_amount: u64,
+    #[serde(rename = "tokenAOutAmount")]
+    pub token_aout_amount: u64,
+    #[serde(rename = "tokenBOutAmount")]
+    pub token_bout_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum RoundDirection {
+    Floor,
+    Ceiling,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum Rounding {
+    Up,
+    Down,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetAdminFeeAccount {
+    #[serde(rename = "adminTokenAFee")]
+    pub admin_token_afee: Pubkey,
+    #[serde(rename = "adminTokenBFee")]
+    pub admin_token_bfee: Pubkey,
+    #[serde(rename = "newAdminTokenAFee")]
+    pub new_admin_token_afee: Pubkey,
+    #[serde(rename = "newAdminTokenBFee")]
+    pub new_admin_token_bfee: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetPoolFees {
+    #[serde(rename = "tradeFeeNumerator")]
+    pub trade_fee_numerator: u64,
+    #[serde(rename = "tradeFeeDenominator")]
+    pub trade_fee_denominator: u64,
+    #[serde(rename = "ownerTradeFeeNumerator")]
+    pub owner_trade_fee_numerator: u64,
+    #[serde(rename = "ownerTradeFeeDenominator")]
+    pub owner_trade_fee_denominator: u64,
+    pub pool: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Swap {
+    #[serde(rename = "inAmount")]
+    pub in_amount: u64,
+    #[serde(rename = "outAmount")]
+    pub out_amount: u64,
+    #[serde(rename = "tradeFee")]
+    pub trade_fee: u64,
+    #[serde(rename = "adminFee")]
+    pub admin_fee: u64,
+    #[serde(rename = "hostFee")]
+    pub host_fee: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TokenMultiplier {
+    #[serde(rename = "tokenAMultiplier")]
+    pub token_amultiplier: u64,
+    #[serde(rename = "tokenBMultiplier")]
+    pub token_bmultiplier: u64,
+    #[serde(rename = "precisionFactor")]
+    pub precision_factor: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum TradeDirection {
+    AtoB,
+    BtoA,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TransferAdmin {
+    pub admin: Pubkey,
+    #[serde(rename = "newAdmin")]
+    pub new_admin: Pubkey,
+    pub pool: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePermissionedPoolInstruction {
+    #[serde(rename = "curveType")]
+    pub curve_type: CurveType,
+}
+
+impl InitializePermissionedPoolInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "lpMint",
+        "tokenAMint",
+        "tokenBMint",
+        "aVault",
+        "bVault",
+        "aVaultLpMint",
+        "bVaultLpMint",
+        "aVaultLp",
+        "bVaultLp",
+        "adminTokenA",
+        "adminTokenB",
+        "adminPoolLp",
+        "adminTokenAFee",
+        "adminTokenBFee",
+        "admin",
+        "feeOwner",
+        "rent",
+        "mintMetadata",
+        "metadataProgram",
+        "vaultProgram",
+        "tokenProgram",
+        "associatedTokenProgram",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for InitializePermissionedPoolInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[77, 85, 178, 157, 50, 48, 212, 126];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePermissionlessPoolInstruction {
+    #[serde(rename = "curveType")]
+    pub curve_type: CurveType,
+    #[serde(rename = "tokenAAmount")]
+    pub token_aamount: u64,
+    #[serde(rename = "tokenBAmount")]
+    pub token_bamount: u64,
+}
+
+impl InitializePermissionlessPoolInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "lpMint",
+        "tokenAMint",
+        "tokenBMint",
+        "aVault",
+        "bVault",
+        "aTokenVault",
+        "bTokenVault",
+        "aVaultLpMint",
+        "bVaultLpMint",
+        "aVaultLp",
+        "bVaultLp",
+        "payerTokenA",
+        "payerTokenB",
+        "payerPoolLp",
+        "adminTokenAFee",
+        "adminTokenBFee",
+        "payer",
+        "feeOwner",
+        "rent",
+        "mintMetadata",
+        "metadataProgram",
+        "vaultProgram",
+        "tokenProgram",
+        "associatedTokenProgram",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for InitializePermissionlessPoolInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[118, 173, 41, 157, 173, 72, 97, 103];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePermissionlessPoolWithFeeTierInstruction {
+    #[serde(rename = "curveType")]
+    pub curve_type: CurveType,
+    #[serde(rename = "tradeFeeBps")]
+    pub trade_fee_bps: u64,
+    #[serde(rename = "tokenAAmount")]
+    pub token_aamount: u64,
+    #[serde(rename = "tokenBAmount")]
+    pub token_bamount: u64,
+}
+
+impl InitializePermissionlessPoolWithFeeTierInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "lpMint",
+        "tokenAMint",
+        "tokenBMint",
+        "aVault",
+        "bVault",
+        "aTokenVault",
+        "bTokenVault",
+        "aVaultLpMint",
+        "bVaultLpMint",
+        "aVaultLp",
+        "bVaultLp",
+        "payerTokenA",
+        "payerTokenB",
+        "payerPoolLp",
+        "adminTokenAFee",
+        "adminTokenBFee",
+        "payer",
+        "feeOwner",
+        "rent",
+        "mintMetadata",
+        "metadataProgram",
+        "vaultProgram",
+        "tokenProgram",
+        "associatedTokenProgram",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for InitializePermissionlessPoolWithFeeTierInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[6, 135, 68, 147, 229, 82, 169, 113];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EnableOrDisablePoolInstruction {
+    pub enable: bool,
+}
+
+impl EnableOrDisablePoolInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "admin",
+    ];
+}
+
+impl IdlDecode for EnableOrDisablePoolInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[128, 6, 228, 131, 55, 161, 52, 169];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapInstruction {
+    #[serde(rename = "inAmount")]
+    pub in_amount: u64,
+    #[serde(rename = "minimumOutAmount")]
+    pub minimum_out_amount: u64,
+}
+
+impl SwapInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "userSourceToken",
+        "userDestinationToken",
+        "aVault",
+        "bVault",
+        "aTokenVault",
+        "bTokenVault",
+        "aVaultLpMint",
+        "bVaultLpMint",
+        "aVaultLp",
+        "bVaultLp",
+        "adminTokenFee",
+        "user",
+        "vaultProgram",
+        "tokenProgram",
+    ];
+}
+
+impl IdlDecode for SwapInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemoveLiquiditySingleSideInstruction {
+    #[serde(rename = "poolTokenAmount")]
+    pub pool_token_amount: u64,
+    #[serde(rename = "minimumOutAmount")]
+    pub minimum_out_amount: u64,
+}
+
+impl RemoveLiquiditySingleSideInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "lpMint",
+        "userPoolLp",
+        "aVaultLp",
+        "bVaultLp",
+        "aVault",
+        "bVault",
+        "aVaultLpMint",
+        "bVaultLpMint",
+        "aTokenVault",
+        "bTokenVault",
+        "userDestinationToken",
+        "user",
+        "vaultProgram",
+        "tokenProgram",
+    ];
+}
+
+impl IdlDecode for RemoveLiquiditySingleSideInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[84, 84, 177, 66, 254, 185, 10, 251];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddImbalanceLiquidityInstruction {
+    #[serde(rename = "minimumPoolTokenAmount")]
+    pub minimum_pool_token_amount: u64,
+    #[serde(rename = "tokenAAmount")]
+    pub token_aamount: u64,
+    #[serde(rename = "tokenBAmount")]
+    pub token_bamount: u64,
+}
+
+impl AddImbalanceLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "lpMint",
+        "userPoolLp",
+        "aVaultLp",
+        "bVaultLp",
+        "aVault",
+        "bVault",
+        "aVaultLpMint",
+        "bVaultLpMint",
+        "aTokenVault",
+        "bTokenVault",
+        "userAToken",
+        "userBToken",
+        "user",
+        "vaultProgram",
+        "tokenProgram",
+    ];
+}
+
+impl IdlDecode for AddImbalanceLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[79, 35, 122, 84, 173, 15, 93, 191];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemoveBalanceLiquidityInstruction {
+    #[serde(rename = "poolTokenAmount")]
+    pub pool_token_amount: u64,
+    #[serde(rename = "minimumATokenOut")]
+    pub minimum_atoken_out: u64,
+    #[serde(rename = "minimumBTokenOut")]
+    pub minimum_btoken_out: u64,
+}
+
+impl RemoveBalanceLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "lpMint",
+        "userPoolLp",
+        "aVaultLp",
+        "bVaultLp",
+        "aVault",
+        "bVault",
+        "aVaultLpMint",
+        "bVaultLpMint",
+        "aTokenVault",
+        "bTokenVault",
+        "userAToken",
+        "userBToken",
+        "user",
+        "vaultProgram",
+        "tokenProgram",
+    ];
+}
+
+impl IdlDecode for RemoveBalanceLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[133, 109, 44, 179, 56, 238, 114, 33];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddBalanceLiquidityInstruction {
+    #[serde(rename = "poolTokenAmount")]
+    pub pool_token_amount: u64,
+    #[serde(rename = "maximumTokenAAmount")]
+    pub maximum_token_aamount: u64,
+    #[serde(rename = "maximumTokenBAmount")]
+    pub maximum_token_bamount: u64,
+}
+
+impl AddBalanceLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "lpMint",
+        "userPoolLp",
+        "aVaultLp",
+        "bVaultLp",
+        "aVault",
+        "bVault",
+        "aVaultLpMint",
+        "bVaultLpMint",
+        "aTokenVault",
+        "bTokenVault",
+        "userAToken",
+        "userBToken",
+        "user",
+        "vaultProgram",
+        "tokenProgram",
+    ];
+}
+
+impl IdlDecode for AddBalanceLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[168, 227, 50, 62, 189, 171, 84, 176];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetPoolFeesInstruction {
+    pub fees: PoolFees,
+}
+
+impl SetPoolFeesInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "admin",
+    ];
+}
+
+impl IdlDecode for SetPoolFeesInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[102, 44, 158, 54, 205, 37, 126, 78];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OverrideCurveParamInstruction {
+    #[serde(rename = "curveType")]
+    pub curve_type: CurveType,
+}
+
+impl OverrideCurveParamInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "admin",
+    ];
+}
+
+impl IdlDecode for OverrideCurveParamInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[98, 86, 204, 51, 94, 71, 69, 187];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TransferAdminInstruction {}
+
+impl TransferAdminInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "admin",
+        "newAdmin",
+    ];
+}
+
+impl IdlDecode for TransferAdminInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[42, 242, 66, 106, 228, 10, 111, 156];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct GetPoolInfoInstruction {}
+
+impl GetPoolInfoInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "lpMint",
+        "aVaultLp",
+        "bVaultLp",
+        "aVault",
+        "bVault",
+        "aVaultLpMint",
+        "bVaultLpMint",
+    ];
+}
+
+impl IdlDecode for GetPoolInfoInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[9, 48, 220, 101, 22, 240, 78, 200];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BootstrapLiquidityInstruction {
+    #[serde(rename = "tokenAAmount")]
+    pub token_aamount: u64,
+    #[serde(rename = "tokenBAmount")]
+    pub token_bamount: u64,
+}
+
+impl BootstrapLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "lpMint",
+        "userPoolLp",
+        "aVaultLp",
+        "bVaultLp",
+        "aVault",
+        "bVault",
+        "aVaultLpMint",
+        "bVaultLpMint",
+        "aTokenVault",
+        "bTokenVault",
+        "userAToken",
+        "userBToken",
+        "user",
+        "vaultProgram",
+        "tokenProgram",
+    ];
+}
+
+impl IdlDecode for BootstrapLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[4, 228, 215, 71, 225, 253, 119, 206];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MigrateFeeAccountInstruction {}
+
+impl MigrateFeeAccountInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "aVaultLp",
+        "adminTokenAFee",
+        "adminTokenBFee",
+        "tokenAMint",
+        "tokenBMint",
+        "newAdminTokenAFee",
+        "newAdminTokenBFee",
+        "admin",
+        "treasuryTokenAFee",
+        "treasuryTokenBFee",
+        "treasury",
+        "tokenProgram",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for MigrateFeeAccountInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[223, 60, 126, 177, 109, 146, 65, 81];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateMintMetadataInstruction {}
+
+impl CreateMintMetadataInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "lpMint",
+        "aVaultLp",
+        "mintMetadata",
+        "metadataProgram",
+        "systemProgram",
+        "payer",
+    ];
+}
+
+impl IdlDecode for CreateMintMetadataInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[13, 70, 168, 41, 250, 100, 148, 90];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateLockEscrowInstruction {}
+
+impl CreateLockEscrowInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "lockEscrow",
+        "owner",
+        "lpMint",
+        "payer",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for CreateLockEscrowInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[54, 87, 165, 19, 69, 227, 218, 224];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LockInstruction {
+    pub amount: u64,
+}
+
+impl LockInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "lpMint",
+        "lockEscrow",
+        "owner",
+        "sourceTokens",
+        "escrowVault",
+        "tokenProgram",
+        "aVault",
+        "bVault",
+        "aVaultLp",
+        "bVaultLp",
+        "aVaultLpMint",
+        "bVaultLpMint",
+    ];
+}
+
+impl IdlDecode for LockInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[21, 19, 208, 43, 237, 62, 255, 87];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimFeeInstruction {
+    #[serde(rename = "maxAmount")]
+    pub max_amount: u64,
+}
+
+impl ClaimFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "lpMint",
+        "lockEscrow",
+        "owner",
+        "sourceTokens",
+        "escrowVault",
+        "tokenProgram",
+        "aTokenVault",
+        "bTokenVault",
+        "aVault",
+        "bVault",
+        "aVaultLp",
+        "bVaultLp",
+        "aVaultLpMint",
+        "bVaultLpMint",
+        "userAToken",
+        "userBToken",
+        "vaultProgram",
+    ];
+}
+
+impl IdlDecode for ClaimFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[169, 32, 79, 137, 136, 232, 70, 137];
+}
+
+impl IdlDecode for AddLiquidity {
+    const DISCRIMINATOR: &'static [u8] = &[31, 94, 125, 90, 227, 52, 61, 186];
+}
+
+impl IdlDecode for RemoveLiquidity {
+    const DISCRIMINATOR: &'static [u8] = &[116, 244, 97, 232, 103, 31, 152, 58];
+}
+
+impl IdlDecode for BootstrapLiquidity {
+    const DISCRIMINATOR: &'static [u8] = &[121, 127, 38, 136, 92, 55, 14, 247];
+}
+
+impl IdlDecode for Swap {
+    const DISCRIMINATOR: &'static [u8] = &[81, 108, 227, 190, 205, 208, 10, 196];
+}
+
+impl IdlDecode for SetPoolFees {
+    const DISCRIMINATOR: &'static [u8] = &[245, 26, 198, 164, 88, 18, 75, 9];
+}
+
+impl IdlDecode for PoolInfo {
+    const DISCRIMINATOR: &'static [u8] = &[207, 20, 87, 97, 251, 212, 234, 45];
+}
+
+impl IdlDecode for TransferAdmin {
+    const DISCRIMINATOR: &'static [u8] = &[228, 169, 131, 244, 61, 56, 65, 254];
+}
+
+impl IdlDecode for SetAdminFeeAccount {
+    const DISCRIMINATOR: &'static [u8] = &[109, 28, 28, 109, 255, 147, 219, 211];
+}
+
+impl IdlDecode for OverrideCurveParam {
+    const DISCRIMINATOR: &'static [u8] = &[247, 20, 165, 248, 75, 5, 54, 246];
+}
+
+impl IdlDecode for PoolCreated {
+    const DISCRIMINATOR: &'static [u8] = &[202, 44, 41, 88, 104, 220, 157, 82];
+}
+
+impl IdlDecode for PoolEnabled {
+    const DISCRIMINATOR: &'static [u8] = &[2, 151, 18, 83, 204, 134, 92, 191];
+}
+
+impl IdlDecode for MigrateFeeAccount {
+    const DISCRIMINATOR: &'static [u8] = &[223, 234, 232, 26, 252, 105, 180, 125];
+}
+
+impl IdlDecode for CreateLockEscrow {
+    const DISCRIMINATOR: &'static [u8] = &[74, 94, 106, 141, 49, 17, 98, 109];
+}
+
+impl IdlDecode for Lock {
+    const DISCRIMINATOR: &'static [u8] = &[220, 183, 67, 215, 153, 207, 56, 234];
+}
+
+impl IdlDecode for ClaimFee {
+    const DISCRIMINATOR: &'static [u8] = &[75, 122, 154, 48, 140, 74, 123, 163];
+}
+
+impl IdlDecode for Pool {
+    const DISCRIMINATOR: &'static [u8] = &[241, 154, 109, 4, 17, 177, 109, 188];
+}
+
+impl IdlDecode for LockEscrow {
+    const DISCRIMINATOR: &'static [u8] = &[190, 106, 121, 6, 200, 182, 21, 75];
+}
+
+/// Discriminator constants
+pub mod discriminators {
+    // Instruction discriminators
+    pub const INITIALIZE_PERMISSIONED_POOL_IX: &[u8] = &[77, 85, 178, 157, 50, 48, 212, 126];
+    pub const INITIALIZE_PERMISSIONLESS_POOL_IX: &[u8] = &[118, 173, 41, 157, 173, 72, 97, 103];
+    pub const INITIALIZE_PERMISSIONLESS_POOL_WITH_FEE_TIER_IX: &[u8] = &[6, 135, 68, 147, 229, 82, 169, 113];
+    pub const ENABLE_OR_DISABLE_POOL_IX: &[u8] = &[128, 6, 228, 131, 55, 161, 52, 169];
+    pub const SWAP_IX: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+    pub const REMOVE_LIQUIDITY_SINGLE_SIDE_IX: &[u8] = &[84, 84, 177, 66, 254, 185, 10, 251];
+    pub const ADD_IMBALANCE_LIQUIDITY_IX: &[u8] = &[79, 35, 122, 84, 173, 15, 93, 191];
+    pub const REMOVE_BALANCE_LIQUIDITY_IX: &[u8] = &[133, 109, 44, 179, 56, 238, 114, 33];
+    pub const ADD_BALANCE_LIQUIDITY_IX: &[u8] = &[168, 227, 50, 62, 189, 171, 84, 176];
+    pub const SET_POOL_FEES_IX: &[u8] = &[102, 44, 158, 54, 205, 37, 126, 78];
+    pub const OVERRIDE_CURVE_PARAM_IX: &[u8] = &[98, 86, 204, 51, 94, 71, 69, 187];
+    pub const TRANSFER_ADMIN_IX: &[u8] = &[42, 242, 66, 106, 228, 10, 111, 156];
+    pub const GET_POOL_INFO_IX: &[u8] = &[9, 48, 220, 101, 22, 240, 78, 200];
+    pub const BOOTSTRAP_LIQUIDITY_IX: &[u8] = &[4, 228, 215, 71, 225, 253, 119, 206];
+    pub const MIGRATE_FEE_ACCOUNT_IX: &[u8] = &[223, 60, 126, 177, 109, 146, 65, 81];
+    pub const CREATE_MINT_METADATA_IX: &[u8] = &[13, 70, 168, 41, 250, 100, 148, 90];
+    pub const CREATE_LOCK_ESCROW_IX: &[u8] = &[54, 87, 165, 19, 69, 227, 218, 224];
+    pub const LOCK_IX: &[u8] = &[21, 19, 208, 43, 237, 62, 255, 87];
+    pub const CLAIM_FEE_IX: &[u8] = &[169, 32, 79, 137, 136, 232, 70, 137];
+
+    // Event discriminators
+    pub const ADD_LIQUIDITY_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 31, 94, 125, 90, 227, 52, 61, 186];
+    pub const REMOVE_LIQUIDITY_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 116, 244, 97, 232, 103, 31, 152, 58];
+    pub const BOOTSTRAP_LIQUIDITY_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 121, 127, 38, 136, 92, 55, 14, 247];
+    pub const SWAP_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 81, 108, 227, 190, 205, 208, 10, 196];
+    pub const SET_POOL_FEES_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 245, 26, 198, 164, 88, 18, 75, 9];
+    pub const POOL_INFO_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 207, 20, 87, 97, 251, 212, 234, 45];
+    pub const TRANSFER_ADMIN_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 228, 169, 131, 244, 61, 56, 65, 254];
+    pub const SET_ADMIN_FEE_ACCOUNT_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 109, 28, 28, 109, 255, 147, 219, 211];
+    pub const OVERRIDE_CURVE_PARAM_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 247, 20, 165, 248, 75, 5, 54, 246];
+    pub const POOL_CREATED_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 202, 44, 41, 88, 104, 220, 157, 82];
+    pub const POOL_ENABLED_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 2, 151, 18, 83, 204, 134, 92, 191];
+    pub const MIGRATE_FEE_ACCOUNT_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 223, 234, 232, 26, 252, 105, 180, 125];
+    pub const CREATE_LOCK_ESCROW_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 74, 94, 106, 141, 49, 17, 98, 109];
+    pub const LOCK_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 220, 183, 67, 215, 153, 207, 56, 234];
+    pub const CLAIM_FEE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 75, 122, 154, 48, 140, 74, 123, 163];
+
+    // Account discriminators
+    pub const POOL_ACCOUNT: &[u8] = &[241, 154, 109, 4, 17, 177, 109, 188];
+    pub const LOCK_ESCROW_ACCOUNT: &[u8] = &[190, 106, 121, 6, 200, 182, 21, 75];
+}
@@ -0,0 +1,1703 @@
+//! Generated from `idl/meteora_damm_v2.json` by `idl::codegen`, do not edit.
+//!
+//! Regenerate with `cargo run --example idl_codegen`.
+
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::idl::IdlDecode;
+
+pub const PROGRAM_ID: Pubkey = solana_sdk::pubkey!("cpamdpZCGKUy5JxQXB4dcpGPiikHawvSWAd6mEn1sGG");
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquidityParameters {
+    pub liquidity_delta: u128,
+    pub token_a_amount_threshold: u64,
+    pub token_b_amount_threshold: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BaseFeeInfo {
+    pub data: [u8; 32],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BaseFeeParameters {
+    pub data: [u8; 30],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BaseFeeStruct {
+    pub base_fee_info: BaseFeeInfo,
+    pub padding_1: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BorshFeeMarketCapScheduler {
+    pub cliff_fee_numerator: u64,
+    pub number_of_period: u16,
+    pub sqrt_price_step_bps: u32,
+    pub scheduler_expiration_duration: u32,
+    pub reduction_factor: u64,
+    pub base_fee_mode: u8,
+    pub padding: [u8; 3],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BorshFeeRateLimiter {
+    pub cliff_fee_numerator: u64,
+    pub fee_increment_bps: u16,
+    pub max_limiter_duration: u32,
+    pub max_fee_bps: u32,
+    pub reference_amount: u64,
+    pub base_fee_mode: u8,
+    pub padding: [u8; 3],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BorshFeeTimeScheduler {
+    pub cliff_fee_numerator: u64,
+    pub number_of_period: u16,
+    pub period_frequency: u64,
+    pub reduction_factor: u64,
+    pub base_fee_mode: u8,
+    pub padding: [u8; 3],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Config {
+    pub vault_config_key: Pubkey,
+    pub pool_creator_authority: Pubkey,
+    pub pool_fees: PoolFeesConfig,
+    pub activation_type: u8,
+    pub collect_fee_mode: u8,
+    pub config_type: u8,
+    pub _padding_0: [u8; 5],
+    pub index: u64,
+    pub sqrt_min_price: u128,
+    pub sqrt_max_price: u128,
+    pub _padding_1: [u64; 10],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DummyParams {
+    pub borsh_fee_time_scheduler_params: BorshFeeTimeScheduler,
+    pub borsh_fee_rate_limiter_params: BorshFeeRateLimiter,
+    pub borsh_fee_market_cap_scheduler_params: BorshFeeMarketCapScheduler,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DynamicConfigParameters {
+    pub pool_creator_authority: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DynamicFeeConfig {
+    pub initialized: u8,
+    pub padding: [u8; 7],
+    pub max_volatility_accumulator: u32,
+    pub variable_fee_control: u32,
+    pub bin_step: u16,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub padding_1: [u8; 8],
+    pub bin_step_u128: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DynamicFeeParameters {
+    pub bin_step: u16,
+    pub bin_step_u128: u128,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub max_volatility_accumulator: u32,
+    pub variable_fee_control: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DynamicFeeStruct {
+    pub initialized: u8,
+    pub padding: [u8; 7],
+    pub max_volatility_accumulator: u32,
+    pub variable_fee_control: u32,
+    pub bin_step: u16,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub last_update_timestamp: u64,
+    pub bin_step_u128: u128,
+    pub sqrt_price_reference: u128,
+    pub volatility_accumulator: u128,
+    pub volatility_reference: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtClaimPartnerFee {
+    pub pool: Pubkey,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtClaimPositionFee {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub fee_a_claimed: u64,
+    pub fee_b_claimed: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtClaimProtocolFee {
+    pub pool: Pubkey,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtClaimReward {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub mint_reward: Pubkey,
+    pub reward_index: u8,
+    pub total_reward: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtCloseConfig {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtClosePosition {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub position: Pubkey,
+    pub position_nft_mint: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtCreateConfig {
+    pub pool_fees: PoolFeeParameters,
+    pub vault_config_key: Pubkey,
+    pub pool_creator_authority: Pubkey,
+    pub activation_type: u8,
+    pub sqrt_min_price: u128,
+    pub sqrt_max_price: u128,
+    pub collect_fee_mode: u8,
+    pub index: u64,
+    pub config: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtCreateDynamicConfig {
+    pub config: Pubkey,
+    pub pool_creator_authority: Pubkey,
+    pub index: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtCreatePosition {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub position: Pubkey,
+    pub position_nft_mint: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtCreateTokenBadge {
+    pub token_mint: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtFundReward {
+    pub pool: Pubkey,
+    pub funder: Pubkey,
+    pub mint_reward: Pubkey,
+    pub reward_index: u8,
+    pub amount: u64,
+    pub transfer_fee_excluded_amount_in: u64,
+    pub reward_duration_end: u64,
+    pub pre_reward_rate: u128,
+    pub post_reward_rate: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtInitializePool {
+    pub pool: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub creator: Pubkey,
+    pub payer: Pubkey,
+    pub alpha_vault: Pubkey,
+    pub pool_fees: PoolFeeParameters,
+    pub sqrt_min_price: u128,
+    pub sqrt_max_price: u128,
+    pub activation_type: u8,
+    pub collect_fee_mode: u8,
+    pub liquidity: u128,
+    pub sqrt_price: u128,
+    pub activation_point: u64,
+    pub token_a_flag: u8,
+    pub token_b_flag: u8,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub total_amount_a: u64,
+    pub total_amount_b: u64,
+    pub pool_type: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtInitializeReward {
+    pub pool: Pubkey,
+    pub reward_mint: Pubkey,
+    pub funder: Pubkey,
+    pub creator: Pubkey,
+    pub reward_index: u8,
+    pub reward_duration: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtLiquidityChange {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub transfer_fee_included_token_a_amount: u64,
+    pub transfer_fee_included_token_b_amount: u64,
+    pub reserve_a_amount: u64,
+    pub reserve_b_amount: u64,
+    pub liquidity_delta: u128,
+    pub token_a_amount_threshold: u64,
+    pub token_b_amount_threshold: u64,
+    pub change_type: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtLockPosition {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub vesting: Pubkey,
+    pub cliff_point: u64,
+    pub period_frequency: u64,
+    pub cliff_unlock_liquidity: u128,
+    pub liquidity_per_period: u128,
+    pub number_of_period: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtPermanentLockPosition {
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub lock_liquidity_amount: u128,
+    pub total_permanent_locked_liquidity: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtSetPoolStatus {
+    pub pool: Pubkey,
+    pub status: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtSplitPosition2 {
+    pub pool: Pubkey,
+    pub first_owner: Pubkey,
+    pub second_owner: Pubkey,
+    pub first_position: Pubkey,
+    pub second_position: Pubkey,
+    pub current_sqrt_price: u128,
+    pub amount_splits: SplitAmountInfo,
+    pub first_position_info: SplitPositionInfo,
+    pub second_position_info: SplitPositionInfo,
+    pub split_position_parameters: SplitPositionParameters2,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtSwap2 {
+    pub pool: Pubkey,
+    pub trade_direction: u8,
+    pub collect_fee_mode: u8,
+    pub has_referral: bool,
+    pub params: SwapParameters2,
+    pub swap_result: SwapResult2,
+    pub included_transfer_fee_amount_in: u64,
+    pub included_transfer_fee_amount_out: u64,
+    pub excluded_transfer_fee_amount_out: u64,
+    pub current_timestamp: u64,
+    pub reserve_a_amount: u64,
+    pub reserve_b_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtUpdatePoolFees {
+    pub pool: Pubkey,
+    pub operator: Pubkey,
+    pub params: UpdatePoolFeesParameters,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtUpdateRewardDuration {
+    pub pool: Pubkey,
+    pub reward_index: u8,
+    pub old_reward_duration: u64,
+    pub new_reward_duration: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtUpdateRewardFunder {
+    pub pool: Pubkey,
+    pub reward_index: u8,
+    pub old_funder: Pubkey,
+    pub new_funder: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct EvtWithdrawIneligibleReward {
+    pub pool: Pubkey,
+    pub reward_mint: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeCustomizablePoolParameters {
+    pub pool_fees: PoolFeeParameters,
+    pub sqrt_min_price: u128,
+    pub sqrt_max_price: u128,
+    pub has_alpha_vault: bool,
+    pub liquidity: u128,
+    pub sqrt_price: u128,
+    pub activation_type: u8,
+    pub collect_fee_mode: u8,
+    pub activation_point: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePoolParameters {
+    pub liquidity: u128,
+    pub sqrt_price: u128,
+    pub activation_point: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Operator {
+    pub whitelisted_address: Pubkey,
+    pub permission: u128,
+    pub padding: [u64; 2],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PodAlignedFeeMarketCapScheduler {
+    pub cliff_fee_numerator: u64,
+    pub base_fee_mode: u8,
+    pub padding: [u8; 5],
+    pub number_of_period: u16,
+    pub sqrt_price_step_bps: u32,
+    pub scheduler_expiration_duration: u32,
+    pub reduction_factor: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PodAlignedFeeRateLimiter {
+    pub cliff_fee_numerator: u64,
+    pub base_fee_mode: u8,
+    pub padding: [u8; 5],
+    pub fee_increment_bps: u16,
+    pub max_limiter_duration: u32,
+    pub max_fee_bps: u32,
+    pub reference_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PodAlignedFeeTimeScheduler {
+    pub cliff_fee_numerator: u64,
+    pub base_fee_mode: u8,
+    pub padding: [u8; 5],
+    pub number_of_period: u16,
+    pub period_frequency: u64,
+    pub reduction_factor: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Pool {
+    pub pool_fees: PoolFeesStruct,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub token_a_vault: Pubkey,
+    pub token_b_vault: Pubkey,
+    pub whitelisted_vault: Pubkey,
+    pub partner: Pubkey,
+    pub liquidity: u128,
+    pub _padding: u128,
+    pub protocol_a_fee: u64,
+    pub protocol_b_fee: u64,
+    pub partner_a_fee: u64,
+    pub partner_b_fee: u64,
+    pub sqrt_min_price: u128,
+    pub sqrt_max_price: u128,
+    pub sqrt_price: u128,
+    pub activation_point: u64,
+    pub activation_type: u8,
+    pub pool_status: u8,
+    pub token_a_flag: u8,
+    pub token_b_flag: u8,
+    pub collect_fee_mode: u8,
+    pub pool_type: u8,
+    pub version: u8,
+    pub _padding_0: u8,
+    pub fee_a_per_liquidity: [u8; 32],
+    pub fee_b_per_liquidity: [u8; 32],
+    pub permanent_lock_liquidity: u128,
+    pub metrics: PoolMetrics,
+    pub creator: Pubkey,
+    pub _padding_1: [u64; 6],
+    pub reward_infos: [RewardInfo; 2],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolFeeParameters {
+    pub base_fee: BaseFeeParameters,
+    pub dynamic_fee: Option<DynamicFeeParameters>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolFeesConfig {
+    pub base_fee: BaseFeeInfo,
+    pub dynamic_fee: DynamicFeeConfig,
+    pub protocol_fee_percent: u8,
+    pub partner_fee_percent: u8,
+    pub referral_fee_percent: u8,
+    pub padding_0: [u8; 5],
+    pub padding_1: [u64; 5],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolFeesStruct {
+    pub base_fee: BaseFeeStruct,
+    pub protocol_fee_percent: u8,
+    pub partner_fee_percent: u8,
+    pub referral_fee_percent: u8,
+    pub padding_0: [u8; 5],
+    pub dynamic_fee: DynamicFeeStruct,
+    pub init_sqrt_price: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolMetrics {
+    pub total_lp_a_fee: u128,
+    pub total_lp_b_fee: u128,
+    pub total_protocol_a_fee: u64,
+    pub total_protocol_b_fee: u64,
+    pub total_partner_a_fee: u64,
+    pub total_partner_b_fee: u64,
+    pub total_position: u64,
+    pub padding: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Position {
+    pub pool: Pubkey,
+    pub nft_mint: Pubkey,
+    pub fee_a_per_token_checkpoint: [u8; 32],
+    pub fee_b_per_token_checkpoint: [u8; 32],
+    pub fee_a_pending: u64,
+    pub fee_b_pending: u64,
+    pub unlocked_liquidity: u128,
+    pub vested_liquidity: u128,
+    pub permanent_locked_liquidity: u128,
+    pub metrics: PositionMetrics,
+    pub reward_infos: [UserRewardInfo; 2],
+    pub padding: [u128; 6],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PositionMetrics {
+    pub total_claimed_a_fee: u64,
+    pub total_claimed_b_fee: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemoveLiquidityParameters {
+    pub liquidity_delta: u128,
+    pub token_a_amount_threshold: u64,
+    pub token_b_amount_threshold: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RewardInfo {
+    pub initialized: u8,
+    pub reward_token_flag: u8,
+    pub _padding_0: [u8; 6],
+    pub _padding_1: [u8; 8],
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub funder: Pubkey,
+    pub reward_duration: u64,
+    pub reward_duration_end: u64,
+    pub reward_rate: u128,
+    pub reward_per_token_stored: [u8; 32],
+    pub last_update_time: u64,
+    pub cumulative_seconds_with_empty_liquidity_reward: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SplitAmountInfo {
+    pub permanent_locked_liquidity: u128,
+    pub unlocked_liquidity: u128,
+    pub fee_a: u64,
+    pub fee_b: u64,
+    pub reward_0: u64,
+    pub reward_1: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SplitPositionInfo {
+    pub liquidity: u128,
+    pub fee_a: u64,
+    pub fee_b: u64,
+    pub reward_0: u64,
+    pub reward_1: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SplitPositionParameters {
+    pub unlocked_liquidity_percentage: u8,
+    pub permanent_locked_liquidity_percentage: u8,
+    pub fee_a_percentage: u8,
+    pub fee_b_percentage: u8,
+    pub reward_0_percentage: u8,
+    pub reward_1_percentage: u8,
+    pub padding: [u8; 16],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SplitPositionParameters2 {
+    pub unlocked_liquidity_numerator: u32,
+    pub permanent_locked_liquidity_numerator: u32,
+    pub fee_a_numerator: u32,
+    pub fee_b_numerator: u32,
+    pub reward_0_numerator: u32,
+    pub reward_1_numerator: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct StaticConfigParameters {
+    pub pool_fees: PoolFeeParameters,
+    pub sqrt_min_price: u128,
+    pub sqrt_max_price: u128,
+    pub vault_config_key: Pubkey,
+    pub pool_creator_authority: Pubkey,
+    pub activation_type: u8,
+    pub collect_fee_mode: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapParameters {
+    pub amount_in: u64,
+    pub minimum_amount_out: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapParameters2 {
+    pub amount_0: u64,
+    pub amount_1: u64,
+    pub swap_mode: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapResult2 {
+    pub included_fee_input_amount: u64,
+    pub excluded_fee_input_amount: u64,
+    pub amount_left: u64,
+    pub output_amount: u64,
+    pub next_sqrt_price: u128,
+    pub trading_fee: u64,
+    pub protocol_fee: u64,
+    pub partner_fee: u64,
+    pub referral_fee: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TokenBadge {
+    pub token_mint: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub _padding: [u8; 128],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdatePoolFeesParameters {
+    pub cliff_fee_numerator: Option<u64>,
+    pub dynamic_fee: Option<DynamicFeeParameters>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UserRewardInfo {
+    pub reward_per_token_checkpoint: [u8; 32],
+    pub reward_pendings: u64,
+    pub total_claimed_rewards: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Vesting {
+    pub position: Pubkey,
+    pub cliff_point: u64,
+    pub period_frequency: u64,
+    pub cliff_unlock_liquidity: u128,
+    pub liquidity_per_period: u128,
+    pub total_released_liquidity: u128,
+    pub number_of_period: u16,
+    pub padding: [u8; 14],
+    pub padding2: [u128; 4],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct VestingParameters {
+    pub cliff_point: Option<u64>,
+    pub period_frequency: u64,
+    pub cliff_unlock_liquidity: u128,
+    pub liquidity_per_period: u128,
+    pub number_of_period: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquidityInstruction {
+    pub params: AddLiquidityParameters,
+}
+
+impl AddLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "position",
+        "token_a_account",
+        "token_b_account",
+        "token_a_vault",
+        "token_b_vault",
+        "token_a_mint",
+        "token_b_mint",
+        "position_nft_account",
+        "owner",
+        "token_a_program",
+        "token_b_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AddLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[181, 157, 89, 67, 143, 182, 52, 72];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimPartnerFeeInstruction {
+    pub max_amount_a: u64,
+    pub max_amount_b: u64,
+}
+
+impl ClaimPartnerFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool_authority",
+        "pool",
+        "token_a_account",
+        "token_b_account",
+        "token_a_vault",
+        "token_b_vault",
+        "token_a_mint",
+        "token_b_mint",
+        "partner",
+        "token_a_program",
+        "token_b_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ClaimPartnerFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[97, 206, 39, 105, 94, 94, 126, 148];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimPositionFeeInstruction {}
+
+impl ClaimPositionFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool_authority",
+        "pool",
+        "position",
+        "token_a_account",
+        "token_b_account",
+        "token_a_vault",
+        "token_b_vault",
+        "token_a_mint",
+        "token_b_mint",
+        "position_nft_account",
+        "owner",
+        "token_a_program",
+        "token_b_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ClaimPositionFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[180, 38, 154, 17, 133, 33, 162, 211];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimProtocolFeeInstruction {
+    pub max_amount_a: u64,
+    pub max_amount_b: u64,
+}
+
+impl ClaimProtocolFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool_authority",
+        "pool",
+        "token_a_vault",
+        "token_b_vault",
+        "token_a_mint",
+        "token_b_mint",
+        "token_a_account",
+        "token_b_account",
+        "operator",
+        "signer",
+        "token_a_program",
+        "token_b_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ClaimProtocolFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[165, 228, 133, 48, 99, 249, 255, 33];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimRewardInstruction {
+    pub reward_index: u8,
+    pub skip_reward: u8,
+}
+
+impl ClaimRewardInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool_authority",
+        "pool",
+        "position",
+        "reward_vault",
+        "reward_mint",
+        "user_token_account",
+        "position_nft_account",
+        "owner",
+        "token_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ClaimRewardInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[149, 95, 181, 242, 94, 90, 158, 162];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CloseConfigInstruction {}
+
+impl CloseConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "config",
+        "operator",
+        "signer",
+        "rent_receiver",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CloseConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[145, 9, 72, 157, 95, 125, 61, 85];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CloseOperatorAccountInstruction {}
+
+impl CloseOperatorAccountInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "operator",
+        "signer",
+        "rent_receiver",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CloseOperatorAccountInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[171, 9, 213, 74, 120, 23, 3, 29];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClosePositionInstruction {}
+
+impl ClosePositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position_nft_mint",
+        "position_nft_account",
+        "pool",
+        "position",
+        "pool_authority",
+        "rent_receiver",
+        "owner",
+        "token_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ClosePositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[123, 134, 81, 0, 49, 68, 98, 98];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CloseTokenBadgeInstruction {}
+
+impl CloseTokenBadgeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "token_badge",
+        "operator",
+        "signer",
+        "rent_receiver",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CloseTokenBadgeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[108, 146, 86, 110, 179, 254, 10, 104];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateConfigInstruction {
+    pub index: u64,
+    pub config_parameters: StaticConfigParameters,
+}
+
+impl CreateConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "config",
+        "operator",
+        "signer",
+        "payer",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CreateConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[201, 207, 243, 114, 75, 111, 47, 189];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateDynamicConfigInstruction {
+    pub index: u64,
+    pub config_parameters: DynamicConfigParameters,
+}
+
+impl CreateDynamicConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "config",
+        "operator",
+        "signer",
+        "payer",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CreateDynamicConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[81, 251, 122, 78, 66, 57, 208, 82];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateOperatorAccountInstruction {
+    pub permission: u128,
+}
+
+impl CreateOperatorAccountInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "operator",
+        "whitelisted_address",
+        "signer",
+        "payer",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CreateOperatorAccountInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[221, 64, 246, 149, 240, 153, 229, 163];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreatePositionInstruction {}
+
+impl CreatePositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "position_nft_mint",
+        "position_nft_account",
+        "pool",
+        "position",
+        "pool_authority",
+        "payer",
+        "token_program",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CreatePositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[48, 215, 197, 153, 96, 203, 180, 133];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateTokenBadgeInstruction {}
+
+impl CreateTokenBadgeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "token_badge",
+        "token_mint",
+        "operator",
+        "signer",
+        "payer",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CreateTokenBadgeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[88, 206, 0, 91, 60, 175, 151, 118];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DummyIxInstruction {
+    pub _ixs: DummyParams,
+}
+
+impl DummyIxInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pod_aligned_fee_time_scheduler",
+        "pod_aligned_fee_rate_limiter",
+        "pod_aligned_fee_market_cap_scheduler",
+    ];
+}
+
+impl IdlDecode for DummyIxInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[234, 95, 176, 185, 7, 42, 35, 159];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FundRewardInstruction {
+    pub reward_index: u8,
+    pub amount: u64,
+    pub carry_forward: bool,
+}
+
+impl FundRewardInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "reward_vault",
+        "reward_mint",
+        "funder_token_account",
+        "funder",
+        "token_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for FundRewardInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[188, 50, 249, 165, 93, 151, 38, 63];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeCustomizablePoolInstruction {
+    pub params: InitializeCustomizablePoolParameters,
+}
+
+impl InitializeCustomizablePoolInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "creator",
+        "position_nft_mint",
+        "position_nft_account",
+        "payer",
+        "pool_authority",
+        "pool",
+        "position",
+        "token_a_mint",
+        "token_b_mint",
+        "token_a_vault",
+        "token_b_vault",
+        "payer_token_a",
+        "payer_token_b",
+        "token_a_program",
+        "token_b_program",
+        "token_2022_program",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializeCustomizablePoolInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[20, 161, 241, 24, 189, 221, 180, 2];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePoolInstruction {
+    pub params: InitializePoolParameters,
+}
+
+impl InitializePoolInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "creator",
+        "position_nft_mint",
+        "position_nft_account",
+        "payer",
+        "config",
+        "pool_authority",
+        "pool",
+        "position",
+        "token_a_mint",
+        "token_b_mint",
+        "token_a_vault",
+        "token_b_vault",
+        "payer_token_a",
+        "payer_token_b",
+        "token_a_program",
+        "token_b_program",
+        "token_2022_program",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializePoolInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[95, 180, 10, 172, 84, 174, 232, 40];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePoolWithDynamicConfigInstruction {
+    pub params: InitializeCustomizablePoolParameters,
+}
+
+impl InitializePoolWithDynamicConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "creator",
+        "position_nft_mint",
+        "position_nft_account",
+        "payer",
+        "pool_creator_authority",
+        "config",
+        "pool_authority",
+        "pool",
+        "position",
+        "token_a_mint",
+        "token_b_mint",
+        "token_a_vault",
+        "token_b_vault",
+        "payer_token_a",
+        "payer_token_b",
+        "token_a_program",
+        "token_b_program",
+        "token_2022_program",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializePoolWithDynamicConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[149, 82, 72, 197, 253, 252, 68, 15];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeRewardInstruction {
+    pub reward_index: u8,
+    pub reward_duration: u64,
+    pub funder: Pubkey,
+}
+
+impl InitializeRewardInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool_authority",
+        "pool",
+        "reward_vault",
+        "reward_mint",
+        "signer",
+        "payer",
+        "token_program",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializeRewardInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[95, 135, 192, 196, 242, 129, 230, 68];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LockPositionInstruction {
+    pub params: VestingParameters,
+}
+
+impl LockPositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "position",
+        "vesting",
+        "position_nft_account",
+        "owner",
+        "payer",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for LockPositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[227, 62, 2, 252, 247, 10, 171, 185];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PermanentLockPositionInstruction {
+    pub permanent_lock_liquidity: u128,
+}
+
+impl PermanentLockPositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "position",
+        "position_nft_account",
+        "owner",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for PermanentLockPositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[165, 176, 125, 6, 231, 171, 186, 213];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RefreshVestingInstruction {}
+
+impl RefreshVestingInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "position",
+        "position_nft_account",
+        "owner",
+    ];
+}
+
+impl IdlDecode for RefreshVestingInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[9, 94, 216, 14, 116, 204, 247, 0];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemoveAllLiquidityInstruction {
+    pub token_a_amount_threshold: u64,
+    pub token_b_amount_threshold: u64,
+}
+
+impl RemoveAllLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool_authority",
+        "pool",
+        "position",
+        "token_a_account",
+        "token_b_account",
+        "token_a_vault",
+        "token_b_vault",
+        "token_a_mint",
+        "token_b_mint",
+        "position_nft_account",
+        "owner",
+        "token_a_program",
+        "token_b_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for RemoveAllLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[10, 51, 61, 35, 112, 105, 24, 85];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemoveLiquidityInstruction {
+    pub params: RemoveLiquidityParameters,
+}
+
+impl RemoveLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool_authority",
+        "pool",
+        "position",
+        "token_a_account",
+        "token_b_account",
+        "token_a_vault",
+        "token_b_vault",
+        "token_a_mint",
+        "token_b_mint",
+        "position_nft_account",
+        "owner",
+        "token_a_program",
+        "token_b_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for RemoveLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[80, 85, 209, 72, 24, 206, 177, 108];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetPoolStatusInstruction {
+    pub status: u8,
+}
+
+impl SetPoolStatusInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "operator",
+        "signer",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SetPoolStatusInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[112, 87, 135, 223, 83, 204, 132, 53];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SplitPositionInstruction {
+    pub params: SplitPositionParameters,
+}
+
+impl SplitPositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "first_position",
+        "first_position_nft_account",
+        "second_position",
+        "second_position_nft_account",
+        "first_owner",
+        "second_owner",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SplitPositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[172, 241, 221, 138, 161, 29, 253, 42];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SplitPosition2Instruction {
+    pub numerator: u32,
+}
+
+impl SplitPosition2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "first_position",
+        "first_position_nft_account",
+        "second_position",
+        "second_position_nft_account",
+        "first_owner",
+        "second_owner",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SplitPosition2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[221, 147, 228, 207, 140, 212, 17, 119];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapInstruction {
+    pub _params: SwapParameters,
+}
+
+impl SwapInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool_authority",
+        "pool",
+        "input_token_account",
+        "output_token_account",
+        "token_a_vault",
+        "token_b_vault",
+        "token_a_mint",
+        "token_b_mint",
+        "payer",
+        "token_a_program",
+        "token_b_program",
+        "referral_token_account",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SwapInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Swap2Instruction {
+    pub _params: SwapParameters2,
+}
+
+impl Swap2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool_authority",
+        "pool",
+        "input_token_account",
+        "output_token_account",
+        "token_a_vault",
+        "token_b_vault",
+        "token_a_mint",
+        "token_b_mint",
+        "payer",
+        "token_a_program",
+        "token_b_program",
+        "referral_token_account",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for Swap2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[65, 75, 63, 76, 235, 91, 91, 136];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdatePoolFeesInstruction {
+    pub params: UpdatePoolFeesParameters,
+}
+
+impl UpdatePoolFeesInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "operator",
+        "signer",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for UpdatePoolFeesInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[118, 217, 203, 179, 60, 8, 70, 89];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateRewardDurationInstruction {
+    pub reward_index: u8,
+    pub new_duration: u64,
+}
+
+impl UpdateRewardDurationInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "signer",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for UpdateRewardDurationInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[138, 174, 196, 169, 213, 235, 254, 107];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateRewardFunderInstruction {
+    pub reward_index: u8,
+    pub new_funder: Pubkey,
+}
+
+impl UpdateRewardFunderInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "signer",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for UpdateRewardFunderInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[211, 28, 48, 32, 215, 160, 35, 23];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawIneligibleRewardInstruction {
+    pub reward_index: u8,
+}
+
+impl WithdrawIneligibleRewardInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool_authority",
+        "pool",
+        "reward_vault",
+        "reward_mint",
+        "funder_token_account",
+        "funder",
+        "token_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for WithdrawIneligibleRewardInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[148, 206, 42, 195, 247, 49, 103, 8];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ZapProtocolFeeInstruction {
+    pub max_amount: u64,
+}
+
+impl ZapProtocolFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool_authority",
+        "pool",
+        "token_vault",
+        "token_mint",
+        "receiver_token",
+        "operator",
+        "signer",
+        "token_program",
+        "sysvar_instructions",
+    ];
+}
+
+impl IdlDecode for ZapProtocolFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[213, 155, 187, 34, 56, 182, 91, 240];
+}
+
+impl IdlDecode for EvtClaimPartnerFee {
+    const DISCRIMINATOR: &'static [u8] = &[118, 99, 77, 10, 226, 1, 1, 87];
+}
+
+impl IdlDecode for EvtClaimPositionFee {
+    const DISCRIMINATOR: &'static [u8] = &[198, 182, 183, 52, 97, 12, 49, 56];
+}
+
+impl IdlDecode for EvtClaimProtocolFee {
+    const DISCRIMINATOR: &'static [u8] = &[186, 244, 75, 251, 188, 13, 25, 33];
+}
+
+impl IdlDecode for EvtClaimReward {
+    const DISCRIMINATOR: &'static [u8] = &[218, 86, 147, 200, 235, 188, 215, 231];
+}
+
+impl IdlDecode for EvtCloseConfig {
+    const DISCRIMINATOR: &'static [u8] = &[36, 30, 239, 45, 58, 132, 14, 5];
+}
+
+impl IdlDecode for EvtClosePosition {
+    const DISCRIMINATOR: &'static [u8] = &[20, 145, 144, 68, 143, 142, 214, 178];
+}
+
+impl IdlDecode for EvtCreateConfig {
+    const DISCRIMINATOR: &'static [u8] = &[131, 207, 180, 174, 180, 73, 165, 54];
+}
+
+impl IdlDecode for EvtCreateDynamicConfig {
+    const DISCRIMINATOR: &'static [u8] = &[231, 197, 13, 164, 248, 213, 133, 152];
+}
+
+impl IdlDecode for EvtCreatePosition {
+    const DISCRIMINATOR: &'static [u8] = &[156, 15, 119, 198, 29, 181, 221, 55];
+}
+
+impl IdlDecode for EvtCreateTokenBadge {
+    const DISCRIMINATOR: &'static [u8] = &[141, 120, 134, 116, 34, 28, 114, 160];
+}
+
+impl IdlDecode for EvtFundReward {
+    const DISCRIMINATOR: &'static [u8] = &[104, 233, 237, 122, 199, 191, 121, 85];
+}
+
+impl IdlDecode for EvtInitializePool {
+    const DISCRIMINATOR: &'static [u8] = &[228, 50, 246, 85, 203, 66, 134, 37];
+}
+
+impl IdlDecode for EvtInitializeReward {
+    const DISCRIMINATOR: &'static [u8] = &[129, 91, 188, 3, 246, 52, 185, 249];
+}
+
+impl IdlDecode for EvtLiquidityChange {
+    const DISCRIMINATOR: &'static [u8] = &[197, 171, 78, 127, 224, 211, 87, 13];
+}
+
+impl IdlDecode for EvtLockPosition {
+    const DISCRIMINATOR: &'static [u8] = &[168, 63, 108, 83, 219, 82, 2, 200];
+}
+
+impl IdlDecode for EvtPermanentLockPosition {
+    const DISCRIMINATOR: &'static [u8] = &[145, 143, 162, 218, 218, 80, 67, 11];
+}
+
+impl IdlDecode for EvtSetPoolStatus {
+    const DISCRIMINATOR: &'static [u8] = &[100, 213, 74, 3, 95, 91, 228, 146];
+}
+
+impl IdlDecode for EvtSplitPosition2 {
+    const DISCRIMINATOR: &'static [u8] = &[165, 32, 203, 174, 72, 100, 233, 103];
+}
+
+impl IdlDecode for EvtSwap2 {
+    const DISCRIMINATOR: &'static [u8] = &[189, 66, 51, 168, 38, 80, 117, 153];
+}
+
+impl IdlDecode for EvtUpdatePoolFees {
+    const DISCRIMINATOR: &'static [u8] = &[76, 165, 246, 102, 102, 217, 156, 44];
+}
+
+impl IdlDecode for EvtUpdateRewardDuration {
+    const DISCRIMINATOR: &'static [u8] = &[149, 135, 65, 231, 129, 153, 65, 57];
+}
+
+impl IdlDecode for EvtUpdateRewardFunder {
+    const DISCRIMINATOR: &'static [u8] = &[76, 154, 208, 13, 40, 115, 246, 146];
+}
+
+impl IdlDecode for EvtWithdrawIneligibleReward {
+    const DISCRIMINATOR: &'static [u8] = &[248, 215, 184, 78, 31, 180, 179, 168];
+}
+
+impl IdlDecode for Config {
+    const DISCRIMINATOR: &'static [u8] = &[155, 12, 170, 224, 30, 250, 204, 130];
+}
+
+impl IdlDecode for Operator {
+    const DISCRIMINATOR: &'static [u8] = &[219, 31, 188, 145, 69, 139, 204, 117];
+}
+
+impl IdlDecode for PodAlignedFeeMarketCapScheduler {
+    const DISCRIMINATOR: &'static [u8] = &[251, 130, 208, 253, 245, 27, 145, 203];
+}
+
+impl IdlDecode for PodAlignedFeeRateLimiter {
+    const DISCRIMINATOR: &'static [u8] = &[160, 219, 8, 251, 179, 7, 16, 117];
+}
+
+impl IdlDecode for PodAlignedFeeTimeScheduler {
+    const DISCRIMINATOR: &'static [u8] = &[239, 132, 138, 213, 67, 154, 130, 70];
+}
+
+impl IdlDecode for Pool {
+    const DISCRIMINATOR: &'static [u8] = &[241, 154, 109, 4, 17, 177, 109, 188];
+}
+
+impl IdlDecode for Position {
+    const DISCRIMINATOR: &'static [u8] = &[170, 188, 143, 228, 122, 64, 247, 208];
+}
+
+impl IdlDecode for TokenBadge {
+    const DISCRIMINATOR: &'static [u8] = &[116, 219, 204, 229, 249, 116, 255, 150];
+}
+
+impl IdlDecode for Vesting {
+    const DISCRIMINATOR: &'static [u8] = &[100, 149, 66, 138, 95, 200, 128, 241];
+}
+
+/// Discriminator constants
+pub mod discriminators {
+    // Instruction discriminators
+    pub const ADD_LIQUIDITY_IX: &[u8] = &[181, 157, 89, 67, 143, 182, 52, 72];
+    pub const CLAIM_PARTNER_FEE_IX: &[u8] = &[97, 206, 39, 105, 94, 94, 126, 148];
+    pub const CLAIM_POSITION_FEE_IX: &[u8] = &[180, 38, 154, 17, 133, 33, 162, 211];
+    pub const CLAIM_PROTOCOL_FEE_IX: &[u8] = &[165, 228, 133, 48, 99, 249, 255, 33];
+    pub const CLAIM_REWARD_IX: &[u8] = &[149, 95, 181, 242, 94, 90, 158, 162];
+    pub const CLOSE_CONFIG_IX: &[u8] = &[145, 9, 72, 157, 95, 125, 61, 85];
+    pub const CLOSE_OPERATOR_ACCOUNT_IX: &[u8] = &[171, 9, 213, 74, 120, 23, 3, 29];
+    pub const CLOSE_POSITION_IX: &[u8] = &[123, 134, 81, 0, 49, 68, 98, 98];
+    pub const CLOSE_TOKEN_BADGE_IX: &[u8] = &[108, 146, 86, 110, 179, 254, 10, 104];
+    pub const CREATE_CONFIG_IX: &[u8] = &[201, 207, 243, 114, 75, 111, 47, 189];
+    pub const CREATE_DYNAMIC_CONFIG_IX: &[u8] = &[81, 251, 122, 78, 66, 57, 208, 82];
+    pub const CREATE_OPERATOR_ACCOUNT_IX: &[u8] = &[221, 64, 246, 149, 240, 153, 229, 163];
+    pub const CREATE_POSITION_IX: &[u8] = &[48, 215, 197, 153, 96, 203, 180, 133];
+    pub const CREATE_TOKEN_BADGE_IX: &[u8] = &[88, 206, 0, 91, 60, 175, 151, 118];
+    pub const DUMMY_IX_IX: &[u8] = &[234, 95, 176, 185, 7, 42, 35, 159];
+    pub const FUND_REWARD_IX: &[u8] = &[188, 50, 249, 165, 93, 151, 38, 63];
+    pub const INITIALIZE_CUSTOMIZABLE_POOL_IX: &[u8] = &[20, 161, 241, 24, 189, 221, 180, 2];
+    pub const INITIALIZE_POOL_IX: &[u8] = &[95, 180, 10, 172, 84, 174, 232, 40];
+    pub const INITIALIZE_POOL_WITH_DYNAMIC_CONFIG_IX: &[u8] = &[149, 82, 72, 197, 253, 252, 68, 15];
+    pub const INITIALIZE_REWARD_IX: &[u8] = &[95, 135, 192, 196, 242, 129, 230, 68];
+    pub const LOCK_POSITION_IX: &[u8] = &[227, 62, 2, 252, 247, 10, 171, 185];
+    pub const PERMANENT_LOCK_POSITION_IX: &[u8] = &[165, 176, 125, 6, 231, 171, 186, 213];
+    pub const REFRESH_VESTING_IX: &[u8] = &[9, 94, 216, 14, 116, 204, 247, 0];
+    pub const REMOVE_ALL_LIQUIDITY_IX: &[u8] = &[10, 51, 61, 35, 112, 105, 24, 85];
+    pub const REMOVE_LIQUIDITY_IX: &[u8] = &[80, 85, 209, 72, 24, 206, 177, 108];
+    pub const SET_POOL_STATUS_IX: &[u8] = &[112, 87, 135, 223, 83, 204, 132, 53];
+    pub const SPLIT_POSITION_IX: &[u8] = &[172, 241, 221, 138, 161, 29, 253, 42];
+    pub const SPLIT_POSITION2_IX: &[u8] = &[221, 147, 228, 207, 140, 212, 17, 119];
+    pub const SWAP_IX: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+    pub const SWAP2_IX: &[u8] = &[65, 75, 63, 76, 235, 91, 91, 136];
+    pub const UPDATE_POOL_FEES_IX: &[u8] = &[118, 217, 203, 179, 60, 8, 70, 89];
+    pub const UPDATE_REWARD_DURATION_IX: &[u8] = &[138, 174, 196, 169, 213, 235, 254, 107];
+    pub const UPDATE_REWARD_FUNDER_IX: &[u8] = &[211, 28, 48, 32, 215, 160, 35, 23];
+    pub const WITHDRAW_INELIGIBLE_REWARD_IX: &[u8] = &[148, 206, 42, 195, 247, 49, 103, 8];
+    pub const ZAP_PROTOCOL_FEE_IX: &[u8] = &[213, 155, 187, 34, 56, 182, 91, 240];
+
+    // Event discriminators
+    pub const EVT_CLAIM_PARTNER_FEE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 118, 99, 77, 10, 226, 1, 1, 87];
+    pub const EVT_CLAIM_POSITION_FEE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 198, 182, 183, 52, 97, 12, 49, 56];
+    pub const EVT_CLAIM_PROTOCOL_FEE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 186, 244, 75, 251, 188, 13, 25, 33];
+    pub const EVT_CLAIM_REWARD_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 218, 86, 147, 200, 235, 188, 215, 231];
+    pub const EVT_CLOSE_CONFIG_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 36, 30, 239, 45, 58, 132, 14, 5];
+    pub const EVT_CLOSE_POSITION_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 20, 145, 144, 68, 143, 142, 214, 178];
+    pub const EVT_CREATE_CONFIG_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 131, 207, 180, 174, 180, 73, 165, 54];
+    pub const EVT_CREATE_DYNAMIC_CONFIG_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 231, 197, 13, 164, 248, 213, 133, 152];
+    pub const EVT_CREATE_POSITION_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 156, 15, 119, 198, 29, 181, 221, 55];
+    pub const EVT_CREATE_TOKEN_BADGE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 141, 120, 134, 116, 34, 28, 114, 160];
+    pub const EVT_FUND_REWARD_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 104, 233, 237, 122, 199, 191, 121, 85];
+    pub const EVT_INITIALIZE_POOL_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 228, 50, 246, 85, 203, 66, 134, 37];
+    pub const EVT_INITIALIZE_REWARD_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 129, 91, 188, 3, 246, 52, 185, 249];
+    pub const EVT_LIQUIDITY_CHANGE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 197, 171, 78, 127, 224, 211, 87, 13];
+    pub const EVT_LOCK_POSITION_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 168, 63, 108, 83, 219, 82, 2, 200];
+    pub const EVT_PERMANENT_LOCK_POSITION_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 145, 143, 162, 218, 218, 80, 67, 11];
+    pub const EVT_SET_POOL_STATUS_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 100, 213, 74, 3, 95, 91, 228, 146];
+    pub const EVT_SPLIT_POSITION2_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 165, 32, 203, 174, 72, 100, 233, 103];
+    pub const EVT_SWAP2_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 189, 66, 51, 168, 38, 80, 117, 153];
+    pub const EVT_UPDATE_POOL_FEES_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 76, 165, 246, 102, 102, 217, 156, 44];
+    pub const EVT_UPDATE_REWARD_DURATION_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 149, 135, 65, 231, 129, 153, 65, 57];
+    pub const EVT_UPDATE_REWARD_FUNDER_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 76, 154, 208, 13, 40, 115, 246, 146];
+    pub const EVT_WITHDRAW_INELIGIBLE_REWARD_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 248, 215, 184, 78, 31, 180, 179, 168];
+
+    // Account discriminators
+    pub const CONFIG_ACCOUNT: &[u8] = &[155, 12, 170, 224, 30, 250, 204, 130];
+    pub const OPERATOR_ACCOUNT: &[u8] = &[219, 31, 188, 145, 69, 139, 204, 117];
+    pub const POD_ALIGNED_FEE_MARKET_CAP_SCHEDULER_ACCOUNT: &[u8] = &[251, 130, 208, 253, 245, 27, 145, 203];
+    pub const POD_ALIGNED_FEE_RATE_LIMITER_ACCOUNT: &[u8] = &[160, 219, 8, 251, 179, 7, 16, 117];
+    pub const POD_ALIGNED_FEE_TIME_SCHEDULER_ACCOUNT: &[u8] = &[239, 132, 138, 213, 67, 154, 130, 70];
+    pub const POOL_ACCOUNT: &[u8] = &[241, 154, 109, 4, 17, 177, 109, 188];
+    pub const POSITION_ACCOUNT: &[u8] = &[170, 188, 143, 228, 122, 64, 247, 208];
+    pub const TOKEN_BADGE_ACCOUNT: &[u8] = &[116, 219, 204, 229, 249, 116, 255, 150];
+    pub const VESTING_ACCOUNT: &[u8] = &[100, 149, 66, 138, 95, 200, 128, 241];
+}
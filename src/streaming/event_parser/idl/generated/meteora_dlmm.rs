@@ -0,0 +1,2728 @@
+//! Generated from `idl/meteora_dlmm.json` by `idl::codegen`, do not edit.
+//!
+//! Regenerate with `cargo run --example idl_codegen`.
+
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::idl::IdlDecode;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum AccountsType {
+    TransferHookX,
+    TransferHookY,
+    TransferHookReward,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum ActivationType {
+    Slot,
+    Timestamp,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquidity {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    pub from: Pubkey,
+    pub position: Pubkey,
+    pub amounts: [u64; 2],
+    #[serde(rename = "activeBinId")]
+    pub active_bin_id: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquiditySingleSidePreciseParameter {
+    pub bins: Vec<CompressedBinDepositAmount>,
+    #[serde(rename = "decompressMultiplier")]
+    pub decompress_multiplier: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquiditySingleSidePreciseParameter2 {
+    pub bins: Vec<CompressedBinDepositAmount>,
+    #[serde(rename = "decompressMultiplier")]
+    pub decompress_multiplier: u64,
+    #[serde(rename = "maxAmount")]
+    pub max_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BaseFeeParameter {
+    #[serde(rename = "protocolShare")]
+    pub protocol_share: u16,
+    #[serde(rename = "baseFactor")]
+    pub base_factor: u16,
+    #[serde(rename = "baseFeePowerFactor")]
+    pub base_fee_power_factor: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Bin {
+    #[serde(rename = "amountX")]
+    pub amount_x: u64,
+    #[serde(rename = "amountY")]
+    pub amount_y: u64,
+    pub price: u128,
+    #[serde(rename = "liquiditySupply")]
+    pub liquidity_supply: u128,
+    #[serde(rename = "rewardPerTokenStored")]
+    pub reward_per_token_stored: [u128; 2],
+    #[serde(rename = "feeAmountXPerTokenStored")]
+    pub fee_amount_xper_token_stored: u128,
+    #[serde(rename = "feeAmountYPerTokenStored")]
+    pub fee_amount_yper_token_stored: u128,
+    #[serde(rename = "amountXIn")]
+    pub amount_xin: u128,
+    #[serde(rename = "amountYIn")]
+    pub amount_yin: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BinArray {
+    pub index: i64,
+    pub version: u8,
+    pub padding: [u8; 7],
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub bins: [Bin; 70],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BinArrayBitmapExtension {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    #[serde(rename = "positiveBinArrayBitmap")]
+    pub positive_bin_array_bitmap: [[u64; 8]; 12],
+    #[serde(rename = "negativeBinArrayBitmap")]
+    pub negative_bin_array_bitmap: [[u64; 8]; 12],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BinLiquidityDistribution {
+    #[serde(rename = "binId")]
+    pub bin_id: i32,
+    #[serde(rename = "distributionX")]
+    pub distribution_x: u16,
+    #[serde(rename = "distributionY")]
+    pub distribution_y: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BinLiquidityDistributionByWeight {
+    #[serde(rename = "binId")]
+    pub bin_id: i32,
+    pub weight: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BinLiquidityReduction {
+    #[serde(rename = "binId")]
+    pub bin_id: i32,
+    #[serde(rename = "bpsToRemove")]
+    pub bps_to_remove: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimFee {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    #[serde(rename = "feeX")]
+    pub fee_x: u64,
+    #[serde(rename = "feeY")]
+    pub fee_y: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimFeeOperator {
+    pub operator: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub padding: [u8; 128],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimReward {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u64,
+    #[serde(rename = "totalReward")]
+    pub total_reward: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CompositionFee {
+    pub from: Pubkey,
+    #[serde(rename = "binId")]
+    pub bin_id: i16,
+    #[serde(rename = "tokenXFeeAmount")]
+    pub token_xfee_amount: u64,
+    #[serde(rename = "tokenYFeeAmount")]
+    pub token_yfee_amount: u64,
+    #[serde(rename = "protocolTokenXFeeAmount")]
+    pub protocol_token_xfee_amount: u64,
+    #[serde(rename = "protocolTokenYFeeAmount")]
+    pub protocol_token_yfee_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CompressedBinDepositAmount {
+    #[serde(rename = "binId")]
+    pub bin_id: i32,
+    pub amount: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CompressedBinDepositAmount2 {
+    #[serde(rename = "binId")]
+    pub bin_id: i32,
+    pub amount: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CustomizableParams {
+    #[serde(rename = "activeId")]
+    pub active_id: i32,
+    #[serde(rename = "binStep")]
+    pub bin_step: u16,
+    #[serde(rename = "baseFactor")]
+    pub base_factor: u16,
+    #[serde(rename = "activationType")]
+    pub activation_type: u8,
+    #[serde(rename = "hasAlphaVault")]
+    pub has_alpha_vault: bool,
+    #[serde(rename = "activationPoint")]
+    pub activation_point: Option<u64>,
+    #[serde(rename = "creatorPoolOnOffControl")]
+    pub creator_pool_on_off_control: bool,
+    #[serde(rename = "baseFeePowerFactor")]
+    pub base_fee_power_factor: u8,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub padding: [u8; 62],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DecreasePositionLength {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    #[serde(rename = "lengthToRemove")]
+    pub length_to_remove: u16,
+    pub side: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DynamicFeeParameter {
+    #[serde(rename = "filterPeriod")]
+    pub filter_period: u16,
+    #[serde(rename = "decayPeriod")]
+    pub decay_period: u16,
+    #[serde(rename = "reductionFactor")]
+    pub reduction_factor: u16,
+    #[serde(rename = "variableFeeControl")]
+    pub variable_fee_control: u32,
+    #[serde(rename = "maxVolatilityAccumulator")]
+    pub max_volatility_accumulator: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DynamicFeeParameterUpdate {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    #[serde(rename = "filterPeriod")]
+    pub filter_period: u16,
+    #[serde(rename = "decayPeriod")]
+    pub decay_period: u16,
+    #[serde(rename = "reductionFactor")]
+    pub reduction_factor: u16,
+    #[serde(rename = "variableFeeControl")]
+    pub variable_fee_control: u32,
+    #[serde(rename = "maxVolatilityAccumulator")]
+    pub max_volatility_accumulator: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FeeInfo {
+    #[serde(rename = "feeXPerTokenComplete")]
+    pub fee_xper_token_complete: u128,
+    #[serde(rename = "feeYPerTokenComplete")]
+    pub fee_yper_token_complete: u128,
+    #[serde(rename = "feeXPending")]
+    pub fee_xpending: u64,
+    #[serde(rename = "feeYPending")]
+    pub fee_ypending: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FeeParameterUpdate {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    #[serde(rename = "protocolShare")]
+    pub protocol_share: u16,
+    #[serde(rename = "baseFactor")]
+    pub base_factor: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FundReward {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    pub funder: Pubkey,
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u64,
+    pub amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct GoToABin {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    #[serde(rename = "fromBinId")]
+    pub from_bin_id: i32,
+    #[serde(rename = "toBinId")]
+    pub to_bin_id: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct IncreaseObservation {
+    pub oracle: Pubkey,
+    #[serde(rename = "newObservationLength")]
+    pub new_observation_length: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct IncreasePositionLength {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    #[serde(rename = "lengthToAdd")]
+    pub length_to_add: u16,
+    pub side: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitPermissionPairIx {
+    #[serde(rename = "activeId")]
+    pub active_id: i32,
+    #[serde(rename = "binStep")]
+    pub bin_step: u16,
+    #[serde(rename = "baseFactor")]
+    pub base_factor: u16,
+    #[serde(rename = "baseFeePowerFactor")]
+    pub base_fee_power_factor: u8,
+    #[serde(rename = "activationType")]
+    pub activation_type: u8,
+    #[serde(rename = "protocolShare")]
+    pub protocol_share: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitPresetParameters2Ix {
+    pub index: u16,
+    #[serde(rename = "binStep")]
+    pub bin_step: u16,
+    #[serde(rename = "baseFactor")]
+    pub base_factor: u16,
+    #[serde(rename = "filterPeriod")]
+    pub filter_period: u16,
+    #[serde(rename = "decayPeriod")]
+    pub decay_period: u16,
+    #[serde(rename = "reductionFactor")]
+    pub reduction_factor: u16,
+    #[serde(rename = "variableFeeControl")]
+    pub variable_fee_control: u32,
+    #[serde(rename = "maxVolatilityAccumulator")]
+    pub max_volatility_accumulator: u32,
+    #[serde(rename = "protocolShare")]
+    pub protocol_share: u16,
+    #[serde(rename = "baseFeePowerFactor")]
+    pub base_fee_power_factor: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitPresetParametersIx {
+    #[serde(rename = "binStep")]
+    pub bin_step: u16,
+    #[serde(rename = "baseFactor")]
+    pub base_factor: u16,
+    #[serde(rename = "filterPeriod")]
+    pub filter_period: u16,
+    #[serde(rename = "decayPeriod")]
+    pub decay_period: u16,
+    #[serde(rename = "reductionFactor")]
+    pub reduction_factor: u16,
+    #[serde(rename = "variableFeeControl")]
+    pub variable_fee_control: u32,
+    #[serde(rename = "maxVolatilityAccumulator")]
+    pub max_volatility_accumulator: u32,
+    #[serde(rename = "protocolShare")]
+    pub protocol_share: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeLbPair2Params {
+    #[serde(rename = "activeId")]
+    pub active_id: i32,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub padding: [u8; 96],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeReward {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    #[serde(rename = "rewardMint")]
+    pub reward_mint: Pubkey,
+    pub funder: Pubkey,
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u64,
+    #[serde(rename = "rewardDuration")]
+    pub reward_duration: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum LayoutVersion {
+    V0,
+    V1,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LbPair {
+    pub parameters: StaticParameters,
+    #[serde(rename = "vParameters")]
+    pub v_parameters: VariableParameters,
+    #[serde(rename = "bumpSeed")]
+    pub bump_seed: [u8; 1],
+    #[serde(rename = "binStepSeed")]
+    pub bin_step_seed: [u8; 2],
+    #[serde(rename = "pairType")]
+    pub pair_type: u8,
+    #[serde(rename = "activeId")]
+    pub active_id: i32,
+    #[serde(rename = "binStep")]
+    pub bin_step: u16,
+    pub status: u8,
+    #[serde(rename = "requireBaseFactorSeed")]
+    pub require_base_factor_seed: u8,
+    #[serde(rename = "baseFactorSeed")]
+    pub base_factor_seed: [u8; 2],
+    #[serde(rename = "activationType")]
+    pub activation_type: u8,
+    #[serde(rename = "creatorPoolOnOffControl")]
+    pub creator_pool_on_off_control: u8,
+    #[serde(rename = "tokenXMint")]
+    pub token_xmint: Pubkey,
+    #[serde(rename = "tokenYMint")]
+    pub token_ymint: Pubkey,
+    #[serde(rename = "reserveX")]
+    pub reserve_x: Pubkey,
+    #[serde(rename = "reserveY")]
+    pub reserve_y: Pubkey,
+    #[serde(rename = "protocolFee")]
+    pub protocol_fee: ProtocolFee,
+    pub padding1: [u8; 32],
+    #[serde(rename = "rewardInfos")]
+    pub reward_infos: [RewardInfo; 2],
+    pub oracle: Pubkey,
+    #[serde(rename = "binArrayBitmap")]
+    pub bin_array_bitmap: [u64; 16],
+    #[serde(rename = "lastUpdatedAt")]
+    pub last_updated_at: i64,
+    pub padding2: [u8; 32],
+    #[serde(rename = "preActivationSwapAddress")]
+    pub pre_activation_swap_address: Pubkey,
+    #[serde(rename = "baseKey")]
+    pub base_key: Pubkey,
+    #[serde(rename = "activationPoint")]
+    pub activation_point: u64,
+    #[serde(rename = "preActivationDuration")]
+    pub pre_activation_duration: u64,
+    pub padding3: [u8; 8],
+    pub padding4: u64,
+    pub creator: Pubkey,
+    #[serde(rename = "tokenMintXProgramFlag")]
+    pub token_mint_xprogram_flag: u8,
+    #[serde(rename = "tokenMintYProgramFlag")]
+    pub token_mint_yprogram_flag: u8,
+    pub reserved: [u8; 22],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LbPairCreate {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    #[serde(rename = "binStep")]
+    pub bin_step: u16,
+    #[serde(rename = "tokenX")]
+    pub token_x: Pubkey,
+    #[serde(rename = "tokenY")]
+    pub token_y: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LiquidityOneSideParameter {
+    pub amount: u64,
+    #[serde(rename = "activeId")]
+    pub active_id: i32,
+    #[serde(rename = "maxActiveBinSlippage")]
+    pub max_active_bin_slippage: i32,
+    #[serde(rename = "binLiquidityDist")]
+    pub bin_liquidity_dist: Vec<BinLiquidityDistributionByWeight>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LiquidityParameter {
+    #[serde(rename = "amountX")]
+    pub amount_x: u64,
+    #[serde(rename = "amountY")]
+    pub amount_y: u64,
+    #[serde(rename = "binLiquidityDist")]
+    pub bin_liquidity_dist: Vec<BinLiquidityDistribution>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LiquidityParameterByStrategy {
+    #[serde(rename = "amountX")]
+    pub amount_x: u64,
+    #[serde(rename = "amountY")]
+    pub amount_y: u64,
+    #[serde(rename = "activeId")]
+    pub active_id: i32,
+    #[serde(rename = "maxActiveBinSlippage")]
+    pub max_active_bin_slippage: i32,
+    #[serde(rename = "strategyParameters")]
+    pub strategy_parameters: StrategyParameters,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LiquidityParameterByStrategyOneSide {
+    pub amount: u64,
+    #[serde(rename = "activeId")]
+    pub active_id: i32,
+    #[serde(rename = "maxActiveBinSlippage")]
+    pub max_active_bin_slippage: i32,
+    #[serde(rename = "strategyParameters")]
+    pub strategy_parameters: StrategyParameters,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LiquidityParameterByWeight {
+    #[serde(rename = "amountX")]
+    pub amount_x: u64,
+    #[serde(rename = "amountY")]
+    pub amount_y: u64,
+    #[serde(rename = "activeId")]
+    pub active_id: i32,
+    #[serde(rename = "maxActiveBinSlippage")]
+    pub max_active_bin_slippage: i32,
+    #[serde(rename = "binLiquidityDist")]
+    pub bin_liquidity_dist: Vec<BinLiquidityDistributionByWeight>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Observation {
+    #[serde(rename = "cumulativeActiveBinId")]
+    pub cumulative_active_bin_id: i128,
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+    #[serde(rename = "lastUpdatedAt")]
+    pub last_updated_at: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Oracle {
+    pub idx: u64,
+    #[serde(rename = "activeSize")]
+    pub active_size: u64,
+    pub length: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum PairStatus {
+    Enabled,
+    Disabled,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum PairType {
+    Permissionless,
+    Permission,
+    CustomizablePermissionless,
+    PermissionlessV2,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Position {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    pub owner: Pubkey,
+    #[serde(rename = "liquidityShares")]
+    #[serde(with = "serde_big_array::BigArray")]
+    pub liquidity_shares: [u64; 70],
+    #[serde(rename = "rewardInfos")]
+    #[serde(with = "serde_big_array::BigArray")]
+    pub reward_infos: [UserRewardInfo; 70],
+    #[serde(rename = "feeInfos")]
+    #[serde(with = "serde_big_array::BigArray")]
+    pub fee_infos: [FeeInfo; 70],
+    #[serde(rename = "lowerBinId")]
+    pub lower_bin_id: i32,
+    #[serde(rename = "upperBinId")]
+    pub upper_bin_id: i32,
+    #[serde(rename = "lastUpdatedAt")]
+    pub last_updated_at: i64,
+    #[serde(rename = "totalClaimedFeeXAmount")]
+    pub total_claimed_fee_xamount: u64,
+    #[serde(rename = "totalClaimedFeeYAmount")]
+    pub total_claimed_fee_yamount: u64,
+    #[serde(rename = "totalClaimedRewards")]
+    pub total_claimed_rewards: [u64; 2],
+    #[serde(with = "serde_big_array::BigArray")]
+    pub reserved: [u8; 160],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PositionClose {
+    pub position: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PositionCreate {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PositionV2 {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    pub owner: Pubkey,
+    #[serde(rename = "liquidityShares")]
+    #[serde(with = "serde_big_array::BigArray")]
+    pub liquidity_shares: [u128; 70],
+    #[serde(rename = "rewardInfos")]
+    #[serde(with = "serde_big_array::BigArray")]
+    pub reward_infos: [UserRewardInfo; 70],
+    #[serde(rename = "feeInfos")]
+    #[serde(with = "serde_big_array::BigArray")]
+    pub fee_infos: [FeeInfo; 70],
+    #[serde(rename = "lowerBinId")]
+    pub lower_bin_id: i32,
+    #[serde(rename = "upperBinId")]
+    pub upper_bin_id: i32,
+    #[serde(rename = "lastUpdatedAt")]
+    pub last_updated_at: i64,
+    #[serde(rename = "totalClaimedFeeXAmount")]
+    pub total_claimed_fee_xamount: u64,
+    #[serde(rename = "totalClaimedFeeYAmount")]
+    pub total_claimed_fee_yamount: u64,
+    #[serde(rename = "totalClaimedRewards")]
+    pub total_claimed_rewards: [u64; 2],
+    pub operator: Pubkey,
+    #[serde(rename = "lockReleasePoint")]
+    pub lock_release_point: u64,
+    pub padding0: u8,
+    #[serde(rename = "feeOwner")]
+    pub fee_owner: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub reserved: [u8; 87],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PresetParameter {
+    #[serde(rename = "binStep")]
+    pub bin_step: u16,
+    #[serde(rename = "baseFactor")]
+    pub base_factor: u16,
+    #[serde(rename = "filterPeriod")]
+    pub filter_period: u16,
+    #[serde(rename = "decayPeriod")]
+    pub decay_period: u16,
+    #[serde(rename = "reductionFactor")]
+    pub reduction_factor: u16,
+    #[serde(rename = "variableFeeControl")]
+    pub variable_fee_control: u32,
+    #[serde(rename = "maxVolatilityAccumulator")]
+    pub max_volatility_accumulator: u32,
+    #[serde(rename = "minBinId")]
+    pub min_bin_id: i32,
+    #[serde(rename = "maxBinId")]
+    pub max_bin_id: i32,
+    #[serde(rename = "protocolShare")]
+    pub protocol_share: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PresetParameter2 {
+    #[serde(rename = "binStep")]
+    pub bin_step: u16,
+    #[serde(rename = "baseFactor")]
+    pub base_factor: u16,
+    #[serde(rename = "filterPeriod")]
+    pub filter_period: u16,
+    #[serde(rename = "decayPeriod")]
+    pub decay_period: u16,
+    #[serde(rename = "variableFeeControl")]
+    pub variable_fee_control: u32,
+    #[serde(rename = "maxVolatilityAccumulator")]
+    pub max_volatility_accumulator: u32,
+    #[serde(rename = "reductionFactor")]
+    pub reduction_factor: u16,
+    #[serde(rename = "protocolShare")]
+    pub protocol_share: u16,
+    pub index: u16,
+    #[serde(rename = "baseFeePowerFactor")]
+    pub base_fee_power_factor: u8,
+    pub padding0: u8,
+    pub padding1: [u64; 20],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ProtocolFee {
+    #[serde(rename = "amountX")]
+    pub amount_x: u64,
+    #[serde(rename = "amountY")]
+    pub amount_y: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemainingAccountsInfo {
+    pub slices: Vec<RemainingAccountsSlice>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemainingAccountsSlice {
+    #[serde(rename = "accountsType")]
+    pub accounts_type: AccountsType,
+    pub length: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemoveLiquidity {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    pub from: Pubkey,
+    pub position: Pubkey,
+    pub amounts: [u64; 2],
+    #[serde(rename = "activeBinId")]
+    pub active_bin_id: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RewardInfo {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub funder: Pubkey,
+    #[serde(rename = "rewardDuration")]
+    pub reward_duration: u64,
+    #[serde(rename = "rewardDurationEnd")]
+    pub reward_duration_end: u64,
+    #[serde(rename = "rewardRate")]
+    pub reward_rate: u128,
+    #[serde(rename = "lastUpdateTime")]
+    pub last_update_time: u64,
+    #[serde(rename = "cumulativeSecondsWithEmptyLiquidityReward")]
+    pub cumulative_seconds_with_empty_liquidity_reward: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum Rounding {
+    Up,
+    Down,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct StaticParameters {
+    #[serde(rename = "baseFactor")]
+    pub base_factor: u16,
+    #[serde(rename = "filterPeriod")]
+    pub filter_period: u16,
+    #[serde(rename = "decayPeriod")]
+    pub decay_period: u16,
+    #[serde(rename = "reductionFactor")]
+    pub reduction_factor: u16,
+    #[serde(rename = "variableFeeControl")]
+    pub variable_fee_control: u32,
+    #[serde(rename = "maxVolatilityAccumulator")]
+    pub max_volatility_accumulator: u32,
+    #[serde(rename = "minBinId")]
+    pub min_bin_id: i32,
+    #[serde(rename = "maxBinId")]
+    pub max_bin_id: i32,
+    #[serde(rename = "protocolShare")]
+    pub protocol_share: u16,
+    #[serde(rename = "baseFeePowerFactor")]
+    pub base_fee_power_factor: u8,
+    pub padding: [u8; 5],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct StrategyParameters {
+    #[serde(rename = "minBinId")]
+    pub min_bin_id: i32,
+    #[serde(rename = "maxBinId")]
+    pub max_bin_id: i32,
+    #[serde(rename = "strategyType")]
+    pub strategy_type: StrategyType,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub parameteres: [u8; 64],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum StrategyType {
+    SpotOneSide,
+    CurveOneSide,
+    BidAskOneSide,
+    SpotBalanced,
+    CurveBalanced,
+    BidAskBalanced,
+    SpotImBalanced,
+    CurveImBalanced,
+    BidAskImBalanced,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Swap {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    pub from: Pubkey,
+    #[serde(rename = "startBinId")]
+    pub start_bin_id: i32,
+    #[serde(rename = "endBinId")]
+    pub end_bin_id: i32,
+    #[serde(rename = "amountIn")]
+    pub amount_in: u64,
+    #[serde(rename = "amountOut")]
+    pub amount_out: u64,
+    #[serde(rename = "swapForY")]
+    pub swap_for_y: bool,
+    pub fee: u64,
+    #[serde(rename = "protocolFee")]
+    pub protocol_fee: u64,
+    #[serde(rename = "feeBps")]
+    pub fee_bps: u128,
+    #[serde(rename = "hostFee")]
+    pub host_fee: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TokenBadge {
+    #[serde(rename = "tokenMint")]
+    pub token_mint: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub padding: [u8; 128],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum TokenProgramFlags {
+    TokenProgram,
+    TokenProgram2022,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdatePositionLockReleasePoint {
+    pub position: Pubkey,
+    #[serde(rename = "currentPoint")]
+    pub current_point: u64,
+    #[serde(rename = "newLockReleasePoint")]
+    pub new_lock_release_point: u64,
+    #[serde(rename = "oldLockReleasePoint")]
+    pub old_lock_release_point: u64,
+    pub sender: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdatePositionOperator {
+    pub position: Pubkey,
+    #[serde(rename = "oldOperator")]
+    pub old_operator: Pubkey,
+    #[serde(rename = "newOperator")]
+    pub new_operator: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateRewardDuration {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u64,
+    #[serde(rename = "oldRewardDuration")]
+    pub old_reward_duration: u64,
+    #[serde(rename = "newRewardDuration")]
+    pub new_reward_duration: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateRewardFunder {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u64,
+    #[serde(rename = "oldFunder")]
+    pub old_funder: Pubkey,
+    #[serde(rename = "newFunder")]
+    pub new_funder: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UserRewardInfo {
+    #[serde(rename = "rewardPerTokenCompletes")]
+    pub reward_per_token_completes: [u128; 2],
+    #[serde(rename = "rewardPendings")]
+    pub reward_pendings: [u64; 2],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct VariableParameters {
+    #[serde(rename = "volatilityAccumulator")]
+    pub volatility_accumulator: u32,
+    #[serde(rename = "volatilityReference")]
+    pub volatility_reference: u32,
+    #[serde(rename = "indexReference")]
+    pub index_reference: i32,
+    pub padding: [u8; 4],
+    #[serde(rename = "lastUpdateTimestamp")]
+    pub last_update_timestamp: i64,
+    pub padding1: [u8; 8],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawIneligibleReward {
+    #[serde(rename = "lbPair")]
+    pub lb_pair: Pubkey,
+    #[serde(rename = "rewardMint")]
+    pub reward_mint: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeLbPairInstruction {
+    #[serde(rename = "activeId")]
+    pub active_id: i32,
+    #[serde(rename = "binStep")]
+    pub bin_step: u16,
+}
+
+impl InitializeLbPairInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "binArrayBitmapExtension",
+        "tokenMintX",
+        "tokenMintY",
+        "reserveX",
+        "reserveY",
+        "oracle",
+        "presetParameter",
+        "funder",
+        "tokenProgram",
+        "systemProgram",
+        "rent",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializeLbPairInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[45, 154, 237, 210, 221, 15, 166, 92];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePermissionLbPairInstruction {
+    #[serde(rename = "ixData")]
+    pub ix_data: InitPermissionPairIx,
+}
+
+impl InitializePermissionLbPairInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "base",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "tokenMintX",
+        "tokenMintY",
+        "reserveX",
+        "reserveY",
+        "oracle",
+        "admin",
+        "tokenBadgeX",
+        "tokenBadgeY",
+        "tokenProgramX",
+        "tokenProgramY",
+        "systemProgram",
+        "rent",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializePermissionLbPairInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[108, 102, 213, 85, 251, 3, 53, 21];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeCustomizablePermissionlessLbPairInstruction {
+    pub params: CustomizableParams,
+}
+
+impl InitializeCustomizablePermissionlessLbPairInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "binArrayBitmapExtension",
+        "tokenMintX",
+        "tokenMintY",
+        "reserveX",
+        "reserveY",
+        "oracle",
+        "userTokenX",
+        "funder",
+        "tokenProgram",
+        "systemProgram",
+        "userTokenY",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializeCustomizablePermissionlessLbPairInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[46, 39, 41, 135, 111, 183, 200, 64];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeBinArrayBitmapExtensionInstruction {}
+
+impl InitializeBinArrayBitmapExtensionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "binArrayBitmapExtension",
+        "funder",
+        "systemProgram",
+        "rent",
+    ];
+}
+
+impl IdlDecode for InitializeBinArrayBitmapExtensionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[47, 157, 226, 180, 12, 240, 33, 71];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeBinArrayInstruction {
+    pub index: i64,
+}
+
+impl InitializeBinArrayInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "binArray",
+        "funder",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for InitializeBinArrayInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[35, 86, 19, 185, 78, 212, 75, 211];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquidityInstruction {
+    #[serde(rename = "liquidityParameter")]
+    pub liquidity_parameter: LiquidityParameter,
+}
+
+impl AddLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "userTokenX",
+        "userTokenY",
+        "reserveX",
+        "reserveY",
+        "tokenXMint",
+        "tokenYMint",
+        "binArrayLower",
+        "binArrayUpper",
+        "sender",
+        "tokenXProgram",
+        "tokenYProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AddLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[181, 157, 89, 67, 143, 182, 52, 72];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquidityByWeightInstruction {
+    #[serde(rename = "liquidityParameter")]
+    pub liquidity_parameter: LiquidityParameterByWeight,
+}
+
+impl AddLiquidityByWeightInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "userTokenX",
+        "userTokenY",
+        "reserveX",
+        "reserveY",
+        "tokenXMint",
+        "tokenYMint",
+        "binArrayLower",
+        "binArrayUpper",
+        "sender",
+        "tokenXProgram",
+        "tokenYProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AddLiquidityByWeightInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[28, 140, 238, 99, 231, 162, 21, 149];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquidityByStrategyInstruction {
+    #[serde(rename = "liquidityParameter")]
+    pub liquidity_parameter: LiquidityParameterByStrategy,
+}
+
+impl AddLiquidityByStrategyInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "userTokenX",
+        "userTokenY",
+        "reserveX",
+        "reserveY",
+        "tokenXMint",
+        "tokenYMint",
+        "binArrayLower",
+        "binArrayUpper",
+        "sender",
+        "tokenXProgram",
+        "tokenYProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AddLiquidityByStrategyInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[7, 3, 150, 127, 148, 40, 61, 200];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquidityByStrategyOneSideInstruction {
+    #[serde(rename = "liquidityParameter")]
+    pub liquidity_parameter: LiquidityParameterByStrategyOneSide,
+}
+
+impl AddLiquidityByStrategyOneSideInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "userToken",
+        "reserve",
+        "tokenMint",
+        "binArrayLower",
+        "binArrayUpper",
+        "sender",
+        "tokenProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AddLiquidityByStrategyOneSideInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[41, 5, 238, 175, 100, 225, 6, 205];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquidityOneSideInstruction {
+    #[serde(rename = "liquidityParameter")]
+    pub liquidity_parameter: LiquidityOneSideParameter,
+}
+
+impl AddLiquidityOneSideInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "userToken",
+        "reserve",
+        "tokenMint",
+        "binArrayLower",
+        "binArrayUpper",
+        "sender",
+        "tokenProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AddLiquidityOneSideInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[94, 155, 103, 151, 70, 95, 220, 165];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemoveLiquidityInstruction {
+    #[serde(rename = "binLiquidityRemoval")]
+    pub bin_liquidity_removal: Vec<BinLiquidityReduction>,
+}
+
+impl RemoveLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "userTokenX",
+        "userTokenY",
+        "reserveX",
+        "reserveY",
+        "tokenXMint",
+        "tokenYMint",
+        "binArrayLower",
+        "binArrayUpper",
+        "sender",
+        "tokenXProgram",
+        "tokenYProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for RemoveLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[80, 85, 209, 72, 24, 206, 177, 108];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePositionInstruction {
+    #[serde(rename = "lowerBinId")]
+    pub lower_bin_id: i32,
+    pub width: i32,
+}
+
+impl InitializePositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "position",
+        "lbPair",
+        "owner",
+        "systemProgram",
+        "rent",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializePositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[219, 192, 234, 71, 190, 191, 102, 80];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePositionPdaInstruction {
+    #[serde(rename = "lowerBinId")]
+    pub lower_bin_id: i32,
+    pub width: i32,
+}
+
+impl InitializePositionPdaInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "base",
+        "position",
+        "lbPair",
+        "owner",
+        "systemProgram",
+        "rent",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializePositionPdaInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[46, 82, 125, 146, 85, 141, 228, 153];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePositionByOperatorInstruction {
+    #[serde(rename = "lowerBinId")]
+    pub lower_bin_id: i32,
+    pub width: i32,
+    #[serde(rename = "feeOwner")]
+    pub fee_owner: Pubkey,
+    #[serde(rename = "lockReleasePoint")]
+    pub lock_release_point: u64,
+}
+
+impl InitializePositionByOperatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "base",
+        "position",
+        "lbPair",
+        "owner",
+        "operator",
+        "operatorTokenX",
+        "ownerTokenX",
+        "systemProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializePositionByOperatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[251, 189, 190, 244, 117, 254, 35, 148];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdatePositionOperatorInstruction {
+    pub operator: Pubkey,
+}
+
+impl UpdatePositionOperatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "owner",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for UpdatePositionOperatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[202, 184, 103, 143, 180, 191, 116, 217];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapInstruction {
+    #[serde(rename = "amountIn")]
+    pub amount_in: u64,
+    #[serde(rename = "minAmountOut")]
+    pub min_amount_out: u64,
+}
+
+impl SwapInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "binArrayBitmapExtension",
+        "reserveX",
+        "reserveY",
+        "userTokenIn",
+        "userTokenOut",
+        "tokenXMint",
+        "tokenYMint",
+        "oracle",
+        "hostFeeIn",
+        "user",
+        "tokenXProgram",
+        "tokenYProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SwapInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapExactOutInstruction {
+    #[serde(rename = "maxInAmount")]
+    pub max_in_amount: u64,
+    #[serde(rename = "outAmount")]
+    pub out_amount: u64,
+}
+
+impl SwapExactOutInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "binArrayBitmapExtension",
+        "reserveX",
+        "reserveY",
+        "userTokenIn",
+        "userTokenOut",
+        "tokenXMint",
+        "tokenYMint",
+        "oracle",
+        "hostFeeIn",
+        "user",
+        "tokenXProgram",
+        "tokenYProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SwapExactOutInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[250, 73, 101, 33, 38, 207, 75, 184];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapWithPriceImpactInstruction {
+    #[serde(rename = "amountIn")]
+    pub amount_in: u64,
+    #[serde(rename = "activeId")]
+    pub active_id: Option<i32>,
+    #[serde(rename = "maxPriceImpactBps")]
+    pub max_price_impact_bps: u16,
+}
+
+impl SwapWithPriceImpactInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "binArrayBitmapExtension",
+        "reserveX",
+        "reserveY",
+        "userTokenIn",
+        "userTokenOut",
+        "tokenXMint",
+        "tokenYMint",
+        "oracle",
+        "hostFeeIn",
+        "user",
+        "tokenXProgram",
+        "tokenYProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SwapWithPriceImpactInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[56, 173, 230, 208, 173, 228, 156, 205];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawProtocolFeeInstruction {
+    #[serde(rename = "amountX")]
+    pub amount_x: u64,
+    #[serde(rename = "amountY")]
+    pub amount_y: u64,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: RemainingAccountsInfo,
+}
+
+impl WithdrawProtocolFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "reserveX",
+        "reserveY",
+        "tokenXMint",
+        "tokenYMint",
+        "receiverTokenX",
+        "receiverTokenY",
+        "claimFeeOperator",
+        "operator",
+        "tokenXProgram",
+        "tokenYProgram",
+        "memoProgram",
+    ];
+}
+
+impl IdlDecode for WithdrawProtocolFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[158, 201, 158, 189, 33, 93, 162, 103];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeRewardInstruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u64,
+    #[serde(rename = "rewardDuration")]
+    pub reward_duration: u64,
+    pub funder: Pubkey,
+}
+
+impl InitializeRewardInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "rewardVault",
+        "rewardMint",
+        "tokenBadge",
+        "admin",
+        "tokenProgram",
+        "systemProgram",
+        "rent",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializeRewardInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[95, 135, 192, 196, 242, 129, 230, 68];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FundRewardInstruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u64,
+    pub amount: u64,
+    #[serde(rename = "carryForward")]
+    pub carry_forward: bool,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: RemainingAccountsInfo,
+}
+
+impl FundRewardInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "rewardVault",
+        "rewardMint",
+        "funderTokenAccount",
+        "funder",
+        "binArray",
+        "tokenProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for FundRewardInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[188, 50, 249, 165, 93, 151, 38, 63];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateRewardFunderInstruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u64,
+    #[serde(rename = "newFunder")]
+    pub new_funder: Pubkey,
+}
+
+impl UpdateRewardFunderInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "admin",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for UpdateRewardFunderInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[211, 28, 48, 32, 215, 160, 35, 23];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateRewardDurationInstruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u64,
+    #[serde(rename = "newDuration")]
+    pub new_duration: u64,
+}
+
+impl UpdateRewardDurationInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "admin",
+        "binArray",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for UpdateRewardDurationInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[138, 174, 196, 169, 213, 235, 254, 107];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimRewardInstruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u64,
+}
+
+impl ClaimRewardInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "position",
+        "binArrayLower",
+        "binArrayUpper",
+        "sender",
+        "rewardVault",
+        "rewardMint",
+        "userTokenAccount",
+        "tokenProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ClaimRewardInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[149, 95, 181, 242, 94, 90, 158, 162];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimFeeInstruction {}
+
+impl ClaimFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "position",
+        "binArrayLower",
+        "binArrayUpper",
+        "sender",
+        "reserveX",
+        "reserveY",
+        "userTokenX",
+        "userTokenY",
+        "tokenXMint",
+        "tokenYMint",
+        "tokenProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ClaimFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[169, 32, 79, 137, 136, 232, 70, 137];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClosePositionInstruction {}
+
+impl ClosePositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayLower",
+        "binArrayUpper",
+        "sender",
+        "rentReceiver",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ClosePositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[123, 134, 81, 0, 49, 68, 98, 98];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateBaseFeeParametersInstruction {
+    #[serde(rename = "feeParameter")]
+    pub fee_parameter: BaseFeeParameter,
+}
+
+impl UpdateBaseFeeParametersInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "admin",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for UpdateBaseFeeParametersInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[75, 168, 223, 161, 16, 195, 3, 47];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateDynamicFeeParametersInstruction {
+    #[serde(rename = "feeParameter")]
+    pub fee_parameter: DynamicFeeParameter,
+}
+
+impl UpdateDynamicFeeParametersInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "admin",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for UpdateDynamicFeeParametersInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[92, 161, 46, 246, 255, 189, 22, 22];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct IncreaseOracleLengthInstruction {
+    #[serde(rename = "lengthToAdd")]
+    pub length_to_add: u64,
+}
+
+impl IncreaseOracleLengthInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "oracle",
+        "funder",
+        "systemProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for IncreaseOracleLengthInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[190, 61, 125, 87, 103, 79, 158, 173];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePresetParameterInstruction {
+    pub ix: InitPresetParametersIx,
+}
+
+impl InitializePresetParameterInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "presetParameter",
+        "admin",
+        "systemProgram",
+        "rent",
+    ];
+}
+
+impl IdlDecode for InitializePresetParameterInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[66, 188, 71, 211, 98, 109, 14, 186];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClosePresetParameterInstruction {}
+
+impl ClosePresetParameterInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "presetParameter",
+        "admin",
+        "rentReceiver",
+    ];
+}
+
+impl IdlDecode for ClosePresetParameterInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[4, 148, 145, 100, 134, 26, 181, 61];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClosePresetParameter2Instruction {}
+
+impl ClosePresetParameter2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "presetParameter",
+        "admin",
+        "rentReceiver",
+    ];
+}
+
+impl IdlDecode for ClosePresetParameter2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[39, 25, 95, 107, 116, 17, 115, 28];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemoveAllLiquidityInstruction {}
+
+impl RemoveAllLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "userTokenX",
+        "userTokenY",
+        "reserveX",
+        "reserveY",
+        "tokenXMint",
+        "tokenYMint",
+        "binArrayLower",
+        "binArrayUpper",
+        "sender",
+        "tokenXProgram",
+        "tokenYProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for RemoveAllLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[10, 51, 61, 35, 112, 105, 24, 85];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetPairStatusInstruction {
+    pub status: u8,
+}
+
+impl SetPairStatusInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "admin",
+    ];
+}
+
+impl IdlDecode for SetPairStatusInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[67, 248, 231, 137, 154, 149, 217, 174];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MigratePositionInstruction {}
+
+impl MigratePositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "positionV2",
+        "positionV1",
+        "lbPair",
+        "binArrayLower",
+        "binArrayUpper",
+        "owner",
+        "systemProgram",
+        "rentReceiver",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for MigratePositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[15, 132, 59, 50, 199, 6, 251, 46];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MigrateBinArrayInstruction {}
+
+impl MigrateBinArrayInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+    ];
+}
+
+impl IdlDecode for MigrateBinArrayInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[17, 23, 159, 211, 101, 184, 41, 241];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateFeesAndRewardsInstruction {}
+
+impl UpdateFeesAndRewardsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayLower",
+        "binArrayUpper",
+        "owner",
+    ];
+}
+
+impl IdlDecode for UpdateFeesAndRewardsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[154, 230, 250, 13, 236, 209, 75, 223];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawIneligibleRewardInstruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u64,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: RemainingAccountsInfo,
+}
+
+impl WithdrawIneligibleRewardInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "rewardVault",
+        "rewardMint",
+        "funderTokenAccount",
+        "funder",
+        "binArray",
+        "tokenProgram",
+        "memoProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for WithdrawIneligibleRewardInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[148, 206, 42, 195, 247, 49, 103, 8];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetActivationPointInstruction {
+    #[serde(rename = "activationPoint")]
+    pub activation_point: u64,
+}
+
+impl SetActivationPointInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "admin",
+    ];
+}
+
+impl IdlDecode for SetActivationPointInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[91, 249, 15, 165, 26, 129, 254, 125];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemoveLiquidityByRangeInstruction {
+    #[serde(rename = "fromBinId")]
+    pub from_bin_id: i32,
+    #[serde(rename = "toBinId")]
+    pub to_bin_id: i32,
+    #[serde(rename = "bpsToRemove")]
+    pub bps_to_remove: u16,
+}
+
+impl RemoveLiquidityByRangeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "userTokenX",
+        "userTokenY",
+        "reserveX",
+        "reserveY",
+        "tokenXMint",
+        "tokenYMint",
+        "binArrayLower",
+        "binArrayUpper",
+        "sender",
+        "tokenXProgram",
+        "tokenYProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for RemoveLiquidityByRangeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[26, 82, 102, 152, 240, 74, 105, 26];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquidityOneSidePreciseInstruction {
+    pub parameter: AddLiquiditySingleSidePreciseParameter,
+}
+
+impl AddLiquidityOneSidePreciseInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "userToken",
+        "reserve",
+        "tokenMint",
+        "binArrayLower",
+        "binArrayUpper",
+        "sender",
+        "tokenProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AddLiquidityOneSidePreciseInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[161, 194, 103, 84, 171, 71, 250, 154];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct GoToAbinInstruction {
+    #[serde(rename = "binId")]
+    pub bin_id: i32,
+}
+
+impl GoToAbinInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "binArrayBitmapExtension",
+        "fromBinArray",
+        "toBinArray",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for GoToAbinInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[5, 179, 162, 148, 83, 215, 185, 180];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetPreActivationDurationInstruction {
+    #[serde(rename = "preActivationDuration")]
+    pub pre_activation_duration: u64,
+}
+
+impl SetPreActivationDurationInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "creator",
+    ];
+}
+
+impl IdlDecode for SetPreActivationDurationInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[165, 61, 201, 244, 130, 159, 22, 100];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetPreActivationSwapAddressInstruction {
+    #[serde(rename = "preActivationSwapAddress")]
+    pub pre_activation_swap_address: Pubkey,
+}
+
+impl SetPreActivationSwapAddressInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "creator",
+    ];
+}
+
+impl IdlDecode for SetPreActivationSwapAddressInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[57, 139, 47, 123, 216, 80, 223, 10];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetPairStatusPermissionlessInstruction {
+    pub status: u8,
+}
+
+impl SetPairStatusPermissionlessInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "creator",
+    ];
+}
+
+impl IdlDecode for SetPairStatusPermissionlessInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[78, 59, 152, 211, 70, 183, 46, 208];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeTokenBadgeInstruction {}
+
+impl InitializeTokenBadgeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenMint",
+        "tokenBadge",
+        "admin",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for InitializeTokenBadgeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[253, 77, 205, 95, 27, 224, 89, 223];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateClaimProtocolFeeOperatorInstruction {}
+
+impl CreateClaimProtocolFeeOperatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "claimFeeOperator",
+        "operator",
+        "admin",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for CreateClaimProtocolFeeOperatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[51, 19, 150, 252, 105, 157, 48, 91];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CloseClaimProtocolFeeOperatorInstruction {}
+
+impl CloseClaimProtocolFeeOperatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "claimFeeOperator",
+        "rentReceiver",
+        "admin",
+    ];
+}
+
+impl IdlDecode for CloseClaimProtocolFeeOperatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[8, 41, 87, 35, 80, 48, 121, 26];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePresetParameter2Instruction {
+    pub ix: InitPresetParameters2Ix,
+}
+
+impl InitializePresetParameter2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "presetParameter",
+        "admin",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for InitializePresetParameter2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[184, 7, 240, 171, 103, 47, 183, 121];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeLbPair2Instruction {
+    pub params: InitializeLbPair2Params,
+}
+
+impl InitializeLbPair2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "binArrayBitmapExtension",
+        "tokenMintX",
+        "tokenMintY",
+        "reserveX",
+        "reserveY",
+        "oracle",
+        "presetParameter",
+        "funder",
+        "tokenBadgeX",
+        "tokenBadgeY",
+        "tokenProgramX",
+        "tokenProgramY",
+        "systemProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializeLbPair2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[73, 59, 36, 120, 237, 83, 108, 198];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeCustomizablePermissionlessLbPair2Instruction {
+    pub params: CustomizableParams,
+}
+
+impl InitializeCustomizablePermissionlessLbPair2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "binArrayBitmapExtension",
+        "tokenMintX",
+        "tokenMintY",
+        "reserveX",
+        "reserveY",
+        "oracle",
+        "userTokenX",
+        "funder",
+        "tokenBadgeX",
+        "tokenBadgeY",
+        "tokenProgramX",
+        "tokenProgramY",
+        "systemProgram",
+        "userTokenY",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializeCustomizablePermissionlessLbPair2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[243, 73, 129, 126, 51, 19, 241, 107];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimFee2Instruction {
+    #[serde(rename = "minBinId")]
+    pub min_bin_id: i32,
+    #[serde(rename = "maxBinId")]
+    pub max_bin_id: i32,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: RemainingAccountsInfo,
+}
+
+impl ClaimFee2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "position",
+        "sender",
+        "reserveX",
+        "reserveY",
+        "userTokenX",
+        "userTokenY",
+        "tokenXMint",
+        "tokenYMint",
+        "tokenProgramX",
+        "tokenProgramY",
+        "memoProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ClaimFee2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[112, 191, 101, 171, 28, 144, 127, 187];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimReward2Instruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u64,
+    #[serde(rename = "minBinId")]
+    pub min_bin_id: i32,
+    #[serde(rename = "maxBinId")]
+    pub max_bin_id: i32,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: RemainingAccountsInfo,
+}
+
+impl ClaimReward2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "position",
+        "sender",
+        "rewardVault",
+        "rewardMint",
+        "userTokenAccount",
+        "tokenProgram",
+        "memoProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ClaimReward2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[190, 3, 127, 119, 178, 87, 157, 183];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquidity2Instruction {
+    #[serde(rename = "liquidityParameter")]
+    pub liquidity_parameter: LiquidityParameter,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: RemainingAccountsInfo,
+}
+
+impl AddLiquidity2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "userTokenX",
+        "userTokenY",
+        "reserveX",
+        "reserveY",
+        "tokenXMint",
+        "tokenYMint",
+        "sender",
+        "tokenXProgram",
+        "tokenYProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AddLiquidity2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[228, 162, 78, 28, 70, 219, 116, 115];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquidityByStrategy2Instruction {
+    #[serde(rename = "liquidityParameter")]
+    pub liquidity_parameter: LiquidityParameterByStrategy,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: RemainingAccountsInfo,
+}
+
+impl AddLiquidityByStrategy2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "userTokenX",
+        "userTokenY",
+        "reserveX",
+        "reserveY",
+        "tokenXMint",
+        "tokenYMint",
+        "sender",
+        "tokenXProgram",
+        "tokenYProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AddLiquidityByStrategy2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[3, 221, 149, 218, 111, 141, 118, 213];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquidityOneSidePrecise2Instruction {
+    #[serde(rename = "liquidityParameter")]
+    pub liquidity_parameter: AddLiquiditySingleSidePreciseParameter2,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: RemainingAccountsInfo,
+}
+
+impl AddLiquidityOneSidePrecise2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "userToken",
+        "reserve",
+        "tokenMint",
+        "sender",
+        "tokenProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AddLiquidityOneSidePrecise2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[33, 51, 163, 201, 117, 98, 125, 231];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemoveLiquidity2Instruction {
+    #[serde(rename = "binLiquidityRemoval")]
+    pub bin_liquidity_removal: Vec<BinLiquidityReduction>,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: RemainingAccountsInfo,
+}
+
+impl RemoveLiquidity2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "userTokenX",
+        "userTokenY",
+        "reserveX",
+        "reserveY",
+        "tokenXMint",
+        "tokenYMint",
+        "sender",
+        "tokenXProgram",
+        "tokenYProgram",
+        "memoProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for RemoveLiquidity2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[230, 215, 82, 127, 241, 101, 227, 146];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemoveLiquidityByRange2Instruction {
+    #[serde(rename = "fromBinId")]
+    pub from_bin_id: i32,
+    #[serde(rename = "toBinId")]
+    pub to_bin_id: i32,
+    #[serde(rename = "bpsToRemove")]
+    pub bps_to_remove: u16,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: RemainingAccountsInfo,
+}
+
+impl RemoveLiquidityByRange2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "binArrayBitmapExtension",
+        "userTokenX",
+        "userTokenY",
+        "reserveX",
+        "reserveY",
+        "tokenXMint",
+        "tokenYMint",
+        "sender",
+        "tokenXProgram",
+        "tokenYProgram",
+        "memoProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for RemoveLiquidityByRange2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[204, 2, 195, 145, 53, 145, 145, 205];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Swap2Instruction {
+    #[serde(rename = "amountIn")]
+    pub amount_in: u64,
+    #[serde(rename = "minAmountOut")]
+    pub min_amount_out: u64,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: RemainingAccountsInfo,
+}
+
+impl Swap2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "binArrayBitmapExtension",
+        "reserveX",
+        "reserveY",
+        "userTokenIn",
+        "userTokenOut",
+        "tokenXMint",
+        "tokenYMint",
+        "oracle",
+        "hostFeeIn",
+        "user",
+        "tokenXProgram",
+        "tokenYProgram",
+        "memoProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for Swap2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[65, 75, 63, 76, 235, 91, 91, 136];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapExactOut2Instruction {
+    #[serde(rename = "maxInAmount")]
+    pub max_in_amount: u64,
+    #[serde(rename = "outAmount")]
+    pub out_amount: u64,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: RemainingAccountsInfo,
+}
+
+impl SwapExactOut2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "binArrayBitmapExtension",
+        "reserveX",
+        "reserveY",
+        "userTokenIn",
+        "userTokenOut",
+        "tokenXMint",
+        "tokenYMint",
+        "oracle",
+        "hostFeeIn",
+        "user",
+        "tokenXProgram",
+        "tokenYProgram",
+        "memoProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SwapExactOut2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[43, 215, 247, 132, 137, 60, 243, 81];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapWithPriceImpact2Instruction {
+    #[serde(rename = "amountIn")]
+    pub amount_in: u64,
+    #[serde(rename = "activeId")]
+    pub active_id: Option<i32>,
+    #[serde(rename = "maxPriceImpactBps")]
+    pub max_price_impact_bps: u16,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: RemainingAccountsInfo,
+}
+
+impl SwapWithPriceImpact2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "lbPair",
+        "binArrayBitmapExtension",
+        "reserveX",
+        "reserveY",
+        "userTokenIn",
+        "userTokenOut",
+        "tokenXMint",
+        "tokenYMint",
+        "oracle",
+        "hostFeeIn",
+        "user",
+        "tokenXProgram",
+        "tokenYProgram",
+        "memoProgram",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SwapWithPriceImpact2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[74, 98, 192, 214, 177, 51, 75, 51];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClosePosition2Instruction {}
+
+impl ClosePosition2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "sender",
+        "rentReceiver",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ClosePosition2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[174, 90, 35, 115, 186, 40, 147, 226];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateFeesAndReward2Instruction {
+    #[serde(rename = "minBinId")]
+    pub min_bin_id: i32,
+    #[serde(rename = "maxBinId")]
+    pub max_bin_id: i32,
+}
+
+impl UpdateFeesAndReward2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "lbPair",
+        "owner",
+    ];
+}
+
+impl IdlDecode for UpdateFeesAndReward2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[32, 142, 184, 154, 103, 65, 184, 88];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClosePositionIfEmptyInstruction {}
+
+impl ClosePositionIfEmptyInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "position",
+        "sender",
+        "rentReceiver",
+        "eventAuthority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ClosePositionIfEmptyInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[59, 124, 212, 118, 91, 152, 110, 157];
+}
+
+impl IdlDecode for CompositionFee {
+    const DISCRIMINATOR: &'static [u8] = &[128, 151, 123, 106, 17, 102, 113, 142];
+}
+
+impl IdlDecode for AddLiquidity {
+    const DISCRIMINATOR: &'static [u8] = &[31, 94, 125, 90, 227, 52, 61, 186];
+}
+
+impl IdlDecode for RemoveLiquidity {
+    const DISCRIMINATOR: &'static [u8] = &[116, 244, 97, 232, 103, 31, 152, 58];
+}
+
+impl IdlDecode for Swap {
+    const DISCRIMINATOR: &'static [u8] = &[81, 108, 227, 190, 205, 208, 10, 196];
+}
+
+impl IdlDecode for ClaimReward {
+    const DISCRIMINATOR: &'static [u8] = &[148, 116, 134, 204, 22, 171, 85, 95];
+}
+
+impl IdlDecode for FundReward {
+    const DISCRIMINATOR: &'static [u8] = &[246, 228, 58, 130, 145, 170, 79, 204];
+}
+
+impl IdlDecode for InitializeReward {
+    const DISCRIMINATOR: &'static [u8] = &[211, 153, 88, 62, 149, 60, 177, 70];
+}
+
+impl IdlDecode for UpdateRewardDuration {
+    const DISCRIMINATOR: &'static [u8] = &[223, 245, 224, 153, 49, 29, 163, 172];
+}
+
+impl IdlDecode for UpdateRewardFunder {
+    const DISCRIMINATOR: &'static [u8] = &[224, 178, 174, 74, 252, 165, 85, 180];
+}
+
+impl IdlDecode for PositionClose {
+    const DISCRIMINATOR: &'static [u8] = &[255, 196, 16, 107, 28, 202, 53, 128];
+}
+
+impl IdlDecode for ClaimFee {
+    const DISCRIMINATOR: &'static [u8] = &[75, 122, 154, 48, 140, 74, 123, 163];
+}
+
+impl IdlDecode for LbPairCreate {
+    const DISCRIMINATOR: &'static [u8] = &[185, 74, 252, 125, 27, 215, 188, 111];
+}
+
+impl IdlDecode for PositionCreate {
+    const DISCRIMINATOR: &'static [u8] = &[144, 142, 252, 84, 157, 53, 37, 121];
+}
+
+impl IdlDecode for IncreasePositionLength {
+    const DISCRIMINATOR: &'static [u8] = &[157, 239, 42, 204, 30, 56, 223, 46];
+}
+
+impl IdlDecode for DecreasePositionLength {
+    const DISCRIMINATOR: &'static [u8] = &[52, 118, 235, 85, 172, 169, 15, 128];
+}
+
+impl IdlDecode for FeeParameterUpdate {
+    const DISCRIMINATOR: &'static [u8] = &[48, 76, 241, 117, 144, 215, 242, 44];
+}
+
+impl IdlDecode for DynamicFeeParameterUpdate {
+    const DISCRIMINATOR: &'static [u8] = &[88, 88, 178, 135, 194, 146, 91, 243];
+}
+
+impl IdlDecode for IncreaseObservation {
+    const DISCRIMINATOR: &'static [u8] = &[99, 249, 17, 121, 166, 156, 207, 215];
+}
+
+impl IdlDecode for WithdrawIneligibleReward {
+    const DISCRIMINATOR: &'static [u8] = &[231, 189, 65, 149, 102, 215, 154, 244];
+}
+
+impl IdlDecode for UpdatePositionOperator {
+    const DISCRIMINATOR: &'static [u8] = &[39, 115, 48, 204, 246, 47, 66, 57];
+}
+
+impl IdlDecode for UpdatePositionLockReleasePoint {
+    const DISCRIMINATOR: &'static [u8] = &[133, 214, 66, 224, 64, 12, 7, 191];
+}
+
+impl IdlDecode for GoToABin {
+    const DISCRIMINATOR: &'static [u8] = &[59, 138, 76, 68, 138, 131, 176, 67];
+}
+
+impl IdlDecode for BinArrayBitmapExtension {
+    const DISCRIMINATOR: &'static [u8] = &[80, 111, 124, 113, 55, 237, 18, 5];
+}
+
+impl IdlDecode for BinArray {
+    const DISCRIMINATOR: &'static [u8] = &[92, 142, 92, 220, 5, 148, 70, 181];
+}
+
+impl IdlDecode for ClaimFeeOperator {
+    const DISCRIMINATOR: &'static [u8] = &[166, 48, 134, 86, 34, 200, 188, 150];
+}
+
+impl IdlDecode for LbPair {
+    const DISCRIMINATOR: &'static [u8] = &[33, 11, 49, 98, 181, 101, 177, 13];
+}
+
+impl IdlDecode for Oracle {
+    const DISCRIMINATOR: &'static [u8] = &[139, 194, 131, 179, 140, 179, 229, 244];
+}
+
+impl IdlDecode for Position {
+    const DISCRIMINATOR: &'static [u8] = &[170, 188, 143, 228, 122, 64, 247, 208];
+}
+
+impl IdlDecode for PositionV2 {
+    const DISCRIMINATOR: &'static [u8] = &[117, 176, 212, 199, 245, 180, 133, 182];
+}
+
+impl IdlDecode for PresetParameter2 {
+    const DISCRIMINATOR: &'static [u8] = &[171, 236, 148, 115, 162, 113, 222, 174];
+}
+
+impl IdlDecode for PresetParameter {
+    const DISCRIMINATOR: &'static [u8] = &[242, 62, 244, 34, 181, 112, 58, 170];
+}
+
+impl IdlDecode for TokenBadge {
+    const DISCRIMINATOR: &'static [u8] = &[116, 219, 204, 229, 249, 116, 255, 150];
+}
+
+/// Discriminator constants
+pub mod discriminators {
+    // Instruction discriminators
+    pub const INITIALIZE_LB_PAIR_IX: &[u8] = &[45, 154, 237, 210, 221, 15, 166, 92];
+    pub const INITIALIZE_PERMISSION_LB_PAIR_IX: &[u8] = &[108, 102, 213, 85, 251, 3, 53, 21];
+    pub const INITIALIZE_CUSTOMIZABLE_PERMISSIONLESS_LB_PAIR_IX: &[u8] = &[46, 39, 41, 135, 111, 183, 200, 64];
+    pub const INITIALIZE_BIN_ARRAY_BITMAP_EXTENSION_IX: &[u8] = &[47, 157, 226, 180, 12, 240, 33, 71];
+    pub const INITIALIZE_BIN_ARRAY_IX: &[u8] = &[35, 86, 19, 185, 78, 212, 75, 211];
+    pub const ADD_LIQUIDITY_IX: &[u8] = &[181, 157, 89, 67, 143, 182, 52, 72];
+    pub const ADD_LIQUIDITY_BY_WEIGHT_IX: &[u8] = &[28, 140, 238, 99, 231, 162, 21, 149];
+    pub const ADD_LIQUIDITY_BY_STRATEGY_IX: &[u8] = &[7, 3, 150, 127, 148, 40, 61, 200];
+    pub const ADD_LIQUIDITY_BY_STRATEGY_ONE_SIDE_IX: &[u8] = &[41, 5, 238, 175, 100, 225, 6, 205];
+    pub const ADD_LIQUIDITY_ONE_SIDE_IX: &[u8] = &[94, 155, 103, 151, 70, 95, 220, 165];
+    pub const REMOVE_LIQUIDITY_IX: &[u8] = &[80, 85, 209, 72, 24, 206, 177, 108];
+    pub const INITIALIZE_POSITION_IX: &[u8] = &[219, 192, 234, 71, 190, 191, 102, 80];
+    pub const INITIALIZE_POSITION_PDA_IX: &[u8] = &[46, 82, 125, 146, 85, 141, 228, 153];
+    pub const INITIALIZE_POSITION_BY_OPERATOR_IX: &[u8] = &[251, 189, 190, 244, 117, 254, 35, 148];
+    pub const UPDATE_POSITION_OPERATOR_IX: &[u8] = &[202, 184, 103, 143, 180, 191, 116, 217];
+    pub const SWAP_IX: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+    pub const SWAP_EXACT_OUT_IX: &[u8] = &[250, 73, 101, 33, 38, 207, 75, 184];
+    pub const SWAP_WITH_PRICE_IMPACT_IX: &[u8] = &[56, 173, 230, 208, 173, 228, 156, 205];
+    pub const WITHDRAW_PROTOCOL_FEE_IX: &[u8] = &[158, 201, 158, 189, 33, 93, 162, 103];
+    pub const INITIALIZE_REWARD_IX: &[u8] = &[95, 135, 192, 196, 242, 129, 230, 68];
+    pub const FUND_REWARD_IX: &[u8] = &[188, 50, 249, 165, 93, 151, 38, 63];
+    pub const UPDATE_REWARD_FUNDER_IX: &[u8] = &[211, 28, 48, 32, 215, 160, 35, 23];
+    pub const UPDATE_REWARD_DURATION_IX: &[u8] = &[138, 174, 196, 169, 213, 235, 254, 107];
+    pub const CLAIM_REWARD_IX: &[u8] = &[149, 95, 181, 242, 94, 90, 158, 162];
+    pub const CLAIM_FEE_IX: &[u8] = &[169, 32, 79, 137, 136, 232, 70, 137];
+    pub const CLOSE_POSITION_IX: &[u8] = &[123, 134, 81, 0, 49, 68, 98, 98];
+    pub const UPDATE_BASE_FEE_PARAMETERS_IX: &[u8] = &[75, 168, 223, 161, 16, 195, 3, 47];
+    pub const UPDATE_DYNAMIC_FEE_PARAMETERS_IX: &[u8] = &[92, 161, 46, 246, 255, 189, 22, 22];
+    pub const INCREASE_ORACLE_LENGTH_IX: &[u8] = &[190, 61, 125, 87, 103, 79, 158, 173];
+    pub const INITIALIZE_PRESET_PARAMETER_IX: &[u8] = &[66, 188, 71, 211, 98, 109, 14, 186];
+    pub const CLOSE_PRESET_PARAMETER_IX: &[u8] = &[4, 148, 145, 100, 134, 26, 181, 61];
+    pub const CLOSE_PRESET_PARAMETER2_IX: &[u8] = &[39, 25, 95, 107, 116, 17, 115, 28];
+    pub const REMOVE_ALL_LIQUIDITY_IX: &[u8] = &[10, 51, 61, 35, 112, 105, 24, 85];
+    pub const SET_PAIR_STATUS_IX: &[u8] = &[67, 248, 231, 137, 154, 149, 217, 174];
+    pub const MIGRATE_POSITION_IX: &[u8] = &[15, 132, 59, 50, 199, 6, 251, 46];
+    pub const MIGRATE_BIN_ARRAY_IX: &[u8] = &[17, 23, 159, 211, 101, 184, 41, 241];
+    pub const UPDATE_FEES_AND_REWARDS_IX: &[u8] = &[154, 230, 250, 13, 236, 209, 75, 223];
+    pub const WITHDRAW_INELIGIBLE_REWARD_IX: &[u8] = &[148, 206, 42, 195, 247, 49, 103, 8];
+    pub const SET_ACTIVATION_POINT_IX: &[u8] = &[91, 249, 15, 165, 26, 129, 254, 125];
+    pub const REMOVE_LIQUIDITY_BY_RANGE_IX: &[u8] = &[26, 82, 102, 152, 240, 74, 105, 26];
+    pub const ADD_LIQUIDITY_ONE_SIDE_PRECISE_IX: &[u8] = &[161, 194, 103, 84, 171, 71, 250, 154];
+    pub const GO_TO_ABIN_IX: &[u8] = &[5, 179, 162, 148, 83, 215, 185, 180];
+    pub const SET_PRE_ACTIVATION_DURATION_IX: &[u8] = &[165, 61, 201, 244, 130, 159, 22, 100];
+    pub const SET_PRE_ACTIVATION_SWAP_ADDRESS_IX: &[u8] = &[57, 139, 47, 123, 216, 80, 223, 10];
+    pub const SET_PAIR_STATUS_PERMISSIONLESS_IX: &[u8] = &[78, 59, 152, 211, 70, 183, 46, 208];
+    pub const INITIALIZE_TOKEN_BADGE_IX: &[u8] = &[253, 77, 205, 95, 27, 224, 89, 223];
+    pub const CREATE_CLAIM_PROTOCOL_FEE_OPERATOR_IX: &[u8] = &[51, 19, 150, 252, 105, 157, 48, 91];
+    pub const CLOSE_CLAIM_PROTOCOL_FEE_OPERATOR_IX: &[u8] = &[8, 41, 87, 35, 80, 48, 121, 26];
+    pub const INITIALIZE_PRESET_PARAMETER2_IX: &[u8] = &[184, 7, 240, 171, 103, 47, 183, 121];
+    pub const INITIALIZE_LB_PAIR2_IX: &[u8] = &[73, 59, 36, 120, 237, 83, 108, 198];
+    pub const INITIALIZE_CUSTOMIZABLE_PERMISSIONLESS_LB_PAIR2_IX: &[u8] = &[243, 73, 129, 126, 51, 19, 241, 107];
+    pub const CLAIM_FEE2_IX: &[u8] = &[112, 191, 101, 171, 28, 144, 127, 187];
+    pub const CLAIM_REWARD2_IX: &[u8] = &[190, 3, 127, 119, 178, 87, 157, 183];
+    pub const ADD_LIQUIDITY2_IX: &[u8] = &[228, 162, 78, 28, 70, 219, 116, 115];
+    pub const ADD_LIQUIDITY_BY_STRATEGY2_IX: &[u8] = &[3, 221, 149, 218, 111, 141, 118, 213];
+    pub const ADD_LIQUIDITY_ONE_SIDE_PRECISE2_IX: &[u8] = &[33, 51, 163, 201, 117, 98, 125, 231];
+    pub const REMOVE_LIQUIDITY2_IX: &[u8] = &[230, 215, 82, 127, 241, 101, 227, 146];
+    pub const REMOVE_LIQUIDITY_BY_RANGE2_IX: &[u8] = &[204, 2, 195, 145, 53, 145, 145, 205];
+    pub const SWAP2_IX: &[u8] = &[65, 75, 63, 76, 235, 91, 91, 136];
+    pub const SWAP_EXACT_OUT2_IX: &[u8] = &[43, 215, 247, 132, 137, 60, 243, 81];
+    pub const SWAP_WITH_PRICE_IMPACT2_IX: &[u8] = &[74, 98, 192, 214, 177, 51, 75, 51];
+    pub const CLOSE_POSITION2_IX: &[u8] = &[174, 90, 35, 115, 186, 40, 147, 226];
+    pub const UPDATE_FEES_AND_REWARD2_IX: &[u8] = &[32, 142, 184, 154, 103, 65, 184, 88];
+    pub const CLOSE_POSITION_IF_EMPTY_IX: &[u8] = &[59, 124, 212, 118, 91, 152, 110, 157];
+
+    // Event discriminators
+    pub const COMPOSITION_FEE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 128, 151, 123, 106, 17, 102, 113, 142];
+    pub const ADD_LIQUIDITY_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 31, 94, 125, 90, 227, 52, 61, 186];
+    pub const REMOVE_LIQUIDITY_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 116, 244, 97, 232, 103, 31, 152, 58];
+    pub const SWAP_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 81, 108, 227, 190, 205, 208, 10, 196];
+    pub const CLAIM_REWARD_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 148, 116, 134, 204, 22, 171, 85, 95];
+    pub const FUND_REWARD_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 246, 228, 58, 130, 145, 170, 79, 204];
+    pub const INITIALIZE_REWARD_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 211, 153, 88, 62, 149, 60, 177, 70];
+    pub const UPDATE_REWARD_DURATION_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 223, 245, 224, 153, 49, 29, 163, 172];
+    pub const UPDATE_REWARD_FUNDER_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 224, 178, 174, 74, 252, 165, 85, 180];
+    pub const POSITION_CLOSE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 255, 196, 16, 107, 28, 202, 53, 128];
+    pub const CLAIM_FEE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 75, 122, 154, 48, 140, 74, 123, 163];
+    pub const LB_PAIR_CREATE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 185, 74, 252, 125, 27, 215, 188, 111];
+    pub const POSITION_CREATE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 144, 142, 252, 84, 157, 53, 37, 121];
+    pub const INCREASE_POSITION_LENGTH_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 157, 239, 42, 204, 30, 56, 223, 46];
+    pub const DECREASE_POSITION_LENGTH_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 52, 118, 235, 85, 172, 169, 15, 128];
+    pub const FEE_PARAMETER_UPDATE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 48, 76, 241, 117, 144, 215, 242, 44];
+    pub const DYNAMIC_FEE_PARAMETER_UPDATE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 88, 88, 178, 135, 194, 146, 91, 243];
+    pub const INCREASE_OBSERVATION_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 99, 249, 17, 121, 166, 156, 207, 215];
+    pub const WITHDRAW_INELIGIBLE_REWARD_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 231, 189, 65, 149, 102, 215, 154, 244];
+    pub const UPDATE_POSITION_OPERATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 39, 115, 48, 204, 246, 47, 66, 57];
+    pub const UPDATE_POSITION_LOCK_RELEASE_POINT_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 133, 214, 66, 224, 64, 12, 7, 191];
+    pub const GO_TO_ABIN_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 59, 138, 76, 68, 138, 131, 176, 67];
+
+    // Account discriminators
+    pub const BIN_ARRAY_BITMAP_EXTENSION_ACCOUNT: &[u8] = &[80, 111, 124, 113, 55, 237, 18, 5];
+    pub const BIN_ARRAY_ACCOUNT: &[u8] = &[92, 142, 92, 220, 5, 148, 70, 181];
+    pub const CLAIM_FEE_OPERATOR_ACCOUNT: &[u8] = &[166, 48, 134, 86, 34, 200, 188, 150];
+    pub const LB_PAIR_ACCOUNT: &[u8] = &[33, 11, 49, 98, 181, 101, 177, 13];
+    pub const ORACLE_ACCOUNT: &[u8] = &[139, 194, 131, 179, 140, 179, 229, 244];
+    pub const POSITION_ACCOUNT: &[u8] = &[170, 188, 143, 228, 122, 64, 247, 208];
+    pub const POSITION_V2_ACCOUNT: &[u8] = &[117, 176, 212, 199, 245, 180, 133, 182];
+    pub const PRESET_PARAMETER2_ACCOUNT: &[u8] = &[171, 236, 148, 115, 162, 113, 222, 174];
+    pub const PRESET_PARAMETER_ACCOUNT: &[u8] = &[242, 62, 244, 34, 181, 112, 58, 170];
+    pub const TOKEN_BADGE_ACCOUNT: &[u8] = &[116, 219, 204, 229, 249, 116, 255, 150];
+}
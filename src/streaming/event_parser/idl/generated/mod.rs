@@ -0,0 +1,40 @@
+//! Typed modules generated from `idl/*.json` by `idl::codegen`, do not edit.
+//!
+//! Regenerate with `cargo run --example idl_codegen`.
+
+#[rustfmt::skip]
+#[allow(clippy::large_enum_variant)]
+pub mod meteora_amm;
+#[rustfmt::skip]
+#[allow(clippy::large_enum_variant)]
+pub mod meteora_damm_v2;
+#[rustfmt::skip]
+#[allow(clippy::large_enum_variant)]
+pub mod meteora_dlmm;
+#[rustfmt::skip]
+#[allow(clippy::large_enum_variant)]
+pub mod orca_whirlpool;
+#[rustfmt::skip]
+#[allow(clippy::large_enum_variant)]
+pub mod pump_amm;
+#[rustfmt::skip]
+#[allow(clippy::large_enum_variant)]
+pub mod pumpfun;
+#[rustfmt::skip]
+#[allow(clippy::large_enum_variant)]
+pub mod raydium_amm;
+#[rustfmt::skip]
+#[allow(clippy::large_enum_variant)]
+pub mod raydium_amm_v4;
+#[rustfmt::skip]
+#[allow(clippy::large_enum_variant)]
+pub mod raydium_clmm;
+#[rustfmt::skip]
+#[allow(clippy::large_enum_variant)]
+pub mod raydium_cpmm;
+#[rustfmt::skip]
+#[allow(clippy::large_enum_variant)]
+pub mod raydium_launchpad;
+#[rustfmt::skip]
+#[allow(clippy::large_enum_variant)]
+pub mod raydium_pool_v4;
@@ -0,0 +1,1920 @@
+//! Generated from `idl/orca_whirlpool.json` by `idl::codegen`, do not edit.
+//!
+//! Regenerate with `cargo run --example idl_codegen`.
+
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::idl::IdlDecode;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum AccountsType {
+    TransferHookA,
+    TransferHookB,
+    TransferHookReward,
+    TransferHookInput,
+    TransferHookIntermediate,
+    TransferHookOutput,
+    SupplementalTickArrays,
+    SupplementalTickArraysOne,
+    SupplementalTickArraysTwo,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdaptiveFeeConstants {
+    #[serde(rename = "filterPeriod")]
+    pub filter_period: u16,
+    #[serde(rename = "decayPeriod")]
+    pub decay_period: u16,
+    #[serde(rename = "reductionFactor")]
+    pub reduction_factor: u16,
+    #[serde(rename = "adaptiveFeeControlFactor")]
+    pub adaptive_fee_control_factor: u32,
+    #[serde(rename = "maxVolatilityAccumulator")]
+    pub max_volatility_accumulator: u32,
+    #[serde(rename = "tickGroupSize")]
+    pub tick_group_size: u16,
+    #[serde(rename = "majorSwapThresholdTicks")]
+    pub major_swap_threshold_ticks: u16,
+    pub reserved: [u8; 16],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdaptiveFeeTier {
+    #[serde(rename = "whirlpoolsConfig")]
+    pub whirlpools_config: Pubkey,
+    #[serde(rename = "feeTierIndex")]
+    pub fee_tier_index: u16,
+    #[serde(rename = "tickSpacing")]
+    pub tick_spacing: u16,
+    #[serde(rename = "initializePoolAuthority")]
+    pub initialize_pool_authority: Pubkey,
+    #[serde(rename = "delegatedFeeAuthority")]
+    pub delegated_fee_authority: Pubkey,
+    #[serde(rename = "defaultBaseFeeRate")]
+    pub default_base_fee_rate: u16,
+    #[serde(rename = "filterPeriod")]
+    pub filter_period: u16,
+    #[serde(rename = "decayPeriod")]
+    pub decay_period: u16,
+    #[serde(rename = "reductionFactor")]
+    pub reduction_factor: u16,
+    #[serde(rename = "adaptiveFeeControlFactor")]
+    pub adaptive_fee_control_factor: u32,
+    #[serde(rename = "maxVolatilityAccumulator")]
+    pub max_volatility_accumulator: u32,
+    #[serde(rename = "tickGroupSize")]
+    pub tick_group_size: u16,
+    #[serde(rename = "majorSwapThresholdTicks")]
+    pub major_swap_threshold_ticks: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdaptiveFeeVariables {
+    #[serde(rename = "lastReferenceUpdateTimestamp")]
+    pub last_reference_update_timestamp: u64,
+    #[serde(rename = "lastMajorSwapTimestamp")]
+    pub last_major_swap_timestamp: u64,
+    #[serde(rename = "volatilityReference")]
+    pub volatility_reference: u32,
+    #[serde(rename = "tickGroupIndexReference")]
+    pub tick_group_index_reference: i32,
+    #[serde(rename = "volatilityAccumulator")]
+    pub volatility_accumulator: u32,
+    pub reserved: [u8; 16],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FeeTier {
+    #[serde(rename = "whirlpoolsConfig")]
+    pub whirlpools_config: Pubkey,
+    #[serde(rename = "tickSpacing")]
+    pub tick_spacing: u16,
+    #[serde(rename = "defaultFeeRate")]
+    pub default_fee_rate: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LiquidityDecreased {
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    #[serde(rename = "tickLowerIndex")]
+    pub tick_lower_index: i32,
+    #[serde(rename = "tickUpperIndex")]
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    #[serde(rename = "tokenAAmount")]
+    pub token_aamount: u64,
+    #[serde(rename = "tokenBAmount")]
+    pub token_bamount: u64,
+    #[serde(rename = "tokenATransferFee")]
+    pub token_atransfer_fee: u64,
+    #[serde(rename = "tokenBTransferFee")]
+    pub token_btransfer_fee: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LiquidityIncreased {
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    #[serde(rename = "tickLowerIndex")]
+    pub tick_lower_index: i32,
+    #[serde(rename = "tickUpperIndex")]
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    #[serde(rename = "tokenAAmount")]
+    pub token_aamount: u64,
+    #[serde(rename = "tokenBAmount")]
+    pub token_bamount: u64,
+    #[serde(rename = "tokenATransferFee")]
+    pub token_atransfer_fee: u64,
+    #[serde(rename = "tokenBTransferFee")]
+    pub token_btransfer_fee: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LockConfig {
+    pub position: Pubkey,
+    #[serde(rename = "positionOwner")]
+    pub position_owner: Pubkey,
+    pub whirlpool: Pubkey,
+    #[serde(rename = "lockedTimestamp")]
+    pub locked_timestamp: u64,
+    #[serde(rename = "lockType")]
+    pub lock_type: LockTypeLabel,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum LockType {
+    Permanent,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum LockTypeLabel {
+    Permanent,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OpenPositionBumps {
+    #[serde(rename = "positionBump")]
+    pub position_bump: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OpenPositionWithMetadataBumps {
+    #[serde(rename = "positionBump")]
+    pub position_bump: u8,
+    #[serde(rename = "metadataBump")]
+    pub metadata_bump: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Oracle {
+    pub whirlpool: Pubkey,
+    #[serde(rename = "tradeEnableTimestamp")]
+    pub trade_enable_timestamp: u64,
+    #[serde(rename = "adaptiveFeeConstants")]
+    pub adaptive_fee_constants: AdaptiveFeeConstants,
+    #[serde(rename = "adaptiveFeeVariables")]
+    pub adaptive_fee_variables: AdaptiveFeeVariables,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub reserved: [u8; 128],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolInitialized {
+    pub whirlpool: Pubkey,
+    #[serde(rename = "whirlpoolsConfig")]
+    pub whirlpools_config: Pubkey,
+    #[serde(rename = "tokenMintA")]
+    pub token_mint_a: Pubkey,
+    #[serde(rename = "tokenMintB")]
+    pub token_mint_b: Pubkey,
+    #[serde(rename = "tickSpacing")]
+    pub tick_spacing: u16,
+    #[serde(rename = "tokenProgramA")]
+    pub token_program_a: Pubkey,
+    #[serde(rename = "tokenProgramB")]
+    pub token_program_b: Pubkey,
+    #[serde(rename = "decimalsA")]
+    pub decimals_a: u8,
+    #[serde(rename = "decimalsB")]
+    pub decimals_b: u8,
+    #[serde(rename = "initialSqrtPrice")]
+    pub initial_sqrt_price: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Position {
+    pub whirlpool: Pubkey,
+    #[serde(rename = "positionMint")]
+    pub position_mint: Pubkey,
+    pub liquidity: u128,
+    #[serde(rename = "tickLowerIndex")]
+    pub tick_lower_index: i32,
+    #[serde(rename = "tickUpperIndex")]
+    pub tick_upper_index: i32,
+    #[serde(rename = "feeGrowthCheckpointA")]
+    pub fee_growth_checkpoint_a: u128,
+    #[serde(rename = "feeOwedA")]
+    pub fee_owed_a: u64,
+    #[serde(rename = "feeGrowthCheckpointB")]
+    pub fee_growth_checkpoint_b: u128,
+    #[serde(rename = "feeOwedB")]
+    pub fee_owed_b: u64,
+    #[serde(rename = "rewardInfos")]
+    pub reward_infos: [PositionRewardInfo; 3],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PositionBundle {
+    #[serde(rename = "positionBundleMint")]
+    pub position_bundle_mint: Pubkey,
+    #[serde(rename = "positionBitmap")]
+    pub position_bitmap: [u8; 32],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PositionRewardInfo {
+    #[serde(rename = "growthInsideCheckpoint")]
+    pub growth_inside_checkpoint: u128,
+    #[serde(rename = "amountOwed")]
+    pub amount_owed: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemainingAccountsInfo {
+    pub slices: Vec<RemainingAccountsSlice>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemainingAccountsSlice {
+    #[serde(rename = "accountsType")]
+    pub accounts_type: AccountsType,
+    pub length: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Tick {
+    pub initialized: bool,
+    #[serde(rename = "liquidityNet")]
+    pub liquidity_net: i128,
+    #[serde(rename = "liquidityGross")]
+    pub liquidity_gross: u128,
+    #[serde(rename = "feeGrowthOutsideA")]
+    pub fee_growth_outside_a: u128,
+    #[serde(rename = "feeGrowthOutsideB")]
+    pub fee_growth_outside_b: u128,
+    #[serde(rename = "rewardGrowthsOutside")]
+    pub reward_growths_outside: [u128; 3],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TickArray {
+    #[serde(rename = "startTickIndex")]
+    pub start_tick_index: i32,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub ticks: [Tick; 88],
+    pub whirlpool: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TokenBadge {
+    #[serde(rename = "whirlpoolsConfig")]
+    pub whirlpools_config: Pubkey,
+    #[serde(rename = "tokenMint")]
+    pub token_mint: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Traded {
+    pub whirlpool: Pubkey,
+    #[serde(rename = "aToB")]
+    pub a_to_b: bool,
+    #[serde(rename = "preSqrtPrice")]
+    pub pre_sqrt_price: u128,
+    #[serde(rename = "postSqrtPrice")]
+    pub post_sqrt_price: u128,
+    #[serde(rename = "inputAmount")]
+    pub input_amount: u64,
+    #[serde(rename = "outputAmount")]
+    pub output_amount: u64,
+    #[serde(rename = "inputTransferFee")]
+    pub input_transfer_fee: u64,
+    #[serde(rename = "outputTransferFee")]
+    pub output_transfer_fee: u64,
+    #[serde(rename = "lpFee")]
+    pub lp_fee: u64,
+    #[serde(rename = "protocolFee")]
+    pub protocol_fee: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Whirlpool {
+    #[serde(rename = "whirlpoolsConfig")]
+    pub whirlpools_config: Pubkey,
+    #[serde(rename = "whirlpoolBump")]
+    pub whirlpool_bump: [u8; 1],
+    #[serde(rename = "tickSpacing")]
+    pub tick_spacing: u16,
+    #[serde(rename = "feeTierIndexSeed")]
+    pub fee_tier_index_seed: [u8; 2],
+    #[serde(rename = "feeRate")]
+    pub fee_rate: u16,
+    #[serde(rename = "protocolFeeRate")]
+    pub protocol_fee_rate: u16,
+    pub liquidity: u128,
+    #[serde(rename = "sqrtPrice")]
+    pub sqrt_price: u128,
+    #[serde(rename = "tickCurrentIndex")]
+    pub tick_current_index: i32,
+    #[serde(rename = "protocolFeeOwedA")]
+    pub protocol_fee_owed_a: u64,
+    #[serde(rename = "protocolFeeOwedB")]
+    pub protocol_fee_owed_b: u64,
+    #[serde(rename = "tokenMintA")]
+    pub token_mint_a: Pubkey,
+    #[serde(rename = "tokenVaultA")]
+    pub token_vault_a: Pubkey,
+    #[serde(rename = "feeGrowthGlobalA")]
+    pub fee_growth_global_a: u128,
+    #[serde(rename = "tokenMintB")]
+    pub token_mint_b: Pubkey,
+    #[serde(rename = "tokenVaultB")]
+    pub token_vault_b: Pubkey,
+    #[serde(rename = "feeGrowthGlobalB")]
+    pub fee_growth_global_b: u128,
+    #[serde(rename = "rewardLastUpdatedTimestamp")]
+    pub reward_last_updated_timestamp: u64,
+    #[serde(rename = "rewardInfos")]
+    pub reward_infos: [WhirlpoolRewardInfo; 3],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WhirlpoolBumps {
+    #[serde(rename = "whirlpoolBump")]
+    pub whirlpool_bump: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WhirlpoolRewardInfo {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    #[serde(rename = "emissionsPerSecondX64")]
+    pub emissions_per_second_x64: u128,
+    #[serde(rename = "growthGlobalX64")]
+    pub growth_global_x64: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WhirlpoolsConfig {
+    #[serde(rename = "feeAuthority")]
+    pub fee_authority: Pubkey,
+    #[serde(rename = "collectProtocolFeesAuthority")]
+    pub collect_protocol_fees_authority: Pubkey,
+    #[serde(rename = "rewardEmissionsSuperAuthority")]
+    pub reward_emissions_super_authority: Pubkey,
+    #[serde(rename = "defaultProtocolFeeRate")]
+    pub default_protocol_fee_rate: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WhirlpoolsConfigExtension {
+    #[serde(rename = "whirlpoolsConfig")]
+    pub whirlpools_config: Pubkey,
+    #[serde(rename = "configExtensionAuthority")]
+    pub config_extension_authority: Pubkey,
+    #[serde(rename = "tokenBadgeAuthority")]
+    pub token_badge_authority: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeConfigInstruction {
+    #[serde(rename = "feeAuthority")]
+    pub fee_authority: Pubkey,
+    #[serde(rename = "collectProtocolFeesAuthority")]
+    pub collect_protocol_fees_authority: Pubkey,
+    #[serde(rename = "rewardEmissionsSuperAuthority")]
+    pub reward_emissions_super_authority: Pubkey,
+    #[serde(rename = "defaultProtocolFeeRate")]
+    pub default_protocol_fee_rate: u16,
+}
+
+impl InitializeConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "config",
+        "funder",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for InitializeConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[208, 127, 21, 1, 194, 190, 196, 70];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePoolInstruction {
+    pub bumps: WhirlpoolBumps,
+    #[serde(rename = "tickSpacing")]
+    pub tick_spacing: u16,
+    #[serde(rename = "initialSqrtPrice")]
+    pub initial_sqrt_price: u128,
+}
+
+impl InitializePoolInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "tokenMintA",
+        "tokenMintB",
+        "funder",
+        "whirlpool",
+        "tokenVaultA",
+        "tokenVaultB",
+        "feeTier",
+        "tokenProgram",
+        "systemProgram",
+        "rent",
+    ];
+}
+
+impl IdlDecode for InitializePoolInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[95, 180, 10, 172, 84, 174, 232, 40];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeTickArrayInstruction {
+    #[serde(rename = "startTickIndex")]
+    pub start_tick_index: i32,
+}
+
+impl InitializeTickArrayInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpool",
+        "funder",
+        "tickArray",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for InitializeTickArrayInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[11, 188, 193, 214, 141, 91, 149, 184];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeFeeTierInstruction {
+    #[serde(rename = "tickSpacing")]
+    pub tick_spacing: u16,
+    #[serde(rename = "defaultFeeRate")]
+    pub default_fee_rate: u16,
+}
+
+impl InitializeFeeTierInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "config",
+        "feeTier",
+        "funder",
+        "feeAuthority",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for InitializeFeeTierInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[183, 74, 156, 160, 112, 2, 42, 30];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeRewardInstruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u8,
+}
+
+impl InitializeRewardInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "rewardAuthority",
+        "funder",
+        "whirlpool",
+        "rewardMint",
+        "rewardVault",
+        "tokenProgram",
+        "systemProgram",
+        "rent",
+    ];
+}
+
+impl IdlDecode for InitializeRewardInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[95, 135, 192, 196, 242, 129, 230, 68];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetRewardEmissionsInstruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u8,
+    #[serde(rename = "emissionsPerSecondX64")]
+    pub emissions_per_second_x64: u128,
+}
+
+impl SetRewardEmissionsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpool",
+        "rewardAuthority",
+        "rewardVault",
+    ];
+}
+
+impl IdlDecode for SetRewardEmissionsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[13, 197, 86, 168, 109, 176, 27, 244];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OpenPositionInstruction {
+    pub bumps: OpenPositionBumps,
+    #[serde(rename = "tickLowerIndex")]
+    pub tick_lower_index: i32,
+    #[serde(rename = "tickUpperIndex")]
+    pub tick_upper_index: i32,
+}
+
+impl OpenPositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "funder",
+        "owner",
+        "position",
+        "positionMint",
+        "positionTokenAccount",
+        "whirlpool",
+        "tokenProgram",
+        "systemProgram",
+        "rent",
+        "associatedTokenProgram",
+    ];
+}
+
+impl IdlDecode for OpenPositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[135, 128, 47, 77, 15, 152, 240, 49];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OpenPositionWithMetadataInstruction {
+    pub bumps: OpenPositionWithMetadataBumps,
+    #[serde(rename = "tickLowerIndex")]
+    pub tick_lower_index: i32,
+    #[serde(rename = "tickUpperIndex")]
+    pub tick_upper_index: i32,
+}
+
+impl OpenPositionWithMetadataInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "funder",
+        "owner",
+        "position",
+        "positionMint",
+        "positionMetadataAccount",
+        "positionTokenAccount",
+        "whirlpool",
+        "tokenProgram",
+        "systemProgram",
+        "rent",
+        "associatedTokenProgram",
+        "metadataProgram",
+        "metadataUpdateAuth",
+    ];
+}
+
+impl IdlDecode for OpenPositionWithMetadataInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[242, 29, 134, 48, 58, 110, 14, 60];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct IncreaseLiquidityInstruction {
+    #[serde(rename = "liquidityAmount")]
+    pub liquidity_amount: u128,
+    #[serde(rename = "tokenMaxA")]
+    pub token_max_a: u64,
+    #[serde(rename = "tokenMaxB")]
+    pub token_max_b: u64,
+}
+
+impl IncreaseLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpool",
+        "tokenProgram",
+        "positionAuthority",
+        "position",
+        "positionTokenAccount",
+        "tokenOwnerAccountA",
+        "tokenOwnerAccountB",
+        "tokenVaultA",
+        "tokenVaultB",
+        "tickArrayLower",
+        "tickArrayUpper",
+    ];
+}
+
+impl IdlDecode for IncreaseLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[46, 156, 243, 118, 13, 205, 251, 178];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DecreaseLiquidityInstruction {
+    #[serde(rename = "liquidityAmount")]
+    pub liquidity_amount: u128,
+    #[serde(rename = "tokenMinA")]
+    pub token_min_a: u64,
+    #[serde(rename = "tokenMinB")]
+    pub token_min_b: u64,
+}
+
+impl DecreaseLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpool",
+        "tokenProgram",
+        "positionAuthority",
+        "position",
+        "positionTokenAccount",
+        "tokenOwnerAccountA",
+        "tokenOwnerAccountB",
+        "tokenVaultA",
+        "tokenVaultB",
+        "tickArrayLower",
+        "tickArrayUpper",
+    ];
+}
+
+impl IdlDecode for DecreaseLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[160, 38, 208, 111, 104, 91, 44, 1];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateFeesAndRewardsInstruction {}
+
+impl UpdateFeesAndRewardsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpool",
+        "position",
+        "tickArrayLower",
+        "tickArrayUpper",
+    ];
+}
+
+impl IdlDecode for UpdateFeesAndRewardsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[154, 230, 250, 13, 236, 209, 75, 223];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectFeesInstruction {}
+
+impl CollectFeesInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpool",
+        "positionAuthority",
+        "position",
+        "positionTokenAccount",
+        "tokenOwnerAccountA",
+        "tokenVaultA",
+        "tokenOwnerAccountB",
+        "tokenVaultB",
+        "tokenProgram",
+    ];
+}
+
+impl IdlDecode for CollectFeesInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[164, 152, 207, 99, 30, 186, 19, 182];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectRewardInstruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u8,
+}
+
+impl CollectRewardInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpool",
+        "positionAuthority",
+        "position",
+        "positionTokenAccount",
+        "rewardOwnerAccount",
+        "rewardVault",
+        "tokenProgram",
+    ];
+}
+
+impl IdlDecode for CollectRewardInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[70, 5, 132, 87, 86, 235, 177, 34];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectProtocolFeesInstruction {}
+
+impl CollectProtocolFeesInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "whirlpool",
+        "collectProtocolFeesAuthority",
+        "tokenVaultA",
+        "tokenVaultB",
+        "tokenDestinationA",
+        "tokenDestinationB",
+        "tokenProgram",
+    ];
+}
+
+impl IdlDecode for CollectProtocolFeesInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[22, 67, 23, 98, 150, 178, 70, 220];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapInstruction {
+    pub amount: u64,
+    #[serde(rename = "otherAmountThreshold")]
+    pub other_amount_threshold: u64,
+    #[serde(rename = "sqrtPriceLimit")]
+    pub sqrt_price_limit: u128,
+    #[serde(rename = "amountSpecifiedIsInput")]
+    pub amount_specified_is_input: bool,
+    #[serde(rename = "aToB")]
+    pub a_to_b: bool,
+}
+
+impl SwapInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "tokenAuthority",
+        "whirlpool",
+        "tokenOwnerAccountA",
+        "tokenVaultA",
+        "tokenOwnerAccountB",
+        "tokenVaultB",
+        "tickArray0",
+        "tickArray1",
+        "tickArray2",
+        "oracle",
+    ];
+}
+
+impl IdlDecode for SwapInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClosePositionInstruction {}
+
+impl ClosePositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "positionAuthority",
+        "receiver",
+        "position",
+        "positionMint",
+        "positionTokenAccount",
+        "tokenProgram",
+    ];
+}
+
+impl IdlDecode for ClosePositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[123, 134, 81, 0, 49, 68, 98, 98];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetDefaultFeeRateInstruction {
+    #[serde(rename = "defaultFeeRate")]
+    pub default_fee_rate: u16,
+}
+
+impl SetDefaultFeeRateInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "feeTier",
+        "feeAuthority",
+    ];
+}
+
+impl IdlDecode for SetDefaultFeeRateInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[118, 215, 214, 157, 182, 229, 208, 228];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetDefaultProtocolFeeRateInstruction {
+    #[serde(rename = "defaultProtocolFeeRate")]
+    pub default_protocol_fee_rate: u16,
+}
+
+impl SetDefaultProtocolFeeRateInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "feeAuthority",
+    ];
+}
+
+impl IdlDecode for SetDefaultProtocolFeeRateInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[107, 205, 249, 226, 151, 35, 86, 0];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetFeeRateInstruction {
+    #[serde(rename = "feeRate")]
+    pub fee_rate: u16,
+}
+
+impl SetFeeRateInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "whirlpool",
+        "feeAuthority",
+    ];
+}
+
+impl IdlDecode for SetFeeRateInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[53, 243, 137, 65, 8, 140, 158, 6];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetProtocolFeeRateInstruction {
+    #[serde(rename = "protocolFeeRate")]
+    pub protocol_fee_rate: u16,
+}
+
+impl SetProtocolFeeRateInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "whirlpool",
+        "feeAuthority",
+    ];
+}
+
+impl IdlDecode for SetProtocolFeeRateInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[95, 7, 4, 50, 154, 79, 156, 131];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetFeeAuthorityInstruction {}
+
+impl SetFeeAuthorityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "feeAuthority",
+        "newFeeAuthority",
+    ];
+}
+
+impl IdlDecode for SetFeeAuthorityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[31, 1, 50, 87, 237, 101, 97, 132];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetCollectProtocolFeesAuthorityInstruction {}
+
+impl SetCollectProtocolFeesAuthorityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "collectProtocolFeesAuthority",
+        "newCollectProtocolFeesAuthority",
+    ];
+}
+
+impl IdlDecode for SetCollectProtocolFeesAuthorityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[34, 150, 93, 244, 139, 225, 233, 67];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetRewardAuthorityInstruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u8,
+}
+
+impl SetRewardAuthorityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpool",
+        "rewardAuthority",
+        "newRewardAuthority",
+    ];
+}
+
+impl IdlDecode for SetRewardAuthorityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[34, 39, 183, 252, 83, 28, 85, 127];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetRewardAuthorityBySuperAuthorityInstruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u8,
+}
+
+impl SetRewardAuthorityBySuperAuthorityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "whirlpool",
+        "rewardEmissionsSuperAuthority",
+        "newRewardAuthority",
+    ];
+}
+
+impl IdlDecode for SetRewardAuthorityBySuperAuthorityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[240, 154, 201, 198, 148, 93, 56, 25];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetRewardEmissionsSuperAuthorityInstruction {}
+
+impl SetRewardEmissionsSuperAuthorityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "rewardEmissionsSuperAuthority",
+        "newRewardEmissionsSuperAuthority",
+    ];
+}
+
+impl IdlDecode for SetRewardEmissionsSuperAuthorityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[207, 5, 200, 209, 122, 56, 82, 183];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TwoHopSwapInstruction {
+    pub amount: u64,
+    #[serde(rename = "otherAmountThreshold")]
+    pub other_amount_threshold: u64,
+    #[serde(rename = "amountSpecifiedIsInput")]
+    pub amount_specified_is_input: bool,
+    #[serde(rename = "aToBOne")]
+    pub a_to_bone: bool,
+    #[serde(rename = "aToBTwo")]
+    pub a_to_btwo: bool,
+    #[serde(rename = "sqrtPriceLimitOne")]
+    pub sqrt_price_limit_one: u128,
+    #[serde(rename = "sqrtPriceLimitTwo")]
+    pub sqrt_price_limit_two: u128,
+}
+
+impl TwoHopSwapInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "tokenAuthority",
+        "whirlpoolOne",
+        "whirlpoolTwo",
+        "tokenOwnerAccountOneA",
+        "tokenVaultOneA",
+        "tokenOwnerAccountOneB",
+        "tokenVaultOneB",
+        "tokenOwnerAccountTwoA",
+        "tokenVaultTwoA",
+        "tokenOwnerAccountTwoB",
+        "tokenVaultTwoB",
+        "tickArrayOne0",
+        "tickArrayOne1",
+        "tickArrayOne2",
+        "tickArrayTwo0",
+        "tickArrayTwo1",
+        "tickArrayTwo2",
+        "oracleOne",
+        "oracleTwo",
+    ];
+}
+
+impl IdlDecode for TwoHopSwapInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[195, 96, 237, 108, 68, 162, 219, 230];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePositionBundleInstruction {}
+
+impl InitializePositionBundleInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "positionBundle",
+        "positionBundleMint",
+        "positionBundleTokenAccount",
+        "positionBundleOwner",
+        "funder",
+        "tokenProgram",
+        "systemProgram",
+        "rent",
+        "associatedTokenProgram",
+    ];
+}
+
+impl IdlDecode for InitializePositionBundleInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[117, 45, 241, 149, 24, 18, 194, 65];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePositionBundleWithMetadataInstruction {}
+
+impl InitializePositionBundleWithMetadataInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "positionBundle",
+        "positionBundleMint",
+        "positionBundleMetadata",
+        "positionBundleTokenAccount",
+        "positionBundleOwner",
+        "funder",
+        "metadataUpdateAuth",
+        "tokenProgram",
+        "systemProgram",
+        "rent",
+        "associatedTokenProgram",
+        "metadataProgram",
+    ];
+}
+
+impl IdlDecode for InitializePositionBundleWithMetadataInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[93, 124, 16, 179, 249, 131, 115, 245];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DeletePositionBundleInstruction {}
+
+impl DeletePositionBundleInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "positionBundle",
+        "positionBundleMint",
+        "positionBundleTokenAccount",
+        "positionBundleOwner",
+        "receiver",
+        "tokenProgram",
+    ];
+}
+
+impl IdlDecode for DeletePositionBundleInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[100, 25, 99, 2, 217, 239, 124, 173];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OpenBundledPositionInstruction {
+    #[serde(rename = "bundleIndex")]
+    pub bundle_index: u16,
+    #[serde(rename = "tickLowerIndex")]
+    pub tick_lower_index: i32,
+    #[serde(rename = "tickUpperIndex")]
+    pub tick_upper_index: i32,
+}
+
+impl OpenBundledPositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "bundledPosition",
+        "positionBundle",
+        "positionBundleTokenAccount",
+        "positionBundleAuthority",
+        "whirlpool",
+        "funder",
+        "systemProgram",
+        "rent",
+    ];
+}
+
+impl IdlDecode for OpenBundledPositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[169, 113, 126, 171, 213, 172, 212, 49];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CloseBundledPositionInstruction {
+    #[serde(rename = "bundleIndex")]
+    pub bundle_index: u16,
+}
+
+impl CloseBundledPositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "bundledPosition",
+        "positionBundle",
+        "positionBundleTokenAccount",
+        "positionBundleAuthority",
+        "receiver",
+    ];
+}
+
+impl IdlDecode for CloseBundledPositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[41, 36, 216, 245, 27, 85, 103, 67];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OpenPositionWithTokenExtensionsInstruction {
+    #[serde(rename = "tickLowerIndex")]
+    pub tick_lower_index: i32,
+    #[serde(rename = "tickUpperIndex")]
+    pub tick_upper_index: i32,
+    #[serde(rename = "withTokenMetadataExtension")]
+    pub with_token_metadata_extension: bool,
+}
+
+impl OpenPositionWithTokenExtensionsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "funder",
+        "owner",
+        "position",
+        "positionMint",
+        "positionTokenAccount",
+        "whirlpool",
+        "token2022Program",
+        "systemProgram",
+        "associatedTokenProgram",
+        "metadataUpdateAuth",
+    ];
+}
+
+impl IdlDecode for OpenPositionWithTokenExtensionsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[212, 47, 95, 92, 114, 102, 131, 250];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClosePositionWithTokenExtensionsInstruction {}
+
+impl ClosePositionWithTokenExtensionsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "positionAuthority",
+        "receiver",
+        "position",
+        "positionMint",
+        "positionTokenAccount",
+        "token2022Program",
+    ];
+}
+
+impl IdlDecode for ClosePositionWithTokenExtensionsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[1, 182, 135, 59, 155, 25, 99, 223];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LockPositionInstruction {
+    #[serde(rename = "lockType")]
+    pub lock_type: LockType,
+}
+
+impl LockPositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "funder",
+        "positionAuthority",
+        "position",
+        "positionMint",
+        "positionTokenAccount",
+        "lockConfig",
+        "whirlpool",
+        "token2022Program",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for LockPositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[227, 62, 2, 252, 247, 10, 171, 185];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ResetPositionRangeInstruction {
+    #[serde(rename = "newTickLowerIndex")]
+    pub new_tick_lower_index: i32,
+    #[serde(rename = "newTickUpperIndex")]
+    pub new_tick_upper_index: i32,
+}
+
+impl ResetPositionRangeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "funder",
+        "positionAuthority",
+        "whirlpool",
+        "position",
+        "positionTokenAccount",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for ResetPositionRangeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[164, 123, 180, 141, 194, 100, 160, 175];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TransferLockedPositionInstruction {}
+
+impl TransferLockedPositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "positionAuthority",
+        "receiver",
+        "position",
+        "positionMint",
+        "positionTokenAccount",
+        "destinationTokenAccount",
+        "lockConfig",
+        "token2022Program",
+    ];
+}
+
+impl IdlDecode for TransferLockedPositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[179, 121, 229, 46, 67, 138, 194, 138];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeAdaptiveFeeTierInstruction {
+    #[serde(rename = "feeTierIndex")]
+    pub fee_tier_index: u16,
+    #[serde(rename = "tickSpacing")]
+    pub tick_spacing: u16,
+    #[serde(rename = "initializePoolAuthority")]
+    pub initialize_pool_authority: Pubkey,
+    #[serde(rename = "delegatedFeeAuthority")]
+    pub delegated_fee_authority: Pubkey,
+    #[serde(rename = "defaultBaseFeeRate")]
+    pub default_base_fee_rate: u16,
+    #[serde(rename = "filterPeriod")]
+    pub filter_period: u16,
+    #[serde(rename = "decayPeriod")]
+    pub decay_period: u16,
+    #[serde(rename = "reductionFactor")]
+    pub reduction_factor: u16,
+    #[serde(rename = "adaptiveFeeControlFactor")]
+    pub adaptive_fee_control_factor: u32,
+    #[serde(rename = "maxVolatilityAccumulator")]
+    pub max_volatility_accumulator: u32,
+    #[serde(rename = "tickGroupSize")]
+    pub tick_group_size: u16,
+    #[serde(rename = "majorSwapThresholdTicks")]
+    pub major_swap_threshold_ticks: u16,
+}
+
+impl InitializeAdaptiveFeeTierInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "adaptiveFeeTier",
+        "funder",
+        "feeAuthority",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for InitializeAdaptiveFeeTierInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[77, 99, 208, 200, 141, 123, 117, 48];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetDefaultBaseFeeRateInstruction {
+    #[serde(rename = "defaultBaseFeeRate")]
+    pub default_base_fee_rate: u16,
+}
+
+impl SetDefaultBaseFeeRateInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "adaptiveFeeTier",
+        "feeAuthority",
+    ];
+}
+
+impl IdlDecode for SetDefaultBaseFeeRateInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[229, 66, 84, 251, 164, 134, 183, 7];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetDelegatedFeeAuthorityInstruction {}
+
+impl SetDelegatedFeeAuthorityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "adaptiveFeeTier",
+        "feeAuthority",
+        "newDelegatedFeeAuthority",
+    ];
+}
+
+impl IdlDecode for SetDelegatedFeeAuthorityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[193, 234, 231, 147, 138, 57, 3, 122];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetInitializePoolAuthorityInstruction {}
+
+impl SetInitializePoolAuthorityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "adaptiveFeeTier",
+        "feeAuthority",
+        "newInitializePoolAuthority",
+    ];
+}
+
+impl IdlDecode for SetInitializePoolAuthorityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[125, 43, 127, 235, 149, 26, 106, 236];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetPresetAdaptiveFeeConstantsInstruction {
+    #[serde(rename = "filterPeriod")]
+    pub filter_period: u16,
+    #[serde(rename = "decayPeriod")]
+    pub decay_period: u16,
+    #[serde(rename = "reductionFactor")]
+    pub reduction_factor: u16,
+    #[serde(rename = "adaptiveFeeControlFactor")]
+    pub adaptive_fee_control_factor: u32,
+    #[serde(rename = "maxVolatilityAccumulator")]
+    pub max_volatility_accumulator: u32,
+    #[serde(rename = "tickGroupSize")]
+    pub tick_group_size: u16,
+    #[serde(rename = "majorSwapThresholdTicks")]
+    pub major_swap_threshold_ticks: u16,
+}
+
+impl SetPresetAdaptiveFeeConstantsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "adaptiveFeeTier",
+        "feeAuthority",
+    ];
+}
+
+impl IdlDecode for SetPresetAdaptiveFeeConstantsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[132, 185, 66, 148, 83, 88, 134, 198];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePoolWithAdaptiveFeeInstruction {
+    #[serde(rename = "initialSqrtPrice")]
+    pub initial_sqrt_price: u128,
+    #[serde(rename = "tradeEnableTimestamp")]
+    pub trade_enable_timestamp: Option<u64>,
+}
+
+impl InitializePoolWithAdaptiveFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "tokenMintA",
+        "tokenMintB",
+        "tokenBadgeA",
+        "tokenBadgeB",
+        "funder",
+        "initializePoolAuthority",
+        "whirlpool",
+        "oracle",
+        "tokenVaultA",
+        "tokenVaultB",
+        "adaptiveFeeTier",
+        "tokenProgramA",
+        "tokenProgramB",
+        "systemProgram",
+        "rent",
+    ];
+}
+
+impl IdlDecode for InitializePoolWithAdaptiveFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[143, 94, 96, 76, 172, 124, 119, 199];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetFeeRateByDelegatedFeeAuthorityInstruction {
+    #[serde(rename = "feeRate")]
+    pub fee_rate: u16,
+}
+
+impl SetFeeRateByDelegatedFeeAuthorityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpool",
+        "adaptiveFeeTier",
+        "delegatedFeeAuthority",
+    ];
+}
+
+impl IdlDecode for SetFeeRateByDelegatedFeeAuthorityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[121, 121, 54, 114, 131, 230, 162, 104];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectFeesV2Instruction {
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+impl CollectFeesV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpool",
+        "positionAuthority",
+        "position",
+        "positionTokenAccount",
+        "tokenMintA",
+        "tokenMintB",
+        "tokenOwnerAccountA",
+        "tokenVaultA",
+        "tokenOwnerAccountB",
+        "tokenVaultB",
+        "tokenProgramA",
+        "tokenProgramB",
+        "memoProgram",
+    ];
+}
+
+impl IdlDecode for CollectFeesV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[207, 117, 95, 191, 229, 180, 226, 15];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectProtocolFeesV2Instruction {
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+impl CollectProtocolFeesV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "whirlpool",
+        "collectProtocolFeesAuthority",
+        "tokenMintA",
+        "tokenMintB",
+        "tokenVaultA",
+        "tokenVaultB",
+        "tokenDestinationA",
+        "tokenDestinationB",
+        "tokenProgramA",
+        "tokenProgramB",
+        "memoProgram",
+    ];
+}
+
+impl IdlDecode for CollectProtocolFeesV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[103, 128, 222, 134, 114, 200, 22, 200];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectRewardV2Instruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u8,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+impl CollectRewardV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpool",
+        "positionAuthority",
+        "position",
+        "positionTokenAccount",
+        "rewardOwnerAccount",
+        "rewardMint",
+        "rewardVault",
+        "rewardTokenProgram",
+        "memoProgram",
+    ];
+}
+
+impl IdlDecode for CollectRewardV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[177, 107, 37, 180, 160, 19, 49, 209];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DecreaseLiquidityV2Instruction {
+    #[serde(rename = "liquidityAmount")]
+    pub liquidity_amount: u128,
+    #[serde(rename = "tokenMinA")]
+    pub token_min_a: u64,
+    #[serde(rename = "tokenMinB")]
+    pub token_min_b: u64,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+impl DecreaseLiquidityV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpool",
+        "tokenProgramA",
+        "tokenProgramB",
+        "memoProgram",
+        "positionAuthority",
+        "position",
+        "positionTokenAccount",
+        "tokenMintA",
+        "tokenMintB",
+        "tokenOwnerAccountA",
+        "tokenOwnerAccountB",
+        "tokenVaultA",
+        "tokenVaultB",
+        "tickArrayLower",
+        "tickArrayUpper",
+    ];
+}
+
+impl IdlDecode for DecreaseLiquidityV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[58, 127, 188, 62, 79, 82, 196, 96];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct IncreaseLiquidityV2Instruction {
+    #[serde(rename = "liquidityAmount")]
+    pub liquidity_amount: u128,
+    #[serde(rename = "tokenMaxA")]
+    pub token_max_a: u64,
+    #[serde(rename = "tokenMaxB")]
+    pub token_max_b: u64,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+impl IncreaseLiquidityV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpool",
+        "tokenProgramA",
+        "tokenProgramB",
+        "memoProgram",
+        "positionAuthority",
+        "position",
+        "positionTokenAccount",
+        "tokenMintA",
+        "tokenMintB",
+        "tokenOwnerAccountA",
+        "tokenOwnerAccountB",
+        "tokenVaultA",
+        "tokenVaultB",
+        "tickArrayLower",
+        "tickArrayUpper",
+    ];
+}
+
+impl IdlDecode for IncreaseLiquidityV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[133, 29, 89, 223, 69, 238, 176, 10];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializePoolV2Instruction {
+    #[serde(rename = "tickSpacing")]
+    pub tick_spacing: u16,
+    #[serde(rename = "initialSqrtPrice")]
+    pub initial_sqrt_price: u128,
+}
+
+impl InitializePoolV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "tokenMintA",
+        "tokenMintB",
+        "tokenBadgeA",
+        "tokenBadgeB",
+        "funder",
+        "whirlpool",
+        "tokenVaultA",
+        "tokenVaultB",
+        "feeTier",
+        "tokenProgramA",
+        "tokenProgramB",
+        "systemProgram",
+        "rent",
+    ];
+}
+
+impl IdlDecode for InitializePoolV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[207, 45, 87, 242, 27, 63, 204, 67];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeRewardV2Instruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u8,
+}
+
+impl InitializeRewardV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "rewardAuthority",
+        "funder",
+        "whirlpool",
+        "rewardMint",
+        "rewardTokenBadge",
+        "rewardVault",
+        "rewardTokenProgram",
+        "systemProgram",
+        "rent",
+    ];
+}
+
+impl IdlDecode for InitializeRewardV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[91, 1, 77, 50, 235, 229, 133, 49];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetRewardEmissionsV2Instruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u8,
+    #[serde(rename = "emissionsPerSecondX64")]
+    pub emissions_per_second_x64: u128,
+}
+
+impl SetRewardEmissionsV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpool",
+        "rewardAuthority",
+        "rewardVault",
+    ];
+}
+
+impl IdlDecode for SetRewardEmissionsV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[114, 228, 72, 32, 193, 48, 160, 102];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapV2Instruction {
+    pub amount: u64,
+    #[serde(rename = "otherAmountThreshold")]
+    pub other_amount_threshold: u64,
+    #[serde(rename = "sqrtPriceLimit")]
+    pub sqrt_price_limit: u128,
+    #[serde(rename = "amountSpecifiedIsInput")]
+    pub amount_specified_is_input: bool,
+    #[serde(rename = "aToB")]
+    pub a_to_b: bool,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+impl SwapV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgramA",
+        "tokenProgramB",
+        "memoProgram",
+        "tokenAuthority",
+        "whirlpool",
+        "tokenMintA",
+        "tokenMintB",
+        "tokenOwnerAccountA",
+        "tokenVaultA",
+        "tokenOwnerAccountB",
+        "tokenVaultB",
+        "tickArray0",
+        "tickArray1",
+        "tickArray2",
+        "oracle",
+    ];
+}
+
+impl IdlDecode for SwapV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[43, 4, 237, 11, 26, 201, 30, 98];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TwoHopSwapV2Instruction {
+    pub amount: u64,
+    #[serde(rename = "otherAmountThreshold")]
+    pub other_amount_threshold: u64,
+    #[serde(rename = "amountSpecifiedIsInput")]
+    pub amount_specified_is_input: bool,
+    #[serde(rename = "aToBOne")]
+    pub a_to_bone: bool,
+    #[serde(rename = "aToBTwo")]
+    pub a_to_btwo: bool,
+    #[serde(rename = "sqrtPriceLimitOne")]
+    pub sqrt_price_limit_one: u128,
+    #[serde(rename = "sqrtPriceLimitTwo")]
+    pub sqrt_price_limit_two: u128,
+    #[serde(rename = "remainingAccountsInfo")]
+    pub remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+impl TwoHopSwapV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolOne",
+        "whirlpoolTwo",
+        "tokenMintInput",
+        "tokenMintIntermediate",
+        "tokenMintOutput",
+        "tokenProgramInput",
+        "tokenProgramIntermediate",
+        "tokenProgramOutput",
+        "tokenOwnerAccountInput",
+        "tokenVaultOneInput",
+        "tokenVaultOneIntermediate",
+        "tokenVaultTwoIntermediate",
+        "tokenVaultTwoOutput",
+        "tokenOwnerAccountOutput",
+        "tokenAuthority",
+        "tickArrayOne0",
+        "tickArrayOne1",
+        "tickArrayOne2",
+        "tickArrayTwo0",
+        "tickArrayTwo1",
+        "tickArrayTwo2",
+        "oracleOne",
+        "oracleTwo",
+        "memoProgram",
+    ];
+}
+
+impl IdlDecode for TwoHopSwapV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[186, 143, 209, 29, 254, 2, 194, 117];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeConfigExtensionInstruction {}
+
+impl InitializeConfigExtensionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "config",
+        "configExtension",
+        "funder",
+        "feeAuthority",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for InitializeConfigExtensionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[55, 9, 53, 9, 114, 57, 209, 52];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetConfigExtensionAuthorityInstruction {}
+
+impl SetConfigExtensionAuthorityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "whirlpoolsConfigExtension",
+        "configExtensionAuthority",
+        "newConfigExtensionAuthority",
+    ];
+}
+
+impl IdlDecode for SetConfigExtensionAuthorityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[44, 94, 241, 116, 24, 188, 60, 143];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetTokenBadgeAuthorityInstruction {}
+
+impl SetTokenBadgeAuthorityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "whirlpoolsConfigExtension",
+        "configExtensionAuthority",
+        "newTokenBadgeAuthority",
+    ];
+}
+
+impl IdlDecode for SetTokenBadgeAuthorityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[207, 202, 4, 32, 205, 79, 13, 178];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeTokenBadgeInstruction {}
+
+impl InitializeTokenBadgeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "whirlpoolsConfigExtension",
+        "tokenBadgeAuthority",
+        "tokenMint",
+        "tokenBadge",
+        "funder",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for InitializeTokenBadgeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[253, 77, 205, 95, 27, 224, 89, 223];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DeleteTokenBadgeInstruction {}
+
+impl DeleteTokenBadgeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "whirlpoolsConfig",
+        "whirlpoolsConfigExtension",
+        "tokenBadgeAuthority",
+        "tokenMint",
+        "tokenBadge",
+        "receiver",
+    ];
+}
+
+impl IdlDecode for DeleteTokenBadgeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[53, 146, 68, 8, 18, 117, 17, 185];
+}
+
+impl IdlDecode for LiquidityDecreased {
+    const DISCRIMINATOR: &'static [u8] = &[166, 1, 36, 71, 112, 202, 181, 171];
+}
+
+impl IdlDecode for LiquidityIncreased {
+    const DISCRIMINATOR: &'static [u8] = &[30, 7, 144, 181, 102, 254, 155, 161];
+}
+
+impl IdlDecode for PoolInitialized {
+    const DISCRIMINATOR: &'static [u8] = &[100, 118, 173, 87, 12, 198, 254, 229];
+}
+
+impl IdlDecode for Traded {
+    const DISCRIMINATOR: &'static [u8] = &[225, 202, 73, 175, 147, 43, 160, 150];
+}
+
+impl IdlDecode for AdaptiveFeeTier {
+    const DISCRIMINATOR: &'static [u8] = &[147, 16, 144, 116, 47, 146, 149, 46];
+}
+
+impl IdlDecode for WhirlpoolsConfig {
+    const DISCRIMINATOR: &'static [u8] = &[157, 20, 49, 224, 217, 87, 193, 254];
+}
+
+impl IdlDecode for WhirlpoolsConfigExtension {
+    const DISCRIMINATOR: &'static [u8] = &[2, 99, 215, 163, 240, 26, 153, 58];
+}
+
+impl IdlDecode for FeeTier {
+    const DISCRIMINATOR: &'static [u8] = &[56, 75, 159, 76, 142, 68, 190, 105];
+}
+
+impl IdlDecode for LockConfig {
+    const DISCRIMINATOR: &'static [u8] = &[106, 47, 238, 159, 124, 12, 160, 192];
+}
+
+impl IdlDecode for Oracle {
+    const DISCRIMINATOR: &'static [u8] = &[139, 194, 131, 179, 140, 179, 229, 244];
+}
+
+impl IdlDecode for Position {
+    const DISCRIMINATOR: &'static [u8] = &[170, 188, 143, 228, 122, 64, 247, 208];
+}
+
+impl IdlDecode for PositionBundle {
+    const DISCRIMINATOR: &'static [u8] = &[129, 169, 175, 65, 185, 95, 32, 100];
+}
+
+impl IdlDecode for TickArray {
+    const DISCRIMINATOR: &'static [u8] = &[69, 97, 189, 190, 110, 7, 66, 187];
+}
+
+impl IdlDecode for TokenBadge {
+    const DISCRIMINATOR: &'static [u8] = &[116, 219, 204, 229, 249, 116, 255, 150];
+}
+
+impl IdlDecode for Whirlpool {
+    const DISCRIMINATOR: &'static [u8] = &[63, 149, 209, 12, 225, 128, 99, 9];
+}
+
+/// Discriminator constants
+pub mod discriminators {
+    // Instruction discriminators
+    pub const INITIALIZE_CONFIG_IX: &[u8] = &[208, 127, 21, 1, 194, 190, 196, 70];
+    pub const INITIALIZE_POOL_IX: &[u8] = &[95, 180, 10, 172, 84, 174, 232, 40];
+    pub const INITIALIZE_TICK_ARRAY_IX: &[u8] = &[11, 188, 193, 214, 141, 91, 149, 184];
+    pub const INITIALIZE_FEE_TIER_IX: &[u8] = &[183, 74, 156, 160, 112, 2, 42, 30];
+    pub const INITIALIZE_REWARD_IX: &[u8] = &[95, 135, 192, 196, 242, 129, 230, 68];
+    pub const SET_REWARD_EMISSIONS_IX: &[u8] = &[13, 197, 86, 168, 109, 176, 27, 244];
+    pub const OPEN_POSITION_IX: &[u8] = &[135, 128, 47, 77, 15, 152, 240, 49];
+    pub const OPEN_POSITION_WITH_METADATA_IX: &[u8] = &[242, 29, 134, 48, 58, 110, 14, 60];
+    pub const INCREASE_LIQUIDITY_IX: &[u8] = &[46, 156, 243, 118, 13, 205, 251, 178];
+    pub const DECREASE_LIQUIDITY_IX: &[u8] = &[160, 38, 208, 111, 104, 91, 44, 1];
+    pub const UPDATE_FEES_AND_REWARDS_IX: &[u8] = &[154, 230, 250, 13, 236, 209, 75, 223];
+    pub const COLLECT_FEES_IX: &[u8] = &[164, 152, 207, 99, 30, 186, 19, 182];
+    pub const COLLECT_REWARD_IX: &[u8] = &[70, 5, 132, 87, 86, 235, 177, 34];
+    pub const COLLECT_PROTOCOL_FEES_IX: &[u8] = &[22, 67, 23, 98, 150, 178, 70, 220];
+    pub const SWAP_IX: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+    pub const CLOSE_POSITION_IX: &[u8] = &[123, 134, 81, 0, 49, 68, 98, 98];
+    pub const SET_DEFAULT_FEE_RATE_IX: &[u8] = &[118, 215, 214, 157, 182, 229, 208, 228];
+    pub const SET_DEFAULT_PROTOCOL_FEE_RATE_IX: &[u8] = &[107, 205, 249, 226, 151, 35, 86, 0];
+    pub const SET_FEE_RATE_IX: &[u8] = &[53, 243, 137, 65, 8, 140, 158, 6];
+    pub const SET_PROTOCOL_FEE_RATE_IX: &[u8] = &[95, 7, 4, 50, 154, 79, 156, 131];
+    pub const SET_FEE_AUTHORITY_IX: &[u8] = &[31, 1, 50, 87, 237, 101, 97, 132];
+    pub const SET_COLLECT_PROTOCOL_FEES_AUTHORITY_IX: &[u8] = &[34, 150, 93, 244, 139, 225, 233, 67];
+    pub const SET_REWARD_AUTHORITY_IX: &[u8] = &[34, 39, 183, 252, 83, 28, 85, 127];
+    pub const SET_REWARD_AUTHORITY_BY_SUPER_AUTHORITY_IX: &[u8] = &[240, 154, 201, 198, 148, 93, 56, 25];
+    pub const SET_REWARD_EMISSIONS_SUPER_AUTHORITY_IX: &[u8] = &[207, 5, 200, 209, 122, 56, 82, 183];
+    pub const TWO_HOP_SWAP_IX: &[u8] = &[195, 96, 237, 108, 68, 162, 219, 230];
+    pub const INITIALIZE_POSITION_BUNDLE_IX: &[u8] = &[117, 45, 241, 149, 24, 18, 194, 65];
+    pub const INITIALIZE_POSITION_BUNDLE_WITH_METADATA_IX: &[u8] = &[93, 124, 16, 179, 249, 131, 115, 245];
+    pub const DELETE_POSITION_BUNDLE_IX: &[u8] = &[100, 25, 99, 2, 217, 239, 124, 173];
+    pub const OPEN_BUNDLED_POSITION_IX: &[u8] = &[169, 113, 126, 171, 213, 172, 212, 49];
+    pub const CLOSE_BUNDLED_POSITION_IX: &[u8] = &[41, 36, 216, 245, 27, 85, 103, 67];
+    pub const OPEN_POSITION_WITH_TOKEN_EXTENSIONS_IX: &[u8] = &[212, 47, 95, 92, 114, 102, 131, 250];
+    pub const CLOSE_POSITION_WITH_TOKEN_EXTENSIONS_IX: &[u8] = &[1, 182, 135, 59, 155, 25, 99, 223];
+    pub const LOCK_POSITION_IX: &[u8] = &[227, 62, 2, 252, 247, 10, 171, 185];
+    pub const RESET_POSITION_RANGE_IX: &[u8] = &[164, 123, 180, 141, 194, 100, 160, 175];
+    pub const TRANSFER_LOCKED_POSITION_IX: &[u8] = &[179, 121, 229, 46, 67, 138, 194, 138];
+    pub const INITIALIZE_ADAPTIVE_FEE_TIER_IX: &[u8] = &[77, 99, 208, 200, 141, 123, 117, 48];
+    pub const SET_DEFAULT_BASE_FEE_RATE_IX: &[u8] = &[229, 66, 84, 251, 164, 134, 183, 7];
+    pub const SET_DELEGATED_FEE_AUTHORITY_IX: &[u8] = &[193, 234, 231, 147, 138, 57, 3, 122];
+    pub const SET_INITIALIZE_POOL_AUTHORITY_IX: &[u8] = &[125, 43, 127, 235, 149, 26, 106, 236];
+    pub const SET_PRESET_ADAPTIVE_FEE_CONSTANTS_IX: &[u8] = &[132, 185, 66, 148, 83, 88, 134, 198];
+    pub const INITIALIZE_POOL_WITH_ADAPTIVE_FEE_IX: &[u8] = &[143, 94, 96, 76, 172, 124, 119, 199];
+    pub const SET_FEE_RATE_BY_DELEGATED_FEE_AUTHORITY_IX: &[u8] = &[121, 121, 54, 114, 131, 230, 162, 104];
+    pub const COLLECT_FEES_V2_IX: &[u8] = &[207, 117, 95, 191, 229, 180, 226, 15];
+    pub const COLLECT_PROTOCOL_FEES_V2_IX: &[u8] = &[103, 128, 222, 134, 114, 200, 22, 200];
+    pub const COLLECT_REWARD_V2_IX: &[u8] = &[177, 107, 37, 180, 160, 19, 49, 209];
+    pub const DECREASE_LIQUIDITY_V2_IX: &[u8] = &[58, 127, 188, 62, 79, 82, 196, 96];
+    pub const INCREASE_LIQUIDITY_V2_IX: &[u8] = &[133, 29, 89, 223, 69, 238, 176, 10];
+    pub const INITIALIZE_POOL_V2_IX: &[u8] = &[207, 45, 87, 242, 27, 63, 204, 67];
+    pub const INITIALIZE_REWARD_V2_IX: &[u8] = &[91, 1, 77, 50, 235, 229, 133, 49];
+    pub const SET_REWARD_EMISSIONS_V2_IX: &[u8] = &[114, 228, 72, 32, 193, 48, 160, 102];
+    pub const SWAP_V2_IX: &[u8] = &[43, 4, 237, 11, 26, 201, 30, 98];
+    pub const TWO_HOP_SWAP_V2_IX: &[u8] = &[186, 143, 209, 29, 254, 2, 194, 117];
+    pub const INITIALIZE_CONFIG_EXTENSION_IX: &[u8] = &[55, 9, 53, 9, 114, 57, 209, 52];
+    pub const SET_CONFIG_EXTENSION_AUTHORITY_IX: &[u8] = &[44, 94, 241, 116, 24, 188, 60, 143];
+    pub const SET_TOKEN_BADGE_AUTHORITY_IX: &[u8] = &[207, 202, 4, 32, 205, 79, 13, 178];
+    pub const INITIALIZE_TOKEN_BADGE_IX: &[u8] = &[253, 77, 205, 95, 27, 224, 89, 223];
+    pub const DELETE_TOKEN_BADGE_IX: &[u8] = &[53, 146, 68, 8, 18, 117, 17, 185];
+
+    // Event discriminators
+    pub const LIQUIDITY_DECREASED_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 166, 1, 36, 71, 112, 202, 181, 171];
+    pub const LIQUIDITY_INCREASED_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 30, 7, 144, 181, 102, 254, 155, 161];
+    pub const POOL_INITIALIZED_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 100, 118, 173, 87, 12, 198, 254, 229];
+    pub const TRADED_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 225, 202, 73, 175, 147, 43, 160, 150];
+
+    // Account discriminators
+    pub const ADAPTIVE_FEE_TIER_ACCOUNT: &[u8] = &[147, 16, 144, 116, 47, 146, 149, 46];
+    pub const WHIRLPOOLS_CONFIG_ACCOUNT: &[u8] = &[157, 20, 49, 224, 217, 87, 193, 254];
+    pub const WHIRLPOOLS_CONFIG_EXTENSION_ACCOUNT: &[u8] = &[2, 99, 215, 163, 240, 26, 153, 58];
+    pub const FEE_TIER_ACCOUNT: &[u8] = &[56, 75, 159, 76, 142, 68, 190, 105];
+    pub const LOCK_CONFIG_ACCOUNT: &[u8] = &[106, 47, 238, 159, 124, 12, 160, 192];
+    pub const ORACLE_ACCOUNT: &[u8] = &[139, 194, 131, 179, 140, 179, 229, 244];
+    pub const POSITION_ACCOUNT: &[u8] = &[170, 188, 143, 228, 122, 64, 247, 208];
+    pub const POSITION_BUNDLE_ACCOUNT: &[u8] = &[129, 169, 175, 65, 185, 95, 32, 100];
+    pub const TICK_ARRAY_ACCOUNT: &[u8] = &[69, 97, 189, 190, 110, 7, 66, 187];
+    pub const TOKEN_BADGE_ACCOUNT: &[u8] = &[116, 219, 204, 229, 249, 116, 255, 150];
+    pub const WHIRLPOOL_ACCOUNT: &[u8] = &[63, 149, 209, 12, 225, 128, 99, 9];
+}
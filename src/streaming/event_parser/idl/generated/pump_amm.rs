@@ -0,0 +1,1179 @@
+//! Generated from `idl/pump_amm.json` by `idl::codegen`, do not edit.
+//!
+//! Regenerate with `cargo run --example idl_codegen`.
+
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::idl::IdlDecode;
+
+pub const PROGRAM_ID: Pubkey = solana_sdk::pubkey!("pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA");
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminSetCoinCreatorEvent {
+    pub timestamp: i64,
+    pub admin_set_coin_creator_authority: Pubkey,
+    pub base_mint: Pubkey,
+    pub pool: Pubkey,
+    pub old_coin_creator: Pubkey,
+    pub new_coin_creator: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminUpdateTokenIncentivesEvent {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub day_number: u64,
+    pub token_supply_per_day: u64,
+    pub mint: Pubkey,
+    pub seconds_in_a_day: i64,
+    pub timestamp: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BondingCurve {
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub token_total_supply: u64,
+    pub complete: bool,
+    pub creator: Pubkey,
+    pub is_mayhem_mode: bool,
+    pub is_cashback_coin: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BuyEvent {
+    pub timestamp: i64,
+    pub base_amount_out: u64,
+    pub max_quote_amount_in: u64,
+    pub user_base_token_reserves: u64,
+    pub user_quote_token_reserves: u64,
+    pub pool_base_token_reserves: u64,
+    pub pool_quote_token_reserves: u64,
+    pub quote_amount_in: u64,
+    pub lp_fee_basis_points: u64,
+    pub lp_fee: u64,
+    pub protocol_fee_basis_points: u64,
+    pub protocol_fee: u64,
+    pub quote_amount_in_with_lp_fee: u64,
+    pub user_quote_amount_in: u64,
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub user_base_token_account: Pubkey,
+    pub user_quote_token_account: Pubkey,
+    pub protocol_fee_recipient: Pubkey,
+    pub protocol_fee_recipient_token_account: Pubkey,
+    pub coin_creator: Pubkey,
+    pub coin_creator_fee_basis_points: u64,
+    pub coin_creator_fee: u64,
+    pub track_volume: bool,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    pub last_update_timestamp: i64,
+    pub min_base_amount_out: u64,
+    pub ix_name: String,
+    pub cashback_fee_basis_points: u64,
+    pub cashback: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimCashbackEvent {
+    pub user: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub total_claimed: u64,
+    pub total_cashback_earned: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimTokenIncentivesEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CloseUserVolumeAccumulatorEvent {
+    pub user: Pubkey,
+    pub timestamp: i64,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    pub last_update_timestamp: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectCoinCreatorFeeEvent {
+    pub timestamp: i64,
+    pub coin_creator: Pubkey,
+    pub coin_creator_fee: u64,
+    pub coin_creator_vault_ata: Pubkey,
+    pub coin_creator_token_account: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum ConfigStatus {
+    Paused,
+    Active,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateConfigEvent {
+    pub timestamp: i64,
+    pub admin: Pubkey,
+    pub lp_fee_basis_points: u64,
+    pub protocol_fee_basis_points: u64,
+    pub protocol_fee_recipients: [Pubkey; 8],
+    pub coin_creator_fee_basis_points: u64,
+    pub admin_set_coin_creator_authority: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreatePoolEvent {
+    pub timestamp: i64,
+    pub index: u16,
+    pub creator: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub base_mint_decimals: u8,
+    pub quote_mint_decimals: u8,
+    pub base_amount_in: u64,
+    pub quote_amount_in: u64,
+    pub pool_base_amount: u64,
+    pub pool_quote_amount: u64,
+    pub minimum_liquidity: u64,
+    pub initial_liquidity: u64,
+    pub lp_token_amount_out: u64,
+    pub pool_bump: u8,
+    pub pool: Pubkey,
+    pub lp_mint: Pubkey,
+    pub user_base_token_account: Pubkey,
+    pub user_quote_token_account: Pubkey,
+    pub coin_creator: Pubkey,
+    pub is_mayhem_mode: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DepositEvent {
+    pub timestamp: i64,
+    pub lp_token_amount_out: u64,
+    pub max_base_amount_in: u64,
+    pub max_quote_amount_in: u64,
+    pub user_base_token_reserves: u64,
+    pub user_quote_token_reserves: u64,
+    pub pool_base_token_reserves: u64,
+    pub pool_quote_token_reserves: u64,
+    pub base_amount_in: u64,
+    pub quote_amount_in: u64,
+    pub lp_mint_supply: u64,
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub user_base_token_account: Pubkey,
+    pub user_quote_token_account: Pubkey,
+    pub user_pool_token_account: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DisableEvent {
+    pub timestamp: i64,
+    pub admin: Pubkey,
+    pub disable_create_pool: bool,
+    pub disable_deposit: bool,
+    pub disable_withdraw: bool,
+    pub disable_buy: bool,
+    pub disable_sell: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ExtendAccountEvent {
+    pub timestamp: i64,
+    pub account: Pubkey,
+    pub user: Pubkey,
+    pub current_size: u64,
+    pub new_size: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FeeConfig {
+    pub bump: u8,
+    pub admin: Pubkey,
+    pub flat_fees: Fees,
+    pub fee_tiers: Vec<FeeTier>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FeeTier {
+    pub market_cap_lamports_threshold: u128,
+    pub fees: Fees,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Fees {
+    pub lp_fee_bps: u64,
+    pub protocol_fee_bps: u64,
+    pub creator_fee_bps: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct GlobalConfig {
+    pub admin: Pubkey,
+    pub lp_fee_basis_points: u64,
+    pub protocol_fee_basis_points: u64,
+    pub disable_flags: u8,
+    pub protocol_fee_recipients: [Pubkey; 8],
+    pub coin_creator_fee_basis_points: u64,
+    pub admin_set_coin_creator_authority: Pubkey,
+    pub whitelist_pda: Pubkey,
+    pub reserved_fee_recipient: Pubkey,
+    pub mayhem_mode_enabled: bool,
+    pub reserved_fee_recipients: [Pubkey; 7],
+    pub is_cashback_enabled: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct GlobalVolumeAccumulator {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub seconds_in_a_day: i64,
+    pub mint: Pubkey,
+    pub total_token_supply: [u64; 30],
+    pub sol_volumes: [u64; 30],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitUserVolumeAccumulatorEvent {
+    pub payer: Pubkey,
+    pub user: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MigratePoolCoinCreatorEvent {
+    pub timestamp: i64,
+    pub base_mint: Pubkey,
+    pub pool: Pubkey,
+    pub sharing_config: Pubkey,
+    pub old_coin_creator: Pubkey,
+    pub new_coin_creator: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OptionBool(pub bool);
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Pool {
+    pub pool_bump: u8,
+    pub index: u16,
+    pub creator: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub pool_base_token_account: Pubkey,
+    pub pool_quote_token_account: Pubkey,
+    pub lp_supply: u64,
+    pub coin_creator: Pubkey,
+    pub is_mayhem_mode: bool,
+    pub is_cashback_coin: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ReservedFeeRecipientsEvent {
+    pub timestamp: i64,
+    pub reserved_fee_recipient: Pubkey,
+    pub reserved_fee_recipients: [Pubkey; 7],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SellEvent {
+    pub timestamp: i64,
+    pub base_amount_in: u64,
+    pub min_quote_amount_out: u64,
+    pub user_base_token_reserves: u64,
+    pub user_quote_token_reserves: u64,
+    pub pool_base_token_reserves: u64,
+    pub pool_quote_token_reserves: u64,
+    pub quote_amount_out: u64,
+    pub lp_fee_basis_points: u64,
+    pub lp_fee: u64,
+    pub protocol_fee_basis_points: u64,
+    pub protocol_fee: u64,
+    pub quote_amount_out_without_lp_fee: u64,
+    pub user_quote_amount_out: u64,
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub user_base_token_account: Pubkey,
+    pub user_quote_token_account: Pubkey,
+    pub protocol_fee_recipient: Pubkey,
+    pub protocol_fee_recipient_token_account: Pubkey,
+    pub coin_creator: Pubkey,
+    pub coin_creator_fee_basis_points: u64,
+    pub coin_creator_fee: u64,
+    pub cashback_fee_basis_points: u64,
+    pub cashback: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetBondingCurveCoinCreatorEvent {
+    pub timestamp: i64,
+    pub base_mint: Pubkey,
+    pub pool: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub coin_creator: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetMetaplexCoinCreatorEvent {
+    pub timestamp: i64,
+    pub base_mint: Pubkey,
+    pub pool: Pubkey,
+    pub metadata: Pubkey,
+    pub coin_creator: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Shareholder {
+    pub address: Pubkey,
+    pub share_bps: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SharingConfig {
+    pub bump: u8,
+    pub version: u8,
+    pub status: ConfigStatus,
+    pub mint: Pubkey,
+    pub admin: Pubkey,
+    pub admin_revoked: bool,
+    pub shareholders: Vec<Shareholder>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SyncUserVolumeAccumulatorEvent {
+    pub user: Pubkey,
+    pub total_claimed_tokens_before: u64,
+    pub total_claimed_tokens_after: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateAdminEvent {
+    pub timestamp: i64,
+    pub admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateFeeConfigEvent {
+    pub timestamp: i64,
+    pub admin: Pubkey,
+    pub lp_fee_basis_points: u64,
+    pub protocol_fee_basis_points: u64,
+    pub protocol_fee_recipients: [Pubkey; 8],
+    pub coin_creator_fee_basis_points: u64,
+    pub admin_set_coin_creator_authority: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UserVolumeAccumulator {
+    pub user: Pubkey,
+    pub needs_claim: bool,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    pub last_update_timestamp: i64,
+    pub has_total_claimed_tokens: bool,
+    pub cashback_earned: u64,
+    pub total_cashback_claimed: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawEvent {
+    pub timestamp: i64,
+    pub lp_token_amount_in: u64,
+    pub min_base_amount_out: u64,
+    pub min_quote_amount_out: u64,
+    pub user_base_token_reserves: u64,
+    pub user_quote_token_reserves: u64,
+    pub pool_base_token_reserves: u64,
+    pub pool_quote_token_reserves: u64,
+    pub base_amount_out: u64,
+    pub quote_amount_out: u64,
+    pub lp_mint_supply: u64,
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub user_base_token_account: Pubkey,
+    pub user_quote_token_account: Pubkey,
+    pub user_pool_token_account: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminSetCoinCreatorInstruction {
+    pub coin_creator: Pubkey,
+}
+
+impl AdminSetCoinCreatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin_set_coin_creator_authority",
+        "global_config",
+        "pool",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AdminSetCoinCreatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[242, 40, 117, 145, 73, 96, 105, 104];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminUpdateTokenIncentivesInstruction {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub seconds_in_a_day: i64,
+    pub day_number: u64,
+    pub token_supply_per_day: u64,
+}
+
+impl AdminUpdateTokenIncentivesInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "global_config",
+        "global_volume_accumulator",
+        "mint",
+        "global_incentive_token_account",
+        "associated_token_program",
+        "system_program",
+        "token_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AdminUpdateTokenIncentivesInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[209, 11, 115, 87, 213, 23, 124, 204];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BuyInstruction {
+    pub base_amount_out: u64,
+    pub max_quote_amount_in: u64,
+    pub track_volume: OptionBool,
+}
+
+impl BuyInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "user",
+        "global_config",
+        "base_mint",
+        "quote_mint",
+        "user_base_token_account",
+        "user_quote_token_account",
+        "pool_base_token_account",
+        "pool_quote_token_account",
+        "protocol_fee_recipient",
+        "protocol_fee_recipient_token_account",
+        "base_token_program",
+        "quote_token_program",
+        "system_program",
+        "associated_token_program",
+        "event_authority",
+        "program",
+        "coin_creator_vault_ata",
+        "coin_creator_vault_authority",
+        "global_volume_accumulator",
+        "user_volume_accumulator",
+        "fee_config",
+        "fee_program",
+    ];
+}
+
+impl IdlDecode for BuyInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[102, 6, 61, 18, 1, 218, 235, 234];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BuyExactQuoteInInstruction {
+    pub spendable_quote_in: u64,
+    pub min_base_amount_out: u64,
+    pub track_volume: OptionBool,
+}
+
+impl BuyExactQuoteInInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "user",
+        "global_config",
+        "base_mint",
+        "quote_mint",
+        "user_base_token_account",
+        "user_quote_token_account",
+        "pool_base_token_account",
+        "pool_quote_token_account",
+        "protocol_fee_recipient",
+        "protocol_fee_recipient_token_account",
+        "base_token_program",
+        "quote_token_program",
+        "system_program",
+        "associated_token_program",
+        "event_authority",
+        "program",
+        "coin_creator_vault_ata",
+        "coin_creator_vault_authority",
+        "global_volume_accumulator",
+        "user_volume_accumulator",
+        "fee_config",
+        "fee_program",
+    ];
+}
+
+impl IdlDecode for BuyExactQuoteInInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[198, 46, 21, 82, 180, 217, 232, 112];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimCashbackInstruction {}
+
+impl ClaimCashbackInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "user",
+        "user_volume_accumulator",
+        "quote_mint",
+        "quote_token_program",
+        "user_volume_accumulator_wsol_token_account",
+        "user_wsol_token_account",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ClaimCashbackInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[37, 58, 35, 126, 190, 53, 228, 197];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimTokenIncentivesInstruction {}
+
+impl ClaimTokenIncentivesInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "user",
+        "user_ata",
+        "global_volume_accumulator",
+        "global_incentive_token_account",
+        "user_volume_accumulator",
+        "mint",
+        "token_program",
+        "system_program",
+        "associated_token_program",
+        "event_authority",
+        "program",
+        "payer",
+    ];
+}
+
+impl IdlDecode for ClaimTokenIncentivesInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[16, 4, 71, 28, 204, 1, 40, 27];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CloseUserVolumeAccumulatorInstruction {}
+
+impl CloseUserVolumeAccumulatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "user",
+        "user_volume_accumulator",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CloseUserVolumeAccumulatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[249, 69, 164, 218, 150, 103, 84, 138];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectCoinCreatorFeeInstruction {}
+
+impl CollectCoinCreatorFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "quote_mint",
+        "quote_token_program",
+        "coin_creator",
+        "coin_creator_vault_authority",
+        "coin_creator_vault_ata",
+        "coin_creator_token_account",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CollectCoinCreatorFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[160, 57, 89, 42, 181, 139, 43, 66];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateConfigInstruction {
+    pub lp_fee_basis_points: u64,
+    pub protocol_fee_basis_points: u64,
+    pub protocol_fee_recipients: [Pubkey; 8],
+    pub coin_creator_fee_basis_points: u64,
+    pub admin_set_coin_creator_authority: Pubkey,
+}
+
+impl CreateConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "global_config",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CreateConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[201, 207, 243, 114, 75, 111, 47, 189];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreatePoolInstruction {
+    pub index: u16,
+    pub base_amount_in: u64,
+    pub quote_amount_in: u64,
+    pub coin_creator: Pubkey,
+    pub is_mayhem_mode: bool,
+    pub is_cashback_coin: OptionBool,
+}
+
+impl CreatePoolInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "global_config",
+        "creator",
+        "base_mint",
+        "quote_mint",
+        "lp_mint",
+        "user_base_token_account",
+        "user_quote_token_account",
+        "user_pool_token_account",
+        "pool_base_token_account",
+        "pool_quote_token_account",
+        "system_program",
+        "token_2022_program",
+        "base_token_program",
+        "quote_token_program",
+        "associated_token_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CreatePoolInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[233, 146, 209, 142, 207, 104, 64, 188];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DepositInstruction {
+    pub lp_token_amount_out: u64,
+    pub max_base_amount_in: u64,
+    pub max_quote_amount_in: u64,
+}
+
+impl DepositInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "global_config",
+        "user",
+        "base_mint",
+        "quote_mint",
+        "lp_mint",
+        "user_base_token_account",
+        "user_quote_token_account",
+        "user_pool_token_account",
+        "pool_base_token_account",
+        "pool_quote_token_account",
+        "token_program",
+        "token_2022_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for DepositInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[242, 35, 198, 137, 82, 225, 242, 182];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DisableInstruction {
+    pub disable_create_pool: bool,
+    pub disable_deposit: bool,
+    pub disable_withdraw: bool,
+    pub disable_buy: bool,
+    pub disable_sell: bool,
+}
+
+impl DisableInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "global_config",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for DisableInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[185, 173, 187, 90, 216, 15, 238, 233];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ExtendAccountInstruction {}
+
+impl ExtendAccountInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "account",
+        "user",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ExtendAccountInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[234, 102, 194, 203, 150, 72, 62, 229];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitUserVolumeAccumulatorInstruction {}
+
+impl InitUserVolumeAccumulatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "user",
+        "user_volume_accumulator",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitUserVolumeAccumulatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[94, 6, 202, 115, 255, 96, 232, 183];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MigratePoolCoinCreatorInstruction {}
+
+impl MigratePoolCoinCreatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "sharing_config",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for MigratePoolCoinCreatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[208, 8, 159, 4, 74, 175, 16, 58];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SellInstruction {
+    pub base_amount_in: u64,
+    pub min_quote_amount_out: u64,
+}
+
+impl SellInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "user",
+        "global_config",
+        "base_mint",
+        "quote_mint",
+        "user_base_token_account",
+        "user_quote_token_account",
+        "pool_base_token_account",
+        "pool_quote_token_account",
+        "protocol_fee_recipient",
+        "protocol_fee_recipient_token_account",
+        "base_token_program",
+        "quote_token_program",
+        "system_program",
+        "associated_token_program",
+        "event_authority",
+        "program",
+        "coin_creator_vault_ata",
+        "coin_creator_vault_authority",
+        "fee_config",
+        "fee_program",
+    ];
+}
+
+impl IdlDecode for SellInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[51, 230, 133, 164, 1, 127, 131, 173];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetCoinCreatorInstruction {}
+
+impl SetCoinCreatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "metadata",
+        "bonding_curve",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SetCoinCreatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[210, 149, 128, 45, 188, 58, 78, 175];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetReservedFeeRecipientsInstruction {
+    pub whitelist_pda: Pubkey,
+}
+
+impl SetReservedFeeRecipientsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "global_config",
+        "admin",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SetReservedFeeRecipientsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[111, 172, 162, 232, 114, 89, 213, 142];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SyncUserVolumeAccumulatorInstruction {}
+
+impl SyncUserVolumeAccumulatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "user",
+        "global_volume_accumulator",
+        "user_volume_accumulator",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SyncUserVolumeAccumulatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[86, 31, 192, 87, 163, 87, 79, 238];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ToggleCashbackEnabledInstruction {
+    pub enabled: bool,
+}
+
+impl ToggleCashbackEnabledInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "global_config",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ToggleCashbackEnabledInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[115, 103, 224, 255, 189, 89, 86, 195];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ToggleMayhemModeInstruction {
+    pub enabled: bool,
+}
+
+impl ToggleMayhemModeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "global_config",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ToggleMayhemModeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[1, 9, 111, 208, 100, 31, 255, 163];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TransferCreatorFeesToPumpInstruction {}
+
+impl TransferCreatorFeesToPumpInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "wsol_mint",
+        "token_program",
+        "system_program",
+        "associated_token_program",
+        "coin_creator",
+        "coin_creator_vault_authority",
+        "coin_creator_vault_ata",
+        "pump_creator_vault",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for TransferCreatorFeesToPumpInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[139, 52, 134, 85, 228, 229, 108, 241];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateAdminInstruction {}
+
+impl UpdateAdminInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "global_config",
+        "new_admin",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for UpdateAdminInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[161, 176, 40, 213, 60, 184, 179, 228];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateFeeConfigInstruction {
+    pub lp_fee_basis_points: u64,
+    pub protocol_fee_basis_points: u64,
+    pub protocol_fee_recipients: [Pubkey; 8],
+    pub coin_creator_fee_basis_points: u64,
+    pub admin_set_coin_creator_authority: Pubkey,
+}
+
+impl UpdateFeeConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "global_config",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for UpdateFeeConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[104, 184, 103, 242, 88, 151, 107, 20];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawInstruction {
+    pub lp_token_amount_in: u64,
+    pub min_base_amount_out: u64,
+    pub min_quote_amount_out: u64,
+}
+
+impl WithdrawInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "pool",
+        "global_config",
+        "user",
+        "base_mint",
+        "quote_mint",
+        "lp_mint",
+        "user_base_token_account",
+        "user_quote_token_account",
+        "user_pool_token_account",
+        "pool_base_token_account",
+        "pool_quote_token_account",
+        "token_program",
+        "token_2022_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for WithdrawInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[183, 18, 70, 156, 148, 109, 161, 34];
+}
+
+impl IdlDecode for AdminSetCoinCreatorEvent {
+    const DISCRIMINATOR: &'static [u8] = &[45, 220, 93, 24, 25, 97, 172, 104];
+}
+
+impl IdlDecode for AdminUpdateTokenIncentivesEvent {
+    const DISCRIMINATOR: &'static [u8] = &[147, 250, 108, 120, 247, 29, 67, 222];
+}
+
+impl IdlDecode for BuyEvent {
+    const DISCRIMINATOR: &'static [u8] = &[103, 244, 82, 31, 44, 245, 119, 119];
+}
+
+impl IdlDecode for ClaimCashbackEvent {
+    const DISCRIMINATOR: &'static [u8] = &[226, 214, 246, 33, 7, 242, 147, 229];
+}
+
+impl IdlDecode for ClaimTokenIncentivesEvent {
+    const DISCRIMINATOR: &'static [u8] = &[79, 172, 246, 49, 205, 91, 206, 232];
+}
+
+impl IdlDecode for CloseUserVolumeAccumulatorEvent {
+    const DISCRIMINATOR: &'static [u8] = &[146, 159, 189, 172, 146, 88, 56, 244];
+}
+
+impl IdlDecode for CollectCoinCreatorFeeEvent {
+    const DISCRIMINATOR: &'static [u8] = &[232, 245, 194, 238, 234, 218, 58, 89];
+}
+
+impl IdlDecode for CreateConfigEvent {
+    const DISCRIMINATOR: &'static [u8] = &[107, 52, 89, 129, 55, 226, 81, 22];
+}
+
+impl IdlDecode for CreatePoolEvent {
+    const DISCRIMINATOR: &'static [u8] = &[177, 49, 12, 210, 160, 118, 167, 116];
+}
+
+impl IdlDecode for DepositEvent {
+    const DISCRIMINATOR: &'static [u8] = &[120, 248, 61, 83, 31, 142, 107, 144];
+}
+
+impl IdlDecode for DisableEvent {
+    const DISCRIMINATOR: &'static [u8] = &[107, 253, 193, 76, 228, 202, 27, 104];
+}
+
+impl IdlDecode for ExtendAccountEvent {
+    const DISCRIMINATOR: &'static [u8] = &[97, 97, 215, 144, 93, 146, 22, 124];
+}
+
+impl IdlDecode for InitUserVolumeAccumulatorEvent {
+    const DISCRIMINATOR: &'static [u8] = &[134, 36, 13, 72, 232, 101, 130, 216];
+}
+
+impl IdlDecode for MigratePoolCoinCreatorEvent {
+    const DISCRIMINATOR: &'static [u8] = &[170, 221, 82, 199, 147, 165, 247, 46];
+}
+
+impl IdlDecode for ReservedFeeRecipientsEvent {
+    const DISCRIMINATOR: &'static [u8] = &[43, 188, 250, 18, 221, 75, 187, 95];
+}
+
+impl IdlDecode for SellEvent {
+    const DISCRIMINATOR: &'static [u8] = &[62, 47, 55, 10, 165, 3, 220, 42];
+}
+
+impl IdlDecode for SetBondingCurveCoinCreatorEvent {
+    const DISCRIMINATOR: &'static [u8] = &[242, 231, 235, 102, 65, 99, 189, 211];
+}
+
+impl IdlDecode for SetMetaplexCoinCreatorEvent {
+    const DISCRIMINATOR: &'static [u8] = &[150, 107, 199, 123, 124, 207, 102, 228];
+}
+
+impl IdlDecode for SyncUserVolumeAccumulatorEvent {
+    const DISCRIMINATOR: &'static [u8] = &[197, 122, 167, 124, 116, 81, 91, 255];
+}
+
+impl IdlDecode for UpdateAdminEvent {
+    const DISCRIMINATOR: &'static [u8] = &[225, 152, 171, 87, 246, 63, 66, 234];
+}
+
+impl IdlDecode for UpdateFeeConfigEvent {
+    const DISCRIMINATOR: &'static [u8] = &[90, 23, 65, 35, 62, 244, 188, 208];
+}
+
+impl IdlDecode for WithdrawEvent {
+    const DISCRIMINATOR: &'static [u8] = &[22, 9, 133, 26, 160, 44, 71, 192];
+}
+
+impl IdlDecode for BondingCurve {
+    const DISCRIMINATOR: &'static [u8] = &[23, 183, 248, 55, 96, 216, 172, 96];
+}
+
+impl IdlDecode for FeeConfig {
+    const DISCRIMINATOR: &'static [u8] = &[143, 52, 146, 187, 219, 123, 76, 155];
+}
+
+impl IdlDecode for GlobalConfig {
+    const DISCRIMINATOR: &'static [u8] = &[149, 8, 156, 202, 160, 252, 176, 217];
+}
+
+impl IdlDecode for GlobalVolumeAccumulator {
+    const DISCRIMINATOR: &'static [u8] = &[202, 42, 246, 43, 142, 190, 30, 255];
+}
+
+impl IdlDecode for Pool {
+    const DISCRIMINATOR: &'static [u8] = &[241, 154, 109, 4, 17, 177, 109, 188];
+}
+
+impl IdlDecode for SharingConfig {
+    const DISCRIMINATOR: &'static [u8] = &[216, 74, 9, 0, 56, 140, 93, 75];
+}
+
+impl IdlDecode for UserVolumeAccumulator {
+    const DISCRIMINATOR: &'static [u8] = &[86, 255, 112, 14, 102, 53, 154, 250];
+}
+
+/// Discriminator constants
+pub mod discriminators {
+    // Instruction discriminators
+    pub const ADMIN_SET_COIN_CREATOR_IX: &[u8] = &[242, 40, 117, 145, 73, 96, 105, 104];
+    pub const ADMIN_UPDATE_TOKEN_INCENTIVES_IX: &[u8] = &[209, 11, 115, 87, 213, 23, 124, 204];
+    pub const BUY_IX: &[u8] = &[102, 6, 61, 18, 1, 218, 235, 234];
+    pub const BUY_EXACT_QUOTE_IN_IX: &[u8] = &[198, 46, 21, 82, 180, 217, 232, 112];
+    pub const CLAIM_CASHBACK_IX: &[u8] = &[37, 58, 35, 126, 190, 53, 228, 197];
+    pub const CLAIM_TOKEN_INCENTIVES_IX: &[u8] = &[16, 4, 71, 28, 204, 1, 40, 27];
+    pub const CLOSE_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[249, 69, 164, 218, 150, 103, 84, 138];
+    pub const COLLECT_COIN_CREATOR_FEE_IX: &[u8] = &[160, 57, 89, 42, 181, 139, 43, 66];
+    pub const CREATE_CONFIG_IX: &[u8] = &[201, 207, 243, 114, 75, 111, 47, 189];
+    pub const CREATE_POOL_IX: &[u8] = &[233, 146, 209, 142, 207, 104, 64, 188];
+    pub const DEPOSIT_IX: &[u8] = &[242, 35, 198, 137, 82, 225, 242, 182];
+    pub const DISABLE_IX: &[u8] = &[185, 173, 187, 90, 216, 15, 238, 233];
+    pub const EXTEND_ACCOUNT_IX: &[u8] = &[234, 102, 194, 203, 150, 72, 62, 229];
+    pub const INIT_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[94, 6, 202, 115, 255, 96, 232, 183];
+    pub const MIGRATE_POOL_COIN_CREATOR_IX: &[u8] = &[208, 8, 159, 4, 74, 175, 16, 58];
+    pub const SELL_IX: &[u8] = &[51, 230, 133, 164, 1, 127, 131, 173];
+    pub const SET_COIN_CREATOR_IX: &[u8] = &[210, 149, 128, 45, 188, 58, 78, 175];
+    pub const SET_RESERVED_FEE_RECIPIENTS_IX: &[u8] = &[111, 172, 162, 232, 114, 89, 213, 142];
+    pub const SYNC_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[86, 31, 192, 87, 163, 87, 79, 238];
+    pub const TOGGLE_CASHBACK_ENABLED_IX: &[u8] = &[115, 103, 224, 255, 189, 89, 86, 195];
+    pub const TOGGLE_MAYHEM_MODE_IX: &[u8] = &[1, 9, 111, 208, 100, 31, 255, 163];
+    pub const TRANSFER_CREATOR_FEES_TO_PUMP_IX: &[u8] = &[139, 52, 134, 85, 228, 229, 108, 241];
+    pub const UPDATE_ADMIN_IX: &[u8] = &[161, 176, 40, 213, 60, 184, 179, 228];
+    pub const UPDATE_FEE_CONFIG_IX: &[u8] = &[104, 184, 103, 242, 88, 151, 107, 20];
+    pub const WITHDRAW_IX: &[u8] = &[183, 18, 70, 156, 148, 109, 161, 34];
+
+    // Event discriminators
+    pub const ADMIN_SET_COIN_CREATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 45, 220, 93, 24, 25, 97, 172, 104];
+    pub const ADMIN_UPDATE_TOKEN_INCENTIVES_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 147, 250, 108, 120, 247, 29, 67, 222];
+    pub const BUY_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 103, 244, 82, 31, 44, 245, 119, 119];
+    pub const CLAIM_CASHBACK_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 226, 214, 246, 33, 7, 242, 147, 229];
+    pub const CLAIM_TOKEN_INCENTIVES_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 79, 172, 246, 49, 205, 91, 206, 232];
+    pub const CLOSE_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 146, 159, 189, 172, 146, 88, 56, 244];
+    pub const COLLECT_COIN_CREATOR_FEE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 232, 245, 194, 238, 234, 218, 58, 89];
+    pub const CREATE_CONFIG_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 107, 52, 89, 129, 55, 226, 81, 22];
+    pub const CREATE_POOL_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 177, 49, 12, 210, 160, 118, 167, 116];
+    pub const DEPOSIT_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 120, 248, 61, 83, 31, 142, 107, 144];
+    pub const DISABLE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 107, 253, 193, 76, 228, 202, 27, 104];
+    pub const EXTEND_ACCOUNT_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 97, 97, 215, 144, 93, 146, 22, 124];
+    pub const INIT_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 134, 36, 13, 72, 232, 101, 130, 216];
+    pub const MIGRATE_POOL_COIN_CREATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 170, 221, 82, 199, 147, 165, 247, 46];
+    pub const RESERVED_FEE_RECIPIENTS_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 43, 188, 250, 18, 221, 75, 187, 95];
+    pub const SELL_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 62, 47, 55, 10, 165, 3, 220, 42];
+    pub const SET_BONDING_CURVE_COIN_CREATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 242, 231, 235, 102, 65, 99, 189, 211];
+    pub const SET_METAPLEX_COIN_CREATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 150, 107, 199, 123, 124, 207, 102, 228];
+    pub const SYNC_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 197, 122, 167, 124, 116, 81, 91, 255];
+    pub const UPDATE_ADMIN_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 225, 152, 171, 87, 246, 63, 66, 234];
+    pub const UPDATE_FEE_CONFIG_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 90, 23, 65, 35, 62, 244, 188, 208];
+    pub const WITHDRAW_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 22, 9, 133, 26, 160, 44, 71, 192];
+
+    // Account discriminators
+    pub const BONDING_CURVE_ACCOUNT: &[u8] = &[23, 183, 248, 55, 96, 216, 172, 96];
+    pub const FEE_CONFIG_ACCOUNT: &[u8] = &[143, 52, 146, 187, 219, 123, 76, 155];
+    pub const GLOBAL_CONFIG_ACCOUNT: &[u8] = &[149, 8, 156, 202, 160, 252, 176, 217];
+    pub const GLOBAL_VOLUME_ACCUMULATOR_ACCOUNT: &[u8] = &[202, 42, 246, 43, 142, 190, 30, 255];
+    pub const POOL_ACCOUNT: &[u8] = &[241, 154, 109, 4, 17, 177, 109, 188];
+    pub const SHARING_CONFIG_ACCOUNT: &[u8] = &[216, 74, 9, 0, 56, 140, 93, 75];
+    pub const USER_VOLUME_ACCUMULATOR_ACCOUNT: &[u8] = &[86, 255, 112, 14, 102, 53, 154, 250];
+}
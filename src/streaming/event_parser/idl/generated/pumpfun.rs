@@ -0,0 +1,1178 @@
+//! Generated from `idl/pumpfun.json` by `idl::codegen`, do not edit.
+//!
+//! Regenerate with `cargo run --example idl_codegen`.
+
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::idl::IdlDecode;
+
+pub const PROGRAM_ID: Pubkey = solana_sdk::pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminSetCreatorEvent {
+    pub timestamp: i64,
+    pub admin_set_creator_authority: Pubkey,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub old_creator: Pubkey,
+    pub new_creator: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminSetIdlAuthorityEvent {
+    pub idl_authority: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminUpdateTokenIncentivesEvent {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub day_number: u64,
+    pub token_supply_per_day: u64,
+    pub mint: Pubkey,
+    pub seconds_in_a_day: i64,
+    pub timestamp: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BondingCurve {
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub token_total_supply: u64,
+    pub complete: bool,
+    pub creator: Pubkey,
+    pub is_mayhem_mode: bool,
+    pub is_cashback_coin: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimCashbackEvent {
+    pub user: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub total_claimed: u64,
+    pub total_cashback_earned: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimTokenIncentivesEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CloseUserVolumeAccumulatorEvent {
+    pub user: Pubkey,
+    pub timestamp: i64,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    pub last_update_timestamp: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectCreatorFeeEvent {
+    pub timestamp: i64,
+    pub creator: Pubkey,
+    pub creator_fee: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CompleteEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CompletePumpAmmMigrationEvent {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub mint_amount: u64,
+    pub sol_amount: u64,
+    pub pool_migration_fee: u64,
+    pub bonding_curve: Pubkey,
+    pub timestamp: i64,
+    pub pool: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum ConfigStatus {
+    Paused,
+    Active,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateEvent {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub user: Pubkey,
+    pub creator: Pubkey,
+    pub timestamp: i64,
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub token_total_supply: u64,
+    pub token_program: Pubkey,
+    pub is_mayhem_mode: bool,
+    pub is_cashback_enabled: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DistributeCreatorFeesEvent {
+    pub timestamp: i64,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub sharing_config: Pubkey,
+    pub admin: Pubkey,
+    pub shareholders: Vec<Shareholder>,
+    pub distributed: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ExtendAccountEvent {
+    pub account: Pubkey,
+    pub user: Pubkey,
+    pub current_size: u64,
+    pub new_size: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FeeConfig {
+    pub bump: u8,
+    pub admin: Pubkey,
+    pub flat_fees: Fees,
+    pub fee_tiers: Vec<FeeTier>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FeeTier {
+    pub market_cap_lamports_threshold: u128,
+    pub fees: Fees,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Fees {
+    pub lp_fee_bps: u64,
+    pub protocol_fee_bps: u64,
+    pub creator_fee_bps: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Global {
+    pub initialized: bool,
+    pub authority: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub initial_virtual_token_reserves: u64,
+    pub initial_virtual_sol_reserves: u64,
+    pub initial_real_token_reserves: u64,
+    pub token_total_supply: u64,
+    pub fee_basis_points: u64,
+    pub withdraw_authority: Pubkey,
+    pub enable_migrate: bool,
+    pub pool_migration_fee: u64,
+    pub creator_fee_basis_points: u64,
+    pub fee_recipients: [Pubkey; 7],
+    pub set_creator_authority: Pubkey,
+    pub admin_set_creator_authority: Pubkey,
+    pub create_v2_enabled: bool,
+    pub whitelist_pda: Pubkey,
+    pub reserved_fee_recipient: Pubkey,
+    pub mayhem_mode_enabled: bool,
+    pub reserved_fee_recipients: [Pubkey; 7],
+    pub is_cashback_enabled: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct GlobalVolumeAccumulator {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub seconds_in_a_day: i64,
+    pub mint: Pubkey,
+    pub total_token_supply: [u64; 30],
+    pub sol_volumes: [u64; 30],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitUserVolumeAccumulatorEvent {
+    pub payer: Pubkey,
+    pub user: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MigrateBondingCurveCreatorEvent {
+    pub timestamp: i64,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub sharing_config: Pubkey,
+    pub old_creator: Pubkey,
+    pub new_creator: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MinimumDistributableFeeEvent {
+    pub minimum_required: u64,
+    pub distributable_fees: u64,
+    pub can_distribute: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OptionBool(pub bool);
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ReservedFeeRecipientsEvent {
+    pub timestamp: i64,
+    pub reserved_fee_recipient: Pubkey,
+    pub reserved_fee_recipients: [Pubkey; 7],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetCreatorEvent {
+    pub timestamp: i64,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub creator: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetMetaplexCreatorEvent {
+    pub timestamp: i64,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub metadata: Pubkey,
+    pub creator: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetParamsEvent {
+    pub initial_virtual_token_reserves: u64,
+    pub initial_virtual_sol_reserves: u64,
+    pub initial_real_token_reserves: u64,
+    pub final_real_sol_reserves: u64,
+    pub token_total_supply: u64,
+    pub fee_basis_points: u64,
+    pub withdraw_authority: Pubkey,
+    pub enable_migrate: bool,
+    pub pool_migration_fee: u64,
+    pub creator_fee_basis_points: u64,
+    pub fee_recipients: [Pubkey; 8],
+    pub timestamp: i64,
+    pub set_creator_authority: Pubkey,
+    pub admin_set_creator_authority: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Shareholder {
+    pub address: Pubkey,
+    pub share_bps: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SharingConfig {
+    pub bump: u8,
+    pub version: u8,
+    pub status: ConfigStatus,
+    pub mint: Pubkey,
+    pub admin: Pubkey,
+    pub admin_revoked: bool,
+    pub shareholders: Vec<Shareholder>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SyncUserVolumeAccumulatorEvent {
+    pub user: Pubkey,
+    pub total_claimed_tokens_before: u64,
+    pub total_claimed_tokens_after: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TradeEvent {
+    pub mint: Pubkey,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub is_buy: bool,
+    pub user: Pubkey,
+    pub timestamp: i64,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub fee_recipient: Pubkey,
+    pub fee_basis_points: u64,
+    pub fee: u64,
+    pub creator: Pubkey,
+    pub creator_fee_basis_points: u64,
+    pub creator_fee: u64,
+    pub track_volume: bool,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    pub last_update_timestamp: i64,
+    pub ix_name: String,
+    pub mayhem_mode: bool,
+    pub cashback_fee_basis_points: u64,
+    pub cashback: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateGlobalAuthorityEvent {
+    pub global: Pubkey,
+    pub authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateMayhemVirtualParamsEvent {
+    pub timestamp: i64,
+    pub mint: Pubkey,
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub new_virtual_token_reserves: u64,
+    pub new_virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub real_sol_reserves: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UserVolumeAccumulator {
+    pub user: Pubkey,
+    pub needs_claim: bool,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    pub last_update_timestamp: i64,
+    pub has_total_claimed_tokens: bool,
+    pub cashback_earned: u64,
+    pub total_cashback_claimed: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminSetCreatorInstruction {
+    pub creator: Pubkey,
+}
+
+impl AdminSetCreatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin_set_creator_authority",
+        "global",
+        "mint",
+        "bonding_curve",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AdminSetCreatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[69, 25, 171, 142, 57, 239, 13, 4];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminSetIdlAuthorityInstruction {
+    pub idl_authority: Pubkey,
+}
+
+impl AdminSetIdlAuthorityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "authority",
+        "global",
+        "idl_account",
+        "system_program",
+        "program_signer",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AdminSetIdlAuthorityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[8, 217, 96, 231, 144, 104, 192, 5];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminUpdateTokenIncentivesInstruction {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub seconds_in_a_day: i64,
+    pub day_number: u64,
+    pub pump_token_supply_per_day: u64,
+}
+
+impl AdminUpdateTokenIncentivesInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "authority",
+        "global",
+        "global_volume_accumulator",
+        "mint",
+        "global_incentive_token_account",
+        "associated_token_program",
+        "system_program",
+        "token_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for AdminUpdateTokenIncentivesInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[209, 11, 115, 87, 213, 23, 124, 204];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BuyInstruction {
+    pub amount: u64,
+    pub max_sol_cost: u64,
+    pub track_volume: OptionBool,
+}
+
+impl BuyInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "global",
+        "fee_recipient",
+        "mint",
+        "bonding_curve",
+        "associated_bonding_curve",
+        "associated_user",
+        "user",
+        "system_program",
+        "token_program",
+        "creator_vault",
+        "event_authority",
+        "program",
+        "global_volume_accumulator",
+        "user_volume_accumulator",
+        "fee_config",
+        "fee_program",
+    ];
+}
+
+impl IdlDecode for BuyInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[102, 6, 61, 18, 1, 218, 235, 234];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BuyExactSolInInstruction {
+    pub spendable_sol_in: u64,
+    pub min_tokens_out: u64,
+    pub track_volume: OptionBool,
+}
+
+impl BuyExactSolInInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "global",
+        "fee_recipient",
+        "mint",
+        "bonding_curve",
+        "associated_bonding_curve",
+        "associated_user",
+        "user",
+        "system_program",
+        "token_program",
+        "creator_vault",
+        "event_authority",
+        "program",
+        "global_volume_accumulator",
+        "user_volume_accumulator",
+        "fee_config",
+        "fee_program",
+    ];
+}
+
+impl IdlDecode for BuyExactSolInInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[56, 252, 116, 8, 158, 223, 205, 95];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimCashbackInstruction {}
+
+impl ClaimCashbackInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "user",
+        "user_volume_accumulator",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ClaimCashbackInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[37, 58, 35, 126, 190, 53, 228, 197];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimTokenIncentivesInstruction {}
+
+impl ClaimTokenIncentivesInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "user",
+        "user_ata",
+        "global_volume_accumulator",
+        "global_incentive_token_account",
+        "user_volume_accumulator",
+        "mint",
+        "token_program",
+        "system_program",
+        "associated_token_program",
+        "event_authority",
+        "program",
+        "payer",
+    ];
+}
+
+impl IdlDecode for ClaimTokenIncentivesInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[16, 4, 71, 28, 204, 1, 40, 27];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CloseUserVolumeAccumulatorInstruction {}
+
+impl CloseUserVolumeAccumulatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "user",
+        "user_volume_accumulator",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CloseUserVolumeAccumulatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[249, 69, 164, 218, 150, 103, 84, 138];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectCreatorFeeInstruction {}
+
+impl CollectCreatorFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "creator",
+        "creator_vault",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CollectCreatorFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[20, 22, 86, 123, 198, 28, 219, 132];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateInstruction {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub creator: Pubkey,
+}
+
+impl CreateInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "mint",
+        "mint_authority",
+        "bonding_curve",
+        "associated_bonding_curve",
+        "global",
+        "mpl_token_metadata",
+        "metadata",
+        "user",
+        "system_program",
+        "token_program",
+        "associated_token_program",
+        "rent",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CreateInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[24, 30, 200, 40, 5, 28, 7, 119];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateV2Instruction {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub creator: Pubkey,
+    pub is_mayhem_mode: bool,
+    pub is_cashback_enabled: OptionBool,
+}
+
+impl CreateV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "mint",
+        "mint_authority",
+        "bonding_curve",
+        "associated_bonding_curve",
+        "global",
+        "user",
+        "system_program",
+        "token_program",
+        "associated_token_program",
+        "mayhem_program_id",
+        "global_params",
+        "sol_vault",
+        "mayhem_state",
+        "mayhem_token_vault",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for CreateV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[214, 144, 76, 236, 95, 139, 49, 180];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DistributeCreatorFeesInstruction {}
+
+impl DistributeCreatorFeesInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "mint",
+        "bonding_curve",
+        "sharing_config",
+        "creator_vault",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for DistributeCreatorFeesInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[165, 114, 103, 0, 121, 206, 247, 81];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ExtendAccountInstruction {}
+
+impl ExtendAccountInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "account",
+        "user",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ExtendAccountInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[234, 102, 194, 203, 150, 72, 62, 229];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct GetMinimumDistributableFeeInstruction {}
+
+impl GetMinimumDistributableFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "mint",
+        "bonding_curve",
+        "sharing_config",
+        "creator_vault",
+    ];
+}
+
+impl IdlDecode for GetMinimumDistributableFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[117, 225, 127, 202, 134, 95, 68, 35];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitUserVolumeAccumulatorInstruction {}
+
+impl InitUserVolumeAccumulatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "user",
+        "user_volume_accumulator",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitUserVolumeAccumulatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[94, 6, 202, 115, 255, 96, 232, 183];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeInstruction {}
+
+impl InitializeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "global",
+        "user",
+        "system_program",
+    ];
+}
+
+impl IdlDecode for InitializeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[175, 175, 109, 31, 13, 152, 155, 237];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MigrateInstruction {}
+
+impl MigrateInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "global",
+        "withdraw_authority",
+        "mint",
+        "bonding_curve",
+        "associated_bonding_curve",
+        "user",
+        "system_program",
+        "token_program",
+        "pump_amm",
+        "pool",
+        "pool_authority",
+        "pool_authority_mint_account",
+        "pool_authority_wsol_account",
+        "amm_global_config",
+        "wsol_mint",
+        "lp_mint",
+        "user_pool_token_account",
+        "pool_base_token_account",
+        "pool_quote_token_account",
+        "token_2022_program",
+        "associated_token_program",
+        "pump_amm_event_authority",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for MigrateInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[155, 234, 231, 146, 236, 158, 162, 30];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MigrateBondingCurveCreatorInstruction {}
+
+impl MigrateBondingCurveCreatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "mint",
+        "bonding_curve",
+        "sharing_config",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for MigrateBondingCurveCreatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[87, 124, 52, 191, 52, 38, 214, 232];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SellInstruction {
+    pub amount: u64,
+    pub min_sol_output: u64,
+}
+
+impl SellInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "global",
+        "fee_recipient",
+        "mint",
+        "bonding_curve",
+        "associated_bonding_curve",
+        "associated_user",
+        "user",
+        "system_program",
+        "creator_vault",
+        "token_program",
+        "event_authority",
+        "program",
+        "fee_config",
+        "fee_program",
+    ];
+}
+
+impl IdlDecode for SellInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[51, 230, 133, 164, 1, 127, 131, 173];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetCreatorInstruction {
+    pub creator: Pubkey,
+}
+
+impl SetCreatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "set_creator_authority",
+        "global",
+        "mint",
+        "metadata",
+        "bonding_curve",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SetCreatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[254, 148, 255, 112, 207, 142, 170, 165];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetMayhemVirtualParamsInstruction {}
+
+impl SetMayhemVirtualParamsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "sol_vault_authority",
+        "mayhem_token_vault",
+        "mint",
+        "global",
+        "bonding_curve",
+        "token_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SetMayhemVirtualParamsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[61, 169, 188, 191, 153, 149, 42, 97];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetMetaplexCreatorInstruction {}
+
+impl SetMetaplexCreatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "mint",
+        "metadata",
+        "bonding_curve",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SetMetaplexCreatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[138, 96, 174, 217, 48, 85, 197, 246];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetParamsInstruction {
+    pub initial_virtual_token_reserves: u64,
+    pub initial_virtual_sol_reserves: u64,
+    pub initial_real_token_reserves: u64,
+    pub token_total_supply: u64,
+    pub fee_basis_points: u64,
+    pub withdraw_authority: Pubkey,
+    pub enable_migrate: bool,
+    pub pool_migration_fee: u64,
+    pub creator_fee_basis_points: u64,
+    pub set_creator_authority: Pubkey,
+    pub admin_set_creator_authority: Pubkey,
+}
+
+impl SetParamsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "global",
+        "authority",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SetParamsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[27, 234, 178, 52, 147, 2, 187, 141];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetReservedFeeRecipientsInstruction {
+    pub whitelist_pda: Pubkey,
+}
+
+impl SetReservedFeeRecipientsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "global",
+        "authority",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SetReservedFeeRecipientsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[111, 172, 162, 232, 114, 89, 213, 142];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SyncUserVolumeAccumulatorInstruction {}
+
+impl SyncUserVolumeAccumulatorInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "user",
+        "global_volume_accumulator",
+        "user_volume_accumulator",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SyncUserVolumeAccumulatorInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[86, 31, 192, 87, 163, 87, 79, 238];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ToggleCashbackEnabledInstruction {
+    pub enabled: bool,
+}
+
+impl ToggleCashbackEnabledInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "global",
+        "authority",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ToggleCashbackEnabledInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[115, 103, 224, 255, 189, 89, 86, 195];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ToggleCreateV2Instruction {
+    pub enabled: bool,
+}
+
+impl ToggleCreateV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "global",
+        "authority",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ToggleCreateV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[28, 255, 230, 240, 172, 107, 203, 171];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ToggleMayhemModeInstruction {
+    pub enabled: bool,
+}
+
+impl ToggleMayhemModeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "global",
+        "authority",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for ToggleMayhemModeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[1, 9, 111, 208, 100, 31, 255, 163];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateGlobalAuthorityInstruction {}
+
+impl UpdateGlobalAuthorityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "global",
+        "authority",
+        "new_authority",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for UpdateGlobalAuthorityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[227, 181, 74, 196, 208, 21, 97, 213];
+}
+
+impl IdlDecode for AdminSetCreatorEvent {
+    const DISCRIMINATOR: &'static [u8] = &[64, 69, 192, 104, 29, 30, 25, 107];
+}
+
+impl IdlDecode for AdminSetIdlAuthorityEvent {
+    const DISCRIMINATOR: &'static [u8] = &[245, 59, 70, 34, 75, 185, 109, 92];
+}
+
+impl IdlDecode for AdminUpdateTokenIncentivesEvent {
+    const DISCRIMINATOR: &'static [u8] = &[147, 250, 108, 120, 247, 29, 67, 222];
+}
+
+impl IdlDecode for ClaimCashbackEvent {
+    const DISCRIMINATOR: &'static [u8] = &[226, 214, 246, 33, 7, 242, 147, 229];
+}
+
+impl IdlDecode for ClaimTokenIncentivesEvent {
+    const DISCRIMINATOR: &'static [u8] = &[79, 172, 246, 49, 205, 91, 206, 232];
+}
+
+impl IdlDecode for CloseUserVolumeAccumulatorEvent {
+    const DISCRIMINATOR: &'static [u8] = &[146, 159, 189, 172, 146, 88, 56, 244];
+}
+
+impl IdlDecode for CollectCreatorFeeEvent {
+    const DISCRIMINATOR: &'static [u8] = &[122, 2, 127, 1, 14, 191, 12, 175];
+}
+
+impl IdlDecode for CompleteEvent {
+    const DISCRIMINATOR: &'static [u8] = &[95, 114, 97, 156, 212, 46, 152, 8];
+}
+
+impl IdlDecode for CompletePumpAmmMigrationEvent {
+    const DISCRIMINATOR: &'static [u8] = &[189, 233, 93, 185, 92, 148, 234, 148];
+}
+
+impl IdlDecode for CreateEvent {
+    const DISCRIMINATOR: &'static [u8] = &[27, 114, 169, 77, 222, 235, 99, 118];
+}
+
+impl IdlDecode for DistributeCreatorFeesEvent {
+    const DISCRIMINATOR: &'static [u8] = &[165, 55, 129, 112, 4, 179, 202, 40];
+}
+
+impl IdlDecode for ExtendAccountEvent {
+    const DISCRIMINATOR: &'static [u8] = &[97, 97, 215, 144, 93, 146, 22, 124];
+}
+
+impl IdlDecode for InitUserVolumeAccumulatorEvent {
+    const DISCRIMINATOR: &'static [u8] = &[134, 36, 13, 72, 232, 101, 130, 216];
+}
+
+impl IdlDecode for MigrateBondingCurveCreatorEvent {
+    const DISCRIMINATOR: &'static [u8] = &[155, 167, 104, 220, 213, 108, 243, 3];
+}
+
+impl IdlDecode for MinimumDistributableFeeEvent {
+    const DISCRIMINATOR: &'static [u8] = &[168, 216, 132, 239, 235, 182, 49, 52];
+}
+
+impl IdlDecode for ReservedFeeRecipientsEvent {
+    const DISCRIMINATOR: &'static [u8] = &[43, 188, 250, 18, 221, 75, 187, 95];
+}
+
+impl IdlDecode for SetCreatorEvent {
+    const DISCRIMINATOR: &'static [u8] = &[237, 52, 123, 37, 245, 251, 72, 210];
+}
+
+impl IdlDecode for SetMetaplexCreatorEvent {
+    const DISCRIMINATOR: &'static [u8] = &[142, 203, 6, 32, 127, 105, 191, 162];
+}
+
+impl IdlDecode for SetParamsEvent {
+    const DISCRIMINATOR: &'static [u8] = &[223, 195, 159, 246, 62, 48, 143, 131];
+}
+
+impl IdlDecode for SyncUserVolumeAccumulatorEvent {
+    const DISCRIMINATOR: &'static [u8] = &[197, 122, 167, 124, 116, 81, 91, 255];
+}
+
+impl IdlDecode for TradeEvent {
+    const DISCRIMINATOR: &'static [u8] = &[189, 219, 127, 211, 78, 230, 97, 238];
+}
+
+impl IdlDecode for UpdateGlobalAuthorityEvent {
+    const DISCRIMINATOR: &'static [u8] = &[182, 195, 137, 42, 35, 206, 207, 247];
+}
+
+impl IdlDecode for UpdateMayhemVirtualParamsEvent {
+    const DISCRIMINATOR: &'static [u8] = &[117, 123, 228, 182, 161, 168, 220, 214];
+}
+
+impl IdlDecode for BondingCurve {
+    const DISCRIMINATOR: &'static [u8] = &[23, 183, 248, 55, 96, 216, 172, 96];
+}
+
+impl IdlDecode for FeeConfig {
+    const DISCRIMINATOR: &'static [u8] = &[143, 52, 146, 187, 219, 123, 76, 155];
+}
+
+impl IdlDecode for Global {
+    const DISCRIMINATOR: &'static [u8] = &[167, 232, 232, 177, 200, 108, 114, 127];
+}
+
+impl IdlDecode for GlobalVolumeAccumulator {
+    const DISCRIMINATOR: &'static [u8] = &[202, 42, 246, 43, 142, 190, 30, 255];
+}
+
+impl IdlDecode for SharingConfig {
+    const DISCRIMINATOR: &'static [u8] = &[216, 74, 9, 0, 56, 140, 93, 75];
+}
+
+impl IdlDecode for UserVolumeAccumulator {
+    const DISCRIMINATOR: &'static [u8] = &[86, 255, 112, 14, 102, 53, 154, 250];
+}
+
+/// Discriminator constants
+pub mod discriminators {
+    // Instruction discriminators
+    pub const ADMIN_SET_CREATOR_IX: &[u8] = &[69, 25, 171, 142, 57, 239, 13, 4];
+    pub const ADMIN_SET_IDL_AUTHORITY_IX: &[u8] = &[8, 217, 96, 231, 144, 104, 192, 5];
+    pub const ADMIN_UPDATE_TOKEN_INCENTIVES_IX: &[u8] = &[209, 11, 115, 87, 213, 23, 124, 204];
+    pub const BUY_IX: &[u8] = &[102, 6, 61, 18, 1, 218, 235, 234];
+    pub const BUY_EXACT_SOL_IN_IX: &[u8] = &[56, 252, 116, 8, 158, 223, 205, 95];
+    pub const CLAIM_CASHBACK_IX: &[u8] = &[37, 58, 35, 126, 190, 53, 228, 197];
+    pub const CLAIM_TOKEN_INCENTIVES_IX: &[u8] = &[16, 4, 71, 28, 204, 1, 40, 27];
+    pub const CLOSE_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[249, 69, 164, 218, 150, 103, 84, 138];
+    pub const COLLECT_CREATOR_FEE_IX: &[u8] = &[20, 22, 86, 123, 198, 28, 219, 132];
+    pub const CREATE_IX: &[u8] = &[24, 30, 200, 40, 5, 28, 7, 119];
+    pub const CREATE_V2_IX: &[u8] = &[214, 144, 76, 236, 95, 139, 49, 180];
+    pub const DISTRIBUTE_CREATOR_FEES_IX: &[u8] = &[165, 114, 103, 0, 121, 206, 247, 81];
+    pub const EXTEND_ACCOUNT_IX: &[u8] = &[234, 102, 194, 203, 150, 72, 62, 229];
+    pub const GET_MINIMUM_DISTRIBUTABLE_FEE_IX: &[u8] = &[117, 225, 127, 202, 134, 95, 68, 35];
+    pub const INIT_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[94, 6, 202, 115, 255, 96, 232, 183];
+    pub const INITIALIZE_IX: &[u8] = &[175, 175, 109, 31, 13, 152, 155, 237];
+    pub const MIGRATE_IX: &[u8] = &[155, 234, 231, 146, 236, 158, 162, 30];
+    pub const MIGRATE_BONDING_CURVE_CREATOR_IX: &[u8] = &[87, 124, 52, 191, 52, 38, 214, 232];
+    pub const SELL_IX: &[u8] = &[51, 230, 133, 164, 1, 127, 131, 173];
+    pub const SET_CREATOR_IX: &[u8] = &[254, 148, 255, 112, 207, 142, 170, 165];
+    pub const SET_MAYHEM_VIRTUAL_PARAMS_IX: &[u8] = &[61, 169, 188, 191, 153, 149, 42, 97];
+    pub const SET_METAPLEX_CREATOR_IX: &[u8] = &[138, 96, 174, 217, 48, 85, 197, 246];
+    pub const SET_PARAMS_IX: &[u8] = &[27, 234, 178, 52, 147, 2, 187, 141];
+    pub const SET_RESERVED_FEE_RECIPIENTS_IX: &[u8] = &[111, 172, 162, 232, 114, 89, 213, 142];
+    pub const SYNC_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[86, 31, 192, 87, 163, 87, 79, 238];
+    pub const TOGGLE_CASHBACK_ENABLED_IX: &[u8] = &[115, 103, 224, 255, 189, 89, 86, 195];
+    pub const TOGGLE_CREATE_V2_IX: &[u8] = &[28, 255, 230, 240, 172, 107, 203, 171];
+    pub const TOGGLE_MAYHEM_MODE_IX: &[u8] = &[1, 9, 111, 208, 100, 31, 255, 163];
+    pub const UPDATE_GLOBAL_AUTHORITY_IX: &[u8] = &[227, 181, 74, 196, 208, 21, 97, 213];
+
+    // Event discriminators
+    pub const ADMIN_SET_CREATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 64, 69, 192, 104, 29, 30, 25, 107];
+    pub const ADMIN_SET_IDL_AUTHORITY_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 245, 59, 70, 34, 75, 185, 109, 92];
+    pub const ADMIN_UPDATE_TOKEN_INCENTIVES_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 147, 250, 108, 120, 247, 29, 67, 222];
+    pub const CLAIM_CASHBACK_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 226, 214, 246, 33, 7, 242, 147, 229];
+    pub const CLAIM_TOKEN_INCENTIVES_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 79, 172, 246, 49, 205, 91, 206, 232];
+    pub const CLOSE_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 146, 159, 189, 172, 146, 88, 56, 244];
+    pub const COLLECT_CREATOR_FEE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 122, 2, 127, 1, 14, 191, 12, 175];
+    pub const COMPLETE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 95, 114, 97, 156, 212, 46, 152, 8];
+    pub const COMPLETE_PUMP_AMM_MIGRATION_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 189, 233, 93, 185, 92, 148, 234, 148];
+    pub const CREATE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 27, 114, 169, 77, 222, 235, 99, 118];
+    pub const DISTRIBUTE_CREATOR_FEES_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 165, 55, 129, 112, 4, 179, 202, 40];
+    pub const EXTEND_ACCOUNT_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 97, 97, 215, 144, 93, 146, 22, 124];
+    pub const INIT_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 134, 36, 13, 72, 232, 101, 130, 216];
+    pub const MIGRATE_BONDING_CURVE_CREATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 155, 167, 104, 220, 213, 108, 243, 3];
+    pub const MINIMUM_DISTRIBUTABLE_FEE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 168, 216, 132, 239, 235, 182, 49, 52];
+    pub const RESERVED_FEE_RECIPIENTS_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 43, 188, 250, 18, 221, 75, 187, 95];
+    pub const SET_CREATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 237, 52, 123, 37, 245, 251, 72, 210];
+    pub const SET_METAPLEX_CREATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 142, 203, 6, 32, 127, 105, 191, 162];
+    pub const SET_PARAMS_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 223, 195, 159, 246, 62, 48, 143, 131];
+    pub const SYNC_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 197, 122, 167, 124, 116, 81, 91, 255];
+    pub const TRADE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 189, 219, 127, 211, 78, 230, 97, 238];
+    pub const UPDATE_GLOBAL_AUTHORITY_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 182, 195, 137, 42, 35, 206, 207, 247];
+    pub const UPDATE_MAYHEM_VIRTUAL_PARAMS_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 117, 123, 228, 182, 161, 168, 220, 214];
+
+    // Account discriminators
+    pub const BONDING_CURVE_ACCOUNT: &[u8] = &[23, 183, 248, 55, 96, 216, 172, 96];
+    pub const FEE_CONFIG_ACCOUNT: &[u8] = &[143, 52, 146, 187, 219, 123, 76, 155];
+    pub const GLOBAL_ACCOUNT: &[u8] = &[167, 232, 232, 177, 200, 108, 114, 127];
+    pub const GLOBAL_VOLUME_ACCUMULATOR_ACCOUNT: &[u8] = &[202, 42, 246, 43, 142, 190, 30, 255];
+    pub const SHARING_CONFIG_ACCOUNT: &[u8] = &[216, 74, 9, 0, 56, 140, 93, 75];
+    pub const USER_VOLUME_ACCUMULATOR_ACCOUNT: &[u8] = &[86, 255, 112, 14, 102, 53, 154, 250];
+}
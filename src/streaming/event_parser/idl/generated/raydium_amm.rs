@@ -0,0 +1,778 @@
+//! Generated from `idl/raydium_amm.json` by `idl::codegen`, do not edit.
+//!
+//! Regenerate with `cargo run --example idl_codegen`.
+
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::idl::IdlDecode;
+
+pub const PROGRAM_ID: Pubkey = solana_sdk::pubkey!("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8");
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AmmConfig {
+    #[serde(rename = "pnlOwner")]
+    pub pnl_owner: Pubkey,
+    #[serde(rename = "cancelOwner")]
+    pub cancel_owner: Pubkey,
+    pub pending1: [u64; 28],
+    pub pending2: [u64; 32],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AmmInfo {
+    pub status: u64,
+    pub nonce: u64,
+    #[serde(rename = "orderNum")]
+    pub order_num: u64,
+    pub depth: u64,
+    #[serde(rename = "coinDecimals")]
+    pub coin_decimals: u64,
+    #[serde(rename = "pcDecimals")]
+    pub pc_decimals: u64,
+    pub state: u64,
+    #[serde(rename = "resetFlag")]
+    pub reset_flag: u64,
+    #[serde(rename = "minSize")]
+    pub min_size: u64,
+    #[serde(rename = "volMaxCutRatio")]
+    pub vol_max_cut_ratio: u64,
+    #[serde(rename = "amountWave")]
+    pub amount_wave: u64,
+    #[serde(rename = "coinLotSize")]
+    pub coin_lot_size: u64,
+    #[serde(rename = "pcLotSize")]
+    pub pc_lot_size: u64,
+    #[serde(rename = "minPriceMultiplier")]
+    pub min_price_multiplier: u64,
+    #[serde(rename = "maxPriceMultiplier")]
+    pub max_price_multiplier: u64,
+    #[serde(rename = "sysDecimalValue")]
+    pub sys_decimal_value: u64,
+    pub fees: Fees,
+    #[serde(rename = "outPut")]
+    pub out_put: OutPutData,
+    #[serde(rename = "tokenCoin")]
+    pub token_coin: Pubkey,
+    #[serde(rename = "tokenPc")]
+    pub token_pc: Pubkey,
+    #[serde(rename = "coinMint")]
+    pub coin_mint: Pubkey,
+    #[serde(rename = "pcMint")]
+    pub pc_mint: Pubkey,
+    #[serde(rename = "lpMint")]
+    pub lp_mint: Pubkey,
+    #[serde(rename = "openOrders")]
+    pub open_orders: Pubkey,
+    pub market: Pubkey,
+    #[serde(rename = "serumDex")]
+    pub serum_dex: Pubkey,
+    #[serde(rename = "targetOrders")]
+    pub target_orders: Pubkey,
+    #[serde(rename = "withdrawQueue")]
+    pub withdraw_queue: Pubkey,
+    #[serde(rename = "tokenTempLp")]
+    pub token_temp_lp: Pubkey,
+    #[serde(rename = "ammOwner")]
+    pub amm_owner: Pubkey,
+    #[serde(rename = "lpAmount")]
+    pub lp_amount: u64,
+    #[serde(rename = "clientOrderId")]
+    pub client_order_id: u64,
+    pub padding: [u64; 2],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Fees {
+    #[serde(rename = "minSeparateNumerator")]
+    pub min_separate_numerator: u64,
+    #[serde(rename = "minSeparateDenominator")]
+    pub min_separate_denominator: u64,
+    #[serde(rename = "tradeFeeNumerator")]
+    pub trade_fee_numerator: u64,
+    #[serde(rename = "tradeFeeDenominator")]
+    pub trade_fee_denominator: u64,
+    #[serde(rename = "pnlNumerator")]
+    pub pnl_numerator: u64,
+    #[serde(rename = "pnlDenominator")]
+    pub pnl_denominator: u64,
+    #[serde(rename = "swapFeeNumerator")]
+    pub swap_fee_numerator: u64,
+    #[serde(rename = "swapFeeDenominator")]
+    pub swap_fee_denominator: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LastOrderDistance {
+    #[serde(rename = "lastOrderNumerator")]
+    pub last_order_numerator: u64,
+    #[serde(rename = "lastOrderDenominator")]
+    pub last_order_denominator: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct NeedTake {
+    #[serde(rename = "needTakePc")]
+    pub need_take_pc: u64,
+    #[serde(rename = "needTakeCoin")]
+    pub need_take_coin: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OutPutData {
+    #[serde(rename = "needTakePnlCoin")]
+    pub need_take_pnl_coin: u64,
+    #[serde(rename = "needTakePnlPc")]
+    pub need_take_pnl_pc: u64,
+    #[serde(rename = "totalPnlPc")]
+    pub total_pnl_pc: u64,
+    #[serde(rename = "totalPnlCoin")]
+    pub total_pnl_coin: u64,
+    #[serde(rename = "poolOpenTime")]
+    pub pool_open_time: u64,
+    #[serde(rename = "punishPcAmount")]
+    pub punish_pc_amount: u64,
+    #[serde(rename = "punishCoinAmount")]
+    pub punish_coin_amount: u64,
+    #[serde(rename = "orderbookToInitTime")]
+    pub orderbook_to_init_time: u64,
+    #[serde(rename = "swapCoinInAmount")]
+    pub swap_coin_in_amount: u128,
+    #[serde(rename = "swapPcOutAmount")]
+    pub swap_pc_out_amount: u128,
+    #[serde(rename = "swapTakePcFee")]
+    pub swap_take_pc_fee: u64,
+    #[serde(rename = "swapPcInAmount")]
+    pub swap_pc_in_amount: u128,
+    #[serde(rename = "swapCoinOutAmount")]
+    pub swap_coin_out_amount: u128,
+    #[serde(rename = "swapTakeCoinFee")]
+    pub swap_take_coin_fee: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapInstructionBaseIn {
+    #[serde(rename = "amountIn")]
+    pub amount_in: u64,
+    #[serde(rename = "minimumAmountOut")]
+    pub minimum_amount_out: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapInstructionBaseOut {
+    #[serde(rename = "maxAmountIn")]
+    pub max_amount_in: u64,
+    #[serde(rename = "amountOut")]
+    pub amount_out: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TargetOrder {
+    pub price: u64,
+    pub vol: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TargetOrders {
+    pub owner: [u64; 4],
+    #[serde(rename = "buyOrders")]
+    #[serde(with = "serde_big_array::BigArray")]
+    pub buy_orders: [TargetOrder; 50],
+    pub padding1: [u64; 8],
+    #[serde(rename = "targetX")]
+    pub target_x: u128,
+    #[serde(rename = "targetY")]
+    pub target_y: u128,
+    #[serde(rename = "planXBuy")]
+    pub plan_xbuy: u128,
+    #[serde(rename = "planYBuy")]
+    pub plan_ybuy: u128,
+    #[serde(rename = "planXSell")]
+    pub plan_xsell: u128,
+    #[serde(rename = "planYSell")]
+    pub plan_ysell: u128,
+    #[serde(rename = "placedX")]
+    pub placed_x: u128,
+    #[serde(rename = "placedY")]
+    pub placed_y: u128,
+    #[serde(rename = "calcPnlX")]
+    pub calc_pnl_x: u128,
+    #[serde(rename = "calcPnlY")]
+    pub calc_pnl_y: u128,
+    #[serde(rename = "sellOrders")]
+    #[serde(with = "serde_big_array::BigArray")]
+    pub sell_orders: [TargetOrder; 50],
+    pub padding2: [u64; 6],
+    #[serde(rename = "replaceBuyClientId")]
+    pub replace_buy_client_id: [u64; 10],
+    #[serde(rename = "replaceSellClientId")]
+    pub replace_sell_client_id: [u64; 10],
+    #[serde(rename = "lastOrderNumerator")]
+    pub last_order_numerator: u64,
+    #[serde(rename = "lastOrderDenominator")]
+    pub last_order_denominator: u64,
+    #[serde(rename = "planOrdersCur")]
+    pub plan_orders_cur: u64,
+    #[serde(rename = "placeOrdersCur")]
+    pub place_orders_cur: u64,
+    #[serde(rename = "validBuyOrderNum")]
+    pub valid_buy_order_num: u64,
+    #[serde(rename = "validSellOrderNum")]
+    pub valid_sell_order_num: u64,
+    pub padding3: [u64; 10],
+    #[serde(rename = "freeSlotBits")]
+    pub free_slot_bits: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawDestToken {
+    #[serde(rename = "withdrawAmount")]
+    pub withdraw_amount: u64,
+    #[serde(rename = "coinAmount")]
+    pub coin_amount: u64,
+    #[serde(rename = "pcAmount")]
+    pub pc_amount: u64,
+    #[serde(rename = "destTokenCoin")]
+    pub dest_token_coin: Pubkey,
+    #[serde(rename = "destTokenPc")]
+    pub dest_token_pc: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawQueue {
+    pub owner: [u64; 4],
+    pub head: u64,
+    pub count: u64,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub buf: [WithdrawDestToken; 64],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeInstruction {
+    pub nonce: u8,
+    #[serde(rename = "openTime")]
+    pub open_time: u64,
+}
+
+impl InitializeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "systemProgram",
+        "rent",
+        "amm",
+        "ammAuthority",
+        "ammOpenOrders",
+        "lpMintAddress",
+        "coinMintAddress",
+        "pcMintAddress",
+        "poolCoinTokenAccount",
+        "poolPcTokenAccount",
+        "poolWithdrawQueue",
+        "poolTargetOrdersAccount",
+        "userLpTokenAccount",
+        "poolTempLpTokenAccount",
+        "serumProgram",
+        "serumMarket",
+        "userWallet",
+    ];
+}
+
+impl IdlDecode for InitializeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[0];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Initialize2Instruction {
+    pub nonce: u8,
+    #[serde(rename = "openTime")]
+    pub open_time: u64,
+    #[serde(rename = "initPcAmount")]
+    pub init_pc_amount: u64,
+    #[serde(rename = "initCoinAmount")]
+    pub init_coin_amount: u64,
+}
+
+impl Initialize2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "splAssociatedTokenAccount",
+        "systemProgram",
+        "rent",
+        "amm",
+        "ammAuthority",
+        "ammOpenOrders",
+        "lpMint",
+        "coinMint",
+        "pcMint",
+        "poolCoinTokenAccount",
+        "poolPcTokenAccount",
+        "poolWithdrawQueue",
+        "ammTargetOrders",
+        "poolTempLp",
+        "serumProgram",
+        "serumMarket",
+        "userWallet",
+        "userTokenCoin",
+        "userTokenPc",
+        "userLpTokenAccount",
+    ];
+}
+
+impl IdlDecode for Initialize2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[1];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MonitorStepInstruction {
+    #[serde(rename = "planOrderLimit")]
+    pub plan_order_limit: u16,
+    #[serde(rename = "placeOrderLimit")]
+    pub place_order_limit: u16,
+    #[serde(rename = "cancelOrderLimit")]
+    pub cancel_order_limit: u16,
+}
+
+impl MonitorStepInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "rent",
+        "clock",
+        "amm",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "poolCoinTokenAccount",
+        "poolPcTokenAccount",
+        "poolWithdrawQueue",
+        "serumProgram",
+        "serumMarket",
+        "serumCoinVaultAccount",
+        "serumPcVaultAccount",
+        "serumVaultSigner",
+        "serumReqQ",
+        "serumEventQ",
+        "serumBids",
+        "serumAsks",
+    ];
+}
+
+impl IdlDecode for MonitorStepInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[2];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DepositInstruction {
+    #[serde(rename = "maxCoinAmount")]
+    pub max_coin_amount: u64,
+    #[serde(rename = "maxPcAmount")]
+    pub max_pc_amount: u64,
+    #[serde(rename = "baseSide")]
+    pub base_side: u64,
+}
+
+impl DepositInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "amm",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "lpMintAddress",
+        "poolCoinTokenAccount",
+        "poolPcTokenAccount",
+        "serumMarket",
+        "userCoinTokenAccount",
+        "userPcTokenAccount",
+        "userLpTokenAccount",
+        "userOwner",
+        "serumEventQueue",
+    ];
+}
+
+impl IdlDecode for DepositInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[3];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawInstruction {
+    pub amount: u64,
+}
+
+impl WithdrawInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "amm",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "lpMintAddress",
+        "poolCoinTokenAccount",
+        "poolPcTokenAccount",
+        "poolWithdrawQueue",
+        "poolTempLpTokenAccount",
+        "serumProgram",
+        "serumMarket",
+        "serumCoinVaultAccount",
+        "serumPcVaultAccount",
+        "serumVaultSigner",
+        "userLpTokenAccount",
+        "userCoinTokenAccount",
+        "userPcTokenAccount",
+        "userOwner",
+        "serumEventQ",
+        "serumBids",
+        "serumAsks",
+    ];
+}
+
+impl IdlDecode for WithdrawInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[4];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MigrateToOpenBookInstruction {}
+
+impl MigrateToOpenBookInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "systemProgram",
+        "rent",
+        "amm",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTokenCoin",
+        "ammTokenPc",
+        "ammTargetOrders",
+        "serumProgram",
+        "serumMarket",
+        "serumBids",
+        "serumAsks",
+        "serumEventQueue",
+        "serumCoinVault",
+        "serumPcVault",
+        "serumVaultSigner",
+        "newAmmOpenOrders",
+        "newSerumProgram",
+        "newSerumMarket",
+        "admin",
+    ];
+}
+
+impl IdlDecode for MigrateToOpenBookInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[5];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetParamsInstruction {
+    pub param: u8,
+    pub value: Option<u64>,
+    #[serde(rename = "newPubkey")]
+    pub new_pubkey: Option<Pubkey>,
+    pub fees: Option<Fees>,
+    #[serde(rename = "lastOrderDistance")]
+    pub last_order_distance: Option<LastOrderDistance>,
+    #[serde(rename = "needTakeAmounts")]
+    pub need_take_amounts: Option<NeedTake>,
+}
+
+impl SetParamsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "amm",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "ammCoinVault",
+        "ammPcVault",
+        "serumProgram",
+        "serumMarket",
+        "serumCoinVault",
+        "serumPcVault",
+        "serumVaultSigner",
+        "serumEventQueue",
+        "serumBids",
+        "serumAsks",
+        "ammAdminAccount",
+    ];
+}
+
+impl IdlDecode for SetParamsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[6];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawPnlInstruction {}
+
+impl WithdrawPnlInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "amm",
+        "ammConfig",
+        "ammAuthority",
+        "ammOpenOrders",
+        "poolCoinTokenAccount",
+        "poolPcTokenAccount",
+        "coinPnlTokenAccount",
+        "pcPnlTokenAccount",
+        "pnlOwnerAccount",
+        "ammTargetOrders",
+        "serumProgram",
+        "serumMarket",
+        "serumEventQueue",
+        "serumCoinVaultAccount",
+        "serumPcVaultAccount",
+        "serumVaultSigner",
+    ];
+}
+
+impl IdlDecode for WithdrawPnlInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[7];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawSrmInstruction {
+    pub amount: u64,
+}
+
+impl WithdrawSrmInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "amm",
+        "ammOwnerAccount",
+        "ammAuthority",
+        "srmToken",
+        "destSrmToken",
+    ];
+}
+
+impl IdlDecode for WithdrawSrmInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[8];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapBaseInInstruction {
+    #[serde(rename = "amountIn")]
+    pub amount_in: u64,
+    #[serde(rename = "minimumAmountOut")]
+    pub minimum_amount_out: u64,
+}
+
+impl SwapBaseInInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "amm",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "poolCoinTokenAccount",
+        "poolPcTokenAccount",
+        "serumProgram",
+        "serumMarket",
+        "serumBids",
+        "serumAsks",
+        "serumEventQueue",
+        "serumCoinVaultAccount",
+        "serumPcVaultAccount",
+        "serumVaultSigner",
+        "userSourceTokenAccount",
+        "userDestinationTokenAccount",
+        "userSourceOwner",
+    ];
+}
+
+impl IdlDecode for SwapBaseInInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[9];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PreInitializeInstruction {
+    pub nonce: u8,
+}
+
+impl PreInitializeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "systemProgram",
+        "rent",
+        "ammTargetOrders",
+        "poolWithdrawQueue",
+        "ammAuthority",
+        "lpMintAddress",
+        "coinMintAddress",
+        "pcMintAddress",
+        "poolCoinTokenAccount",
+        "poolPcTokenAccount",
+        "poolTempLpTokenAccount",
+        "serumMarket",
+        "userWallet",
+    ];
+}
+
+impl IdlDecode for PreInitializeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[10];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapBaseOutInstruction {
+    #[serde(rename = "maxAmountIn")]
+    pub max_amount_in: u64,
+    #[serde(rename = "amountOut")]
+    pub amount_out: u64,
+}
+
+impl SwapBaseOutInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "amm",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "poolCoinTokenAccount",
+        "poolPcTokenAccount",
+        "serumProgram",
+        "serumMarket",
+        "serumBids",
+        "serumAsks",
+        "serumEventQueue",
+        "serumCoinVaultAccount",
+        "serumPcVaultAccount",
+        "serumVaultSigner",
+        "userSourceTokenAccount",
+        "userDestinationTokenAccount",
+        "userSourceOwner",
+    ];
+}
+
+impl IdlDecode for SwapBaseOutInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[11];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SimulateInfoInstruction {
+    pub param: u8,
+    #[serde(rename = "swapBaseInValue")]
+    pub swap_base_in_value: Option<SwapInstructionBaseIn>,
+    #[serde(rename = "swapBaseOutValue")]
+    pub swap_base_out_value: Option<SwapInstructionBaseOut>,
+}
+
+impl SimulateInfoInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "amm",
+        "ammAuthority",
+        "ammOpenOrders",
+        "poolCoinTokenAccount",
+        "poolPcTokenAccount",
+        "lpMintAddress",
+        "serumMarket",
+        "serumEventQueue",
+    ];
+}
+
+impl IdlDecode for SimulateInfoInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[12];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminCancelOrdersInstruction {
+    pub limit: u16,
+}
+
+impl AdminCancelOrdersInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "tokenProgram",
+        "amm",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "poolCoinTokenAccount",
+        "poolPcTokenAccount",
+        "ammOwnerAccount",
+        "ammConfig",
+        "serumProgram",
+        "serumMarket",
+        "serumCoinVaultAccount",
+        "serumPcVaultAccount",
+        "serumVaultSigner",
+        "serumEventQ",
+        "serumBids",
+        "serumAsks",
+    ];
+}
+
+impl IdlDecode for AdminCancelOrdersInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[13];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateConfigAccountInstruction {}
+
+impl CreateConfigAccountInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "ammConfig",
+        "owner",
+        "systemProgram",
+        "rent",
+    ];
+}
+
+impl IdlDecode for CreateConfigAccountInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[14];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateConfigAccountInstruction {
+    pub param: u8,
+    pub owner: Pubkey,
+}
+
+impl UpdateConfigAccountInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "ammConfig",
+    ];
+}
+
+impl IdlDecode for UpdateConfigAccountInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[15];
+}
+
+impl IdlDecode for TargetOrders {
+    const DISCRIMINATOR: &'static [u8] = &[113, 225, 140, 255, 65, 144, 239, 231];
+}
+
+impl IdlDecode for Fees {
+    const DISCRIMINATOR: &'static [u8] = &[151, 157, 50, 115, 130, 72, 179, 36];
+}
+
+impl IdlDecode for AmmInfo {
+    const DISCRIMINATOR: &'static [u8] = &[33, 217, 2, 203, 184, 83, 235, 91];
+}
+
+/// Discriminator constants
+pub mod discriminators {
+    // Instruction discriminators
+    pub const INITIALIZE_IX: &[u8] = &[0];
+    pub const INITIALIZE2_IX: &[u8] = &[1];
+    pub const MONITOR_STEP_IX: &[u8] = &[2];
+    pub const DEPOSIT_IX: &[u8] = &[3];
+    pub const WITHDRAW_IX: &[u8] = &[4];
+    pub const MIGRATE_TO_OPEN_BOOK_IX: &[u8] = &[5];
+    pub const SET_PARAMS_IX: &[u8] = &[6];
+    pub const WITHDRAW_PNL_IX: &[u8] = &[7];
+    pub const WITHDRAW_SRM_IX: &[u8] = &[8];
+    pub const SWAP_BASE_IN_IX: &[u8] = &[9];
+    pub const PRE_INITIALIZE_IX: &[u8] = &[10];
+    pub const SWAP_BASE_OUT_IX: &[u8] = &[11];
+    pub const SIMULATE_INFO_IX: &[u8] = &[12];
+    pub const ADMIN_CANCEL_ORDERS_IX: &[u8] = &[13];
+    pub const CREATE_CONFIG_ACCOUNT_IX: &[u8] = &[14];
+    pub const UPDATE_CONFIG_ACCOUNT_IX: &[u8] = &[15];
+
+    // Account discriminators
+    pub const TARGET_ORDERS_ACCOUNT: &[u8] = &[113, 225, 140, 255, 65, 144, 239, 231];
+    pub const FEES_ACCOUNT: &[u8] = &[151, 157, 50, 115, 130, 72, 179, 36];
+    pub const AMM_INFO_ACCOUNT: &[u8] = &[33, 217, 2, 203, 184, 83, 235, 91];
+}
@@ -0,0 +1,1265 @@
+//! Generated from `idl/raydium_clmm.json` by `idl::codegen`, do not edit.
+//!
+//! Regenerate with `cargo run --example idl_codegen`.
+
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::idl::IdlDecode;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AmmConfig {
+    pub bump: u8,
+    pub index: u16,
+    pub owner: Pubkey,
+    #[serde(rename = "protocolFeeRate")]
+    pub protocol_fee_rate: u32,
+    #[serde(rename = "tradeFeeRate")]
+    pub trade_fee_rate: u32,
+    #[serde(rename = "tickSpacing")]
+    pub tick_spacing: u16,
+    #[serde(rename = "fundFeeRate")]
+    pub fund_fee_rate: u32,
+    #[serde(rename = "paddingU32")]
+    pub padding_u32: u32,
+    #[serde(rename = "fundOwner")]
+    pub fund_owner: Pubkey,
+    pub padding: [u64; 3],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectPersonalFeeEvent {
+    #[serde(rename = "positionNftMint")]
+    pub position_nft_mint: Pubkey,
+    #[serde(rename = "recipientTokenAccount0")]
+    pub recipient_token_account0: Pubkey,
+    #[serde(rename = "recipientTokenAccount1")]
+    pub recipient_token_account1: Pubkey,
+    pub amount0: u64,
+    pub amount1: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectProtocolFeeEvent {
+    #[serde(rename = "poolState")]
+    pub pool_state: Pubkey,
+    #[serde(rename = "recipientTokenAccount0")]
+    pub recipient_token_account0: Pubkey,
+    #[serde(rename = "recipientTokenAccount1")]
+    pub recipient_token_account1: Pubkey,
+    pub amount0: u64,
+    pub amount1: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ConfigChangeEvent {
+    pub index: u16,
+    pub owner: Pubkey,
+    #[serde(rename = "protocolFeeRate")]
+    pub protocol_fee_rate: u32,
+    #[serde(rename = "tradeFeeRate")]
+    pub trade_fee_rate: u32,
+    #[serde(rename = "tickSpacing")]
+    pub tick_spacing: u16,
+    #[serde(rename = "fundFeeRate")]
+    pub fund_fee_rate: u32,
+    #[serde(rename = "fundOwner")]
+    pub fund_owner: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreatePersonalPositionEvent {
+    #[serde(rename = "poolState")]
+    pub pool_state: Pubkey,
+    pub minter: Pubkey,
+    #[serde(rename = "nftOwner")]
+    pub nft_owner: Pubkey,
+    #[serde(rename = "tickLowerIndex")]
+    pub tick_lower_index: i32,
+    #[serde(rename = "tickUpperIndex")]
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    #[serde(rename = "depositAmount0")]
+    pub deposit_amount0: u64,
+    #[serde(rename = "depositAmount1")]
+    pub deposit_amount1: u64,
+    #[serde(rename = "depositAmount0TransferFee")]
+    pub deposit_amount0_transfer_fee: u64,
+    #[serde(rename = "depositAmount1TransferFee")]
+    pub deposit_amount1_transfer_fee: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DecreaseLiquidityEvent {
+    #[serde(rename = "positionNftMint")]
+    pub position_nft_mint: Pubkey,
+    pub liquidity: u128,
+    #[serde(rename = "decreaseAmount0")]
+    pub decrease_amount0: u64,
+    #[serde(rename = "decreaseAmount1")]
+    pub decrease_amount1: u64,
+    #[serde(rename = "feeAmount0")]
+    pub fee_amount0: u64,
+    #[serde(rename = "feeAmount1")]
+    pub fee_amount1: u64,
+    #[serde(rename = "rewardAmounts")]
+    pub reward_amounts: [u64; 3],
+    #[serde(rename = "transferFee0")]
+    pub transfer_fee0: u64,
+    #[serde(rename = "transferFee1")]
+    pub transfer_fee1: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct IncreaseLiquidityEvent {
+    #[serde(rename = "positionNftMint")]
+    pub position_nft_mint: Pubkey,
+    pub liquidity: u128,
+    pub amount0: u64,
+    pub amount1: u64,
+    #[serde(rename = "amount0TransferFee")]
+    pub amount0_transfer_fee: u64,
+    #[serde(rename = "amount1TransferFee")]
+    pub amount1_transfer_fee: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeRewardParam {
+    #[serde(rename = "openTime")]
+    pub open_time: u64,
+    #[serde(rename = "endTime")]
+    pub end_time: u64,
+    #[serde(rename = "emissionsPerSecondX64")]
+    pub emissions_per_second_x64: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LiquidityCalculateEvent {
+    #[serde(rename = "poolLiquidity")]
+    pub pool_liquidity: u128,
+    #[serde(rename = "poolSqrtPriceX64")]
+    pub pool_sqrt_price_x64: u128,
+    #[serde(rename = "poolTick")]
+    pub pool_tick: i32,
+    #[serde(rename = "calcAmount0")]
+    pub calc_amount0: u64,
+    #[serde(rename = "calcAmount1")]
+    pub calc_amount1: u64,
+    #[serde(rename = "tradeFeeOwed0")]
+    pub trade_fee_owed0: u64,
+    #[serde(rename = "tradeFeeOwed1")]
+    pub trade_fee_owed1: u64,
+    #[serde(rename = "transferFee0")]
+    pub transfer_fee0: u64,
+    #[serde(rename = "transferFee1")]
+    pub transfer_fee1: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LiquidityChangeEvent {
+    #[serde(rename = "poolState")]
+    pub pool_state: Pubkey,
+    pub tick: i32,
+    #[serde(rename = "tickLower")]
+    pub tick_lower: i32,
+    #[serde(rename = "tickUpper")]
+    pub tick_upper: i32,
+    #[serde(rename = "liquidityBefore")]
+    pub liquidity_before: u128,
+    #[serde(rename = "liquidityAfter")]
+    pub liquidity_after: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Observation {
+    #[serde(rename = "blockTimestamp")]
+    pub block_timestamp: u32,
+    #[serde(rename = "tickCumulative")]
+    pub tick_cumulative: i64,
+    pub padding: [u64; 4],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ObservationState {
+    pub initialized: bool,
+    #[serde(rename = "recentEpoch")]
+    pub recent_epoch: u64,
+    #[serde(rename = "observationIndex")]
+    pub observation_index: u16,
+    #[serde(rename = "poolId")]
+    pub pool_id: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub observations: [Observation; 100],
+    pub padding: [u64; 4],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OperationState {
+    pub bump: u8,
+    #[serde(rename = "operationOwners")]
+    pub operation_owners: [Pubkey; 10],
+    #[serde(rename = "whitelistMints")]
+    #[serde(with = "serde_big_array::BigArray")]
+    pub whitelist_mints: [Pubkey; 100],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PersonalPositionState {
+    pub bump: [u8; 1],
+    #[serde(rename = "nftMint")]
+    pub nft_mint: Pubkey,
+    #[serde(rename = "poolId")]
+    pub pool_id: Pubkey,
+    #[serde(rename = "tickLowerIndex")]
+    pub tick_lower_index: i32,
+    #[serde(rename = "tickUpperIndex")]
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    #[serde(rename = "feeGrowthInside0LastX64")]
+    pub fee_growth_inside0_last_x64: u128,
+    #[serde(rename = "feeGrowthInside1LastX64")]
+    pub fee_growth_inside1_last_x64: u128,
+    #[serde(rename = "tokenFeesOwed0")]
+    pub token_fees_owed0: u64,
+    #[serde(rename = "tokenFeesOwed1")]
+    pub token_fees_owed1: u64,
+    #[serde(rename = "rewardInfos")]
+    pub reward_infos: [PositionRewardInfo; 3],
+    #[serde(rename = "recentEpoch")]
+    pub recent_epoch: u64,
+    pub padding: [u64; 7],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolCreatedEvent {
+    #[serde(rename = "tokenMint0")]
+    pub token_mint0: Pubkey,
+    #[serde(rename = "tokenMint1")]
+    pub token_mint1: Pubkey,
+    #[serde(rename = "tickSpacing")]
+    pub tick_spacing: u16,
+    #[serde(rename = "poolState")]
+    pub pool_state: Pubkey,
+    #[serde(rename = "sqrtPriceX64")]
+    pub sqrt_price_x64: u128,
+    pub tick: i32,
+    #[serde(rename = "tokenVault0")]
+    pub token_vault0: Pubkey,
+    #[serde(rename = "tokenVault1")]
+    pub token_vault1: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolState {
+    pub bump: [u8; 1],
+    #[serde(rename = "ammConfig")]
+    pub amm_config: Pubkey,
+    pub owner: Pubkey,
+    #[serde(rename = "tokenMint0")]
+    pub token_mint0: Pubkey,
+    #[serde(rename = "tokenMint1")]
+    pub token_mint1: Pubkey,
+    #[serde(rename = "tokenVault0")]
+    pub token_vault0: Pubkey,
+    #[serde(rename = "tokenVault1")]
+    pub token_vault1: Pubkey,
+    #[serde(rename = "observationKey")]
+    pub observation_key: Pubkey,
+    #[serde(rename = "mintDecimals0")]
+    pub mint_decimals0: u8,
+    #[serde(rename = "mintDecimals1")]
+    pub mint_decimals1: u8,
+    #[serde(rename = "tickSpacing")]
+    pub tick_spacing: u16,
+    pub liquidity: u128,
+    #[serde(rename = "sqrtPriceX64")]
+    pub sqrt_price_x64: u128,
+    #[serde(rename = "tickCurrent")]
+    pub tick_current: i32,
+    pub padding3: u16,
+    pub padding4: u16,
+    #[serde(rename = "feeGrowthGlobal0X64")]
+    pub fee_growth_global0_x64: u128,
+    #[serde(rename = "feeGrowthGlobal1X64")]
+    pub fee_growth_global1_x64: u128,
+    #[serde(rename = "protocolFeesToken0")]
+    pub protocol_fees_token0: u64,
+    #[serde(rename = "protocolFeesToken1")]
+    pub protocol_fees_token1: u64,
+    #[serde(rename = "swapInAmountToken0")]
+    pub swap_in_amount_token0: u128,
+    #[serde(rename = "swapOutAmountToken1")]
+    pub swap_out_amount_token1: u128,
+    #[serde(rename = "swapInAmountToken1")]
+    pub swap_in_amount_token1: u128,
+    #[serde(rename = "swapOutAmountToken0")]
+    pub swap_out_amount_token0: u128,
+    pub status: u8,
+    pub padding: [u8; 7],
+    #[serde(rename = "rewardInfos")]
+    pub reward_infos: [RewardInfo; 3],
+    #[serde(rename = "tickArrayBitmap")]
+    pub tick_array_bitmap: [u64; 16],
+    #[serde(rename = "totalFeesToken0")]
+    pub total_fees_token0: u64,
+    #[serde(rename = "totalFeesClaimedToken0")]
+    pub total_fees_claimed_token0: u64,
+    #[serde(rename = "totalFeesToken1")]
+    pub total_fees_token1: u64,
+    #[serde(rename = "totalFeesClaimedToken1")]
+    pub total_fees_claimed_token1: u64,
+    #[serde(rename = "fundFeesToken0")]
+    pub fund_fees_token0: u64,
+    #[serde(rename = "fundFeesToken1")]
+    pub fund_fees_token1: u64,
+    #[serde(rename = "openTime")]
+    pub open_time: u64,
+    #[serde(rename = "recentEpoch")]
+    pub recent_epoch: u64,
+    pub padding1: [u64; 24],
+    pub padding2: [u64; 32],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum PoolStatusBitFlag {
+    Enable,
+    Disable,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum PoolStatusBitIndex {
+    OpenPositionOrIncreaseLiquidity,
+    DecreaseLiquidity,
+    CollectFee,
+    CollectReward,
+    Swap,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PositionRewardInfo {
+    #[serde(rename = "growthInsideLastX64")]
+    pub growth_inside_last_x64: u128,
+    #[serde(rename = "rewardAmountOwed")]
+    pub reward_amount_owed: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ProtocolPositionState {
+    pub bump: u8,
+    #[serde(rename = "poolId")]
+    pub pool_id: Pubkey,
+    #[serde(rename = "tickLowerIndex")]
+    pub tick_lower_index: i32,
+    #[serde(rename = "tickUpperIndex")]
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    #[serde(rename = "feeGrowthInside0LastX64")]
+    pub fee_growth_inside0_last_x64: u128,
+    #[serde(rename = "feeGrowthInside1LastX64")]
+    pub fee_growth_inside1_last_x64: u128,
+    #[serde(rename = "tokenFeesOwed0")]
+    pub token_fees_owed0: u64,
+    #[serde(rename = "tokenFeesOwed1")]
+    pub token_fees_owed1: u64,
+    #[serde(rename = "rewardGrowthInside")]
+    pub reward_growth_inside: [u128; 3],
+    #[serde(rename = "recentEpoch")]
+    pub recent_epoch: u64,
+    pub padding: [u64; 7],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RewardInfo {
+    #[serde(rename = "rewardState")]
+    pub reward_state: u8,
+    #[serde(rename = "openTime")]
+    pub open_time: u64,
+    #[serde(rename = "endTime")]
+    pub end_time: u64,
+    #[serde(rename = "lastUpdateTime")]
+    pub last_update_time: u64,
+    #[serde(rename = "emissionsPerSecondX64")]
+    pub emissions_per_second_x64: u128,
+    #[serde(rename = "rewardTotalEmissioned")]
+    pub reward_total_emissioned: u64,
+    #[serde(rename = "rewardClaimed")]
+    pub reward_claimed: u64,
+    #[serde(rename = "tokenMint")]
+    pub token_mint: Pubkey,
+    #[serde(rename = "tokenVault")]
+    pub token_vault: Pubkey,
+    pub authority: Pubkey,
+    #[serde(rename = "rewardGrowthGlobalX64")]
+    pub reward_growth_global_x64: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum RewardState {
+    Uninitialized,
+    Initialized,
+    Opening,
+    Ended,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapEvent {
+    #[serde(rename = "poolState")]
+    pub pool_state: Pubkey,
+    pub sender: Pubkey,
+    #[serde(rename = "tokenAccount0")]
+    pub token_account0: Pubkey,
+    #[serde(rename = "tokenAccount1")]
+    pub token_account1: Pubkey,
+    pub amount0: u64,
+    #[serde(rename = "transferFee0")]
+    pub transfer_fee0: u64,
+    pub amount1: u64,
+    #[serde(rename = "transferFee1")]
+    pub transfer_fee1: u64,
+    #[serde(rename = "zeroForOne")]
+    pub zero_for_one: bool,
+    #[serde(rename = "sqrtPriceX64")]
+    pub sqrt_price_x64: u128,
+    pub liquidity: u128,
+    pub tick: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TickArrayBitmapExtension {
+    #[serde(rename = "poolId")]
+    pub pool_id: Pubkey,
+    #[serde(rename = "positiveTickArrayBitmap")]
+    pub positive_tick_array_bitmap: [[u64; 8]; 14],
+    #[serde(rename = "negativeTickArrayBitmap")]
+    pub negative_tick_array_bitmap: [[u64; 8]; 14],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TickArrayState {
+    #[serde(rename = "poolId")]
+    pub pool_id: Pubkey,
+    #[serde(rename = "startTickIndex")]
+    pub start_tick_index: i32,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub ticks: [TickState; 60],
+    #[serde(rename = "initializedTickCount")]
+    pub initialized_tick_count: u8,
+    #[serde(rename = "recentEpoch")]
+    pub recent_epoch: u64,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub padding: [u8; 107],
+}
+
+pub type TickArryBitmap = [u64; 8];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TickState {
+    pub tick: i32,
+    #[serde(rename = "liquidityNet")]
+    pub liquidity_net: i128,
+    #[serde(rename = "liquidityGross")]
+    pub liquidity_gross: u128,
+    #[serde(rename = "feeGrowthOutside0X64")]
+    pub fee_growth_outside0_x64: u128,
+    #[serde(rename = "feeGrowthOutside1X64")]
+    pub fee_growth_outside1_x64: u128,
+    #[serde(rename = "rewardGrowthsOutsideX64")]
+    pub reward_growths_outside_x64: [u128; 3],
+    pub padding: [u32; 13],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateRewardInfosEvent {
+    #[serde(rename = "rewardGrowthGlobalX64")]
+    pub reward_growth_global_x64: [u128; 3],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateAmmConfigInstruction {
+    pub index: u16,
+    #[serde(rename = "tickSpacing")]
+    pub tick_spacing: u16,
+    #[serde(rename = "tradeFeeRate")]
+    pub trade_fee_rate: u32,
+    #[serde(rename = "protocolFeeRate")]
+    pub protocol_fee_rate: u32,
+    #[serde(rename = "fundFeeRate")]
+    pub fund_fee_rate: u32,
+}
+
+impl CreateAmmConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "ammConfig",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for CreateAmmConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[137, 52, 237, 212, 215, 117, 108, 104];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateAmmConfigInstruction {
+    pub param: u8,
+    pub value: u32,
+}
+
+impl UpdateAmmConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "ammConfig",
+    ];
+}
+
+impl IdlDecode for UpdateAmmConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[49, 60, 174, 136, 154, 28, 116, 200];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreatePoolInstruction {
+    #[serde(rename = "sqrtPriceX64")]
+    pub sqrt_price_x64: u128,
+    #[serde(rename = "openTime")]
+    pub open_time: u64,
+}
+
+impl CreatePoolInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "poolCreator",
+        "ammConfig",
+        "poolState",
+        "tokenMint0",
+        "tokenMint1",
+        "tokenVault0",
+        "tokenVault1",
+        "observationState",
+        "tickArrayBitmap",
+        "tokenProgram0",
+        "tokenProgram1",
+        "systemProgram",
+        "rent",
+    ];
+}
+
+impl IdlDecode for CreatePoolInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[233, 146, 209, 142, 207, 104, 64, 188];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdatePoolStatusInstruction {
+    pub status: u8,
+}
+
+impl UpdatePoolStatusInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "authority",
+        "poolState",
+    ];
+}
+
+impl IdlDecode for UpdatePoolStatusInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[130, 87, 108, 6, 46, 224, 117, 123];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateOperationAccountInstruction {}
+
+impl CreateOperationAccountInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "operationState",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for CreateOperationAccountInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[63, 87, 148, 33, 109, 35, 8, 104];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateOperationAccountInstruction {
+    pub param: u8,
+    pub keys: Vec<Pubkey>,
+}
+
+impl UpdateOperationAccountInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "operationState",
+        "systemProgram",
+    ];
+}
+
+impl IdlDecode for UpdateOperationAccountInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[127, 70, 119, 40, 188, 227, 61, 7];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TransferRewardOwnerInstruction {
+    #[serde(rename = "newOwner")]
+    pub new_owner: Pubkey,
+}
+
+impl TransferRewardOwnerInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "authority",
+        "poolState",
+    ];
+}
+
+impl IdlDecode for TransferRewardOwnerInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[7, 22, 12, 83, 242, 43, 48, 121];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeRewardInstruction {
+    pub param: InitializeRewardParam,
+}
+
+impl InitializeRewardInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "rewardFunder",
+        "funderTokenAccount",
+        "ammConfig",
+        "poolState",
+        "operationState",
+        "rewardTokenMint",
+        "rewardTokenVault",
+        "rewardTokenProgram",
+        "systemProgram",
+        "rent",
+    ];
+}
+
+impl IdlDecode for InitializeRewardInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[95, 135, 192, 196, 242, 129, 230, 68];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectRemainingRewardsInstruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u8,
+}
+
+impl CollectRemainingRewardsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "rewardFunder",
+        "funderTokenAccount",
+        "poolState",
+        "rewardTokenVault",
+        "rewardVaultMint",
+        "tokenProgram",
+        "tokenProgram2022",
+        "memoProgram",
+    ];
+}
+
+impl IdlDecode for CollectRemainingRewardsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[18, 237, 166, 197, 34, 16, 213, 144];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateRewardInfosInstruction {}
+
+impl UpdateRewardInfosInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "poolState",
+    ];
+}
+
+impl IdlDecode for UpdateRewardInfosInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[163, 172, 224, 52, 11, 154, 106, 223];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetRewardParamsInstruction {
+    #[serde(rename = "rewardIndex")]
+    pub reward_index: u8,
+    #[serde(rename = "emissionsPerSecondX64")]
+    pub emissions_per_second_x64: u128,
+    #[serde(rename = "openTime")]
+    pub open_time: u64,
+    #[serde(rename = "endTime")]
+    pub end_time: u64,
+}
+
+impl SetRewardParamsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "authority",
+        "ammConfig",
+        "poolState",
+        "operationState",
+        "tokenProgram",
+        "tokenProgram2022",
+    ];
+}
+
+impl IdlDecode for SetRewardParamsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[112, 52, 167, 75, 32, 201, 211, 137];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectProtocolFeeInstruction {
+    #[serde(rename = "amount0Requested")]
+    pub amount0_requested: u64,
+    #[serde(rename = "amount1Requested")]
+    pub amount1_requested: u64,
+}
+
+impl CollectProtocolFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "poolState",
+        "ammConfig",
+        "tokenVault0",
+        "tokenVault1",
+        "vault0Mint",
+        "vault1Mint",
+        "recipientTokenAccount0",
+        "recipientTokenAccount1",
+        "tokenProgram",
+        "tokenProgram2022",
+    ];
+}
+
+impl IdlDecode for CollectProtocolFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[136, 136, 252, 221, 194, 66, 126, 89];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectFundFeeInstruction {
+    #[serde(rename = "amount0Requested")]
+    pub amount0_requested: u64,
+    #[serde(rename = "amount1Requested")]
+    pub amount1_requested: u64,
+}
+
+impl CollectFundFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "poolState",
+        "ammConfig",
+        "tokenVault0",
+        "tokenVault1",
+        "vault0Mint",
+        "vault1Mint",
+        "recipientTokenAccount0",
+        "recipientTokenAccount1",
+        "tokenProgram",
+        "tokenProgram2022",
+    ];
+}
+
+impl IdlDecode for CollectFundFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[167, 138, 78, 149, 223, 194, 6, 126];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OpenPositionInstruction {
+    #[serde(rename = "tickLowerIndex")]
+    pub tick_lower_index: i32,
+    #[serde(rename = "tickUpperIndex")]
+    pub tick_upper_index: i32,
+    #[serde(rename = "tickArrayLowerStartIndex")]
+    pub tick_array_lower_start_index: i32,
+    #[serde(rename = "tickArrayUpperStartIndex")]
+    pub tick_array_upper_start_index: i32,
+    pub liquidity: u128,
+    #[serde(rename = "amount0Max")]
+    pub amount0_max: u64,
+    #[serde(rename = "amount1Max")]
+    pub amount1_max: u64,
+}
+
+impl OpenPositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "positionNftOwner",
+        "positionNftMint",
+        "positionNftAccount",
+        "metadataAccount",
+        "poolState",
+        "protocolPosition",
+        "tickArrayLower",
+        "tickArrayUpper",
+        "personalPosition",
+        "tokenAccount0",
+        "tokenAccount1",
+        "tokenVault0",
+        "tokenVault1",
+        "rent",
+        "systemProgram",
+        "tokenProgram",
+        "associatedTokenProgram",
+        "metadataProgram",
+    ];
+}
+
+impl IdlDecode for OpenPositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[135, 128, 47, 77, 15, 152, 240, 49];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OpenPositionV2Instruction {
+    #[serde(rename = "tickLowerIndex")]
+    pub tick_lower_index: i32,
+    #[serde(rename = "tickUpperIndex")]
+    pub tick_upper_index: i32,
+    #[serde(rename = "tickArrayLowerStartIndex")]
+    pub tick_array_lower_start_index: i32,
+    #[serde(rename = "tickArrayUpperStartIndex")]
+    pub tick_array_upper_start_index: i32,
+    pub liquidity: u128,
+    #[serde(rename = "amount0Max")]
+    pub amount0_max: u64,
+    #[serde(rename = "amount1Max")]
+    pub amount1_max: u64,
+    #[serde(rename = "withMetadata")]
+    pub with_metadata: bool,
+    #[serde(rename = "baseFlag")]
+    pub base_flag: Option<bool>,
+}
+
+impl OpenPositionV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "positionNftOwner",
+        "positionNftMint",
+        "positionNftAccount",
+        "metadataAccount",
+        "poolState",
+        "protocolPosition",
+        "tickArrayLower",
+        "tickArrayUpper",
+        "personalPosition",
+        "tokenAccount0",
+        "tokenAccount1",
+        "tokenVault0",
+        "tokenVault1",
+        "rent",
+        "systemProgram",
+        "tokenProgram",
+        "associatedTokenProgram",
+        "metadataProgram",
+        "tokenProgram2022",
+        "vault0Mint",
+        "vault1Mint",
+    ];
+}
+
+impl IdlDecode for OpenPositionV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[77, 184, 74, 214, 112, 86, 241, 199];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OpenPositionWithToken22NftInstruction {
+    #[serde(rename = "tickLowerIndex")]
+    pub tick_lower_index: i32,
+    #[serde(rename = "tickUpperIndex")]
+    pub tick_upper_index: i32,
+    #[serde(rename = "tickArrayLowerStartIndex")]
+    pub tick_array_lower_start_index: i32,
+    #[serde(rename = "tickArrayUpperStartIndex")]
+    pub tick_array_upper_start_index: i32,
+    pub liquidity: u128,
+    #[serde(rename = "amount0Max")]
+    pub amount0_max: u64,
+    #[serde(rename = "amount1Max")]
+    pub amount1_max: u64,
+    #[serde(rename = "withMetadata")]
+    pub with_metadata: bool,
+    #[serde(rename = "baseFlag")]
+    pub base_flag: Option<bool>,
+}
+
+impl OpenPositionWithToken22NftInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "positionNftOwner",
+        "positionNftMint",
+        "positionNftAccount",
+        "poolState",
+        "protocolPosition",
+        "tickArrayLower",
+        "tickArrayUpper",
+        "personalPosition",
+        "tokenAccount0",
+        "tokenAccount1",
+        "tokenVault0",
+        "tokenVault1",
+        "rent",
+        "systemProgram",
+        "tokenProgram",
+        "associatedTokenProgram",
+        "tokenProgram2022",
+        "vault0Mint",
+        "vault1Mint",
+    ];
+}
+
+impl IdlDecode for OpenPositionWithToken22NftInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[77, 255, 174, 82, 125, 29, 201, 46];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClosePositionInstruction {}
+
+impl ClosePositionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "nftOwner",
+        "positionNftMint",
+        "positionNftAccount",
+        "personalPosition",
+        "systemProgram",
+        "tokenProgram",
+    ];
+}
+
+impl IdlDecode for ClosePositionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[123, 134, 81, 0, 49, 68, 98, 98];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct IncreaseLiquidityInstruction {
+    pub liquidity: u128,
+    #[serde(rename = "amount0Max")]
+    pub amount0_max: u64,
+    #[serde(rename = "amount1Max")]
+    pub amount1_max: u64,
+}
+
+impl IncreaseLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "nftOwner",
+        "nftAccount",
+        "poolState",
+        "protocolPosition",
+        "personalPosition",
+        "tickArrayLower",
+        "tickArrayUpper",
+        "tokenAccount0",
+        "tokenAccount1",
+        "tokenVault0",
+        "tokenVault1",
+        "tokenProgram",
+    ];
+}
+
+impl IdlDecode for IncreaseLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[46, 156, 243, 118, 13, 205, 251, 178];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct IncreaseLiquidityV2Instruction {
+    pub liquidity: u128,
+    #[serde(rename = "amount0Max")]
+    pub amount0_max: u64,
+    #[serde(rename = "amount1Max")]
+    pub amount1_max: u64,
+    #[serde(rename = "baseFlag")]
+    pub base_flag: Option<bool>,
+}
+
+impl IncreaseLiquidityV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "nftOwner",
+        "nftAccount",
+        "poolState",
+        "protocolPosition",
+        "personalPosition",
+        "tickArrayLower",
+        "tickArrayUpper",
+        "tokenAccount0",
+        "tokenAccount1",
+        "tokenVault0",
+        "tokenVault1",
+        "tokenProgram",
+        "tokenProgram2022",
+        "vault0Mint",
+        "vault1Mint",
+    ];
+}
+
+impl IdlDecode for IncreaseLiquidityV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[133, 29, 89, 223, 69, 238, 176, 10];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DecreaseLiquidityInstruction {
+    pub liquidity: u128,
+    #[serde(rename = "amount0Min")]
+    pub amount0_min: u64,
+    #[serde(rename = "amount1Min")]
+    pub amount1_min: u64,
+}
+
+impl DecreaseLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "nftOwner",
+        "nftAccount",
+        "personalPosition",
+        "poolState",
+        "protocolPosition",
+        "tokenVault0",
+        "tokenVault1",
+        "tickArrayLower",
+        "tickArrayUpper",
+        "recipientTokenAccount0",
+        "recipientTokenAccount1",
+        "tokenProgram",
+    ];
+}
+
+impl IdlDecode for DecreaseLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[160, 38, 208, 111, 104, 91, 44, 1];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DecreaseLiquidityV2Instruction {
+    pub liquidity: u128,
+    #[serde(rename = "amount0Min")]
+    pub amount0_min: u64,
+    #[serde(rename = "amount1Min")]
+    pub amount1_min: u64,
+}
+
+impl DecreaseLiquidityV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "nftOwner",
+        "nftAccount",
+        "personalPosition",
+        "poolState",
+        "protocolPosition",
+        "tokenVault0",
+        "tokenVault1",
+        "tickArrayLower",
+        "tickArrayUpper",
+        "recipientTokenAccount0",
+        "recipientTokenAccount1",
+        "tokenProgram",
+        "tokenProgram2022",
+        "memoProgram",
+        "vault0Mint",
+        "vault1Mint",
+    ];
+}
+
+impl IdlDecode for DecreaseLiquidityV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[58, 127, 188, 62, 79, 82, 196, 96];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapInstruction {
+    pub amount: u64,
+    #[serde(rename = "otherAmountThreshold")]
+    pub other_amount_threshold: u64,
+    #[serde(rename = "sqrtPriceLimitX64")]
+    pub sqrt_price_limit_x64: u128,
+    #[serde(rename = "isBaseInput")]
+    pub is_base_input: bool,
+}
+
+impl SwapInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "ammConfig",
+        "poolState",
+        "inputTokenAccount",
+        "outputTokenAccount",
+        "inputVault",
+        "outputVault",
+        "observationState",
+        "tokenProgram",
+        "tickArray",
+    ];
+}
+
+impl IdlDecode for SwapInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapV2Instruction {
+    pub amount: u64,
+    #[serde(rename = "otherAmountThreshold")]
+    pub other_amount_threshold: u64,
+    #[serde(rename = "sqrtPriceLimitX64")]
+    pub sqrt_price_limit_x64: u128,
+    #[serde(rename = "isBaseInput")]
+    pub is_base_input: bool,
+}
+
+impl SwapV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "ammConfig",
+        "poolState",
+        "inputTokenAccount",
+        "outputTokenAccount",
+        "inputVault",
+        "outputVault",
+        "observationState",
+        "tokenProgram",
+        "tokenProgram2022",
+        "memoProgram",
+        "inputVaultMint",
+        "outputVaultMint",
+    ];
+}
+
+impl IdlDecode for SwapV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[43, 4, 237, 11, 26, 201, 30, 98];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapRouterBaseInInstruction {
+    #[serde(rename = "amountIn")]
+    pub amount_in: u64,
+    #[serde(rename = "amountOutMinimum")]
+    pub amount_out_minimum: u64,
+}
+
+impl SwapRouterBaseInInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "inputTokenAccount",
+        "inputTokenMint",
+        "tokenProgram",
+        "tokenProgram2022",
+        "memoProgram",
+    ];
+}
+
+impl IdlDecode for SwapRouterBaseInInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[69, 125, 115, 218, 245, 186, 242, 196];
+}
+
+impl IdlDecode for ConfigChangeEvent {
+    const DISCRIMINATOR: &'static [u8] = &[247, 189, 7, 119, 106, 112, 95, 151];
+}
+
+impl IdlDecode for CreatePersonalPositionEvent {
+    const DISCRIMINATOR: &'static [u8] = &[100, 30, 87, 249, 196, 223, 154, 206];
+}
+
+impl IdlDecode for IncreaseLiquidityEvent {
+    const DISCRIMINATOR: &'static [u8] = &[49, 79, 105, 212, 32, 34, 30, 84];
+}
+
+impl IdlDecode for DecreaseLiquidityEvent {
+    const DISCRIMINATOR: &'static [u8] = &[58, 222, 86, 58, 68, 50, 85, 56];
+}
+
+impl IdlDecode for LiquidityCalculateEvent {
+    const DISCRIMINATOR: &'static [u8] = &[237, 112, 148, 230, 57, 84, 180, 162];
+}
+
+impl IdlDecode for CollectPersonalFeeEvent {
+    const DISCRIMINATOR: &'static [u8] = &[166, 174, 105, 192, 81, 161, 83, 105];
+}
+
+impl IdlDecode for UpdateRewardInfosEvent {
+    const DISCRIMINATOR: &'static [u8] = &[109, 127, 186, 78, 114, 65, 37, 236];
+}
+
+impl IdlDecode for PoolCreatedEvent {
+    const DISCRIMINATOR: &'static [u8] = &[25, 94, 75, 47, 112, 99, 53, 63];
+}
+
+impl IdlDecode for CollectProtocolFeeEvent {
+    const DISCRIMINATOR: &'static [u8] = &[206, 87, 17, 79, 45, 41, 213, 61];
+}
+
+impl IdlDecode for SwapEvent {
+    const DISCRIMINATOR: &'static [u8] = &[64, 198, 205, 232, 38, 8, 113, 226];
+}
+
+impl IdlDecode for LiquidityChangeEvent {
+    const DISCRIMINATOR: &'static [u8] = &[126, 240, 175, 206, 158, 88, 153, 107];
+}
+
+impl IdlDecode for AmmConfig {
+    const DISCRIMINATOR: &'static [u8] = &[218, 244, 33, 104, 203, 203, 43, 111];
+}
+
+impl IdlDecode for OperationState {
+    const DISCRIMINATOR: &'static [u8] = &[19, 236, 58, 237, 81, 222, 183, 252];
+}
+
+impl IdlDecode for ObservationState {
+    const DISCRIMINATOR: &'static [u8] = &[122, 174, 197, 53, 129, 9, 165, 132];
+}
+
+impl IdlDecode for PersonalPositionState {
+    const DISCRIMINATOR: &'static [u8] = &[70, 111, 150, 126, 230, 15, 25, 117];
+}
+
+impl IdlDecode for PoolState {
+    const DISCRIMINATOR: &'static [u8] = &[247, 237, 227, 245, 215, 195, 222, 70];
+}
+
+impl IdlDecode for ProtocolPositionState {
+    const DISCRIMINATOR: &'static [u8] = &[100, 226, 145, 99, 146, 218, 160, 106];
+}
+
+impl IdlDecode for TickArrayState {
+    const DISCRIMINATOR: &'static [u8] = &[192, 155, 85, 205, 49, 249, 129, 42];
+}
+
+impl IdlDecode for TickArrayBitmapExtension {
+    const DISCRIMINATOR: &'static [u8] = &[60, 150, 36, 219, 97, 128, 139, 153];
+}
+
+/// Discriminator constants
+pub mod discriminators {
+    // Instruction discriminators
+    pub const CREATE_AMM_CONFIG_IX: &[u8] = &[137, 52, 237, 212, 215, 117, 108, 104];
+    pub const UPDATE_AMM_CONFIG_IX: &[u8] = &[49, 60, 174, 136, 154, 28, 116, 200];
+    pub const CREATE_POOL_IX: &[u8] = &[233, 146, 209, 142, 207, 104, 64, 188];
+    pub const UPDATE_POOL_STATUS_IX: &[u8] = &[130, 87, 108, 6, 46, 224, 117, 123];
+    pub const CREATE_OPERATION_ACCOUNT_IX: &[u8] = &[63, 87, 148, 33, 109, 35, 8, 104];
+    pub const UPDATE_OPERATION_ACCOUNT_IX: &[u8] = &[127, 70, 119, 40, 188, 227, 61, 7];
+    pub const TRANSFER_REWARD_OWNER_IX: &[u8] = &[7, 22, 12, 83, 242, 43, 48, 121];
+    pub const INITIALIZE_REWARD_IX: &[u8] = &[95, 135, 192, 196, 242, 129, 230, 68];
+    pub const COLLECT_REMAINING_REWARDS_IX: &[u8] = &[18, 237, 166, 197, 34, 16, 213, 144];
+    pub const UPDATE_REWARD_INFOS_IX: &[u8] = &[163, 172, 224, 52, 11, 154, 106, 223];
+    pub const SET_REWARD_PARAMS_IX: &[u8] = &[112, 52, 167, 75, 32, 201, 211, 137];
+    pub const COLLECT_PROTOCOL_FEE_IX: &[u8] = &[136, 136, 252, 221, 194, 66, 126, 89];
+    pub const COLLECT_FUND_FEE_IX: &[u8] = &[167, 138, 78, 149, 223, 194, 6, 126];
+    pub const OPEN_POSITION_IX: &[u8] = &[135, 128, 47, 77, 15, 152, 240, 49];
+    pub const OPEN_POSITION_V2_IX: &[u8] = &[77, 184, 74, 214, 112, 86, 241, 199];
+    pub const OPEN_POSITION_WITH_TOKEN22_NFT_IX: &[u8] = &[77, 255, 174, 82, 125, 29, 201, 46];
+    pub const CLOSE_POSITION_IX: &[u8] = &[123, 134, 81, 0, 49, 68, 98, 98];
+    pub const INCREASE_LIQUIDITY_IX: &[u8] = &[46, 156, 243, 118, 13, 205, 251, 178];
+    pub const INCREASE_LIQUIDITY_V2_IX: &[u8] = &[133, 29, 89, 223, 69, 238, 176, 10];
+    pub const DECREASE_LIQUIDITY_IX: &[u8] = &[160, 38, 208, 111, 104, 91, 44, 1];
+    pub const DECREASE_LIQUIDITY_V2_IX: &[u8] = &[58, 127, 188, 62, 79, 82, 196, 96];
+    pub const SWAP_IX: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
+    pub const SWAP_V2_IX: &[u8] = &[43, 4, 237, 11, 26, 201, 30, 98];
+    pub const SWAP_ROUTER_BASE_IN_IX: &[u8] = &[69, 125, 115, 218, 245, 186, 242, 196];
+
+    // Event discriminators
+    pub const CONFIG_CHANGE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 247, 189, 7, 119, 106, 112, 95, 151];
+    pub const CREATE_PERSONAL_POSITION_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 100, 30, 87, 249, 196, 223, 154, 206];
+    pub const INCREASE_LIQUIDITY_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 49, 79, 105, 212, 32, 34, 30, 84];
+    pub const DECREASE_LIQUIDITY_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 58, 222, 86, 58, 68, 50, 85, 56];
+    pub const LIQUIDITY_CALCULATE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 237, 112, 148, 230, 57, 84, 180, 162];
+    pub const COLLECT_PERSONAL_FEE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 166, 174, 105, 192, 81, 161, 83, 105];
+    pub const UPDATE_REWARD_INFOS_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 109, 127, 186, 78, 114, 65, 37, 236];
+    pub const POOL_CREATED_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 25, 94, 75, 47, 112, 99, 53, 63];
+    pub const COLLECT_PROTOCOL_FEE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 206, 87, 17, 79, 45, 41, 213, 61];
+    pub const SWAP_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 64, 198, 205, 232, 38, 8, 113, 226];
+    pub const LIQUIDITY_CHANGE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 126, 240, 175, 206, 158, 88, 153, 107];
+
+    // Account discriminators
+    pub const AMM_CONFIG_ACCOUNT: &[u8] = &[218, 244, 33, 104, 203, 203, 43, 111];
+    pub const OPERATION_STATE_ACCOUNT: &[u8] = &[19, 236, 58, 237, 81, 222, 183, 252];
+    pub const OBSERVATION_STATE_ACCOUNT: &[u8] = &[122, 174, 197, 53, 129, 9, 165, 132];
+    pub const PERSONAL_POSITION_STATE_ACCOUNT: &[u8] = &[70, 111, 150, 126, 230, 15, 25, 117];
+    pub const POOL_STATE_ACCOUNT: &[u8] = &[247, 237, 227, 245, 215, 195, 222, 70];
+    pub const PROTOCOL_POSITION_STATE_ACCOUNT: &[u8] = &[100, 226, 145, 99, 146, 218, 160, 106];
+    pub const TICK_ARRAY_STATE_ACCOUNT: &[u8] = &[192, 155, 85, 205, 49, 249, 129, 42];
+    pub const TICK_ARRAY_BITMAP_EXTENSION_ACCOUNT: &[u8] = &[60, 150, 36, 219, 97, 128, 139, 153];
+}
@@ -0,0 +1,527 @@
+//! Generated from `idl/raydium_cpmm.json` by `idl::codegen`, do not edit.
+//!
+//! Regenerate with `cargo run --example idl_codegen`.
+
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::idl::IdlDecode;
+
+pub const PROGRAM_ID: Pubkey = solana_sdk::pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AmmConfig {
+    pub bump: u8,
+    pub disable_create_pool: bool,
+    pub index: u16,
+    pub trade_fee_rate: u64,
+    pub protocol_fee_rate: u64,
+    pub fund_fee_rate: u64,
+    pub create_pool_fee: u64,
+    pub protocol_owner: Pubkey,
+    pub fund_owner: Pubkey,
+    pub creator_fee_rate: u64,
+    pub padding: [u64; 15],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum CreatorFeeOn {
+    BothToken,
+    OnlyToken0,
+    OnlyToken1,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LpChangeEvent {
+    pub pool_id: Pubkey,
+    pub lp_amount_before: u64,
+    pub token_0_vault_before: u64,
+    pub token_1_vault_before: u64,
+    pub token_0_amount: u64,
+    pub token_1_amount: u64,
+    pub token_0_transfer_fee: u64,
+    pub token_1_transfer_fee: u64,
+    pub change_type: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Observation {
+    pub block_timestamp: u64,
+    pub cumulative_token_0_price_x32: u128,
+    pub cumulative_token_1_price_x32: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ObservationState {
+    pub initialized: bool,
+    pub observation_index: u16,
+    pub pool_id: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub observations: [Observation; 100],
+    pub padding: [u64; 4],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Permission {
+    pub authority: Pubkey,
+    pub padding: [u64; 30],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolState {
+    pub amm_config: Pubkey,
+    pub pool_creator: Pubkey,
+    pub token_0_vault: Pubkey,
+    pub token_1_vault: Pubkey,
+    pub lp_mint: Pubkey,
+    pub token_0_mint: Pubkey,
+    pub token_1_mint: Pubkey,
+    pub token_0_program: Pubkey,
+    pub token_1_program: Pubkey,
+    pub observation_key: Pubkey,
+    pub auth_bump: u8,
+    pub status: u8,
+    pub lp_mint_decimals: u8,
+    pub mint_0_decimals: u8,
+    pub mint_1_decimals: u8,
+    pub lp_supply: u64,
+    pub protocol_fees_token_0: u64,
+    pub protocol_fees_token_1: u64,
+    pub fund_fees_token_0: u64,
+    pub fund_fees_token_1: u64,
+    pub open_time: u64,
+    pub recent_epoch: u64,
+    pub creator_fee_on: u8,
+    pub enable_creator_fee: bool,
+    pub padding1: [u8; 6],
+    pub creator_fees_token_0: u64,
+    pub creator_fees_token_1: u64,
+    pub padding: [u64; 28],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapEvent {
+    pub pool_id: Pubkey,
+    pub input_vault_before: u64,
+    pub output_vault_before: u64,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub input_transfer_fee: u64,
+    pub output_transfer_fee: u64,
+    pub base_input: bool,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub trade_fee: u64,
+    pub creator_fee: u64,
+    pub creator_fee_on_input: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClosePermissionPdaInstruction {}
+
+impl ClosePermissionPdaInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "permission_authority",
+        "permission",
+        "system_program",
+    ];
+}
+
+impl IdlDecode for ClosePermissionPdaInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[156, 84, 32, 118, 69, 135, 70, 123];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectCreatorFeeInstruction {}
+
+impl CollectCreatorFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "creator",
+        "authority",
+        "pool_state",
+        "amm_config",
+        "token_0_vault",
+        "token_1_vault",
+        "vault_0_mint",
+        "vault_1_mint",
+        "creator_token_0",
+        "creator_token_1",
+        "token_0_program",
+        "token_1_program",
+        "associated_token_program",
+        "system_program",
+    ];
+}
+
+impl IdlDecode for CollectCreatorFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[20, 22, 86, 123, 198, 28, 219, 132];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectFundFeeInstruction {
+    pub amount_0_requested: u64,
+    pub amount_1_requested: u64,
+}
+
+impl CollectFundFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "authority",
+        "pool_state",
+        "amm_config",
+        "token_0_vault",
+        "token_1_vault",
+        "vault_0_mint",
+        "vault_1_mint",
+        "recipient_token_0_account",
+        "recipient_token_1_account",
+        "token_program",
+        "token_program_2022",
+    ];
+}
+
+impl IdlDecode for CollectFundFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[167, 138, 78, 149, 223, 194, 6, 126];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectProtocolFeeInstruction {
+    pub amount_0_requested: u64,
+    pub amount_1_requested: u64,
+}
+
+impl CollectProtocolFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "authority",
+        "pool_state",
+        "amm_config",
+        "token_0_vault",
+        "token_1_vault",
+        "vault_0_mint",
+        "vault_1_mint",
+        "recipient_token_0_account",
+        "recipient_token_1_account",
+        "token_program",
+        "token_program_2022",
+    ];
+}
+
+impl IdlDecode for CollectProtocolFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[136, 136, 252, 221, 194, 66, 126, 89];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateAmmConfigInstruction {
+    pub index: u16,
+    pub trade_fee_rate: u64,
+    pub protocol_fee_rate: u64,
+    pub fund_fee_rate: u64,
+    pub create_pool_fee: u64,
+    pub creator_fee_rate: u64,
+}
+
+impl CreateAmmConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "amm_config",
+        "system_program",
+    ];
+}
+
+impl IdlDecode for CreateAmmConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[137, 52, 237, 212, 215, 117, 108, 104];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreatePermissionPdaInstruction {}
+
+impl CreatePermissionPdaInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "permission_authority",
+        "permission",
+        "system_program",
+    ];
+}
+
+impl IdlDecode for CreatePermissionPdaInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[135, 136, 2, 216, 137, 169, 181, 202];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DepositInstruction {
+    pub lp_token_amount: u64,
+    pub maximum_token_0_amount: u64,
+    pub maximum_token_1_amount: u64,
+}
+
+impl DepositInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "authority",
+        "pool_state",
+        "owner_lp_token",
+        "token_0_account",
+        "token_1_account",
+        "token_0_vault",
+        "token_1_vault",
+        "token_program",
+        "token_program_2022",
+        "vault_0_mint",
+        "vault_1_mint",
+        "lp_mint",
+    ];
+}
+
+impl IdlDecode for DepositInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[242, 35, 198, 137, 82, 225, 242, 182];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeInstruction {
+    pub init_amount_0: u64,
+    pub init_amount_1: u64,
+    pub open_time: u64,
+}
+
+impl InitializeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "creator",
+        "amm_config",
+        "authority",
+        "pool_state",
+        "token_0_mint",
+        "token_1_mint",
+        "lp_mint",
+        "creator_token_0",
+        "creator_token_1",
+        "creator_lp_token",
+        "token_0_vault",
+        "token_1_vault",
+        "create_pool_fee",
+        "observation_state",
+        "token_program",
+        "token_0_program",
+        "token_1_program",
+        "associated_token_program",
+        "system_program",
+        "rent",
+    ];
+}
+
+impl IdlDecode for InitializeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[175, 175, 109, 31, 13, 152, 155, 237];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeWithPermissionInstruction {
+    pub init_amount_0: u64,
+    pub init_amount_1: u64,
+    pub open_time: u64,
+    pub creator_fee_on: CreatorFeeOn,
+}
+
+impl InitializeWithPermissionInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "creator",
+        "amm_config",
+        "authority",
+        "pool_state",
+        "token_0_mint",
+        "token_1_mint",
+        "lp_mint",
+        "payer_token_0",
+        "payer_token_1",
+        "payer_lp_token",
+        "token_0_vault",
+        "token_1_vault",
+        "create_pool_fee",
+        "observation_state",
+        "permission",
+        "token_program",
+        "token_0_program",
+        "token_1_program",
+        "associated_token_program",
+        "system_program",
+    ];
+}
+
+impl IdlDecode for InitializeWithPermissionInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[63, 55, 254, 65, 49, 178, 89, 121];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapBaseInputInstruction {
+    pub amount_in: u64,
+    pub minimum_amount_out: u64,
+}
+
+impl SwapBaseInputInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "authority",
+        "amm_config",
+        "pool_state",
+        "input_token_account",
+        "output_token_account",
+        "input_vault",
+        "output_vault",
+        "input_token_program",
+        "output_token_program",
+        "input_token_mint",
+        "output_token_mint",
+        "observation_state",
+    ];
+}
+
+impl IdlDecode for SwapBaseInputInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[143, 190, 90, 218, 196, 30, 51, 222];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapBaseOutputInstruction {
+    pub max_amount_in: u64,
+    pub amount_out: u64,
+}
+
+impl SwapBaseOutputInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "authority",
+        "amm_config",
+        "pool_state",
+        "input_token_account",
+        "output_token_account",
+        "input_vault",
+        "output_vault",
+        "input_token_program",
+        "output_token_program",
+        "input_token_mint",
+        "output_token_mint",
+        "observation_state",
+    ];
+}
+
+impl IdlDecode for SwapBaseOutputInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[55, 217, 98, 86, 163, 74, 180, 173];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateAmmConfigInstruction {
+    pub param: u8,
+    pub value: u64,
+}
+
+impl UpdateAmmConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "amm_config",
+    ];
+}
+
+impl IdlDecode for UpdateAmmConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[49, 60, 174, 136, 154, 28, 116, 200];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdatePoolStatusInstruction {
+    pub status: u8,
+}
+
+impl UpdatePoolStatusInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "authority",
+        "pool_state",
+    ];
+}
+
+impl IdlDecode for UpdatePoolStatusInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[130, 87, 108, 6, 46, 224, 117, 123];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawInstruction {
+    pub lp_token_amount: u64,
+    pub minimum_token_0_amount: u64,
+    pub minimum_token_1_amount: u64,
+}
+
+impl WithdrawInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "authority",
+        "pool_state",
+        "owner_lp_token",
+        "token_0_account",
+        "token_1_account",
+        "token_0_vault",
+        "token_1_vault",
+        "token_program",
+        "token_program_2022",
+        "vault_0_mint",
+        "vault_1_mint",
+        "lp_mint",
+        "memo_program",
+    ];
+}
+
+impl IdlDecode for WithdrawInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[183, 18, 70, 156, 148, 109, 161, 34];
+}
+
+impl IdlDecode for LpChangeEvent {
+    const DISCRIMINATOR: &'static [u8] = &[121, 163, 205, 201, 57, 218, 117, 60];
+}
+
+impl IdlDecode for SwapEvent {
+    const DISCRIMINATOR: &'static [u8] = &[64, 198, 205, 232, 38, 8, 113, 226];
+}
+
+impl IdlDecode for AmmConfig {
+    const DISCRIMINATOR: &'static [u8] = &[218, 244, 33, 104, 203, 203, 43, 111];
+}
+
+impl IdlDecode for ObservationState {
+    const DISCRIMINATOR: &'static [u8] = &[122, 174, 197, 53, 129, 9, 165, 132];
+}
+
+impl IdlDecode for Permission {
+    const DISCRIMINATOR: &'static [u8] = &[224, 83, 28, 79, 10, 253, 161, 28];
+}
+
+impl IdlDecode for PoolState {
+    const DISCRIMINATOR: &'static [u8] = &[247, 237, 227, 245, 215, 195, 222, 70];
+}
+
+/// Discriminator constants
+pub mod discriminators {
+    // Instruction discriminators
+    pub const CLOSE_PERMISSION_PDA_IX: &[u8] = &[156, 84, 32, 118, 69, 135, 70, 123];
+    pub const COLLECT_CREATOR_FEE_IX: &[u8] = &[20, 22, 86, 123, 198, 28, 219, 132];
+    pub const COLLECT_FUND_FEE_IX: &[u8] = &[167, 138, 78, 149, 223, 194, 6, 126];
+    pub const COLLECT_PROTOCOL_FEE_IX: &[u8] = &[136, 136, 252, 221, 194, 66, 126, 89];
+    pub const CREATE_AMM_CONFIG_IX: &[u8] = &[137, 52, 237, 212, 215, 117, 108, 104];
+    pub const CREATE_PERMISSION_PDA_IX: &[u8] = &[135, 136, 2, 216, 137, 169, 181, 202];
+    pub const DEPOSIT_IX: &[u8] = &[242, 35, 198, 137, 82, 225, 242, 182];
+    pub const INITIALIZE_IX: &[u8] = &[175, 175, 109, 31, 13, 152, 155, 237];
+    pub const INITIALIZE_WITH_PERMISSION_IX: &[u8] = &[63, 55, 254, 65, 49, 178, 89, 121];
+    pub const SWAP_BASE_INPUT_IX: &[u8] = &[143, 190, 90, 218, 196, 30, 51, 222];
+    pub const SWAP_BASE_OUTPUT_IX: &[u8] = &[55, 217, 98, 86, 163, 74, 180, 173];
+    pub const UPDATE_AMM_CONFIG_IX: &[u8] = &[49, 60, 174, 136, 154, 28, 116, 200];
+    pub const UPDATE_POOL_STATUS_IX: &[u8] = &[130, 87, 108, 6, 46, 224, 117, 123];
+    pub const WITHDRAW_IX: &[u8] = &[183, 18, 70, 156, 148, 109, 161, 34];
+
+    // Event discriminators
+    pub const LP_CHANGE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 121, 163, 205, 201, 57, 218, 117, 60];
+    pub const SWAP_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 64, 198, 205, 232, 38, 8, 113, 226];
+
+    // Account discriminators
+    pub const AMM_CONFIG_ACCOUNT: &[u8] = &[218, 244, 33, 104, 203, 203, 43, 111];
+    pub const OBSERVATION_STATE_ACCOUNT: &[u8] = &[122, 174, 197, 53, 129, 9, 165, 132];
+    pub const PERMISSION_ACCOUNT: &[u8] = &[224, 83, 28, 79, 10, 253, 161, 28];
+    pub const POOL_STATE_ACCOUNT: &[u8] = &[247, 237, 227, 245, 215, 195, 222, 70];
+}
@@ -0,0 +1,965 @@
+//! Generated from `idl/raydium_launchpad.json` by `idl::codegen`, do not edit.
+//!
+//! Regenerate with `cargo run --example idl_codegen`.
+
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::idl::IdlDecode;
+
+pub const PROGRAM_ID: Pubkey = solana_sdk::pubkey!("LanMV9sAd7wArD4vJFi2qDdfnVhFxYSUg6eADduJ3uj");
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum AmmCreatorFeeOn {
+    QuoteToken,
+    BothToken,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BondingCurveParam {
+    pub migrate_type: u8,
+    pub migrate_cpmm_fee_on: u8,
+    pub supply: u64,
+    pub total_base_sell: u64,
+    pub total_quote_fund_raising: u64,
+    pub total_locked_amount: u64,
+    pub cliff_period: u64,
+    pub unlock_period: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimVestedEvent {
+    pub pool_state: Pubkey,
+    pub beneficiary: Pubkey,
+    pub claim_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ConstantCurve {
+    pub supply: u64,
+    pub total_base_sell: u64,
+    pub total_quote_fund_raising: u64,
+    pub migrate_type: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateVestingEvent {
+    pub pool_state: Pubkey,
+    pub beneficiary: Pubkey,
+    pub share_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum CurveParams {
+    Constant {
+        data: ConstantCurve,
+    },
+    Fixed {
+        data: FixedCurve,
+    },
+    Linear {
+        data: LinearCurve,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FixedCurve {
+    pub supply: u64,
+    pub total_quote_fund_raising: u64,
+    pub migrate_type: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct GlobalConfig {
+    pub epoch: u64,
+    pub curve_type: u8,
+    pub index: u16,
+    pub migrate_fee: u64,
+    pub trade_fee_rate: u64,
+    pub max_share_fee_rate: u64,
+    pub min_base_supply: u64,
+    pub max_lock_rate: u64,
+    pub min_base_sell_rate: u64,
+    pub min_base_migrate_rate: u64,
+    pub min_quote_fund_raising: u64,
+    pub quote_mint: Pubkey,
+    pub protocol_fee_owner: Pubkey,
+    pub migrate_fee_owner: Pubkey,
+    pub migrate_to_amm_wallet: Pubkey,
+    pub migrate_to_cpswap_wallet: Pubkey,
+    pub padding: [u64; 16],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LinearCurve {
+    pub supply: u64,
+    pub total_quote_fund_raising: u64,
+    pub migrate_type: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MigrateNftInfo {
+    pub platform_scale: u64,
+    pub creator_scale: u64,
+    pub burn_scale: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MintParams {
+    pub decimals: u8,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PlatformConfig {
+    pub epoch: u64,
+    pub platform_fee_wallet: Pubkey,
+    pub platform_nft_wallet: Pubkey,
+    pub platform_scale: u64,
+    pub creator_scale: u64,
+    pub burn_scale: u64,
+    pub fee_rate: u64,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub name: [u8; 64],
+    #[serde(with = "serde_big_array::BigArray")]
+    pub web: [u8; 256],
+    #[serde(with = "serde_big_array::BigArray")]
+    pub img: [u8; 256],
+    pub cpswap_config: Pubkey,
+    pub creator_fee_rate: u64,
+    pub transfer_fee_extension_auth: Pubkey,
+    pub platform_vesting_wallet: Pubkey,
+    pub platform_vesting_scale: u64,
+    pub platform_cp_creator: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub padding: [u8; 108],
+    pub curve_params: Vec<PlatformCurveParam>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PlatformConfigInfo {
+    pub fee_wallet: Pubkey,
+    pub nft_wallet: Pubkey,
+    pub migrate_nft_info: MigrateNftInfo,
+    pub fee_rate: u64,
+    pub name: String,
+    pub web: String,
+    pub img: String,
+    pub transfer_fee_extension_auth: Pubkey,
+    pub creator_fee_rate: u64,
+    pub platform_vesting_scale: u64,
+    pub vesting_wallet: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum PlatformConfigParam {
+    FeeWallet(Pubkey),
+    NFTWallet(Pubkey),
+    MigrateNftInfo(MigrateNftInfo),
+    FeeRate(u64),
+    Name(String),
+    Web(String),
+    Img(String),
+    CpSwapConfig,
+    AllInfo(PlatformConfigInfo),
+    VestingWallet(Pubkey),
+    PlatformVestingScale(u64),
+    PlatformCPCreator(Pubkey),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PlatformCurveParam {
+    pub epoch: u64,
+    pub index: u8,
+    pub global_config: Pubkey,
+    pub bonding_curve_param: BondingCurveParam,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub padding: [u64; 50],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PlatformParams {
+    pub migrate_nft_info: MigrateNftInfo,
+    pub fee_rate: u64,
+    pub name: String,
+    pub web: String,
+    pub img: String,
+    pub creator_fee_rate: u64,
+    pub platform_vesting_scale: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolCreateEvent {
+    pub pool_state: Pubkey,
+    pub creator: Pubkey,
+    pub config: Pubkey,
+    pub base_mint_param: MintParams,
+    pub curve_param: CurveParams,
+    pub vesting_param: VestingParams,
+    pub amm_fee_on: AmmCreatorFeeOn,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolState {
+    pub epoch: u64,
+    pub auth_bump: u8,
+    pub status: u8,
+    pub base_decimals: u8,
+    pub quote_decimals: u8,
+    pub migrate_type: u8,
+    pub supply: u64,
+    pub total_base_sell: u64,
+    pub virtual_base: u64,
+    pub virtual_quote: u64,
+    pub real_base: u64,
+    pub real_quote: u64,
+    pub total_quote_fund_raising: u64,
+    pub quote_protocol_fee: u64,
+    pub platform_fee: u64,
+    pub migrate_fee: u64,
+    pub vesting_schedule: VestingSchedule,
+    pub global_config: Pubkey,
+    pub platform_config: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub creator: Pubkey,
+    pub token_program_flag: u8,
+    pub amm_creator_fee_on: AmmCreatorFeeOn,
+    pub platform_vesting_share: u64,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub padding: [u8; 54],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum PoolStatus {
+    Fund,
+    Migrate,
+    Trade,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum TradeDirection {
+    Buy,
+    Sell,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TradeEvent {
+    pub pool_state: Pubkey,
+    pub total_base_sell: u64,
+    pub virtual_base: u64,
+    pub virtual_quote: u64,
+    pub real_base_before: u64,
+    pub real_quote_before: u64,
+    pub real_base_after: u64,
+    pub real_quote_after: u64,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub protocol_fee: u64,
+    pub platform_fee: u64,
+    pub creator_fee: u64,
+    pub share_fee: u64,
+    pub trade_direction: TradeDirection,
+    pub pool_status: PoolStatus,
+    pub exact_in: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TransferFeeExtensionParams {
+    pub transfer_fee_basis_points: u16,
+    pub maximum_fee: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct VestingParams {
+    pub total_locked_amount: u64,
+    pub cliff_period: u64,
+    pub unlock_period: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct VestingRecord {
+    pub epoch: u64,
+    pub pool: Pubkey,
+    pub beneficiary: Pubkey,
+    pub claimed_amount: u64,
+    pub token_share_amount: u64,
+    pub padding: [u64; 8],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct VestingSchedule {
+    pub total_locked_amount: u64,
+    pub cliff_period: u64,
+    pub unlock_period: u64,
+    pub start_time: u64,
+    pub allocated_share_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BuyExactInInstruction {
+    pub amount_in: u64,
+    pub minimum_amount_out: u64,
+    pub share_fee_rate: u64,
+}
+
+impl BuyExactInInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "authority",
+        "global_config",
+        "platform_config",
+        "pool_state",
+        "user_base_token",
+        "user_quote_token",
+        "base_vault",
+        "quote_vault",
+        "base_token_mint",
+        "quote_token_mint",
+        "base_token_program",
+        "quote_token_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for BuyExactInInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[250, 234, 13, 123, 213, 156, 19, 236];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BuyExactOutInstruction {
+    pub amount_out: u64,
+    pub maximum_amount_in: u64,
+    pub share_fee_rate: u64,
+}
+
+impl BuyExactOutInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "authority",
+        "global_config",
+        "platform_config",
+        "pool_state",
+        "user_base_token",
+        "user_quote_token",
+        "base_vault",
+        "quote_vault",
+        "base_token_mint",
+        "quote_token_mint",
+        "base_token_program",
+        "quote_token_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for BuyExactOutInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[24, 211, 116, 40, 105, 3, 153, 56];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimCreatorFeeInstruction {}
+
+impl ClaimCreatorFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "creator",
+        "fee_vault_authority",
+        "creator_fee_vault",
+        "recipient_token_account",
+        "quote_mint",
+        "token_program",
+        "system_program",
+        "associated_token_program",
+    ];
+}
+
+impl IdlDecode for ClaimCreatorFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[26, 97, 138, 203, 132, 171, 141, 252];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimPlatformFeeInstruction {}
+
+impl ClaimPlatformFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "platform_fee_wallet",
+        "authority",
+        "pool_state",
+        "platform_config",
+        "quote_vault",
+        "recipient_token_account",
+        "quote_mint",
+        "token_program",
+        "system_program",
+        "associated_token_program",
+    ];
+}
+
+impl IdlDecode for ClaimPlatformFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[156, 39, 208, 135, 76, 237, 61, 72];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimPlatformFeeFromVaultInstruction {}
+
+impl ClaimPlatformFeeFromVaultInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "platform_fee_wallet",
+        "fee_vault_authority",
+        "platform_config",
+        "platform_fee_vault",
+        "recipient_token_account",
+        "quote_mint",
+        "token_program",
+        "system_program",
+        "associated_token_program",
+    ];
+}
+
+impl IdlDecode for ClaimPlatformFeeFromVaultInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[117, 241, 198, 168, 248, 218, 80, 29];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ClaimVestedTokenInstruction {}
+
+impl ClaimVestedTokenInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "beneficiary",
+        "authority",
+        "pool_state",
+        "vesting_record",
+        "base_vault",
+        "user_base_token",
+        "base_token_mint",
+        "base_token_program",
+        "system_program",
+        "associated_token_program",
+    ];
+}
+
+impl IdlDecode for ClaimVestedTokenInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[49, 33, 104, 30, 189, 157, 79, 35];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectFeeInstruction {}
+
+impl CollectFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "authority",
+        "pool_state",
+        "global_config",
+        "quote_vault",
+        "quote_mint",
+        "recipient_token_account",
+        "token_program",
+    ];
+}
+
+impl IdlDecode for CollectFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[60, 173, 247, 103, 4, 93, 130, 48];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CollectMigrateFeeInstruction {}
+
+impl CollectMigrateFeeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "authority",
+        "pool_state",
+        "global_config",
+        "quote_vault",
+        "quote_mint",
+        "recipient_token_account",
+        "token_program",
+    ];
+}
+
+impl IdlDecode for CollectMigrateFeeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[255, 186, 150, 223, 235, 118, 201, 186];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateConfigInstruction {
+    pub curve_type: u8,
+    pub index: u16,
+    pub migrate_fee: u64,
+    pub trade_fee_rate: u64,
+}
+
+impl CreateConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "global_config",
+        "quote_token_mint",
+        "protocol_fee_owner",
+        "migrate_fee_owner",
+        "migrate_to_amm_wallet",
+        "migrate_to_cpswap_wallet",
+        "system_program",
+    ];
+}
+
+impl IdlDecode for CreateConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[201, 207, 243, 114, 75, 111, 47, 189];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreatePlatformConfigInstruction {
+    pub platform_params: PlatformParams,
+}
+
+impl CreatePlatformConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "platform_admin",
+        "platform_fee_wallet",
+        "platform_nft_wallet",
+        "platform_config",
+        "cpswap_config",
+        "system_program",
+        "transfer_fee_extension_authority",
+        "platform_vesting_wallet",
+    ];
+}
+
+impl IdlDecode for CreatePlatformConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[176, 90, 196, 175, 253, 113, 220, 20];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreatePlatformVestingAccountInstruction {}
+
+impl CreatePlatformVestingAccountInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "platform_vesting_wallet",
+        "beneficiary",
+        "platform_config",
+        "pool_state",
+        "platform_vesting_record",
+        "system_program",
+    ];
+}
+
+impl IdlDecode for CreatePlatformVestingAccountInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[146, 71, 173, 69, 98, 19, 15, 106];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CreateVestingAccountInstruction {
+    pub share_amount: u64,
+}
+
+impl CreateVestingAccountInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "creator",
+        "beneficiary",
+        "pool_state",
+        "vesting_record",
+        "system_program",
+    ];
+}
+
+impl IdlDecode for CreateVestingAccountInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[129, 178, 2, 13, 217, 172, 230, 218];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeInstruction {
+    pub base_mint_param: MintParams,
+    pub curve_param: CurveParams,
+    pub vesting_param: VestingParams,
+}
+
+impl InitializeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "creator",
+        "global_config",
+        "platform_config",
+        "authority",
+        "pool_state",
+        "base_mint",
+        "quote_mint",
+        "base_vault",
+        "quote_vault",
+        "metadata_account",
+        "base_token_program",
+        "quote_token_program",
+        "metadata_program",
+        "system_program",
+        "rent_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[175, 175, 109, 31, 13, 152, 155, 237];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeV2Instruction {
+    pub base_mint_param: MintParams,
+    pub curve_param: CurveParams,
+    pub vesting_param: VestingParams,
+    pub amm_fee_on: AmmCreatorFeeOn,
+}
+
+impl InitializeV2Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "creator",
+        "global_config",
+        "platform_config",
+        "authority",
+        "pool_state",
+        "base_mint",
+        "quote_mint",
+        "base_vault",
+        "quote_vault",
+        "metadata_account",
+        "base_token_program",
+        "quote_token_program",
+        "metadata_program",
+        "system_program",
+        "rent_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializeV2Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[67, 153, 175, 39, 218, 16, 38, 32];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeWithToken2022Instruction {
+    pub base_mint_param: MintParams,
+    pub curve_param: CurveParams,
+    pub vesting_param: VestingParams,
+    pub amm_fee_on: AmmCreatorFeeOn,
+    pub transfer_fee_extension_param: Option<TransferFeeExtensionParams>,
+}
+
+impl InitializeWithToken2022Instruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "creator",
+        "global_config",
+        "platform_config",
+        "authority",
+        "pool_state",
+        "base_mint",
+        "quote_mint",
+        "base_vault",
+        "quote_vault",
+        "base_token_program",
+        "quote_token_program",
+        "system_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for InitializeWithToken2022Instruction {
+    const DISCRIMINATOR: &'static [u8] = &[37, 190, 126, 222, 44, 154, 171, 17];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MigrateToAmmInstruction {
+    pub base_lot_size: u64,
+    pub quote_lot_size: u64,
+    pub market_vault_signer_nonce: u8,
+}
+
+impl MigrateToAmmInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "base_mint",
+        "quote_mint",
+        "openbook_program",
+        "market",
+        "request_queue",
+        "event_queue",
+        "bids",
+        "asks",
+        "market_vault_signer",
+        "market_base_vault",
+        "market_quote_vault",
+        "amm_program",
+        "amm_pool",
+        "amm_authority",
+        "amm_open_orders",
+        "amm_lp_mint",
+        "amm_base_vault",
+        "amm_quote_vault",
+        "amm_target_orders",
+        "amm_config",
+        "amm_create_fee_destination",
+        "authority",
+        "pool_state",
+        "global_config",
+        "base_vault",
+        "quote_vault",
+        "pool_lp_token",
+        "spl_token_program",
+        "associated_token_program",
+        "system_program",
+        "rent_program",
+    ];
+}
+
+impl IdlDecode for MigrateToAmmInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[207, 82, 192, 145, 254, 207, 145, 223];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MigrateToCpswapInstruction {}
+
+impl MigrateToCpswapInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "base_mint",
+        "quote_mint",
+        "platform_config",
+        "cpswap_program",
+        "cpswap_pool",
+        "cpswap_authority",
+        "cpswap_lp_mint",
+        "cpswap_base_vault",
+        "cpswap_quote_vault",
+        "cpswap_config",
+        "cpswap_create_pool_fee",
+        "cpswap_observation",
+        "lock_program",
+        "lock_authority",
+        "lock_lp_vault",
+        "authority",
+        "pool_state",
+        "global_config",
+        "base_vault",
+        "quote_vault",
+        "pool_lp_token",
+        "base_token_program",
+        "quote_token_program",
+        "associated_token_program",
+        "system_program",
+        "rent_program",
+        "metadata_program",
+    ];
+}
+
+impl IdlDecode for MigrateToCpswapInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[136, 92, 200, 103, 28, 218, 144, 140];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemovePlatformCurveParamInstruction {
+    pub index: u8,
+}
+
+impl RemovePlatformCurveParamInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "platform_admin",
+        "platform_config",
+    ];
+}
+
+impl IdlDecode for RemovePlatformCurveParamInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[27, 30, 62, 169, 93, 224, 24, 145];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SellExactInInstruction {
+    pub amount_in: u64,
+    pub minimum_amount_out: u64,
+    pub share_fee_rate: u64,
+}
+
+impl SellExactInInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "authority",
+        "global_config",
+        "platform_config",
+        "pool_state",
+        "user_base_token",
+        "user_quote_token",
+        "base_vault",
+        "quote_vault",
+        "base_token_mint",
+        "quote_token_mint",
+        "base_token_program",
+        "quote_token_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SellExactInInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[149, 39, 222, 155, 211, 124, 152, 26];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SellExactOutInstruction {
+    pub amount_out: u64,
+    pub maximum_amount_in: u64,
+    pub share_fee_rate: u64,
+}
+
+impl SellExactOutInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "payer",
+        "authority",
+        "global_config",
+        "platform_config",
+        "pool_state",
+        "user_base_token",
+        "user_quote_token",
+        "base_vault",
+        "quote_vault",
+        "base_token_mint",
+        "quote_token_mint",
+        "base_token_program",
+        "quote_token_program",
+        "event_authority",
+        "program",
+    ];
+}
+
+impl IdlDecode for SellExactOutInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[95, 200, 71, 34, 8, 9, 11, 166];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdateConfigInstruction {
+    pub param: u8,
+    pub value: u64,
+}
+
+impl UpdateConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "owner",
+        "global_config",
+    ];
+}
+
+impl IdlDecode for UpdateConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[29, 158, 252, 191, 10, 83, 219, 99];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdatePlatformConfigInstruction {
+    pub param: PlatformConfigParam,
+}
+
+impl UpdatePlatformConfigInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "platform_admin",
+        "platform_config",
+    ];
+}
+
+impl IdlDecode for UpdatePlatformConfigInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[195, 60, 76, 129, 146, 45, 67, 143];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UpdatePlatformCurveParamInstruction {
+    pub index: u8,
+    pub bonding_curve_param: BondingCurveParam,
+}
+
+impl UpdatePlatformCurveParamInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "platform_admin",
+        "platform_config",
+        "global_config",
+        "system_program",
+    ];
+}
+
+impl IdlDecode for UpdatePlatformCurveParamInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[138, 144, 138, 250, 220, 128, 4, 57];
+}
+
+impl IdlDecode for ClaimVestedEvent {
+    const DISCRIMINATOR: &'static [u8] = &[21, 194, 114, 87, 120, 211, 226, 32];
+}
+
+impl IdlDecode for CreateVestingEvent {
+    const DISCRIMINATOR: &'static [u8] = &[150, 152, 11, 179, 52, 210, 191, 125];
+}
+
+impl IdlDecode for PoolCreateEvent {
+    const DISCRIMINATOR: &'static [u8] = &[151, 215, 226, 9, 118, 161, 115, 174];
+}
+
+impl IdlDecode for TradeEvent {
+    const DISCRIMINATOR: &'static [u8] = &[189, 219, 127, 211, 78, 230, 97, 238];
+}
+
+impl IdlDecode for GlobalConfig {
+    const DISCRIMINATOR: &'static [u8] = &[149, 8, 156, 202, 160, 252, 176, 217];
+}
+
+impl IdlDecode for PlatformConfig {
+    const DISCRIMINATOR: &'static [u8] = &[160, 78, 128, 0, 248, 83, 230, 160];
+}
+
+impl IdlDecode for PoolState {
+    const DISCRIMINATOR: &'static [u8] = &[247, 237, 227, 245, 215, 195, 222, 70];
+}
+
+impl IdlDecode for VestingRecord {
+    const DISCRIMINATOR: &'static [u8] = &[106, 243, 221, 205, 230, 126, 85, 83];
+}
+
+/// Discriminator constants
+pub mod discriminators {
+    // Instruction discriminators
+    pub const BUY_EXACT_IN_IX: &[u8] = &[250, 234, 13, 123, 213, 156, 19, 236];
+    pub const BUY_EXACT_OUT_IX: &[u8] = &[24, 211, 116, 40, 105, 3, 153, 56];
+    pub const CLAIM_CREATOR_FEE_IX: &[u8] = &[26, 97, 138, 203, 132, 171, 141, 252];
+    pub const CLAIM_PLATFORM_FEE_IX: &[u8] = &[156, 39, 208, 135, 76, 237, 61, 72];
+    pub const CLAIM_PLATFORM_FEE_FROM_VAULT_IX: &[u8] = &[117, 241, 198, 168, 248, 218, 80, 29];
+    pub const CLAIM_VESTED_TOKEN_IX: &[u8] = &[49, 33, 104, 30, 189, 157, 79, 35];
+    pub const COLLECT_FEE_IX: &[u8] = &[60, 173, 247, 103, 4, 93, 130, 48];
+    pub const COLLECT_MIGRATE_FEE_IX: &[u8] = &[255, 186, 150, 223, 235, 118, 201, 186];
+    pub const CREATE_CONFIG_IX: &[u8] = &[201, 207, 243, 114, 75, 111, 47, 189];
+    pub const CREATE_PLATFORM_CONFIG_IX: &[u8] = &[176, 90, 196, 175, 253, 113, 220, 20];
+    pub const CREATE_PLATFORM_VESTING_ACCOUNT_IX: &[u8] = &[146, 71, 173, 69, 98, 19, 15, 106];
+    pub const CREATE_VESTING_ACCOUNT_IX: &[u8] = &[129, 178, 2, 13, 217, 172, 230, 218];
+    pub const INITIALIZE_IX: &[u8] = &[175, 175, 109, 31, 13, 152, 155, 237];
+    pub const INITIALIZE_V2_IX: &[u8] = &[67, 153, 175, 39, 218, 16, 38, 32];
+    pub const INITIALIZE_WITH_TOKEN_2022_IX: &[u8] = &[37, 190, 126, 222, 44, 154, 171, 17];
+    pub const MIGRATE_TO_AMM_IX: &[u8] = &[207, 82, 192, 145, 254, 207, 145, 223];
+    pub const MIGRATE_TO_CPSWAP_IX: &[u8] = &[136, 92, 200, 103, 28, 218, 144, 140];
+    pub const REMOVE_PLATFORM_CURVE_PARAM_IX: &[u8] = &[27, 30, 62, 169, 93, 224, 24, 145];
+    pub const SELL_EXACT_IN_IX: &[u8] = &[149, 39, 222, 155, 211, 124, 152, 26];
+    pub const SELL_EXACT_OUT_IX: &[u8] = &[95, 200, 71, 34, 8, 9, 11, 166];
+    pub const UPDATE_CONFIG_IX: &[u8] = &[29, 158, 252, 191, 10, 83, 219, 99];
+    pub const UPDATE_PLATFORM_CONFIG_IX: &[u8] = &[195, 60, 76, 129, 146, 45, 67, 143];
+    pub const UPDATE_PLATFORM_CURVE_PARAM_IX: &[u8] = &[138, 144, 138, 250, 220, 128, 4, 57];
+
+    // Event discriminators
+    pub const CLAIM_VESTED_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 21, 194, 114, 87, 120, 211, 226, 32];
+    pub const CREATE_VESTING_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 150, 152, 11, 179, 52, 210, 191, 125];
+    pub const POOL_CREATE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 151, 215, 226, 9, 118, 161, 115, 174];
+    pub const TRADE_EVENT: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29, 189, 219, 127, 211, 78, 230, 97, 238];
+
+    // Account discriminators
+    pub const GLOBAL_CONFIG_ACCOUNT: &[u8] = &[149, 8, 156, 202, 160, 252, 176, 217];
+    pub const PLATFORM_CONFIG_ACCOUNT: &[u8] = &[160, 78, 128, 0, 248, 83, 230, 160];
+    pub const POOL_STATE_ACCOUNT: &[u8] = &[247, 237, 227, 245, 215, 195, 222, 70];
+    pub const VESTING_RECORD_ACCOUNT: &[u8] = &[106, 243, 221, 205, 230, 126, 85, 83];
+}
@@ -0,0 +1,1232 @@
+//! Generated from `idl/raydium_pool_v4.json` by `idl::codegen`, do not edit.
+//!
+//! Regenerate with `cargo run --example idl_codegen`.
+
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::idl::IdlDecode;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BorrowParam {
+    pub borrow0: u64,
+    pub borrow1: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PositionStopParam {
+    #[serde(rename = "liquidateType")]
+    pub liquidate_type: u8,
+    #[serde(rename = "stopLossType")]
+    pub stop_loss_type: u8,
+    #[serde(rename = "stopLossRatio")]
+    pub stop_loss_ratio: u8,
+    #[serde(rename = "takeProfitType")]
+    pub take_profit_type: u8,
+    #[serde(rename = "takeProfitRatio")]
+    pub take_profit_ratio: u16,
+    #[serde(rename = "rangeStopType")]
+    pub range_stop_type: u8,
+    #[serde(rename = "priceRange0")]
+    pub price_range0: u128,
+    #[serde(rename = "priceRange1")]
+    pub price_range1: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ProtocolVerison {
+    #[serde(rename = "protocolVersion")]
+    pub protocol_version: u8,
+    #[serde(rename = "subVersion")]
+    pub sub_version: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub enum RaydiumError {
+    InvalidVersion,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct StrategyState {
+    #[serde(rename = "protocolVersion")]
+    pub protocol_version: u8,
+    #[serde(rename = "protocolSubVersion")]
+    pub protocol_sub_version: u8,
+    #[serde(rename = "lastUpdateSlot")]
+    pub last_update_slot: u64,
+    #[serde(rename = "totalLp")]
+    pub total_lp: u64,
+    #[serde(rename = "totalShares")]
+    pub total_shares: u64,
+    #[serde(rename = "totalBorrowed0")]
+    pub total_borrowed0: u64,
+    #[serde(rename = "totalBorrowed1")]
+    pub total_borrowed1: u64,
+    #[serde(rename = "pendingTkn0")]
+    pub pending_tkn0: u64,
+    #[serde(rename = "pendingTkn1")]
+    pub pending_tkn1: u64,
+    #[serde(rename = "pendingWithdrawLp")]
+    pub pending_withdraw_lp: u64,
+    #[serde(rename = "pendingRepay0")]
+    pub pending_repay0: u64,
+    #[serde(rename = "pendingRepay1")]
+    pub pending_repay1: u64,
+    #[serde(rename = "cumulatedBorrowRate0")]
+    pub cumulated_borrow_rate0: u128,
+    #[serde(rename = "cumulatedBorrowRate1")]
+    pub cumulated_borrow_rate1: u128,
+    pub admin: Pubkey,
+    pub authority: Pubkey,
+    #[serde(rename = "authorityNonce")]
+    pub authority_nonce: u8,
+    #[serde(rename = "feeAccount")]
+    pub fee_account: Pubkey,
+    #[serde(rename = "tknAccount0")]
+    pub tkn_account0: Pubkey,
+    #[serde(rename = "tknAccount1")]
+    pub tkn_account1: Pubkey,
+    #[serde(rename = "lpAccount")]
+    pub lp_account: Pubkey,
+    #[serde(rename = "rewardAccount")]
+    pub reward_account: Pubkey,
+    #[serde(rename = "rewardAccountB")]
+    pub reward_account_b: Pubkey,
+    #[serde(rename = "lendingProgramId")]
+    pub lending_program_id: Pubkey,
+    #[serde(rename = "lendingPool0")]
+    pub lending_pool0: Pubkey,
+    #[serde(rename = "strategyLendingCreditAccount0")]
+    pub strategy_lending_credit_account0: Pubkey,
+    #[serde(rename = "lendingPool1")]
+    pub lending_pool1: Pubkey,
+    #[serde(rename = "strategyLendingCreditAccount1")]
+    pub strategy_lending_credit_account1: Pubkey,
+    #[serde(rename = "platformRewardsEnable")]
+    pub platform_rewards_enable: u8,
+    #[serde(rename = "rewardsStartSlot")]
+    pub rewards_start_slot: u64,
+    #[serde(rename = "rewardsEndSlot")]
+    pub rewards_end_slot: u64,
+    #[serde(rename = "rewardsPerSlot")]
+    pub rewards_per_slot: u64,
+    #[serde(rename = "platformRewardsTknMint")]
+    pub platform_rewards_tkn_mint: Pubkey,
+    #[serde(rename = "platformRewardsTknAccount")]
+    pub platform_rewards_tkn_account: Pubkey,
+    #[serde(rename = "accumulatedRewardsPerShare")]
+    pub accumulated_rewards_per_share: u128,
+    #[serde(rename = "maxLeverage")]
+    pub max_leverage: u8,
+    #[serde(rename = "liquidateLine")]
+    pub liquidate_line: u8,
+    #[serde(rename = "compoundRewardsRate")]
+    pub compound_rewards_rate: u8,
+    #[serde(rename = "ammProgramId")]
+    pub amm_program_id: Pubkey,
+    #[serde(rename = "ammId")]
+    pub amm_id: Pubkey,
+    #[serde(rename = "ammIdForRewards")]
+    pub amm_id_for_rewards: Pubkey,
+    #[serde(rename = "ammIdForRewardsB")]
+    pub amm_id_for_rewards_b: Pubkey,
+    #[serde(rename = "stakeProgramId")]
+    pub stake_program_id: Pubkey,
+    #[serde(rename = "stakePoolId")]
+    pub stake_pool_id: Pubkey,
+    #[serde(rename = "stakePoolTkn")]
+    pub stake_pool_tkn: Pubkey,
+    pub padding0: [u8; 32],
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapAndWithdrawParam {
+    #[serde(rename = "withdrawType")]
+    pub withdraw_type: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapParam {
+    #[serde(rename = "amountIn")]
+    pub amount_in: u64,
+    #[serde(rename = "minAmountOut")]
+    pub min_amount_out: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TransferForInvestParam {
+    #[serde(rename = "stopLoss")]
+    pub stop_loss: u8,
+    pub amount0: u64,
+    pub amount1: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TransferLpParam {
+    #[serde(rename = "lpAmount")]
+    pub lp_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UnstakeLpParam {
+    #[serde(rename = "sharesAmount")]
+    pub shares_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UserInfo {
+    pub version: u8,
+    #[serde(rename = "lastUpdateSlot")]
+    pub last_update_slot: u64,
+    #[serde(rename = "strategyStateAccount")]
+    pub strategy_state_account: Pubkey,
+    #[serde(rename = "userMainAccount")]
+    pub user_main_account: Pubkey,
+    pub padding0: u8,
+    #[serde(rename = "pendingInvestFlag")]
+    pub pending_invest_flag: u8,
+    #[serde(rename = "stopLoss")]
+    pub stop_loss: u8,
+    pub tkn0: u64,
+    pub tkn1: u64,
+    pub borrowed0: u64,
+    pub borrowed1: u64,
+    pub principle0: u64,
+    pub principle1: u64,
+    #[serde(rename = "investedLp")]
+    pub invested_lp: u64,
+    #[serde(rename = "lpShares")]
+    pub lp_shares: u64,
+    #[serde(rename = "pendingWithdrawLp")]
+    pub pending_withdraw_lp: u64,
+    #[serde(rename = "pendingRepay0")]
+    pub pending_repay0: u64,
+    #[serde(rename = "pendingRepay1")]
+    pub pending_repay1: u64,
+    #[serde(rename = "cumulatedBorrowRate0")]
+    pub cumulated_borrow_rate0: u128,
+    #[serde(rename = "cumulatedBorrowRate1")]
+    pub cumulated_borrow_rate1: u128,
+    #[serde(rename = "platformRewardsDebt")]
+    pub platform_rewards_debt: u128,
+    #[serde(rename = "pendingWithdrawFlag")]
+    pub pending_withdraw_flag: u8,
+    #[serde(rename = "takeProfitLine")]
+    pub take_profit_line: u16,
+    #[serde(rename = "stopPositionType")]
+    pub stop_position_type: u8,
+    #[serde(rename = "priceRange0")]
+    pub price_range0: u128,
+    #[serde(rename = "priceRange1")]
+    pub price_range1: u128,
+    pub padding2: [u8; 23],
+    #[serde(rename = "accountNonce")]
+    pub account_nonce: u32,
+    #[serde(rename = "isEmpty")]
+    pub is_empty: u8,
+    pub bump: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UserRepayParam {
+    #[serde(rename = "repaySide")]
+    pub repay_side: u8,
+    #[serde(rename = "repayAmount")]
+    pub repay_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawParam {
+    #[serde(rename = "withdrawType")]
+    pub withdraw_type: u8,
+    #[serde(rename = "sharesAmount")]
+    pub shares_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeStrategyInstruction {
+    pub version: ProtocolVerison,
+}
+
+impl InitializeStrategyInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "strategyState",
+        "strategyAuthority",
+        "strategyFeeAccount",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "strategyLpAccount",
+        "rewardTknAccount",
+        "rewardTknAccountB",
+        "lendingProgramId",
+        "lendingPoolAccount0",
+        "lendingPoolCreditAccount0",
+        "lendingPoolAccount1",
+        "lendingPoolCreditAccount1",
+        "clock",
+        "rent",
+        "ammProgramId",
+        "ammId",
+        "ammIdRewards",
+        "ammIdRewardsB",
+        "stakeProgramId",
+        "stakePoolId",
+        "stakePoolTkn",
+    ];
+}
+
+impl IdlDecode for InitializeStrategyInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[0];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitializeUserWithNonceInstruction {
+    pub nonce: u32,
+    #[serde(rename = "bumpSeed")]
+    pub bump_seed: u8,
+}
+
+impl InitializeUserWithNonceInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfoAccount",
+        "strategyState",
+        "systemProgram",
+        "clock",
+    ];
+}
+
+impl IdlDecode for InitializeUserWithNonceInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[1];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct CloseEmptyAccountInstruction {}
+
+impl CloseEmptyAccountInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfoAccount",
+    ];
+}
+
+impl IdlDecode for CloseEmptyAccountInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[2];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetPositionStopLossInstruction {
+    #[serde(rename = "stopLossType")]
+    pub stop_loss_type: u8,
+    #[serde(rename = "stopLossRatio")]
+    pub stop_loss_ratio: u8,
+}
+
+impl SetPositionStopLossInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfoAccount",
+        "strategyState",
+    ];
+}
+
+impl IdlDecode for SetPositionStopLossInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[3];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SetPositionRangeStopInstruction {
+    #[serde(rename = "rangeStopType")]
+    pub range_stop_type: u8,
+    #[serde(rename = "priceRange0")]
+    pub price_range0: u128,
+    #[serde(rename = "priceRange1")]
+    pub price_range1: u128,
+}
+
+impl SetPositionRangeStopInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfoAccount",
+        "strategyState",
+    ];
+}
+
+impl IdlDecode for SetPositionRangeStopInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[4];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TransferInstruction {
+    pub param: TransferForInvestParam,
+}
+
+impl TransferInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfoAccount",
+        "userTknAccount0",
+        "userTknAccount1",
+        "strategyState",
+        "strategyAuthority",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "tokenProgramId",
+        "clock",
+    ];
+}
+
+impl IdlDecode for TransferInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[5];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TransferLpInstruction {
+    pub param: TransferLpParam,
+}
+
+impl TransferLpInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfoAccount",
+        "userLpAccount",
+        "strategyState",
+        "strategyAuthority",
+        "strategyLpAccount",
+        "userFrcRewardsTknAccount",
+        "strategyFrcRewardsTknAccount",
+        "tokenProgramId",
+        "clock",
+    ];
+}
+
+impl IdlDecode for TransferLpInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[6];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BorrowInstruction {
+    pub param: BorrowParam,
+}
+
+impl BorrowInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfoAccount",
+        "strategyState",
+        "strategyAuthority",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "lendingMarketAccount",
+        "lendingMarketAuthorityInfo",
+        "lendingPoolProgramId",
+        "lendingPoolInfoAccount0",
+        "lendingPoolTknAccount0",
+        "lendingPoolCreditAccount0",
+        "strategyCreditAccount0",
+        "lendingPoolInfoAccount1",
+        "lendingPoolTknAccount1",
+        "lendingPoolCreditAccount1",
+        "strategyCreditAccount1",
+        "tokenProgramId",
+        "clock",
+        "ammId",
+        "ammOpenOrders",
+        "ammTknAccount0",
+        "ammTknAccount1",
+        "lpMintAccount",
+    ];
+}
+
+impl IdlDecode for BorrowInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[7];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapLimitInstruction {
+    #[serde(rename = "swapLimit")]
+    pub swap_limit: u64,
+}
+
+impl SwapLimitInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfo",
+        "strategyState",
+        "strategyAuthority",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "strategyLpAccount",
+        "tokenProgramId",
+        "raydiumAmmProgramId",
+        "ammId",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "ammTknAccount0",
+        "ammTknAccount1",
+        "lpMintAccount",
+        "serumProgramId",
+        "serumMarketId",
+        "serumBids",
+        "serumAsks",
+        "serumEventQueue",
+        "serumTknVault0",
+        "serumTknVault1",
+        "serumVaultSinger",
+    ];
+}
+
+impl IdlDecode for SwapLimitInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[8];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapBaseInInstruction {
+    pub param: SwapParam,
+}
+
+impl SwapBaseInInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "TokenProgram",
+        "AmmId",
+        "AmmAuthority",
+        "AmmOpenOrders",
+        "AmmTargetOrders",
+        "PoolCoinTokenAccount",
+        "PoolPcTokenAccount",
+        "OpenBookProgramId",
+        "OpenBookMarket",
+        "OpenBookBids",
+        "OpenBookAsks",
+        "OpenBookQueue",
+        "OpenBookVaultAccount",
+        "OpenBookPcVaultAccount",
+        "OpenBookVaultSigner",
+        "UserSourceTokenAccount",
+        "UserDestTokenAccount",
+        "UserOwner",
+    ];
+}
+
+impl IdlDecode for SwapBaseInInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[9];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddLiquidityInstruction {}
+
+impl AddLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfo",
+        "strategyState",
+        "strategyAuthority",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "strategyLpAccount",
+        "tokenProgramId",
+        "raydiumAmmProgramId",
+        "ammId",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "ammTknAccount0",
+        "ammTknAccount1",
+        "lpMintAccount",
+        "serumMarketId",
+        "userRewardsTknAccount",
+        "strategyRewardsTknAccount",
+        "clock",
+        "serumEventQueue",
+    ];
+}
+
+impl IdlDecode for AddLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[10];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct StakeLpInstruction {}
+
+impl StakeLpInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "strategyState",
+        "strategyAuthority",
+        "strategyLpAccount",
+        "strategyRewardTknAccount",
+        "strategyRewardTknAccountB",
+        "strategyFarmInfo",
+        "raydiumStakingProgramId",
+        "poolId",
+        "poolAuthority",
+        "poolLpAccount",
+        "poolRewardTknAccount",
+        "poolRewardTknAccountB",
+        "tokenProgramId",
+        "clock",
+    ];
+}
+
+impl IdlDecode for StakeLpInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[11];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UnstakeLpInstruction {
+    pub param: UnstakeLpParam,
+}
+
+impl UnstakeLpInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfo",
+        "strategyState",
+        "strategyAuthority",
+        "strategyLpAccount",
+        "strategyRewardTknAccount",
+        "strategyRewardTknAccountB",
+        "strategyFarmInfo",
+        "raydiumStakingProgramId",
+        "poolId",
+        "poolAuthority",
+        "poolLpAccount",
+        "poolRewardTknAccount",
+        "poolRewardTknAccountB",
+        "tokenProgramId",
+        "clock",
+        "lendingPoolInfoAccount0",
+        "lendingPoolInfoAccount1",
+        "userRewardsTknAccount",
+        "strategyRewardsTknAccount",
+    ];
+}
+
+impl IdlDecode for UnstakeLpInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[12];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UnstakeLpWithTypeInstruction {
+    pub param: WithdrawParam,
+}
+
+impl UnstakeLpWithTypeInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfo",
+        "strategyState",
+        "strategyAuthority",
+        "strategyLpAccount",
+        "strategyRewardTknAccount",
+        "strategyRewardTknAccountB",
+        "strategyFarmInfo",
+        "raydiumStakingProgramId",
+        "poolId",
+        "poolAuthority",
+        "poolLpAccount",
+        "poolRewardTknAccount",
+        "poolRewardTknAccountB",
+        "tokenProgramId",
+        "clock",
+        "lendingPoolInfoAccount0",
+        "lendingPoolInfoAccount1",
+        "userRewardsTknAccount",
+        "strategyRewardsTknAccount",
+    ];
+}
+
+impl IdlDecode for UnstakeLpWithTypeInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[13];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemoveLiquidityInstruction {}
+
+impl RemoveLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfo",
+        "strategyState",
+        "strategyAuthority",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "strategyLpAccount",
+        "tokenProgramId",
+        "raydiumAmmProgramId",
+        "ammId",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "ammTknAccount1",
+        "ammTknAccount0",
+        "lpMintAccount",
+        "ammWithdrawQueue",
+        "ammPoolTempLpAccount",
+        "serumProgramId",
+        "serumMarketId",
+        "serumTknVault1",
+        "serumTknVault0",
+        "serumVaultSinger",
+    ];
+}
+
+impl IdlDecode for RemoveLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[14];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RemoveLiquidityLimitInstruction {
+    pub limit: u64,
+}
+
+impl RemoveLiquidityLimitInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfo",
+        "strategyState",
+        "strategyAuthority",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "strategyLpAccount",
+        "tokenProgramId",
+        "raydiumAmmProgramId",
+        "ammId",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "ammTknAccount1",
+        "ammTknAccount0",
+        "lpMintAccount",
+        "ammWithdrawQueue",
+        "ammPoolTempLpAccount",
+        "serumProgramId",
+        "serumMarketId",
+        "serumTknVault1",
+        "serumTknVault0",
+        "serumVaultSinger",
+    ];
+}
+
+impl IdlDecode for RemoveLiquidityLimitInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[15];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapForWithdrawLimitInstruction {
+    #[serde(rename = "swapLimit")]
+    pub swap_limit: u64,
+    #[serde(rename = "withdrawType")]
+    pub withdraw_type: u8,
+}
+
+impl SwapForWithdrawLimitInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfo",
+        "userTknAccount0",
+        "userTknAccount1",
+        "strategyState",
+        "strategyAuthority",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "strategyLpAccount",
+        "tokenProgramId",
+        "raydiumAmmProgramId",
+        "ammId",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "ammTknAccount1",
+        "ammTknAccount0",
+        "serumProgramId",
+        "serumMarketId",
+        "serumBids",
+        "serumAsks",
+        "serumEventQueue",
+        "serumTknVault1",
+        "serumTknVault0",
+        "serumVaultSinger",
+    ];
+}
+
+impl IdlDecode for SwapForWithdrawLimitInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[16];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapAndWithdrawInstruction {
+    pub param: SwapAndWithdrawParam,
+}
+
+impl SwapAndWithdrawInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfo",
+        "userTknAccount0",
+        "userTknAccount1",
+        "strategyState",
+        "strategyAuthority",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "strategyLpAccount",
+        "tokenProgramId",
+        "raydiumAmmProgramId",
+        "ammId",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "ammTknAccount1",
+        "ammTknAccount0",
+        "serumProgramId",
+        "serumMarketId",
+        "serumBids",
+        "serumAsks",
+        "serumEventQueue",
+        "serumTknVault1",
+        "serumTknVault0",
+        "serumVaultSinger",
+    ];
+}
+
+impl IdlDecode for SwapAndWithdrawInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[17];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RepayBorrowedPcInstruction {}
+
+impl RepayBorrowedPcInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "strategyState",
+        "strategyAuthority",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "lendingMarketAccount",
+        "lendingMarketAuthorityInfo",
+        "lendingPoolProgramId",
+        "lendingPoolInfoAccount0",
+        "lendingPoolTknAccount0",
+        "lendingPoolCreditMint0",
+        "lendingPoolCreditAccount0",
+        "strategyCreditAccount0",
+        "lendingPoolInfoAccount1",
+        "lendingPoolTknAccount1",
+        "lendingPoolCreditMint1",
+        "lendingPoolCreditAccount1",
+        "strategyCreditAccount1",
+        "tokenProgramId",
+        "clock",
+    ];
+}
+
+impl IdlDecode for RepayBorrowedPcInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[18];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RepayBorrowedCoinInstruction {}
+
+impl RepayBorrowedCoinInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "strategyState",
+        "strategyAuthority",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "lendingMarketAccount",
+        "lendingMarketAuthorityInfo",
+        "lendingPoolProgramId",
+        "lendingPoolInfoAccount0",
+        "lendingPoolTknAccount0",
+        "lendingPoolCreditMint0",
+        "lendingPoolCreditAccount0",
+        "strategyCreditAccount0",
+        "lendingPoolInfoAccount1",
+        "lendingPoolTknAccount1",
+        "lendingPoolCreditMint1",
+        "lendingPoolCreditAccount1",
+        "strategyCreditAccount1",
+        "tokenProgramId",
+        "clock",
+    ];
+}
+
+impl IdlDecode for RepayBorrowedCoinInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[19];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UserRepayInstruction {
+    pub param: UserRepayParam,
+}
+
+impl UserRepayInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userInfoAccount",
+        "userTknAccount0",
+        "userTknAccount1",
+        "strategyState",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "lendingPoolInfoAccount0",
+        "lendingPoolInfoAccount1",
+        "tokenProgramId",
+    ];
+}
+
+impl IdlDecode for UserRepayInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[20];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LiquidateUnstakeLpInstruction {
+    #[serde(rename = "liquidateType")]
+    pub liquidate_type: u8,
+}
+
+impl LiquidateUnstakeLpInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "liquidator",
+        "liquidatorLpTknAccount",
+        "userInfo",
+        "strategyState",
+        "strategyAuthority",
+        "strategyLpAccount",
+        "strategyRewardTknAccount",
+        "strategyRewardTknAccountB",
+        "strategyFarmInfo",
+        "raydiumStakingProgramId",
+        "poolId",
+        "poolAuthority",
+        "poolLpAccount",
+        "poolRewardTknAccount",
+        "poolRewardTknAccountB",
+        "tokenProgramId",
+        "clock",
+        "lendingPoolInfoAccount0",
+        "lendingPoolInfoAccount1",
+        "ammId",
+        "ammOpenOrders",
+        "ammTknAccount0",
+        "ammTknAccount1",
+        "lpMintAccount",
+        "userRewardsTknAccount",
+        "strategyRewardsTknAccount",
+    ];
+}
+
+impl IdlDecode for LiquidateUnstakeLpInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[21];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LiquidateRepayAndGetLpInstruction {}
+
+impl LiquidateRepayAndGetLpInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "liquidator",
+        "userInfo",
+        "userLpAccount",
+        "liquidatorLpAccount",
+        "liquidatorTknAccount0",
+        "liquidatorTknAccount1",
+        "strategyState",
+        "strategyAuthority",
+        "strategyLpAccount",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "ammId",
+        "ammOpenOrders",
+        "ammTknAccount0",
+        "ammTknAccount1",
+        "lpMintAccount",
+        "tokenProgramId",
+    ];
+}
+
+impl IdlDecode for LiquidateRepayAndGetLpInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[22];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapRewardsToTargetInstruction {
+    pub direction: u8,
+}
+
+impl SwapRewardsToTargetInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "strategyState",
+        "strategyAuthority",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "tokenProgramId",
+        "raydiumAmmProgramId",
+        "ammId",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "ammTknAccount0",
+        "ammTknAccount1",
+        "serumProgramId",
+        "serumMarketId",
+        "serumBids",
+        "serumAsks",
+        "serumEventQueue",
+        "serumTknVault0",
+        "serumTknVault1",
+        "serumVaultSinger",
+    ];
+}
+
+impl IdlDecode for SwapRewardsToTargetInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[23];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapRewardsInstruction {}
+
+impl SwapRewardsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "strategyState",
+        "strategyAuthority",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "strategyLpAccount",
+        "tokenProgramId",
+        "raydiumAmmProgramId",
+        "ammId",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "ammTknAccount0",
+        "ammTknAccount1",
+        "lpMintAccount",
+        "serumProgramId",
+        "serumMarketId",
+        "serumBids",
+        "serumAsks",
+        "serumEventQueue",
+        "serumTknVault0",
+        "serumTknVault1",
+        "serumVaultSinger",
+    ];
+}
+
+impl IdlDecode for SwapRewardsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[24];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AddRewardsToLiquidityInstruction {}
+
+impl AddRewardsToLiquidityInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "userMainAccount",
+        "userLpAccount",
+        "strategyState",
+        "strategyAuthority",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "strategyLpAccount",
+        "tokenProgramId",
+        "raydiumAmmProgramId",
+        "ammId",
+        "ammAuthority",
+        "ammOpenOrders",
+        "ammTargetOrders",
+        "ammTknAccount0",
+        "ammTknAccount1",
+        "lpMintAccount",
+        "serumMarketId",
+        "serumEventQueue",
+    ];
+}
+
+impl IdlDecode for AddRewardsToLiquidityInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[25];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminUpdateRewardsAmmIdInstruction {}
+
+impl AdminUpdateRewardsAmmIdInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "strategyState",
+        "rewardsAmmId",
+        "rewardsAmmIdB",
+    ];
+}
+
+impl IdlDecode for AdminUpdateRewardsAmmIdInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[26];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminUpdateStakeInfoInstruction {}
+
+impl AdminUpdateStakeInfoInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "strategyState",
+        "stakePoolId",
+        "stakePoolTkn",
+    ];
+}
+
+impl IdlDecode for AdminUpdateStakeInfoInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[27];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminUpdateMaxLeverageInstruction {
+    #[serde(rename = "newLeverage")]
+    pub new_leverage: u8,
+}
+
+impl AdminUpdateMaxLeverageInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "strategyState",
+    ];
+}
+
+impl IdlDecode for AdminUpdateMaxLeverageInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[28];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminUpdateLiquidateLineInstruction {
+    #[serde(rename = "newLiquidateLine")]
+    pub new_liquidate_line: u8,
+}
+
+impl AdminUpdateLiquidateLineInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "strategyState",
+    ];
+}
+
+impl IdlDecode for AdminUpdateLiquidateLineInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[29];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminUpdateCompoundRewardsRateInstruction {
+    #[serde(rename = "compoundRewardsRate")]
+    pub compound_rewards_rate: u8,
+}
+
+impl AdminUpdateCompoundRewardsRateInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "strategyState",
+    ];
+}
+
+impl IdlDecode for AdminUpdateCompoundRewardsRateInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[30];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminRepayBadDebtsInstruction {
+    #[serde(rename = "tknRepay0")]
+    pub tkn_repay0: u64,
+    #[serde(rename = "tknRepay1")]
+    pub tkn_repay1: u64,
+}
+
+impl AdminRepayBadDebtsInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "strategyState",
+        "strategyAuthority",
+        "adminTknAccount0",
+        "adminTknAccount1",
+        "strategyTknAccount0",
+        "strategyTknAccount1",
+        "tokenProgramId",
+    ];
+}
+
+impl IdlDecode for AdminRepayBadDebtsInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[31];
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize)]
+pub struct AdminBurnBorrowCreditInstruction {}
+
+impl AdminBurnBorrowCreditInstruction {
+    pub const ACCOUNTS: &'static [&'static str] = &[
+        "admin",
+        "strategyState",
+        "strategyAuthority",
+        "creditMint0",
+        "strategyCreditAccount0",
+        "creditMint1",
+        "strategyCreditAccount1",
+        "tokenProgramId",
+    ];
+}
+
+impl IdlDecode for AdminBurnBorrowCreditInstruction {
+    const DISCRIMINATOR: &'static [u8] = &[32];
+}
+
+impl IdlDecode for StrategyState {
+    const DISCRIMINATOR: &'static [u8] = &[83, 18, 224, 109, 174, 100, 39, 139];
+}
+
+impl IdlDecode for UserInfo {
+    const DISCRIMINATOR: &'static [u8] = &[83, 134, 200, 56, 144, 56, 10, 62];
+}
+
+/// Discriminator constants
+pub mod discriminators {
+    // Instruction discriminators
+    pub const INITIALIZE_STRATEGY_IX: &[u8] = &[0];
+    pub const INITIALIZE_USER_WITH_NONCE_IX: &[u8] = &[1];
+    pub const CLOSE_EMPTY_ACCOUNT_IX: &[u8] = &[2];
+    pub const SET_POSITION_STOP_LOSS_IX: &[u8] = &[3];
+    pub const SET_POSITION_RANGE_STOP_IX: &[u8] = &[4];
+    pub const TRANSFER_IX: &[u8] = &[5];
+    pub const TRANSFER_LP_IX: &[u8] = &[6];
+    pub const BORROW_IX: &[u8] = &[7];
+    pub const SWAP_LIMIT_IX: &[u8] = &[8];
+    pub const SWAP_BASE_IN_IX: &[u8] = &[9];
+    pub const ADD_LIQUIDITY_IX: &[u8] = &[10];
+    pub const STAKE_LP_IX: &[u8] = &[11];
+    pub const UNSTAKE_LP_IX: &[u8] = &[12];
+    pub const UNSTAKE_LP_WITH_TYPE_IX: &[u8] = &[13];
+    pub const REMOVE_LIQUIDITY_IX: &[u8] = &[14];
+    pub const REMOVE_LIQUIDITY_LIMIT_IX: &[u8] = &[15];
+    pub const SWAP_FOR_WITHDRAW_LIMIT_IX: &[u8] = &[16];
+    pub const SWAP_AND_WITHDRAW_IX: &[u8] = &[17];
+    pub const REPAY_BORROWED_PC_IX: &[u8] = &[18];
+    pub const REPAY_BORROWED_COIN_IX: &[u8] = &[19];
+    pub const USER_REPAY_IX: &[u8] = &[20];
+    pub const LIQUIDATE_UNSTAKE_LP_IX: &[u8] = &[21];
+    pub const LIQUIDATE_REPAY_AND_GET_LP_IX: &[u8] = &[22];
+    pub const SWAP_REWARDS_TO_TARGET_IX: &[u8] = &[23];
+    pub const SWAP_REWARDS_IX: &[u8] = &[24];
+    pub const ADD_REWARDS_TO_LIQUIDITY_IX: &[u8] = &[25];
+    pub const ADMIN_UPDATE_REWARDS_AMM_ID_IX: &[u8] = &[26];
+    pub const ADMIN_UPDATE_STAKE_INFO_IX: &[u8] = &[27];
+    pub const ADMIN_UPDATE_MAX_LEVERAGE_IX: &[u8] = &[28];
+    pub const ADMIN_UPDATE_LIQUIDATE_LINE_IX: &[u8] = &[29];
+    pub const ADMIN_UPDATE_COMPOUND_REWARDS_RATE_IX: &[u8] = &[30];
+    pub const ADMIN_REPAY_BAD_DEBTS_IX: &[u8] = &[31];
+    pub const ADMIN_BURN_BORROW_CREDIT_IX: &[u8] = &[32];
+
+    // Account discriminators
+    pub const STRATEGY_STATE_ACCOUNT: &[u8] = &[83, 18, 224, 109, 174, 100, 39, 139];
+    pub const USER_INFO_ACCOUNT: &[u8] = &[83, 134, 200, 56, 144, 56, 10, 62];
+}
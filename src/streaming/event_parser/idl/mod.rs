@@ -0,0 +1,109 @@
+//! 运行时 Anchor IDL 解码
+//!
+//! 加载 IDL JSON 后即可按 discriminator 解码任意 Anchor 程序的指令、`emit_cpi!` 事件
+//! 与账户数据。通过 `EventDispatcher::register_idl` 注册后，将返回的 `Protocol::Custom`
+//! 加入订阅的协议列表，解析出的事件以 `DexEvent::IdlEvent` 返回。
+
+pub mod codegen;
+pub mod events;
+pub mod generated;
+pub mod parser;
+pub mod program;
+pub mod types;
+pub mod value;
+
+pub use events::*;
+pub use program::{sighash, DecodedInstruction, IdlDecode, IdlProgram, EVENT_IX_TAG};
+pub use value::IdlValue;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        common::EventType,
+        core::{
+            dispatcher::EventDispatcher,
+            transaction_input::fixtures::{
+                account_index, inner_instruction, inner_instructions_meta, parse, transaction,
+                unique_pubkeys,
+            },
+        },
+        DexEvent,
+    };
+    use solana_sdk::pubkey::Pubkey;
+
+    fn load(name: &str) -> IdlProgram {
+        IdlProgram::from_file(format!("{}/idl/{}.json", env!("CARGO_MANIFEST_DIR"), name)).unwrap()
+    }
+
+    #[test]
+    fn test_idl_discriminators() {
+        for entry in std::fs::read_dir(format!("{}/idl", env!("CARGO_MANIFEST_DIR"))).unwrap() {
+            let path = entry.unwrap().path();
+            IdlProgram::from_file(&path).unwrap_or_else(|e| panic!("{}: {e:#}", path.display()));
+        }
+        // 新版 IDL 自带 discriminator，与计算结果一致
+        let pumpfun = load("pumpfun");
+        assert_eq!(pumpfun.instruction_discriminator("buy"), Some(&sighash("global", "buy")[..]));
+        assert_eq!(
+            pumpfun.event_discriminator("TradeEvent"),
+            Some(&sighash("event", "TradeEvent")[..])
+        );
+        // 旧版 IDL 由名称计算
+        let clmm = load("raydium_clmm");
+        assert_eq!(
+            clmm.instruction_discriminator("swapV2"),
+            Some(&sighash("global", "swap_v2")[..])
+        );
+        assert_eq!(
+            clmm.account_discriminator("PoolState"),
+            Some(&sighash("account", "PoolState")[..])
+        );
+        assert_eq!(
+            load("raydium_amm_v4").instruction_discriminator("initialize"),
+            Some(&[0u8][..])
+        );
+    }
+
+    #[test]
+    fn test_registered_idl_emits_named_instruction_event() {
+        // 内置协议的程序 ID 优先匹配，这里部署到新的地址
+        let program =
+            load("pumpfun").with_name("pumpfun-idl").with_program_id(Pubkey::new_unique());
+        let program_id = program.program_id();
+        let protocol = EventDispatcher::register_idl(program);
+
+        let accounts = unique_pubkeys(16);
+        let mut data = sighash("global", "buy").to_vec();
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&2_000u64.to_le_bytes());
+        data.push(1); // OptionBool(true)
+        let tx = transaction(program_id, &data, &accounts);
+
+        // emit_cpi!(AdminSetCreatorEvent { timestamp, .. })
+        let mut log = EVENT_IX_TAG.to_vec();
+        log.extend_from_slice(&sighash("event", "AdminSetCreatorEvent"));
+        log.extend_from_slice(&42i64.to_le_bytes());
+        log.extend(accounts[..5].iter().flat_map(|k| k.to_bytes()));
+        let program_index = account_index(&tx, &program_id);
+        let meta = inner_instructions_meta(vec![inner_instruction(program_index, log, vec![])]);
+
+        let events = parse(&[protocol], &tx, &meta);
+        EventDispatcher::unregister_protocol_parser("pumpfun-idl");
+
+        assert_eq!(events.len(), 1);
+        let DexEvent::IdlEvent(event) = &events[0] else { panic!("expected idl event") };
+        assert_eq!(event.metadata.event_type, EventType::Idl);
+        assert_eq!((event.program.as_str(), event.name.as_str()), ("pumpfun-idl", "buy"));
+        assert_eq!(event.args.get("max_sol_cost").and_then(IdlValue::as_u64), Some(2_000));
+        assert_eq!(event.account("user"), Some(accounts[6]));
+        assert_eq!(
+            event.args.to_json()["track_volume"],
+            serde_json::json!(true),
+            "tuple struct decodes to its single field"
+        );
+        let cpi_event = event.cpi_event.as_ref().unwrap();
+        assert_eq!(cpi_event.name, "AdminSetCreatorEvent");
+        assert_eq!(cpi_event.data.get("mint").and_then(IdlValue::as_pubkey), Some(accounts[1]));
+    }
+}
@@ -0,0 +1,96 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::{
+    common::{EventMetadata, EventType, ProtocolType},
+    idl::{
+        events::{IdlAccount, IdlCpiEvent, IdlEvent, IdlEventKind},
+        program::IdlProgram,
+        value::IdlValue,
+    },
+    DexEvent,
+};
+use crate::streaming::grpc::AccountPretty;
+
+fn idl_event(
+    program: &IdlProgram,
+    kind: IdlEventKind,
+    name: String,
+    args: IdlValue,
+    accounts: Vec<IdlAccount>,
+    mut metadata: EventMetadata,
+) -> DexEvent {
+    metadata.protocol = ProtocolType::Custom;
+    metadata.event_type = EventType::Idl;
+    DexEvent::IdlEvent(IdlEvent {
+        metadata,
+        program: program.name().to_string(),
+        kind,
+        name,
+        args,
+        accounts,
+        cpi_event: None,
+    })
+}
+
+/// 解析指令数据
+pub fn parse_idl_instruction_data(
+    program: &IdlProgram,
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    // discriminator 长度由调用方按 8 字节截取，IDL 中的 discriminator 长度可能不同
+    let instruction = program.decode_instruction(&[discriminator, data].concat(), accounts)?;
+    let accounts = instruction
+        .accounts
+        .into_iter()
+        .map(|(name, pubkey)| IdlAccount { name, pubkey })
+        .collect();
+    Some(idl_event(
+        program,
+        IdlEventKind::Instruction,
+        instruction.name,
+        instruction.args,
+        accounts,
+        metadata,
+    ))
+}
+
+/// 解析 `emit_cpi!` 事件（16 字节 discriminator + 事件数据）
+pub fn parse_idl_inner_instruction_data(
+    program: &IdlProgram,
+    discriminator: &[u8],
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    let (name, args) = program.decode_cpi_event(&[discriminator, data].concat())?;
+    Some(idl_event(program, IdlEventKind::Event, name, args, Vec::new(), metadata))
+}
+
+/// 解析 `emit!` 事件（`Program data:` 日志，8 字节 discriminator + 事件数据）
+pub fn parse_idl_log_data(
+    program: &IdlProgram,
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    let (name, args) = program.decode_event(data)?;
+    Some(idl_event(program, IdlEventKind::Event, name, args, Vec::new(), metadata))
+}
+
+/// 解析账户数据
+pub fn parse_idl_account_data(
+    program: &IdlProgram,
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    let (name, args) = program.decode_account(&account.data)?;
+    let accounts = vec![IdlAccount { name: name.clone(), pubkey: account.pubkey }];
+    Some(idl_event(program, IdlEventKind::Account, name, args, accounts, metadata))
+}
+
+/// 将 CPI 事件合并到指令事件
+pub fn merge_idl_cpi_event(instruction_event: &mut IdlEvent, cpi_log_event: IdlEvent) {
+    instruction_event.cpi_event =
+        Some(IdlCpiEvent { name: cpi_log_event.name, data: cpi_log_event.args });
+}
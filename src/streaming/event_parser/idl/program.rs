@@ -0,0 +1,431 @@
+//! 基于 IDL 的 borsh 解码器
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context};
+use borsh::BorshDeserialize;
+use solana_sdk::{hash::hashv, pubkey::Pubkey};
+
+use crate::common::AnyResult;
+use crate::streaming::event_parser::idl::types::{
+    Idl, IdlDefinedFields, IdlField, IdlInstructionAccount, IdlType, IdlTypeDefTy,
+};
+use crate::streaming::event_parser::idl::value::IdlValue;
+
+/// `emit_cpi!` 事件指令的前 8 字节
+pub const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+/// 由 IDL 生成的指令参数、事件与账户类型（见 `idl::codegen`）
+pub trait IdlDecode: BorshDeserialize {
+    /// 指令 / 事件 / 账户 discriminator，事件不含 [`EVENT_IX_TAG`]
+    const DISCRIMINATOR: &'static [u8];
+
+    /// 解码含 discriminator 的数据
+    fn decode(data: &[u8]) -> Option<Self> {
+        Self::decode_body(data.strip_prefix(Self::DISCRIMINATOR)?)
+    }
+
+    /// 解码去掉 discriminator 后的数据，忽略尾部多余字节（程序升级后新增的字段）
+    fn decode_body(mut data: &[u8]) -> Option<Self> {
+        Self::deserialize(&mut data).ok()
+    }
+
+    /// 解码 `emit_cpi!` 事件指令数据
+    fn decode_cpi(data: &[u8]) -> Option<Self> {
+        Self::decode(data.strip_prefix(&EVENT_IX_TAG)?)
+    }
+}
+
+/// 自定义类型的最大嵌套深度，防止错误的 IDL 造成无限递归
+const MAX_DEPTH: usize = 32;
+
+/// 计算 Anchor discriminator：`sha256("<namespace>:<name>")[..8]`
+///
+/// 指令使用 `global:<snake_case 名称>`，账户使用 `account:<名称>`，事件使用 `event:<名称>`
+pub fn sighash(namespace: &str, name: &str) -> [u8; 8] {
+    let hash = hashv(&[namespace.as_bytes(), b":", name.as_bytes()]);
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash.to_bytes()[..8]);
+    discriminator
+}
+
+/// 将旧版 IDL 中的 camelCase 指令名转换为 snake_case
+pub(crate) fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    let mut prev_lower = false;
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            if prev_lower {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+            prev_lower = false;
+        } else {
+            snake.push(c);
+            prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        }
+    }
+    snake
+}
+
+#[derive(Clone, Debug)]
+struct InstructionDef {
+    discriminator: Vec<u8>,
+    name: String,
+    accounts: Vec<String>,
+    args: Vec<IdlField>,
+}
+
+#[derive(Clone, Debug)]
+struct TypedDef {
+    discriminator: Vec<u8>,
+    name: String,
+    ty: IdlTypeDefTy,
+}
+
+/// 解码后的指令
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DecodedInstruction {
+    pub name: String,
+    pub args: IdlValue,
+    /// (IDL 中的账户名称, 账户地址)，IDL 未声明的额外账户命名为 `remaining_<n>`
+    pub accounts: Vec<(String, Pubkey)>,
+}
+
+/// 由 Anchor IDL 构建的程序解码器
+#[derive(Clone, Debug)]
+pub struct IdlProgram {
+    name: String,
+    program_id: Pubkey,
+    instructions: Vec<InstructionDef>,
+    accounts: Vec<TypedDef>,
+    events: Vec<TypedDef>,
+    types: HashMap<String, IdlTypeDefTy>,
+}
+
+impl IdlProgram {
+    /// 从 IDL JSON 构建，程序 ID 取自 IDL 的 `address`（或 `metadata.address`）
+    pub fn from_json(json: &str) -> AnyResult<Self> {
+        let idl: Idl = serde_json::from_str(json).context("invalid IDL json")?;
+        Self::from_idl(idl)
+    }
+
+    /// 从 IDL 文件构建
+    pub fn from_file(path: impl AsRef<Path>) -> AnyResult<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read IDL {}", path.display()))?;
+        Self::from_json(&json)
+    }
+
+    pub fn from_idl(idl: Idl) -> AnyResult<Self> {
+        let metadata = idl.metadata.unwrap_or_default();
+        let name = metadata.name.or(idl.name).ok_or_else(|| anyhow!("IDL has no program name"))?;
+        let program_id = match idl.address.or(metadata.address) {
+            Some(address) => Pubkey::from_str(&address)
+                .map_err(|e| anyhow!("invalid program address {address}: {e}"))?,
+            // 未声明地址时需通过 with_program_id 指定
+            None => Pubkey::default(),
+        };
+        let index_selector = metadata.instruction_selector_type.as_deref() == Some("index");
+
+        let mut types: HashMap<String, IdlTypeDefTy> =
+            idl.types.into_iter().map(|t| (t.name, t.ty)).collect();
+
+        let instructions = idl
+            .instructions
+            .into_iter()
+            .enumerate()
+            .map(|(index, ix)| {
+                let discriminator = match ix.discriminator {
+                    Some(discriminator) => discriminator,
+                    None if index_selector => vec![index as u8],
+                    None => sighash("global", &to_snake_case(&ix.name)).to_vec(),
+                };
+                let mut accounts = Vec::new();
+                flatten_accounts(&ix.accounts, "", &mut accounts);
+                InstructionDef { discriminator, name: ix.name, accounts, args: ix.args }
+            })
+            .collect();
+
+        // 旧版 IDL 的账户/事件结构内联定义，统一放入 types 以便按名称查找
+        let accounts = idl
+            .accounts
+            .into_iter()
+            .filter_map(|account| {
+                if let Some(ty) = account.ty {
+                    types.insert(account.name.clone(), ty);
+                }
+                let ty = types.get(&account.name)?.clone();
+                let discriminator = account
+                    .discriminator
+                    .unwrap_or_else(|| sighash("account", &account.name).to_vec());
+                Some(TypedDef { discriminator, name: account.name, ty })
+            })
+            .collect();
+
+        let events = idl
+            .events
+            .into_iter()
+            .filter_map(|event| {
+                let ty = match event.fields {
+                    Some(fields) => {
+                        IdlTypeDefTy::Struct { fields: IdlDefinedFields::Named(fields) }
+                    }
+                    None => types.get(&event.name)?.clone(),
+                };
+                let discriminator =
+                    event.discriminator.unwrap_or_else(|| sighash("event", &event.name).to_vec());
+                Some(TypedDef { discriminator, name: event.name, ty })
+            })
+            .collect();
+
+        Ok(Self { name, program_id, instructions, accounts, events, types })
+    }
+
+    /// 指定程序 ID（IDL 未声明地址，或同一 IDL 部署在其他地址时）
+    pub fn with_program_id(mut self, program_id: Pubkey) -> Self {
+        self.program_id = program_id;
+        self
+    }
+
+    /// 指定名称，同时作为 `Protocol::Custom` 的名称
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    pub fn instruction_discriminator(&self, name: &str) -> Option<&[u8]> {
+        self.instructions.iter().find(|d| d.name == name).map(|d| d.discriminator.as_slice())
+    }
+
+    pub fn account_discriminator(&self, name: &str) -> Option<&[u8]> {
+        self.accounts.iter().find(|d| d.name == name).map(|d| d.discriminator.as_slice())
+    }
+
+    pub fn event_discriminator(&self, name: &str) -> Option<&[u8]> {
+        self.events.iter().find(|d| d.name == name).map(|d| d.discriminator.as_slice())
+    }
+
+    /// 解码指令数据（含 discriminator），未知指令或数据不足时返回 None
+    pub fn decode_instruction(
+        &self,
+        data: &[u8],
+        accounts: &[Pubkey],
+    ) -> Option<DecodedInstruction> {
+        let def = self
+            .instructions
+            .iter()
+            .find(|d| !d.discriminator.is_empty() && data.starts_with(&d.discriminator))?;
+        let mut reader = &data[def.discriminator.len()..];
+        let args = self.decode_named_fields(&def.args, &mut reader, 0)?;
+        let accounts = accounts
+            .iter()
+            .enumerate()
+            .map(|(i, pubkey)| {
+                let name =
+                    def.accounts.get(i).cloned().unwrap_or_else(|| format!("remaining_{}", i));
+                (name, *pubkey)
+            })
+            .collect();
+        Some(DecodedInstruction { name: def.name.clone(), args, accounts })
+    }
+
+    /// 解码事件数据（8 字节 discriminator + borsh 数据），返回 (事件名称, 数据)
+    pub fn decode_event(&self, data: &[u8]) -> Option<(String, IdlValue)> {
+        self.decode_typed(&self.events, data)
+    }
+
+    /// 解码 `emit_cpi!` 事件指令数据（[`EVENT_IX_TAG`] + 事件数据）
+    pub fn decode_cpi_event(&self, data: &[u8]) -> Option<(String, IdlValue)> {
+        self.decode_event(data.strip_prefix(&EVENT_IX_TAG)?)
+    }
+
+    /// 解码账户数据（8 字节 discriminator + borsh 数据），返回 (账户类型名称, 数据)
+    pub fn decode_account(&self, data: &[u8]) -> Option<(String, IdlValue)> {
+        self.decode_typed(&self.accounts, data)
+    }
+
+    /// 按 IDL 类型解码，成功后 `data` 指向剩余数据
+    pub fn decode_type(&self, ty: &IdlType, data: &mut &[u8]) -> Option<IdlValue> {
+        self.decode(ty, data, 0)
+    }
+
+    fn decode_typed(&self, defs: &[TypedDef], data: &[u8]) -> Option<(String, IdlValue)> {
+        let def = defs
+            .iter()
+            .find(|d| !d.discriminator.is_empty() && data.starts_with(&d.discriminator))?;
+        let mut reader = &data[def.discriminator.len()..];
+        let value = self.decode_defined(&def.ty, &mut reader, 0)?;
+        Some((def.name.clone(), value))
+    }
+
+    fn decode_named_fields(
+        &self,
+        fields: &[IdlField],
+        data: &mut &[u8],
+        depth: usize,
+    ) -> Option<IdlValue> {
+        let mut values = Vec::with_capacity(fields.len());
+        for field in fields {
+            values.push((field.name.clone(), self.decode(&field.ty, data, depth)?));
+        }
+        Some(IdlValue::Struct(values))
+    }
+
+    /// 元组字段：单个字段直接返回其值，多个字段返回数组
+    fn decode_fields(
+        &self,
+        fields: &IdlDefinedFields,
+        data: &mut &[u8],
+        depth: usize,
+    ) -> Option<IdlValue> {
+        match fields {
+            IdlDefinedFields::Named(fields) => self.decode_named_fields(fields, data, depth),
+            IdlDefinedFields::Tuple(types) => {
+                let mut values = Vec::with_capacity(types.len());
+                for ty in types {
+                    values.push(self.decode(ty, data, depth)?);
+                }
+                if values.len() == 1 {
+                    values.pop()
+                } else {
+                    Some(IdlValue::Vec(values))
+                }
+            }
+        }
+    }
+
+    fn decode_defined(
+        &self,
+        ty: &IdlTypeDefTy,
+        data: &mut &[u8],
+        depth: usize,
+    ) -> Option<IdlValue> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        match ty {
+            IdlTypeDefTy::Struct { fields } => self.decode_fields(fields, data, depth + 1),
+            IdlTypeDefTy::Enum { variants } => {
+                let variant = variants.get(take(data, 1)?[0] as usize)?;
+                let value = match &variant.fields {
+                    Some(fields) => self.decode_fields(fields, data, depth + 1)?,
+                    None => IdlValue::Null,
+                };
+                Some(IdlValue::Enum { variant: variant.name.clone(), value: Box::new(value) })
+            }
+            IdlTypeDefTy::Alias { value } | IdlTypeDefTy::Type { alias: value } => {
+                self.decode(value, data, depth + 1)
+            }
+        }
+    }
+
+    fn decode(&self, ty: &IdlType, data: &mut &[u8], depth: usize) -> Option<IdlValue> {
+        Some(match ty {
+            IdlType::Bool => IdlValue::Bool(take(data, 1)?[0] != 0),
+            IdlType::U8 => IdlValue::Uint(take_array::<1>(data)?[0] as u128),
+            IdlType::I8 => IdlValue::Int(i8::from_le_bytes(take_array(data)?) as i128),
+            IdlType::U16 => IdlValue::Uint(u16::from_le_bytes(take_array(data)?) as u128),
+            IdlType::I16 => IdlValue::Int(i16::from_le_bytes(take_array(data)?) as i128),
+            IdlType::U32 => IdlValue::Uint(u32::from_le_bytes(take_array(data)?) as u128),
+            IdlType::I32 => IdlValue::Int(i32::from_le_bytes(take_array(data)?) as i128),
+            IdlType::F32 => IdlValue::Float(f32::from_le_bytes(take_array(data)?) as f64),
+            IdlType::U64 => IdlValue::Uint(u64::from_le_bytes(take_array(data)?) as u128),
+            IdlType::I64 => IdlValue::Int(i64::from_le_bytes(take_array(data)?) as i128),
+            IdlType::F64 => IdlValue::Float(f64::from_le_bytes(take_array(data)?)),
+            IdlType::U128 => IdlValue::Uint(u128::from_le_bytes(take_array(data)?)),
+            IdlType::I128 => IdlValue::Int(i128::from_le_bytes(take_array(data)?)),
+            IdlType::Pubkey => IdlValue::Pubkey(Pubkey::new_from_array(take_array(data)?)),
+            IdlType::String => {
+                let len = u32::from_le_bytes(take_array(data)?) as usize;
+                IdlValue::String(String::from_utf8_lossy(take(data, len)?).into_owned())
+            }
+            IdlType::Bytes => {
+                let len = u32::from_le_bytes(take_array(data)?) as usize;
+                IdlValue::Bytes(take(data, len)?.to_vec())
+            }
+            IdlType::Option(inner) => match take(data, 1)?[0] {
+                0 => IdlValue::Option(None),
+                _ => IdlValue::Option(Some(Box::new(self.decode(inner, data, depth)?))),
+            },
+            IdlType::COption(inner) => match u32::from_le_bytes(take_array(data)?) {
+                0 => {
+                    // COption 为定长编码，None 时仍占用内部类型的空间
+                    self.decode(inner, data, depth)?;
+                    IdlValue::Option(None)
+                }
+                _ => IdlValue::Option(Some(Box::new(self.decode(inner, data, depth)?))),
+            },
+            IdlType::Vec(inner) => {
+                let len = u32::from_le_bytes(take_array(data)?) as usize;
+                self.decode_seq(inner, len, data, depth)?
+            }
+            IdlType::Array(inner, len) => match inner.as_ref() {
+                IdlType::U8 => IdlValue::Bytes(take(data, *len)?.to_vec()),
+                _ => self.decode_seq(inner, *len, data, depth)?,
+            },
+            IdlType::Defined(name) => self.decode_defined(self.types.get(name)?, data, depth)?,
+        })
+    }
+
+    fn decode_seq(
+        &self,
+        ty: &IdlType,
+        len: usize,
+        data: &mut &[u8],
+        depth: usize,
+    ) -> Option<IdlValue> {
+        // 长度来自链上数据，按剩余字节数限制预分配
+        let mut items = Vec::with_capacity(len.min(data.len()));
+        for _ in 0..len {
+            items.push(self.decode(ty, data, depth)?);
+        }
+        Some(IdlValue::Vec(items))
+    }
+}
+
+/// 展开嵌套的账户组，组内账户命名为 `<组名>.<账户名>`
+pub(crate) fn flatten_accounts(
+    accounts: &[IdlInstructionAccount],
+    prefix: &str,
+    out: &mut Vec<String>,
+) {
+    for account in accounts {
+        let name = if prefix.is_empty() {
+            account.name.clone()
+        } else {
+            format!("{}.{}", prefix, account.name)
+        };
+        if account.accounts.is_empty() {
+            out.push(name);
+        } else {
+            flatten_accounts(&account.accounts, &name, out);
+        }
+    }
+}
+
+#[inline]
+fn take<'a>(data: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if data.len() < len {
+        return None;
+    }
+    let (head, tail) = data.split_at(len);
+    *data = tail;
+    Some(head)
+}
+
+#[inline]
+fn take_array<const N: usize>(data: &mut &[u8]) -> Option<[u8; N]> {
+    take(data, N)?.try_into().ok()
+}
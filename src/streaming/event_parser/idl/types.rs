@@ -0,0 +1,217 @@
+//! Anchor IDL JSON 结构
+//!
+//! 同时兼容新版（Anchor 0.30+，带 `discriminator`、`pubkey`、`defined: { name }`）
+//! 与旧版（无 discriminator、`publicKey`、`defined: "Name"`、事件字段内联）两种格式，
+//! 只保留解码所需的字段。
+
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Idl {
+    #[serde(default)]
+    pub address: Option<String>,
+    /// 旧版 IDL 的程序名称
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub metadata: Option<IdlMetadata>,
+    #[serde(default)]
+    pub instructions: Vec<IdlInstruction>,
+    #[serde(default)]
+    pub accounts: Vec<IdlAccountDef>,
+    #[serde(default)]
+    pub events: Vec<IdlEventDef>,
+    #[serde(default)]
+    pub types: Vec<IdlTypeDef>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct IdlMetadata {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub address: Option<String>,
+    /// `index` 表示指令以 1 字节序号区分（如 Raydium AMM V4），默认为 Anchor sighash
+    #[serde(default)]
+    pub instruction_selector_type: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlInstruction {
+    pub name: String,
+    #[serde(default)]
+    pub discriminator: Option<Vec<u8>>,
+    #[serde(default)]
+    pub accounts: Vec<IdlInstructionAccount>,
+    #[serde(default)]
+    pub args: Vec<IdlField>,
+}
+
+/// 指令账户，`accounts` 非空时为嵌套的账户组
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlInstructionAccount {
+    pub name: String,
+    #[serde(default)]
+    pub accounts: Vec<IdlInstructionAccount>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlAccountDef {
+    pub name: String,
+    #[serde(default)]
+    pub discriminator: Option<Vec<u8>>,
+    /// 旧版 IDL 内联的账户结构，新版从 `types` 中按名称查找
+    #[serde(default, rename = "type")]
+    pub ty: Option<IdlTypeDefTy>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlEventDef {
+    pub name: String,
+    #[serde(default)]
+    pub discriminator: Option<Vec<u8>>,
+    /// 旧版 IDL 内联的事件字段，新版从 `types` 中按名称查找
+    #[serde(default)]
+    pub fields: Option<Vec<IdlField>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlTypeDef {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlTypeDefTy,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum IdlTypeDefTy {
+    Struct {
+        #[serde(default)]
+        fields: IdlDefinedFields,
+    },
+    Enum {
+        variants: Vec<IdlEnumVariant>,
+    },
+    Alias {
+        value: IdlType,
+    },
+    /// 旧版 IDL 的类型别名写法
+    Type {
+        alias: IdlType,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum IdlDefinedFields {
+    Named(Vec<IdlField>),
+    Tuple(Vec<IdlType>),
+}
+
+impl Default for IdlDefinedFields {
+    fn default() -> Self {
+        IdlDefinedFields::Named(Vec::new())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlEnumVariant {
+    pub name: String,
+    #[serde(default)]
+    pub fields: Option<IdlDefinedFields>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct IdlField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlType,
+}
+
+/// 字段类型
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "Value")]
+pub enum IdlType {
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    F32,
+    U64,
+    I64,
+    F64,
+    U128,
+    I128,
+    String,
+    Bytes,
+    Pubkey,
+    Option(Box<IdlType>),
+    /// SPL 的 `COption`，以 4 字节标记区分
+    COption(Box<IdlType>),
+    Vec(Box<IdlType>),
+    Array(Box<IdlType>, usize),
+    Defined(String),
+}
+
+impl TryFrom<Value> for IdlType {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let inner = |v: &Value| IdlType::try_from(v.clone()).map(Box::new);
+        match &value {
+            Value::String(s) => Ok(match s.as_str() {
+                "bool" => IdlType::Bool,
+                "u8" => IdlType::U8,
+                "i8" => IdlType::I8,
+                "u16" => IdlType::U16,
+                "i16" => IdlType::I16,
+                "u32" => IdlType::U32,
+                "i32" => IdlType::I32,
+                "f32" => IdlType::F32,
+                "u64" => IdlType::U64,
+                "i64" => IdlType::I64,
+                "f64" => IdlType::F64,
+                "u128" => IdlType::U128,
+                "i128" => IdlType::I128,
+                "string" => IdlType::String,
+                "bytes" => IdlType::Bytes,
+                "pubkey" | "publicKey" => IdlType::Pubkey,
+                other => return Err(format!("unsupported idl type: {other}")),
+            }),
+            Value::Object(map) => {
+                if let Some(v) = map.get("option") {
+                    Ok(IdlType::Option(inner(v)?))
+                } else if let Some(v) = map.get("coption") {
+                    Ok(IdlType::COption(inner(v)?))
+                } else if let Some(v) = map.get("vec") {
+                    Ok(IdlType::Vec(inner(v)?))
+                } else if let Some(Value::Array(array)) = map.get("array") {
+                    match array.as_slice() {
+                        [ty, Value::Number(len)] => Ok(IdlType::Array(
+                            inner(ty)?,
+                            len.as_u64().ok_or("invalid array length")? as usize,
+                        )),
+                        _ => Err(format!("unsupported array type: {value}")),
+                    }
+                } else if let Some(defined) = map.get("defined") {
+                    match defined {
+                        Value::String(name) => Ok(IdlType::Defined(name.clone())),
+                        Value::Object(d) => d
+                            .get("name")
+                            .and_then(Value::as_str)
+                            .map(|name| IdlType::Defined(name.to_string()))
+                            .ok_or_else(|| format!("invalid defined type: {value}")),
+                        _ => Err(format!("invalid defined type: {value}")),
+                    }
+                } else {
+                    Err(format!("unsupported idl type: {value}"))
+                }
+            }
+            _ => Err(format!("unsupported idl type: {value}")),
+        }
+    }
+}
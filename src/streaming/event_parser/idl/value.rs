@@ -0,0 +1,174 @@
+//! IDL 解码后的动态值
+
+use serde::{
+    ser::{SerializeMap, SerializeSeq},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+/// 按 IDL 类型解码出的值
+///
+/// 序列化为自然的 JSON：Pubkey 为 base58 字符串，结构体为对象，
+/// 枚举为 `{ "Variant": value }`（无数据的变体为字符串），超出 `u64` / `i64` 的整数为字符串
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum IdlValue {
+    #[default]
+    Null,
+    Bool(bool),
+    Uint(u128),
+    Int(i128),
+    Float(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Pubkey(Pubkey),
+    Option(Option<Box<IdlValue>>),
+    Vec(Vec<IdlValue>),
+    /// 字段保持 IDL 中的顺序；元组结构体解码为单个值或 `Vec`
+    Struct(Vec<(String, IdlValue)>),
+    Enum {
+        variant: String,
+        value: Box<IdlValue>,
+    },
+}
+
+impl IdlValue {
+    /// 获取结构体字段
+    pub fn get(&self, name: &str) -> Option<&IdlValue> {
+        match self {
+            IdlValue::Struct(fields) => fields.iter().find(|(n, _)| n == name).map(|(_, v)| v),
+            IdlValue::Option(Some(value)) => value.get(name),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            IdlValue::Uint(v) => u64::try_from(*v).ok(),
+            IdlValue::Int(v) => u64::try_from(*v).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            IdlValue::Uint(v) => i64::try_from(*v).ok(),
+            IdlValue::Int(v) => i64::try_from(*v).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            IdlValue::Uint(v) => Some(*v),
+            IdlValue::Int(v) => u128::try_from(*v).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            IdlValue::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            IdlValue::String(v) => Some(v),
+            IdlValue::Enum { variant, .. } => Some(variant),
+            _ => None,
+        }
+    }
+
+    pub fn as_pubkey(&self) -> Option<Pubkey> {
+        match self {
+            IdlValue::Pubkey(v) => Some(*v),
+            IdlValue::String(v) => Pubkey::from_str(v).ok(),
+            _ => None,
+        }
+    }
+
+    /// 转换为 JSON
+    pub fn to_json(&self) -> Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl Serialize for IdlValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            IdlValue::Null => serializer.serialize_unit(),
+            IdlValue::Bool(v) => serializer.serialize_bool(*v),
+            IdlValue::Uint(v) => match u64::try_from(*v) {
+                Ok(v) => serializer.serialize_u64(v),
+                Err(_) => serializer.serialize_str(&v.to_string()),
+            },
+            IdlValue::Int(v) => match i64::try_from(*v) {
+                Ok(v) => serializer.serialize_i64(v),
+                Err(_) => serializer.serialize_str(&v.to_string()),
+            },
+            IdlValue::Float(v) => serializer.serialize_f64(*v),
+            IdlValue::String(v) => serializer.serialize_str(v),
+            IdlValue::Bytes(v) => v.serialize(serializer),
+            IdlValue::Pubkey(v) => serializer.serialize_str(&v.to_string()),
+            IdlValue::Option(v) => match v {
+                Some(v) => v.serialize(serializer),
+                None => serializer.serialize_none(),
+            },
+            IdlValue::Vec(items) => {
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(item)?;
+                }
+                seq.end()
+            }
+            IdlValue::Struct(fields) => {
+                let mut map = serializer.serialize_map(Some(fields.len()))?;
+                for (name, value) in fields {
+                    map.serialize_entry(name, value)?;
+                }
+                map.end()
+            }
+            IdlValue::Enum { variant, value } => match value.as_ref() {
+                IdlValue::Null => serializer.serialize_str(variant),
+                value => {
+                    let mut map = serializer.serialize_map(Some(1))?;
+                    map.serialize_entry(variant, value)?;
+                    map.end()
+                }
+            },
+        }
+    }
+}
+
+/// 从 JSON 还原（有损）：字符串不会还原为 Pubkey，对象还原为结构体
+impl<'de> Deserialize<'de> for IdlValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Value::deserialize(deserializer)?.into())
+    }
+}
+
+impl From<Value> for IdlValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => IdlValue::Null,
+            Value::Bool(v) => IdlValue::Bool(v),
+            Value::Number(n) => {
+                if let Some(v) = n.as_u64() {
+                    IdlValue::Uint(v as u128)
+                } else if let Some(v) = n.as_i64() {
+                    IdlValue::Int(v as i128)
+                } else {
+                    IdlValue::Float(n.as_f64().unwrap_or_default())
+                }
+            }
+            Value::String(v) => IdlValue::String(v),
+            Value::Array(items) => IdlValue::Vec(items.into_iter().map(Into::into).collect()),
+            Value::Object(map) => {
+                IdlValue::Struct(map.into_iter().map(|(k, v)| (k, v.into())).collect())
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
 pub mod common;
 pub mod core;
+pub mod idl;
 pub mod protocols;
 
 pub use core::traits::DexEvent;
@@ -1,6 +1,7 @@
 use crate::streaming::event_parser::common::EventMetadata;
 use crate::streaming::event_parser::protocols::bonk::types::{
-    CurveParams, MintParams, PoolStatus, TradeDirection, VestingParams,
+    BondingCurveParam, CurveParams, MintParams, PlatformConfigParam, PoolStatus, TradeDirection,
+    VestingParams, VestingRecord,
 };
 use crate::streaming::event_parser::protocols::bonk::{
     AmmFeeOn, GlobalConfig, PlatformConfig, PoolState,
@@ -227,6 +228,159 @@ pub struct BonkMigrateToCpswapEvent {
     pub remaining_accounts: Vec<Pubkey>,
 }
 
+/// Claim vested token event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BonkClaimVestedEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pool_state: Pubkey,
+    pub beneficiary: Pubkey,
+    pub claim_amount: u64,
+    #[borsh(skip)]
+    pub authority: Pubkey,
+    #[borsh(skip)]
+    pub vesting_record: Pubkey,
+    #[borsh(skip)]
+    pub base_vault: Pubkey,
+    #[borsh(skip)]
+    pub user_base_token: Pubkey,
+    #[borsh(skip)]
+    pub base_token_mint: Pubkey,
+    #[borsh(skip)]
+    pub base_token_program: Pubkey,
+}
+
+pub const BONK_CLAIM_VESTED_EVENT_LOG_SIZE: usize = 32 + 32 + 8;
+
+pub fn bonk_claim_vested_event_log_decode(data: &[u8]) -> Option<BonkClaimVestedEvent> {
+    if data.len() < BONK_CLAIM_VESTED_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<BonkClaimVestedEvent>(&data[..BONK_CLAIM_VESTED_EVENT_LOG_SIZE]).ok()
+}
+
+/// Create vesting account event
+///
+/// create_vesting_account 与 create_platform_vesting_account 共用，
+/// 后者的 creator 为 platform_vesting_wallet
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BonkCreateVestingEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pool_state: Pubkey,
+    pub beneficiary: Pubkey,
+    pub share_amount: u64,
+    #[borsh(skip)]
+    pub creator: Pubkey,
+    #[borsh(skip)]
+    pub vesting_record: Pubkey,
+    #[borsh(skip)]
+    pub platform_config: Option<Pubkey>,
+}
+
+pub const BONK_CREATE_VESTING_EVENT_LOG_SIZE: usize = 32 + 32 + 8;
+
+pub fn bonk_create_vesting_event_log_decode(data: &[u8]) -> Option<BonkCreateVestingEvent> {
+    if data.len() < BONK_CREATE_VESTING_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<BonkCreateVestingEvent>(&data[..BONK_CREATE_VESTING_EVENT_LOG_SIZE]).ok()
+}
+
+/// Claim creator fee event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BonkClaimCreatorFeeEvent {
+    pub metadata: EventMetadata,
+    pub creator: Pubkey,
+    pub fee_vault_authority: Pubkey,
+    pub creator_fee_vault: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub quote_mint: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// Claim platform fee event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BonkClaimPlatformFeeEvent {
+    pub metadata: EventMetadata,
+    pub platform_fee_wallet: Pubkey,
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+    pub platform_config: Pubkey,
+    pub quote_vault: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub quote_mint: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// Claim platform fee from vault event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BonkClaimPlatformFeeFromVaultEvent {
+    pub metadata: EventMetadata,
+    pub platform_fee_wallet: Pubkey,
+    pub fee_vault_authority: Pubkey,
+    pub platform_config: Pubkey,
+    pub platform_fee_vault: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub quote_mint: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// Collect protocol / migrate fee event
+///
+/// collect_fee 与 collect_migrate_fee 共用，通过 event_type 区分
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BonkCollectFeeEvent {
+    pub metadata: EventMetadata,
+    pub owner: Pubkey,
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+    pub global_config: Pubkey,
+    pub quote_vault: Pubkey,
+    pub quote_mint: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// Update global config event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BonkUpdateConfigEvent {
+    pub metadata: EventMetadata,
+    pub param: u8,
+    pub value: u64,
+    pub owner: Pubkey,
+    pub global_config: Pubkey,
+}
+
+/// Update platform config event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BonkUpdatePlatformConfigEvent {
+    pub metadata: EventMetadata,
+    pub param: PlatformConfigParam,
+    pub platform_admin: Pubkey,
+    pub platform_config: Pubkey,
+}
+
+/// Update platform curve param event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BonkUpdatePlatformCurveParamEvent {
+    pub metadata: EventMetadata,
+    pub index: u8,
+    pub bonding_curve_param: BondingCurveParam,
+    pub platform_admin: Pubkey,
+    pub platform_config: Pubkey,
+    pub global_config: Pubkey,
+}
+
+/// Remove platform curve param event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BonkRemovePlatformCurveParamEvent {
+    pub metadata: EventMetadata,
+    pub index: u8,
+    pub platform_admin: Pubkey,
+    pub platform_config: Pubkey,
+}
+
 /// 池状态
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BonkPoolStateAccountEvent {
@@ -263,6 +417,18 @@ pub struct BonkPlatformConfigAccountEvent {
     pub platform_config: PlatformConfig,
 }
 
+/// 锁仓记录
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BonkVestingRecordAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub vesting_record: VestingRecord,
+}
+
 /// Event discriminator constants
 pub mod discriminators {
     // Event discriminators
@@ -272,6 +438,10 @@ pub mod discriminators {
     // pub const POOL_CREATE_EVENT: &str = "0xe445a52e51cb9a1d97d7e20976a173ae";
     pub const POOL_CREATE_EVENT: &[u8] =
         &[228, 69, 165, 46, 81, 203, 154, 29, 151, 215, 226, 9, 118, 161, 115, 174];
+    pub const CLAIM_VESTED_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 21, 194, 114, 87, 120, 211, 226, 32];
+    pub const CREATE_VESTING_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 150, 152, 11, 179, 52, 210, 191, 125];
 
     // Instruction discriminators
     pub const BUY_EXACT_IN: &[u8] = &[250, 234, 13, 123, 213, 156, 19, 236];
@@ -283,9 +453,22 @@ pub mod discriminators {
     pub const INITIALIZE_WITH_TOKEN_2022: &[u8] = &[37, 190, 126, 222, 44, 154, 171, 17];
     pub const MIGRATE_TO_AMM: &[u8] = &[207, 82, 192, 145, 254, 207, 145, 223];
     pub const MIGRATE_TO_CP_SWAP: &[u8] = &[136, 92, 200, 103, 28, 218, 144, 140];
+    pub const CLAIM_VESTED_TOKEN: &[u8] = &[49, 33, 104, 30, 189, 157, 79, 35];
+    pub const CREATE_VESTING_ACCOUNT: &[u8] = &[129, 178, 2, 13, 217, 172, 230, 218];
+    pub const CREATE_PLATFORM_VESTING_ACCOUNT: &[u8] = &[146, 71, 173, 69, 98, 19, 15, 106];
+    pub const CLAIM_CREATOR_FEE: &[u8] = &[26, 97, 138, 203, 132, 171, 141, 252];
+    pub const CLAIM_PLATFORM_FEE: &[u8] = &[156, 39, 208, 135, 76, 237, 61, 72];
+    pub const CLAIM_PLATFORM_FEE_FROM_VAULT: &[u8] = &[117, 241, 198, 168, 248, 218, 80, 29];
+    pub const COLLECT_FEE: &[u8] = &[60, 173, 247, 103, 4, 93, 130, 48];
+    pub const COLLECT_MIGRATE_FEE: &[u8] = &[255, 186, 150, 223, 235, 118, 201, 186];
+    pub const UPDATE_CONFIG: &[u8] = &[29, 158, 252, 191, 10, 83, 219, 99];
+    pub const UPDATE_PLATFORM_CONFIG: &[u8] = &[195, 60, 76, 129, 146, 45, 67, 143];
+    pub const UPDATE_PLATFORM_CURVE_PARAM: &[u8] = &[138, 144, 138, 250, 220, 128, 4, 57];
+    pub const REMOVE_PLATFORM_CURVE_PARAM: &[u8] = &[27, 30, 62, 169, 93, 224, 24, 145];
 
     // 账户鉴别器
     pub const POOL_STATE_ACCOUNT: &[u8] = &[247, 237, 227, 245, 215, 195, 222, 70];
     pub const GLOBAL_CONFIG_ACCOUNT: &[u8] = &[149, 8, 156, 202, 160, 252, 176, 217];
     pub const PLATFORM_CONFIG_ACCOUNT: &[u8] = &[160, 78, 128, 0, 248, 83, 230, 160];
+    pub const VESTING_RECORD_ACCOUNT: &[u8] = &[106, 243, 221, 205, 230, 126, 85, 83];
 }
@@ -1,15 +1,22 @@
+use borsh::BorshDeserialize;
 use solana_sdk::pubkey::Pubkey;
 
 use crate::streaming::event_parser::{
     common::{utils::*, EventMetadata, EventType},
     protocols::bonk::{
-        bonk_pool_create_event_log_decode, bonk_trade_event_log_decode, discriminators, AmmFeeOn,
-        BonkMigrateToAmmEvent, BonkMigrateToCpswapEvent, BonkPoolCreateEvent, BonkTradeEvent,
-        ConstantCurve, CurveParams, FixedCurve, LinearCurve, MintParams, TradeDirection,
-        VestingParams,
+        bonk_claim_vested_event_log_decode, bonk_create_vesting_event_log_decode,
+        bonk_pool_create_event_log_decode, bonk_trade_event_log_decode, discriminators, types,
+        AmmFeeOn, BondingCurveParam, BonkClaimCreatorFeeEvent, BonkClaimPlatformFeeEvent,
+        BonkClaimPlatformFeeFromVaultEvent, BonkClaimVestedEvent, BonkCollectFeeEvent,
+        BonkCreateVestingEvent, BonkMigrateToAmmEvent, BonkMigrateToCpswapEvent,
+        BonkPoolCreateEvent, BonkRemovePlatformCurveParamEvent, BonkTradeEvent,
+        BonkUpdateConfigEvent, BonkUpdatePlatformConfigEvent, BonkUpdatePlatformCurveParamEvent,
+        ConstantCurve, CurveParams, FixedCurve, LinearCurve, MintParams, PlatformConfigParam,
+        TradeDirection, VestingParams,
     },
     DexEvent,
 };
+use crate::streaming::grpc::AccountPretty;
 
 /// Bonk Program ID
 pub const BONK_PROGRAM_ID: Pubkey =
@@ -52,6 +59,40 @@ pub fn parse_bonk_instruction_data(
         discriminators::MIGRATE_TO_CP_SWAP => {
             parse_migrate_to_cpswap_instruction(data, accounts, metadata)
         }
+        discriminators::CLAIM_VESTED_TOKEN => {
+            parse_claim_vested_token_instruction(data, accounts, metadata)
+        }
+        discriminators::CREATE_VESTING_ACCOUNT => {
+            parse_create_vesting_account_instruction(data, accounts, metadata)
+        }
+        discriminators::CREATE_PLATFORM_VESTING_ACCOUNT => {
+            parse_create_platform_vesting_account_instruction(data, accounts, metadata)
+        }
+        discriminators::CLAIM_CREATOR_FEE => {
+            parse_claim_creator_fee_instruction(data, accounts, metadata)
+        }
+        discriminators::CLAIM_PLATFORM_FEE => {
+            parse_claim_platform_fee_instruction(data, accounts, metadata)
+        }
+        discriminators::CLAIM_PLATFORM_FEE_FROM_VAULT => {
+            parse_claim_platform_fee_from_vault_instruction(data, accounts, metadata)
+        }
+        discriminators::COLLECT_FEE => {
+            parse_collect_fee_instruction(EventType::BonkCollectFee, accounts, metadata)
+        }
+        discriminators::COLLECT_MIGRATE_FEE => {
+            parse_collect_fee_instruction(EventType::BonkCollectMigrateFee, accounts, metadata)
+        }
+        discriminators::UPDATE_CONFIG => parse_update_config_instruction(data, accounts, metadata),
+        discriminators::UPDATE_PLATFORM_CONFIG => {
+            parse_update_platform_config_instruction(data, accounts, metadata)
+        }
+        discriminators::UPDATE_PLATFORM_CURVE_PARAM => {
+            parse_update_platform_curve_param_instruction(data, accounts, metadata)
+        }
+        discriminators::REMOVE_PLATFORM_CURVE_PARAM => {
+            parse_remove_platform_curve_param_instruction(data, accounts, metadata)
+        }
         _ => None,
     }
 }
@@ -71,28 +112,43 @@ pub fn parse_bonk_inner_instruction_data(
         discriminators::POOL_CREATE_EVENT => {
             parse_pool_create_inner_instruction(data, metadata)
         }
+        discriminators::CLAIM_VESTED_EVENT => {
+            parse_claim_vested_inner_instruction(data, metadata)
+        }
+        discriminators::CREATE_VESTING_EVENT => {
+            parse_create_vesting_inner_instruction(data, metadata)
+        }
         _ => None,
     }
 }
 
+/// 解析 "Program data:" 日志中的 Bonk 事件
+///
+/// claim_vested_token / create_vesting_account / create_platform_vesting_account 不带
+/// event_authority，事件通过 `emit!` 写入日志。`data` 为 base64 解码后的日志数据，
+/// 以 8 字节事件判别器开头
+pub fn parse_bonk_log_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    if data.len() < 8 {
+        return None;
+    }
+    let mut discriminator = discriminators::TRADE_EVENT[..8].to_vec();
+    discriminator.extend_from_slice(&data[..8]);
+    parse_bonk_inner_instruction_data(&discriminator, &data[8..], metadata)
+}
+
 /// 解析 Bonk 账户数据
 ///
 /// 根据判别器路由到具体的账户解析函数
 pub fn parse_bonk_account_data(
     discriminator: &[u8],
-    account: &crate::streaming::grpc::AccountPretty,
-    metadata: crate::streaming::event_parser::common::EventMetadata,
-) -> Option<crate::streaming::event_parser::DexEvent> {
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
     match discriminator {
-        discriminators::POOL_STATE_ACCOUNT => {
-            crate::streaming::event_parser::protocols::bonk::types::pool_state_parser(account, metadata)
-        }
-        discriminators::GLOBAL_CONFIG_ACCOUNT => {
-            crate::streaming::event_parser::protocols::bonk::types::global_config_parser(account, metadata)
-        }
-        discriminators::PLATFORM_CONFIG_ACCOUNT => {
-            crate::streaming::event_parser::protocols::bonk::types::platform_config_parser(account, metadata)
-        }
+        discriminators::POOL_STATE_ACCOUNT => types::pool_state_parser(account, metadata),
+        discriminators::GLOBAL_CONFIG_ACCOUNT => types::global_config_parser(account, metadata),
+        discriminators::PLATFORM_CONFIG_ACCOUNT => types::platform_config_parser(account, metadata),
+        discriminators::VESTING_RECORD_ACCOUNT => types::vesting_record_parser(account, metadata),
         _ => None,
     }
 }
@@ -111,6 +167,22 @@ fn parse_pool_create_inner_instruction(
     }
 }
 
+/// Parse claim vested event
+fn parse_claim_vested_inner_instruction(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    bonk_claim_vested_event_log_decode(data)
+        .map(|event| DexEvent::BonkClaimVestedEvent(BonkClaimVestedEvent { metadata, ..event }))
+}
+
+/// Parse create vesting event
+fn parse_create_vesting_inner_instruction(
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    // event_type 由指令解析器设置，create_vesting_account 与 create_platform_vesting_account 共用该事件
+    bonk_create_vesting_event_log_decode(data)
+        .map(|event| DexEvent::BonkCreateVestingEvent(BonkCreateVestingEvent { metadata, ..event }))
+}
+
 /// Parse trade event
 fn parse_trade_inner_instruction(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
     if let Some(event) = bonk_trade_event_log_decode(data) {
@@ -614,3 +686,301 @@ fn parse_migrate_to_cpswap_instruction(
         ..Default::default()
     }))
 }
+
+/// Parse claim vested token event
+fn parse_claim_vested_token_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::BonkClaimVestedToken;
+
+    if accounts.len() < 8 {
+        return None;
+    }
+
+    // claim_amount 由 ClaimVestedEvent 合并
+    Some(DexEvent::BonkClaimVestedEvent(BonkClaimVestedEvent {
+        metadata,
+        beneficiary: accounts[0],
+        authority: accounts[1],
+        pool_state: accounts[2],
+        vesting_record: accounts[3],
+        base_vault: accounts[4],
+        user_base_token: accounts[5],
+        base_token_mint: accounts[6],
+        base_token_program: accounts[7],
+        ..Default::default()
+    }))
+}
+
+/// Parse create vesting account event
+fn parse_create_vesting_account_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::BonkCreateVestingAccount;
+
+    if data.len() < 8 || accounts.len() < 4 {
+        return None;
+    }
+
+    Some(DexEvent::BonkCreateVestingEvent(BonkCreateVestingEvent {
+        metadata,
+        share_amount: read_u64_le(data, 0)?,
+        creator: accounts[0],
+        beneficiary: accounts[1],
+        pool_state: accounts[2],
+        vesting_record: accounts[3],
+        platform_config: None,
+    }))
+}
+
+/// Parse create platform vesting account event
+fn parse_create_platform_vesting_account_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::BonkCreatePlatformVestingAccount;
+
+    if accounts.len() < 5 {
+        return None;
+    }
+
+    // share_amount 由平台锁仓比例计算，通过 CreateVestingEvent 合并
+    Some(DexEvent::BonkCreateVestingEvent(BonkCreateVestingEvent {
+        metadata,
+        creator: accounts[0],
+        beneficiary: accounts[1],
+        platform_config: Some(accounts[2]),
+        pool_state: accounts[3],
+        vesting_record: accounts[4],
+        ..Default::default()
+    }))
+}
+
+/// Parse claim creator fee event
+fn parse_claim_creator_fee_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::BonkClaimCreatorFee;
+
+    if accounts.len() < 6 {
+        return None;
+    }
+
+    Some(DexEvent::BonkClaimCreatorFeeEvent(BonkClaimCreatorFeeEvent {
+        metadata,
+        creator: accounts[0],
+        fee_vault_authority: accounts[1],
+        creator_fee_vault: accounts[2],
+        recipient_token_account: accounts[3],
+        quote_mint: accounts[4],
+        token_program: accounts[5],
+    }))
+}
+
+/// Parse claim platform fee event
+fn parse_claim_platform_fee_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::BonkClaimPlatformFee;
+
+    if accounts.len() < 8 {
+        return None;
+    }
+
+    Some(DexEvent::BonkClaimPlatformFeeEvent(BonkClaimPlatformFeeEvent {
+        metadata,
+        platform_fee_wallet: accounts[0],
+        authority: accounts[1],
+        pool_state: accounts[2],
+        platform_config: accounts[3],
+        quote_vault: accounts[4],
+        recipient_token_account: accounts[5],
+        quote_mint: accounts[6],
+        token_program: accounts[7],
+    }))
+}
+
+/// Parse claim platform fee from vault event
+fn parse_claim_platform_fee_from_vault_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::BonkClaimPlatformFeeFromVault;
+
+    if accounts.len() < 7 {
+        return None;
+    }
+
+    Some(DexEvent::BonkClaimPlatformFeeFromVaultEvent(BonkClaimPlatformFeeFromVaultEvent {
+        metadata,
+        platform_fee_wallet: accounts[0],
+        fee_vault_authority: accounts[1],
+        platform_config: accounts[2],
+        platform_fee_vault: accounts[3],
+        recipient_token_account: accounts[4],
+        quote_mint: accounts[5],
+        token_program: accounts[6],
+    }))
+}
+
+/// Parse collect fee / collect migrate fee event
+fn parse_collect_fee_instruction(
+    event_type: EventType,
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = event_type;
+
+    if accounts.len() < 8 {
+        return None;
+    }
+
+    Some(DexEvent::BonkCollectFeeEvent(BonkCollectFeeEvent {
+        metadata,
+        owner: accounts[0],
+        authority: accounts[1],
+        pool_state: accounts[2],
+        global_config: accounts[3],
+        quote_vault: accounts[4],
+        quote_mint: accounts[5],
+        recipient_token_account: accounts[6],
+        token_program: accounts[7],
+    }))
+}
+
+/// Parse update config event
+fn parse_update_config_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::BonkUpdateConfig;
+
+    if data.len() < 9 || accounts.len() < 2 {
+        return None;
+    }
+
+    Some(DexEvent::BonkUpdateConfigEvent(BonkUpdateConfigEvent {
+        metadata,
+        param: data[0],
+        value: read_u64_le(data, 1)?,
+        owner: accounts[0],
+        global_config: accounts[1],
+    }))
+}
+
+/// Parse update platform config event
+fn parse_update_platform_config_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::BonkUpdatePlatformConfig;
+
+    if accounts.len() < 2 {
+        return None;
+    }
+
+    let param = PlatformConfigParam::deserialize(&mut &data[..]).ok()?;
+
+    Some(DexEvent::BonkUpdatePlatformConfigEvent(BonkUpdatePlatformConfigEvent {
+        metadata,
+        param,
+        platform_admin: accounts[0],
+        platform_config: accounts[1],
+    }))
+}
+
+/// Parse update platform curve param event
+fn parse_update_platform_curve_param_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::BonkUpdatePlatformCurveParam;
+
+    if data.is_empty() || accounts.len() < 3 {
+        return None;
+    }
+
+    let bonding_curve_param = BondingCurveParam::deserialize(&mut &data[1..]).ok()?;
+
+    Some(DexEvent::BonkUpdatePlatformCurveParamEvent(BonkUpdatePlatformCurveParamEvent {
+        metadata,
+        index: data[0],
+        bonding_curve_param,
+        platform_admin: accounts[0],
+        platform_config: accounts[1],
+        global_config: accounts[2],
+    }))
+}
+
+/// Parse remove platform curve param event
+fn parse_remove_platform_curve_param_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::BonkRemovePlatformCurveParam;
+
+    if data.is_empty() || accounts.len() < 2 {
+        return None;
+    }
+
+    Some(DexEvent::BonkRemovePlatformCurveParamEvent(BonkRemovePlatformCurveParamEvent {
+        metadata,
+        index: data[0],
+        platform_admin: accounts[0],
+        platform_config: accounts[1],
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        core::transaction_input::fixtures::{
+            parse, program_data_meta, transaction, unique_pubkeys,
+        },
+        Protocol,
+    };
+
+    #[test]
+    fn test_create_platform_vesting_account_merges_create_vesting_log() {
+        let accounts = unique_pubkeys(6);
+        let tx = transaction(
+            BONK_PROGRAM_ID,
+            discriminators::CREATE_PLATFORM_VESTING_ACCOUNT,
+            &accounts,
+        );
+
+        // 没有 event_authority，CreateVestingEvent 通过 emit! 写入日志
+        let mut log = discriminators::CREATE_VESTING_EVENT[8..].to_vec();
+        log.extend_from_slice(accounts[3].as_ref());
+        log.extend_from_slice(accounts[1].as_ref());
+        log.extend_from_slice(&5_000_000u64.to_le_bytes());
+        let meta = program_data_meta(BONK_PROGRAM_ID, &[log]);
+
+        let events = parse(&[Protocol::Bonk], &tx, &meta);
+
+        assert_eq!(events.len(), 1);
+        let DexEvent::BonkCreateVestingEvent(event) = &events[0] else {
+            panic!("expected create vesting")
+        };
+        assert_eq!(event.metadata.event_type, EventType::BonkCreatePlatformVestingAccount);
+        assert_eq!((event.creator, event.beneficiary), (accounts[0], accounts[1]));
+        assert_eq!(event.platform_config, Some(accounts[2]));
+        assert_eq!((event.pool_state, event.vesting_record), (accounts[3], accounts[4]));
+        assert_eq!(event.share_amount, 5_000_000);
+    }
+}
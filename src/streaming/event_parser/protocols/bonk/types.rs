@@ -6,7 +6,8 @@ use crate::streaming::{
     event_parser::{
         common::{EventMetadata, EventType},
         protocols::bonk::{
-            BonkGlobalConfigAccountEvent, BonkPlatformConfigAccountEvent, BonkPoolStateAccountEvent,
+            BonkGlobalConfigAccountEvent, BonkPlatformConfigAccountEvent,
+            BonkPoolStateAccountEvent, BonkVestingRecordAccountEvent,
         },
         DexEvent,
     },
@@ -383,3 +384,83 @@ pub fn platform_config_parser(
         None
     }
 }
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MigrateNftInfo {
+    pub platform_scale: u64,
+    pub creator_scale: u64,
+    pub burn_scale: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PlatformConfigInfo {
+    pub fee_wallet: Pubkey,
+    pub nft_wallet: Pubkey,
+    pub migrate_nft_info: MigrateNftInfo,
+    pub fee_rate: u64,
+    pub name: String,
+    pub web: String,
+    pub img: String,
+    pub transfer_fee_extension_auth: Pubkey,
+    pub creator_fee_rate: u64,
+    pub platform_vesting_scale: u64,
+    pub vesting_wallet: Pubkey,
+}
+
+/// update_platform_config 修改的字段
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub enum PlatformConfigParam {
+    FeeWallet(Pubkey),
+    NFTWallet(Pubkey),
+    MigrateNftInfo(MigrateNftInfo),
+    FeeRate(u64),
+    Name(String),
+    Web(String),
+    Img(String),
+    #[default]
+    CpSwapConfig,
+    AllInfo(Box<PlatformConfigInfo>),
+    VestingWallet(Pubkey),
+    PlatformVestingScale(u64),
+    PlatformCPCreator(Pubkey),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct VestingRecord {
+    pub epoch: u64,
+    pub pool: Pubkey,
+    pub beneficiary: Pubkey,
+    pub claimed_amount: u64,
+    pub token_share_amount: u64,
+    pub padding: [u64; 8],
+}
+
+pub const VESTING_RECORD_SIZE: usize = 8 + 32 * 2 + 8 * 2 + 8 * 8;
+
+pub fn vesting_record_decode(data: &[u8]) -> Option<VestingRecord> {
+    if data.len() < VESTING_RECORD_SIZE {
+        return None;
+    }
+    borsh::from_slice::<VestingRecord>(&data[..VESTING_RECORD_SIZE]).ok()
+}
+
+pub fn vesting_record_parser(
+    account: &AccountPretty,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountBonkVestingRecord;
+
+    if account.data.len() < VESTING_RECORD_SIZE + 8 {
+        return None;
+    }
+    let vesting_record = vesting_record_decode(&account.data[8..VESTING_RECORD_SIZE + 8])?;
+    Some(DexEvent::BonkVestingRecordAccountEvent(BonkVestingRecordAccountEvent {
+        metadata,
+        pubkey: account.pubkey,
+        executable: account.executable,
+        lamports: account.lamports,
+        owner: account.owner,
+        rent_epoch: account.rent_epoch,
+        vesting_record,
+    }))
+}
@@ -0,0 +1,108 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::common::EventMetadata;
+
+/// Jupiter v6 路由（route / shared_accounts_route / exact_out_route 及其 token ledger、v2 变体）
+///
+/// 用户的总输入/输出与各跳明细来自路由的 SwapEvent CPI；没有 inner instructions（如 shred）时
+/// 只有指令参数，hops 为空
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JupiterRouteEvent {
+    pub metadata: EventMetadata,
+    /// 仅 shared_accounts 变体
+    pub id: Option<u8>,
+    /// 指令中 route_plan 的步数
+    pub route_plan_len: u32,
+    /// 用户实际输入；token ledger 变体与 exact out 在指令中没有，由各跳累加
+    pub in_amount: u64,
+    /// 用户实际输出，未扣除平台费；exact out 取指令中的 out_amount，其余由各跳累加
+    pub out_amount: u64,
+    /// 仅 exact out
+    pub quoted_in_amount: u64,
+    /// exact in 变体的报价输出
+    pub quoted_out_amount: u64,
+    pub slippage_bps: u16,
+    /// v1 变体指令中为 u8
+    pub platform_fee_bps: u16,
+    /// 仅 v2 变体
+    pub positive_slippage_bps: u16,
+    /// 按执行顺序排列的各跳
+    pub hops: Vec<JupiterRouteHop>,
+
+    // 来自 Input Accounts 的数据
+    pub user_transfer_authority: Pubkey,
+    pub source_token_account: Pubkey,
+    pub destination_token_account: Pubkey,
+    /// v1 的 route 变体没有 source_mint 账户，取第一跳的 input_mint
+    pub source_mint: Pubkey,
+    pub destination_mint: Pubkey,
+    /// v2 变体没有平台费账户，为默认值
+    pub platform_fee_account: Pubkey,
+}
+
+/// 路由中的一跳
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JupiterRouteHop {
+    pub amm: Pubkey,
+    pub input_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_mint: Pubkey,
+    pub output_amount: u64,
+    /// SwapEvent 所在的 inner instruction 下标，该跳的子事件位于它之前
+    pub inner_index: Option<i64>,
+}
+
+/// Jupiter SwapEvent（emit_cpi!），每一跳一个，合并到 JupiterRouteEvent
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct JupiterSwapEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub amm: Pubkey,
+    pub input_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_mint: Pubkey,
+    pub output_amount: u64,
+}
+
+pub const JUPITER_SWAP_EVENT_LOG_SIZE: usize = 112;
+pub fn jupiter_swap_event_log_decode(data: &[u8]) -> Option<JupiterSwapEvent> {
+    if data.len() < JUPITER_SWAP_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<JupiterSwapEvent>(&data[..JUPITER_SWAP_EVENT_LOG_SIZE]).ok()
+}
+
+impl From<JupiterSwapEvent> for JupiterRouteHop {
+    fn from(event: JupiterSwapEvent) -> Self {
+        Self {
+            amm: event.amm,
+            input_mint: event.input_mint,
+            input_amount: event.input_amount,
+            output_mint: event.output_mint,
+            output_amount: event.output_amount,
+            inner_index: event.metadata.inner_index,
+        }
+    }
+}
+
+/// 事件鉴别器
+pub mod discriminators {
+    // 指令鉴别器
+    pub const ROUTE: &[u8] = &[229, 23, 203, 151, 122, 227, 173, 42];
+    pub const ROUTE_WITH_TOKEN_LEDGER: &[u8] = &[150, 86, 71, 116, 167, 93, 14, 104];
+    pub const SHARED_ACCOUNTS_ROUTE: &[u8] = &[193, 32, 155, 51, 65, 214, 156, 129];
+    pub const SHARED_ACCOUNTS_ROUTE_WITH_TOKEN_LEDGER: &[u8] =
+        &[230, 121, 143, 80, 119, 159, 106, 170];
+    pub const EXACT_OUT_ROUTE: &[u8] = &[208, 51, 239, 151, 123, 43, 237, 92];
+    pub const SHARED_ACCOUNTS_EXACT_OUT_ROUTE: &[u8] = &[176, 209, 105, 168, 154, 125, 69, 62];
+    pub const ROUTE_V2: &[u8] = &[187, 100, 250, 204, 49, 196, 175, 20];
+    pub const EXACT_OUT_ROUTE_V2: &[u8] = &[157, 138, 184, 82, 21, 244, 243, 36];
+    pub const SHARED_ACCOUNTS_ROUTE_V2: &[u8] = &[209, 152, 83, 147, 124, 254, 216, 233];
+    pub const SHARED_ACCOUNTS_EXACT_OUT_ROUTE_V2: &[u8] = &[53, 96, 229, 202, 216, 187, 250, 24];
+
+    // 事件鉴别器
+    pub const SWAP_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 64, 198, 205, 232, 38, 8, 113, 226];
+}
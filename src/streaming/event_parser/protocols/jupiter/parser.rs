@@ -0,0 +1,530 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::{
+    common::{utils::*, EventMetadata, EventType},
+    protocols::jupiter::{
+        discriminators, jupiter_swap_event_log_decode, JupiterRouteEvent, JupiterSwapEvent,
+    },
+    DexEvent,
+};
+
+/// Jupiter Aggregator v6 Program ID
+pub const JUPITER_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+
+/// 路由指令的参数布局
+///
+/// route_plan 中 Swap 枚举的各变体长度不一，这里只读取开头的步数，其余定长参数从末尾读取；
+/// v2 的 route_plan 位于参数末尾，定长参数从开头读取
+#[derive(Clone, Copy)]
+enum RouteArgs {
+    /// in_amount, quoted_out_amount, slippage_bps, platform_fee_bps
+    ExactIn,
+    /// quoted_out_amount, slippage_bps, platform_fee_bps
+    TokenLedger,
+    /// out_amount, quoted_in_amount, slippage_bps, platform_fee_bps
+    ExactOut,
+}
+
+impl RouteArgs {
+    fn tail_len(self) -> usize {
+        match self {
+            RouteArgs::ExactIn | RouteArgs::ExactOut => 19,
+            RouteArgs::TokenLedger => 11,
+        }
+    }
+}
+
+/// 解析 Jupiter instruction data
+///
+/// 根据判别器路由到具体的 instruction 解析函数
+pub fn parse_jupiter_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::ROUTE => parse_route_instruction(
+            EventType::JupiterRoute,
+            RouteArgs::ExactIn,
+            data,
+            accounts,
+            metadata,
+        ),
+        discriminators::ROUTE_WITH_TOKEN_LEDGER => parse_route_instruction(
+            EventType::JupiterRouteWithTokenLedger,
+            RouteArgs::TokenLedger,
+            data,
+            accounts,
+            metadata,
+        ),
+        discriminators::EXACT_OUT_ROUTE => {
+            parse_exact_out_route_instruction(data, accounts, metadata)
+        }
+        discriminators::SHARED_ACCOUNTS_ROUTE => parse_shared_accounts_route_instruction(
+            EventType::JupiterSharedAccountsRoute,
+            RouteArgs::ExactIn,
+            data,
+            accounts,
+            metadata,
+        ),
+        discriminators::SHARED_ACCOUNTS_ROUTE_WITH_TOKEN_LEDGER => {
+            parse_shared_accounts_route_instruction(
+                EventType::JupiterSharedAccountsRouteWithTokenLedger,
+                RouteArgs::TokenLedger,
+                data,
+                accounts,
+                metadata,
+            )
+        }
+        discriminators::SHARED_ACCOUNTS_EXACT_OUT_ROUTE => parse_shared_accounts_route_instruction(
+            EventType::JupiterSharedAccountsExactOutRoute,
+            RouteArgs::ExactOut,
+            data,
+            accounts,
+            metadata,
+        ),
+        discriminators::ROUTE_V2 => parse_route_v2_instruction(
+            EventType::JupiterRouteV2,
+            RouteArgs::ExactIn,
+            data,
+            accounts,
+            metadata,
+        ),
+        discriminators::EXACT_OUT_ROUTE_V2 => parse_route_v2_instruction(
+            EventType::JupiterExactOutRouteV2,
+            RouteArgs::ExactOut,
+            data,
+            accounts,
+            metadata,
+        ),
+        discriminators::SHARED_ACCOUNTS_ROUTE_V2 => parse_shared_accounts_route_v2_instruction(
+            EventType::JupiterSharedAccountsRouteV2,
+            RouteArgs::ExactIn,
+            data,
+            accounts,
+            metadata,
+        ),
+        discriminators::SHARED_ACCOUNTS_EXACT_OUT_ROUTE_V2 => {
+            parse_shared_accounts_route_v2_instruction(
+                EventType::JupiterSharedAccountsExactOutRouteV2,
+                RouteArgs::ExactOut,
+                data,
+                accounts,
+                metadata,
+            )
+        }
+        _ => None,
+    }
+}
+
+/// 解析 Jupiter inner instruction data（emit_cpi! 事件）
+pub fn parse_jupiter_inner_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::SWAP_EVENT => parse_swap_inner_instruction(data, metadata),
+        _ => None,
+    }
+}
+
+/// 读取 route_plan 步数与末尾的定长参数
+fn read_route_args(data: &[u8], args: RouteArgs, event: &mut JupiterRouteEvent) -> Option<()> {
+    let route_plan_len = read_u32_le(data, 0)?;
+    let tail = data.len().checked_sub(args.tail_len())?;
+    // 每一步至少 4 字节（Swap 变体下标 + percent + input_index + output_index）
+    if (route_plan_len as usize).checked_mul(4)? > tail.checked_sub(4)? {
+        return None;
+    }
+    event.route_plan_len = route_plan_len;
+
+    let mut offset = tail;
+    match args {
+        RouteArgs::ExactIn => {
+            event.in_amount = read_u64_le(data, offset)?;
+            event.quoted_out_amount = read_u64_le(data, offset + 8)?;
+            offset += 16;
+        }
+        RouteArgs::TokenLedger => {
+            event.quoted_out_amount = read_u64_le(data, offset)?;
+            offset += 8;
+        }
+        RouteArgs::ExactOut => {
+            event.out_amount = read_u64_le(data, offset)?;
+            event.quoted_in_amount = read_u64_le(data, offset + 8)?;
+            offset += 16;
+        }
+    }
+    event.slippage_bps = read_u16_le(data, offset)?;
+    event.platform_fee_bps = read_u8(data, offset + 2)?.into();
+    Some(())
+}
+
+/// 读取 v2 开头的定长参数与末尾 route_plan 的步数，v2 没有 token ledger 变体
+fn read_route_v2_args(data: &[u8], args: RouteArgs, event: &mut JupiterRouteEvent) -> Option<()> {
+    match args {
+        RouteArgs::ExactIn => {
+            event.in_amount = read_u64_le(data, 0)?;
+            event.quoted_out_amount = read_u64_le(data, 8)?;
+        }
+        RouteArgs::ExactOut => {
+            event.out_amount = read_u64_le(data, 0)?;
+            event.quoted_in_amount = read_u64_le(data, 8)?;
+        }
+        RouteArgs::TokenLedger => return None,
+    }
+    event.slippage_bps = read_u16_le(data, 16)?;
+    event.platform_fee_bps = read_u16_le(data, 18)?;
+    event.positive_slippage_bps = read_u16_le(data, 20)?;
+
+    let route_plan_len = read_u32_le(data, 22)?;
+    // 每一步至少 5 字节（Swap 变体下标 + bps + input_index + output_index）
+    if (route_plan_len as usize).checked_mul(5)? > data.len().checked_sub(26)? {
+        return None;
+    }
+    event.route_plan_len = route_plan_len;
+    Some(())
+}
+
+/// 解析 route / route_with_token_ledger 指令
+fn parse_route_instruction(
+    event_type: EventType,
+    args: RouteArgs,
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = event_type;
+
+    if accounts.len() < 7 {
+        return None;
+    }
+
+    let mut event = JupiterRouteEvent {
+        metadata,
+        user_transfer_authority: accounts[1],
+        source_token_account: accounts[2],
+        destination_token_account: accounts[3],
+        destination_mint: accounts[5],
+        platform_fee_account: accounts[6],
+        ..Default::default()
+    };
+    read_route_args(data, args, &mut event)?;
+    Some(DexEvent::JupiterRouteEvent(event))
+}
+
+/// 解析 exact_out_route 指令
+fn parse_exact_out_route_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::JupiterExactOutRoute;
+
+    if accounts.len() < 8 {
+        return None;
+    }
+
+    let mut event = JupiterRouteEvent {
+        metadata,
+        user_transfer_authority: accounts[1],
+        source_token_account: accounts[2],
+        destination_token_account: accounts[3],
+        source_mint: accounts[5],
+        destination_mint: accounts[6],
+        platform_fee_account: accounts[7],
+        ..Default::default()
+    };
+    read_route_args(data, RouteArgs::ExactOut, &mut event)?;
+    Some(DexEvent::JupiterRouteEvent(event))
+}
+
+/// 解析 shared_accounts_* 路由指令，数据以 1 字节 id 开头
+fn parse_shared_accounts_route_instruction(
+    event_type: EventType,
+    args: RouteArgs,
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = event_type;
+
+    if accounts.len() < 10 {
+        return None;
+    }
+
+    let mut event = JupiterRouteEvent {
+        metadata,
+        id: Some(read_u8(data, 0)?),
+        user_transfer_authority: accounts[2],
+        source_token_account: accounts[3],
+        destination_token_account: accounts[6],
+        source_mint: accounts[7],
+        destination_mint: accounts[8],
+        platform_fee_account: accounts[9],
+        ..Default::default()
+    };
+    read_route_args(&data[1..], args, &mut event)?;
+    Some(DexEvent::JupiterRouteEvent(event))
+}
+
+/// 解析 route_v2 / exact_out_route_v2 指令
+fn parse_route_v2_instruction(
+    event_type: EventType,
+    args: RouteArgs,
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = event_type;
+
+    if accounts.len() < 5 {
+        return None;
+    }
+
+    let mut event = JupiterRouteEvent {
+        metadata,
+        user_transfer_authority: accounts[0],
+        source_token_account: accounts[1],
+        destination_token_account: accounts[2],
+        source_mint: accounts[3],
+        destination_mint: accounts[4],
+        ..Default::default()
+    };
+    read_route_v2_args(data, args, &mut event)?;
+    Some(DexEvent::JupiterRouteEvent(event))
+}
+
+/// 解析 shared_accounts_*_v2 路由指令，数据以 1 字节 id 开头
+fn parse_shared_accounts_route_v2_instruction(
+    event_type: EventType,
+    args: RouteArgs,
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = event_type;
+
+    if accounts.len() < 8 {
+        return None;
+    }
+
+    let mut event = JupiterRouteEvent {
+        metadata,
+        id: Some(read_u8(data, 0)?),
+        user_transfer_authority: accounts[1],
+        source_token_account: accounts[2],
+        destination_token_account: accounts[5],
+        source_mint: accounts[6],
+        destination_mint: accounts[7],
+        ..Default::default()
+    };
+    read_route_v2_args(&data[1..], args, &mut event)?;
+    Some(DexEvent::JupiterRouteEvent(event))
+}
+
+/// 解析 SwapEvent 事件
+fn parse_swap_inner_instruction(data: &[u8], mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::JupiterSwap;
+    jupiter_swap_event_log_decode(data)
+        .map(|event| DexEvent::JupiterSwapEvent(JupiterSwapEvent { metadata, ..event }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        common::RouteRef,
+        core::{
+            merger_event::merge,
+            transaction_input::fixtures::{
+                account_index, inner_instruction, inner_instructions_meta, parse, transaction,
+                unique_pubkeys,
+            },
+        },
+        protocols::spl_token::{self, parser::SPL_TOKEN_PROGRAM_ID},
+        Protocol,
+    };
+
+    fn swap_event(amm: &Pubkey, input: (Pubkey, u64), output: (Pubkey, u64)) -> Vec<u8> {
+        let mut data = discriminators::SWAP_EVENT.to_vec();
+        data.extend_from_slice(amm.as_ref());
+        data.extend_from_slice(input.0.as_ref());
+        data.extend_from_slice(&input.1.to_le_bytes());
+        data.extend_from_slice(output.0.as_ref());
+        data.extend_from_slice(&output.1.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_route_merges_hops_and_links_child_events() {
+        let mut accounts = unique_pubkeys(9);
+        accounts[0] = SPL_TOKEN_PROGRAM_ID;
+        let (source_mint, middle_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let destination_mint = accounts[5];
+
+        let mut data = discriminators::ROUTE.to_vec();
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(&[0, 100, 0, 1, 0, 100, 1, 2]);
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&6_900u64.to_le_bytes());
+        data.extend_from_slice(&50u16.to_le_bytes());
+        data.push(0);
+        let tx = transaction(JUPITER_PROGRAM_ID, &data, &accounts);
+        let index_of = |key: &Pubkey| account_index(&tx, key);
+        let (jupiter, token) = (index_of(&JUPITER_PROGRAM_ID), index_of(&SPL_TOKEN_PROGRAM_ID));
+        let transfer_accounts =
+            vec![index_of(&accounts[2]), index_of(&accounts[3]), index_of(&accounts[1])];
+        let transfer = |amount: u64| {
+            let mut data = spl_token::discriminators::TRANSFER.to_vec();
+            data.extend_from_slice(&amount.to_le_bytes());
+            inner_instruction(token, data, transfer_accounts.clone())
+        };
+        let (amm_one, amm_two) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let meta = inner_instructions_meta(vec![
+            transfer(1_000),
+            inner_instruction(
+                jupiter,
+                swap_event(&amm_one, (source_mint, 1_000), (middle_mint, 50)),
+                vec![],
+            ),
+            transfer(50),
+            inner_instruction(
+                jupiter,
+                swap_event(&amm_two, (middle_mint, 50), (destination_mint, 7_000)),
+                vec![],
+            ),
+            // 最后一跳之后的平台费转账不属于任何一跳
+            transfer(7),
+        ]);
+
+        let events = parse(&[Protocol::Jupiter, Protocol::SplToken], &tx, &meta);
+
+        assert_eq!(events.len(), 4);
+        let DexEvent::JupiterRouteEvent(route) = &events[0] else { panic!("expected route") };
+        assert_eq!(route.metadata.event_type, EventType::JupiterRoute);
+        assert_eq!(
+            (route.route_plan_len, route.quoted_out_amount, route.slippage_bps),
+            (2, 6_900, 50)
+        );
+        assert_eq!(
+            (route.source_mint, route.in_amount, route.out_amount),
+            (source_mint, 1_000, 7_000)
+        );
+        let hops: Vec<_> = route.hops.iter().map(|hop| (hop.amm, hop.inner_index)).collect();
+        assert_eq!(hops, vec![(amm_one, Some(1)), (amm_two, Some(3))]);
+
+        let parent = RouteRef { program_id: JUPITER_PROGRAM_ID, outer_index: 0, inner_index: None };
+        let parents: Vec<_> = events[1..].iter().map(|e| e.metadata().parent_route).collect();
+        assert_eq!(parents, vec![Some(parent), Some(parent), None]);
+    }
+
+    #[test]
+    fn test_exact_out_route_keeps_instruction_out_amount() {
+        let accounts = unique_pubkeys(8);
+        let (source_mint, destination_mint) = (accounts[5], accounts[6]);
+        let mut data = 1u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&[0, 100, 0, 1]);
+        data.extend_from_slice(&5_000u64.to_le_bytes());
+        data.extend_from_slice(&1_100u64.to_le_bytes());
+        data.extend_from_slice(&50u16.to_le_bytes());
+        data.push(0);
+        let mut event = parse_jupiter_instruction_data(
+            discriminators::EXACT_OUT_ROUTE,
+            &data,
+            &accounts,
+            EventMetadata::default(),
+        )
+        .unwrap();
+
+        // 各跳累加的输出与指令中的 out_amount 不一致时，以指令为准
+        let swap = parse_jupiter_inner_instruction_data(
+            discriminators::SWAP_EVENT,
+            &swap_event(&Pubkey::new_unique(), (source_mint, 1_040), (destination_mint, 5_010))
+                [16..],
+            EventMetadata::default(),
+        )
+        .unwrap();
+        merge(&mut event, swap);
+
+        let DexEvent::JupiterRouteEvent(route) = &event else { panic!("expected route") };
+        assert_eq!((route.in_amount, route.quoted_in_amount), (1_040, 1_100));
+        assert_eq!(route.out_amount, 5_000);
+        let swap_data = route.metadata.swap_data.as_ref().unwrap();
+        assert_eq!((swap_data.from_amount, swap_data.to_amount), (1_040, 5_000));
+    }
+
+    #[test]
+    fn test_v2_routes_read_leading_args() {
+        let route_v2_data = |exact_amount: u64, quoted_amount: u64| {
+            let mut data = exact_amount.to_le_bytes().to_vec();
+            data.extend_from_slice(&quoted_amount.to_le_bytes());
+            data.extend_from_slice(&50u16.to_le_bytes());
+            data.extend_from_slice(&300u16.to_le_bytes());
+            data.extend_from_slice(&10u16.to_le_bytes());
+            data.extend_from_slice(&1u32.to_le_bytes());
+            data.extend_from_slice(&[0, 16, 39, 0, 1]);
+            data
+        };
+        let swap = |input: (Pubkey, u64), output: (Pubkey, u64)| {
+            parse_jupiter_inner_instruction_data(
+                discriminators::SWAP_EVENT,
+                &swap_event(&Pubkey::new_unique(), input, output)[16..],
+                EventMetadata::default(),
+            )
+            .unwrap()
+        };
+
+        let accounts = unique_pubkeys(10);
+        let mut event = parse_jupiter_instruction_data(
+            discriminators::ROUTE_V2,
+            &route_v2_data(1_000, 6_900),
+            &accounts,
+            EventMetadata::default(),
+        )
+        .unwrap();
+        merge(&mut event, swap((accounts[3], 1_000), (accounts[4], 7_000)));
+        let DexEvent::JupiterRouteEvent(route) = &event else { panic!("expected route") };
+        assert_eq!(route.metadata.event_type, EventType::JupiterRouteV2);
+        assert_eq!((route.source_mint, route.destination_mint), (accounts[3], accounts[4]));
+        assert_eq!(
+            (route.slippage_bps, route.platform_fee_bps, route.positive_slippage_bps),
+            (50, 300, 10)
+        );
+        assert_eq!((route.route_plan_len, route.in_amount, route.out_amount), (1, 1_000, 7_000));
+
+        let accounts = unique_pubkeys(12);
+        let mut data = vec![3];
+        data.extend(route_v2_data(5_000, 1_100));
+        let mut event = parse_jupiter_instruction_data(
+            discriminators::SHARED_ACCOUNTS_EXACT_OUT_ROUTE_V2,
+            &data,
+            &accounts,
+            EventMetadata::default(),
+        )
+        .unwrap();
+        merge(&mut event, swap((accounts[6], 1_040), (accounts[7], 5_010)));
+        let DexEvent::JupiterRouteEvent(route) = &event else { panic!("expected route") };
+        assert_eq!(route.metadata.event_type, EventType::JupiterSharedAccountsExactOutRouteV2);
+        assert_eq!((route.id, route.user_transfer_authority), (Some(3), accounts[1]));
+        assert_eq!(
+            (route.in_amount, route.quoted_in_amount, route.out_amount),
+            (1_040, 1_100, 5_000)
+        );
+
+        // route_plan 长度超出数据时不发出事件
+        let mut truncated = route_v2_data(1_000, 6_900);
+        truncated[22..26].copy_from_slice(&2u32.to_le_bytes());
+        assert!(parse_jupiter_instruction_data(
+            discriminators::EXACT_OUT_ROUTE_V2,
+            &truncated,
+            &accounts,
+            EventMetadata::default(),
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,428 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::meteora_damm_v1::types::{
+    CurveType, LockEscrow, Pool,
+};
+
+/// 由 `idl/meteora_amm.json` 生成
+pub use crate::streaming::event_parser::idl::generated::meteora_amm::discriminators;
+
+/// Meteora DAMM v1 Swap Event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV1SwapEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 Swap 事件的数据
+    pub in_amount: u64,
+    pub out_amount: u64,
+    pub trade_fee: u64,
+    pub admin_fee: u64,
+    pub host_fee: u64,
+
+    // 指令参数
+    #[borsh(skip)]
+    pub param_in_amount: u64,
+    #[borsh(skip)]
+    pub minimum_out_amount: u64,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub pool: Pubkey,
+    #[borsh(skip)]
+    pub user_source_token: Pubkey,
+    #[borsh(skip)]
+    pub user_destination_token: Pubkey,
+    #[borsh(skip)]
+    pub a_vault: Pubkey,
+    #[borsh(skip)]
+    pub b_vault: Pubkey,
+    #[borsh(skip)]
+    pub a_token_vault: Pubkey,
+    #[borsh(skip)]
+    pub b_token_vault: Pubkey,
+    #[borsh(skip)]
+    pub a_vault_lp_mint: Pubkey,
+    #[borsh(skip)]
+    pub b_vault_lp_mint: Pubkey,
+    #[borsh(skip)]
+    pub a_vault_lp: Pubkey,
+    #[borsh(skip)]
+    pub b_vault_lp: Pubkey,
+    #[borsh(skip)]
+    pub admin_token_fee: Pubkey,
+    #[borsh(skip)]
+    pub user: Pubkey,
+}
+
+/// Meteora DAMM v1 Add Liquidity Event (addBalanceLiquidity / addImbalanceLiquidity)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV1AddLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 AddLiquidity 事件的数据
+    pub lp_mint_amount: u64,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+
+    // 指令参数
+    // addBalanceLiquidity: pool_token_amount 为精确值，token_a/b 为上限
+    // addImbalanceLiquidity: pool_token_amount 为下限，token_a/b 为精确值
+    #[borsh(skip)]
+    pub param_pool_token_amount: u64,
+    #[borsh(skip)]
+    pub param_token_a_amount: u64,
+    #[borsh(skip)]
+    pub param_token_b_amount: u64,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub pool: Pubkey,
+    #[borsh(skip)]
+    pub lp_mint: Pubkey,
+    #[borsh(skip)]
+    pub user_pool_lp: Pubkey,
+    #[borsh(skip)]
+    pub a_vault_lp: Pubkey,
+    #[borsh(skip)]
+    pub b_vault_lp: Pubkey,
+    #[borsh(skip)]
+    pub a_vault: Pubkey,
+    #[borsh(skip)]
+    pub b_vault: Pubkey,
+    #[borsh(skip)]
+    pub a_token_vault: Pubkey,
+    #[borsh(skip)]
+    pub b_token_vault: Pubkey,
+    #[borsh(skip)]
+    pub user_a_token: Pubkey,
+    #[borsh(skip)]
+    pub user_b_token: Pubkey,
+    #[borsh(skip)]
+    pub user: Pubkey,
+}
+
+/// Meteora DAMM v1 Remove Liquidity Event (removeBalanceLiquidity / removeLiquiditySingleSide)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV1RemoveLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 RemoveLiquidity 事件的数据
+    pub lp_unmint_amount: u64,
+    pub token_a_out_amount: u64,
+    pub token_b_out_amount: u64,
+
+    // 指令参数
+    #[borsh(skip)]
+    pub pool_token_amount: u64,
+    #[borsh(skip)]
+    pub minimum_a_token_out: u64,
+    #[borsh(skip)]
+    pub minimum_b_token_out: u64,
+    /// 仅 removeLiquiditySingleSide
+    #[borsh(skip)]
+    pub minimum_out_amount: u64,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub pool: Pubkey,
+    #[borsh(skip)]
+    pub lp_mint: Pubkey,
+    #[borsh(skip)]
+    pub user_pool_lp: Pubkey,
+    #[borsh(skip)]
+    pub a_vault_lp: Pubkey,
+    #[borsh(skip)]
+    pub b_vault_lp: Pubkey,
+    #[borsh(skip)]
+    pub a_vault: Pubkey,
+    #[borsh(skip)]
+    pub b_vault: Pubkey,
+    #[borsh(skip)]
+    pub a_token_vault: Pubkey,
+    #[borsh(skip)]
+    pub b_token_vault: Pubkey,
+    /// 仅 removeBalanceLiquidity
+    #[borsh(skip)]
+    pub user_a_token: Pubkey,
+    /// 仅 removeBalanceLiquidity
+    #[borsh(skip)]
+    pub user_b_token: Pubkey,
+    /// 仅 removeLiquiditySingleSide
+    #[borsh(skip)]
+    pub user_destination_token: Pubkey,
+    #[borsh(skip)]
+    pub user: Pubkey,
+}
+
+/// Meteora DAMM v1 Bootstrap Liquidity Event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV1BootstrapLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 BootstrapLiquidity 事件的数据
+    pub lp_mint_amount: u64,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub pool: Pubkey,
+
+    // 指令参数
+    #[borsh(skip)]
+    pub param_token_a_amount: u64,
+    #[borsh(skip)]
+    pub param_token_b_amount: u64,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub lp_mint: Pubkey,
+    #[borsh(skip)]
+    pub user_pool_lp: Pubkey,
+    #[borsh(skip)]
+    pub a_vault: Pubkey,
+    #[borsh(skip)]
+    pub b_vault: Pubkey,
+    #[borsh(skip)]
+    pub a_token_vault: Pubkey,
+    #[borsh(skip)]
+    pub b_token_vault: Pubkey,
+    #[borsh(skip)]
+    pub user_a_token: Pubkey,
+    #[borsh(skip)]
+    pub user_b_token: Pubkey,
+    #[borsh(skip)]
+    pub user: Pubkey,
+}
+
+/// Meteora DAMM v1 Initialize Pool Event
+/// (initializePermissionedPool / initializePermissionlessPool / initializePermissionlessPoolWithFeeTier)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV1InitializePoolEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 PoolCreated 事件的数据
+    pub lp_mint: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub pool_type: u8, // 0: Permissioned, 1: Permissionless
+    pub pool: Pubkey,
+
+    // 指令参数
+    #[borsh(skip)]
+    pub curve_type: CurveType,
+    /// 仅 permissionless
+    #[borsh(skip)]
+    pub token_a_amount: u64,
+    /// 仅 permissionless
+    #[borsh(skip)]
+    pub token_b_amount: u64,
+    /// 仅 initializePermissionlessPoolWithFeeTier
+    #[borsh(skip)]
+    pub trade_fee_bps: u64,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub a_vault: Pubkey,
+    #[borsh(skip)]
+    pub b_vault: Pubkey,
+    /// 仅 permissionless
+    #[borsh(skip)]
+    pub a_token_vault: Pubkey,
+    /// 仅 permissionless
+    #[borsh(skip)]
+    pub b_token_vault: Pubkey,
+    #[borsh(skip)]
+    pub a_vault_lp: Pubkey,
+    #[borsh(skip)]
+    pub b_vault_lp: Pubkey,
+    #[borsh(skip)]
+    pub payer_token_a: Pubkey,
+    #[borsh(skip)]
+    pub payer_token_b: Pubkey,
+    #[borsh(skip)]
+    pub payer_pool_lp: Pubkey,
+    #[borsh(skip)]
+    pub admin_token_a_fee: Pubkey,
+    #[borsh(skip)]
+    pub admin_token_b_fee: Pubkey,
+    /// permissioned 池为 admin
+    #[borsh(skip)]
+    pub payer: Pubkey,
+    #[borsh(skip)]
+    pub fee_owner: Pubkey,
+}
+
+/// Meteora DAMM v1 Lock Event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV1LockEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 Lock 事件的数据
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub lp_mint: Pubkey,
+    #[borsh(skip)]
+    pub lock_escrow: Pubkey,
+    #[borsh(skip)]
+    pub source_tokens: Pubkey,
+    #[borsh(skip)]
+    pub escrow_vault: Pubkey,
+}
+
+/// Meteora DAMM v1 Claim Fee Event
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV1ClaimFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 ClaimFee 事件的数据
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub a_fee: u64,
+    pub b_fee: u64,
+
+    // 指令参数
+    #[borsh(skip)]
+    pub max_amount: u64,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub lp_mint: Pubkey,
+    #[borsh(skip)]
+    pub lock_escrow: Pubkey,
+    #[borsh(skip)]
+    pub source_tokens: Pubkey,
+    #[borsh(skip)]
+    pub escrow_vault: Pubkey,
+    #[borsh(skip)]
+    pub a_token_vault: Pubkey,
+    #[borsh(skip)]
+    pub b_token_vault: Pubkey,
+    #[borsh(skip)]
+    pub user_a_token: Pubkey,
+    #[borsh(skip)]
+    pub user_b_token: Pubkey,
+}
+
+/// Pool 账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV1PoolAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub pool: Pool,
+}
+
+/// LockEscrow 账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV1LockEscrowAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub lock_escrow: LockEscrow,
+}
+
+/// Decode Swap event
+pub const METEORA_DAMM_V1_SWAP_EVENT_LOG_SIZE: usize = 40;
+pub fn meteora_damm_v1_swap_event_decode(data: &[u8]) -> Option<MeteoraDammV1SwapEvent> {
+    if data.len() < METEORA_DAMM_V1_SWAP_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV1SwapEvent>(&data[..METEORA_DAMM_V1_SWAP_EVENT_LOG_SIZE]).ok()
+}
+
+/// Decode AddLiquidity event
+pub const METEORA_DAMM_V1_LIQUIDITY_EVENT_LOG_SIZE: usize = 24;
+pub fn meteora_damm_v1_add_liquidity_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDammV1AddLiquidityEvent> {
+    if data.len() < METEORA_DAMM_V1_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV1AddLiquidityEvent>(
+        &data[..METEORA_DAMM_V1_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// Decode RemoveLiquidity event
+pub fn meteora_damm_v1_remove_liquidity_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDammV1RemoveLiquidityEvent> {
+    if data.len() < METEORA_DAMM_V1_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV1RemoveLiquidityEvent>(
+        &data[..METEORA_DAMM_V1_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// Decode BootstrapLiquidity event
+pub const METEORA_DAMM_V1_BOOTSTRAP_LIQUIDITY_EVENT_LOG_SIZE: usize = 56;
+pub fn meteora_damm_v1_bootstrap_liquidity_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDammV1BootstrapLiquidityEvent> {
+    if data.len() < METEORA_DAMM_V1_BOOTSTRAP_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV1BootstrapLiquidityEvent>(
+        &data[..METEORA_DAMM_V1_BOOTSTRAP_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// Decode PoolCreated event
+pub const METEORA_DAMM_V1_POOL_CREATED_EVENT_LOG_SIZE: usize = 129;
+pub fn meteora_damm_v1_pool_created_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDammV1InitializePoolEvent> {
+    if data.len() < METEORA_DAMM_V1_POOL_CREATED_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV1InitializePoolEvent>(
+        &data[..METEORA_DAMM_V1_POOL_CREATED_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// Decode Lock event
+pub const METEORA_DAMM_V1_LOCK_EVENT_LOG_SIZE: usize = 72;
+pub fn meteora_damm_v1_lock_event_decode(data: &[u8]) -> Option<MeteoraDammV1LockEvent> {
+    if data.len() < METEORA_DAMM_V1_LOCK_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV1LockEvent>(&data[..METEORA_DAMM_V1_LOCK_EVENT_LOG_SIZE]).ok()
+}
+
+/// Decode ClaimFee event
+pub const METEORA_DAMM_V1_CLAIM_FEE_EVENT_LOG_SIZE: usize = 88;
+pub fn meteora_damm_v1_claim_fee_event_decode(data: &[u8]) -> Option<MeteoraDammV1ClaimFeeEvent> {
+    if data.len() < METEORA_DAMM_V1_CLAIM_FEE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV1ClaimFeeEvent>(
+        &data[..METEORA_DAMM_V1_CLAIM_FEE_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
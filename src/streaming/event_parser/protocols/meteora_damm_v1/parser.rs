@@ -0,0 +1,602 @@
+use borsh::BorshDeserialize;
+
+use crate::streaming::event_parser::protocols::meteora_damm_v1::types::{
+    lock_escrow_parser, pool_parser, CurveType,
+};
+use crate::streaming::event_parser::{
+    common::{read_u64_le, EventMetadata, EventType},
+    protocols::meteora_damm_v1::{
+        discriminators, meteora_damm_v1_add_liquidity_event_decode,
+        meteora_damm_v1_bootstrap_liquidity_event_decode, meteora_damm_v1_claim_fee_event_decode,
+        meteora_damm_v1_lock_event_decode, meteora_damm_v1_pool_created_event_decode,
+        meteora_damm_v1_remove_liquidity_event_decode, meteora_damm_v1_swap_event_decode,
+        MeteoraDammV1AddLiquidityEvent, MeteoraDammV1BootstrapLiquidityEvent,
+        MeteoraDammV1ClaimFeeEvent, MeteoraDammV1InitializePoolEvent, MeteoraDammV1LockEvent,
+        MeteoraDammV1RemoveLiquidityEvent, MeteoraDammV1SwapEvent,
+    },
+    DexEvent,
+};
+use crate::streaming::grpc::AccountPretty;
+use solana_sdk::pubkey::Pubkey;
+
+/// Meteora DAMM v1 (Dynamic AMM) 程序ID
+pub const METEORA_DAMM_V1_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB");
+
+/// 解析 Meteora DAMM v1 instruction data
+///
+/// 根据判别器路由到具体的 instruction 解析函数
+pub fn parse_meteora_damm_v1_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::SWAP_IX => parse_swap_instruction(data, accounts, metadata),
+        discriminators::ADD_BALANCE_LIQUIDITY_IX => parse_add_liquidity_instruction(
+            data,
+            accounts,
+            EventType::MeteoraDammV1AddBalanceLiquidity,
+            metadata,
+        ),
+        discriminators::ADD_IMBALANCE_LIQUIDITY_IX => parse_add_liquidity_instruction(
+            data,
+            accounts,
+            EventType::MeteoraDammV1AddImbalanceLiquidity,
+            metadata,
+        ),
+        discriminators::REMOVE_BALANCE_LIQUIDITY_IX => {
+            parse_remove_balance_liquidity_instruction(data, accounts, metadata)
+        }
+        discriminators::REMOVE_LIQUIDITY_SINGLE_SIDE_IX => {
+            parse_remove_liquidity_single_side_instruction(data, accounts, metadata)
+        }
+        discriminators::BOOTSTRAP_LIQUIDITY_IX => {
+            parse_bootstrap_liquidity_instruction(data, accounts, metadata)
+        }
+        discriminators::INITIALIZE_PERMISSIONED_POOL_IX => {
+            parse_initialize_permissioned_pool_instruction(data, accounts, metadata)
+        }
+        discriminators::INITIALIZE_PERMISSIONLESS_POOL_IX => {
+            parse_initialize_permissionless_pool_instruction(data, accounts, false, metadata)
+        }
+        discriminators::INITIALIZE_PERMISSIONLESS_POOL_WITH_FEE_TIER_IX => {
+            parse_initialize_permissionless_pool_instruction(data, accounts, true, metadata)
+        }
+        discriminators::LOCK_IX => parse_lock_instruction(data, accounts, metadata),
+        discriminators::CLAIM_FEE_IX => parse_claim_fee_instruction(data, accounts, metadata),
+        _ => None,
+    }
+}
+
+/// 解析 Meteora DAMM v1 inner instruction data
+///
+/// DAMM v1 通过 `emit!` 将事件写入 "Program data:" 日志，这里处理以 CPI 形式
+/// (EVENT_IX_TAG + 事件判别器) 出现的同名事件
+pub fn parse_meteora_damm_v1_inner_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::SWAP_EVENT => parse_swap_inner_instruction(data, metadata),
+        discriminators::ADD_LIQUIDITY_EVENT => {
+            parse_add_liquidity_inner_instruction(data, metadata)
+        }
+        discriminators::REMOVE_LIQUIDITY_EVENT => {
+            parse_remove_liquidity_inner_instruction(data, metadata)
+        }
+        discriminators::BOOTSTRAP_LIQUIDITY_EVENT => {
+            parse_bootstrap_liquidity_inner_instruction(data, metadata)
+        }
+        discriminators::POOL_CREATED_EVENT => parse_pool_created_inner_instruction(data, metadata),
+        discriminators::LOCK_EVENT => parse_lock_inner_instruction(data, metadata),
+        discriminators::CLAIM_FEE_EVENT => parse_claim_fee_inner_instruction(data, metadata),
+        _ => None,
+    }
+}
+
+/// 解析 "Program data:" 日志中的 Meteora DAMM v1 事件
+///
+/// `data` 为 base64 解码后的日志数据，以 8 字节事件判别器开头
+pub fn parse_meteora_damm_v1_log_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    if data.len() < 8 {
+        return None;
+    }
+    let mut discriminator = discriminators::SWAP_EVENT[..8].to_vec();
+    discriminator.extend_from_slice(&data[..8]);
+    parse_meteora_damm_v1_inner_instruction_data(&discriminator, &data[8..], metadata)
+}
+
+/// 解析 Meteora DAMM v1 账户数据
+///
+/// 根据判别器路由到具体的账户解析函数
+pub fn parse_meteora_damm_v1_account_data(
+    discriminator: &[u8],
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::POOL_ACCOUNT => pool_parser(account, metadata),
+        discriminators::LOCK_ESCROW_ACCOUNT => lock_escrow_parser(account, metadata),
+        _ => None,
+    }
+}
+
+/// 解析 swap 指令
+fn parse_swap_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV1Swap;
+
+    if accounts.len() < 13 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV1SwapEvent(MeteoraDammV1SwapEvent {
+        metadata,
+        param_in_amount: read_u64_le(data, 0)?,
+        minimum_out_amount: read_u64_le(data, 8)?,
+        pool: accounts[0],
+        user_source_token: accounts[1],
+        user_destination_token: accounts[2],
+        a_vault: accounts[3],
+        b_vault: accounts[4],
+        a_token_vault: accounts[5],
+        b_token_vault: accounts[6],
+        a_vault_lp_mint: accounts[7],
+        b_vault_lp_mint: accounts[8],
+        a_vault_lp: accounts[9],
+        b_vault_lp: accounts[10],
+        admin_token_fee: accounts[11],
+        user: accounts[12],
+        ..Default::default()
+    }))
+}
+
+/// 添加流动性类指令共用的账户布局
+fn add_liquidity_event_from_accounts(
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<MeteoraDammV1AddLiquidityEvent> {
+    if accounts.len() < 14 {
+        return None;
+    }
+    Some(MeteoraDammV1AddLiquidityEvent {
+        metadata,
+        pool: accounts[0],
+        lp_mint: accounts[1],
+        user_pool_lp: accounts[2],
+        a_vault_lp: accounts[3],
+        b_vault_lp: accounts[4],
+        a_vault: accounts[5],
+        b_vault: accounts[6],
+        a_token_vault: accounts[9],
+        b_token_vault: accounts[10],
+        user_a_token: accounts[11],
+        user_b_token: accounts[12],
+        user: accounts[13],
+        ..Default::default()
+    })
+}
+
+/// 解析 addBalanceLiquidity / addImbalanceLiquidity 指令，两者参数顺序相同
+fn parse_add_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    event_type: EventType,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = event_type;
+    let mut event = add_liquidity_event_from_accounts(accounts, metadata)?;
+    // balance: pool_token_amount, maximum_token_a_amount, maximum_token_b_amount
+    // imbalance: minimum_pool_token_amount, token_a_amount, token_b_amount
+    event.param_pool_token_amount = read_u64_le(data, 0)?;
+    event.param_token_a_amount = read_u64_le(data, 8)?;
+    event.param_token_b_amount = read_u64_le(data, 16)?;
+    Some(DexEvent::MeteoraDammV1AddLiquidityEvent(event))
+}
+
+/// 解析 removeBalanceLiquidity 指令
+fn parse_remove_balance_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV1RemoveBalanceLiquidity;
+
+    if accounts.len() < 14 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV1RemoveLiquidityEvent(MeteoraDammV1RemoveLiquidityEvent {
+        metadata,
+        pool_token_amount: read_u64_le(data, 0)?,
+        minimum_a_token_out: read_u64_le(data, 8)?,
+        minimum_b_token_out: read_u64_le(data, 16)?,
+        pool: accounts[0],
+        lp_mint: accounts[1],
+        user_pool_lp: accounts[2],
+        a_vault_lp: accounts[3],
+        b_vault_lp: accounts[4],
+        a_vault: accounts[5],
+        b_vault: accounts[6],
+        a_token_vault: accounts[9],
+        b_token_vault: accounts[10],
+        user_a_token: accounts[11],
+        user_b_token: accounts[12],
+        user: accounts[13],
+        ..Default::default()
+    }))
+}
+
+/// 解析 removeLiquiditySingleSide 指令
+fn parse_remove_liquidity_single_side_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV1RemoveLiquiditySingleSide;
+
+    if accounts.len() < 13 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV1RemoveLiquidityEvent(MeteoraDammV1RemoveLiquidityEvent {
+        metadata,
+        pool_token_amount: read_u64_le(data, 0)?,
+        minimum_out_amount: read_u64_le(data, 8)?,
+        pool: accounts[0],
+        lp_mint: accounts[1],
+        user_pool_lp: accounts[2],
+        a_vault_lp: accounts[3],
+        b_vault_lp: accounts[4],
+        a_vault: accounts[5],
+        b_vault: accounts[6],
+        a_token_vault: accounts[9],
+        b_token_vault: accounts[10],
+        user_destination_token: accounts[11],
+        user: accounts[12],
+        ..Default::default()
+    }))
+}
+
+/// 解析 bootstrapLiquidity 指令，账户布局与添加流动性一致
+fn parse_bootstrap_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV1BootstrapLiquidity;
+
+    if accounts.len() < 14 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV1BootstrapLiquidityEvent(MeteoraDammV1BootstrapLiquidityEvent {
+        metadata,
+        param_token_a_amount: read_u64_le(data, 0)?,
+        param_token_b_amount: read_u64_le(data, 8)?,
+        pool: accounts[0],
+        lp_mint: accounts[1],
+        user_pool_lp: accounts[2],
+        a_vault: accounts[5],
+        b_vault: accounts[6],
+        a_token_vault: accounts[9],
+        b_token_vault: accounts[10],
+        user_a_token: accounts[11],
+        user_b_token: accounts[12],
+        user: accounts[13],
+        ..Default::default()
+    }))
+}
+
+/// 解析 initializePermissionedPool 指令
+fn parse_initialize_permissioned_pool_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV1InitializePermissionedPool;
+
+    if accounts.len() < 17 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV1InitializePoolEvent(MeteoraDammV1InitializePoolEvent {
+        metadata,
+        curve_type: <CurveType as BorshDeserialize>::deserialize(&mut &data[..]).ok()?,
+        pool: accounts[0],
+        lp_mint: accounts[1],
+        token_a_mint: accounts[2],
+        token_b_mint: accounts[3],
+        a_vault: accounts[4],
+        b_vault: accounts[5],
+        a_vault_lp: accounts[8],
+        b_vault_lp: accounts[9],
+        payer_token_a: accounts[10],
+        payer_token_b: accounts[11],
+        payer_pool_lp: accounts[12],
+        admin_token_a_fee: accounts[13],
+        admin_token_b_fee: accounts[14],
+        payer: accounts[15],
+        fee_owner: accounts[16],
+        ..Default::default()
+    }))
+}
+
+/// 解析 initializePermissionlessPool / initializePermissionlessPoolWithFeeTier 指令
+///
+/// 参数以变长的 curve_type 开头，WithFeeTier 在其后多出 trade_fee_bps
+fn parse_initialize_permissionless_pool_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    with_fee_tier: bool,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = if with_fee_tier {
+        EventType::MeteoraDammV1InitializePermissionlessPoolWithFeeTier
+    } else {
+        EventType::MeteoraDammV1InitializePermissionlessPool
+    };
+
+    if accounts.len() < 19 {
+        return None;
+    }
+
+    let mut rest = data;
+    let curve_type = <CurveType as BorshDeserialize>::deserialize(&mut rest).ok()?;
+    let (trade_fee_bps, offset) = if with_fee_tier { (read_u64_le(rest, 0)?, 8) } else { (0, 0) };
+
+    Some(DexEvent::MeteoraDammV1InitializePoolEvent(MeteoraDammV1InitializePoolEvent {
+        metadata,
+        curve_type,
+        trade_fee_bps,
+        token_a_amount: read_u64_le(rest, offset)?,
+        token_b_amount: read_u64_le(rest, offset + 8)?,
+        pool: accounts[0],
+        lp_mint: accounts[1],
+        token_a_mint: accounts[2],
+        token_b_mint: accounts[3],
+        a_vault: accounts[4],
+        b_vault: accounts[5],
+        a_token_vault: accounts[6],
+        b_token_vault: accounts[7],
+        a_vault_lp: accounts[10],
+        b_vault_lp: accounts[11],
+        payer_token_a: accounts[12],
+        payer_token_b: accounts[13],
+        payer_pool_lp: accounts[14],
+        admin_token_a_fee: accounts[15],
+        admin_token_b_fee: accounts[16],
+        payer: accounts[17],
+        fee_owner: accounts[18],
+        pool_type: 1,
+    }))
+}
+
+/// 解析 lock 指令
+fn parse_lock_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV1Lock;
+
+    if accounts.len() < 6 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV1LockEvent(MeteoraDammV1LockEvent {
+        metadata,
+        amount: read_u64_le(data, 0)?,
+        pool: accounts[0],
+        lp_mint: accounts[1],
+        lock_escrow: accounts[2],
+        owner: accounts[3],
+        source_tokens: accounts[4],
+        escrow_vault: accounts[5],
+    }))
+}
+
+/// 解析 claimFee 指令
+fn parse_claim_fee_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV1ClaimFee;
+
+    if accounts.len() < 17 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV1ClaimFeeEvent(MeteoraDammV1ClaimFeeEvent {
+        metadata,
+        max_amount: read_u64_le(data, 0)?,
+        pool: accounts[0],
+        lp_mint: accounts[1],
+        lock_escrow: accounts[2],
+        owner: accounts[3],
+        source_tokens: accounts[4],
+        escrow_vault: accounts[5],
+        a_token_vault: accounts[7],
+        b_token_vault: accounts[8],
+        user_a_token: accounts[15],
+        user_b_token: accounts[16],
+        ..Default::default()
+    }))
+}
+
+/// 解析 Swap 事件
+fn parse_swap_inner_instruction(data: &[u8], mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV1Swap;
+    meteora_damm_v1_swap_event_decode(data)
+        .map(|event| DexEvent::MeteoraDammV1SwapEvent(MeteoraDammV1SwapEvent { metadata, ..event }))
+}
+
+/// 解析 AddLiquidity 事件
+fn parse_add_liquidity_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV1AddBalanceLiquidity;
+    meteora_damm_v1_add_liquidity_event_decode(data).map(|event| {
+        DexEvent::MeteoraDammV1AddLiquidityEvent(MeteoraDammV1AddLiquidityEvent {
+            metadata,
+            ..event
+        })
+    })
+}
+
+/// 解析 RemoveLiquidity 事件
+fn parse_remove_liquidity_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV1RemoveBalanceLiquidity;
+    meteora_damm_v1_remove_liquidity_event_decode(data).map(|event| {
+        DexEvent::MeteoraDammV1RemoveLiquidityEvent(MeteoraDammV1RemoveLiquidityEvent {
+            metadata,
+            ..event
+        })
+    })
+}
+
+/// 解析 BootstrapLiquidity 事件
+fn parse_bootstrap_liquidity_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV1BootstrapLiquidity;
+    meteora_damm_v1_bootstrap_liquidity_event_decode(data).map(|event| {
+        DexEvent::MeteoraDammV1BootstrapLiquidityEvent(MeteoraDammV1BootstrapLiquidityEvent {
+            metadata,
+            ..event
+        })
+    })
+}
+
+/// 解析 PoolCreated 事件
+fn parse_pool_created_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV1InitializePermissionlessPool;
+    meteora_damm_v1_pool_created_event_decode(data).map(|event| {
+        DexEvent::MeteoraDammV1InitializePoolEvent(MeteoraDammV1InitializePoolEvent {
+            metadata,
+            ..event
+        })
+    })
+}
+
+/// 解析 Lock 事件
+fn parse_lock_inner_instruction(data: &[u8], mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV1Lock;
+    meteora_damm_v1_lock_event_decode(data)
+        .map(|event| DexEvent::MeteoraDammV1LockEvent(MeteoraDammV1LockEvent { metadata, ..event }))
+}
+
+/// 解析 ClaimFee 事件
+fn parse_claim_fee_inner_instruction(data: &[u8], mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV1ClaimFee;
+    meteora_damm_v1_claim_fee_event_decode(data).map(|event| {
+        DexEvent::MeteoraDammV1ClaimFeeEvent(MeteoraDammV1ClaimFeeEvent { metadata, ..event })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::core::merger_event::merge;
+    use crate::streaming::event_parser::protocols::meteora_damm_v1::types::{
+        Depeg, TokenMultiplier,
+    };
+
+    #[test]
+    fn test_initialize_pool_with_fee_tier_reads_args_after_curve_type() {
+        let accounts: Vec<Pubkey> = (0..19).map(|_| Pubkey::new_unique()).collect();
+        // CurveType::Stable { amp, token_multiplier, depeg, last_amp_updated_timestamp }
+        let mut data = vec![1u8];
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.extend_from_slice(&1000u64.to_le_bytes());
+        data.push(9);
+        data.extend_from_slice(&[0u8; 16]);
+        data.push(2);
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&25u64.to_le_bytes());
+        data.extend_from_slice(&5_000u64.to_le_bytes());
+        data.extend_from_slice(&7_000u64.to_le_bytes());
+
+        let Some(DexEvent::MeteoraDammV1InitializePoolEvent(event)) =
+            parse_meteora_damm_v1_instruction_data(
+                discriminators::INITIALIZE_PERMISSIONLESS_POOL_WITH_FEE_TIER_IX,
+                &data,
+                &accounts,
+                EventMetadata::default(),
+            )
+        else {
+            panic!("expected initialize pool")
+        };
+        assert_eq!(
+            event.metadata.event_type,
+            EventType::MeteoraDammV1InitializePermissionlessPoolWithFeeTier
+        );
+        assert_eq!(
+            event.curve_type,
+            CurveType::Stable {
+                amp: 100,
+                token_multiplier: TokenMultiplier {
+                    token_a_multiplier: 1,
+                    token_b_multiplier: 1000,
+                    precision_factor: 9,
+                },
+                depeg: Depeg { base_virtual_price: 0, base_cache_updated: 0, depeg_type: 2 },
+                last_amp_updated_timestamp: 0,
+            }
+        );
+        assert_eq!(
+            (event.trade_fee_bps, event.token_a_amount, event.token_b_amount),
+            (25, 5_000, 7_000)
+        );
+        assert_eq!((event.payer, event.fee_owner), (accounts[17], accounts[18]));
+    }
+
+    #[test]
+    fn test_swap_merges_swap_event() {
+        let accounts: Vec<Pubkey> = (0..15).map(|_| Pubkey::new_unique()).collect();
+        let mut data = 1_000u64.to_le_bytes().to_vec();
+        data.extend_from_slice(&900u64.to_le_bytes());
+        let mut event = parse_meteora_damm_v1_instruction_data(
+            discriminators::SWAP_IX,
+            &data,
+            &accounts,
+            EventMetadata::default(),
+        )
+        .unwrap();
+
+        let mut log = discriminators::SWAP_EVENT.to_vec();
+        for amount in [1_000u64, 950, 3, 1, 0] {
+            log.extend_from_slice(&amount.to_le_bytes());
+        }
+        let cpi_event = parse_meteora_damm_v1_inner_instruction_data(
+            &log[..16],
+            &log[16..],
+            EventMetadata::default(),
+        )
+        .unwrap();
+        merge(&mut event, cpi_event);
+
+        let DexEvent::MeteoraDammV1SwapEvent(event) = event else { panic!("expected swap") };
+        assert_eq!((event.param_in_amount, event.minimum_out_amount), (1_000, 900));
+        assert_eq!((event.out_amount, event.trade_fee, event.admin_fee), (950, 3, 1));
+        assert_eq!((event.user, event.admin_token_fee), (accounts[12], accounts[11]));
+        let swap_data = event.metadata.swap_data.unwrap();
+        assert_eq!((swap_data.from_amount, swap_data.to_amount), (1_000, 950));
+    }
+}
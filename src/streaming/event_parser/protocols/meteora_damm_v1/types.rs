@@ -0,0 +1,144 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::{EventMetadata, EventType},
+        protocols::meteora_damm_v1::{
+            MeteoraDammV1LockEscrowAccountEvent, MeteoraDammV1PoolAccountEvent,
+        },
+        DexEvent,
+    },
+    grpc::AccountPretty,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TokenMultiplier {
+    pub token_a_multiplier: u64,
+    pub token_b_multiplier: u64,
+    pub precision_factor: u8,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Depeg {
+    pub base_virtual_price: u64,
+    pub base_cache_updated: u64,
+    pub depeg_type: u8, // 0: None, 1: Marinade, 2: Lido, 3: SplStake
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub enum CurveType {
+    #[default]
+    ConstantProduct,
+    Stable {
+        amp: u64,
+        token_multiplier: TokenMultiplier,
+        depeg: Depeg,
+        last_amp_updated_timestamp: u64,
+    },
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolFees {
+    pub trade_fee_numerator: u64,
+    pub trade_fee_denominator: u64,
+    pub owner_trade_fee_numerator: u64,
+    pub owner_trade_fee_denominator: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Padding {
+    pub padding0: [u8; 7],
+    pub padding: [u128; 29],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Pool {
+    pub lp_mint: Pubkey,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub a_vault: Pubkey,
+    pub b_vault: Pubkey,
+    pub a_vault_lp: Pubkey,
+    pub b_vault_lp: Pubkey,
+    pub a_vault_lp_bump: u8,
+    pub enabled: bool,
+    pub admin_token_a_fee: Pubkey,
+    pub admin_token_b_fee: Pubkey,
+    pub admin: Pubkey,
+    pub fees: PoolFees,
+    pub pool_type: u8, // 0: Permissioned, 1: Permissionless
+    pub stake: Pubkey,
+    pub total_locked_lp: u64,
+    pub padding: Padding,
+    pub curve_type: CurveType,
+}
+
+/// curve_type 为枚举，长度取决于曲线类型，这里只解码到其结尾，忽略账户剩余的空间
+pub fn pool_decode(data: &[u8]) -> Option<Pool> {
+    BorshDeserialize::deserialize(&mut &data[..]).ok()
+}
+
+pub fn pool_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountMeteoraDammV1Pool;
+
+    if account.data.len() < 8 {
+        return None;
+    }
+    pool_decode(&account.data[8..]).map(|pool| {
+        DexEvent::MeteoraDammV1PoolAccountEvent(MeteoraDammV1PoolAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            pool,
+        })
+    })
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LockEscrow {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub escrow_vault: Pubkey,
+    pub bump: u8,
+    pub total_locked_amount: u64,
+    pub lp_per_token: u128,
+    pub unclaimed_fee_pending: u64,
+    pub a_fee: u64,
+    pub b_fee: u64,
+}
+
+pub const LOCK_ESCROW_SIZE: usize = 32 * 3 + 1 + 8 + 16 + 8 * 3;
+
+pub fn lock_escrow_decode(data: &[u8]) -> Option<LockEscrow> {
+    if data.len() < LOCK_ESCROW_SIZE {
+        return None;
+    }
+    borsh::from_slice::<LockEscrow>(&data[..LOCK_ESCROW_SIZE]).ok()
+}
+
+pub fn lock_escrow_parser(
+    account: &AccountPretty,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountMeteoraDammV1LockEscrow;
+
+    if account.data.len() < LOCK_ESCROW_SIZE + 8 {
+        return None;
+    }
+    lock_escrow_decode(&account.data[8..LOCK_ESCROW_SIZE + 8]).map(|lock_escrow| {
+        DexEvent::MeteoraDammV1LockEscrowAccountEvent(MeteoraDammV1LockEscrowAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            lock_escrow,
+        })
+    })
+}
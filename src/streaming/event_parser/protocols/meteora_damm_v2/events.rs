@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
 use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::meteora_damm_v2::types::{Config, Pool, Position};
 
 /// Base fee parameters
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
@@ -374,8 +375,310 @@ pub struct MeteoraDammV2InitializePoolWithDynamicConfigEvent {
     pub config: Pubkey,
 }
 
+/// Meteora DAMM v2 Add Liquidity Event (对应 add_liquidity 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV2AddLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据 (EvtLiquidityChange)
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub transfer_fee_included_token_a_amount: u64,
+    pub transfer_fee_included_token_b_amount: u64,
+    pub reserve_a_amount: u64,
+    pub reserve_b_amount: u64,
+    pub liquidity_delta: u128,
+    pub token_a_amount_threshold: u64,
+    pub token_b_amount_threshold: u64,
+    pub change_type: u8, // 0: Add, 1: Remove
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub token_a_account: Pubkey,
+    #[borsh(skip)]
+    pub token_b_account: Pubkey,
+    #[borsh(skip)]
+    pub token_a_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_b_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_a_mint: Pubkey,
+    #[borsh(skip)]
+    pub token_b_mint: Pubkey,
+    #[borsh(skip)]
+    pub position_nft_account: Pubkey,
+    #[borsh(skip)]
+    pub token_a_program: Pubkey,
+    #[borsh(skip)]
+    pub token_b_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+/// Meteora DAMM v2 Remove Liquidity Event (对应 remove_liquidity / remove_all_liquidity 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV2RemoveLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据 (EvtLiquidityChange)
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub transfer_fee_included_token_a_amount: u64,
+    pub transfer_fee_included_token_b_amount: u64,
+    pub reserve_a_amount: u64,
+    pub reserve_b_amount: u64,
+    pub liquidity_delta: u128,
+    pub token_a_amount_threshold: u64,
+    pub token_b_amount_threshold: u64,
+    pub change_type: u8, // 0: Add, 1: Remove
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub pool_authority: Pubkey,
+    #[borsh(skip)]
+    pub token_a_account: Pubkey,
+    #[borsh(skip)]
+    pub token_b_account: Pubkey,
+    #[borsh(skip)]
+    pub token_a_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_b_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_a_mint: Pubkey,
+    #[borsh(skip)]
+    pub token_b_mint: Pubkey,
+    #[borsh(skip)]
+    pub position_nft_account: Pubkey,
+    #[borsh(skip)]
+    pub token_a_program: Pubkey,
+    #[borsh(skip)]
+    pub token_b_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+/// Meteora DAMM v2 Create Position Event (对应 create_position 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeteoraDammV2CreatePositionEvent {
+    pub metadata: EventMetadata,
+
+    // 来自 Input Accounts 的数据
+    pub owner: Pubkey,
+    pub position_nft_mint: Pubkey,
+    pub position_nft_account: Pubkey,
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub pool_authority: Pubkey,
+    pub payer: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub event_authority: Pubkey,
+    pub program: Pubkey,
+}
+
+/// Meteora DAMM v2 Close Position Event (对应 close_position 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeteoraDammV2ClosePositionEvent {
+    pub metadata: EventMetadata,
+
+    // 来自 Input Accounts 的数据
+    pub position_nft_mint: Pubkey,
+    pub position_nft_account: Pubkey,
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub pool_authority: Pubkey,
+    pub rent_receiver: Pubkey,
+    pub owner: Pubkey,
+    pub token_program: Pubkey,
+    pub event_authority: Pubkey,
+    pub program: Pubkey,
+}
+
+/// Meteora DAMM v2 Split Position Event (对应 split_position / split_position2 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeteoraDammV2SplitPositionEvent {
+    pub metadata: EventMetadata,
+
+    // split_position 参数 (百分比)
+    pub unlocked_liquidity_percentage: u8,
+    pub permanent_locked_liquidity_percentage: u8,
+    pub fee_a_percentage: u8,
+    pub fee_b_percentage: u8,
+    pub reward_0_percentage: u8,
+    pub reward_1_percentage: u8,
+    // split_position2 参数 (所有部分共用同一个分子)
+    pub numerator: u32,
+
+    // 来自 Input Accounts 的数据
+    pub pool: Pubkey,
+    pub first_position: Pubkey,
+    pub first_position_nft_account: Pubkey,
+    pub second_position: Pubkey,
+    pub second_position_nft_account: Pubkey,
+    pub first_owner: Pubkey,
+    pub second_owner: Pubkey,
+    pub event_authority: Pubkey,
+    pub program: Pubkey,
+}
+
+/// Meteora DAMM v2 Lock Position Event (对应 lock_position 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeteoraDammV2LockPositionEvent {
+    pub metadata: EventMetadata,
+
+    // VestingParameters
+    pub cliff_point: Option<u64>,
+    pub period_frequency: u64,
+    pub cliff_unlock_liquidity: u128,
+    pub liquidity_per_period: u128,
+    pub number_of_period: u16,
+
+    // 来自 Input Accounts 的数据
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub vesting: Pubkey,
+    pub position_nft_account: Pubkey,
+    pub owner: Pubkey,
+    pub payer: Pubkey,
+    pub system_program: Pubkey,
+    pub event_authority: Pubkey,
+    pub program: Pubkey,
+}
+
+/// Meteora DAMM v2 Permanent Lock Position Event (对应 permanent_lock_position 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeteoraDammV2PermanentLockPositionEvent {
+    pub metadata: EventMetadata,
+
+    pub permanent_lock_liquidity: u128,
+
+    // 来自 Input Accounts 的数据
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub position_nft_account: Pubkey,
+    pub owner: Pubkey,
+    pub event_authority: Pubkey,
+    pub program: Pubkey,
+}
+
+/// Meteora DAMM v2 Claim Position Fee Event (对应 claim_position_fee 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV2ClaimPositionFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据 (EvtClaimPositionFee)
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub fee_a_claimed: u64,
+    pub fee_b_claimed: u64,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub pool_authority: Pubkey,
+    #[borsh(skip)]
+    pub token_a_account: Pubkey,
+    #[borsh(skip)]
+    pub token_b_account: Pubkey,
+    #[borsh(skip)]
+    pub token_a_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_b_vault: Pubkey,
+    #[borsh(skip)]
+    pub token_a_mint: Pubkey,
+    #[borsh(skip)]
+    pub token_b_mint: Pubkey,
+    #[borsh(skip)]
+    pub position_nft_account: Pubkey,
+    #[borsh(skip)]
+    pub token_a_program: Pubkey,
+    #[borsh(skip)]
+    pub token_b_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+/// Meteora DAMM v2 Claim Reward Event (对应 claim_reward 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeteoraDammV2ClaimRewardEvent {
+    pub metadata: EventMetadata,
+
+    pub reward_index: u8,
+    pub skip_reward: u8,
+
+    // 来自 Input Accounts 的数据
+    pub pool_authority: Pubkey,
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub reward_vault: Pubkey,
+    pub reward_mint: Pubkey,
+    pub user_token_account: Pubkey,
+    pub position_nft_account: Pubkey,
+    pub owner: Pubkey,
+    pub token_program: Pubkey,
+    pub event_authority: Pubkey,
+    pub program: Pubkey,
+}
+
+/// Pool 账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV2PoolAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub pool: Pool,
+}
+
+/// Position 账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV2PositionAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub position: Position,
+}
+
+/// Config 账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDammV2ConfigAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub config: Config,
+}
+
 /// Event discriminators
 pub mod discriminators {
+    /// 其余指令、事件与账户判别器由 `idl/meteora_damm_v2.json` 生成
+    pub use crate::streaming::event_parser::idl::generated::meteora_damm_v2::discriminators::*;
+
     // Instruction discriminators
     // 从文档中提取的 instruction data 第一个 8 bytes
     pub const SWAP_IX: &[u8] = &[0xf8, 0xc6, 0x9e, 0x91, 0xe1, 0x75, 0x87, 0xc8]; // swap
@@ -415,3 +718,43 @@ pub fn meteora_damm_v2_initialize_pool_event_decode(
 ) -> Option<MeteoraDammV2InitializePoolEvent> {
     borsh::from_slice::<MeteoraDammV2InitializePoolEvent>(&data).ok()
 }
+
+/// Decode liquidity change event from CPI log
+pub const METEORA_DAMM_V2_LIQUIDITY_CHANGE_EVENT_LOG_SIZE: usize = 177;
+pub fn meteora_damm_v2_add_liquidity_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDammV2AddLiquidityEvent> {
+    if data.len() < METEORA_DAMM_V2_LIQUIDITY_CHANGE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV2AddLiquidityEvent>(
+        &data[..METEORA_DAMM_V2_LIQUIDITY_CHANGE_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+pub fn meteora_damm_v2_remove_liquidity_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDammV2RemoveLiquidityEvent> {
+    if data.len() < METEORA_DAMM_V2_LIQUIDITY_CHANGE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV2RemoveLiquidityEvent>(
+        &data[..METEORA_DAMM_V2_LIQUIDITY_CHANGE_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// Decode claim position fee event from CPI log
+pub const METEORA_DAMM_V2_CLAIM_POSITION_FEE_EVENT_LOG_SIZE: usize = 112;
+pub fn meteora_damm_v2_claim_position_fee_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDammV2ClaimPositionFeeEvent> {
+    if data.len() < METEORA_DAMM_V2_CLAIM_POSITION_FEE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDammV2ClaimPositionFeeEvent>(
+        &data[..METEORA_DAMM_V2_CLAIM_POSITION_FEE_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
@@ -1,13 +1,27 @@
-use crate::streaming::event_parser::{
-    common::{EventMetadata, EventType},
-    protocols::meteora_damm_v2::{
-        discriminators, meteora_damm_v2_initialize_pool_event_decode,
-        meteora_damm_v2_swap_event_decode, MeteoraDammV2InitializeCustomizablePoolEvent,
-        MeteoraDammV2InitializePoolEvent, MeteoraDammV2InitializePoolWithDynamicConfigEvent,
-        MeteoraDammV2Swap2Event, MeteoraDammV2SwapEvent,
+use crate::streaming::{
+    event_parser::{
+        common::{EventMetadata, EventType},
+        idl::generated::meteora_damm_v2::VestingParameters,
+        protocols::meteora_damm_v2::{
+            discriminators, meteora_damm_v2_add_liquidity_event_decode,
+            meteora_damm_v2_claim_position_fee_event_decode,
+            meteora_damm_v2_initialize_pool_event_decode,
+            meteora_damm_v2_remove_liquidity_event_decode, meteora_damm_v2_swap_event_decode,
+            types::{config_parser, pool_parser, position_parser},
+            MeteoraDammV2AddLiquidityEvent, MeteoraDammV2ClaimPositionFeeEvent,
+            MeteoraDammV2ClaimRewardEvent, MeteoraDammV2ClosePositionEvent,
+            MeteoraDammV2CreatePositionEvent, MeteoraDammV2InitializeCustomizablePoolEvent,
+            MeteoraDammV2InitializePoolEvent, MeteoraDammV2InitializePoolWithDynamicConfigEvent,
+            MeteoraDammV2LockPositionEvent, MeteoraDammV2PermanentLockPositionEvent,
+            MeteoraDammV2RemoveLiquidityEvent, MeteoraDammV2SplitPositionEvent,
+            MeteoraDammV2Swap2Event, MeteoraDammV2SwapEvent,
+            METEORA_DAMM_V2_LIQUIDITY_CHANGE_EVENT_LOG_SIZE,
+        },
+        DexEvent,
     },
-    DexEvent,
+    grpc::AccountPretty,
 };
+use borsh::BorshDeserialize;
 use solana_sdk::pubkey::Pubkey;
 
 /// Meteora DAMM v2 程序ID
@@ -35,6 +49,37 @@ pub fn parse_meteora_damm_v2_instruction_data(
         discriminators::INITIALIZE_POOL_WITH_DYNAMIC_CONFIG_IX => {
             parse_initialize_pool_with_dynamic_config_instruction(data, accounts, metadata)
         }
+        discriminators::ADD_LIQUIDITY_IX => {
+            parse_add_liquidity_instruction(data, accounts, metadata)
+        }
+        discriminators::REMOVE_LIQUIDITY_IX => {
+            parse_remove_liquidity_instruction(data, accounts, metadata)
+        }
+        discriminators::REMOVE_ALL_LIQUIDITY_IX => {
+            parse_remove_all_liquidity_instruction(data, accounts, metadata)
+        }
+        discriminators::CREATE_POSITION_IX => {
+            parse_create_position_instruction(data, accounts, metadata)
+        }
+        discriminators::CLOSE_POSITION_IX => {
+            parse_close_position_instruction(data, accounts, metadata)
+        }
+        discriminators::SPLIT_POSITION_IX => {
+            parse_split_position_instruction(data, accounts, metadata)
+        }
+        discriminators::SPLIT_POSITION2_IX => {
+            parse_split_position2_instruction(data, accounts, metadata)
+        }
+        discriminators::LOCK_POSITION_IX => {
+            parse_lock_position_instruction(data, accounts, metadata)
+        }
+        discriminators::PERMANENT_LOCK_POSITION_IX => {
+            parse_permanent_lock_position_instruction(data, accounts, metadata)
+        }
+        discriminators::CLAIM_POSITION_FEE_IX => {
+            parse_claim_position_fee_instruction(data, accounts, metadata)
+        }
+        discriminators::CLAIM_REWARD_IX => parse_claim_reward_instruction(data, accounts, metadata),
         _ => None,
     }
 }
@@ -52,6 +97,28 @@ pub fn parse_meteora_damm_v2_inner_instruction_data(
         discriminators::INITIALIZE_POOL_EVENT => {
             parse_initialize_pool_inner_instruction(data, metadata)
         }
+        discriminators::EVT_LIQUIDITY_CHANGE_EVENT => {
+            parse_liquidity_change_inner_instruction(data, metadata)
+        }
+        discriminators::EVT_CLAIM_POSITION_FEE_EVENT => {
+            parse_claim_position_fee_inner_instruction(data, metadata)
+        }
+        _ => None,
+    }
+}
+
+/// 解析 Meteora DAMM v2 账户数据
+///
+/// 根据判别器路由到具体的账户解析函数
+pub fn parse_meteora_damm_v2_account_data(
+    discriminator: &[u8],
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::POOL_ACCOUNT => pool_parser(account, metadata),
+        discriminators::POSITION_ACCOUNT => position_parser(account, metadata),
+        discriminators::CONFIG_ACCOUNT => config_parser(account, metadata),
         _ => None,
     }
 }
@@ -442,3 +509,432 @@ fn parse_initialize_pool_inner_instruction(
         None
     }
 }
+
+/// 解析 add_liquidity 指令
+fn parse_add_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2AddLiquidity;
+
+    if data.len() < 32 || accounts.len() < 14 {
+        return None;
+    }
+
+    // AddLiquidityParameters: liquidity_delta (u128) + token_a/b_amount_threshold (u64)
+    Some(DexEvent::MeteoraDammV2AddLiquidityEvent(MeteoraDammV2AddLiquidityEvent {
+        metadata,
+        liquidity_delta: u128::from_le_bytes(data[0..16].try_into().unwrap()),
+        token_a_amount_threshold: u64::from_le_bytes(data[16..24].try_into().unwrap()),
+        token_b_amount_threshold: u64::from_le_bytes(data[24..32].try_into().unwrap()),
+        pool: accounts[0],
+        position: accounts[1],
+        token_a_account: accounts[2],
+        token_b_account: accounts[3],
+        token_a_vault: accounts[4],
+        token_b_vault: accounts[5],
+        token_a_mint: accounts[6],
+        token_b_mint: accounts[7],
+        position_nft_account: accounts[8],
+        owner: accounts[9],
+        token_a_program: accounts[10],
+        token_b_program: accounts[11],
+        event_authority: accounts[12],
+        program: accounts[13],
+        ..Default::default()
+    }))
+}
+
+/// 解析 remove_liquidity 指令
+fn parse_remove_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2RemoveLiquidity;
+
+    if data.len() < 32 || accounts.len() < 15 {
+        return None;
+    }
+
+    // RemoveLiquidityParameters: liquidity_delta (u128) + token_a/b_amount_threshold (u64)
+    Some(DexEvent::MeteoraDammV2RemoveLiquidityEvent(MeteoraDammV2RemoveLiquidityEvent {
+        liquidity_delta: u128::from_le_bytes(data[0..16].try_into().unwrap()),
+        token_a_amount_threshold: u64::from_le_bytes(data[16..24].try_into().unwrap()),
+        token_b_amount_threshold: u64::from_le_bytes(data[24..32].try_into().unwrap()),
+        ..remove_liquidity_accounts(accounts, metadata)
+    }))
+}
+
+/// 解析 remove_all_liquidity 指令，liquidity_delta 需合并 EvtLiquidityChange 后才可得
+fn parse_remove_all_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2RemoveAllLiquidity;
+
+    if data.len() < 16 || accounts.len() < 15 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV2RemoveLiquidityEvent(MeteoraDammV2RemoveLiquidityEvent {
+        token_a_amount_threshold: u64::from_le_bytes(data[0..8].try_into().unwrap()),
+        token_b_amount_threshold: u64::from_le_bytes(data[8..16].try_into().unwrap()),
+        ..remove_liquidity_accounts(accounts, metadata)
+    }))
+}
+
+fn remove_liquidity_accounts(
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> MeteoraDammV2RemoveLiquidityEvent {
+    MeteoraDammV2RemoveLiquidityEvent {
+        metadata,
+        change_type: 1,
+        pool_authority: accounts[0],
+        pool: accounts[1],
+        position: accounts[2],
+        token_a_account: accounts[3],
+        token_b_account: accounts[4],
+        token_a_vault: accounts[5],
+        token_b_vault: accounts[6],
+        token_a_mint: accounts[7],
+        token_b_mint: accounts[8],
+        position_nft_account: accounts[9],
+        owner: accounts[10],
+        token_a_program: accounts[11],
+        token_b_program: accounts[12],
+        event_authority: accounts[13],
+        program: accounts[14],
+        ..Default::default()
+    }
+}
+
+/// 解析 create_position 指令
+fn parse_create_position_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2CreatePosition;
+
+    if accounts.len() < 11 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV2CreatePositionEvent(MeteoraDammV2CreatePositionEvent {
+        metadata,
+        owner: accounts[0],
+        position_nft_mint: accounts[1],
+        position_nft_account: accounts[2],
+        pool: accounts[3],
+        position: accounts[4],
+        pool_authority: accounts[5],
+        payer: accounts[6],
+        token_program: accounts[7],
+        system_program: accounts[8],
+        event_authority: accounts[9],
+        program: accounts[10],
+    }))
+}
+
+/// 解析 close_position 指令
+fn parse_close_position_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2ClosePosition;
+
+    if accounts.len() < 10 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV2ClosePositionEvent(MeteoraDammV2ClosePositionEvent {
+        metadata,
+        position_nft_mint: accounts[0],
+        position_nft_account: accounts[1],
+        pool: accounts[2],
+        position: accounts[3],
+        pool_authority: accounts[4],
+        rent_receiver: accounts[5],
+        owner: accounts[6],
+        token_program: accounts[7],
+        event_authority: accounts[8],
+        program: accounts[9],
+    }))
+}
+
+/// 解析 split_position 指令
+fn parse_split_position_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2SplitPosition;
+
+    // SplitPositionParameters: 6 个百分比 (u8) + padding ([u8; 16])
+    if data.len() < 6 || accounts.len() < 9 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV2SplitPositionEvent(MeteoraDammV2SplitPositionEvent {
+        unlocked_liquidity_percentage: data[0],
+        permanent_locked_liquidity_percentage: data[1],
+        fee_a_percentage: data[2],
+        fee_b_percentage: data[3],
+        reward_0_percentage: data[4],
+        reward_1_percentage: data[5],
+        ..split_position_accounts(accounts, metadata)
+    }))
+}
+
+/// 解析 split_position2 指令
+fn parse_split_position2_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2SplitPosition2;
+
+    if data.len() < 4 || accounts.len() < 9 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV2SplitPositionEvent(MeteoraDammV2SplitPositionEvent {
+        numerator: u32::from_le_bytes(data[0..4].try_into().unwrap()),
+        ..split_position_accounts(accounts, metadata)
+    }))
+}
+
+fn split_position_accounts(
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> MeteoraDammV2SplitPositionEvent {
+    MeteoraDammV2SplitPositionEvent {
+        metadata,
+        pool: accounts[0],
+        first_position: accounts[1],
+        first_position_nft_account: accounts[2],
+        second_position: accounts[3],
+        second_position_nft_account: accounts[4],
+        first_owner: accounts[5],
+        second_owner: accounts[6],
+        event_authority: accounts[7],
+        program: accounts[8],
+        ..Default::default()
+    }
+}
+
+/// 解析 lock_position 指令
+fn parse_lock_position_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2LockPosition;
+
+    if accounts.len() < 9 {
+        return None;
+    }
+
+    // VestingParameters 以 Option<u64> 开头，长度不固定
+    let params = <VestingParameters as BorshDeserialize>::deserialize(&mut &data[..]).ok()?;
+
+    Some(DexEvent::MeteoraDammV2LockPositionEvent(MeteoraDammV2LockPositionEvent {
+        metadata,
+        cliff_point: params.cliff_point,
+        period_frequency: params.period_frequency,
+        cliff_unlock_liquidity: params.cliff_unlock_liquidity,
+        liquidity_per_period: params.liquidity_per_period,
+        number_of_period: params.number_of_period,
+        pool: accounts[0],
+        position: accounts[1],
+        vesting: accounts[2],
+        position_nft_account: accounts[3],
+        owner: accounts[4],
+        payer: accounts[5],
+        system_program: accounts[6],
+        event_authority: accounts[7],
+        program: accounts[8],
+    }))
+}
+
+/// 解析 permanent_lock_position 指令
+fn parse_permanent_lock_position_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2PermanentLockPosition;
+
+    if data.len() < 16 || accounts.len() < 6 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV2PermanentLockPositionEvent(
+        MeteoraDammV2PermanentLockPositionEvent {
+            metadata,
+            permanent_lock_liquidity: u128::from_le_bytes(data[0..16].try_into().unwrap()),
+            pool: accounts[0],
+            position: accounts[1],
+            position_nft_account: accounts[2],
+            owner: accounts[3],
+            event_authority: accounts[4],
+            program: accounts[5],
+        },
+    ))
+}
+
+/// 解析 claim_position_fee 指令
+fn parse_claim_position_fee_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2ClaimPositionFee;
+
+    if accounts.len() < 15 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV2ClaimPositionFeeEvent(MeteoraDammV2ClaimPositionFeeEvent {
+        metadata,
+        pool_authority: accounts[0],
+        pool: accounts[1],
+        position: accounts[2],
+        token_a_account: accounts[3],
+        token_b_account: accounts[4],
+        token_a_vault: accounts[5],
+        token_b_vault: accounts[6],
+        token_a_mint: accounts[7],
+        token_b_mint: accounts[8],
+        position_nft_account: accounts[9],
+        owner: accounts[10],
+        token_a_program: accounts[11],
+        token_b_program: accounts[12],
+        event_authority: accounts[13],
+        program: accounts[14],
+        ..Default::default()
+    }))
+}
+
+/// 解析 claim_reward 指令
+fn parse_claim_reward_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2ClaimReward;
+
+    if data.len() < 2 || accounts.len() < 11 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDammV2ClaimRewardEvent(MeteoraDammV2ClaimRewardEvent {
+        metadata,
+        reward_index: data[0],
+        skip_reward: data[1],
+        pool_authority: accounts[0],
+        pool: accounts[1],
+        position: accounts[2],
+        reward_vault: accounts[3],
+        reward_mint: accounts[4],
+        user_token_account: accounts[5],
+        position_nft_account: accounts[6],
+        owner: accounts[7],
+        token_program: accounts[8],
+        event_authority: accounts[9],
+        program: accounts[10],
+    }))
+}
+
+/// 解析 EvtLiquidityChange (CPI event)，按 change_type 区分添加与移除
+fn parse_liquidity_change_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    let change_type = *data.get(METEORA_DAMM_V2_LIQUIDITY_CHANGE_EVENT_LOG_SIZE - 1)?;
+    if change_type == 0 {
+        metadata.event_type = EventType::MeteoraDammV2AddLiquidity;
+        meteora_damm_v2_add_liquidity_event_decode(data).map(|event| {
+            DexEvent::MeteoraDammV2AddLiquidityEvent(MeteoraDammV2AddLiquidityEvent {
+                metadata,
+                ..event
+            })
+        })
+    } else {
+        metadata.event_type = EventType::MeteoraDammV2RemoveLiquidity;
+        meteora_damm_v2_remove_liquidity_event_decode(data).map(|event| {
+            DexEvent::MeteoraDammV2RemoveLiquidityEvent(MeteoraDammV2RemoveLiquidityEvent {
+                metadata,
+                ..event
+            })
+        })
+    }
+}
+
+/// 解析 EvtClaimPositionFee (CPI event)
+fn parse_claim_position_fee_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDammV2ClaimPositionFee;
+    meteora_damm_v2_claim_position_fee_event_decode(data).map(|event| {
+        DexEvent::MeteoraDammV2ClaimPositionFeeEvent(MeteoraDammV2ClaimPositionFeeEvent {
+            metadata,
+            ..event
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::core::merger_event::merge;
+
+    #[test]
+    fn test_remove_all_liquidity_merges_liquidity_change() {
+        let accounts: Vec<Pubkey> = (0..15).map(|_| Pubkey::new_unique()).collect();
+        let mut data = 10u64.to_le_bytes().to_vec();
+        data.extend_from_slice(&20u64.to_le_bytes());
+        let mut event = parse_meteora_damm_v2_instruction_data(
+            discriminators::REMOVE_ALL_LIQUIDITY_IX,
+            &data,
+            &accounts,
+            EventMetadata::default(),
+        )
+        .unwrap();
+
+        let mut log = discriminators::EVT_LIQUIDITY_CHANGE_EVENT.to_vec();
+        log.extend_from_slice(accounts[1].as_ref());
+        log.extend_from_slice(accounts[2].as_ref());
+        log.extend_from_slice(accounts[10].as_ref());
+        for amount in [100u64, 200, 101, 202, 5_000, 6_000] {
+            log.extend_from_slice(&amount.to_le_bytes());
+        }
+        log.extend_from_slice(&777u128.to_le_bytes());
+        log.extend_from_slice(&10u64.to_le_bytes());
+        log.extend_from_slice(&20u64.to_le_bytes());
+        log.push(1);
+        let cpi_event = parse_meteora_damm_v2_inner_instruction_data(
+            &log[..16],
+            &log[16..],
+            EventMetadata::default(),
+        )
+        .unwrap();
+        merge(&mut event, cpi_event);
+
+        let DexEvent::MeteoraDammV2RemoveLiquidityEvent(event) = event else {
+            panic!("expected remove liquidity")
+        };
+        assert_eq!(event.metadata.event_type, EventType::MeteoraDammV2RemoveAllLiquidity);
+        assert_eq!((event.token_a_amount, event.token_b_amount), (100, 200));
+        assert_eq!((event.reserve_a_amount, event.reserve_b_amount), (5_000, 6_000));
+        assert_eq!(event.liquidity_delta, 777);
+        assert_eq!((event.token_a_amount_threshold, event.token_b_amount_threshold), (10, 20));
+        assert_eq!((event.owner, event.token_a_vault), (accounts[10], accounts[5]));
+    }
+}
@@ -1,2 +1,280 @@
-// 此文件用于定义 Meteora DAMM v2 的账户数据结构
-// 暂时留空,后续如需要解析 Pool 账户状态时可以在这里添加
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::{EventMetadata, EventType},
+        protocols::meteora_damm_v2::{
+            MeteoraDammV2ConfigAccountEvent, MeteoraDammV2PoolAccountEvent,
+            MeteoraDammV2PositionAccountEvent,
+        },
+        DexEvent,
+    },
+    grpc::AccountPretty,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BaseFeeStruct {
+    pub base_fee_info: [u8; 32],
+    pub padding_1: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DynamicFeeStruct {
+    pub initialized: u8,
+    pub padding: [u8; 7],
+    pub max_volatility_accumulator: u32,
+    pub variable_fee_control: u32,
+    pub bin_step: u16,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub last_update_timestamp: u64,
+    pub bin_step_u128: u128,
+    pub sqrt_price_reference: u128,
+    pub volatility_accumulator: u128,
+    pub volatility_reference: u128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolFeesStruct {
+    pub base_fee: BaseFeeStruct,
+    pub protocol_fee_percent: u8,
+    pub partner_fee_percent: u8,
+    pub referral_fee_percent: u8,
+    pub padding_0: [u8; 5],
+    pub dynamic_fee: DynamicFeeStruct,
+    pub init_sqrt_price: u128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolMetrics {
+    pub total_lp_a_fee: u128,
+    pub total_lp_b_fee: u128,
+    pub total_protocol_a_fee: u64,
+    pub total_protocol_b_fee: u64,
+    pub total_partner_a_fee: u64,
+    pub total_partner_b_fee: u64,
+    pub total_position: u64,
+    pub padding: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RewardInfo {
+    pub initialized: u8,
+    pub reward_token_flag: u8,
+    pub padding_0: [u8; 6],
+    pub padding_1: [u8; 8],
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub funder: Pubkey,
+    pub reward_duration: u64,
+    pub reward_duration_end: u64,
+    pub reward_rate: u128,
+    pub reward_per_token_stored: [u8; 32],
+    pub last_update_time: u64,
+    pub cumulative_seconds_with_empty_liquidity_reward: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Pool {
+    pub pool_fees: PoolFeesStruct,
+    pub token_a_mint: Pubkey,
+    pub token_b_mint: Pubkey,
+    pub token_a_vault: Pubkey,
+    pub token_b_vault: Pubkey,
+    pub whitelisted_vault: Pubkey,
+    pub partner: Pubkey,
+    pub liquidity: u128,
+    pub padding: u128,
+    pub protocol_a_fee: u64,
+    pub protocol_b_fee: u64,
+    pub partner_a_fee: u64,
+    pub partner_b_fee: u64,
+    pub sqrt_min_price: u128,
+    pub sqrt_max_price: u128,
+    pub sqrt_price: u128,
+    pub activation_point: u64,
+    pub activation_type: u8,
+    pub pool_status: u8,
+    pub token_a_flag: u8,
+    pub token_b_flag: u8,
+    pub collect_fee_mode: u8,
+    pub pool_type: u8,
+    pub version: u8,
+    pub padding_0: u8,
+    pub fee_a_per_liquidity: [u8; 32],
+    pub fee_b_per_liquidity: [u8; 32],
+    pub permanent_lock_liquidity: u128,
+    pub metrics: PoolMetrics,
+    pub creator: Pubkey,
+    pub padding_1: [u64; 6],
+    pub reward_infos: [RewardInfo; 2],
+}
+
+pub const POOL_SIZE: usize = 1104;
+
+pub fn pool_decode(data: &[u8]) -> Option<Pool> {
+    if data.len() < POOL_SIZE {
+        return None;
+    }
+    borsh::from_slice::<Pool>(&data[..POOL_SIZE]).ok()
+}
+
+pub fn pool_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountMeteoraDammV2Pool;
+
+    if account.data.len() < POOL_SIZE + 8 {
+        return None;
+    }
+    pool_decode(&account.data[8..POOL_SIZE + 8]).map(|pool| {
+        DexEvent::MeteoraDammV2PoolAccountEvent(MeteoraDammV2PoolAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            pool,
+        })
+    })
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PositionMetrics {
+    pub total_claimed_a_fee: u64,
+    pub total_claimed_b_fee: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UserRewardInfo {
+    pub reward_per_token_checkpoint: [u8; 32],
+    pub reward_pendings: u64,
+    pub total_claimed_rewards: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Position {
+    pub pool: Pubkey,
+    pub nft_mint: Pubkey,
+    pub fee_a_per_token_checkpoint: [u8; 32],
+    pub fee_b_per_token_checkpoint: [u8; 32],
+    pub fee_a_pending: u64,
+    pub fee_b_pending: u64,
+    pub unlocked_liquidity: u128,
+    pub vested_liquidity: u128,
+    pub permanent_locked_liquidity: u128,
+    pub metrics: PositionMetrics,
+    pub reward_infos: [UserRewardInfo; 2],
+    pub padding: [u128; 6],
+}
+
+pub const POSITION_SIZE: usize = 400;
+
+pub fn position_decode(data: &[u8]) -> Option<Position> {
+    if data.len() < POSITION_SIZE {
+        return None;
+    }
+    borsh::from_slice::<Position>(&data[..POSITION_SIZE]).ok()
+}
+
+pub fn position_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountMeteoraDammV2Position;
+
+    if account.data.len() < POSITION_SIZE + 8 {
+        return None;
+    }
+    position_decode(&account.data[8..POSITION_SIZE + 8]).map(|position| {
+        DexEvent::MeteoraDammV2PositionAccountEvent(MeteoraDammV2PositionAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            position,
+        })
+    })
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DynamicFeeConfig {
+    pub initialized: u8,
+    pub padding: [u8; 7],
+    pub max_volatility_accumulator: u32,
+    pub variable_fee_control: u32,
+    pub bin_step: u16,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub padding_1: [u8; 8],
+    pub bin_step_u128: u128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PoolFeesConfig {
+    pub base_fee_info: [u8; 32],
+    pub dynamic_fee: DynamicFeeConfig,
+    pub protocol_fee_percent: u8,
+    pub partner_fee_percent: u8,
+    pub referral_fee_percent: u8,
+    pub padding_0: [u8; 5],
+    pub padding_1: [u64; 5],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Config {
+    pub vault_config_key: Pubkey,
+    pub pool_creator_authority: Pubkey,
+    pub pool_fees: PoolFeesConfig,
+    pub activation_type: u8,
+    pub collect_fee_mode: u8,
+    pub config_type: u8, // 0: Static, 1: Dynamic
+    pub padding_0: [u8; 5],
+    pub index: u64,
+    pub sqrt_min_price: u128,
+    pub sqrt_max_price: u128,
+    pub padding_1: [u64; 10],
+}
+
+pub const CONFIG_SIZE: usize = 320;
+
+pub fn config_decode(data: &[u8]) -> Option<Config> {
+    if data.len() < CONFIG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<Config>(&data[..CONFIG_SIZE]).ok()
+}
+
+pub fn config_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountMeteoraDammV2Config;
+
+    if account.data.len() < CONFIG_SIZE + 8 {
+        return None;
+    }
+    config_decode(&account.data[8..CONFIG_SIZE + 8]).map(|config| {
+        DexEvent::MeteoraDammV2ConfigAccountEvent(MeteoraDammV2ConfigAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            config,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_account_sizes_match_layout() {
+        // borsh::from_slice 要求恰好消费全部字节，长度不符会解码失败
+        assert!(borsh::from_slice::<Pool>(&[0u8; POOL_SIZE]).is_ok());
+        assert!(borsh::from_slice::<Position>(&[0u8; POSITION_SIZE]).is_ok());
+        assert!(borsh::from_slice::<Config>(&[0u8; CONFIG_SIZE]).is_ok());
+    }
+}
@@ -0,0 +1,382 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::meteora_dlmm::types::{
+    BinArray, LbPair, PositionV2,
+};
+
+/// 由 `idl/meteora_dlmm.json` 生成
+pub use crate::streaming::event_parser::idl::generated::meteora_dlmm::discriminators;
+
+/// Meteora DLMM Swap Event (swap / swapExactOut / swapWithPriceImpact 及其 v2 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmSwapEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据
+    pub lb_pair: Pubkey,
+    pub from: Pubkey,
+    pub start_bin_id: i32,
+    pub end_bin_id: i32,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub swap_for_y: bool,
+    pub fee: u64,
+    pub protocol_fee: u64,
+    pub fee_bps: u128,
+    pub host_fee: u64,
+
+    // 指令参数
+    /// swap / swapWithPriceImpact 为 amountIn，swapExactOut 为 maxInAmount
+    #[borsh(skip)]
+    pub param_amount_in: u64,
+    /// swap 为 minAmountOut，swapExactOut 为 outAmount，swapWithPriceImpact 为 0
+    #[borsh(skip)]
+    pub param_amount_out: u64,
+    /// swapWithPriceImpact 的 activeId
+    #[borsh(skip)]
+    pub active_id: Option<i32>,
+    /// swapWithPriceImpact 的 maxPriceImpactBps
+    #[borsh(skip)]
+    pub max_price_impact_bps: u16,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub bin_array_bitmap_extension: Pubkey,
+    #[borsh(skip)]
+    pub reserve_x: Pubkey,
+    #[borsh(skip)]
+    pub reserve_y: Pubkey,
+    #[borsh(skip)]
+    pub user_token_in: Pubkey,
+    #[borsh(skip)]
+    pub user_token_out: Pubkey,
+    #[borsh(skip)]
+    pub token_x_mint: Pubkey,
+    #[borsh(skip)]
+    pub token_y_mint: Pubkey,
+    #[borsh(skip)]
+    pub oracle: Pubkey,
+    #[borsh(skip)]
+    pub host_fee_in: Pubkey,
+    #[borsh(skip)]
+    pub user: Pubkey,
+    #[borsh(skip)]
+    pub token_x_program: Pubkey,
+    #[borsh(skip)]
+    pub token_y_program: Pubkey,
+}
+
+/// Meteora DLMM Add Liquidity Event (双边添加流动性：addLiquidity / ByWeight / ByStrategy 及其 v2 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmAddLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据
+    pub lb_pair: Pubkey,
+    pub from: Pubkey,
+    pub position: Pubkey,
+    /// [amount_x, amount_y]
+    pub amounts: [u64; 2],
+    pub active_bin_id: i32,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub bin_array_bitmap_extension: Pubkey,
+    #[borsh(skip)]
+    pub user_token_x: Pubkey,
+    #[borsh(skip)]
+    pub user_token_y: Pubkey,
+    #[borsh(skip)]
+    pub reserve_x: Pubkey,
+    #[borsh(skip)]
+    pub reserve_y: Pubkey,
+    #[borsh(skip)]
+    pub token_x_mint: Pubkey,
+    #[borsh(skip)]
+    pub token_y_mint: Pubkey,
+    #[borsh(skip)]
+    pub sender: Pubkey,
+}
+
+/// Meteora DLMM Add Liquidity One Side Event (单边添加流动性：addLiquidityOneSide / ByStrategyOneSide / OneSidePrecise 及其 v2 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmAddLiquidityOneSideEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据
+    pub lb_pair: Pubkey,
+    pub from: Pubkey,
+    pub position: Pubkey,
+    /// [amount_x, amount_y]，其中一项为 0
+    pub amounts: [u64; 2],
+    pub active_bin_id: i32,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub bin_array_bitmap_extension: Pubkey,
+    #[borsh(skip)]
+    pub user_token: Pubkey,
+    #[borsh(skip)]
+    pub reserve: Pubkey,
+    #[borsh(skip)]
+    pub token_mint: Pubkey,
+    #[borsh(skip)]
+    pub sender: Pubkey,
+}
+
+/// Meteora DLMM Remove Liquidity Event (removeLiquidity / removeAllLiquidity / removeLiquidityByRange 及其 v2 指令)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmRemoveLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据
+    pub lb_pair: Pubkey,
+    pub from: Pubkey,
+    pub position: Pubkey,
+    /// [amount_x, amount_y]
+    pub amounts: [u64; 2],
+    pub active_bin_id: i32,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub bin_array_bitmap_extension: Pubkey,
+    #[borsh(skip)]
+    pub user_token_x: Pubkey,
+    #[borsh(skip)]
+    pub user_token_y: Pubkey,
+    #[borsh(skip)]
+    pub reserve_x: Pubkey,
+    #[borsh(skip)]
+    pub reserve_y: Pubkey,
+    #[borsh(skip)]
+    pub token_x_mint: Pubkey,
+    #[borsh(skip)]
+    pub token_y_mint: Pubkey,
+    #[borsh(skip)]
+    pub sender: Pubkey,
+}
+
+/// Meteora DLMM Position Create Event (initializePosition / initializePositionPda / initializePositionByOperator)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmPositionCreateEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+
+    // 指令参数
+    #[borsh(skip)]
+    pub lower_bin_id: i32,
+    #[borsh(skip)]
+    pub width: i32,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub payer: Pubkey,
+    /// initializePosition 没有 base 账户
+    #[borsh(skip)]
+    pub base: Pubkey,
+}
+
+/// Meteora DLMM Position Close Event (closePosition / closePosition2 / closePositionIfEmpty)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmPositionCloseEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据
+    pub position: Pubkey,
+    pub owner: Pubkey,
+
+    // 来自 Input Accounts 的数据
+    /// 仅 closePosition 带有 lb_pair 账户
+    #[borsh(skip)]
+    pub lb_pair: Pubkey,
+    #[borsh(skip)]
+    pub sender: Pubkey,
+    #[borsh(skip)]
+    pub rent_receiver: Pubkey,
+}
+
+/// Meteora DLMM Claim Fee Event (claimFee / claimFee2)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmClaimFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub fee_x: u64,
+    pub fee_y: u64,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub sender: Pubkey,
+    #[borsh(skip)]
+    pub reserve_x: Pubkey,
+    #[borsh(skip)]
+    pub reserve_y: Pubkey,
+    #[borsh(skip)]
+    pub user_token_x: Pubkey,
+    #[borsh(skip)]
+    pub user_token_y: Pubkey,
+    #[borsh(skip)]
+    pub token_x_mint: Pubkey,
+    #[borsh(skip)]
+    pub token_y_mint: Pubkey,
+}
+
+/// Meteora DLMM Claim Reward Event (claimReward / claimReward2)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmClaimRewardEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 CPI Log Event 的数据
+    pub lb_pair: Pubkey,
+    pub position: Pubkey,
+    pub owner: Pubkey,
+    pub reward_index: u64,
+    pub total_reward: u64,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub sender: Pubkey,
+    #[borsh(skip)]
+    pub reward_vault: Pubkey,
+    #[borsh(skip)]
+    pub reward_mint: Pubkey,
+    #[borsh(skip)]
+    pub user_token_account: Pubkey,
+}
+
+/// LbPair 账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmLbPairAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub lb_pair: LbPair,
+}
+
+/// BinArray 账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmBinArrayAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub bin_array: BinArray,
+}
+
+/// PositionV2 账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct MeteoraDlmmPositionV2AccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub position: PositionV2,
+}
+
+/// Decode swap event from CPI log
+pub const METEORA_DLMM_SWAP_EVENT_LOG_SIZE: usize = 129;
+pub fn meteora_dlmm_swap_event_decode(data: &[u8]) -> Option<MeteoraDlmmSwapEvent> {
+    if data.len() < METEORA_DLMM_SWAP_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmSwapEvent>(&data[..METEORA_DLMM_SWAP_EVENT_LOG_SIZE]).ok()
+}
+
+/// Decode add / remove liquidity event from CPI log
+pub const METEORA_DLMM_LIQUIDITY_EVENT_LOG_SIZE: usize = 116;
+pub fn meteora_dlmm_add_liquidity_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDlmmAddLiquidityEvent> {
+    if data.len() < METEORA_DLMM_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmAddLiquidityEvent>(
+        &data[..METEORA_DLMM_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+pub fn meteora_dlmm_remove_liquidity_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDlmmRemoveLiquidityEvent> {
+    if data.len() < METEORA_DLMM_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmRemoveLiquidityEvent>(
+        &data[..METEORA_DLMM_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// Decode position create event from CPI log
+pub const METEORA_DLMM_POSITION_CREATE_EVENT_LOG_SIZE: usize = 96;
+pub fn meteora_dlmm_position_create_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDlmmPositionCreateEvent> {
+    if data.len() < METEORA_DLMM_POSITION_CREATE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmPositionCreateEvent>(
+        &data[..METEORA_DLMM_POSITION_CREATE_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// Decode position close event from CPI log
+pub const METEORA_DLMM_POSITION_CLOSE_EVENT_LOG_SIZE: usize = 64;
+pub fn meteora_dlmm_position_close_event_decode(
+    data: &[u8],
+) -> Option<MeteoraDlmmPositionCloseEvent> {
+    if data.len() < METEORA_DLMM_POSITION_CLOSE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmPositionCloseEvent>(
+        &data[..METEORA_DLMM_POSITION_CLOSE_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// Decode claim fee / claim reward event from CPI log
+pub const METEORA_DLMM_CLAIM_EVENT_LOG_SIZE: usize = 112;
+pub fn meteora_dlmm_claim_fee_event_decode(data: &[u8]) -> Option<MeteoraDlmmClaimFeeEvent> {
+    if data.len() < METEORA_DLMM_CLAIM_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmClaimFeeEvent>(&data[..METEORA_DLMM_CLAIM_EVENT_LOG_SIZE]).ok()
+}
+
+pub fn meteora_dlmm_claim_reward_event_decode(data: &[u8]) -> Option<MeteoraDlmmClaimRewardEvent> {
+    if data.len() < METEORA_DLMM_CLAIM_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<MeteoraDlmmClaimRewardEvent>(&data[..METEORA_DLMM_CLAIM_EVENT_LOG_SIZE])
+        .ok()
+}
@@ -0,0 +1,541 @@
+use crate::streaming::event_parser::protocols::meteora_dlmm::types::{
+    bin_array_parser, lb_pair_parser, position_v2_parser,
+};
+use crate::streaming::event_parser::{
+    common::{read_i32_le, read_u16_le, read_u64_le, read_u8_le, EventMetadata, EventType},
+    protocols::meteora_dlmm::{
+        discriminators, meteora_dlmm_add_liquidity_event_decode,
+        meteora_dlmm_claim_fee_event_decode, meteora_dlmm_claim_reward_event_decode,
+        meteora_dlmm_position_close_event_decode, meteora_dlmm_position_create_event_decode,
+        meteora_dlmm_remove_liquidity_event_decode, meteora_dlmm_swap_event_decode,
+        MeteoraDlmmAddLiquidityEvent, MeteoraDlmmAddLiquidityOneSideEvent,
+        MeteoraDlmmClaimFeeEvent, MeteoraDlmmClaimRewardEvent, MeteoraDlmmPositionCloseEvent,
+        MeteoraDlmmPositionCreateEvent, MeteoraDlmmRemoveLiquidityEvent, MeteoraDlmmSwapEvent,
+    },
+    DexEvent,
+};
+use crate::streaming::grpc::AccountPretty;
+use solana_sdk::pubkey::Pubkey;
+
+/// Meteora DLMM 程序ID
+pub const METEORA_DLMM_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo");
+
+/// 解析 Meteora DLMM instruction data
+///
+/// 根据判别器路由到具体的 instruction 解析函数
+pub fn parse_meteora_dlmm_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::SWAP_IX | discriminators::SWAP2_IX => {
+            parse_swap_instruction(data, accounts, metadata)
+        }
+        discriminators::SWAP_EXACT_OUT_IX | discriminators::SWAP_EXACT_OUT2_IX => {
+            parse_swap_exact_out_instruction(data, accounts, metadata)
+        }
+        discriminators::SWAP_WITH_PRICE_IMPACT_IX | discriminators::SWAP_WITH_PRICE_IMPACT2_IX => {
+            parse_swap_with_price_impact_instruction(data, accounts, metadata)
+        }
+        discriminators::ADD_LIQUIDITY_IX
+        | discriminators::ADD_LIQUIDITY_BY_WEIGHT_IX
+        | discriminators::ADD_LIQUIDITY_BY_STRATEGY_IX => {
+            parse_add_liquidity_instruction(accounts, 11, metadata)
+        }
+        discriminators::ADD_LIQUIDITY2_IX | discriminators::ADD_LIQUIDITY_BY_STRATEGY2_IX => {
+            parse_add_liquidity_instruction(accounts, 9, metadata)
+        }
+        discriminators::ADD_LIQUIDITY_ONE_SIDE_IX
+        | discriminators::ADD_LIQUIDITY_BY_STRATEGY_ONE_SIDE_IX
+        | discriminators::ADD_LIQUIDITY_ONE_SIDE_PRECISE_IX => {
+            parse_add_liquidity_one_side_instruction(accounts, 8, metadata)
+        }
+        discriminators::ADD_LIQUIDITY_ONE_SIDE_PRECISE2_IX => {
+            parse_add_liquidity_one_side_instruction(accounts, 6, metadata)
+        }
+        discriminators::REMOVE_LIQUIDITY_IX
+        | discriminators::REMOVE_ALL_LIQUIDITY_IX
+        | discriminators::REMOVE_LIQUIDITY_BY_RANGE_IX => {
+            parse_remove_liquidity_instruction(accounts, 11, metadata)
+        }
+        discriminators::REMOVE_LIQUIDITY2_IX | discriminators::REMOVE_LIQUIDITY_BY_RANGE2_IX => {
+            parse_remove_liquidity_instruction(accounts, 9, metadata)
+        }
+        discriminators::INITIALIZE_POSITION_IX => {
+            parse_position_create_instruction(data, accounts, false, metadata)
+        }
+        discriminators::INITIALIZE_POSITION_PDA_IX
+        | discriminators::INITIALIZE_POSITION_BY_OPERATOR_IX => {
+            parse_position_create_instruction(data, accounts, true, metadata)
+        }
+        discriminators::CLOSE_POSITION_IX => parse_close_position_instruction(accounts, metadata),
+        discriminators::CLOSE_POSITION2_IX | discriminators::CLOSE_POSITION_IF_EMPTY_IX => {
+            parse_close_position2_instruction(accounts, metadata)
+        }
+        discriminators::CLAIM_FEE_IX => parse_claim_fee_instruction(accounts, 4, metadata),
+        discriminators::CLAIM_FEE2_IX => parse_claim_fee_instruction(accounts, 2, metadata),
+        discriminators::CLAIM_REWARD_IX => {
+            parse_claim_reward_instruction(data, accounts, 4, metadata)
+        }
+        discriminators::CLAIM_REWARD2_IX => {
+            parse_claim_reward_instruction(data, accounts, 2, metadata)
+        }
+        _ => None,
+    }
+}
+
+/// 解析 Meteora DLMM inner instruction data (CPI events)
+///
+/// 根据判别器路由到具体的 inner instruction 解析函数
+pub fn parse_meteora_dlmm_inner_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::SWAP_EVENT => parse_swap_inner_instruction(data, metadata),
+        discriminators::ADD_LIQUIDITY_EVENT => {
+            parse_add_liquidity_inner_instruction(data, metadata)
+        }
+        discriminators::REMOVE_LIQUIDITY_EVENT => {
+            parse_remove_liquidity_inner_instruction(data, metadata)
+        }
+        discriminators::POSITION_CREATE_EVENT => {
+            parse_position_create_inner_instruction(data, metadata)
+        }
+        discriminators::POSITION_CLOSE_EVENT => {
+            parse_position_close_inner_instruction(data, metadata)
+        }
+        discriminators::CLAIM_FEE_EVENT => parse_claim_fee_inner_instruction(data, metadata),
+        discriminators::CLAIM_REWARD_EVENT => parse_claim_reward_inner_instruction(data, metadata),
+        _ => None,
+    }
+}
+
+/// 解析 Meteora DLMM 账户数据
+///
+/// 根据判别器路由到具体的账户解析函数
+pub fn parse_meteora_dlmm_account_data(
+    discriminator: &[u8],
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::LB_PAIR_ACCOUNT => lb_pair_parser(account, metadata),
+        discriminators::BIN_ARRAY_ACCOUNT => bin_array_parser(account, metadata),
+        discriminators::POSITION_V2_ACCOUNT => position_v2_parser(account, metadata),
+        _ => None,
+    }
+}
+
+/// swap 系列指令共用的账户布局，v2 指令在 tokenYProgram 之后多出 memoProgram
+fn swap_event_from_accounts(
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<MeteoraDlmmSwapEvent> {
+    if accounts.len() < 15 {
+        return None;
+    }
+    Some(MeteoraDlmmSwapEvent {
+        metadata,
+        lb_pair: accounts[0],
+        bin_array_bitmap_extension: accounts[1],
+        reserve_x: accounts[2],
+        reserve_y: accounts[3],
+        user_token_in: accounts[4],
+        user_token_out: accounts[5],
+        token_x_mint: accounts[6],
+        token_y_mint: accounts[7],
+        oracle: accounts[8],
+        host_fee_in: accounts[9],
+        user: accounts[10],
+        from: accounts[10],
+        token_x_program: accounts[11],
+        token_y_program: accounts[12],
+        ..Default::default()
+    })
+}
+
+/// 解析 swap / swap2 指令
+fn parse_swap_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmSwap;
+    let mut event = swap_event_from_accounts(accounts, metadata)?;
+    event.param_amount_in = read_u64_le(data, 0)?;
+    event.param_amount_out = read_u64_le(data, 8)?;
+    Some(DexEvent::MeteoraDlmmSwapEvent(event))
+}
+
+/// 解析 swap_exact_out / swap_exact_out2 指令
+fn parse_swap_exact_out_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmSwapExactOut;
+    let mut event = swap_event_from_accounts(accounts, metadata)?;
+    // max_in_amount, out_amount
+    event.param_amount_in = read_u64_le(data, 0)?;
+    event.param_amount_out = read_u64_le(data, 8)?;
+    Some(DexEvent::MeteoraDlmmSwapEvent(event))
+}
+
+/// 解析 swap_with_price_impact / swap_with_price_impact2 指令
+fn parse_swap_with_price_impact_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmSwapWithPriceImpact;
+    let mut event = swap_event_from_accounts(accounts, metadata)?;
+    event.param_amount_in = read_u64_le(data, 0)?;
+    // active_id: Option<i32>
+    let offset = match read_u8_le(data, 8)? {
+        1 => {
+            event.active_id = Some(read_i32_le(data, 9)?);
+            13
+        }
+        _ => 9,
+    };
+    event.max_price_impact_bps = read_u16_le(data, offset)?;
+    Some(DexEvent::MeteoraDlmmSwapEvent(event))
+}
+
+/// 解析双边添加流动性指令，v1 指令带 bin array 账户，v2 指令的 sender 位于索引 9
+fn parse_add_liquidity_instruction(
+    accounts: &[Pubkey],
+    sender_index: usize,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmAddLiquidity;
+
+    if accounts.len() <= sender_index {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDlmmAddLiquidityEvent(MeteoraDlmmAddLiquidityEvent {
+        metadata,
+        position: accounts[0],
+        lb_pair: accounts[1],
+        bin_array_bitmap_extension: accounts[2],
+        user_token_x: accounts[3],
+        user_token_y: accounts[4],
+        reserve_x: accounts[5],
+        reserve_y: accounts[6],
+        token_x_mint: accounts[7],
+        token_y_mint: accounts[8],
+        sender: accounts[sender_index],
+        from: accounts[sender_index],
+        ..Default::default()
+    }))
+}
+
+/// 解析单边添加流动性指令
+fn parse_add_liquidity_one_side_instruction(
+    accounts: &[Pubkey],
+    sender_index: usize,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmAddLiquidityOneSide;
+
+    if accounts.len() <= sender_index {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDlmmAddLiquidityOneSideEvent(MeteoraDlmmAddLiquidityOneSideEvent {
+        metadata,
+        position: accounts[0],
+        lb_pair: accounts[1],
+        bin_array_bitmap_extension: accounts[2],
+        user_token: accounts[3],
+        reserve: accounts[4],
+        token_mint: accounts[5],
+        sender: accounts[sender_index],
+        from: accounts[sender_index],
+        ..Default::default()
+    }))
+}
+
+/// 解析移除流动性指令，账户布局与双边添加流动性一致
+fn parse_remove_liquidity_instruction(
+    accounts: &[Pubkey],
+    sender_index: usize,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmRemoveLiquidity;
+
+    if accounts.len() <= sender_index {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDlmmRemoveLiquidityEvent(MeteoraDlmmRemoveLiquidityEvent {
+        metadata,
+        position: accounts[0],
+        lb_pair: accounts[1],
+        bin_array_bitmap_extension: accounts[2],
+        user_token_x: accounts[3],
+        user_token_y: accounts[4],
+        reserve_x: accounts[5],
+        reserve_y: accounts[6],
+        token_x_mint: accounts[7],
+        token_y_mint: accounts[8],
+        sender: accounts[sender_index],
+        from: accounts[sender_index],
+        ..Default::default()
+    }))
+}
+
+/// 解析 initialize_position / initialize_position_pda / initialize_position_by_operator 指令
+fn parse_position_create_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    has_base: bool,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmPositionCreate;
+
+    // payer, [base], position, lb_pair, owner
+    let offset = if has_base { 1 } else { 0 };
+    if accounts.len() < 4 + offset {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDlmmPositionCreateEvent(MeteoraDlmmPositionCreateEvent {
+        metadata,
+        payer: accounts[0],
+        base: if has_base { accounts[1] } else { Pubkey::default() },
+        position: accounts[1 + offset],
+        lb_pair: accounts[2 + offset],
+        owner: accounts[3 + offset],
+        lower_bin_id: read_i32_le(data, 0)?,
+        width: read_i32_le(data, 4)?,
+    }))
+}
+
+/// 解析 close_position 指令
+fn parse_close_position_instruction(
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmPositionClose;
+
+    if accounts.len() < 6 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDlmmPositionCloseEvent(MeteoraDlmmPositionCloseEvent {
+        metadata,
+        position: accounts[0],
+        lb_pair: accounts[1],
+        sender: accounts[4],
+        owner: accounts[4],
+        rent_receiver: accounts[5],
+    }))
+}
+
+/// 解析 close_position2 / close_position_if_empty 指令
+fn parse_close_position2_instruction(
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmPositionClose;
+
+    if accounts.len() < 3 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDlmmPositionCloseEvent(MeteoraDlmmPositionCloseEvent {
+        metadata,
+        position: accounts[0],
+        sender: accounts[1],
+        owner: accounts[1],
+        rent_receiver: accounts[2],
+        ..Default::default()
+    }))
+}
+
+/// 解析 claim_fee / claim_fee2 指令，claim_fee 在 position 之后多出两个 bin array 账户
+fn parse_claim_fee_instruction(
+    accounts: &[Pubkey],
+    sender_index: usize,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmClaimFee;
+
+    if accounts.len() < sender_index + 7 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDlmmClaimFeeEvent(MeteoraDlmmClaimFeeEvent {
+        metadata,
+        lb_pair: accounts[0],
+        position: accounts[1],
+        sender: accounts[sender_index],
+        owner: accounts[sender_index],
+        reserve_x: accounts[sender_index + 1],
+        reserve_y: accounts[sender_index + 2],
+        user_token_x: accounts[sender_index + 3],
+        user_token_y: accounts[sender_index + 4],
+        token_x_mint: accounts[sender_index + 5],
+        token_y_mint: accounts[sender_index + 6],
+        ..Default::default()
+    }))
+}
+
+/// 解析 claim_reward / claim_reward2 指令，claim_reward 在 position 之后多出两个 bin array 账户
+fn parse_claim_reward_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    sender_index: usize,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmClaimReward;
+
+    if accounts.len() < sender_index + 4 {
+        return None;
+    }
+
+    Some(DexEvent::MeteoraDlmmClaimRewardEvent(MeteoraDlmmClaimRewardEvent {
+        metadata,
+        lb_pair: accounts[0],
+        position: accounts[1],
+        sender: accounts[sender_index],
+        owner: accounts[sender_index],
+        reward_vault: accounts[sender_index + 1],
+        reward_mint: accounts[sender_index + 2],
+        user_token_account: accounts[sender_index + 3],
+        reward_index: read_u64_le(data, 0)?,
+        ..Default::default()
+    }))
+}
+
+/// 解析 swap inner instruction (CPI event)
+fn parse_swap_inner_instruction(data: &[u8], mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmSwap;
+    meteora_dlmm_swap_event_decode(data)
+        .map(|event| DexEvent::MeteoraDlmmSwapEvent(MeteoraDlmmSwapEvent { metadata, ..event }))
+}
+
+/// 解析 add liquidity inner instruction (CPI event)
+fn parse_add_liquidity_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmAddLiquidity;
+    meteora_dlmm_add_liquidity_event_decode(data).map(|event| {
+        DexEvent::MeteoraDlmmAddLiquidityEvent(MeteoraDlmmAddLiquidityEvent { metadata, ..event })
+    })
+}
+
+/// 解析 remove liquidity inner instruction (CPI event)
+fn parse_remove_liquidity_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmRemoveLiquidity;
+    meteora_dlmm_remove_liquidity_event_decode(data).map(|event| {
+        DexEvent::MeteoraDlmmRemoveLiquidityEvent(MeteoraDlmmRemoveLiquidityEvent {
+            metadata,
+            ..event
+        })
+    })
+}
+
+/// 解析 position create inner instruction (CPI event)
+fn parse_position_create_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmPositionCreate;
+    meteora_dlmm_position_create_event_decode(data).map(|event| {
+        DexEvent::MeteoraDlmmPositionCreateEvent(MeteoraDlmmPositionCreateEvent {
+            metadata,
+            ..event
+        })
+    })
+}
+
+/// 解析 position close inner instruction (CPI event)
+fn parse_position_close_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmPositionClose;
+    meteora_dlmm_position_close_event_decode(data).map(|event| {
+        DexEvent::MeteoraDlmmPositionCloseEvent(MeteoraDlmmPositionCloseEvent { metadata, ..event })
+    })
+}
+
+/// 解析 claim fee inner instruction (CPI event)
+fn parse_claim_fee_inner_instruction(data: &[u8], mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmClaimFee;
+    meteora_dlmm_claim_fee_event_decode(data).map(|event| {
+        DexEvent::MeteoraDlmmClaimFeeEvent(MeteoraDlmmClaimFeeEvent { metadata, ..event })
+    })
+}
+
+/// 解析 claim reward inner instruction (CPI event)
+fn parse_claim_reward_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::MeteoraDlmmClaimReward;
+    meteora_dlmm_claim_reward_event_decode(data).map(|event| {
+        DexEvent::MeteoraDlmmClaimRewardEvent(MeteoraDlmmClaimRewardEvent { metadata, ..event })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        core::transaction_input::fixtures::{
+            account_index, inner_instruction, inner_instructions_meta, parse, transaction,
+            unique_pubkeys,
+        },
+        Protocol,
+    };
+
+    #[test]
+    fn test_swap_merges_event_log_and_sets_swap_data() {
+        let accounts = unique_pubkeys(15);
+        let mut data = discriminators::SWAP_IX.to_vec();
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&900u64.to_le_bytes());
+        let tx = transaction(METEORA_DLMM_PROGRAM_ID, &data, &accounts);
+
+        // Swap { lb_pair, from, start_bin_id, end_bin_id, amount_in, amount_out, swap_for_y, .. }
+        let mut log = discriminators::SWAP_EVENT.to_vec();
+        log.extend_from_slice(&accounts[0].to_bytes());
+        log.extend_from_slice(&accounts[10].to_bytes());
+        log.extend_from_slice(&(-5i32).to_le_bytes());
+        log.extend_from_slice(&(-3i32).to_le_bytes());
+        log.extend_from_slice(&1_000u64.to_le_bytes());
+        log.extend_from_slice(&950u64.to_le_bytes());
+        log.push(0);
+        log.extend_from_slice(&3u64.to_le_bytes());
+        log.extend_from_slice(&1u64.to_le_bytes());
+        log.extend_from_slice(&30u128.to_le_bytes());
+        log.extend_from_slice(&0u64.to_le_bytes());
+        let program_index = account_index(&tx, &METEORA_DLMM_PROGRAM_ID);
+        let meta = inner_instructions_meta(vec![inner_instruction(program_index, log, vec![])]);
+
+        let events = parse(&[Protocol::MeteoraDlmm], &tx, &meta);
+
+        assert_eq!(events.len(), 1);
+        let DexEvent::MeteoraDlmmSwapEvent(event) = &events[0] else { panic!("expected swap") };
+        assert_eq!(event.metadata.event_type, EventType::MeteoraDlmmSwap);
+        assert_eq!((event.param_amount_in, event.param_amount_out), (1_000, 900));
+        assert_eq!((event.amount_out, event.end_bin_id, event.fee_bps), (950, -3, 30));
+        let swap_data = event.metadata.swap_data.as_ref().unwrap();
+        // swap_for_y = false: Y -> X
+        assert_eq!((swap_data.from_mint, swap_data.to_mint), (accounts[7], accounts[6]));
+        assert_eq!((swap_data.from_amount, swap_data.to_amount), (1_000, 950));
+    }
+}
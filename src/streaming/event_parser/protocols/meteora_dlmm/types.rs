@@ -0,0 +1,279 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::{
+    event_parser::{
+        common::{EventMetadata, EventType},
+        protocols::meteora_dlmm::{
+            MeteoraDlmmBinArrayAccountEvent, MeteoraDlmmLbPairAccountEvent,
+            MeteoraDlmmPositionV2AccountEvent,
+        },
+        DexEvent,
+    },
+    grpc::AccountPretty,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct StaticParameters {
+    pub base_factor: u16,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub variable_fee_control: u32,
+    pub max_volatility_accumulator: u32,
+    pub min_bin_id: i32,
+    pub max_bin_id: i32,
+    pub protocol_share: u16,
+    pub base_fee_power_factor: u8,
+    pub padding: [u8; 5],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct VariableParameters {
+    pub volatility_accumulator: u32,
+    pub volatility_reference: u32,
+    pub index_reference: i32,
+    pub padding: [u8; 4],
+    pub last_update_timestamp: i64,
+    pub padding1: [u8; 8],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ProtocolFee {
+    pub amount_x: u64,
+    pub amount_y: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RewardInfo {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub funder: Pubkey,
+    pub reward_duration: u64,
+    pub reward_duration_end: u64,
+    pub reward_rate: u128,
+    pub last_update_time: u64,
+    pub cumulative_seconds_with_empty_liquidity_reward: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct LbPair {
+    pub parameters: StaticParameters,
+    pub v_parameters: VariableParameters,
+    pub bump_seed: [u8; 1],
+    pub bin_step_seed: [u8; 2],
+    pub pair_type: u8,
+    pub active_id: i32,
+    pub bin_step: u16,
+    pub status: u8,
+    pub require_base_factor_seed: u8,
+    pub base_factor_seed: [u8; 2],
+    pub activation_type: u8,
+    pub creator_pool_on_off_control: u8,
+    pub token_x_mint: Pubkey,
+    pub token_y_mint: Pubkey,
+    pub reserve_x: Pubkey,
+    pub reserve_y: Pubkey,
+    pub protocol_fee: ProtocolFee,
+    pub padding1: [u8; 32],
+    pub reward_infos: [RewardInfo; 2],
+    pub oracle: Pubkey,
+    pub bin_array_bitmap: [u64; 16],
+    pub last_updated_at: i64,
+    pub padding2: [u8; 32],
+    pub pre_activation_swap_address: Pubkey,
+    pub base_key: Pubkey,
+    pub activation_point: u64,
+    pub pre_activation_duration: u64,
+    pub padding3: [u8; 8],
+    pub padding4: u64,
+    pub creator: Pubkey,
+    pub token_mint_x_program_flag: u8,
+    pub token_mint_y_program_flag: u8,
+    pub reserved: [u8; 22],
+}
+
+pub const LB_PAIR_SIZE: usize = 896;
+
+pub fn lb_pair_decode(data: &[u8]) -> Option<LbPair> {
+    if data.len() < LB_PAIR_SIZE {
+        return None;
+    }
+    borsh::from_slice::<LbPair>(&data[..LB_PAIR_SIZE]).ok()
+}
+
+pub fn lb_pair_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountMeteoraDlmmLbPair;
+
+    if account.data.len() < LB_PAIR_SIZE + 8 {
+        return None;
+    }
+    lb_pair_decode(&account.data[8..LB_PAIR_SIZE + 8]).map(|lb_pair| {
+        DexEvent::MeteoraDlmmLbPairAccountEvent(MeteoraDlmmLbPairAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            lb_pair,
+        })
+    })
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Bin {
+    pub amount_x: u64,
+    pub amount_y: u64,
+    pub price: u128,
+    pub liquidity_supply: u128,
+    pub reward_per_token_stored: [u128; 2],
+    pub fee_amount_x_per_token_stored: u128,
+    pub fee_amount_y_per_token_stored: u128,
+    pub amount_x_in: u128,
+    pub amount_y_in: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct BinArray {
+    pub index: i64,
+    pub version: u8,
+    pub padding: [u8; 7],
+    pub lb_pair: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub bins: [Bin; 70],
+}
+
+impl Default for BinArray {
+    fn default() -> Self {
+        Self {
+            index: 0,
+            version: 0,
+            padding: [0; 7],
+            lb_pair: Pubkey::default(),
+            bins: core::array::from_fn(|_| Bin::default()),
+        }
+    }
+}
+
+pub const BIN_ARRAY_SIZE: usize = 8 + 1 + 7 + 32 + 144 * 70;
+
+pub fn bin_array_decode(data: &[u8]) -> Option<BinArray> {
+    if data.len() < BIN_ARRAY_SIZE {
+        return None;
+    }
+    borsh::from_slice::<BinArray>(&data[..BIN_ARRAY_SIZE]).ok()
+}
+
+pub fn bin_array_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountMeteoraDlmmBinArray;
+
+    if account.data.len() < BIN_ARRAY_SIZE + 8 {
+        return None;
+    }
+    bin_array_decode(&account.data[8..BIN_ARRAY_SIZE + 8]).map(|bin_array| {
+        DexEvent::MeteoraDlmmBinArrayAccountEvent(MeteoraDlmmBinArrayAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            bin_array,
+        })
+    })
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct UserRewardInfo {
+    pub reward_per_token_completes: [u128; 2],
+    pub reward_pendings: [u64; 2],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FeeInfo {
+    pub fee_x_per_token_complete: u128,
+    pub fee_y_per_token_complete: u128,
+    pub fee_x_pending: u64,
+    pub fee_y_pending: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PositionV2 {
+    pub lb_pair: Pubkey,
+    pub owner: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub liquidity_shares: [u128; 70],
+    #[serde(with = "serde_big_array::BigArray")]
+    pub reward_infos: [UserRewardInfo; 70],
+    #[serde(with = "serde_big_array::BigArray")]
+    pub fee_infos: [FeeInfo; 70],
+    pub lower_bin_id: i32,
+    pub upper_bin_id: i32,
+    pub last_updated_at: i64,
+    pub total_claimed_fee_x_amount: u64,
+    pub total_claimed_fee_y_amount: u64,
+    pub total_claimed_rewards: [u64; 2],
+    pub operator: Pubkey,
+    pub lock_release_point: u64,
+    pub padding0: u8,
+    pub fee_owner: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub reserved: [u8; 87],
+}
+
+impl Default for PositionV2 {
+    fn default() -> Self {
+        Self {
+            lb_pair: Pubkey::default(),
+            owner: Pubkey::default(),
+            liquidity_shares: [0; 70],
+            reward_infos: core::array::from_fn(|_| UserRewardInfo::default()),
+            fee_infos: core::array::from_fn(|_| FeeInfo::default()),
+            lower_bin_id: 0,
+            upper_bin_id: 0,
+            last_updated_at: 0,
+            total_claimed_fee_x_amount: 0,
+            total_claimed_fee_y_amount: 0,
+            total_claimed_rewards: [0; 2],
+            operator: Pubkey::default(),
+            lock_release_point: 0,
+            padding0: 0,
+            fee_owner: Pubkey::default(),
+            reserved: [0; 87],
+        }
+    }
+}
+
+pub const POSITION_V2_SIZE: usize =
+    32 * 2 + 16 * 70 + 48 * 70 * 2 + 4 * 2 + 8 * 3 + 8 * 2 + 32 + 8 + 1 + 32 + 87;
+
+pub fn position_v2_decode(data: &[u8]) -> Option<PositionV2> {
+    if data.len() < POSITION_V2_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PositionV2>(&data[..POSITION_V2_SIZE]).ok()
+}
+
+pub fn position_v2_parser(
+    account: &AccountPretty,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountMeteoraDlmmPositionV2;
+
+    if account.data.len() < POSITION_V2_SIZE + 8 {
+        return None;
+    }
+    position_v2_decode(&account.data[8..POSITION_V2_SIZE + 8]).map(|position| {
+        DexEvent::MeteoraDlmmPositionV2AccountEvent(MeteoraDlmmPositionV2AccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            position,
+        })
+    })
+}
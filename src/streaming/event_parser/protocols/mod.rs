@@ -1,11 +1,17 @@
 pub mod block;
 pub mod bonk;
+pub mod jupiter;
+pub mod meteora_damm_v1;
 pub mod meteora_damm_v2;
+pub mod meteora_dlmm;
+pub mod orca_whirlpool;
 pub mod pumpfun;
 pub mod pumpswap;
 pub mod raydium_amm_v4;
 pub mod raydium_clmm;
 pub mod raydium_cpmm;
+pub mod spl_token;
+pub mod system;
 pub mod types;
 pub use block::block_meta_event::BlockMetaEvent;
 pub use types::Protocol;
@@ -0,0 +1,355 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::common::EventMetadata;
+use crate::streaming::event_parser::protocols::orca_whirlpool::types::{
+    Position, TickArray, Whirlpool,
+};
+
+/// 由 `idl/orca_whirlpool.json` 生成
+pub use crate::streaming::event_parser::idl::generated::orca_whirlpool::discriminators;
+
+/// Orca Whirlpool Swap Event (swap / swapV2)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OrcaWhirlpoolSwapEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 Traded 事件的数据
+    pub whirlpool: Pubkey,
+    pub a_to_b: bool,
+    pub pre_sqrt_price: u128,
+    pub post_sqrt_price: u128,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub input_transfer_fee: u64,
+    pub output_transfer_fee: u64,
+    pub lp_fee: u64,
+    pub protocol_fee: u64,
+
+    // 指令参数
+    #[borsh(skip)]
+    pub amount: u64,
+    #[borsh(skip)]
+    pub other_amount_threshold: u64,
+    #[borsh(skip)]
+    pub sqrt_price_limit: u128,
+    #[borsh(skip)]
+    pub amount_specified_is_input: bool,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub token_authority: Pubkey,
+    /// 仅 swapV2
+    #[borsh(skip)]
+    pub token_mint_a: Pubkey,
+    /// 仅 swapV2
+    #[borsh(skip)]
+    pub token_mint_b: Pubkey,
+    #[borsh(skip)]
+    pub token_owner_account_a: Pubkey,
+    #[borsh(skip)]
+    pub token_vault_a: Pubkey,
+    #[borsh(skip)]
+    pub token_owner_account_b: Pubkey,
+    #[borsh(skip)]
+    pub token_vault_b: Pubkey,
+    #[borsh(skip)]
+    pub tick_array_0: Pubkey,
+    #[borsh(skip)]
+    pub tick_array_1: Pubkey,
+    #[borsh(skip)]
+    pub tick_array_2: Pubkey,
+    #[borsh(skip)]
+    pub oracle: Pubkey,
+}
+
+/// twoHopSwap 中的单个池子
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolSwapLeg {
+    pub whirlpool: Pubkey,
+    pub a_to_b: bool,
+    pub sqrt_price_limit: u128,
+    pub tick_arrays: [Pubkey; 3],
+    pub oracle: Pubkey,
+
+    // 来自 Traded 事件的数据
+    pub pre_sqrt_price: u128,
+    pub post_sqrt_price: u128,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub input_transfer_fee: u64,
+    pub output_transfer_fee: u64,
+    pub lp_fee: u64,
+    pub protocol_fee: u64,
+}
+
+/// Orca Whirlpool Two Hop Swap Event (twoHopSwap / twoHopSwapV2)
+///
+/// 第一跳的输出即第二跳的输入，两个池子的 Traded 事件分别合并到 `leg_one` / `leg_two`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolTwoHopSwapEvent {
+    pub metadata: EventMetadata,
+
+    // 指令参数
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub amount_specified_is_input: bool,
+
+    pub leg_one: OrcaWhirlpoolSwapLeg,
+    pub leg_two: OrcaWhirlpoolSwapLeg,
+
+    // 来自 Input Accounts 的数据，twoHopSwap 按 a_to_b 换算为输入 / 中间 / 输出
+    pub token_authority: Pubkey,
+    pub token_owner_account_input: Pubkey,
+    pub token_owner_account_output: Pubkey,
+    pub token_vault_one_input: Pubkey,
+    pub token_vault_one_intermediate: Pubkey,
+    pub token_vault_two_intermediate: Pubkey,
+    pub token_vault_two_output: Pubkey,
+    /// 仅 twoHopSwapV2
+    pub token_mint_input: Pubkey,
+    /// 仅 twoHopSwapV2
+    pub token_mint_intermediate: Pubkey,
+    /// 仅 twoHopSwapV2
+    pub token_mint_output: Pubkey,
+}
+
+/// Orca Whirlpool Increase Liquidity Event (increaseLiquidity / increaseLiquidityV2)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OrcaWhirlpoolIncreaseLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 LiquidityIncreased 事件的数据
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub token_a_transfer_fee: u64,
+    pub token_b_transfer_fee: u64,
+
+    // 指令参数
+    #[borsh(skip)]
+    pub liquidity_amount: u128,
+    #[borsh(skip)]
+    pub token_max_a: u64,
+    #[borsh(skip)]
+    pub token_max_b: u64,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub position_authority: Pubkey,
+    #[borsh(skip)]
+    pub position_token_account: Pubkey,
+    /// 仅 increaseLiquidityV2
+    #[borsh(skip)]
+    pub token_mint_a: Pubkey,
+    /// 仅 increaseLiquidityV2
+    #[borsh(skip)]
+    pub token_mint_b: Pubkey,
+    #[borsh(skip)]
+    pub token_owner_account_a: Pubkey,
+    #[borsh(skip)]
+    pub token_owner_account_b: Pubkey,
+    #[borsh(skip)]
+    pub token_vault_a: Pubkey,
+    #[borsh(skip)]
+    pub token_vault_b: Pubkey,
+    #[borsh(skip)]
+    pub tick_array_lower: Pubkey,
+    #[borsh(skip)]
+    pub tick_array_upper: Pubkey,
+}
+
+/// Orca Whirlpool Decrease Liquidity Event (decreaseLiquidity / decreaseLiquidityV2)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OrcaWhirlpoolDecreaseLiquidityEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    // 来自 LiquidityDecreased 事件的数据
+    pub whirlpool: Pubkey,
+    pub position: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub token_a_transfer_fee: u64,
+    pub token_b_transfer_fee: u64,
+
+    // 指令参数
+    #[borsh(skip)]
+    pub liquidity_amount: u128,
+    #[borsh(skip)]
+    pub token_min_a: u64,
+    #[borsh(skip)]
+    pub token_min_b: u64,
+
+    // 来自 Input Accounts 的数据
+    #[borsh(skip)]
+    pub position_authority: Pubkey,
+    #[borsh(skip)]
+    pub position_token_account: Pubkey,
+    /// 仅 decreaseLiquidityV2
+    #[borsh(skip)]
+    pub token_mint_a: Pubkey,
+    /// 仅 decreaseLiquidityV2
+    #[borsh(skip)]
+    pub token_mint_b: Pubkey,
+    #[borsh(skip)]
+    pub token_owner_account_a: Pubkey,
+    #[borsh(skip)]
+    pub token_owner_account_b: Pubkey,
+    #[borsh(skip)]
+    pub token_vault_a: Pubkey,
+    #[borsh(skip)]
+    pub token_vault_b: Pubkey,
+    #[borsh(skip)]
+    pub tick_array_lower: Pubkey,
+    #[borsh(skip)]
+    pub tick_array_upper: Pubkey,
+}
+
+/// Orca Whirlpool Open Position Event (openPosition / openPositionWithMetadata / openPositionWithTokenExtensions)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolOpenPositionEvent {
+    pub metadata: EventMetadata,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    /// 仅 openPositionWithTokenExtensions
+    pub with_token_metadata_extension: bool,
+    pub funder: Pubkey,
+    pub owner: Pubkey,
+    pub position: Pubkey,
+    pub position_mint: Pubkey,
+    /// 仅 openPositionWithMetadata
+    pub position_metadata_account: Option<Pubkey>,
+    pub position_token_account: Pubkey,
+    pub whirlpool: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// Orca Whirlpool Close Position Event (closePosition / closePositionWithTokenExtensions)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolClosePositionEvent {
+    pub metadata: EventMetadata,
+    pub position_authority: Pubkey,
+    pub receiver: Pubkey,
+    pub position: Pubkey,
+    pub position_mint: Pubkey,
+    pub position_token_account: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// Orca Whirlpool Collect Fees Event (collectFees / collectFeesV2)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolCollectFeesEvent {
+    pub metadata: EventMetadata,
+    pub whirlpool: Pubkey,
+    pub position_authority: Pubkey,
+    pub position: Pubkey,
+    pub position_token_account: Pubkey,
+    /// 仅 collectFeesV2
+    pub token_mint_a: Pubkey,
+    /// 仅 collectFeesV2
+    pub token_mint_b: Pubkey,
+    pub token_owner_account_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub token_owner_account_b: Pubkey,
+    pub token_vault_b: Pubkey,
+}
+
+/// Orca Whirlpool Collect Reward Event (collectReward / collectRewardV2)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcaWhirlpoolCollectRewardEvent {
+    pub metadata: EventMetadata,
+    pub reward_index: u8,
+    pub whirlpool: Pubkey,
+    pub position_authority: Pubkey,
+    pub position: Pubkey,
+    pub position_token_account: Pubkey,
+    pub reward_owner_account: Pubkey,
+    /// 仅 collectRewardV2
+    pub reward_mint: Pubkey,
+    pub reward_vault: Pubkey,
+}
+
+/// Whirlpool 账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OrcaWhirlpoolWhirlpoolAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub whirlpool: Whirlpool,
+}
+
+/// TickArray 账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OrcaWhirlpoolTickArrayAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub tick_array: TickArray,
+}
+
+/// Position 账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct OrcaWhirlpoolPositionAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub position: Position,
+}
+
+/// Decode Traded event
+pub const ORCA_WHIRLPOOL_TRADED_EVENT_LOG_SIZE: usize = 113;
+pub fn orca_whirlpool_traded_event_decode(data: &[u8]) -> Option<OrcaWhirlpoolSwapEvent> {
+    if data.len() < ORCA_WHIRLPOOL_TRADED_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<OrcaWhirlpoolSwapEvent>(&data[..ORCA_WHIRLPOOL_TRADED_EVENT_LOG_SIZE]).ok()
+}
+
+/// Decode LiquidityIncreased / LiquidityDecreased event
+pub const ORCA_WHIRLPOOL_LIQUIDITY_EVENT_LOG_SIZE: usize = 120;
+pub fn orca_whirlpool_liquidity_increased_event_decode(
+    data: &[u8],
+) -> Option<OrcaWhirlpoolIncreaseLiquidityEvent> {
+    if data.len() < ORCA_WHIRLPOOL_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<OrcaWhirlpoolIncreaseLiquidityEvent>(
+        &data[..ORCA_WHIRLPOOL_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+pub fn orca_whirlpool_liquidity_decreased_event_decode(
+    data: &[u8],
+) -> Option<OrcaWhirlpoolDecreaseLiquidityEvent> {
+    if data.len() < ORCA_WHIRLPOOL_LIQUIDITY_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<OrcaWhirlpoolDecreaseLiquidityEvent>(
+        &data[..ORCA_WHIRLPOOL_LIQUIDITY_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
@@ -0,0 +1,683 @@
+use crate::streaming::event_parser::protocols::orca_whirlpool::types::{
+    position_parser, tick_array_parser, whirlpool_parser,
+};
+use crate::streaming::event_parser::{
+    common::{read_i32_le, read_u128_le, read_u64_le, read_u8_le, EventMetadata, EventType},
+    protocols::orca_whirlpool::{
+        discriminators, orca_whirlpool_liquidity_decreased_event_decode,
+        orca_whirlpool_liquidity_increased_event_decode, orca_whirlpool_traded_event_decode,
+        OrcaWhirlpoolClosePositionEvent, OrcaWhirlpoolCollectFeesEvent,
+        OrcaWhirlpoolCollectRewardEvent, OrcaWhirlpoolDecreaseLiquidityEvent,
+        OrcaWhirlpoolIncreaseLiquidityEvent, OrcaWhirlpoolOpenPositionEvent,
+        OrcaWhirlpoolSwapEvent, OrcaWhirlpoolSwapLeg, OrcaWhirlpoolTwoHopSwapEvent,
+    },
+    DexEvent,
+};
+use crate::streaming::grpc::AccountPretty;
+use solana_sdk::pubkey::Pubkey;
+
+/// Orca Whirlpool 程序ID
+pub const ORCA_WHIRLPOOL_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+
+/// 解析 Orca Whirlpool instruction data
+///
+/// 根据判别器路由到具体的 instruction 解析函数
+pub fn parse_orca_whirlpool_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::SWAP_IX => parse_swap_instruction(data, accounts, metadata),
+        discriminators::SWAP_V2_IX => parse_swap_v2_instruction(data, accounts, metadata),
+        discriminators::TWO_HOP_SWAP_IX => parse_two_hop_swap_instruction(data, accounts, metadata),
+        discriminators::TWO_HOP_SWAP_V2_IX => {
+            parse_two_hop_swap_v2_instruction(data, accounts, metadata)
+        }
+        discriminators::INCREASE_LIQUIDITY_IX => {
+            parse_increase_liquidity_instruction(data, accounts, false, metadata)
+        }
+        discriminators::INCREASE_LIQUIDITY_V2_IX => {
+            parse_increase_liquidity_instruction(data, accounts, true, metadata)
+        }
+        discriminators::DECREASE_LIQUIDITY_IX => {
+            parse_decrease_liquidity_instruction(data, accounts, false, metadata)
+        }
+        discriminators::DECREASE_LIQUIDITY_V2_IX => {
+            parse_decrease_liquidity_instruction(data, accounts, true, metadata)
+        }
+        discriminators::OPEN_POSITION_IX => {
+            parse_open_position_instruction(data, accounts, 1, false, metadata)
+        }
+        discriminators::OPEN_POSITION_WITH_METADATA_IX => {
+            parse_open_position_instruction(data, accounts, 2, true, metadata)
+        }
+        discriminators::OPEN_POSITION_WITH_TOKEN_EXTENSIONS_IX => {
+            parse_open_position_with_token_extensions_instruction(data, accounts, metadata)
+        }
+        discriminators::CLOSE_POSITION_IX => parse_close_position_instruction(
+            accounts,
+            EventType::OrcaWhirlpoolClosePosition,
+            metadata,
+        ),
+        discriminators::CLOSE_POSITION_WITH_TOKEN_EXTENSIONS_IX => {
+            parse_close_position_instruction(
+                accounts,
+                EventType::OrcaWhirlpoolClosePositionWithTokenExtensions,
+                metadata,
+            )
+        }
+        discriminators::COLLECT_FEES_IX => {
+            parse_collect_fees_instruction(accounts, false, metadata)
+        }
+        discriminators::COLLECT_FEES_V2_IX => {
+            parse_collect_fees_instruction(accounts, true, metadata)
+        }
+        discriminators::COLLECT_REWARD_IX => {
+            parse_collect_reward_instruction(data, accounts, false, metadata)
+        }
+        discriminators::COLLECT_REWARD_V2_IX => {
+            parse_collect_reward_instruction(data, accounts, true, metadata)
+        }
+        _ => None,
+    }
+}
+
+/// 解析 Orca Whirlpool inner instruction data
+///
+/// Whirlpool 通过 `emit!` 将事件写入 "Program data:" 日志，这里处理以 CPI 形式
+/// (EVENT_IX_TAG + 事件判别器) 出现的同名事件
+pub fn parse_orca_whirlpool_inner_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::TRADED_EVENT => parse_traded_inner_instruction(data, metadata),
+        discriminators::LIQUIDITY_INCREASED_EVENT => {
+            parse_liquidity_increased_inner_instruction(data, metadata)
+        }
+        discriminators::LIQUIDITY_DECREASED_EVENT => {
+            parse_liquidity_decreased_inner_instruction(data, metadata)
+        }
+        _ => None,
+    }
+}
+
+/// 解析 "Program data:" 日志中的 Orca Whirlpool 事件
+///
+/// `data` 为 base64 解码后的日志数据，以 8 字节事件判别器开头
+pub fn parse_orca_whirlpool_log_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    if data.len() < 8 {
+        return None;
+    }
+    let mut discriminator = discriminators::TRADED_EVENT[..8].to_vec();
+    discriminator.extend_from_slice(&data[..8]);
+    parse_orca_whirlpool_inner_instruction_data(&discriminator, &data[8..], metadata)
+}
+
+/// 解析 Orca Whirlpool 账户数据
+///
+/// 根据判别器路由到具体的账户解析函数
+pub fn parse_orca_whirlpool_account_data(
+    discriminator: &[u8],
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::WHIRLPOOL_ACCOUNT => whirlpool_parser(account, metadata),
+        discriminators::TICK_ARRAY_ACCOUNT => tick_array_parser(account, metadata),
+        discriminators::POSITION_ACCOUNT => position_parser(account, metadata),
+        _ => None,
+    }
+}
+
+/// 读取 swap / swapV2 指令参数
+fn read_swap_args(data: &[u8], event: &mut OrcaWhirlpoolSwapEvent) -> Option<()> {
+    event.amount = read_u64_le(data, 0)?;
+    event.other_amount_threshold = read_u64_le(data, 8)?;
+    event.sqrt_price_limit = read_u128_le(data, 16)?;
+    event.amount_specified_is_input = read_u8_le(data, 32)? == 1;
+    event.a_to_b = read_u8_le(data, 33)? == 1;
+    Some(())
+}
+
+/// 解析 swap 指令
+fn parse_swap_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolSwap;
+
+    if accounts.len() < 11 {
+        return None;
+    }
+
+    let mut event = OrcaWhirlpoolSwapEvent {
+        metadata,
+        token_authority: accounts[1],
+        whirlpool: accounts[2],
+        token_owner_account_a: accounts[3],
+        token_vault_a: accounts[4],
+        token_owner_account_b: accounts[5],
+        token_vault_b: accounts[6],
+        tick_array_0: accounts[7],
+        tick_array_1: accounts[8],
+        tick_array_2: accounts[9],
+        oracle: accounts[10],
+        ..Default::default()
+    };
+    read_swap_args(data, &mut event)?;
+    Some(DexEvent::OrcaWhirlpoolSwapEvent(event))
+}
+
+/// 解析 swapV2 指令
+fn parse_swap_v2_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolSwap;
+
+    if accounts.len() < 15 {
+        return None;
+    }
+
+    let mut event = OrcaWhirlpoolSwapEvent {
+        metadata,
+        token_authority: accounts[3],
+        whirlpool: accounts[4],
+        token_mint_a: accounts[5],
+        token_mint_b: accounts[6],
+        token_owner_account_a: accounts[7],
+        token_vault_a: accounts[8],
+        token_owner_account_b: accounts[9],
+        token_vault_b: accounts[10],
+        tick_array_0: accounts[11],
+        tick_array_1: accounts[12],
+        tick_array_2: accounts[13],
+        oracle: accounts[14],
+        ..Default::default()
+    };
+    read_swap_args(data, &mut event)?;
+    Some(DexEvent::OrcaWhirlpoolSwapEvent(event))
+}
+
+/// 读取 twoHopSwap / twoHopSwapV2 指令参数
+fn read_two_hop_swap_args(data: &[u8], event: &mut OrcaWhirlpoolTwoHopSwapEvent) -> Option<()> {
+    event.amount = read_u64_le(data, 0)?;
+    event.other_amount_threshold = read_u64_le(data, 8)?;
+    event.amount_specified_is_input = read_u8_le(data, 16)? == 1;
+    event.leg_one.a_to_b = read_u8_le(data, 17)? == 1;
+    event.leg_two.a_to_b = read_u8_le(data, 18)? == 1;
+    event.leg_one.sqrt_price_limit = read_u128_le(data, 19)?;
+    event.leg_two.sqrt_price_limit = read_u128_le(data, 35)?;
+    Some(())
+}
+
+/// 解析 twoHopSwap 指令
+///
+/// v1 账户按 A/B 排列，这里根据两跳各自的方向换算为输入 / 中间 / 输出账户
+fn parse_two_hop_swap_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolTwoHopSwap;
+
+    if accounts.len() < 20 {
+        return None;
+    }
+
+    let mut event = OrcaWhirlpoolTwoHopSwapEvent {
+        metadata,
+        token_authority: accounts[1],
+        leg_one: OrcaWhirlpoolSwapLeg {
+            whirlpool: accounts[2],
+            tick_arrays: [accounts[12], accounts[13], accounts[14]],
+            oracle: accounts[18],
+            ..Default::default()
+        },
+        leg_two: OrcaWhirlpoolSwapLeg {
+            whirlpool: accounts[3],
+            tick_arrays: [accounts[15], accounts[16], accounts[17]],
+            oracle: accounts[19],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    read_two_hop_swap_args(data, &mut event)?;
+
+    // (owner, vault) 按 a_to_b 选出 (输入, 输出)
+    let (one_in, one_out) = if event.leg_one.a_to_b { (4, 6) } else { (6, 4) };
+    let (two_in, two_out) = if event.leg_two.a_to_b { (8, 10) } else { (10, 8) };
+    event.token_owner_account_input = accounts[one_in];
+    event.token_vault_one_input = accounts[one_in + 1];
+    event.token_vault_one_intermediate = accounts[one_out + 1];
+    event.token_vault_two_intermediate = accounts[two_in + 1];
+    event.token_vault_two_output = accounts[two_out + 1];
+    event.token_owner_account_output = accounts[two_out];
+    Some(DexEvent::OrcaWhirlpoolTwoHopSwapEvent(event))
+}
+
+/// 解析 twoHopSwapV2 指令
+fn parse_two_hop_swap_v2_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolTwoHopSwap;
+
+    if accounts.len() < 23 {
+        return None;
+    }
+
+    let mut event = OrcaWhirlpoolTwoHopSwapEvent {
+        metadata,
+        leg_one: OrcaWhirlpoolSwapLeg {
+            whirlpool: accounts[0],
+            tick_arrays: [accounts[15], accounts[16], accounts[17]],
+            oracle: accounts[21],
+            ..Default::default()
+        },
+        leg_two: OrcaWhirlpoolSwapLeg {
+            whirlpool: accounts[1],
+            tick_arrays: [accounts[18], accounts[19], accounts[20]],
+            oracle: accounts[22],
+            ..Default::default()
+        },
+        token_mint_input: accounts[2],
+        token_mint_intermediate: accounts[3],
+        token_mint_output: accounts[4],
+        token_owner_account_input: accounts[8],
+        token_vault_one_input: accounts[9],
+        token_vault_one_intermediate: accounts[10],
+        token_vault_two_intermediate: accounts[11],
+        token_vault_two_output: accounts[12],
+        token_owner_account_output: accounts[13],
+        token_authority: accounts[14],
+        ..Default::default()
+    };
+    read_two_hop_swap_args(data, &mut event)?;
+    Some(DexEvent::OrcaWhirlpoolTwoHopSwapEvent(event))
+}
+
+/// 解析 increaseLiquidity / increaseLiquidityV2 指令，v2 指令额外带 token program、memo 与 mint 账户
+fn parse_increase_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    is_v2: bool,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolIncreaseLiquidity;
+
+    let mut event = if is_v2 {
+        if accounts.len() < 15 {
+            return None;
+        }
+        OrcaWhirlpoolIncreaseLiquidityEvent {
+            metadata,
+            whirlpool: accounts[0],
+            position_authority: accounts[4],
+            position: accounts[5],
+            position_token_account: accounts[6],
+            token_mint_a: accounts[7],
+            token_mint_b: accounts[8],
+            token_owner_account_a: accounts[9],
+            token_owner_account_b: accounts[10],
+            token_vault_a: accounts[11],
+            token_vault_b: accounts[12],
+            tick_array_lower: accounts[13],
+            tick_array_upper: accounts[14],
+            ..Default::default()
+        }
+    } else {
+        if accounts.len() < 11 {
+            return None;
+        }
+        OrcaWhirlpoolIncreaseLiquidityEvent {
+            metadata,
+            whirlpool: accounts[0],
+            position_authority: accounts[2],
+            position: accounts[3],
+            position_token_account: accounts[4],
+            token_owner_account_a: accounts[5],
+            token_owner_account_b: accounts[6],
+            token_vault_a: accounts[7],
+            token_vault_b: accounts[8],
+            tick_array_lower: accounts[9],
+            tick_array_upper: accounts[10],
+            ..Default::default()
+        }
+    };
+    event.liquidity_amount = read_u128_le(data, 0)?;
+    event.token_max_a = read_u64_le(data, 16)?;
+    event.token_max_b = read_u64_le(data, 24)?;
+    Some(DexEvent::OrcaWhirlpoolIncreaseLiquidityEvent(event))
+}
+
+/// 解析 decreaseLiquidity / decreaseLiquidityV2 指令，账户布局与增加流动性一致
+fn parse_decrease_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    is_v2: bool,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolDecreaseLiquidity;
+
+    let mut event = if is_v2 {
+        if accounts.len() < 15 {
+            return None;
+        }
+        OrcaWhirlpoolDecreaseLiquidityEvent {
+            metadata,
+            whirlpool: accounts[0],
+            position_authority: accounts[4],
+            position: accounts[5],
+            position_token_account: accounts[6],
+            token_mint_a: accounts[7],
+            token_mint_b: accounts[8],
+            token_owner_account_a: accounts[9],
+            token_owner_account_b: accounts[10],
+            token_vault_a: accounts[11],
+            token_vault_b: accounts[12],
+            tick_array_lower: accounts[13],
+            tick_array_upper: accounts[14],
+            ..Default::default()
+        }
+    } else {
+        if accounts.len() < 11 {
+            return None;
+        }
+        OrcaWhirlpoolDecreaseLiquidityEvent {
+            metadata,
+            whirlpool: accounts[0],
+            position_authority: accounts[2],
+            position: accounts[3],
+            position_token_account: accounts[4],
+            token_owner_account_a: accounts[5],
+            token_owner_account_b: accounts[6],
+            token_vault_a: accounts[7],
+            token_vault_b: accounts[8],
+            tick_array_lower: accounts[9],
+            tick_array_upper: accounts[10],
+            ..Default::default()
+        }
+    };
+    event.liquidity_amount = read_u128_le(data, 0)?;
+    event.token_min_a = read_u64_le(data, 16)?;
+    event.token_min_b = read_u64_le(data, 24)?;
+    Some(DexEvent::OrcaWhirlpoolDecreaseLiquidityEvent(event))
+}
+
+/// 解析 openPosition / openPositionWithMetadata 指令
+///
+/// 两者的参数都以 bumps 开头 (分别为 1 和 2 字节)，WithMetadata 在 positionMint 之后多出 metadata 账户
+fn parse_open_position_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    bumps_len: usize,
+    has_metadata: bool,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolOpenPosition;
+
+    let offset = if has_metadata { 1 } else { 0 };
+    if accounts.len() < 7 + offset {
+        return None;
+    }
+
+    Some(DexEvent::OrcaWhirlpoolOpenPositionEvent(OrcaWhirlpoolOpenPositionEvent {
+        metadata,
+        tick_lower_index: read_i32_le(data, bumps_len)?,
+        tick_upper_index: read_i32_le(data, bumps_len + 4)?,
+        funder: accounts[0],
+        owner: accounts[1],
+        position: accounts[2],
+        position_mint: accounts[3],
+        position_metadata_account: if has_metadata { Some(accounts[4]) } else { None },
+        position_token_account: accounts[4 + offset],
+        whirlpool: accounts[5 + offset],
+        token_program: accounts[6 + offset],
+        ..Default::default()
+    }))
+}
+
+/// 解析 openPositionWithTokenExtensions 指令
+fn parse_open_position_with_token_extensions_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolOpenPositionWithTokenExtensions;
+
+    if accounts.len() < 7 {
+        return None;
+    }
+
+    Some(DexEvent::OrcaWhirlpoolOpenPositionEvent(OrcaWhirlpoolOpenPositionEvent {
+        metadata,
+        tick_lower_index: read_i32_le(data, 0)?,
+        tick_upper_index: read_i32_le(data, 4)?,
+        with_token_metadata_extension: read_u8_le(data, 8)? == 1,
+        funder: accounts[0],
+        owner: accounts[1],
+        position: accounts[2],
+        position_mint: accounts[3],
+        position_metadata_account: None,
+        position_token_account: accounts[4],
+        whirlpool: accounts[5],
+        token_program: accounts[6],
+    }))
+}
+
+/// 解析 closePosition / closePositionWithTokenExtensions 指令，两者账户布局一致
+fn parse_close_position_instruction(
+    accounts: &[Pubkey],
+    event_type: EventType,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = event_type;
+
+    if accounts.len() < 6 {
+        return None;
+    }
+
+    Some(DexEvent::OrcaWhirlpoolClosePositionEvent(OrcaWhirlpoolClosePositionEvent {
+        metadata,
+        position_authority: accounts[0],
+        receiver: accounts[1],
+        position: accounts[2],
+        position_mint: accounts[3],
+        position_token_account: accounts[4],
+        token_program: accounts[5],
+    }))
+}
+
+/// 解析 collectFees / collectFeesV2 指令，v2 指令在 positionTokenAccount 之后多出两个 mint 账户
+fn parse_collect_fees_instruction(
+    accounts: &[Pubkey],
+    is_v2: bool,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolCollectFees;
+
+    let offset = if is_v2 { 2 } else { 0 };
+    if accounts.len() < 8 + offset {
+        return None;
+    }
+
+    Some(DexEvent::OrcaWhirlpoolCollectFeesEvent(OrcaWhirlpoolCollectFeesEvent {
+        metadata,
+        whirlpool: accounts[0],
+        position_authority: accounts[1],
+        position: accounts[2],
+        position_token_account: accounts[3],
+        token_mint_a: if is_v2 { accounts[4] } else { Pubkey::default() },
+        token_mint_b: if is_v2 { accounts[5] } else { Pubkey::default() },
+        token_owner_account_a: accounts[4 + offset],
+        token_vault_a: accounts[5 + offset],
+        token_owner_account_b: accounts[6 + offset],
+        token_vault_b: accounts[7 + offset],
+    }))
+}
+
+/// 解析 collectReward / collectRewardV2 指令，v2 指令在 rewardVault 之前多出 rewardMint 账户
+fn parse_collect_reward_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    is_v2: bool,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolCollectReward;
+
+    let offset = if is_v2 { 1 } else { 0 };
+    if accounts.len() < 6 + offset {
+        return None;
+    }
+
+    Some(DexEvent::OrcaWhirlpoolCollectRewardEvent(OrcaWhirlpoolCollectRewardEvent {
+        metadata,
+        reward_index: read_u8_le(data, 0)?,
+        whirlpool: accounts[0],
+        position_authority: accounts[1],
+        position: accounts[2],
+        position_token_account: accounts[3],
+        reward_owner_account: accounts[4],
+        reward_mint: if is_v2 { accounts[5] } else { Pubkey::default() },
+        reward_vault: accounts[5 + offset],
+    }))
+}
+
+/// 解析 Traded 事件
+fn parse_traded_inner_instruction(data: &[u8], mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolSwap;
+    orca_whirlpool_traded_event_decode(data)
+        .map(|event| DexEvent::OrcaWhirlpoolSwapEvent(OrcaWhirlpoolSwapEvent { metadata, ..event }))
+}
+
+/// 解析 LiquidityIncreased 事件
+fn parse_liquidity_increased_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolIncreaseLiquidity;
+    orca_whirlpool_liquidity_increased_event_decode(data).map(|event| {
+        DexEvent::OrcaWhirlpoolIncreaseLiquidityEvent(OrcaWhirlpoolIncreaseLiquidityEvent {
+            metadata,
+            ..event
+        })
+    })
+}
+
+/// 解析 LiquidityDecreased 事件
+fn parse_liquidity_decreased_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::OrcaWhirlpoolDecreaseLiquidity;
+    orca_whirlpool_liquidity_decreased_event_decode(data).map(|event| {
+        DexEvent::OrcaWhirlpoolDecreaseLiquidityEvent(OrcaWhirlpoolDecreaseLiquidityEvent {
+            metadata,
+            ..event
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        core::transaction_input::fixtures::{
+            account_index, inner_instruction, inner_instructions_meta, parse, program_data_meta,
+            transaction, unique_pubkeys,
+        },
+        Protocol,
+    };
+
+    fn traded_log(whirlpool: &Pubkey, a_to_b: bool, input: u64, output: u64) -> Vec<u8> {
+        let mut log = whirlpool.to_bytes().to_vec();
+        log.push(a_to_b as u8);
+        log.extend_from_slice(&100u128.to_le_bytes());
+        log.extend_from_slice(&90u128.to_le_bytes());
+        log.extend_from_slice(&input.to_le_bytes());
+        log.extend_from_slice(&output.to_le_bytes());
+        log.extend_from_slice(&0u64.to_le_bytes());
+        log.extend_from_slice(&0u64.to_le_bytes());
+        log.extend_from_slice(&3u64.to_le_bytes());
+        log.extend_from_slice(&1u64.to_le_bytes());
+        log
+    }
+
+    #[test]
+    fn test_swap_v2_merges_traded_event_and_sets_swap_data() {
+        let accounts = unique_pubkeys(15);
+        let mut data = discriminators::SWAP_V2_IX.to_vec();
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&900u64.to_le_bytes());
+        data.extend_from_slice(&0u128.to_le_bytes());
+        data.extend_from_slice(&[1, 0]);
+        let tx = transaction(ORCA_WHIRLPOOL_PROGRAM_ID, &data, &accounts);
+
+        let mut log = discriminators::TRADED_EVENT.to_vec();
+        log.extend_from_slice(&traded_log(&accounts[4], false, 1_000, 950));
+        let program_index = account_index(&tx, &ORCA_WHIRLPOOL_PROGRAM_ID);
+        let meta = inner_instructions_meta(vec![inner_instruction(program_index, log, vec![])]);
+
+        let events = parse(&[Protocol::OrcaWhirlpool], &tx, &meta);
+
+        assert_eq!(events.len(), 1);
+        let DexEvent::OrcaWhirlpoolSwapEvent(event) = &events[0] else { panic!("expected swap") };
+        assert_eq!(event.metadata.event_type, EventType::OrcaWhirlpoolSwap);
+        assert_eq!((event.amount, event.other_amount_threshold), (1_000, 900));
+        assert!(event.amount_specified_is_input);
+        assert_eq!((event.output_amount, event.lp_fee), (950, 3));
+        let swap_data = event.metadata.swap_data.as_ref().unwrap();
+        // a_to_b = false: B -> A
+        assert_eq!((swap_data.from_mint, swap_data.to_mint), (accounts[6], accounts[5]));
+        assert_eq!((swap_data.from_amount, swap_data.to_amount), (1_000, 950));
+    }
+
+    #[test]
+    fn test_two_hop_swap_assigns_traded_events_to_legs() {
+        let accounts = unique_pubkeys(20);
+        let mut data = discriminators::TWO_HOP_SWAP_IX.to_vec();
+        data.extend_from_slice(&500u64.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&[1, 1, 0]);
+        data.extend_from_slice(&[0u8; 32]);
+        let tx = transaction(ORCA_WHIRLPOOL_PROGRAM_ID, &data, &accounts);
+
+        // Traded 事件通过 emit! 输出：8 字节事件 discriminator + 事件数据
+        let emitted = |whirlpool: &Pubkey, output: u64| {
+            let mut log = discriminators::TRADED_EVENT[8..].to_vec();
+            log.extend_from_slice(&traded_log(whirlpool, true, 1, output));
+            log
+        };
+        let meta = program_data_meta(
+            ORCA_WHIRLPOOL_PROGRAM_ID,
+            &[emitted(&accounts[2], 70), emitted(&accounts[3], 40)],
+        );
+
+        let events = parse(&[Protocol::OrcaWhirlpool], &tx, &meta);
+
+        assert_eq!(events.len(), 1);
+        let DexEvent::OrcaWhirlpoolTwoHopSwapEvent(e) = &events[0] else {
+            panic!("expected two hop swap")
+        };
+        // 第一跳 A -> B，第二跳 B -> A
+        assert_eq!(
+            (e.token_owner_account_input, e.token_vault_one_input),
+            (accounts[4], accounts[5])
+        );
+        assert_eq!(
+            (e.token_vault_two_output, e.token_owner_account_output),
+            (accounts[9], accounts[8])
+        );
+        assert_eq!((e.leg_one.whirlpool, e.leg_one.output_amount), (accounts[2], 70));
+        assert_eq!((e.leg_two.whirlpool, e.leg_two.output_amount), (accounts[3], 40));
+    }
+}
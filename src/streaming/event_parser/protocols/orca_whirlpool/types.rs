@@ -0,0 +1,271 @@
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use yellowstone_grpc_proto::geyser::{
+    subscribe_request_filter_accounts_filter::Filter,
+    subscribe_request_filter_accounts_filter_memcmp::Data, SubscribeRequestFilterAccountsFilter,
+    SubscribeRequestFilterAccountsFilterMemcmp,
+};
+
+use crate::streaming::{
+    event_parser::{
+        common::{EventMetadata, EventType},
+        protocols::orca_whirlpool::{
+            discriminators, OrcaWhirlpoolPositionAccountEvent, OrcaWhirlpoolTickArrayAccountEvent,
+            OrcaWhirlpoolWhirlpoolAccountEvent,
+        },
+        DexEvent,
+    },
+    grpc::AccountPretty,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WhirlpoolRewardInfo {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub emissions_per_second_x64: u128,
+    pub growth_global_x64: u128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Whirlpool {
+    pub whirlpools_config: Pubkey,
+    pub whirlpool_bump: [u8; 1],
+    pub tick_spacing: u16,
+    pub fee_tier_index_seed: [u8; 2],
+    pub fee_rate: u16,
+    pub protocol_fee_rate: u16,
+    pub liquidity: u128,
+    pub sqrt_price: u128,
+    pub tick_current_index: i32,
+    pub protocol_fee_owed_a: u64,
+    pub protocol_fee_owed_b: u64,
+    pub token_mint_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub fee_growth_global_a: u128,
+    pub token_mint_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub fee_growth_global_b: u128,
+    pub reward_last_updated_timestamp: u64,
+    pub reward_infos: [WhirlpoolRewardInfo; 3],
+}
+
+pub const WHIRLPOOL_SIZE: usize =
+    32 + 1 + 2 + 2 + 2 + 2 + 16 * 2 + 4 + 8 * 2 + (32 * 2 + 16) * 2 + 8 + 128 * 3;
+
+pub fn whirlpool_decode(data: &[u8]) -> Option<Whirlpool> {
+    if data.len() < WHIRLPOOL_SIZE {
+        return None;
+    }
+    borsh::from_slice::<Whirlpool>(&data[..WHIRLPOOL_SIZE]).ok()
+}
+
+pub fn whirlpool_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountOrcaWhirlpoolWhirlpool;
+
+    if account.data.len() < WHIRLPOOL_SIZE + 8 {
+        return None;
+    }
+    whirlpool_decode(&account.data[8..WHIRLPOOL_SIZE + 8]).map(|whirlpool| {
+        DexEvent::OrcaWhirlpoolWhirlpoolAccountEvent(OrcaWhirlpoolWhirlpoolAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            whirlpool,
+        })
+    })
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Tick {
+    pub initialized: bool,
+    pub liquidity_net: i128,
+    pub liquidity_gross: u128,
+    pub fee_growth_outside_a: u128,
+    pub fee_growth_outside_b: u128,
+    pub reward_growths_outside: [u128; 3],
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TickArray {
+    pub start_tick_index: i32,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub ticks: [Tick; 88],
+    pub whirlpool: Pubkey,
+}
+
+impl Default for TickArray {
+    fn default() -> Self {
+        Self {
+            start_tick_index: 0,
+            ticks: core::array::from_fn(|_| Tick::default()),
+            whirlpool: Pubkey::default(),
+        }
+    }
+}
+
+pub const TICK_ARRAY_SIZE: usize = 4 + 113 * 88 + 32;
+
+pub fn tick_array_decode(data: &[u8]) -> Option<TickArray> {
+    if data.len() < TICK_ARRAY_SIZE {
+        return None;
+    }
+    borsh::from_slice::<TickArray>(&data[..TICK_ARRAY_SIZE]).ok()
+}
+
+pub fn tick_array_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountOrcaWhirlpoolTickArray;
+
+    if account.data.len() < TICK_ARRAY_SIZE + 8 {
+        return None;
+    }
+    tick_array_decode(&account.data[8..TICK_ARRAY_SIZE + 8]).map(|tick_array| {
+        DexEvent::OrcaWhirlpoolTickArrayAccountEvent(OrcaWhirlpoolTickArrayAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            tick_array,
+        })
+    })
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PositionRewardInfo {
+    pub growth_inside_checkpoint: u128,
+    pub amount_owed: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Position {
+    pub whirlpool: Pubkey,
+    pub position_mint: Pubkey,
+    pub liquidity: u128,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub fee_growth_checkpoint_a: u128,
+    pub fee_owed_a: u64,
+    pub fee_growth_checkpoint_b: u128,
+    pub fee_owed_b: u64,
+    pub reward_infos: [PositionRewardInfo; 3],
+}
+
+pub const POSITION_SIZE: usize = 32 * 2 + 16 + 4 * 2 + (16 + 8) * 2 + 24 * 3;
+
+pub fn position_decode(data: &[u8]) -> Option<Position> {
+    if data.len() < POSITION_SIZE {
+        return None;
+    }
+    borsh::from_slice::<Position>(&data[..POSITION_SIZE]).ok()
+}
+
+pub fn position_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountOrcaWhirlpoolPosition;
+
+    if account.data.len() < POSITION_SIZE + 8 {
+        return None;
+    }
+    position_decode(&account.data[8..POSITION_SIZE + 8]).map(|position| {
+        DexEvent::OrcaWhirlpoolPositionAccountEvent(OrcaWhirlpoolPositionAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            position,
+        })
+    })
+}
+
+/// 账户数据中各字段的偏移量（包含 8 字节判别器），用于构造 memcmp 过滤器
+pub mod offsets {
+    pub const WHIRLPOOL_WHIRLPOOLS_CONFIG: u64 = 8;
+    pub const WHIRLPOOL_TOKEN_MINT_A: u64 = 101;
+    pub const WHIRLPOOL_TOKEN_MINT_B: u64 = 181;
+    pub const TICK_ARRAY_WHIRLPOOL: u64 = 9956;
+    pub const POSITION_WHIRLPOOL: u64 = 8;
+    pub const POSITION_POSITION_MINT: u64 = 40;
+}
+
+fn memcmp_filter(offset: u64, bytes: Vec<u8>) -> SubscribeRequestFilterAccountsFilter {
+    SubscribeRequestFilterAccountsFilter {
+        filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
+            offset,
+            data: Some(Data::Bytes(bytes)),
+        })),
+    }
+}
+
+/// 只订阅 Whirlpool 账户
+pub fn whirlpool_account_filter() -> SubscribeRequestFilterAccountsFilter {
+    memcmp_filter(0, discriminators::WHIRLPOOL_ACCOUNT.to_vec())
+}
+
+/// 只订阅 TickArray 账户
+pub fn tick_array_account_filter() -> SubscribeRequestFilterAccountsFilter {
+    memcmp_filter(0, discriminators::TICK_ARRAY_ACCOUNT.to_vec())
+}
+
+/// 只订阅 Position 账户
+pub fn position_account_filter() -> SubscribeRequestFilterAccountsFilter {
+    memcmp_filter(0, discriminators::POSITION_ACCOUNT.to_vec())
+}
+
+/// 按 WhirlpoolsConfig 过滤 Whirlpool 账户
+pub fn whirlpool_by_config_filter(config: &Pubkey) -> SubscribeRequestFilterAccountsFilter {
+    memcmp_filter(offsets::WHIRLPOOL_WHIRLPOOLS_CONFIG, config.to_bytes().to_vec())
+}
+
+/// 按 token_mint_a 过滤 Whirlpool 账户
+pub fn whirlpool_by_token_mint_a_filter(mint: &Pubkey) -> SubscribeRequestFilterAccountsFilter {
+    memcmp_filter(offsets::WHIRLPOOL_TOKEN_MINT_A, mint.to_bytes().to_vec())
+}
+
+/// 按 token_mint_b 过滤 Whirlpool 账户
+pub fn whirlpool_by_token_mint_b_filter(mint: &Pubkey) -> SubscribeRequestFilterAccountsFilter {
+    memcmp_filter(offsets::WHIRLPOOL_TOKEN_MINT_B, mint.to_bytes().to_vec())
+}
+
+/// 按所属池子过滤 TickArray 账户
+pub fn tick_array_by_whirlpool_filter(whirlpool: &Pubkey) -> SubscribeRequestFilterAccountsFilter {
+    memcmp_filter(offsets::TICK_ARRAY_WHIRLPOOL, whirlpool.to_bytes().to_vec())
+}
+
+/// 按所属池子过滤 Position 账户
+pub fn position_by_whirlpool_filter(whirlpool: &Pubkey) -> SubscribeRequestFilterAccountsFilter {
+    memcmp_filter(offsets::POSITION_WHIRLPOOL, whirlpool.to_bytes().to_vec())
+}
+
+/// 按仓位 NFT mint 过滤 Position 账户
+pub fn position_by_mint_filter(position_mint: &Pubkey) -> SubscribeRequestFilterAccountsFilter {
+    memcmp_filter(offsets::POSITION_POSITION_MINT, position_mint.to_bytes().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_account_sizes_and_filter_offsets() {
+        assert_eq!(WHIRLPOOL_SIZE, 645);
+        assert_eq!(TICK_ARRAY_SIZE, 9980);
+        assert_eq!(POSITION_SIZE, 208);
+
+        let mint_a = Pubkey::new_unique();
+        let mint_b = Pubkey::new_unique();
+        let mut data = vec![0u8; WHIRLPOOL_SIZE + 8];
+        let a = offsets::WHIRLPOOL_TOKEN_MINT_A as usize;
+        let b = offsets::WHIRLPOOL_TOKEN_MINT_B as usize;
+        data[a..a + 32].copy_from_slice(mint_a.as_ref());
+        data[b..b + 32].copy_from_slice(mint_b.as_ref());
+        let whirlpool = whirlpool_decode(&data[8..]).unwrap();
+        assert_eq!((whirlpool.token_mint_a, whirlpool.token_mint_b), (mint_a, mint_b));
+    }
+}
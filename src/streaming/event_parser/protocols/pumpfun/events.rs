@@ -3,7 +3,10 @@ use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
 use crate::streaming::event_parser::common::EventMetadata;
-use crate::streaming::event_parser::protocols::pumpfun::types::{BondingCurve, Global};
+use crate::streaming::event_parser::protocols::pumpfun::types::{
+    BondingCurve, FeeConfig, Global, GlobalVolumeAccumulator, Shareholder, SharingConfig,
+    UserVolumeAccumulator,
+};
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct PumpFunCreateTokenEvent {
@@ -323,7 +326,8 @@ pub fn pumpfun_trade_event_log_decode(data: &[u8]) -> Option<PumpFunTradeEvent>
     if data.len() < PUMPFUN_TRADE_EVENT_LOG_SIZE {
         return None;
     }
-    let mut event = borsh::from_slice::<PumpFunTradeEvent>(&data[..PUMPFUN_TRADE_EVENT_LOG_SIZE]).ok()?;
+    let mut event =
+        borsh::from_slice::<PumpFunTradeEvent>(&data[..PUMPFUN_TRADE_EVENT_LOG_SIZE]).ok()?;
     let mut offset = PUMPFUN_TRADE_EVENT_LOG_SIZE;
     if offset < data.len() {
         let (ix_name, inc) = read_borsh_string(data, offset).unwrap_or((String::new(), 0));
@@ -335,7 +339,8 @@ pub fn pumpfun_trade_event_log_decode(data: &[u8]) -> Option<PumpFunTradeEvent>
         offset += 1;
     }
     if offset + 8 <= data.len() {
-        event.cashback_fee_basis_points = u64::from_le_bytes(data[offset..offset + 8].try_into().ok()?);
+        event.cashback_fee_basis_points =
+            u64::from_le_bytes(data[offset..offset + 8].try_into().ok()?);
         offset += 8;
     }
     if offset + 8 <= data.len() {
@@ -424,6 +429,374 @@ pub fn pumpfun_migrate_event_log_decode(data: &[u8]) -> Option<PumpFunMigrateEve
     borsh::from_slice::<PumpFunMigrateEvent>(&data[..PUMPFUN_MIGRATE_EVENT_LOG_SIZE]).ok()
 }
 
+/// 联合曲线完成事件 (CompleteEvent)：买入使联合曲线填满时由 `emit_cpi!` 单独发出，不对应任何指令
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunCompleteEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub timestamp: i64,
+}
+
+pub const PUMPFUN_COMPLETE_EVENT_LOG_SIZE: usize = 104;
+
+pub fn pumpfun_complete_event_log_decode(data: &[u8]) -> Option<PumpFunCompleteEvent> {
+    if data.len() < PUMPFUN_COMPLETE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunCompleteEvent>(&data[..PUMPFUN_COMPLETE_EVENT_LOG_SIZE]).ok()
+}
+
+/// collect_creator_fee：创作者提取 creator_vault 中的手续费
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunCollectCreatorFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    pub timestamp: i64,
+    pub creator: Pubkey,
+    pub creator_fee: u64,
+
+    #[borsh(skip)]
+    pub creator_vault: Pubkey,
+    #[borsh(skip)]
+    pub system_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_COLLECT_CREATOR_FEE_EVENT_LOG_SIZE: usize = 48;
+
+pub fn pumpfun_collect_creator_fee_event_log_decode(
+    data: &[u8],
+) -> Option<PumpFunCollectCreatorFeeEvent> {
+    if data.len() < PUMPFUN_COLLECT_CREATOR_FEE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunCollectCreatorFeeEvent>(
+        &data[..PUMPFUN_COLLECT_CREATOR_FEE_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// distribute_creator_fees：按 sharing_config 将创作者手续费分配给各 shareholder
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunDistributeCreatorFeesEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    pub timestamp: i64,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub sharing_config: Pubkey,
+    pub admin: Pubkey,
+    pub shareholders: Vec<Shareholder>,
+    pub distributed: u64,
+
+    #[borsh(skip)]
+    pub creator_vault: Pubkey,
+    #[borsh(skip)]
+    pub system_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+/// shareholders 为变长数组，按 borsh 顺序解码，忽略尾部多余字节
+pub fn pumpfun_distribute_creator_fees_event_log_decode(
+    data: &[u8],
+) -> Option<PumpFunDistributeCreatorFeesEvent> {
+    BorshDeserialize::deserialize(&mut &data[..]).ok()
+}
+
+/// claim_cashback：用户领取 cashback
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunClaimCashbackEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    pub user: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub total_claimed: u64,
+    pub total_cashback_earned: u64,
+
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub system_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_CLAIM_CASHBACK_EVENT_LOG_SIZE: usize = 64;
+
+pub fn pumpfun_claim_cashback_event_log_decode(data: &[u8]) -> Option<PumpFunClaimCashbackEvent> {
+    if data.len() < PUMPFUN_CLAIM_CASHBACK_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunClaimCashbackEvent>(&data[..PUMPFUN_CLAIM_CASHBACK_EVENT_LOG_SIZE])
+        .ok()
+}
+
+/// set_creator：设置联合曲线的创作者
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunSetCreatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    pub timestamp: i64,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub creator: Pubkey,
+
+    #[borsh(skip)]
+    pub set_creator_authority: Pubkey,
+    #[borsh(skip)]
+    pub global: Pubkey,
+    #[borsh(skip)]
+    pub metadata_account: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_SET_CREATOR_EVENT_LOG_SIZE: usize = 104;
+
+pub fn pumpfun_set_creator_event_log_decode(data: &[u8]) -> Option<PumpFunSetCreatorEvent> {
+    if data.len() < PUMPFUN_SET_CREATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunSetCreatorEvent>(&data[..PUMPFUN_SET_CREATOR_EVENT_LOG_SIZE]).ok()
+}
+
+/// set_metaplex_creator：以 Metaplex metadata 中的创作者作为联合曲线创作者
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunSetMetaplexCreatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    pub timestamp: i64,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub metadata_account: Pubkey,
+    pub creator: Pubkey,
+
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_SET_METAPLEX_CREATOR_EVENT_LOG_SIZE: usize = 136;
+
+pub fn pumpfun_set_metaplex_creator_event_log_decode(
+    data: &[u8],
+) -> Option<PumpFunSetMetaplexCreatorEvent> {
+    if data.len() < PUMPFUN_SET_METAPLEX_CREATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunSetMetaplexCreatorEvent>(
+        &data[..PUMPFUN_SET_METAPLEX_CREATOR_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// migrate_bonding_curve_creator：将联合曲线创作者迁移为 sharing_config
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunMigrateBondingCurveCreatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    pub timestamp: i64,
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub sharing_config: Pubkey,
+    pub old_creator: Pubkey,
+    pub new_creator: Pubkey,
+
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_MIGRATE_BONDING_CURVE_CREATOR_EVENT_LOG_SIZE: usize = 168;
+
+pub fn pumpfun_migrate_bonding_curve_creator_event_log_decode(
+    data: &[u8],
+) -> Option<PumpFunMigrateBondingCurveCreatorEvent> {
+    if data.len() < PUMPFUN_MIGRATE_BONDING_CURVE_CREATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunMigrateBondingCurveCreatorEvent>(
+        &data[..PUMPFUN_MIGRATE_BONDING_CURVE_CREATOR_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// init_user_volume_accumulator
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunInitUserVolumeAccumulatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    pub payer: Pubkey,
+    pub user: Pubkey,
+    pub timestamp: i64,
+
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub system_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_INIT_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE: usize = 72;
+
+pub fn pumpfun_init_user_volume_accumulator_event_log_decode(
+    data: &[u8],
+) -> Option<PumpFunInitUserVolumeAccumulatorEvent> {
+    if data.len() < PUMPFUN_INIT_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunInitUserVolumeAccumulatorEvent>(
+        &data[..PUMPFUN_INIT_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// sync_user_volume_accumulator
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunSyncUserVolumeAccumulatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    pub user: Pubkey,
+    pub total_claimed_tokens_before: u64,
+    pub total_claimed_tokens_after: u64,
+    pub timestamp: i64,
+
+    #[borsh(skip)]
+    pub global_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_SYNC_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE: usize = 56;
+
+pub fn pumpfun_sync_user_volume_accumulator_event_log_decode(
+    data: &[u8],
+) -> Option<PumpFunSyncUserVolumeAccumulatorEvent> {
+    if data.len() < PUMPFUN_SYNC_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunSyncUserVolumeAccumulatorEvent>(
+        &data[..PUMPFUN_SYNC_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// close_user_volume_accumulator
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunCloseUserVolumeAccumulatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    pub user: Pubkey,
+    pub timestamp: i64,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    pub last_update_timestamp: i64,
+
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+}
+
+pub const PUMPFUN_CLOSE_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE: usize = 72;
+
+pub fn pumpfun_close_user_volume_accumulator_event_log_decode(
+    data: &[u8],
+) -> Option<PumpFunCloseUserVolumeAccumulatorEvent> {
+    if data.len() < PUMPFUN_CLOSE_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunCloseUserVolumeAccumulatorEvent>(
+        &data[..PUMPFUN_CLOSE_USER_VOLUME_ACCUMULATOR_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// claim_token_incentives：按交易量领取代币激励
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpFunClaimTokenIncentivesEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+
+    #[borsh(skip)]
+    pub user_ata: Pubkey,
+    #[borsh(skip)]
+    pub global_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub global_incentive_token_account: Pubkey,
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub token_program: Pubkey,
+    #[borsh(skip)]
+    pub system_program: Pubkey,
+    #[borsh(skip)]
+    pub associated_token_program: Pubkey,
+    #[borsh(skip)]
+    pub event_authority: Pubkey,
+    #[borsh(skip)]
+    pub program: Pubkey,
+    #[borsh(skip)]
+    pub payer: Pubkey,
+}
+
+pub const PUMPFUN_CLAIM_TOKEN_INCENTIVES_EVENT_LOG_SIZE: usize = 96;
+
+pub fn pumpfun_claim_token_incentives_event_log_decode(
+    data: &[u8],
+) -> Option<PumpFunClaimTokenIncentivesEvent> {
+    if data.len() < PUMPFUN_CLAIM_TOKEN_INCENTIVES_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpFunClaimTokenIncentivesEvent>(
+        &data[..PUMPFUN_CLAIM_TOKEN_INCENTIVES_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
 /// Bonding curve
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct PumpFunBondingCurveAccountEvent {
@@ -450,6 +823,54 @@ pub struct PumpFunGlobalAccountEvent {
     pub global: Global,
 }
 
+/// Fee config
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpFunFeeConfigAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub fee_config: FeeConfig,
+}
+
+/// Creator fee sharing config
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpFunSharingConfigAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub sharing_config: SharingConfig,
+}
+
+/// Global volume accumulator
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpFunGlobalVolumeAccumulatorAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub global_volume_accumulator: GlobalVolumeAccumulator,
+}
+
+/// User volume accumulator
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpFunUserVolumeAccumulatorAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub user_volume_accumulator: UserVolumeAccumulator,
+}
+
 /// Event discriminator constants
 pub mod discriminators {
     // Event discriminators
@@ -462,6 +883,31 @@ pub mod discriminators {
     // pub const COMPLETE_PUMP_AMM_MIGRATION_EVENT: &str = "0xe445a52e51cb9a1dbde95db95c94ea94";
     pub const COMPLETE_PUMP_AMM_MIGRATION_EVENT: &[u8] =
         &[228, 69, 165, 46, 81, 203, 154, 29, 189, 233, 93, 185, 92, 148, 234, 148];
+    pub const COMPLETE_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 95, 114, 97, 156, 212, 46, 152, 8];
+    pub const COLLECT_CREATOR_FEE_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 122, 2, 127, 1, 14, 191, 12, 175];
+    pub const DISTRIBUTE_CREATOR_FEES_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 165, 55, 129, 112, 4, 179, 202, 40];
+    pub const CLAIM_CASHBACK_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 226, 214, 246, 33, 7, 242, 147, 229];
+    pub const SET_CREATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 237, 52, 123, 37, 245, 251, 72, 210];
+    pub const SET_METAPLEX_CREATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 142, 203, 6, 32, 127, 105, 191, 162];
+    pub const MIGRATE_BONDING_CURVE_CREATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 155, 167, 104, 220, 213, 108, 243, 3];
+    pub const INIT_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 134, 36, 13, 72, 232, 101, 130, 216];
+    pub const SYNC_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 197, 122, 167, 124, 116, 81, 91, 255];
+    pub const CLOSE_USER_VOLUME_ACCUMULATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 146, 159, 189, 172, 146, 88, 56, 244];
+    pub const CLAIM_TOKEN_INCENTIVES_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 79, 172, 246, 49, 205, 91, 206, 232];
+
+    /// `emit_cpi!` 自调用指令的前 8 字节，其后紧跟事件判别器
+    pub const EVENT_IX_TAG: &[u8] = &[228, 69, 165, 46, 81, 203, 154, 29];
 
     // Instruction discriminators
     pub const CREATE_TOKEN_IX: &[u8] = &[24, 30, 200, 40, 5, 28, 7, 119];
@@ -470,8 +916,22 @@ pub mod discriminators {
     pub const BUY_EXACT_SOL_IN_IX: &[u8] = &[56, 252, 116, 8, 158, 223, 205, 95];
     pub const SELL_IX: &[u8] = &[51, 230, 133, 164, 1, 127, 131, 173];
     pub const MIGRATE_IX: &[u8] = &[155, 234, 231, 146, 236, 158, 162, 30];
+    pub const COLLECT_CREATOR_FEE_IX: &[u8] = &[20, 22, 86, 123, 198, 28, 219, 132];
+    pub const DISTRIBUTE_CREATOR_FEES_IX: &[u8] = &[165, 114, 103, 0, 121, 206, 247, 81];
+    pub const CLAIM_CASHBACK_IX: &[u8] = &[37, 58, 35, 126, 190, 53, 228, 197];
+    pub const SET_CREATOR_IX: &[u8] = &[254, 148, 255, 112, 207, 142, 170, 165];
+    pub const SET_METAPLEX_CREATOR_IX: &[u8] = &[138, 96, 174, 217, 48, 85, 197, 246];
+    pub const MIGRATE_BONDING_CURVE_CREATOR_IX: &[u8] = &[87, 124, 52, 191, 52, 38, 214, 232];
+    pub const INIT_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[94, 6, 202, 115, 255, 96, 232, 183];
+    pub const SYNC_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[86, 31, 192, 87, 163, 87, 79, 238];
+    pub const CLOSE_USER_VOLUME_ACCUMULATOR_IX: &[u8] = &[249, 69, 164, 218, 150, 103, 84, 138];
+    pub const CLAIM_TOKEN_INCENTIVES_IX: &[u8] = &[16, 4, 71, 28, 204, 1, 40, 27];
 
     // Account discriminators
     pub const BONDING_CURVE_ACCOUNT: &[u8] = &[23, 183, 248, 55, 96, 216, 172, 96];
     pub const GLOBAL_ACCOUNT: &[u8] = &[167, 232, 232, 177, 200, 108, 114, 127];
+    pub const FEE_CONFIG_ACCOUNT: &[u8] = &[143, 52, 146, 187, 219, 123, 76, 155];
+    pub const SHARING_CONFIG_ACCOUNT: &[u8] = &[216, 74, 9, 0, 56, 140, 93, 75];
+    pub const GLOBAL_VOLUME_ACCUMULATOR_ACCOUNT: &[u8] = &[202, 42, 246, 43, 142, 190, 30, 255];
+    pub const USER_VOLUME_ACCUMULATOR_ACCOUNT: &[u8] = &[86, 255, 112, 14, 102, 53, 154, 250];
 }
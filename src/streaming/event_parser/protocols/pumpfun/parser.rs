@@ -1,9 +1,22 @@
 use crate::streaming::event_parser::{
-    common::{EventMetadata, EventType},
+    common::{EventMetadata, EventType, ProtocolType},
+    core::parser_cache::AccountEventParseConfig,
     protocols::pumpfun::{
-        discriminators, pumpfun_create_v2_token_event_log_decode, pumpfun_migrate_event_log_decode,
-        pumpfun_trade_event_log_decode, PumpFunCreateTokenEvent, PumpFunCreateV2TokenEvent,
-        PumpFunMigrateEvent, PumpFunTradeEvent,
+        discriminators, pumpfun_claim_cashback_event_log_decode,
+        pumpfun_claim_token_incentives_event_log_decode,
+        pumpfun_close_user_volume_accumulator_event_log_decode,
+        pumpfun_collect_creator_fee_event_log_decode, pumpfun_complete_event_log_decode,
+        pumpfun_create_v2_token_event_log_decode, pumpfun_distribute_creator_fees_event_log_decode,
+        pumpfun_init_user_volume_accumulator_event_log_decode,
+        pumpfun_migrate_bonding_curve_creator_event_log_decode, pumpfun_migrate_event_log_decode,
+        pumpfun_set_creator_event_log_decode, pumpfun_set_metaplex_creator_event_log_decode,
+        pumpfun_sync_user_volume_accumulator_event_log_decode, pumpfun_trade_event_log_decode,
+        PumpFunClaimCashbackEvent, PumpFunClaimTokenIncentivesEvent,
+        PumpFunCloseUserVolumeAccumulatorEvent, PumpFunCollectCreatorFeeEvent,
+        PumpFunCompleteEvent, PumpFunCreateTokenEvent, PumpFunCreateV2TokenEvent,
+        PumpFunDistributeCreatorFeesEvent, PumpFunInitUserVolumeAccumulatorEvent,
+        PumpFunMigrateBondingCurveCreatorEvent, PumpFunMigrateEvent, PumpFunSetCreatorEvent,
+        PumpFunSetMetaplexCreatorEvent, PumpFunSyncUserVolumeAccumulatorEvent, PumpFunTradeEvent,
     },
     DexEvent,
 };
@@ -13,6 +26,10 @@ use solana_sdk::pubkey::Pubkey;
 pub const PUMPFUN_PROGRAM_ID: Pubkey =
     solana_sdk::pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
 
+/// Pump fee program ID，持有 FeeConfig 与 SharingConfig 账户
+pub const PUMP_FEES_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("pfeeUxB6jkeY1Hxd7CsFCAjcbHA9rWtchMGdZ6VojVZ");
+
 /// 解析 PumpFun instruction data
 ///
 /// 根据判别器路由到具体的 instruction 解析函数
@@ -28,9 +45,41 @@ pub fn parse_pumpfun_instruction_data(
             parse_create_v2_token_instruction(data, accounts, metadata)
         }
         discriminators::BUY_IX => parse_buy_instruction(data, accounts, metadata),
-        discriminators::BUY_EXACT_SOL_IN_IX => parse_buy_exact_sol_in_instruction(data, accounts, metadata),
+        discriminators::BUY_EXACT_SOL_IN_IX => {
+            parse_buy_exact_sol_in_instruction(data, accounts, metadata)
+        }
         discriminators::SELL_IX => parse_sell_instruction(data, accounts, metadata),
         discriminators::MIGRATE_IX => parse_migrate_instruction(data, accounts, metadata),
+        discriminators::COLLECT_CREATOR_FEE_IX => {
+            parse_collect_creator_fee_instruction(data, accounts, metadata)
+        }
+        discriminators::DISTRIBUTE_CREATOR_FEES_IX => {
+            parse_distribute_creator_fees_instruction(data, accounts, metadata)
+        }
+        discriminators::CLAIM_CASHBACK_IX => {
+            parse_claim_cashback_instruction(data, accounts, metadata)
+        }
+        discriminators::SET_CREATOR_IX => parse_set_creator_instruction(data, accounts, metadata),
+        discriminators::SET_METAPLEX_CREATOR_IX => {
+            parse_set_metaplex_creator_instruction(data, accounts, metadata)
+        }
+        discriminators::MIGRATE_BONDING_CURVE_CREATOR_IX => {
+            parse_migrate_bonding_curve_creator_instruction(data, accounts, metadata)
+        }
+        discriminators::INIT_USER_VOLUME_ACCUMULATOR_IX => {
+            parse_init_user_volume_accumulator_instruction(data, accounts, metadata)
+        }
+        discriminators::SYNC_USER_VOLUME_ACCUMULATOR_IX => {
+            parse_sync_user_volume_accumulator_instruction(data, accounts, metadata)
+        }
+        discriminators::CLOSE_USER_VOLUME_ACCUMULATOR_IX => {
+            parse_close_user_volume_accumulator_instruction(data, accounts, metadata)
+        }
+        discriminators::CLAIM_TOKEN_INCENTIVES_IX => {
+            parse_claim_token_incentives_instruction(data, accounts, metadata)
+        }
+        // CompleteEvent 不属于任何指令，只能从 emit_cpi! 自调用的 inner instruction 中取得
+        discriminators::EVENT_IX_TAG => parse_event_instruction(data, metadata),
         _ => None,
     }
 }
@@ -41,7 +90,7 @@ pub fn parse_pumpfun_instruction_data(
 pub fn parse_pumpfun_inner_instruction_data(
     discriminator: &[u8],
     data: &[u8],
-    metadata: EventMetadata,
+    mut metadata: EventMetadata,
 ) -> Option<DexEvent> {
     match discriminator {
         discriminators::CREATE_TOKEN_EVENT => parse_create_token_inner_instruction(data, metadata),
@@ -49,6 +98,87 @@ pub fn parse_pumpfun_inner_instruction_data(
         discriminators::COMPLETE_PUMP_AMM_MIGRATION_EVENT => {
             parse_migrate_inner_instruction(data, metadata)
         }
+        discriminators::COMPLETE_EVENT => parse_complete_inner_instruction(data, metadata),
+        discriminators::COLLECT_CREATOR_FEE_EVENT => {
+            metadata.event_type = EventType::PumpFunCollectCreatorFee;
+            pumpfun_collect_creator_fee_event_log_decode(data).map(|event| {
+                DexEvent::PumpFunCollectCreatorFeeEvent(PumpFunCollectCreatorFeeEvent {
+                    metadata,
+                    ..event
+                })
+            })
+        }
+        discriminators::DISTRIBUTE_CREATOR_FEES_EVENT => {
+            metadata.event_type = EventType::PumpFunDistributeCreatorFees;
+            pumpfun_distribute_creator_fees_event_log_decode(data).map(|event| {
+                DexEvent::PumpFunDistributeCreatorFeesEvent(PumpFunDistributeCreatorFeesEvent {
+                    metadata,
+                    ..event
+                })
+            })
+        }
+        discriminators::CLAIM_CASHBACK_EVENT => {
+            metadata.event_type = EventType::PumpFunClaimCashback;
+            pumpfun_claim_cashback_event_log_decode(data).map(|event| {
+                DexEvent::PumpFunClaimCashbackEvent(PumpFunClaimCashbackEvent { metadata, ..event })
+            })
+        }
+        discriminators::SET_CREATOR_EVENT => {
+            metadata.event_type = EventType::PumpFunSetCreator;
+            pumpfun_set_creator_event_log_decode(data).map(|event| {
+                DexEvent::PumpFunSetCreatorEvent(PumpFunSetCreatorEvent { metadata, ..event })
+            })
+        }
+        discriminators::SET_METAPLEX_CREATOR_EVENT => {
+            metadata.event_type = EventType::PumpFunSetMetaplexCreator;
+            pumpfun_set_metaplex_creator_event_log_decode(data).map(|event| {
+                DexEvent::PumpFunSetMetaplexCreatorEvent(PumpFunSetMetaplexCreatorEvent {
+                    metadata,
+                    ..event
+                })
+            })
+        }
+        discriminators::MIGRATE_BONDING_CURVE_CREATOR_EVENT => {
+            metadata.event_type = EventType::PumpFunMigrateBondingCurveCreator;
+            pumpfun_migrate_bonding_curve_creator_event_log_decode(data).map(|event| {
+                DexEvent::PumpFunMigrateBondingCurveCreatorEvent(
+                    PumpFunMigrateBondingCurveCreatorEvent { metadata, ..event },
+                )
+            })
+        }
+        discriminators::INIT_USER_VOLUME_ACCUMULATOR_EVENT => {
+            metadata.event_type = EventType::PumpFunInitUserVolumeAccumulator;
+            pumpfun_init_user_volume_accumulator_event_log_decode(data).map(|event| {
+                DexEvent::PumpFunInitUserVolumeAccumulatorEvent(
+                    PumpFunInitUserVolumeAccumulatorEvent { metadata, ..event },
+                )
+            })
+        }
+        discriminators::SYNC_USER_VOLUME_ACCUMULATOR_EVENT => {
+            metadata.event_type = EventType::PumpFunSyncUserVolumeAccumulator;
+            pumpfun_sync_user_volume_accumulator_event_log_decode(data).map(|event| {
+                DexEvent::PumpFunSyncUserVolumeAccumulatorEvent(
+                    PumpFunSyncUserVolumeAccumulatorEvent { metadata, ..event },
+                )
+            })
+        }
+        discriminators::CLOSE_USER_VOLUME_ACCUMULATOR_EVENT => {
+            metadata.event_type = EventType::PumpFunCloseUserVolumeAccumulator;
+            pumpfun_close_user_volume_accumulator_event_log_decode(data).map(|event| {
+                DexEvent::PumpFunCloseUserVolumeAccumulatorEvent(
+                    PumpFunCloseUserVolumeAccumulatorEvent { metadata, ..event },
+                )
+            })
+        }
+        discriminators::CLAIM_TOKEN_INCENTIVES_EVENT => {
+            metadata.event_type = EventType::PumpFunClaimTokenIncentives;
+            pumpfun_claim_token_incentives_event_log_decode(data).map(|event| {
+                DexEvent::PumpFunClaimTokenIncentivesEvent(PumpFunClaimTokenIncentivesEvent {
+                    metadata,
+                    ..event
+                })
+            })
+        }
         _ => None,
     }
 }
@@ -86,6 +216,62 @@ fn parse_migrate_inner_instruction(data: &[u8], mut metadata: EventMetadata) ->
     }
 }
 
+/// 不经判别器路由、通过 `AccountEventParseConfig` 注册的 PumpFun 账户解析器
+///
+/// FeeConfig / SharingConfig 由 Pump fee program 持有，交易量累计账户由 PumpFun 程序持有
+pub fn pumpfun_account_event_parse_configs() -> Vec<AccountEventParseConfig> {
+    use crate::streaming::event_parser::protocols::pumpfun::types;
+
+    vec![
+        AccountEventParseConfig {
+            program_id: PUMP_FEES_PROGRAM_ID,
+            protocol_type: ProtocolType::PumpFun,
+            event_type: EventType::AccountPumpFunFeeConfig,
+            account_discriminator: discriminators::FEE_CONFIG_ACCOUNT,
+            account_parser: types::fee_config_parser,
+        },
+        AccountEventParseConfig {
+            program_id: PUMP_FEES_PROGRAM_ID,
+            protocol_type: ProtocolType::PumpFun,
+            event_type: EventType::AccountPumpFunSharingConfig,
+            account_discriminator: discriminators::SHARING_CONFIG_ACCOUNT,
+            account_parser: types::sharing_config_parser,
+        },
+        AccountEventParseConfig {
+            program_id: PUMPFUN_PROGRAM_ID,
+            protocol_type: ProtocolType::PumpFun,
+            event_type: EventType::AccountPumpFunGlobalVolumeAccumulator,
+            account_discriminator: discriminators::GLOBAL_VOLUME_ACCUMULATOR_ACCOUNT,
+            account_parser: types::global_volume_accumulator_parser,
+        },
+        AccountEventParseConfig {
+            program_id: PUMPFUN_PROGRAM_ID,
+            protocol_type: ProtocolType::PumpFun,
+            event_type: EventType::AccountPumpFunUserVolumeAccumulator,
+            account_discriminator: discriminators::USER_VOLUME_ACCUMULATOR_ACCOUNT,
+            account_parser: types::user_volume_accumulator_parser,
+        },
+    ]
+}
+
+/// 解析联合曲线完成事件
+fn parse_complete_inner_instruction(data: &[u8], mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunComplete;
+    pumpfun_complete_event_log_decode(data)
+        .map(|event| DexEvent::PumpFunCompleteEvent(PumpFunCompleteEvent { metadata, ..event }))
+}
+
+/// 解析 emit_cpi! 自调用指令 (EVENT_IX_TAG + 8 字节事件判别器 + 事件数据)
+///
+/// 其余事件已在各自指令中合并，这里只单独发出 CompleteEvent，
+/// 因此它会与触发它的 Buy 事件一同出现，且 inner_index 位于其后
+fn parse_event_instruction(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    if data.len() < 8 || data[..8] != discriminators::COMPLETE_EVENT[8..] {
+        return None;
+    }
+    parse_complete_inner_instruction(&data[8..], metadata)
+}
+
 /// 解析创建代币日志事件
 fn parse_create_token_inner_instruction(
     data: &[u8],
@@ -320,7 +506,8 @@ fn parse_buy_instruction(
 /// Same account layout as buy: 16 fixed + optional 17th (index 16).
 /// Args: spendable_sol_in (SOL), min_tokens_out (token).
 fn parse_buy_exact_sol_in_instruction(
-    data: &[u8], accounts: &[Pubkey],
+    data: &[u8],
+    accounts: &[Pubkey],
     mut metadata: EventMetadata,
 ) -> Option<DexEvent> {
     metadata.event_type = EventType::PumpFunBuy;
@@ -443,3 +630,320 @@ fn parse_migrate_instruction(
         ..Default::default()
     }))
 }
+
+/// 解析 collect_creator_fee 指令
+/// 账户: 0: creator, 1: creator_vault, 2: system_program, 3: event_authority, 4: program
+fn parse_collect_creator_fee_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunCollectCreatorFee;
+
+    if accounts.len() < 5 {
+        return None;
+    }
+    Some(DexEvent::PumpFunCollectCreatorFeeEvent(PumpFunCollectCreatorFeeEvent {
+        metadata,
+        creator: accounts[0],
+        creator_vault: accounts[1],
+        system_program: accounts[2],
+        event_authority: accounts[3],
+        program: accounts[4],
+        ..Default::default()
+    }))
+}
+
+/// 解析 distribute_creator_fees 指令
+/// 账户: 0: mint, 1: bonding_curve, 2: sharing_config, 3: creator_vault, 4: system_program,
+/// 5: event_authority, 6: program。shareholder 账户作为 remaining accounts 追加在后
+fn parse_distribute_creator_fees_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunDistributeCreatorFees;
+
+    if accounts.len() < 7 {
+        return None;
+    }
+    Some(DexEvent::PumpFunDistributeCreatorFeesEvent(PumpFunDistributeCreatorFeesEvent {
+        metadata,
+        mint: accounts[0],
+        bonding_curve: accounts[1],
+        sharing_config: accounts[2],
+        creator_vault: accounts[3],
+        system_program: accounts[4],
+        event_authority: accounts[5],
+        program: accounts[6],
+        ..Default::default()
+    }))
+}
+
+/// 解析 claim_cashback 指令
+/// 账户: 0: user, 1: user_volume_accumulator, 2: system_program, 3: event_authority, 4: program
+fn parse_claim_cashback_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunClaimCashback;
+
+    if accounts.len() < 5 {
+        return None;
+    }
+    Some(DexEvent::PumpFunClaimCashbackEvent(PumpFunClaimCashbackEvent {
+        metadata,
+        user: accounts[0],
+        user_volume_accumulator: accounts[1],
+        system_program: accounts[2],
+        event_authority: accounts[3],
+        program: accounts[4],
+        ..Default::default()
+    }))
+}
+
+/// 解析 set_creator 指令
+/// 账户: 0: set_creator_authority, 1: global, 2: mint, 3: metadata, 4: bonding_curve,
+/// 5: event_authority, 6: program
+fn parse_set_creator_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunSetCreator;
+
+    if data.len() < 32 || accounts.len() < 7 {
+        return None;
+    }
+    Some(DexEvent::PumpFunSetCreatorEvent(PumpFunSetCreatorEvent {
+        metadata,
+        creator: Pubkey::new_from_array(data[0..32].try_into().unwrap()),
+        set_creator_authority: accounts[0],
+        global: accounts[1],
+        mint: accounts[2],
+        metadata_account: accounts[3],
+        bonding_curve: accounts[4],
+        event_authority: accounts[5],
+        program: accounts[6],
+        ..Default::default()
+    }))
+}
+
+/// 解析 set_metaplex_creator 指令
+/// 账户: 0: mint, 1: metadata, 2: bonding_curve, 3: event_authority, 4: program
+fn parse_set_metaplex_creator_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunSetMetaplexCreator;
+
+    if accounts.len() < 5 {
+        return None;
+    }
+    Some(DexEvent::PumpFunSetMetaplexCreatorEvent(PumpFunSetMetaplexCreatorEvent {
+        metadata,
+        mint: accounts[0],
+        metadata_account: accounts[1],
+        bonding_curve: accounts[2],
+        event_authority: accounts[3],
+        program: accounts[4],
+        ..Default::default()
+    }))
+}
+
+/// 解析 migrate_bonding_curve_creator 指令
+/// 账户: 0: mint, 1: bonding_curve, 2: sharing_config, 3: event_authority, 4: program
+fn parse_migrate_bonding_curve_creator_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunMigrateBondingCurveCreator;
+
+    if accounts.len() < 5 {
+        return None;
+    }
+    Some(DexEvent::PumpFunMigrateBondingCurveCreatorEvent(PumpFunMigrateBondingCurveCreatorEvent {
+        metadata,
+        mint: accounts[0],
+        bonding_curve: accounts[1],
+        sharing_config: accounts[2],
+        event_authority: accounts[3],
+        program: accounts[4],
+        ..Default::default()
+    }))
+}
+
+/// 解析 init_user_volume_accumulator 指令
+/// 账户: 0: payer, 1: user, 2: user_volume_accumulator, 3: system_program, 4: event_authority, 5: program
+fn parse_init_user_volume_accumulator_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunInitUserVolumeAccumulator;
+
+    if accounts.len() < 6 {
+        return None;
+    }
+    Some(DexEvent::PumpFunInitUserVolumeAccumulatorEvent(PumpFunInitUserVolumeAccumulatorEvent {
+        metadata,
+        payer: accounts[0],
+        user: accounts[1],
+        user_volume_accumulator: accounts[2],
+        system_program: accounts[3],
+        event_authority: accounts[4],
+        program: accounts[5],
+        ..Default::default()
+    }))
+}
+
+/// 解析 sync_user_volume_accumulator 指令
+/// 账户: 0: user, 1: global_volume_accumulator, 2: user_volume_accumulator, 3: event_authority, 4: program
+fn parse_sync_user_volume_accumulator_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunSyncUserVolumeAccumulator;
+
+    if accounts.len() < 5 {
+        return None;
+    }
+    Some(DexEvent::PumpFunSyncUserVolumeAccumulatorEvent(PumpFunSyncUserVolumeAccumulatorEvent {
+        metadata,
+        user: accounts[0],
+        global_volume_accumulator: accounts[1],
+        user_volume_accumulator: accounts[2],
+        event_authority: accounts[3],
+        program: accounts[4],
+        ..Default::default()
+    }))
+}
+
+/// 解析 close_user_volume_accumulator 指令
+/// 账户: 0: user, 1: user_volume_accumulator, 2: event_authority, 3: program
+fn parse_close_user_volume_accumulator_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunCloseUserVolumeAccumulator;
+
+    if accounts.len() < 4 {
+        return None;
+    }
+    Some(DexEvent::PumpFunCloseUserVolumeAccumulatorEvent(PumpFunCloseUserVolumeAccumulatorEvent {
+        metadata,
+        user: accounts[0],
+        user_volume_accumulator: accounts[1],
+        event_authority: accounts[2],
+        program: accounts[3],
+        ..Default::default()
+    }))
+}
+
+/// 解析 claim_token_incentives 指令
+/// 账户: 0: user, 1: user_ata, 2: global_volume_accumulator, 3: global_incentive_token_account,
+/// 4: user_volume_accumulator, 5: mint, 6: token_program, 7: system_program,
+/// 8: associated_token_program, 9: event_authority, 10: program, 11: payer
+fn parse_claim_token_incentives_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpFunClaimTokenIncentives;
+
+    if accounts.len() < 12 {
+        return None;
+    }
+    Some(DexEvent::PumpFunClaimTokenIncentivesEvent(PumpFunClaimTokenIncentivesEvent {
+        metadata,
+        user: accounts[0],
+        user_ata: accounts[1],
+        global_volume_accumulator: accounts[2],
+        global_incentive_token_account: accounts[3],
+        user_volume_accumulator: accounts[4],
+        mint: accounts[5],
+        token_program: accounts[6],
+        system_program: accounts[7],
+        associated_token_program: accounts[8],
+        event_authority: accounts[9],
+        program: accounts[10],
+        payer: accounts[11],
+        ..Default::default()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::core::merger_event::merge;
+
+    #[test]
+    fn test_complete_event_from_emit_cpi_instruction() {
+        let (user, mint, bonding_curve) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut data = discriminators::COMPLETE_EVENT[8..].to_vec();
+        data.extend_from_slice(user.as_ref());
+        data.extend_from_slice(mint.as_ref());
+        data.extend_from_slice(bonding_curve.as_ref());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+
+        let event = parse_pumpfun_instruction_data(
+            discriminators::EVENT_IX_TAG,
+            &data,
+            &[],
+            EventMetadata::default(),
+        )
+        .unwrap();
+        let DexEvent::PumpFunCompleteEvent(event) = event else { panic!("expected complete") };
+        assert_eq!(event.metadata.event_type, EventType::PumpFunComplete);
+        assert_eq!((event.user, event.mint, event.bonding_curve), (user, mint, bonding_curve));
+        assert_eq!(event.timestamp, 1_700_000_000);
+
+        // 其他事件的 emit_cpi! 自调用不会重复发出
+        let mut other = discriminators::COLLECT_CREATOR_FEE_EVENT[8..].to_vec();
+        other.extend_from_slice(&[0u8; 48]);
+        assert!(parse_pumpfun_instruction_data(
+            discriminators::EVENT_IX_TAG,
+            &other,
+            &[],
+            EventMetadata::default(),
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_collect_creator_fee_merges_cpi_log() {
+        let accounts: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let mut event = parse_pumpfun_instruction_data(
+            discriminators::COLLECT_CREATOR_FEE_IX,
+            &[],
+            &accounts,
+            EventMetadata::default(),
+        )
+        .unwrap();
+
+        let mut log = 1_700_000_000i64.to_le_bytes().to_vec();
+        log.extend_from_slice(accounts[0].as_ref());
+        log.extend_from_slice(&42_000u64.to_le_bytes());
+        let cpi_event = parse_pumpfun_inner_instruction_data(
+            discriminators::COLLECT_CREATOR_FEE_EVENT,
+            &log,
+            EventMetadata::default(),
+        )
+        .unwrap();
+        merge(&mut event, cpi_event);
+
+        let DexEvent::PumpFunCollectCreatorFeeEvent(event) = event else {
+            panic!("expected collect creator fee")
+        };
+        assert_eq!(event.metadata.event_type, EventType::PumpFunCollectCreatorFee);
+        assert_eq!((event.creator, event.creator_vault), (accounts[0], accounts[1]));
+        assert_eq!(event.creator_fee, 42_000);
+        assert_eq!(event.timestamp, 1_700_000_000);
+    }
+}
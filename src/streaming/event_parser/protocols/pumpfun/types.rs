@@ -1,16 +1,34 @@
+use std::sync::LazyLock;
+
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
+use yellowstone_grpc_proto::geyser::{
+    subscribe_request_filter_accounts_filter::Filter,
+    subscribe_request_filter_accounts_filter_memcmp::Data, SubscribeRequestFilterAccountsFilter,
+    SubscribeRequestFilterAccountsFilterMemcmp,
+};
 
 use crate::streaming::{
     event_parser::{
-        common::{EventMetadata, EventType},
-        protocols::pumpfun::{PumpFunBondingCurveAccountEvent, PumpFunGlobalAccountEvent},
+        common::{read_i64_le, read_u64_le, EventMetadata, EventType},
+        protocols::pumpfun::{
+            discriminators, parser::PUMPFUN_PROGRAM_ID, parser::PUMP_FEES_PROGRAM_ID,
+            PumpFunBondingCurveAccountEvent, PumpFunFeeConfigAccountEvent,
+            PumpFunGlobalAccountEvent, PumpFunGlobalVolumeAccumulatorAccountEvent,
+            PumpFunSharingConfigAccountEvent, PumpFunUserVolumeAccumulatorAccountEvent,
+        },
         DexEvent,
     },
     grpc::AccountPretty,
 };
 
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Shareholder {
+    pub address: Pubkey,
+    pub share_bps: u16,
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct BondingCurve {
     pub virtual_token_reserves: u64,
@@ -111,3 +129,377 @@ pub fn global_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Op
         None
     }
 }
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Fees {
+    pub lp_fee_bps: u64,
+    pub protocol_fee_bps: u64,
+    pub creator_fee_bps: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FeeTier {
+    pub market_cap_lamports_threshold: u128,
+    pub fees: Fees,
+}
+
+/// 分级手续费配置，由 Pump fee program 持有，PumpFun 与 PumpSwap 各有一个
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct FeeConfig {
+    pub bump: u8,
+    pub admin: Pubkey,
+    pub flat_fees: Fees,
+    pub fee_tiers: Vec<FeeTier>,
+}
+
+impl FeeConfig {
+    /// 按市值（lamports）选取生效的费率档位，未配置档位时返回 flat_fees
+    pub fn fees_for_market_cap(&self, market_cap_lamports: u128) -> &Fees {
+        self.fee_tiers
+            .iter()
+            .rev()
+            .find(|tier| market_cap_lamports >= tier.market_cap_lamports_threshold)
+            .or(self.fee_tiers.first())
+            .map_or(&self.flat_fees, |tier| &tier.fees)
+    }
+}
+
+pub fn fee_config_decode(data: &[u8]) -> Option<FeeConfig> {
+    <FeeConfig as BorshDeserialize>::deserialize(&mut &data[..]).ok()
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub enum ConfigStatus {
+    #[default]
+    Paused,
+    Active,
+}
+
+/// creator 手续费分成配置，按 mint 派生，PumpFun 与 PumpSwap 共用
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SharingConfig {
+    pub bump: u8,
+    pub version: u8,
+    pub status: ConfigStatus,
+    pub mint: Pubkey,
+    pub admin: Pubkey,
+    pub admin_revoked: bool,
+    pub shareholders: Vec<Shareholder>,
+}
+
+pub fn sharing_config_decode(data: &[u8]) -> Option<SharingConfig> {
+    <SharingConfig as BorshDeserialize>::deserialize(&mut &data[..]).ok()
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct GlobalVolumeAccumulator {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub seconds_in_a_day: i64,
+    pub mint: Pubkey,
+    pub total_token_supply: [u64; 30],
+    pub sol_volumes: [u64; 30],
+}
+
+pub const GLOBAL_VOLUME_ACCUMULATOR_SIZE: usize = 8 * 3 + 32 + 8 * 30 * 2;
+
+pub fn global_volume_accumulator_decode(data: &[u8]) -> Option<GlobalVolumeAccumulator> {
+    if data.len() < GLOBAL_VOLUME_ACCUMULATOR_SIZE {
+        return None;
+    }
+    borsh::from_slice::<GlobalVolumeAccumulator>(&data[..GLOBAL_VOLUME_ACCUMULATOR_SIZE]).ok()
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserVolumeAccumulator {
+    pub user: Pubkey,
+    pub needs_claim: bool,
+    pub total_unclaimed_tokens: u64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    pub last_update_timestamp: i64,
+    pub has_total_claimed_tokens: bool,
+    pub cashback_earned: u64,
+    pub total_cashback_claimed: u64,
+}
+
+/// 旧账户只到 last_update_timestamp，其后字段为后续追加
+pub const USER_VOLUME_ACCUMULATOR_SIZE_LEGACY: usize = 32 + 1 + 8 * 4;
+pub const USER_VOLUME_ACCUMULATOR_SIZE: usize = USER_VOLUME_ACCUMULATOR_SIZE_LEGACY + 1 + 8 * 2;
+
+pub fn user_volume_accumulator_decode(data: &[u8]) -> Option<UserVolumeAccumulator> {
+    if data.len() < USER_VOLUME_ACCUMULATOR_SIZE_LEGACY {
+        return None;
+    }
+    Some(UserVolumeAccumulator {
+        user: Pubkey::new_from_array(data[0..32].try_into().ok()?),
+        needs_claim: data[32] != 0,
+        total_unclaimed_tokens: read_u64_le(data, 33)?,
+        total_claimed_tokens: read_u64_le(data, 41)?,
+        current_sol_volume: read_u64_le(data, 49)?,
+        last_update_timestamp: read_i64_le(data, 57)?,
+        has_total_claimed_tokens: data.get(65).is_some_and(|b| *b != 0),
+        cashback_earned: read_u64_le(data, 66).unwrap_or(0),
+        total_cashback_claimed: read_u64_le(data, 74).unwrap_or(0),
+    })
+}
+
+/// PumpFun 的 FeeConfig 地址（fee program 下以 PumpFun 程序 ID 派生）
+pub static FEE_CONFIG_ADDRESS: LazyLock<Pubkey> = LazyLock::new(|| {
+    Pubkey::find_program_address(
+        &[b"fee_config", PUMPFUN_PROGRAM_ID.as_ref()],
+        &PUMP_FEES_PROGRAM_ID,
+    )
+    .0
+});
+
+pub static GLOBAL_VOLUME_ACCUMULATOR_ADDRESS: LazyLock<Pubkey> = LazyLock::new(|| {
+    Pubkey::find_program_address(&[b"global_volume_accumulator"], &PUMPFUN_PROGRAM_ID).0
+});
+
+/// 用户在 PumpFun 的交易量累计账户地址
+pub fn user_volume_accumulator_pda(user: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"user_volume_accumulator", user.as_ref()], &PUMPFUN_PROGRAM_ID)
+        .0
+}
+
+/// mint 对应的 SharingConfig 地址
+pub fn sharing_config_pda(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"sharing-config", mint.as_ref()], &PUMP_FEES_PROGRAM_ID).0
+}
+
+pub fn fee_config_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountPumpFunFeeConfig;
+
+    // PumpSwap 的 FeeConfig 布局相同，只能按地址区分
+    if account.pubkey != *FEE_CONFIG_ADDRESS {
+        return None;
+    }
+    fee_config_decode(account.data.get(8..)?).map(|fee_config| {
+        DexEvent::PumpFunFeeConfigAccountEvent(PumpFunFeeConfigAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            fee_config,
+        })
+    })
+}
+
+pub fn sharing_config_parser(
+    account: &AccountPretty,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountPumpFunSharingConfig;
+
+    sharing_config_decode(account.data.get(8..)?).map(|sharing_config| {
+        DexEvent::PumpFunSharingConfigAccountEvent(PumpFunSharingConfigAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            sharing_config,
+        })
+    })
+}
+
+pub fn global_volume_accumulator_parser(
+    account: &AccountPretty,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountPumpFunGlobalVolumeAccumulator;
+
+    global_volume_accumulator_decode(account.data.get(8..)?).map(|global_volume_accumulator| {
+        DexEvent::PumpFunGlobalVolumeAccumulatorAccountEvent(
+            PumpFunGlobalVolumeAccumulatorAccountEvent {
+                metadata,
+                pubkey: account.pubkey,
+                executable: account.executable,
+                lamports: account.lamports,
+                owner: account.owner,
+                rent_epoch: account.rent_epoch,
+                global_volume_accumulator,
+            },
+        )
+    })
+}
+
+pub fn user_volume_accumulator_parser(
+    account: &AccountPretty,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountPumpFunUserVolumeAccumulator;
+
+    user_volume_accumulator_decode(account.data.get(8..)?).map(|user_volume_accumulator| {
+        DexEvent::PumpFunUserVolumeAccumulatorAccountEvent(
+            PumpFunUserVolumeAccumulatorAccountEvent {
+                metadata,
+                pubkey: account.pubkey,
+                executable: account.executable,
+                lamports: account.lamports,
+                owner: account.owner,
+                rent_epoch: account.rent_epoch,
+                user_volume_accumulator,
+            },
+        )
+    })
+}
+
+/// 账户数据中各字段的偏移量（包含 8 字节判别器），用于构造 memcmp 过滤器
+pub mod offsets {
+    pub const USER_VOLUME_ACCUMULATOR_USER: u64 = 8;
+    pub const SHARING_CONFIG_MINT: u64 = 8 + 1 + 1 + 1;
+}
+
+fn memcmp_filter(offset: u64, bytes: Vec<u8>) -> SubscribeRequestFilterAccountsFilter {
+    SubscribeRequestFilterAccountsFilter {
+        filter: Some(Filter::Memcmp(SubscribeRequestFilterAccountsFilterMemcmp {
+            offset,
+            data: Some(Data::Bytes(bytes)),
+        })),
+    }
+}
+
+/// 只订阅 UserVolumeAccumulator 账户（PumpFun 与 PumpSwap 判别器相同）
+pub fn user_volume_accumulator_account_filter() -> SubscribeRequestFilterAccountsFilter {
+    memcmp_filter(0, discriminators::USER_VOLUME_ACCUMULATOR_ACCOUNT.to_vec())
+}
+
+/// 按用户过滤 UserVolumeAccumulator 账户
+pub fn user_volume_accumulator_by_user_filter(
+    user: &Pubkey,
+) -> SubscribeRequestFilterAccountsFilter {
+    memcmp_filter(offsets::USER_VOLUME_ACCUMULATOR_USER, user.to_bytes().to_vec())
+}
+
+/// 只订阅 SharingConfig 账户
+pub fn sharing_config_account_filter() -> SubscribeRequestFilterAccountsFilter {
+    memcmp_filter(0, discriminators::SHARING_CONFIG_ACCOUNT.to_vec())
+}
+
+/// 按 mint 过滤 SharingConfig 账户
+pub fn sharing_config_by_mint_filter(mint: &Pubkey) -> SubscribeRequestFilterAccountsFilter {
+    memcmp_filter(offsets::SHARING_CONFIG_MINT, mint.to_bytes().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        common::filter::EventTypeFilter, core::account_event_parser::AccountEventParser,
+        protocols::pumpswap, Protocol,
+    };
+
+    #[test]
+    fn test_user_volume_accumulator_legacy_and_extended() {
+        let user = Pubkey::new_unique();
+        let mut data = user.to_bytes().to_vec();
+        data.push(1);
+        for v in [10u64, 20, 30, 40] {
+            data.extend_from_slice(&v.to_le_bytes());
+        }
+        let legacy = user_volume_accumulator_decode(&data).unwrap();
+        assert_eq!((legacy.user, legacy.current_sol_volume), (user, 30));
+        assert_eq!(legacy.cashback_earned, 0);
+
+        data.push(1);
+        data.extend_from_slice(&500u64.to_le_bytes());
+        data.extend_from_slice(&200u64.to_le_bytes());
+        let extended = user_volume_accumulator_decode(&data).unwrap();
+        assert!(extended.has_total_claimed_tokens);
+        assert_eq!((extended.cashback_earned, extended.total_cashback_claimed), (500, 200));
+    }
+
+    #[test]
+    fn test_fee_config_routed_by_address() {
+        let fees = |bps: u64| Fees { lp_fee_bps: bps, protocol_fee_bps: 0, creator_fee_bps: 0 };
+        let config = FeeConfig {
+            bump: 255,
+            admin: Pubkey::new_unique(),
+            flat_fees: fees(1),
+            fee_tiers: vec![
+                FeeTier { market_cap_lamports_threshold: 0, fees: fees(95) },
+                FeeTier { market_cap_lamports_threshold: 1_000, fees: fees(50) },
+            ],
+        };
+        assert_eq!(config.fees_for_market_cap(999).lp_fee_bps, 95);
+        assert_eq!(config.fees_for_market_cap(1_000).lp_fee_bps, 50);
+
+        let mut data = discriminators::FEE_CONFIG_ACCOUNT.to_vec();
+        data.push(config.bump);
+        data.extend_from_slice(config.admin.as_ref());
+        for v in [1u64, 0, 0] {
+            data.extend_from_slice(&v.to_le_bytes());
+        }
+        data.extend_from_slice(&2u32.to_le_bytes());
+        for (threshold, bps) in [(0u128, 95u64), (1_000, 50)] {
+            data.extend_from_slice(&threshold.to_le_bytes());
+            for v in [bps, 0, 0] {
+                data.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+        // 账户分配空间通常大于实际内容
+        data.extend_from_slice(&[0u8; 64]);
+
+        let protocols = [Protocol::PumpFun, Protocol::PumpSwap];
+        let account = AccountPretty {
+            pubkey: *pumpswap::types::FEE_CONFIG_ADDRESS,
+            owner: PUMP_FEES_PROGRAM_ID,
+            data,
+            ..Default::default()
+        };
+        let event =
+            AccountEventParser::parse_account_event(&protocols, account.clone(), None).unwrap();
+        let DexEvent::PumpSwapFeeConfigAccountEvent(event) = event else {
+            panic!("expected PumpSwap fee config")
+        };
+        assert_eq!(event.fee_config, config);
+
+        let pumpfun_account = AccountPretty { pubkey: *FEE_CONFIG_ADDRESS, ..account };
+        let event =
+            AccountEventParser::parse_account_event(&protocols, pumpfun_account, None).unwrap();
+        assert_eq!(event.metadata().event_type, EventType::AccountPumpFunFeeConfig);
+    }
+
+    #[test]
+    fn test_sharing_config_follows_subscription_order() {
+        let mint = Pubkey::new_unique();
+        let mut data = discriminators::SHARING_CONFIG_ACCOUNT.to_vec();
+        data.extend_from_slice(&[254, 1, 1]);
+        data.extend_from_slice(mint.as_ref());
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.push(0);
+        data.extend_from_slice(&0u32.to_le_bytes());
+        let account = AccountPretty {
+            pubkey: sharing_config_pda(&mint),
+            owner: PUMP_FEES_PROGRAM_ID,
+            data,
+            ..Default::default()
+        };
+
+        let parse = |protocols: &[Protocol], filter: Option<&EventTypeFilter>| {
+            AccountEventParser::parse_account_event(protocols, account.clone(), filter)
+                .unwrap()
+                .metadata()
+                .event_type
+                .clone()
+        };
+        assert_eq!(
+            parse(&[Protocol::PumpSwap, Protocol::PumpFun], None),
+            EventType::AccountPumpSwapSharingConfig
+        );
+        assert_eq!(
+            parse(&[Protocol::PumpFun, Protocol::PumpSwap], None),
+            EventType::AccountPumpFunSharingConfig
+        );
+        let filter = EventTypeFilter { include: vec![EventType::AccountPumpSwapSharingConfig] };
+        assert_eq!(
+            parse(&[Protocol::PumpFun, Protocol::PumpSwap], Some(&filter)),
+            EventType::AccountPumpSwapSharingConfig
+        );
+    }
+}
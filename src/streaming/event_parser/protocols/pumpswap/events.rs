@@ -4,7 +4,9 @@ use solana_sdk::pubkey::Pubkey;
 
 use crate::streaming::event_parser::common::utils::{read_i64_le, read_u32_le, read_u64_le};
 use crate::streaming::event_parser::common::EventMetadata;
-use crate::streaming::event_parser::protocols::pumpswap::types::{GlobalConfig, Pool};
+use crate::streaming::event_parser::protocols::pumpswap::types::{
+    FeeConfig, GlobalConfig, GlobalVolumeAccumulator, Pool, SharingConfig, UserVolumeAccumulator,
+};
 
 /// 买入事件
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
@@ -406,6 +408,211 @@ pub fn pump_swap_withdraw_event_log_decode(data: &[u8]) -> Option<PumpSwapWithdr
     borsh::from_slice::<PumpSwapWithdrawEvent>(&data[..PUMP_SWAP_WITHDRAW_EVENT_LOG_SIZE]).ok()
 }
 
+/// 领取 coin creator 手续费事件
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpSwapCollectCoinCreatorFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub timestamp: i64,
+    pub coin_creator: Pubkey,
+    pub coin_creator_fee: u64,
+    pub coin_creator_vault_ata: Pubkey,
+    pub coin_creator_token_account: Pubkey,
+    #[borsh(skip)]
+    pub quote_mint: Pubkey,
+    #[borsh(skip)]
+    pub quote_token_program: Pubkey,
+    #[borsh(skip)]
+    pub coin_creator_vault_authority: Pubkey,
+}
+
+pub const PUMP_SWAP_COLLECT_COIN_CREATOR_FEE_EVENT_LOG_SIZE: usize = 112;
+
+pub fn pump_swap_collect_coin_creator_fee_event_log_decode(
+    data: &[u8],
+) -> Option<PumpSwapCollectCoinCreatorFeeEvent> {
+    if data.len() < PUMP_SWAP_COLLECT_COIN_CREATOR_FEE_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpSwapCollectCoinCreatorFeeEvent>(
+        &data[..PUMP_SWAP_COLLECT_COIN_CREATOR_FEE_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// 领取 cashback 事件
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpSwapClaimCashbackEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub total_claimed: u64,
+    pub total_cashback_earned: u64,
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub quote_mint: Pubkey,
+    #[borsh(skip)]
+    pub quote_token_program: Pubkey,
+    #[borsh(skip)]
+    pub user_volume_accumulator_wsol_token_account: Pubkey,
+    #[borsh(skip)]
+    pub user_wsol_token_account: Pubkey,
+}
+
+pub const PUMP_SWAP_CLAIM_CASHBACK_EVENT_LOG_SIZE: usize = 64;
+
+pub fn pump_swap_claim_cashback_event_log_decode(
+    data: &[u8],
+) -> Option<PumpSwapClaimCashbackEvent> {
+    if data.len() < PUMP_SWAP_CLAIM_CASHBACK_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpSwapClaimCashbackEvent>(
+        &data[..PUMP_SWAP_CLAIM_CASHBACK_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// 领取交易量激励代币事件
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpSwapClaimTokenIncentivesEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub total_claimed_tokens: u64,
+    pub current_sol_volume: u64,
+    #[borsh(skip)]
+    pub user_ata: Pubkey,
+    #[borsh(skip)]
+    pub global_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub global_incentive_token_account: Pubkey,
+    #[borsh(skip)]
+    pub user_volume_accumulator: Pubkey,
+    #[borsh(skip)]
+    pub token_program: Pubkey,
+    #[borsh(skip)]
+    pub payer: Pubkey,
+}
+
+pub const PUMP_SWAP_CLAIM_TOKEN_INCENTIVES_EVENT_LOG_SIZE: usize = 96;
+
+pub fn pump_swap_claim_token_incentives_event_log_decode(
+    data: &[u8],
+) -> Option<PumpSwapClaimTokenIncentivesEvent> {
+    if data.len() < PUMP_SWAP_CLAIM_TOKEN_INCENTIVES_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpSwapClaimTokenIncentivesEvent>(
+        &data[..PUMP_SWAP_CLAIM_TOKEN_INCENTIVES_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// 设置 coin creator 事件
+///
+/// set_coin_creator 根据来源发出 SetBondingCurveCoinCreatorEvent 或
+/// SetMetaplexCoinCreatorEvent，两者布局仅第 4 个 pubkey 不同，统一解码到此结构
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpSwapSetCoinCreatorEvent {
+    pub metadata: EventMetadata,
+    pub timestamp: i64,
+    pub base_mint: Pubkey,
+    pub pool: Pubkey,
+    pub coin_creator: Pubkey,
+    /// coin creator 是否取自 Metaplex metadata（否则取自 bonding curve）
+    pub from_metaplex: bool,
+    pub metadata_account: Pubkey,
+    pub bonding_curve: Pubkey,
+}
+
+pub const PUMP_SWAP_SET_COIN_CREATOR_EVENT_LOG_SIZE: usize = 136;
+
+pub fn pump_swap_set_coin_creator_event_log_decode(
+    data: &[u8],
+    from_metaplex: bool,
+) -> Option<PumpSwapSetCoinCreatorEvent> {
+    if data.len() < PUMP_SWAP_SET_COIN_CREATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    let source = Pubkey::new_from_array(data.get(72..104)?.try_into().ok()?);
+    let (metadata_account, bonding_curve) =
+        if from_metaplex { (source, Pubkey::default()) } else { (Pubkey::default(), source) };
+    Some(PumpSwapSetCoinCreatorEvent {
+        metadata: EventMetadata::default(),
+        timestamp: read_i64_le(data, 0)?,
+        base_mint: Pubkey::new_from_array(data.get(8..40)?.try_into().ok()?),
+        pool: Pubkey::new_from_array(data.get(40..72)?.try_into().ok()?),
+        coin_creator: Pubkey::new_from_array(data.get(104..136)?.try_into().ok()?),
+        from_metaplex,
+        metadata_account,
+        bonding_curve,
+    })
+}
+
+/// 迁移池子 coin creator 事件
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PumpSwapMigratePoolCoinCreatorEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub timestamp: i64,
+    pub base_mint: Pubkey,
+    pub pool: Pubkey,
+    pub sharing_config: Pubkey,
+    pub old_coin_creator: Pubkey,
+    pub new_coin_creator: Pubkey,
+}
+
+pub const PUMP_SWAP_MIGRATE_POOL_COIN_CREATOR_EVENT_LOG_SIZE: usize = 168;
+
+pub fn pump_swap_migrate_pool_coin_creator_event_log_decode(
+    data: &[u8],
+) -> Option<PumpSwapMigratePoolCoinCreatorEvent> {
+    if data.len() < PUMP_SWAP_MIGRATE_POOL_COIN_CREATOR_EVENT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PumpSwapMigratePoolCoinCreatorEvent>(
+        &data[..PUMP_SWAP_MIGRATE_POOL_COIN_CREATOR_EVENT_LOG_SIZE],
+    )
+    .ok()
+}
+
+/// 将 coin creator 手续费转入 pump creator vault（指令无 CPI 事件，仅账户）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpSwapTransferCreatorFeesToPumpEvent {
+    pub metadata: EventMetadata,
+    pub wsol_mint: Pubkey,
+    pub token_program: Pubkey,
+    pub coin_creator: Pubkey,
+    pub coin_creator_vault_authority: Pubkey,
+    pub coin_creator_vault_ata: Pubkey,
+    pub pump_creator_vault: Pubkey,
+}
+
+/// 切换 mayhem 模式（指令无 CPI 事件）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpSwapToggleMayhemModeEvent {
+    pub metadata: EventMetadata,
+    pub enabled: bool,
+    pub admin: Pubkey,
+    pub global_config: Pubkey,
+}
+
+/// 切换 cashback 开关（指令无 CPI 事件）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpSwapToggleCashbackEnabledEvent {
+    pub metadata: EventMetadata,
+    pub enabled: bool,
+    pub admin: Pubkey,
+    pub global_config: Pubkey,
+}
+
 /// 全局配置
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct PumpSwapGlobalConfigAccountEvent {
@@ -432,6 +639,54 @@ pub struct PumpSwapPoolAccountEvent {
     pub pool: Pool,
 }
 
+/// 分级手续费配置
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpSwapFeeConfigAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub fee_config: FeeConfig,
+}
+
+/// creator 手续费分成配置
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpSwapSharingConfigAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub sharing_config: SharingConfig,
+}
+
+/// 全局交易量累计
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpSwapGlobalVolumeAccumulatorAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub global_volume_accumulator: GlobalVolumeAccumulator,
+}
+
+/// 用户交易量累计
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PumpSwapUserVolumeAccumulatorAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub user_volume_accumulator: UserVolumeAccumulator,
+}
+
 /// 事件鉴别器常量
 pub mod discriminators {
     // 事件鉴别器
@@ -450,6 +705,18 @@ pub mod discriminators {
     // pub const WITHDRAW_EVENT: &str = "0xe445a52e51cb9a1d1609851aa02c47c0";
     pub const WITHDRAW_EVENT: &[u8] =
         &[228, 69, 165, 46, 81, 203, 154, 29, 22, 9, 133, 26, 160, 44, 71, 192];
+    pub const COLLECT_COIN_CREATOR_FEE_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 232, 245, 194, 238, 234, 218, 58, 89];
+    pub const CLAIM_CASHBACK_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 226, 214, 246, 33, 7, 242, 147, 229];
+    pub const CLAIM_TOKEN_INCENTIVES_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 79, 172, 246, 49, 205, 91, 206, 232];
+    pub const SET_BONDING_CURVE_COIN_CREATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 242, 231, 235, 102, 65, 99, 189, 211];
+    pub const SET_METAPLEX_COIN_CREATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 150, 107, 199, 123, 124, 207, 102, 228];
+    pub const MIGRATE_POOL_COIN_CREATOR_EVENT: &[u8] =
+        &[228, 69, 165, 46, 81, 203, 154, 29, 170, 221, 82, 199, 147, 165, 247, 46];
 
     // 指令鉴别器
     pub const BUY_IX: &[u8] = &[102, 6, 61, 18, 1, 218, 235, 234];
@@ -458,8 +725,20 @@ pub mod discriminators {
     pub const CREATE_POOL_IX: &[u8] = &[233, 146, 209, 142, 207, 104, 64, 188];
     pub const DEPOSIT_IX: &[u8] = &[242, 35, 198, 137, 82, 225, 242, 182];
     pub const WITHDRAW_IX: &[u8] = &[183, 18, 70, 156, 148, 109, 161, 34];
+    pub const COLLECT_COIN_CREATOR_FEE_IX: &[u8] = &[160, 57, 89, 42, 181, 139, 43, 66];
+    pub const CLAIM_CASHBACK_IX: &[u8] = &[37, 58, 35, 126, 190, 53, 228, 197];
+    pub const CLAIM_TOKEN_INCENTIVES_IX: &[u8] = &[16, 4, 71, 28, 204, 1, 40, 27];
+    pub const SET_COIN_CREATOR_IX: &[u8] = &[210, 149, 128, 45, 188, 58, 78, 175];
+    pub const MIGRATE_POOL_COIN_CREATOR_IX: &[u8] = &[208, 8, 159, 4, 74, 175, 16, 58];
+    pub const TRANSFER_CREATOR_FEES_TO_PUMP_IX: &[u8] = &[139, 52, 134, 85, 228, 229, 108, 241];
+    pub const TOGGLE_MAYHEM_MODE_IX: &[u8] = &[1, 9, 111, 208, 100, 31, 255, 163];
+    pub const TOGGLE_CASHBACK_ENABLED_IX: &[u8] = &[115, 103, 224, 255, 189, 89, 86, 195];
 
     // 账户鉴别器
     pub const GLOBAL_CONFIG_ACCOUNT: &[u8] = &[149, 8, 156, 202, 160, 252, 176, 217];
     pub const POOL_ACCOUNT: &[u8] = &[241, 154, 109, 4, 17, 177, 109, 188];
+    pub const FEE_CONFIG_ACCOUNT: &[u8] = &[143, 52, 146, 187, 219, 123, 76, 155];
+    pub const SHARING_CONFIG_ACCOUNT: &[u8] = &[216, 74, 9, 0, 56, 140, 93, 75];
+    pub const GLOBAL_VOLUME_ACCUMULATOR_ACCOUNT: &[u8] = &[202, 42, 246, 43, 142, 190, 30, 255];
+    pub const USER_VOLUME_ACCUMULATOR_ACCOUNT: &[u8] = &[86, 255, 112, 14, 102, 53, 154, 250];
 }
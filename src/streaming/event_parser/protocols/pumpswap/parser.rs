@@ -1,10 +1,19 @@
 use crate::streaming::event_parser::{
-    common::{read_u64_le, EventMetadata, EventType},
+    common::{read_u64_le, EventMetadata, EventType, ProtocolType},
+    core::parser_cache::AccountEventParseConfig,
+    protocols::pumpfun::parser::PUMP_FEES_PROGRAM_ID,
     protocols::pumpswap::{
-        discriminators, pump_swap_buy_event_log_decode, pump_swap_create_pool_event_log_decode,
-        pump_swap_deposit_event_log_decode, pump_swap_sell_event_log_decode,
-        pump_swap_withdraw_event_log_decode, PumpSwapBuyEvent, PumpSwapCreatePoolEvent,
-        PumpSwapDepositEvent, PumpSwapSellEvent, PumpSwapWithdrawEvent,
+        discriminators, pump_swap_buy_event_log_decode, pump_swap_claim_cashback_event_log_decode,
+        pump_swap_claim_token_incentives_event_log_decode,
+        pump_swap_collect_coin_creator_fee_event_log_decode,
+        pump_swap_create_pool_event_log_decode, pump_swap_deposit_event_log_decode,
+        pump_swap_migrate_pool_coin_creator_event_log_decode, pump_swap_sell_event_log_decode,
+        pump_swap_set_coin_creator_event_log_decode, pump_swap_withdraw_event_log_decode,
+        PumpSwapBuyEvent, PumpSwapClaimCashbackEvent, PumpSwapClaimTokenIncentivesEvent,
+        PumpSwapCollectCoinCreatorFeeEvent, PumpSwapCreatePoolEvent, PumpSwapDepositEvent,
+        PumpSwapMigratePoolCoinCreatorEvent, PumpSwapSellEvent, PumpSwapSetCoinCreatorEvent,
+        PumpSwapToggleCashbackEnabledEvent, PumpSwapToggleMayhemModeEvent,
+        PumpSwapTransferCreatorFeesToPumpEvent, PumpSwapWithdrawEvent,
     },
     DexEvent,
 };
@@ -32,6 +41,30 @@ pub fn parse_pumpswap_instruction_data(
         }
         discriminators::DEPOSIT_IX => parse_deposit_instruction(data, accounts, metadata),
         discriminators::WITHDRAW_IX => parse_withdraw_instruction(data, accounts, metadata),
+        discriminators::COLLECT_COIN_CREATOR_FEE_IX => {
+            parse_collect_coin_creator_fee_instruction(data, accounts, metadata)
+        }
+        discriminators::CLAIM_CASHBACK_IX => {
+            parse_claim_cashback_instruction(data, accounts, metadata)
+        }
+        discriminators::CLAIM_TOKEN_INCENTIVES_IX => {
+            parse_claim_token_incentives_instruction(data, accounts, metadata)
+        }
+        discriminators::SET_COIN_CREATOR_IX => {
+            parse_set_coin_creator_instruction(data, accounts, metadata)
+        }
+        discriminators::MIGRATE_POOL_COIN_CREATOR_IX => {
+            parse_migrate_pool_coin_creator_instruction(data, accounts, metadata)
+        }
+        discriminators::TRANSFER_CREATOR_FEES_TO_PUMP_IX => {
+            parse_transfer_creator_fees_to_pump_instruction(data, accounts, metadata)
+        }
+        discriminators::TOGGLE_MAYHEM_MODE_IX => {
+            parse_toggle_mayhem_mode_instruction(data, accounts, metadata)
+        }
+        discriminators::TOGGLE_CASHBACK_ENABLED_IX => {
+            parse_toggle_cashback_enabled_instruction(data, accounts, metadata)
+        }
         _ => None,
     }
 }
@@ -52,6 +85,43 @@ pub fn parse_pumpswap_inner_instruction_data(
         }
         discriminators::DEPOSIT_EVENT => parse_deposit_inner_instruction(data, metadata),
         discriminators::WITHDRAW_EVENT => parse_withdraw_inner_instruction(data, metadata),
+        discriminators::COLLECT_COIN_CREATOR_FEE_EVENT => {
+            pump_swap_collect_coin_creator_fee_event_log_decode(data).map(|event| {
+                DexEvent::PumpSwapCollectCoinCreatorFeeEvent(PumpSwapCollectCoinCreatorFeeEvent {
+                    metadata,
+                    ..event
+                })
+            })
+        }
+        discriminators::CLAIM_CASHBACK_EVENT => pump_swap_claim_cashback_event_log_decode(data)
+            .map(|event| {
+                DexEvent::PumpSwapClaimCashbackEvent(PumpSwapClaimCashbackEvent {
+                    metadata,
+                    ..event
+                })
+            }),
+        discriminators::CLAIM_TOKEN_INCENTIVES_EVENT => {
+            pump_swap_claim_token_incentives_event_log_decode(data).map(|event| {
+                DexEvent::PumpSwapClaimTokenIncentivesEvent(PumpSwapClaimTokenIncentivesEvent {
+                    metadata,
+                    ..event
+                })
+            })
+        }
+        discriminators::SET_BONDING_CURVE_COIN_CREATOR_EVENT => {
+            parse_set_coin_creator_inner_instruction(data, false, metadata)
+        }
+        discriminators::SET_METAPLEX_COIN_CREATOR_EVENT => {
+            parse_set_coin_creator_inner_instruction(data, true, metadata)
+        }
+        discriminators::MIGRATE_POOL_COIN_CREATOR_EVENT => {
+            pump_swap_migrate_pool_coin_creator_event_log_decode(data).map(|event| {
+                DexEvent::PumpSwapMigratePoolCoinCreatorEvent(PumpSwapMigratePoolCoinCreatorEvent {
+                    metadata,
+                    ..event
+                })
+            })
+        }
         _ => None,
     }
 }
@@ -76,6 +146,44 @@ pub fn parse_pumpswap_account_data(
     }
 }
 
+/// 不经判别器路由、通过 `AccountEventParseConfig` 注册的 PumpSwap 账户解析器
+///
+/// FeeConfig / SharingConfig 由 Pump fee program 持有，交易量累计账户由 PumpSwap 程序持有
+pub fn pumpswap_account_event_parse_configs() -> Vec<AccountEventParseConfig> {
+    use crate::streaming::event_parser::protocols::pumpswap::types;
+
+    vec![
+        AccountEventParseConfig {
+            program_id: PUMP_FEES_PROGRAM_ID,
+            protocol_type: ProtocolType::PumpSwap,
+            event_type: EventType::AccountPumpSwapFeeConfig,
+            account_discriminator: discriminators::FEE_CONFIG_ACCOUNT,
+            account_parser: types::fee_config_parser,
+        },
+        AccountEventParseConfig {
+            program_id: PUMP_FEES_PROGRAM_ID,
+            protocol_type: ProtocolType::PumpSwap,
+            event_type: EventType::AccountPumpSwapSharingConfig,
+            account_discriminator: discriminators::SHARING_CONFIG_ACCOUNT,
+            account_parser: types::sharing_config_parser,
+        },
+        AccountEventParseConfig {
+            program_id: PUMPSWAP_PROGRAM_ID,
+            protocol_type: ProtocolType::PumpSwap,
+            event_type: EventType::AccountPumpSwapGlobalVolumeAccumulator,
+            account_discriminator: discriminators::GLOBAL_VOLUME_ACCUMULATOR_ACCOUNT,
+            account_parser: types::global_volume_accumulator_parser,
+        },
+        AccountEventParseConfig {
+            program_id: PUMPSWAP_PROGRAM_ID,
+            protocol_type: ProtocolType::PumpSwap,
+            event_type: EventType::AccountPumpSwapUserVolumeAccumulator,
+            account_discriminator: discriminators::USER_VOLUME_ACCUMULATOR_ACCOUNT,
+            account_parser: types::user_volume_accumulator_parser,
+        },
+    ]
+}
+
 /// 解析买入日志事件
 fn parse_buy_inner_instruction(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
     // Note: event_type will be set by instruction parser
@@ -129,6 +237,17 @@ fn parse_withdraw_inner_instruction(data: &[u8], metadata: EventMetadata) -> Opt
     }
 }
 
+/// 解析设置 coin creator 日志事件
+fn parse_set_coin_creator_inner_instruction(
+    data: &[u8],
+    from_metaplex: bool,
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    pump_swap_set_coin_creator_event_log_decode(data, from_metaplex).map(|event| {
+        DexEvent::PumpSwapSetCoinCreatorEvent(PumpSwapSetCoinCreatorEvent { metadata, ..event })
+    })
+}
+
 /// 解析买入指令事件
 /// Buy 指令共 23 个固定账户（与 idl/pump_amm.json 一致）:
 /// 0: pool, 1: user, 2: global_config, 3: base_mint, 4: quote_mint, 5: user_base_token_account,
@@ -367,3 +486,237 @@ fn parse_withdraw_instruction(
         ..Default::default()
     }))
 }
+
+/// 解析领取 coin creator 手续费指令事件
+/// 账户: 0: quote_mint, 1: quote_token_program, 2: coin_creator, 3: coin_creator_vault_authority,
+/// 4: coin_creator_vault_ata, 5: coin_creator_token_account, 6: event_authority, 7: program
+fn parse_collect_coin_creator_fee_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpSwapCollectCoinCreatorFee;
+
+    if accounts.len() < 6 {
+        return None;
+    }
+
+    Some(DexEvent::PumpSwapCollectCoinCreatorFeeEvent(PumpSwapCollectCoinCreatorFeeEvent {
+        metadata,
+        quote_mint: accounts[0],
+        quote_token_program: accounts[1],
+        coin_creator: accounts[2],
+        coin_creator_vault_authority: accounts[3],
+        coin_creator_vault_ata: accounts[4],
+        coin_creator_token_account: accounts[5],
+        ..Default::default()
+    }))
+}
+
+/// 解析领取 cashback 指令事件
+/// 账户: 0: user, 1: user_volume_accumulator, 2: quote_mint, 3: quote_token_program,
+/// 4: user_volume_accumulator_wsol_token_account, 5: user_wsol_token_account, 6: system_program,
+/// 7: event_authority, 8: program
+fn parse_claim_cashback_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpSwapClaimCashback;
+
+    if accounts.len() < 6 {
+        return None;
+    }
+
+    Some(DexEvent::PumpSwapClaimCashbackEvent(PumpSwapClaimCashbackEvent {
+        metadata,
+        user: accounts[0],
+        user_volume_accumulator: accounts[1],
+        quote_mint: accounts[2],
+        quote_token_program: accounts[3],
+        user_volume_accumulator_wsol_token_account: accounts[4],
+        user_wsol_token_account: accounts[5],
+        ..Default::default()
+    }))
+}
+
+/// 解析领取交易量激励代币指令事件
+/// 账户: 0: user, 1: user_ata, 2: global_volume_accumulator, 3: global_incentive_token_account,
+/// 4: user_volume_accumulator, 5: mint, 6: token_program, 7: system_program,
+/// 8: associated_token_program, 9: event_authority, 10: program, 11: payer
+fn parse_claim_token_incentives_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpSwapClaimTokenIncentives;
+
+    if accounts.len() < 12 {
+        return None;
+    }
+
+    Some(DexEvent::PumpSwapClaimTokenIncentivesEvent(PumpSwapClaimTokenIncentivesEvent {
+        metadata,
+        user: accounts[0],
+        user_ata: accounts[1],
+        global_volume_accumulator: accounts[2],
+        global_incentive_token_account: accounts[3],
+        user_volume_accumulator: accounts[4],
+        mint: accounts[5],
+        token_program: accounts[6],
+        payer: accounts[11],
+        ..Default::default()
+    }))
+}
+
+/// 解析设置 coin creator 指令事件
+/// 账户: 0: pool, 1: metadata, 2: bonding_curve, 3: event_authority, 4: program
+fn parse_set_coin_creator_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpSwapSetCoinCreator;
+
+    if accounts.len() < 3 {
+        return None;
+    }
+
+    Some(DexEvent::PumpSwapSetCoinCreatorEvent(PumpSwapSetCoinCreatorEvent {
+        metadata,
+        pool: accounts[0],
+        metadata_account: accounts[1],
+        bonding_curve: accounts[2],
+        ..Default::default()
+    }))
+}
+
+/// 解析迁移池子 coin creator 指令事件
+/// 账户: 0: pool, 1: sharing_config, 2: event_authority, 3: program
+fn parse_migrate_pool_coin_creator_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpSwapMigratePoolCoinCreator;
+
+    if accounts.len() < 2 {
+        return None;
+    }
+
+    Some(DexEvent::PumpSwapMigratePoolCoinCreatorEvent(PumpSwapMigratePoolCoinCreatorEvent {
+        metadata,
+        pool: accounts[0],
+        sharing_config: accounts[1],
+        ..Default::default()
+    }))
+}
+
+/// 解析 coin creator 手续费转入 pump 指令事件
+/// 账户: 0: wsol_mint, 1: token_program, 2: system_program, 3: associated_token_program,
+/// 4: coin_creator, 5: coin_creator_vault_authority, 6: coin_creator_vault_ata,
+/// 7: pump_creator_vault, 8: event_authority, 9: program
+fn parse_transfer_creator_fees_to_pump_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpSwapTransferCreatorFeesToPump;
+
+    if accounts.len() < 8 {
+        return None;
+    }
+
+    Some(DexEvent::PumpSwapTransferCreatorFeesToPumpEvent(PumpSwapTransferCreatorFeesToPumpEvent {
+        metadata,
+        wsol_mint: accounts[0],
+        token_program: accounts[1],
+        coin_creator: accounts[4],
+        coin_creator_vault_authority: accounts[5],
+        coin_creator_vault_ata: accounts[6],
+        pump_creator_vault: accounts[7],
+    }))
+}
+
+/// 解析切换 mayhem 模式指令事件
+/// 账户: 0: admin, 1: global_config, 2: event_authority, 3: program
+fn parse_toggle_mayhem_mode_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpSwapToggleMayhemMode;
+
+    if data.is_empty() || accounts.len() < 2 {
+        return None;
+    }
+
+    Some(DexEvent::PumpSwapToggleMayhemModeEvent(PumpSwapToggleMayhemModeEvent {
+        metadata,
+        enabled: data[0] != 0,
+        admin: accounts[0],
+        global_config: accounts[1],
+    }))
+}
+
+/// 解析切换 cashback 开关指令事件
+/// 账户: 0: admin, 1: global_config, 2: event_authority, 3: program
+fn parse_toggle_cashback_enabled_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::PumpSwapToggleCashbackEnabled;
+
+    if data.is_empty() || accounts.len() < 2 {
+        return None;
+    }
+
+    Some(DexEvent::PumpSwapToggleCashbackEnabledEvent(PumpSwapToggleCashbackEnabledEvent {
+        metadata,
+        enabled: data[0] != 0,
+        admin: accounts[0],
+        global_config: accounts[1],
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::core::merger_event::merge;
+
+    #[test]
+    fn test_set_coin_creator_merges_metaplex_log() {
+        let accounts: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let mut event = parse_pumpswap_instruction_data(
+            discriminators::SET_COIN_CREATOR_IX,
+            &[],
+            &accounts,
+            EventMetadata::default(),
+        )
+        .unwrap();
+
+        let (base_mint, coin_creator) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut log = 1_700_000_000i64.to_le_bytes().to_vec();
+        log.extend_from_slice(base_mint.as_ref());
+        log.extend_from_slice(accounts[0].as_ref());
+        log.extend_from_slice(accounts[1].as_ref());
+        log.extend_from_slice(coin_creator.as_ref());
+        let cpi_event = parse_pumpswap_inner_instruction_data(
+            discriminators::SET_METAPLEX_COIN_CREATOR_EVENT,
+            &log,
+            EventMetadata::default(),
+        )
+        .unwrap();
+        merge(&mut event, cpi_event);
+
+        let DexEvent::PumpSwapSetCoinCreatorEvent(event) = event else {
+            panic!("expected set coin creator")
+        };
+        assert_eq!(event.metadata.event_type, EventType::PumpSwapSetCoinCreator);
+        assert!(event.from_metaplex);
+        assert_eq!((event.base_mint, event.coin_creator), (base_mint, coin_creator));
+        assert_eq!((event.metadata_account, event.bonding_curve), (accounts[1], accounts[2]));
+        assert_eq!(event.timestamp, 1_700_000_000);
+    }
+}
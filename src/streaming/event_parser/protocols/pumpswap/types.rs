@@ -1,3 +1,5 @@
+use std::sync::LazyLock;
+
 use borsh::BorshDeserialize;
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
@@ -5,12 +7,29 @@ use solana_sdk::pubkey::Pubkey;
 use crate::streaming::{
     event_parser::{
         common::{EventMetadata, EventType},
-        protocols::pumpswap::{PumpSwapGlobalConfigAccountEvent, PumpSwapPoolAccountEvent},
+        protocols::{
+            pumpfun::parser::PUMP_FEES_PROGRAM_ID,
+            pumpswap::{
+                parser::PUMPSWAP_PROGRAM_ID, PumpSwapFeeConfigAccountEvent,
+                PumpSwapGlobalConfigAccountEvent, PumpSwapGlobalVolumeAccumulatorAccountEvent,
+                PumpSwapPoolAccountEvent, PumpSwapSharingConfigAccountEvent,
+                PumpSwapUserVolumeAccumulatorAccountEvent,
+            },
+        },
         DexEvent,
     },
     grpc::AccountPretty,
 };
 
+// FeeConfig / SharingConfig / 交易量累计账户与 PumpFun 布局一致，过滤器与 SharingConfig 地址也通用
+pub use crate::streaming::event_parser::protocols::pumpfun::types::{
+    fee_config_decode, global_volume_accumulator_decode, sharing_config_account_filter,
+    sharing_config_by_mint_filter, sharing_config_decode, sharing_config_pda,
+    user_volume_accumulator_account_filter, user_volume_accumulator_by_user_filter,
+    user_volume_accumulator_decode, ConfigStatus, FeeConfig, FeeTier, Fees,
+    GlobalVolumeAccumulator, Shareholder, SharingConfig, UserVolumeAccumulator,
+};
+
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
 pub struct GlobalConfig {
     pub admin: Pubkey,
@@ -153,6 +172,106 @@ pub fn pool_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Opti
     }
 }
 
+/// PumpSwap 的 FeeConfig 地址（fee program 下以 PumpSwap 程序 ID 派生）
+pub static FEE_CONFIG_ADDRESS: LazyLock<Pubkey> = LazyLock::new(|| {
+    Pubkey::find_program_address(
+        &[b"fee_config", PUMPSWAP_PROGRAM_ID.as_ref()],
+        &PUMP_FEES_PROGRAM_ID,
+    )
+    .0
+});
+
+pub static GLOBAL_VOLUME_ACCUMULATOR_ADDRESS: LazyLock<Pubkey> = LazyLock::new(|| {
+    Pubkey::find_program_address(&[b"global_volume_accumulator"], &PUMPSWAP_PROGRAM_ID).0
+});
+
+/// 用户在 PumpSwap 的交易量累计账户地址
+pub fn user_volume_accumulator_pda(user: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"user_volume_accumulator", user.as_ref()], &PUMPSWAP_PROGRAM_ID)
+        .0
+}
+
+pub fn fee_config_parser(account: &AccountPretty, mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountPumpSwapFeeConfig;
+
+    if account.pubkey != *FEE_CONFIG_ADDRESS {
+        return None;
+    }
+    fee_config_decode(account.data.get(8..)?).map(|fee_config| {
+        DexEvent::PumpSwapFeeConfigAccountEvent(PumpSwapFeeConfigAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            fee_config,
+        })
+    })
+}
+
+/// SharingConfig 由两个协议共用；同时订阅 PumpFun 时归属订阅列表中排在前面的协议
+pub fn sharing_config_parser(
+    account: &AccountPretty,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountPumpSwapSharingConfig;
+
+    sharing_config_decode(account.data.get(8..)?).map(|sharing_config| {
+        DexEvent::PumpSwapSharingConfigAccountEvent(PumpSwapSharingConfigAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            sharing_config,
+        })
+    })
+}
+
+pub fn global_volume_accumulator_parser(
+    account: &AccountPretty,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountPumpSwapGlobalVolumeAccumulator;
+
+    global_volume_accumulator_decode(account.data.get(8..)?).map(|global_volume_accumulator| {
+        DexEvent::PumpSwapGlobalVolumeAccumulatorAccountEvent(
+            PumpSwapGlobalVolumeAccumulatorAccountEvent {
+                metadata,
+                pubkey: account.pubkey,
+                executable: account.executable,
+                lamports: account.lamports,
+                owner: account.owner,
+                rent_epoch: account.rent_epoch,
+                global_volume_accumulator,
+            },
+        )
+    })
+}
+
+pub fn user_volume_accumulator_parser(
+    account: &AccountPretty,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountPumpSwapUserVolumeAccumulator;
+
+    user_volume_accumulator_decode(account.data.get(8..)?).map(|user_volume_accumulator| {
+        DexEvent::PumpSwapUserVolumeAccumulatorAccountEvent(
+            PumpSwapUserVolumeAccumulatorAccountEvent {
+                metadata,
+                pubkey: account.pubkey,
+                executable: account.executable,
+                lamports: account.lamports,
+                owner: account.owner,
+                rent_epoch: account.rent_epoch,
+                user_volume_accumulator,
+            },
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
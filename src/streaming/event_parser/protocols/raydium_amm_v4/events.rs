@@ -17,6 +17,16 @@ pub struct RaydiumAmmV4SwapEvent {
     // base out
     pub max_amount_in: u64,
     pub amount_out: u64,
+    // ray_log
+    /// 1: coin -> pc, 2: pc -> coin
+    pub direction: u64,
+    pub user_source_amount: u64,
+    pub pool_coin_amount: u64,
+    pub pool_pc_amount: u64,
+    /// base in 实际输出数量
+    pub out_amount: u64,
+    /// base out 实际输入数量
+    pub deduct_in: u64,
 
     pub token_program: Pubkey,
     pub amm: Pubkey,
@@ -46,6 +56,15 @@ pub struct RaydiumAmmV4DepositEvent {
     pub max_coin_amount: u64,
     pub max_pc_amount: u64,
     pub base_side: u64,
+    // ray_log
+    pub pool_coin_amount: u64,
+    pub pool_pc_amount: u64,
+    pub pool_lp_amount: u64,
+    pub calc_pnl_x: u128,
+    pub calc_pnl_y: u128,
+    pub deduct_coin: u64,
+    pub deduct_pc: u64,
+    pub mint_lp: u64,
 
     pub token_program: Pubkey,
     pub amm: Pubkey,
@@ -72,6 +91,11 @@ pub struct RaydiumAmmV4Initialize2Event {
     pub open_time: u64,
     pub init_pc_amount: u64,
     pub init_coin_amount: u64,
+    // ray_log
+    pub pc_decimals: u8,
+    pub coin_decimals: u8,
+    pub pc_lot_size: u64,
+    pub coin_lot_size: u64,
 
     pub token_program: Pubkey,
     pub spl_associated_token_account: Pubkey,
@@ -102,6 +126,15 @@ pub struct RaydiumAmmV4WithdrawEvent {
     #[borsh(skip)]
     pub metadata: EventMetadata,
     pub amount: u64,
+    // ray_log
+    pub user_lp_amount: u64,
+    pub pool_coin_amount: u64,
+    pub pool_pc_amount: u64,
+    pub pool_lp_amount: u64,
+    pub calc_pnl_x: u128,
+    pub calc_pnl_y: u128,
+    pub out_coin: u64,
+    pub out_pc: u64,
 
     pub token_program: Pubkey,
     pub amm: Pubkey,
@@ -177,4 +210,11 @@ pub mod discriminators {
 
     /// 池信息鉴别器
     pub const AMM_INFO: &[u8] = &[6];
+
+    // ray_log 类型
+    pub const INIT_LOG: &[u8] = &[0];
+    pub const DEPOSIT_LOG: &[u8] = &[1];
+    pub const WITHDRAW_LOG: &[u8] = &[2];
+    pub const SWAP_BASE_IN_LOG: &[u8] = &[3];
+    pub const SWAP_BASE_OUT_LOG: &[u8] = &[4];
 }
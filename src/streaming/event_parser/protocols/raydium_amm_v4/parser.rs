@@ -1,11 +1,12 @@
 use crate::streaming::event_parser::{
-    common::{read_u64_le, EventMetadata, EventType},
+    common::{extract_program_log, read_u64_le, EventMetadata, EventType},
     protocols::raydium_amm_v4::{
-        discriminators, RaydiumAmmV4DepositEvent, RaydiumAmmV4Initialize2Event,
+        discriminators, types, RaydiumAmmV4DepositEvent, RaydiumAmmV4Initialize2Event,
         RaydiumAmmV4SwapEvent, RaydiumAmmV4WithdrawEvent, RaydiumAmmV4WithdrawPnlEvent,
     },
     DexEvent,
 };
+use base64::Engine;
 use solana_sdk::pubkey::Pubkey;
 
 /// Raydium AMM V4程序ID
@@ -49,6 +50,115 @@ pub fn parse_raydium_amm_v4_inner_instruction_data(
     None
 }
 
+/// ray_log 日志前缀
+pub const RAY_LOG_PREFIX: &str = "Program log: ray_log: ";
+
+/// 解析 Raydium AMM V4 指令日志
+///
+/// AMM V4 不发出 CPI 事件，实际成交数量与池子储备通过 `ray_log`（base64 编码）输出
+pub fn parse_raydium_amm_v4_instruction_logs(
+    logs: &[&str],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    let ray_log = logs.iter().rev().find_map(|log| extract_program_log(log, RAY_LOG_PREFIX))?;
+    let data = base64::engine::general_purpose::STANDARD.decode(ray_log).ok()?;
+    parse_ray_log_data(&data, metadata)
+}
+
+/// 解析解码后的 ray_log 数据
+///
+/// 首字节为日志类型，其余字段按小端序定长编码
+pub fn parse_ray_log_data(data: &[u8], mut metadata: EventMetadata) -> Option<DexEvent> {
+    if data.is_empty() {
+        return None;
+    }
+    match &data[..1] {
+        discriminators::SWAP_BASE_IN_LOG => {
+            let log = types::swap_base_in_log_decode(data)?;
+            metadata.event_type = EventType::RaydiumAmmV4SwapBaseIn;
+            Some(DexEvent::RaydiumAmmV4SwapEvent(RaydiumAmmV4SwapEvent {
+                metadata,
+                amount_in: log.amount_in,
+                minimum_amount_out: log.minimum_out,
+                direction: log.direction,
+                user_source_amount: log.user_source,
+                pool_coin_amount: log.pool_coin,
+                pool_pc_amount: log.pool_pc,
+                out_amount: log.out_amount,
+                ..Default::default()
+            }))
+        }
+        discriminators::SWAP_BASE_OUT_LOG => {
+            let log = types::swap_base_out_log_decode(data)?;
+            metadata.event_type = EventType::RaydiumAmmV4SwapBaseOut;
+            Some(DexEvent::RaydiumAmmV4SwapEvent(RaydiumAmmV4SwapEvent {
+                metadata,
+                max_amount_in: log.max_in,
+                amount_out: log.amount_out,
+                direction: log.direction,
+                user_source_amount: log.user_source,
+                pool_coin_amount: log.pool_coin,
+                pool_pc_amount: log.pool_pc,
+                deduct_in: log.deduct_in,
+                ..Default::default()
+            }))
+        }
+        discriminators::DEPOSIT_LOG => {
+            let log = types::deposit_log_decode(data)?;
+            metadata.event_type = EventType::RaydiumAmmV4Deposit;
+            Some(DexEvent::RaydiumAmmV4DepositEvent(RaydiumAmmV4DepositEvent {
+                metadata,
+                max_coin_amount: log.max_coin,
+                max_pc_amount: log.max_pc,
+                base_side: log.base,
+                pool_coin_amount: log.pool_coin,
+                pool_pc_amount: log.pool_pc,
+                pool_lp_amount: log.pool_lp,
+                calc_pnl_x: log.calc_pnl_x,
+                calc_pnl_y: log.calc_pnl_y,
+                deduct_coin: log.deduct_coin,
+                deduct_pc: log.deduct_pc,
+                mint_lp: log.mint_lp,
+                ..Default::default()
+            }))
+        }
+        discriminators::WITHDRAW_LOG => {
+            let log = types::withdraw_log_decode(data)?;
+            metadata.event_type = EventType::RaydiumAmmV4Withdraw;
+            Some(DexEvent::RaydiumAmmV4WithdrawEvent(RaydiumAmmV4WithdrawEvent {
+                metadata,
+                amount: log.withdraw_lp,
+                user_lp_amount: log.user_lp,
+                pool_coin_amount: log.pool_coin,
+                pool_pc_amount: log.pool_pc,
+                pool_lp_amount: log.pool_lp,
+                calc_pnl_x: log.calc_pnl_x,
+                calc_pnl_y: log.calc_pnl_y,
+                out_coin: log.out_coin,
+                out_pc: log.out_pc,
+                ..Default::default()
+            }))
+        }
+        discriminators::INIT_LOG => {
+            let log = types::init_log_decode(data)?;
+            metadata.event_type = EventType::RaydiumAmmV4Initialize2;
+            Some(DexEvent::RaydiumAmmV4Initialize2Event(RaydiumAmmV4Initialize2Event {
+                metadata,
+                open_time: log.time,
+                init_pc_amount: log.pc_amount,
+                init_coin_amount: log.coin_amount,
+                pc_decimals: log.pc_decimals,
+                coin_decimals: log.coin_decimals,
+                pc_lot_size: log.pc_lot_size,
+                coin_lot_size: log.coin_lot_size,
+                serum_market: log.market,
+                ..Default::default()
+            }))
+        }
+        _ => None,
+    }
+}
+
 
 /// 解析 Raydium AMM V4 账户数据
 ///
@@ -140,6 +250,8 @@ fn parse_withdraw_instruction(
         serum_event_queue: accounts[19],
         serum_bids: accounts[20],
         serum_asks: accounts[21],
+
+        ..Default::default()
     }))
 }
 
@@ -187,6 +299,8 @@ fn parse_initialize2_instruction(
         user_token_coin: accounts[18],
         user_token_pc: accounts[19],
         user_lp_token_account: accounts[20],
+
+        ..Default::default()
     }))
 }
 
@@ -225,6 +339,8 @@ fn parse_deposit_instruction(
         user_lp_token_account: accounts[11],
         user_owner: accounts[12],
         serum_event_queue: accounts[13],
+
+        ..Default::default()
     }))
 }
 
@@ -325,3 +441,52 @@ fn parse_swap_base_input_instruction(
         ..Default::default()
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        core::transaction_input::fixtures::{parse, transaction, unique_pubkeys},
+        Protocol,
+    };
+    use solana_transaction_status::TransactionStatusMeta;
+
+    #[test]
+    fn test_swap_base_in_merges_ray_log() {
+        let accounts = unique_pubkeys(18);
+        let mut data = discriminators::SWAP_BASE_IN.to_vec();
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&900u64.to_le_bytes());
+        let tx = transaction(RAYDIUM_AMM_V4_PROGRAM_ID, &data, &accounts);
+
+        let mut ray_log = discriminators::SWAP_BASE_IN_LOG.to_vec();
+        for amount in [1_000u64, 900, 2, 5_000, 80_000, 40_000, 1_950] {
+            ray_log.extend_from_slice(&amount.to_le_bytes());
+        }
+        let token = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+        let meta = TransactionStatusMeta {
+            log_messages: Some(vec![
+                format!("Program {RAYDIUM_AMM_V4_PROGRAM_ID} invoke [1]"),
+                format!(
+                    "{RAY_LOG_PREFIX}{}",
+                    base64::engine::general_purpose::STANDARD.encode(&ray_log)
+                ),
+                format!("Program {token} invoke [2]"),
+                "Program log: Instruction: Transfer".to_string(),
+                format!("Program {token} success"),
+                format!("Program {RAYDIUM_AMM_V4_PROGRAM_ID} success"),
+            ]),
+            ..Default::default()
+        };
+
+        let events = parse(&[Protocol::RaydiumAmmV4], &tx, &meta);
+
+        assert_eq!(events.len(), 1);
+        let DexEvent::RaydiumAmmV4SwapEvent(event) = &events[0] else { panic!("expected swap") };
+        assert_eq!(event.metadata.event_type, EventType::RaydiumAmmV4SwapBaseIn);
+        assert_eq!(event.amm, accounts[1]);
+        assert_eq!((event.amount_in, event.minimum_amount_out), (1_000, 900));
+        assert_eq!((event.direction, event.out_amount), (2, 1_950));
+        assert_eq!((event.pool_coin_amount, event.pool_pc_amount), (80_000, 40_000));
+    }
+}
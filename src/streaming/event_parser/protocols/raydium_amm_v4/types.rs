@@ -142,3 +142,120 @@ pub fn market_state_decode(data: &[u8]) -> Option<MarketState> {
     }
     borsh::from_slice::<MarketState>(&data[..MARKET_STATE_SIZE]).ok()
 }
+
+/// ray_log: 初始化池子
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct InitLog {
+    pub log_type: u8,
+    pub time: u64,
+    pub pc_decimals: u8,
+    pub coin_decimals: u8,
+    pub pc_lot_size: u64,
+    pub coin_lot_size: u64,
+    pub pc_amount: u64,
+    pub coin_amount: u64,
+    pub market: Pubkey,
+}
+
+pub const INIT_LOG_SIZE: usize = 75;
+
+/// ray_log: 添加流动性
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct DepositLog {
+    pub log_type: u8,
+    pub max_coin: u64,
+    pub max_pc: u64,
+    pub base: u64,
+    pub pool_coin: u64,
+    pub pool_pc: u64,
+    pub pool_lp: u64,
+    pub calc_pnl_x: u128,
+    pub calc_pnl_y: u128,
+    pub deduct_coin: u64,
+    pub deduct_pc: u64,
+    pub mint_lp: u64,
+}
+
+pub const DEPOSIT_LOG_SIZE: usize = 105;
+
+/// ray_log: 移除流动性
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct WithdrawLog {
+    pub log_type: u8,
+    pub withdraw_lp: u64,
+    pub user_lp: u64,
+    pub pool_coin: u64,
+    pub pool_pc: u64,
+    pub pool_lp: u64,
+    pub calc_pnl_x: u128,
+    pub calc_pnl_y: u128,
+    pub out_coin: u64,
+    pub out_pc: u64,
+}
+
+pub const WITHDRAW_LOG_SIZE: usize = 89;
+
+/// ray_log: swap_base_in
+///
+/// direction: 1 为 coin -> pc，2 为 pc -> coin
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapBaseInLog {
+    pub log_type: u8,
+    pub amount_in: u64,
+    pub minimum_out: u64,
+    pub direction: u64,
+    pub user_source: u64,
+    pub pool_coin: u64,
+    pub pool_pc: u64,
+    pub out_amount: u64,
+}
+
+/// ray_log: swap_base_out
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct SwapBaseOutLog {
+    pub log_type: u8,
+    pub max_in: u64,
+    pub amount_out: u64,
+    pub direction: u64,
+    pub user_source: u64,
+    pub pool_coin: u64,
+    pub pool_pc: u64,
+    pub deduct_in: u64,
+}
+
+pub const SWAP_LOG_SIZE: usize = 57;
+
+pub fn init_log_decode(data: &[u8]) -> Option<InitLog> {
+    if data.len() < INIT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<InitLog>(&data[..INIT_LOG_SIZE]).ok()
+}
+
+pub fn deposit_log_decode(data: &[u8]) -> Option<DepositLog> {
+    if data.len() < DEPOSIT_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<DepositLog>(&data[..DEPOSIT_LOG_SIZE]).ok()
+}
+
+pub fn withdraw_log_decode(data: &[u8]) -> Option<WithdrawLog> {
+    if data.len() < WITHDRAW_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<WithdrawLog>(&data[..WITHDRAW_LOG_SIZE]).ok()
+}
+
+pub fn swap_base_in_log_decode(data: &[u8]) -> Option<SwapBaseInLog> {
+    if data.len() < SWAP_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<SwapBaseInLog>(&data[..SWAP_LOG_SIZE]).ok()
+}
+
+pub fn swap_base_out_log_decode(data: &[u8]) -> Option<SwapBaseOutLog> {
+    if data.len() < SWAP_LOG_SIZE {
+        return None;
+    }
+    borsh::from_slice::<SwapBaseOutLog>(&data[..SWAP_LOG_SIZE]).ok()
+}
@@ -1,5 +1,7 @@
 use crate::streaming::event_parser::common::EventMetadata;
-use crate::streaming::event_parser::protocols::raydium_clmm::types::{PoolState, TickArrayState};
+use crate::streaming::event_parser::protocols::raydium_clmm::types::{
+    ObservationState, PersonalPositionState, PoolState, TickArrayBitmapExtension, TickArrayState,
+};
 use crate::{
     streaming::event_parser::protocols::raydium_clmm::types::AmmConfig,
 };
@@ -25,6 +27,16 @@ pub struct RaydiumClmmSwapEvent {
     pub token_program: Pubkey,
     pub tick_array: Pubkey,
     pub remaining_accounts: Vec<Pubkey>,
+
+    // 来自 SwapEvent 日志的数据
+    pub amount0: u64,
+    pub transfer_fee0: u64,
+    pub amount1: u64,
+    pub transfer_fee1: u64,
+    pub zero_for_one: bool,
+    pub sqrt_price_x64: u128,
+    pub liquidity: u128,
+    pub tick: i32,
 }
 
 
@@ -50,6 +62,16 @@ pub struct RaydiumClmmSwapV2Event {
     pub input_vault_mint: Pubkey,
     pub output_vault_mint: Pubkey,
     pub remaining_accounts: Vec<Pubkey>,
+
+    // 来自 SwapEvent 日志的数据
+    pub amount0: u64,
+    pub transfer_fee0: u64,
+    pub amount1: u64,
+    pub transfer_fee1: u64,
+    pub zero_for_one: bool,
+    pub sqrt_price_x64: u128,
+    pub liquidity: u128,
+    pub tick: i32,
 }
 
 /// 关闭仓位
@@ -88,6 +110,16 @@ pub struct RaydiumClmmDecreaseLiquidityV2Event {
     pub vault0_mint: Pubkey,
     pub vault1_mint: Pubkey,
     pub remaining_accounts: Vec<Pubkey>,
+
+    // 来自 DecreaseLiquidityEvent 日志的数据
+    pub position_nft_mint: Pubkey,
+    pub decrease_amount0: u64,
+    pub decrease_amount1: u64,
+    pub fee_amount0: u64,
+    pub fee_amount1: u64,
+    pub reward_amounts: [u64; 3],
+    pub transfer_fee0: u64,
+    pub transfer_fee1: u64,
 }
 
 /// 创建池
@@ -134,6 +166,13 @@ pub struct RaydiumClmmIncreaseLiquidityV2Event {
     pub token_program2022: Pubkey,
     pub vault0_mint: Pubkey,
     pub vault1_mint: Pubkey,
+
+    // 来自 IncreaseLiquidityEvent 日志的数据
+    pub position_nft_mint: Pubkey,
+    pub amount0: u64,
+    pub amount1: u64,
+    pub amount0_transfer_fee: u64,
+    pub amount1_transfer_fee: u64,
 }
 
 /// 打开仓位v2
@@ -211,6 +250,225 @@ pub struct RaydiumClmmOpenPositionV2Event {
     pub remaining_accounts: Vec<Pubkey>,
 }
 
+/// 打开仓位
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmOpenPositionEvent {
+    pub metadata: EventMetadata,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub tick_array_lower_start_index: i32,
+    pub tick_array_upper_start_index: i32,
+    pub liquidity: u128,
+    pub amount0_max: u64,
+    pub amount1_max: u64,
+
+    pub payer: Pubkey,
+    pub position_nft_owner: Pubkey,
+    pub position_nft_mint: Pubkey,
+    pub position_nft_account: Pubkey,
+    pub metadata_account: Pubkey,
+    pub pool_state: Pubkey,
+    pub protocol_position: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+    pub personal_position: Pubkey,
+    pub token_account0: Pubkey,
+    pub token_account1: Pubkey,
+    pub token_vault0: Pubkey,
+    pub token_vault1: Pubkey,
+    pub rent: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub associated_token_program: Pubkey,
+    pub metadata_program: Pubkey,
+    pub remaining_accounts: Vec<Pubkey>,
+}
+
+/// 增加流动性
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmIncreaseLiquidityEvent {
+    pub metadata: EventMetadata,
+    pub liquidity: u128,
+    pub amount0_max: u64,
+    pub amount1_max: u64,
+    pub nft_owner: Pubkey,
+    pub nft_account: Pubkey,
+    pub pool_state: Pubkey,
+    pub protocol_position: Pubkey,
+    pub personal_position: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+    pub token_account0: Pubkey,
+    pub token_account1: Pubkey,
+    pub token_vault0: Pubkey,
+    pub token_vault1: Pubkey,
+    pub token_program: Pubkey,
+
+    // 来自 IncreaseLiquidityEvent 日志的数据
+    pub position_nft_mint: Pubkey,
+    pub amount0: u64,
+    pub amount1: u64,
+    pub amount0_transfer_fee: u64,
+    pub amount1_transfer_fee: u64,
+}
+
+/// 减少流动性
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmDecreaseLiquidityEvent {
+    pub metadata: EventMetadata,
+    pub liquidity: u128,
+    pub amount0_min: u64,
+    pub amount1_min: u64,
+    pub nft_owner: Pubkey,
+    pub nft_account: Pubkey,
+    pub personal_position: Pubkey,
+    pub pool_state: Pubkey,
+    pub protocol_position: Pubkey,
+    pub token_vault0: Pubkey,
+    pub token_vault1: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+    pub recipient_token_account0: Pubkey,
+    pub recipient_token_account1: Pubkey,
+    pub token_program: Pubkey,
+    pub remaining_accounts: Vec<Pubkey>,
+
+    // 来自 DecreaseLiquidityEvent 日志的数据
+    pub position_nft_mint: Pubkey,
+    pub decrease_amount0: u64,
+    pub decrease_amount1: u64,
+    pub fee_amount0: u64,
+    pub fee_amount1: u64,
+    pub reward_amounts: [u64; 3],
+    pub transfer_fee0: u64,
+    pub transfer_fee1: u64,
+}
+
+/// 多跳交易 (swapRouterBaseIn)
+///
+/// 每一跳的池子账户位于 `remaining_accounts`，各跳成交明细来自 SwapEvent 日志，
+/// 按出现顺序合并到 `hops`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmSwapRouterBaseInEvent {
+    pub metadata: EventMetadata,
+    pub amount_in: u64,
+    pub amount_out_minimum: u64,
+    pub payer: Pubkey,
+    pub input_token_account: Pubkey,
+    pub input_token_mint: Pubkey,
+    pub token_program: Pubkey,
+    pub token_program2022: Pubkey,
+    pub memo_program: Pubkey,
+    pub remaining_accounts: Vec<Pubkey>,
+
+    // 来自 SwapEvent 日志的数据
+    pub hops: Vec<RaydiumClmmSwapHop>,
+}
+
+/// 多跳交易中的一跳
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmSwapHop {
+    pub pool_state: Pubkey,
+    pub input_token_account: Pubkey,
+    pub output_token_account: Pubkey,
+    pub zero_for_one: bool,
+    pub input_amount: u64,
+    pub input_transfer_fee: u64,
+    pub output_amount: u64,
+    pub output_transfer_fee: u64,
+    pub sqrt_price_x64: u128,
+    pub liquidity: u128,
+    pub tick: i32,
+}
+
+impl From<RaydiumClmmSwapEvent> for RaydiumClmmSwapHop {
+    fn from(event: RaydiumClmmSwapEvent) -> Self {
+        let (input_amount, input_transfer_fee, output_amount, output_transfer_fee) =
+            if event.zero_for_one {
+                (event.amount0, event.transfer_fee0, event.amount1, event.transfer_fee1)
+            } else {
+                (event.amount1, event.transfer_fee1, event.amount0, event.transfer_fee0)
+            };
+        Self {
+            pool_state: event.pool_state,
+            input_token_account: event.input_token_account,
+            output_token_account: event.output_token_account,
+            zero_for_one: event.zero_for_one,
+            input_amount,
+            input_transfer_fee,
+            output_amount,
+            output_transfer_fee,
+            sqrt_price_x64: event.sqrt_price_x64,
+            liquidity: event.liquidity,
+            tick: event.tick,
+        }
+    }
+}
+
+/// 领取个人仓位手续费 (仅来自 CollectPersonalFeeEvent 日志)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmCollectPersonalFeeEvent {
+    pub metadata: EventMetadata,
+    pub position_nft_mint: Pubkey,
+    pub recipient_token_account0: Pubkey,
+    pub recipient_token_account1: Pubkey,
+    pub amount0: u64,
+    pub amount1: u64,
+}
+
+/// 回收剩余奖励
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmCollectRemainingRewardsEvent {
+    pub metadata: EventMetadata,
+    pub reward_index: u8,
+    pub reward_funder: Pubkey,
+    pub funder_token_account: Pubkey,
+    pub pool_state: Pubkey,
+    pub reward_token_vault: Pubkey,
+    pub reward_vault_mint: Pubkey,
+    pub token_program: Pubkey,
+    pub token_program2022: Pubkey,
+    pub memo_program: Pubkey,
+}
+
+/// 领取协议手续费
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmCollectProtocolFeeEvent {
+    pub metadata: EventMetadata,
+    pub amount0_requested: u64,
+    pub amount1_requested: u64,
+    pub owner: Pubkey,
+    pub pool_state: Pubkey,
+    pub amm_config: Pubkey,
+    pub token_vault0: Pubkey,
+    pub token_vault1: Pubkey,
+    pub vault0_mint: Pubkey,
+    pub vault1_mint: Pubkey,
+    pub recipient_token_account0: Pubkey,
+    pub recipient_token_account1: Pubkey,
+    pub token_program: Pubkey,
+    pub token_program2022: Pubkey,
+}
+
+/// 领取基金手续费
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmCollectFundFeeEvent {
+    pub metadata: EventMetadata,
+    pub amount0_requested: u64,
+    pub amount1_requested: u64,
+    pub owner: Pubkey,
+    pub pool_state: Pubkey,
+    pub amm_config: Pubkey,
+    pub token_vault0: Pubkey,
+    pub token_vault1: Pubkey,
+    pub vault0_mint: Pubkey,
+    pub vault1_mint: Pubkey,
+    pub recipient_token_account0: Pubkey,
+    pub recipient_token_account1: Pubkey,
+    pub token_program: Pubkey,
+    pub token_program2022: Pubkey,
+}
+
 /// 池配置
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RaydiumClmmAmmConfigAccountEvent {
@@ -247,8 +505,47 @@ pub struct RaydiumClmmTickArrayStateAccountEvent {
     pub tick_array_state: TickArrayState,
 }
 
+/// 个人仓位
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmPersonalPositionStateAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub personal_position_state: PersonalPositionState,
+}
+
+/// 价格观测
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmObservationStateAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub observation_state: ObservationState,
+}
+
+/// Tick array 位图扩展
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaydiumClmmTickArrayBitmapExtensionAccountEvent {
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub tick_array_bitmap_extension: TickArrayBitmapExtension,
+}
+
 /// 事件鉴别器常量
 pub mod discriminators {
+    /// 其余指令、事件与账户判别器由 `idl/raydium_clmm.json` 生成
+    pub use crate::streaming::event_parser::idl::generated::raydium_clmm::discriminators::*;
+
     // 指令鉴别器
     pub const SWAP: &[u8] = &[248, 198, 158, 145, 225, 117, 135, 200];
     pub const SWAP_V2: &[u8] = &[43, 4, 237, 11, 26, 201, 30, 98];
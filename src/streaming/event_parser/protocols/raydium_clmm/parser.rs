@@ -3,14 +3,20 @@ use crate::streaming::event_parser::{
         read_i32_le, read_option_bool, read_u128_le, read_u64_le, read_u8_le, EventMetadata,
         EventType,
     },
+    idl::{generated::raydium_clmm as idl_types, IdlDecode},
     protocols::raydium_clmm::{
-        discriminators, RaydiumClmmClosePositionEvent, RaydiumClmmCreatePoolEvent,
-        RaydiumClmmDecreaseLiquidityV2Event, RaydiumClmmIncreaseLiquidityV2Event,
-        RaydiumClmmOpenPositionV2Event, RaydiumClmmOpenPositionWithToken22NftEvent,
-        RaydiumClmmSwapEvent, RaydiumClmmSwapV2Event,
+        discriminators, types, RaydiumClmmClosePositionEvent, RaydiumClmmCollectFundFeeEvent,
+        RaydiumClmmCollectPersonalFeeEvent, RaydiumClmmCollectProtocolFeeEvent,
+        RaydiumClmmCollectRemainingRewardsEvent, RaydiumClmmCreatePoolEvent,
+        RaydiumClmmDecreaseLiquidityEvent, RaydiumClmmDecreaseLiquidityV2Event,
+        RaydiumClmmIncreaseLiquidityEvent, RaydiumClmmIncreaseLiquidityV2Event,
+        RaydiumClmmOpenPositionEvent, RaydiumClmmOpenPositionV2Event,
+        RaydiumClmmOpenPositionWithToken22NftEvent, RaydiumClmmSwapEvent,
+        RaydiumClmmSwapRouterBaseInEvent, RaydiumClmmSwapV2Event,
     },
     DexEvent,
 };
+use crate::streaming::grpc::AccountPretty;
 use solana_sdk::pubkey::Pubkey;
 
 /// Raydium CLMM程序ID
@@ -45,39 +51,87 @@ pub fn parse_raydium_clmm_instruction_data(
         discriminators::OPEN_POSITION_V2 => {
             parse_open_position_v2_instruction(data, accounts, metadata)
         }
+        discriminators::OPEN_POSITION_IX => {
+            parse_open_position_instruction(data, accounts, metadata)
+        }
+        discriminators::INCREASE_LIQUIDITY_IX => {
+            parse_increase_liquidity_instruction(data, accounts, metadata)
+        }
+        discriminators::DECREASE_LIQUIDITY_IX => {
+            parse_decrease_liquidity_instruction(data, accounts, metadata)
+        }
+        discriminators::SWAP_ROUTER_BASE_IN_IX => {
+            parse_swap_router_base_in_instruction(data, accounts, metadata)
+        }
+        discriminators::COLLECT_REMAINING_REWARDS_IX => {
+            parse_collect_remaining_rewards_instruction(data, accounts, metadata)
+        }
+        discriminators::COLLECT_PROTOCOL_FEE_IX => {
+            parse_collect_protocol_fee_instruction(data, accounts, metadata)
+        }
+        discriminators::COLLECT_FUND_FEE_IX => {
+            parse_collect_fund_fee_instruction(data, accounts, metadata)
+        }
         _ => None,
     }
 }
 
 /// 解析 Raydium CLMM inner instruction data
 ///
-/// Raydium CLMM 没有 inner instruction 事件
+/// Raydium CLMM 通过 `emit!` 将事件写入 "Program data:" 日志，这里处理以 CPI 形式
+/// (EVENT_IX_TAG + 事件判别器) 出现的同名事件
 pub fn parse_raydium_clmm_inner_instruction_data(
-    _discriminator: &[u8],
-    _data: &[u8],
-    _metadata: EventMetadata,
+    discriminator: &[u8],
+    data: &[u8],
+    metadata: EventMetadata,
 ) -> Option<DexEvent> {
-    None
+    match discriminator {
+        discriminators::SWAP_EVENT => parse_swap_inner_instruction(data, metadata),
+        discriminators::INCREASE_LIQUIDITY_EVENT => {
+            parse_increase_liquidity_inner_instruction(data, metadata)
+        }
+        discriminators::DECREASE_LIQUIDITY_EVENT => {
+            parse_decrease_liquidity_inner_instruction(data, metadata)
+        }
+        discriminators::COLLECT_PERSONAL_FEE_EVENT => {
+            parse_collect_personal_fee_inner_instruction(data, metadata)
+        }
+        _ => None,
+    }
 }
 
+/// 解析 "Program data:" 日志中的 Raydium CLMM 事件
+///
+/// `data` 为 base64 解码后的日志数据，以 8 字节事件判别器开头
+pub fn parse_raydium_clmm_log_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    if data.len() < 8 {
+        return None;
+    }
+    let mut discriminator = discriminators::SWAP_EVENT[..8].to_vec();
+    discriminator.extend_from_slice(&data[..8]);
+    parse_raydium_clmm_inner_instruction_data(&discriminator, &data[8..], metadata)
+}
 
 /// 解析 Raydium CLMM 账户数据
 ///
 /// 根据判别器路由到具体的账户解析函数
 pub fn parse_raydium_clmm_account_data(
     discriminator: &[u8],
-    account: &crate::streaming::grpc::AccountPretty,
-    metadata: crate::streaming::event_parser::common::EventMetadata,
-) -> Option<crate::streaming::event_parser::DexEvent> {
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
     match discriminator {
-        discriminators::AMM_CONFIG => {
-            crate::streaming::event_parser::protocols::raydium_clmm::types::amm_config_parser(account, metadata)
+        discriminators::AMM_CONFIG => types::amm_config_parser(account, metadata),
+        discriminators::POOL_STATE => types::pool_state_parser(account, metadata),
+        discriminators::TICK_ARRAY_STATE => types::tick_array_state_parser(account, metadata),
+        discriminators::PERSONAL_POSITION_STATE_ACCOUNT => {
+            types::personal_position_state_parser(account, metadata)
         }
-        discriminators::POOL_STATE => {
-            crate::streaming::event_parser::protocols::raydium_clmm::types::pool_state_parser(account, metadata)
+        discriminators::OBSERVATION_STATE_ACCOUNT => {
+            types::observation_state_parser(account, metadata)
         }
-        discriminators::TICK_ARRAY_STATE => {
-            crate::streaming::event_parser::protocols::raydium_clmm::types::tick_array_state_parser(account, metadata)
+        discriminators::TICK_ARRAY_BITMAP_EXTENSION_ACCOUNT => {
+            types::tick_array_bitmap_extension_parser(account, metadata)
         }
         _ => None,
     }
@@ -210,6 +264,7 @@ fn parse_increase_liquidity_v2_instruction(
         token_program2022: accounts[12],
         vault0_mint: accounts[13],
         vault1_mint: accounts[14],
+        ..Default::default()
     }))
 }
 
@@ -277,6 +332,7 @@ fn parse_decrease_liquidity_v2_instruction(
         vault0_mint: accounts[14],
         vault1_mint: accounts[15],
         remaining_accounts: accounts[16..].to_vec(),
+        ..Default::default()
     }))
 }
 
@@ -336,6 +392,7 @@ fn parse_swap_instruction(
         token_program: accounts[8],
         tick_array: accounts[9],
         remaining_accounts: accounts[10..].to_vec(),
+        ..Default::default()
     }))
 }
 
@@ -375,5 +432,428 @@ fn parse_swap_v2_instruction(
         input_vault_mint: accounts[11],
         output_vault_mint: accounts[12],
         remaining_accounts: accounts[13..].to_vec(),
+        ..Default::default()
+    }))
+}
+
+/// 解析打开仓位指令事件
+fn parse_open_position_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumClmmOpenPosition;
+
+    if data.len() < 48 || accounts.len() < 19 {
+        return None;
+    }
+    Some(DexEvent::RaydiumClmmOpenPositionEvent(RaydiumClmmOpenPositionEvent {
+        metadata,
+        tick_lower_index: read_i32_le(data, 0)?,
+        tick_upper_index: read_i32_le(data, 4)?,
+        tick_array_lower_start_index: read_i32_le(data, 8)?,
+        tick_array_upper_start_index: read_i32_le(data, 12)?,
+        liquidity: read_u128_le(data, 16)?,
+        amount0_max: read_u64_le(data, 32)?,
+        amount1_max: read_u64_le(data, 40)?,
+        payer: accounts[0],
+        position_nft_owner: accounts[1],
+        position_nft_mint: accounts[2],
+        position_nft_account: accounts[3],
+        metadata_account: accounts[4],
+        pool_state: accounts[5],
+        protocol_position: accounts[6],
+        tick_array_lower: accounts[7],
+        tick_array_upper: accounts[8],
+        personal_position: accounts[9],
+        token_account0: accounts[10],
+        token_account1: accounts[11],
+        token_vault0: accounts[12],
+        token_vault1: accounts[13],
+        rent: accounts[14],
+        system_program: accounts[15],
+        token_program: accounts[16],
+        associated_token_program: accounts[17],
+        metadata_program: accounts[18],
+        remaining_accounts: accounts[19..].to_vec(),
+    }))
+}
+
+/// 解析增加流动性指令事件
+fn parse_increase_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumClmmIncreaseLiquidity;
+
+    if data.len() < 32 || accounts.len() < 12 {
+        return None;
+    }
+    Some(DexEvent::RaydiumClmmIncreaseLiquidityEvent(RaydiumClmmIncreaseLiquidityEvent {
+        metadata,
+        liquidity: read_u128_le(data, 0)?,
+        amount0_max: read_u64_le(data, 16)?,
+        amount1_max: read_u64_le(data, 24)?,
+        nft_owner: accounts[0],
+        nft_account: accounts[1],
+        pool_state: accounts[2],
+        protocol_position: accounts[3],
+        personal_position: accounts[4],
+        tick_array_lower: accounts[5],
+        tick_array_upper: accounts[6],
+        token_account0: accounts[7],
+        token_account1: accounts[8],
+        token_vault0: accounts[9],
+        token_vault1: accounts[10],
+        token_program: accounts[11],
+        ..Default::default()
+    }))
+}
+
+/// 解析减少流动性指令事件
+fn parse_decrease_liquidity_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumClmmDecreaseLiquidity;
+
+    if data.len() < 32 || accounts.len() < 12 {
+        return None;
+    }
+    Some(DexEvent::RaydiumClmmDecreaseLiquidityEvent(RaydiumClmmDecreaseLiquidityEvent {
+        metadata,
+        liquidity: read_u128_le(data, 0)?,
+        amount0_min: read_u64_le(data, 16)?,
+        amount1_min: read_u64_le(data, 24)?,
+        nft_owner: accounts[0],
+        nft_account: accounts[1],
+        personal_position: accounts[2],
+        pool_state: accounts[3],
+        protocol_position: accounts[4],
+        token_vault0: accounts[5],
+        token_vault1: accounts[6],
+        tick_array_lower: accounts[7],
+        tick_array_upper: accounts[8],
+        recipient_token_account0: accounts[9],
+        recipient_token_account1: accounts[10],
+        token_program: accounts[11],
+        remaining_accounts: accounts[12..].to_vec(),
+        ..Default::default()
+    }))
+}
+
+/// 解析多跳交易指令事件
+fn parse_swap_router_base_in_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumClmmSwapRouterBaseIn;
+
+    if data.len() < 16 || accounts.len() < 6 {
+        return None;
+    }
+    Some(DexEvent::RaydiumClmmSwapRouterBaseInEvent(RaydiumClmmSwapRouterBaseInEvent {
+        metadata,
+        amount_in: read_u64_le(data, 0)?,
+        amount_out_minimum: read_u64_le(data, 8)?,
+        payer: accounts[0],
+        input_token_account: accounts[1],
+        input_token_mint: accounts[2],
+        token_program: accounts[3],
+        token_program2022: accounts[4],
+        memo_program: accounts[5],
+        remaining_accounts: accounts[6..].to_vec(),
+        ..Default::default()
+    }))
+}
+
+/// 解析回收剩余奖励指令事件
+fn parse_collect_remaining_rewards_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumClmmCollectRemainingRewards;
+
+    if data.is_empty() || accounts.len() < 8 {
+        return None;
+    }
+    Some(DexEvent::RaydiumClmmCollectRemainingRewardsEvent(
+        RaydiumClmmCollectRemainingRewardsEvent {
+            metadata,
+            reward_index: read_u8_le(data, 0)?,
+            reward_funder: accounts[0],
+            funder_token_account: accounts[1],
+            pool_state: accounts[2],
+            reward_token_vault: accounts[3],
+            reward_vault_mint: accounts[4],
+            token_program: accounts[5],
+            token_program2022: accounts[6],
+            memo_program: accounts[7],
+        },
+    ))
+}
+
+/// 解析领取协议手续费指令事件
+fn parse_collect_protocol_fee_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumClmmCollectProtocolFee;
+
+    if data.len() < 16 || accounts.len() < 11 {
+        return None;
+    }
+    Some(DexEvent::RaydiumClmmCollectProtocolFeeEvent(RaydiumClmmCollectProtocolFeeEvent {
+        metadata,
+        amount0_requested: read_u64_le(data, 0)?,
+        amount1_requested: read_u64_le(data, 8)?,
+        owner: accounts[0],
+        pool_state: accounts[1],
+        amm_config: accounts[2],
+        token_vault0: accounts[3],
+        token_vault1: accounts[4],
+        vault0_mint: accounts[5],
+        vault1_mint: accounts[6],
+        recipient_token_account0: accounts[7],
+        recipient_token_account1: accounts[8],
+        token_program: accounts[9],
+        token_program2022: accounts[10],
     }))
 }
+
+/// 解析领取基金手续费指令事件
+fn parse_collect_fund_fee_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumClmmCollectFundFee;
+
+    if data.len() < 16 || accounts.len() < 11 {
+        return None;
+    }
+    Some(DexEvent::RaydiumClmmCollectFundFeeEvent(RaydiumClmmCollectFundFeeEvent {
+        metadata,
+        amount0_requested: read_u64_le(data, 0)?,
+        amount1_requested: read_u64_le(data, 8)?,
+        owner: accounts[0],
+        pool_state: accounts[1],
+        amm_config: accounts[2],
+        token_vault0: accounts[3],
+        token_vault1: accounts[4],
+        vault0_mint: accounts[5],
+        vault1_mint: accounts[6],
+        recipient_token_account0: accounts[7],
+        recipient_token_account1: accounts[8],
+        token_program: accounts[9],
+        token_program2022: accounts[10],
+    }))
+}
+
+/// 解析 SwapEvent 事件
+fn parse_swap_inner_instruction(data: &[u8], mut metadata: EventMetadata) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumClmmSwap;
+    let event = idl_types::SwapEvent::decode_body(data)?;
+    let (input_token_account, output_token_account) = if event.zero_for_one {
+        (event.token_account0, event.token_account1)
+    } else {
+        (event.token_account1, event.token_account0)
+    };
+    Some(DexEvent::RaydiumClmmSwapEvent(RaydiumClmmSwapEvent {
+        metadata,
+        payer: event.sender,
+        pool_state: event.pool_state,
+        input_token_account,
+        output_token_account,
+        amount0: event.amount0,
+        transfer_fee0: event.transfer_fee0,
+        amount1: event.amount1,
+        transfer_fee1: event.transfer_fee1,
+        zero_for_one: event.zero_for_one,
+        sqrt_price_x64: event.sqrt_price_x64,
+        liquidity: event.liquidity,
+        tick: event.tick,
+        ..Default::default()
+    }))
+}
+
+/// 解析 IncreaseLiquidityEvent 事件
+fn parse_increase_liquidity_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumClmmIncreaseLiquidity;
+    let event = idl_types::IncreaseLiquidityEvent::decode_body(data)?;
+    Some(DexEvent::RaydiumClmmIncreaseLiquidityEvent(RaydiumClmmIncreaseLiquidityEvent {
+        metadata,
+        liquidity: event.liquidity,
+        position_nft_mint: event.position_nft_mint,
+        amount0: event.amount0,
+        amount1: event.amount1,
+        amount0_transfer_fee: event.amount0_transfer_fee,
+        amount1_transfer_fee: event.amount1_transfer_fee,
+        ..Default::default()
+    }))
+}
+
+/// 解析 DecreaseLiquidityEvent 事件
+fn parse_decrease_liquidity_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumClmmDecreaseLiquidity;
+    let event = idl_types::DecreaseLiquidityEvent::decode_body(data)?;
+    Some(DexEvent::RaydiumClmmDecreaseLiquidityEvent(RaydiumClmmDecreaseLiquidityEvent {
+        metadata,
+        liquidity: event.liquidity,
+        position_nft_mint: event.position_nft_mint,
+        decrease_amount0: event.decrease_amount0,
+        decrease_amount1: event.decrease_amount1,
+        fee_amount0: event.fee_amount0,
+        fee_amount1: event.fee_amount1,
+        reward_amounts: event.reward_amounts,
+        transfer_fee0: event.transfer_fee0,
+        transfer_fee1: event.transfer_fee1,
+        ..Default::default()
+    }))
+}
+
+/// 解析 CollectPersonalFeeEvent 事件
+fn parse_collect_personal_fee_inner_instruction(
+    data: &[u8],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumClmmCollectPersonalFee;
+    let event = idl_types::CollectPersonalFeeEvent::decode_body(data)?;
+    Some(DexEvent::RaydiumClmmCollectPersonalFeeEvent(RaydiumClmmCollectPersonalFeeEvent {
+        metadata,
+        position_nft_mint: event.position_nft_mint,
+        recipient_token_account0: event.recipient_token_account0,
+        recipient_token_account1: event.recipient_token_account1,
+        amount0: event.amount0,
+        amount1: event.amount1,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        core::{
+            merger_event::merge,
+            transaction_input::fixtures::{parse, program_data_meta, transaction, unique_pubkeys},
+        },
+        Protocol,
+    };
+
+    #[test]
+    fn test_swap_v2_merges_swap_log_and_sets_swap_data() {
+        let accounts = unique_pubkeys(13);
+        let mut data = 1_000u64.to_le_bytes().to_vec();
+        data.extend_from_slice(&900u64.to_le_bytes());
+        data.extend_from_slice(&0u128.to_le_bytes());
+        data.push(1);
+        let mut event =
+            parse_swap_v2_instruction(&data, &accounts, EventMetadata::default()).unwrap();
+
+        // SwapEvent: zero_for_one = false，token1 -> token0
+        let mut log = discriminators::SWAP_EVENT[8..].to_vec();
+        for key in [accounts[2], accounts[0], accounts[4], accounts[3]] {
+            log.extend_from_slice(key.as_ref());
+        }
+        for amount in [950u64, 0, 1_000, 0] {
+            log.extend_from_slice(&amount.to_le_bytes());
+        }
+        log.push(0);
+        log.extend_from_slice(&(1u128 << 64).to_le_bytes());
+        log.extend_from_slice(&5_000u128.to_le_bytes());
+        log.extend_from_slice(&(-12i32).to_le_bytes());
+        let swap_log = parse_raydium_clmm_log_data(&log, EventMetadata::default()).unwrap();
+        merge(&mut event, swap_log);
+
+        let DexEvent::RaydiumClmmSwapV2Event(event) = &event else { panic!("expected swap v2") };
+        assert_eq!((event.amount0, event.amount1, event.tick), (950, 1_000, -12));
+        assert!(!event.zero_for_one);
+        let swap_data = event.metadata.swap_data.as_ref().unwrap();
+        assert_eq!((swap_data.from_mint, swap_data.to_mint), (accounts[11], accounts[12]));
+        assert_eq!((swap_data.from_amount, swap_data.to_amount), (1_000, 950));
+    }
+
+    /// 解析只包含一条 Raydium CLMM 指令的交易，`logs` 为该指令输出的 `Program data:` 数据
+    fn parse_clmm(data: &[u8], accounts: &[Pubkey], logs: &[Vec<u8>]) -> Vec<DexEvent> {
+        let tx = transaction(RAYDIUM_CLMM_PROGRAM_ID, data, accounts);
+        parse(&[Protocol::RaydiumClmm], &tx, &program_data_meta(RAYDIUM_CLMM_PROGRAM_ID, logs))
+    }
+
+    #[test]
+    fn test_decrease_liquidity_merges_collect_personal_fee_and_decrease_logs() {
+        let accounts = unique_pubkeys(12);
+        let mut data = discriminators::DECREASE_LIQUIDITY_IX.to_vec();
+        data.extend_from_slice(&[0u8; 32]);
+
+        // 领取手续费的 CollectPersonalFeeEvent 先于 DecreaseLiquidityEvent 输出
+        let nft_mint = Pubkey::new_unique();
+        let mut collect_log = discriminators::COLLECT_PERSONAL_FEE_EVENT[8..].to_vec();
+        for key in [nft_mint, accounts[9], accounts[10]] {
+            collect_log.extend_from_slice(key.as_ref());
+        }
+        collect_log.extend_from_slice(&7u64.to_le_bytes());
+        collect_log.extend_from_slice(&8u64.to_le_bytes());
+        let mut decrease_log = discriminators::DECREASE_LIQUIDITY_EVENT[8..].to_vec();
+        decrease_log.extend_from_slice(nft_mint.as_ref());
+        decrease_log.extend_from_slice(&1_000u128.to_le_bytes());
+        for amount in [100u64, 200, 7, 8, 1, 2, 3, 4, 5] {
+            decrease_log.extend_from_slice(&amount.to_le_bytes());
+        }
+
+        let events = parse_clmm(&data, &accounts, &[collect_log, decrease_log]);
+        assert_eq!(events.len(), 1);
+        let DexEvent::RaydiumClmmDecreaseLiquidityEvent(event) = &events[0] else { panic!() };
+        assert_eq!(event.position_nft_mint, nft_mint);
+        assert_eq!((event.fee_amount0, event.fee_amount1), (7, 8));
+        assert_eq!((event.decrease_amount0, event.decrease_amount1), (100, 200));
+        assert_eq!(event.reward_amounts, [1, 2, 3]);
+        assert_eq!((event.transfer_fee0, event.transfer_fee1), (4, 5));
+    }
+
+    #[test]
+    fn test_swap_router_base_in_collects_every_hop() {
+        let accounts = unique_pubkeys(10);
+        let mut data = discriminators::SWAP_ROUTER_BASE_IN_IX.to_vec();
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&200u64.to_le_bytes());
+
+        let swap_log = |pool: Pubkey, zero_for_one: bool, amount0: u64, amount1: u64| {
+            let mut log = discriminators::SWAP_EVENT[8..].to_vec();
+            for key in [pool, accounts[0], Pubkey::new_unique(), Pubkey::new_unique()] {
+                log.extend_from_slice(key.as_ref());
+            }
+            for amount in [amount0, 0, amount1, 0] {
+                log.extend_from_slice(&amount.to_le_bytes());
+            }
+            log.push(zero_for_one as u8);
+            log.extend_from_slice(&(1u128 << 64).to_le_bytes());
+            log.extend_from_slice(&5_000u128.to_le_bytes());
+            log.extend_from_slice(&0i32.to_le_bytes());
+            log
+        };
+        let logs =
+            [swap_log(accounts[7], true, 1_000, 500), swap_log(accounts[9], false, 250, 500)];
+
+        let events = parse_clmm(&data, &accounts, &logs);
+        assert_eq!(events.len(), 1);
+        let DexEvent::RaydiumClmmSwapRouterBaseInEvent(event) = &events[0] else { panic!() };
+        assert_eq!(event.remaining_accounts, accounts[6..]);
+        let hops: Vec<_> = event
+            .hops
+            .iter()
+            .map(|hop| (hop.pool_state, hop.input_amount, hop.output_amount))
+            .collect();
+        assert_eq!(hops, [(accounts[7], 1_000, 500), (accounts[9], 500, 250)]);
+    }
+}
@@ -6,8 +6,9 @@ use crate::streaming::{
     event_parser::{
         common::{EventMetadata, EventType},
         protocols::raydium_clmm::{
-            RaydiumClmmAmmConfigAccountEvent, RaydiumClmmPoolStateAccountEvent,
-            RaydiumClmmTickArrayStateAccountEvent,
+            RaydiumClmmAmmConfigAccountEvent, RaydiumClmmObservationStateAccountEvent,
+            RaydiumClmmPersonalPositionStateAccountEvent, RaydiumClmmPoolStateAccountEvent,
+            RaydiumClmmTickArrayBitmapExtensionAccountEvent, RaydiumClmmTickArrayStateAccountEvent,
         },
         DexEvent,
     },
@@ -231,3 +232,165 @@ pub fn tick_array_state_parser(
         None
     }
 }
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PositionRewardInfo {
+    pub growth_inside_last_x64: u128,
+    pub reward_amount_owed: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct PersonalPositionState {
+    pub bump: [u8; 1],
+    pub nft_mint: Pubkey,
+    pub pool_id: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    pub fee_growth_inside0_last_x64: u128,
+    pub fee_growth_inside1_last_x64: u128,
+    pub token_fees_owed0: u64,
+    pub token_fees_owed1: u64,
+    pub reward_infos: [PositionRewardInfo; 3],
+    pub recent_epoch: u64,
+    pub padding: [u64; 7],
+}
+
+pub const PERSONAL_POSITION_STATE_SIZE: usize =
+    1 + 32 * 2 + 4 * 2 + 16 * 3 + 8 * 2 + 24 * 3 + 8 + 8 * 7;
+
+pub fn personal_position_state_decode(data: &[u8]) -> Option<PersonalPositionState> {
+    if data.len() < PERSONAL_POSITION_STATE_SIZE {
+        return None;
+    }
+    borsh::from_slice::<PersonalPositionState>(&data[..PERSONAL_POSITION_STATE_SIZE]).ok()
+}
+
+pub fn personal_position_state_parser(
+    account: &AccountPretty,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountRaydiumClmmPersonalPositionState;
+
+    if account.data.len() < PERSONAL_POSITION_STATE_SIZE + 8 {
+        return None;
+    }
+    let personal_position_state =
+        personal_position_state_decode(&account.data[8..PERSONAL_POSITION_STATE_SIZE + 8])?;
+    Some(DexEvent::RaydiumClmmPersonalPositionStateAccountEvent(
+        RaydiumClmmPersonalPositionStateAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            personal_position_state,
+        },
+    ))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Observation {
+    pub block_timestamp: u32,
+    pub tick_cumulative: i64,
+    pub padding: [u64; 4],
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ObservationState {
+    pub initialized: bool,
+    pub recent_epoch: u64,
+    pub observation_index: u16,
+    pub pool_id: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub observations: [Observation; 100],
+    pub padding: [u64; 4],
+}
+
+impl Default for ObservationState {
+    fn default() -> Self {
+        Self {
+            initialized: false,
+            recent_epoch: 0,
+            observation_index: 0,
+            pool_id: Pubkey::default(),
+            observations: core::array::from_fn(|_| Observation::default()),
+            padding: [0; 4],
+        }
+    }
+}
+
+pub const OBSERVATION_STATE_SIZE: usize = 1 + 8 + 2 + 32 + (4 + 8 + 8 * 4) * 100 + 8 * 4;
+
+pub fn observation_state_decode(data: &[u8]) -> Option<ObservationState> {
+    if data.len() < OBSERVATION_STATE_SIZE {
+        return None;
+    }
+    borsh::from_slice::<ObservationState>(&data[..OBSERVATION_STATE_SIZE]).ok()
+}
+
+pub fn observation_state_parser(
+    account: &AccountPretty,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountRaydiumClmmObservationState;
+
+    if account.data.len() < OBSERVATION_STATE_SIZE + 8 {
+        return None;
+    }
+    let observation_state = observation_state_decode(&account.data[8..OBSERVATION_STATE_SIZE + 8])?;
+    Some(DexEvent::RaydiumClmmObservationStateAccountEvent(
+        RaydiumClmmObservationStateAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            observation_state,
+        },
+    ))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct TickArrayBitmapExtension {
+    pub pool_id: Pubkey,
+    /// start_tick_index 为正数的已初始化 tick array 位图
+    pub positive_tick_array_bitmap: [[u64; 8]; 14],
+    /// start_tick_index 为负数的已初始化 tick array 位图
+    pub negative_tick_array_bitmap: [[u64; 8]; 14],
+}
+
+pub const TICK_ARRAY_BITMAP_EXTENSION_SIZE: usize = 32 + 8 * 8 * 14 * 2;
+
+pub fn tick_array_bitmap_extension_decode(data: &[u8]) -> Option<TickArrayBitmapExtension> {
+    if data.len() < TICK_ARRAY_BITMAP_EXTENSION_SIZE {
+        return None;
+    }
+    borsh::from_slice::<TickArrayBitmapExtension>(&data[..TICK_ARRAY_BITMAP_EXTENSION_SIZE]).ok()
+}
+
+pub fn tick_array_bitmap_extension_parser(
+    account: &AccountPretty,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountRaydiumClmmTickArrayBitmapExtension;
+
+    if account.data.len() < TICK_ARRAY_BITMAP_EXTENSION_SIZE + 8 {
+        return None;
+    }
+    let tick_array_bitmap_extension =
+        tick_array_bitmap_extension_decode(&account.data[8..TICK_ARRAY_BITMAP_EXTENSION_SIZE + 8])?;
+    Some(DexEvent::RaydiumClmmTickArrayBitmapExtensionAccountEvent(
+        RaydiumClmmTickArrayBitmapExtensionAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            tick_array_bitmap_extension,
+        },
+    ))
+}
@@ -1,5 +1,5 @@
 use crate::streaming::event_parser::common::EventMetadata;
-use crate::streaming::event_parser::protocols::raydium_cpmm::types::PoolState;
+use crate::streaming::event_parser::protocols::raydium_cpmm::types::{ObservationState, PoolState};
 use crate::{
     streaming::event_parser::protocols::raydium_cpmm::types::AmmConfig,
 };
@@ -29,6 +29,22 @@ pub struct RaydiumCpmmSwapEvent {
     pub input_token_mint: Pubkey,
     pub output_token_mint: Pubkey,
     pub observation_state: Pubkey,
+
+    // 来自 SwapEvent 日志的数据
+    /// 交易前输入 vault 余额（已扣除累计交易手续费）
+    pub input_vault_before: u64,
+    /// 交易前输出 vault 余额（已扣除累计交易手续费）
+    pub output_vault_before: u64,
+    /// 实际输入数量（不含 transfer fee）
+    pub input_amount: u64,
+    /// 实际输出数量（不含 transfer fee）
+    pub output_amount: u64,
+    pub input_transfer_fee: u64,
+    pub output_transfer_fee: u64,
+    pub base_input: bool,
+    pub trade_fee: u64,
+    pub creator_fee: u64,
+    pub creator_fee_on_input: bool,
 }
 
 
@@ -54,6 +70,19 @@ pub struct RaydiumCpmmDepositEvent {
     pub vault_0_mint: Pubkey,
     pub vault_1_mint: Pubkey,
     pub lp_mint: Pubkey,
+
+    // 来自 LpChangeEvent 日志的数据
+    pub lp_amount_before: u64,
+    /// 变动前 token0 vault 余额（已扣除累计交易手续费）
+    pub token_0_vault_before: u64,
+    /// 变动前 token1 vault 余额（已扣除累计交易手续费）
+    pub token_1_vault_before: u64,
+    /// 实际 token0 数量（不含 transfer fee）
+    pub token_0_amount: u64,
+    /// 实际 token1 数量（不含 transfer fee）
+    pub token_1_amount: u64,
+    pub token_0_transfer_fee: u64,
+    pub token_1_transfer_fee: u64,
 }
 
 /// 初始化
@@ -110,6 +139,84 @@ pub struct RaydiumCpmmWithdrawEvent {
     pub vault_1_mint: Pubkey,
     pub lp_mint: Pubkey,
     pub memo_program: Pubkey,
+
+    // 来自 LpChangeEvent 日志的数据
+    pub lp_amount_before: u64,
+    /// 变动前 token0 vault 余额（已扣除累计交易手续费）
+    pub token_0_vault_before: u64,
+    /// 变动前 token1 vault 余额（已扣除累计交易手续费）
+    pub token_1_vault_before: u64,
+    /// 实际 token0 数量（不含 transfer fee）
+    pub token_0_amount: u64,
+    /// 实际 token1 数量（不含 transfer fee）
+    pub token_1_amount: u64,
+    pub token_0_transfer_fee: u64,
+    pub token_1_transfer_fee: u64,
+}
+
+/// 领取创建者手续费
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RaydiumCpmmCollectCreatorFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub creator: Pubkey,
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+    pub amm_config: Pubkey,
+    pub token_0_vault: Pubkey,
+    pub token_1_vault: Pubkey,
+    pub vault_0_mint: Pubkey,
+    pub vault_1_mint: Pubkey,
+    pub creator_token_0: Pubkey,
+    pub creator_token_1: Pubkey,
+    pub token_0_program: Pubkey,
+    pub token_1_program: Pubkey,
+    pub associated_token_program: Pubkey,
+    pub system_program: Pubkey,
+}
+
+/// 领取协议手续费
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RaydiumCpmmCollectProtocolFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub amount_0_requested: u64,
+    pub amount_1_requested: u64,
+
+    pub owner: Pubkey,
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+    pub amm_config: Pubkey,
+    pub token_0_vault: Pubkey,
+    pub token_1_vault: Pubkey,
+    pub vault_0_mint: Pubkey,
+    pub vault_1_mint: Pubkey,
+    pub recipient_token_0_account: Pubkey,
+    pub recipient_token_1_account: Pubkey,
+    pub token_program: Pubkey,
+    pub token_program2022: Pubkey,
+}
+
+/// 领取基金手续费
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RaydiumCpmmCollectFundFeeEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub amount_0_requested: u64,
+    pub amount_1_requested: u64,
+
+    pub owner: Pubkey,
+    pub authority: Pubkey,
+    pub pool_state: Pubkey,
+    pub amm_config: Pubkey,
+    pub token_0_vault: Pubkey,
+    pub token_1_vault: Pubkey,
+    pub vault_0_mint: Pubkey,
+    pub vault_1_mint: Pubkey,
+    pub recipient_token_0_account: Pubkey,
+    pub recipient_token_1_account: Pubkey,
+    pub token_program: Pubkey,
+    pub token_program2022: Pubkey,
 }
 
 /// 池配置
@@ -138,8 +245,24 @@ pub struct RaydiumCpmmPoolStateAccountEvent {
     pub pool_state: PoolState,
 }
 
+/// 价格观测
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct RaydiumCpmmObservationStateAccountEvent {
+    #[borsh(skip)]
+    pub metadata: EventMetadata,
+    pub pubkey: Pubkey,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub rent_epoch: u64,
+    pub observation_state: ObservationState,
+}
+
 /// 事件鉴别器常量
 pub mod discriminators {
+    /// 其余指令、事件与账户判别器由 `idl/raydium_cpmm.json` 生成
+    pub use crate::streaming::event_parser::idl::generated::raydium_cpmm::discriminators::*;
+
     // 指令鉴别器
     pub const SWAP_BASE_IN: &[u8] = &[143, 190, 90, 218, 196, 30, 51, 222];
     pub const SWAP_BASE_OUT: &[u8] = &[55, 217, 98, 86, 163, 74, 180, 173];
@@ -2,12 +2,15 @@ use solana_sdk::pubkey::Pubkey;
 
 use crate::streaming::event_parser::{
     common::{read_u64_le, EventMetadata, EventType},
+    idl::{generated::raydium_cpmm as idl_types, IdlDecode},
     protocols::raydium_cpmm::{
-        discriminators, RaydiumCpmmDepositEvent, RaydiumCpmmInitializeEvent, RaydiumCpmmSwapEvent,
-        RaydiumCpmmWithdrawEvent,
+        discriminators, types, RaydiumCpmmCollectCreatorFeeEvent, RaydiumCpmmCollectFundFeeEvent,
+        RaydiumCpmmCollectProtocolFeeEvent, RaydiumCpmmDepositEvent, RaydiumCpmmInitializeEvent,
+        RaydiumCpmmSwapEvent, RaydiumCpmmWithdrawEvent,
     },
     DexEvent,
 };
+use crate::streaming::grpc::AccountPretty;
 
 /// Raydium CPMM程序ID
 pub const RAYDIUM_CPMM_PROGRAM_ID: Pubkey =
@@ -32,19 +35,45 @@ pub fn parse_raydium_cpmm_instruction_data(
         discriminators::DEPOSIT => parse_deposit_instruction(data, accounts, metadata),
         discriminators::INITIALIZE => parse_initialize_instruction(data, accounts, metadata),
         discriminators::WITHDRAW => parse_withdraw_instruction(data, accounts, metadata),
+        discriminators::COLLECT_CREATOR_FEE_IX => {
+            parse_collect_creator_fee_instruction(data, accounts, metadata)
+        }
+        discriminators::COLLECT_PROTOCOL_FEE_IX => {
+            parse_collect_protocol_fee_instruction(data, accounts, metadata)
+        }
+        discriminators::COLLECT_FUND_FEE_IX => {
+            parse_collect_fund_fee_instruction(data, accounts, metadata)
+        }
         _ => None,
     }
 }
 
 /// 解析 Raydium CPMM inner instruction data
 ///
-/// Raydium CPMM 没有 inner instruction 事件
+/// Raydium CPMM 通过 `emit!` 将事件写入 "Program data:" 日志，这里处理以 CPI 形式
+/// (EVENT_IX_TAG + 事件判别器) 出现的同名事件
 pub fn parse_raydium_cpmm_inner_instruction_data(
-    _discriminator: &[u8],
-    _data: &[u8],
-    _metadata: EventMetadata,
+    discriminator: &[u8],
+    data: &[u8],
+    metadata: EventMetadata,
 ) -> Option<DexEvent> {
-    None
+    match discriminator {
+        discriminators::SWAP_EVENT => parse_swap_inner_instruction(data, metadata),
+        discriminators::LP_CHANGE_EVENT => parse_lp_change_inner_instruction(data, metadata),
+        _ => None,
+    }
+}
+
+/// 解析 "Program data:" 日志中的 Raydium CPMM 事件
+///
+/// `data` 为 base64 解码后的日志数据，以 8 字节事件判别器开头
+pub fn parse_raydium_cpmm_log_data(data: &[u8], metadata: EventMetadata) -> Option<DexEvent> {
+    if data.len() < 8 {
+        return None;
+    }
+    let mut discriminator = discriminators::SWAP_EVENT[..8].to_vec();
+    discriminator.extend_from_slice(&data[..8]);
+    parse_raydium_cpmm_inner_instruction_data(&discriminator, &data[8..], metadata)
 }
 
 
@@ -53,15 +82,14 @@ pub fn parse_raydium_cpmm_inner_instruction_data(
 /// 根据判别器路由到具体的账户解析函数
 pub fn parse_raydium_cpmm_account_data(
     discriminator: &[u8],
-    account: &crate::streaming::grpc::AccountPretty,
-    metadata: crate::streaming::event_parser::common::EventMetadata,
-) -> Option<crate::streaming::event_parser::DexEvent> {
+    account: &AccountPretty,
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
     match discriminator {
-        discriminators::AMM_CONFIG => {
-            crate::streaming::event_parser::protocols::raydium_cpmm::types::amm_config_parser(account, metadata)
-        }
-        discriminators::POOL_STATE => {
-            crate::streaming::event_parser::protocols::raydium_cpmm::types::pool_state_parser(account, metadata)
+        discriminators::AMM_CONFIG => types::amm_config_parser(account, metadata),
+        discriminators::POOL_STATE => types::pool_state_parser(account, metadata),
+        discriminators::OBSERVATION_STATE_ACCOUNT => {
+            types::observation_state_parser(account, metadata)
         }
         _ => None,
     }
@@ -98,6 +126,7 @@ fn parse_withdraw_instruction(
         vault_1_mint: accounts[11],
         lp_mint: accounts[12],
         memo_program: accounts[13],
+        ..Default::default()
     }))
 }
 
@@ -169,6 +198,7 @@ fn parse_deposit_instruction(
         vault_0_mint: accounts[10],
         vault_1_mint: accounts[11],
         lp_mint: accounts[12],
+        ..Default::default()
     }))
 }
 
@@ -242,3 +272,254 @@ fn parse_swap_base_output_instruction(
         ..Default::default()
     }))
 }
+
+/// 解析领取创建者手续费指令事件
+fn parse_collect_creator_fee_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumCpmmCollectCreatorFee;
+
+    if accounts.len() < 14 {
+        return None;
+    }
+    Some(DexEvent::RaydiumCpmmCollectCreatorFeeEvent(RaydiumCpmmCollectCreatorFeeEvent {
+        metadata,
+        creator: accounts[0],
+        authority: accounts[1],
+        pool_state: accounts[2],
+        amm_config: accounts[3],
+        token_0_vault: accounts[4],
+        token_1_vault: accounts[5],
+        vault_0_mint: accounts[6],
+        vault_1_mint: accounts[7],
+        creator_token_0: accounts[8],
+        creator_token_1: accounts[9],
+        token_0_program: accounts[10],
+        token_1_program: accounts[11],
+        associated_token_program: accounts[12],
+        system_program: accounts[13],
+    }))
+}
+
+/// 解析领取协议手续费指令事件
+fn parse_collect_protocol_fee_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumCpmmCollectProtocolFee;
+
+    if data.len() < 16 || accounts.len() < 12 {
+        return None;
+    }
+    Some(DexEvent::RaydiumCpmmCollectProtocolFeeEvent(RaydiumCpmmCollectProtocolFeeEvent {
+        metadata,
+        amount_0_requested: read_u64_le(data, 0)?,
+        amount_1_requested: read_u64_le(data, 8)?,
+        owner: accounts[0],
+        authority: accounts[1],
+        pool_state: accounts[2],
+        amm_config: accounts[3],
+        token_0_vault: accounts[4],
+        token_1_vault: accounts[5],
+        vault_0_mint: accounts[6],
+        vault_1_mint: accounts[7],
+        recipient_token_0_account: accounts[8],
+        recipient_token_1_account: accounts[9],
+        token_program: accounts[10],
+        token_program2022: accounts[11],
+    }))
+}
+
+/// 解析领取基金手续费指令事件
+fn parse_collect_fund_fee_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::RaydiumCpmmCollectFundFee;
+
+    if data.len() < 16 || accounts.len() < 12 {
+        return None;
+    }
+    Some(DexEvent::RaydiumCpmmCollectFundFeeEvent(RaydiumCpmmCollectFundFeeEvent {
+        metadata,
+        amount_0_requested: read_u64_le(data, 0)?,
+        amount_1_requested: read_u64_le(data, 8)?,
+        owner: accounts[0],
+        authority: accounts[1],
+        pool_state: accounts[2],
+        amm_config: accounts[3],
+        token_0_vault: accounts[4],
+        token_1_vault: accounts[5],
+        vault_0_mint: accounts[6],
+        vault_1_mint: accounts[7],
+        recipient_token_0_account: accounts[8],
+        recipient_token_1_account: accounts[9],
+        token_program: accounts[10],
+        token_program2022: accounts[11],
+    }))
+}
+
+/// 解析 SwapEvent 事件
+fn parse_swap_inner_instruction(data: &[u8], mut metadata: EventMetadata) -> Option<DexEvent> {
+    let event = idl_types::SwapEvent::decode_body(data)?;
+    metadata.event_type = if event.base_input {
+        EventType::RaydiumCpmmSwapBaseInput
+    } else {
+        EventType::RaydiumCpmmSwapBaseOutput
+    };
+    Some(DexEvent::RaydiumCpmmSwapEvent(RaydiumCpmmSwapEvent {
+        metadata,
+        pool_state: event.pool_id,
+        input_token_mint: event.input_mint,
+        output_token_mint: event.output_mint,
+        input_vault_before: event.input_vault_before,
+        output_vault_before: event.output_vault_before,
+        input_amount: event.input_amount,
+        output_amount: event.output_amount,
+        input_transfer_fee: event.input_transfer_fee,
+        output_transfer_fee: event.output_transfer_fee,
+        base_input: event.base_input,
+        trade_fee: event.trade_fee,
+        creator_fee: event.creator_fee,
+        creator_fee_on_input: event.creator_fee_on_input,
+        ..Default::default()
+    }))
+}
+
+/// 解析 LpChangeEvent 事件
+///
+/// change_type 为 0 时对应 deposit，为 1 时对应 withdraw
+fn parse_lp_change_inner_instruction(data: &[u8], mut metadata: EventMetadata) -> Option<DexEvent> {
+    let event = idl_types::LpChangeEvent::decode_body(data)?;
+    match event.change_type {
+        0 => {
+            metadata.event_type = EventType::RaydiumCpmmDeposit;
+            Some(DexEvent::RaydiumCpmmDepositEvent(RaydiumCpmmDepositEvent {
+                metadata,
+                pool_state: event.pool_id,
+                lp_amount_before: event.lp_amount_before,
+                token_0_vault_before: event.token_0_vault_before,
+                token_1_vault_before: event.token_1_vault_before,
+                token_0_amount: event.token_0_amount,
+                token_1_amount: event.token_1_amount,
+                token_0_transfer_fee: event.token_0_transfer_fee,
+                token_1_transfer_fee: event.token_1_transfer_fee,
+                ..Default::default()
+            }))
+        }
+        1 => {
+            metadata.event_type = EventType::RaydiumCpmmWithdraw;
+            Some(DexEvent::RaydiumCpmmWithdrawEvent(RaydiumCpmmWithdrawEvent {
+                metadata,
+                pool_state: event.pool_id,
+                lp_amount_before: event.lp_amount_before,
+                token_0_vault_before: event.token_0_vault_before,
+                token_1_vault_before: event.token_1_vault_before,
+                token_0_amount: event.token_0_amount,
+                token_1_amount: event.token_1_amount,
+                token_0_transfer_fee: event.token_0_transfer_fee,
+                token_1_transfer_fee: event.token_1_transfer_fee,
+                ..Default::default()
+            }))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::{
+        core::transaction_input::fixtures::{
+            account_index, inner_instruction, inner_instructions_meta, parse, program_data_meta,
+            transaction, unique_pubkeys,
+        },
+        Protocol,
+    };
+    use solana_sdk::transaction::VersionedTransaction;
+
+    /// 构造 swap_base_input 交易
+    fn swap_base_input_tx(accounts: &[Pubkey]) -> VersionedTransaction {
+        let mut data = discriminators::SWAP_BASE_INPUT_IX.to_vec();
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&900u64.to_le_bytes());
+        transaction(RAYDIUM_CPMM_PROGRAM_ID, &data, accounts)
+    }
+
+    /// SwapEvent 数据（不含判别器）
+    fn swap_event_body(accounts: &[Pubkey]) -> Vec<u8> {
+        let mut body = accounts[3].to_bytes().to_vec();
+        for amount in [50_000u64, 80_000, 1_000, 1_550, 0, 0] {
+            body.extend_from_slice(&amount.to_le_bytes());
+        }
+        body.push(1);
+        body.extend_from_slice(accounts[10].as_ref());
+        body.extend_from_slice(accounts[11].as_ref());
+        body.extend_from_slice(&3u64.to_le_bytes());
+        body.extend_from_slice(&1u64.to_le_bytes());
+        body.push(1);
+        body
+    }
+
+    fn assert_merged_swap(events: &[DexEvent], accounts: &[Pubkey]) {
+        assert_eq!(events.len(), 1);
+        let DexEvent::RaydiumCpmmSwapEvent(event) = &events[0] else { panic!("expected swap") };
+        assert_eq!(event.metadata.event_type, EventType::RaydiumCpmmSwapBaseInput);
+        assert_eq!((event.amount_in, event.minimum_amount_out), (1_000, 900));
+        assert_eq!((event.input_vault_before, event.output_vault_before), (50_000, 80_000));
+        assert_eq!((event.output_amount, event.trade_fee, event.creator_fee), (1_550, 3, 1));
+        let swap_data = event.metadata.swap_data.as_ref().unwrap();
+        assert_eq!((swap_data.from_mint, swap_data.to_mint), (accounts[10], accounts[11]));
+        assert_eq!((swap_data.from_amount, swap_data.to_amount), (1_000, 1_550));
+    }
+
+    #[test]
+    fn test_swap_base_input_merges_swap_event_cpi_log() {
+        let accounts = unique_pubkeys(13);
+        let tx = swap_base_input_tx(&accounts);
+
+        let mut log = discriminators::SWAP_EVENT.to_vec();
+        log.extend_from_slice(&swap_event_body(&accounts));
+        let program_index = account_index(&tx, &RAYDIUM_CPMM_PROGRAM_ID);
+        let meta = inner_instructions_meta(vec![inner_instruction(program_index, log, vec![])]);
+
+        let events = parse(&[Protocol::RaydiumCpmm], &tx, &meta);
+        assert_merged_swap(&events, &accounts);
+    }
+
+    #[test]
+    fn test_swap_base_input_merges_swap_event_program_data_log() {
+        let accounts = unique_pubkeys(13);
+        let tx = swap_base_input_tx(&accounts);
+
+        // Anchor emit!：8 字节事件判别器 + 数据，base64 编码后输出到 Program data
+        let mut data = discriminators::SWAP_EVENT[8..].to_vec();
+        data.extend_from_slice(&swap_event_body(&accounts));
+        let meta = program_data_meta(RAYDIUM_CPMM_PROGRAM_ID, &[data]);
+
+        let events = parse(&[Protocol::RaydiumCpmm], &tx, &meta);
+        assert_merged_swap(&events, &accounts);
+    }
+
+    #[test]
+    fn test_lp_change_event_routes_by_change_type() {
+        let mut log = Pubkey::new_unique().to_bytes().to_vec();
+        for amount in [10u64, 20, 30, 4, 5, 0, 0] {
+            log.extend_from_slice(&amount.to_le_bytes());
+        }
+        log.push(1);
+        let event = parse_raydium_cpmm_inner_instruction_data(
+            discriminators::LP_CHANGE_EVENT,
+            &log,
+            EventMetadata::default(),
+        );
+        let Some(DexEvent::RaydiumCpmmWithdrawEvent(e)) = event else {
+            panic!("expected withdraw")
+        };
+        assert_eq!((e.lp_amount_before, e.token_0_amount, e.token_1_amount), (10, 4, 5));
+    }
+}
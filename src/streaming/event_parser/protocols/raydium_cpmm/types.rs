@@ -6,7 +6,8 @@ use crate::streaming::{
     event_parser::{
         common::{EventMetadata, EventType},
         protocols::raydium_cpmm::{
-            RaydiumCpmmAmmConfigAccountEvent, RaydiumCpmmPoolStateAccountEvent,
+            RaydiumCpmmAmmConfigAccountEvent, RaydiumCpmmObservationStateAccountEvent,
+            RaydiumCpmmPoolStateAccountEvent,
         },
         DexEvent,
     },
@@ -119,3 +120,67 @@ pub fn pool_state_parser(account: &AccountPretty, mut metadata: EventMetadata) -
         None
     }
 }
+
+/// 程序内为 `#[repr(C, packed)]` 的 zero-copy 结构，按字段顺序小端读取即可
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct Observation {
+    pub block_timestamp: u64,
+    /// token0 价格累计值，Q32.32
+    pub cumulative_token_0_price_x32: u128,
+    /// token1 价格累计值，Q32.32
+    pub cumulative_token_1_price_x32: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, BorshDeserialize)]
+pub struct ObservationState {
+    pub initialized: bool,
+    pub observation_index: u16,
+    pub pool_id: Pubkey,
+    #[serde(with = "serde_big_array::BigArray")]
+    pub observations: [Observation; 100],
+    pub padding: [u64; 4],
+}
+
+impl Default for ObservationState {
+    fn default() -> Self {
+        Self {
+            initialized: false,
+            observation_index: 0,
+            pool_id: Pubkey::default(),
+            observations: core::array::from_fn(|_| Observation::default()),
+            padding: [0; 4],
+        }
+    }
+}
+
+pub const OBSERVATION_STATE_SIZE: usize = 1 + 2 + 32 + (8 + 16 + 16) * 100 + 8 * 4;
+
+pub fn observation_state_decode(data: &[u8]) -> Option<ObservationState> {
+    if data.len() < OBSERVATION_STATE_SIZE {
+        return None;
+    }
+    borsh::from_slice::<ObservationState>(&data[..OBSERVATION_STATE_SIZE]).ok()
+}
+
+pub fn observation_state_parser(
+    account: &AccountPretty,
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AccountRaydiumCpmmObservationState;
+
+    if account.data.len() < OBSERVATION_STATE_SIZE + 8 {
+        return None;
+    }
+    let observation_state = observation_state_decode(&account.data[8..OBSERVATION_STATE_SIZE + 8])?;
+    Some(DexEvent::RaydiumCpmmObservationStateAccountEvent(
+        RaydiumCpmmObservationStateAccountEvent {
+            metadata,
+            pubkey: account.pubkey,
+            executable: account.executable,
+            lamports: account.lamports,
+            owner: account.owner,
+            rent_epoch: account.rent_epoch,
+            observation_state,
+        },
+    ))
+}
@@ -0,0 +1,123 @@
+use crate::streaming::event_parser::common::EventMetadata;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// 转账
+///
+/// Transfer、TransferChecked 与 Token-2022 TransferCheckedWithFee 共用，通过 event_type 区分
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplTokenTransferEvent {
+    pub metadata: EventMetadata,
+    pub amount: u64,
+    /// 仅 checked 指令携带
+    pub decimals: Option<u8>,
+    /// Token-2022 transfer fee，仅 TransferCheckedWithFee 携带
+    pub fee: u64,
+    pub source: Pubkey,
+    /// Transfer 指令不含 mint，由交易 meta 的 token balances 补全
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    /// owner 或 delegate
+    pub authority: Pubkey,
+    /// 来自交易 meta 的 token balances，无法获取时为默认值
+    pub source_owner: Pubkey,
+    pub destination_owner: Pubkey,
+}
+
+/// 铸币（MintTo / MintToChecked）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplTokenMintToEvent {
+    pub metadata: EventMetadata,
+    pub amount: u64,
+    pub decimals: Option<u8>,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub mint_authority: Pubkey,
+    /// 来自交易 meta 的 token balances
+    pub destination_owner: Pubkey,
+}
+
+/// 销毁（Burn / BurnChecked）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplTokenBurnEvent {
+    pub metadata: EventMetadata,
+    pub amount: u64,
+    pub decimals: Option<u8>,
+    pub account: Pubkey,
+    pub mint: Pubkey,
+    /// owner 或 delegate
+    pub authority: Pubkey,
+    /// 来自交易 meta 的 token balances
+    pub owner: Pubkey,
+}
+
+/// 关闭 token 账户
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplTokenCloseAccountEvent {
+    pub metadata: EventMetadata,
+    pub account: Pubkey,
+    /// 接收租金的账户
+    pub destination: Pubkey,
+    /// owner 或 close authority
+    pub authority: Pubkey,
+    /// 来自交易 meta 的 token balances
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+}
+
+/// 授权（Approve / ApproveChecked）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplTokenApproveEvent {
+    pub metadata: EventMetadata,
+    pub amount: u64,
+    pub decimals: Option<u8>,
+    pub source: Pubkey,
+    /// Approve 指令不含 mint，由交易 meta 的 token balances 补全
+    pub mint: Pubkey,
+    pub delegate: Pubkey,
+    pub owner: Pubkey,
+}
+
+/// 修改 mint 或 token 账户的权限
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplTokenSetAuthorityEvent {
+    pub metadata: EventMetadata,
+    /// 0: MintTokens, 1: FreezeAccount, 2: AccountOwner, 3: CloseAccount，
+    /// Token-2022 扩展权限见 `spl_token_2022::instruction::AuthorityType`
+    pub authority_type: u8,
+    /// None 表示撤销权限
+    pub new_authority: Option<Pubkey>,
+    /// mint 或 token 账户
+    pub account: Pubkey,
+    pub current_authority: Pubkey,
+}
+
+/// 初始化 token 账户（InitializeAccount / InitializeAccount2 / InitializeAccount3）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplTokenInitializeAccountEvent {
+    pub metadata: EventMetadata,
+    pub account: Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+}
+
+/// 指令鉴别器，Token 与 Token-2022 共用
+pub mod discriminators {
+    pub const INITIALIZE_ACCOUNT: &[u8] = &[1];
+    pub const TRANSFER: &[u8] = &[3];
+    pub const APPROVE: &[u8] = &[4];
+    pub const SET_AUTHORITY: &[u8] = &[6];
+    pub const MINT_TO: &[u8] = &[7];
+    pub const BURN: &[u8] = &[8];
+    pub const CLOSE_ACCOUNT: &[u8] = &[9];
+    pub const TRANSFER_CHECKED: &[u8] = &[12];
+    pub const APPROVE_CHECKED: &[u8] = &[13];
+    pub const MINT_TO_CHECKED: &[u8] = &[14];
+    pub const BURN_CHECKED: &[u8] = &[15];
+    pub const INITIALIZE_ACCOUNT2: &[u8] = &[16];
+    pub const INITIALIZE_ACCOUNT3: &[u8] = &[18];
+
+    // Token-2022 扩展指令，第二个字节为子指令
+    pub const TRANSFER_FEE_EXTENSION: &[u8] = &[26];
+    pub const TRANSFER_CHECKED_WITH_FEE: u8 = 1;
+}
@@ -0,0 +1,426 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::{
+    common::{utils::*, EventMetadata, EventType},
+    core::token_accounts::TokenAccounts,
+    protocols::spl_token::{
+        discriminators, SplTokenApproveEvent, SplTokenBurnEvent, SplTokenCloseAccountEvent,
+        SplTokenInitializeAccountEvent, SplTokenMintToEvent, SplTokenSetAuthorityEvent,
+        SplTokenTransferEvent,
+    },
+    DexEvent,
+};
+
+/// SPL Token Program ID
+pub const SPL_TOKEN_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+/// SPL Token-2022 Program ID
+pub const SPL_TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// 解析 SPL Token / Token-2022 instruction data
+///
+/// 判别器为 1 字节，根据判别器路由到具体的 instruction 解析函数
+pub fn parse_spl_token_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::TRANSFER => parse_transfer_instruction(data, accounts, metadata),
+        discriminators::TRANSFER_CHECKED => {
+            parse_transfer_checked_instruction(data, accounts, metadata)
+        }
+        discriminators::TRANSFER_FEE_EXTENSION => {
+            parse_transfer_fee_extension_instruction(data, accounts, metadata)
+        }
+        discriminators::MINT_TO => {
+            parse_mint_to_instruction(EventType::SplTokenMintTo, data, accounts, metadata)
+        }
+        discriminators::MINT_TO_CHECKED => {
+            parse_mint_to_instruction(EventType::SplTokenMintToChecked, data, accounts, metadata)
+        }
+        discriminators::BURN => {
+            parse_burn_instruction(EventType::SplTokenBurn, data, accounts, metadata)
+        }
+        discriminators::BURN_CHECKED => {
+            parse_burn_instruction(EventType::SplTokenBurnChecked, data, accounts, metadata)
+        }
+        discriminators::CLOSE_ACCOUNT => parse_close_account_instruction(accounts, metadata),
+        discriminators::APPROVE => parse_approve_instruction(data, accounts, metadata),
+        discriminators::APPROVE_CHECKED => {
+            parse_approve_checked_instruction(data, accounts, metadata)
+        }
+        discriminators::SET_AUTHORITY => parse_set_authority_instruction(data, accounts, metadata),
+        discriminators::INITIALIZE_ACCOUNT => {
+            parse_initialize_account_instruction(data, accounts, metadata)
+        }
+        discriminators::INITIALIZE_ACCOUNT2 => parse_initialize_account_with_owner_instruction(
+            EventType::SplTokenInitializeAccount2,
+            data,
+            accounts,
+            metadata,
+        ),
+        discriminators::INITIALIZE_ACCOUNT3 => parse_initialize_account_with_owner_instruction(
+            EventType::SplTokenInitializeAccount3,
+            data,
+            accounts,
+            metadata,
+        ),
+        _ => None,
+    }
+}
+
+/// 使用交易 meta 的 token balances 补全 SPL Token 事件的 mint 与 owner
+///
+/// 指令本身不携带的字段（如 Transfer 的 mint、各 token 账户的 owner）只能从 meta 获取，
+/// 无法获取时保持默认值
+pub fn fill_spl_token_accounts(event: &mut DexEvent, token_accounts: &TokenAccounts) {
+    match event {
+        DexEvent::SplTokenTransferEvent(e) => {
+            let source = token_accounts.get(&e.source);
+            let destination = token_accounts.get(&e.destination);
+            if e.mint == Pubkey::default() {
+                if let Some(info) = source.or(destination) {
+                    e.mint = info.mint;
+                }
+            }
+            if let Some(info) = source {
+                e.source_owner = info.owner;
+            }
+            if let Some(info) = destination {
+                e.destination_owner = info.owner;
+            }
+        }
+        DexEvent::SplTokenMintToEvent(e) => {
+            if let Some(info) = token_accounts.get(&e.destination) {
+                e.destination_owner = info.owner;
+            }
+        }
+        DexEvent::SplTokenBurnEvent(e) => {
+            if let Some(info) = token_accounts.get(&e.account) {
+                e.owner = info.owner;
+            }
+        }
+        DexEvent::SplTokenCloseAccountEvent(e) => {
+            if let Some(info) = token_accounts.get(&e.account) {
+                e.mint = info.mint;
+                e.owner = info.owner;
+            }
+        }
+        DexEvent::SplTokenApproveEvent(e) if e.mint == Pubkey::default() => {
+            if let Some(info) = token_accounts.get(&e.source) {
+                e.mint = info.mint;
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Parse transfer event
+fn parse_transfer_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::SplTokenTransfer;
+
+    if accounts.len() < 3 {
+        return None;
+    }
+
+    Some(DexEvent::SplTokenTransferEvent(SplTokenTransferEvent {
+        metadata,
+        amount: read_u64_le(data, 0)?,
+        source: accounts[0],
+        destination: accounts[1],
+        authority: accounts[2],
+        ..Default::default()
+    }))
+}
+
+/// Parse transfer checked event
+fn parse_transfer_checked_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::SplTokenTransferChecked;
+
+    if accounts.len() < 4 {
+        return None;
+    }
+
+    Some(DexEvent::SplTokenTransferEvent(SplTokenTransferEvent {
+        metadata,
+        amount: read_u64_le(data, 0)?,
+        decimals: Some(read_u8(data, 8)?),
+        source: accounts[0],
+        mint: accounts[1],
+        destination: accounts[2],
+        authority: accounts[3],
+        ..Default::default()
+    }))
+}
+
+/// Parse Token-2022 transfer fee extension event
+///
+/// 只处理 TransferCheckedWithFee，其余 transfer fee 子指令（提取 withheld 等）不发出事件
+fn parse_transfer_fee_extension_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    if read_u8(data, 0)? != discriminators::TRANSFER_CHECKED_WITH_FEE || accounts.len() < 4 {
+        return None;
+    }
+    metadata.event_type = EventType::SplTokenTransferCheckedWithFee;
+
+    Some(DexEvent::SplTokenTransferEvent(SplTokenTransferEvent {
+        metadata,
+        amount: read_u64_le(data, 1)?,
+        decimals: Some(read_u8(data, 9)?),
+        fee: read_u64_le(data, 10)?,
+        source: accounts[0],
+        mint: accounts[1],
+        destination: accounts[2],
+        authority: accounts[3],
+        ..Default::default()
+    }))
+}
+
+/// Parse mint to / mint to checked event
+fn parse_mint_to_instruction(
+    event_type: EventType,
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    let decimals = match event_type {
+        EventType::SplTokenMintToChecked => Some(read_u8(data, 8)?),
+        _ => None,
+    };
+    metadata.event_type = event_type;
+
+    if accounts.len() < 3 {
+        return None;
+    }
+
+    Some(DexEvent::SplTokenMintToEvent(SplTokenMintToEvent {
+        metadata,
+        amount: read_u64_le(data, 0)?,
+        decimals,
+        mint: accounts[0],
+        destination: accounts[1],
+        mint_authority: accounts[2],
+        ..Default::default()
+    }))
+}
+
+/// Parse burn / burn checked event
+fn parse_burn_instruction(
+    event_type: EventType,
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    let decimals = match event_type {
+        EventType::SplTokenBurnChecked => Some(read_u8(data, 8)?),
+        _ => None,
+    };
+    metadata.event_type = event_type;
+
+    if accounts.len() < 3 {
+        return None;
+    }
+
+    Some(DexEvent::SplTokenBurnEvent(SplTokenBurnEvent {
+        metadata,
+        amount: read_u64_le(data, 0)?,
+        decimals,
+        account: accounts[0],
+        mint: accounts[1],
+        authority: accounts[2],
+        ..Default::default()
+    }))
+}
+
+/// Parse close account event
+fn parse_close_account_instruction(
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::SplTokenCloseAccount;
+
+    if accounts.len() < 3 {
+        return None;
+    }
+
+    Some(DexEvent::SplTokenCloseAccountEvent(SplTokenCloseAccountEvent {
+        metadata,
+        account: accounts[0],
+        destination: accounts[1],
+        authority: accounts[2],
+        ..Default::default()
+    }))
+}
+
+/// Parse approve event
+fn parse_approve_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::SplTokenApprove;
+
+    if accounts.len() < 3 {
+        return None;
+    }
+
+    Some(DexEvent::SplTokenApproveEvent(SplTokenApproveEvent {
+        metadata,
+        amount: read_u64_le(data, 0)?,
+        source: accounts[0],
+        delegate: accounts[1],
+        owner: accounts[2],
+        ..Default::default()
+    }))
+}
+
+/// Parse approve checked event
+fn parse_approve_checked_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::SplTokenApproveChecked;
+
+    if accounts.len() < 4 {
+        return None;
+    }
+
+    Some(DexEvent::SplTokenApproveEvent(SplTokenApproveEvent {
+        metadata,
+        amount: read_u64_le(data, 0)?,
+        decimals: Some(read_u8(data, 8)?),
+        source: accounts[0],
+        mint: accounts[1],
+        delegate: accounts[2],
+        owner: accounts[3],
+    }))
+}
+
+/// Parse set authority event
+fn parse_set_authority_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::SplTokenSetAuthority;
+
+    if accounts.len() < 2 {
+        return None;
+    }
+
+    let authority_type = read_u8(data, 0)?;
+    // COption<Pubkey>: 1 字节标记 + 32 字节公钥
+    let new_authority = match read_u8(data, 1)? {
+        0 => None,
+        1 => Some(Pubkey::try_from(data.get(2..34)?).ok()?),
+        _ => return None,
+    };
+
+    Some(DexEvent::SplTokenSetAuthorityEvent(SplTokenSetAuthorityEvent {
+        metadata,
+        authority_type,
+        new_authority,
+        account: accounts[0],
+        current_authority: accounts[1],
+    }))
+}
+
+/// Parse initialize account event
+fn parse_initialize_account_instruction(
+    _data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::SplTokenInitializeAccount;
+
+    if accounts.len() < 3 {
+        return None;
+    }
+
+    Some(DexEvent::SplTokenInitializeAccountEvent(SplTokenInitializeAccountEvent {
+        metadata,
+        account: accounts[0],
+        mint: accounts[1],
+        owner: accounts[2],
+    }))
+}
+
+/// Parse initialize account 2 / 3 event，owner 位于指令数据中
+fn parse_initialize_account_with_owner_instruction(
+    event_type: EventType,
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = event_type;
+
+    if accounts.len() < 2 {
+        return None;
+    }
+
+    Some(DexEvent::SplTokenInitializeAccountEvent(SplTokenInitializeAccountEvent {
+        metadata,
+        account: accounts[0],
+        mint: accounts[1],
+        owner: Pubkey::try_from(data.get(..32)?).ok()?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_fee_and_token_balance_resolution() {
+        let accounts: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+
+        let mut data = vec![discriminators::TRANSFER_CHECKED_WITH_FEE];
+        data.extend_from_slice(&1_000_000u64.to_le_bytes());
+        data.push(6);
+        data.extend_from_slice(&2_500u64.to_le_bytes());
+        let event = parse_spl_token_instruction_data(
+            discriminators::TRANSFER_FEE_EXTENSION,
+            &data,
+            &accounts,
+            EventMetadata::default(),
+        )
+        .unwrap();
+        let DexEvent::SplTokenTransferEvent(event) = event else { panic!("expected transfer") };
+        assert_eq!(event.metadata.event_type, EventType::SplTokenTransferCheckedWithFee);
+        assert_eq!((event.amount, event.decimals, event.fee), (1_000_000, Some(6), 2_500));
+        assert_eq!((event.mint, event.destination), (accounts[1], accounts[2]));
+
+        // Transfer 不含 mint，由 token balances 补全；owner 未知的账户保持默认值
+        let (mint, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (mint_str, owner_str) = (mint.to_string(), owner.to_string());
+        let token_accounts =
+            TokenAccounts::new(&accounts, [(0, mint_str.as_str(), owner_str.as_str())]);
+        let mut event = parse_spl_token_instruction_data(
+            discriminators::TRANSFER,
+            &42u64.to_le_bytes(),
+            &accounts[..3],
+            EventMetadata::default(),
+        )
+        .unwrap();
+        fill_spl_token_accounts(&mut event, &token_accounts);
+        let DexEvent::SplTokenTransferEvent(event) = event else { panic!("expected transfer") };
+        assert_eq!(event.metadata.event_type, EventType::SplTokenTransfer);
+        assert_eq!((event.amount, event.mint, event.source_owner), (42, mint, owner));
+        assert_eq!(event.destination_owner, Pubkey::default());
+    }
+}
@@ -0,0 +1,106 @@
+use crate::streaming::event_parser::common::EventMetadata;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// SOL 转账（Transfer / TransferWithSeed）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolTransferEvent {
+    pub metadata: EventMetadata,
+    pub lamports: u64,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    /// TransferWithSeed：from 由 base + seed + from_owner 派生
+    pub base: Option<Pubkey>,
+    pub seed: Option<String>,
+    pub from_owner: Option<Pubkey>,
+}
+
+/// 创建账户（CreateAccount / CreateAccountWithSeed）
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateAccountEvent {
+    pub metadata: EventMetadata,
+    pub lamports: u64,
+    pub space: u64,
+    /// 新账户所属程序
+    pub owner: Pubkey,
+    pub funding_account: Pubkey,
+    pub new_account: Pubkey,
+    /// CreateAccountWithSeed：new_account 由 base + seed + owner 派生
+    pub base: Option<Pubkey>,
+    pub seed: Option<String>,
+}
+
+/// 推进 durable nonce
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NonceAdvanceEvent {
+    pub metadata: EventMetadata,
+    pub nonce_account: Pubkey,
+    pub nonce_authority: Pubkey,
+}
+
+/// 修改 durable nonce 的 authority
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NonceAuthorizeEvent {
+    pub metadata: EventMetadata,
+    pub nonce_account: Pubkey,
+    pub nonce_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+/// 创建地址查找表
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressLookupTableCreateEvent {
+    pub metadata: EventMetadata,
+    pub recent_slot: u64,
+    pub bump_seed: u8,
+    pub lookup_table: Pubkey,
+    pub authority: Pubkey,
+    pub payer: Pubkey,
+}
+
+/// 向地址查找表追加地址
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressLookupTableExtendEvent {
+    pub metadata: EventMetadata,
+    pub new_addresses: Vec<Pubkey>,
+    pub lookup_table: Pubkey,
+    pub authority: Pubkey,
+    /// 表需要扩容时才传入
+    pub payer: Option<Pubkey>,
+}
+
+/// 冻结 / 停用地址查找表，通过 event_type 区分
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressLookupTableDeactivateEvent {
+    pub metadata: EventMetadata,
+    pub lookup_table: Pubkey,
+    pub authority: Pubkey,
+}
+
+/// 关闭地址查找表
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressLookupTableCloseEvent {
+    pub metadata: EventMetadata,
+    pub lookup_table: Pubkey,
+    pub authority: Pubkey,
+    /// 接收租金的账户
+    pub recipient: Pubkey,
+}
+
+/// 指令鉴别器，System Program 与 Address Lookup Table Program 均为 4 字节 u32 枚举下标
+pub mod discriminators {
+    // System Program
+    pub const CREATE_ACCOUNT: &[u8] = &[0, 0, 0, 0];
+    pub const TRANSFER: &[u8] = &[2, 0, 0, 0];
+    pub const CREATE_ACCOUNT_WITH_SEED: &[u8] = &[3, 0, 0, 0];
+    pub const ADVANCE_NONCE_ACCOUNT: &[u8] = &[4, 0, 0, 0];
+    pub const AUTHORIZE_NONCE_ACCOUNT: &[u8] = &[7, 0, 0, 0];
+    pub const TRANSFER_WITH_SEED: &[u8] = &[11, 0, 0, 0];
+
+    // Address Lookup Table Program
+    pub const CREATE_LOOKUP_TABLE: &[u8] = &[0, 0, 0, 0];
+    pub const FREEZE_LOOKUP_TABLE: &[u8] = &[1, 0, 0, 0];
+    pub const EXTEND_LOOKUP_TABLE: &[u8] = &[2, 0, 0, 0];
+    pub const DEACTIVATE_LOOKUP_TABLE: &[u8] = &[3, 0, 0, 0];
+    pub const CLOSE_LOOKUP_TABLE: &[u8] = &[4, 0, 0, 0];
+}
@@ -0,0 +1,4 @@
+pub mod events;
+pub mod parser;
+
+pub use events::*;
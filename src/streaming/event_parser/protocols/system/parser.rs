@@ -0,0 +1,362 @@
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::{
+    common::{utils::*, EventMetadata, EventType},
+    protocols::system::{
+        discriminators, AddressLookupTableCloseEvent, AddressLookupTableCreateEvent,
+        AddressLookupTableDeactivateEvent, AddressLookupTableExtendEvent, CreateAccountEvent,
+        NonceAdvanceEvent, NonceAuthorizeEvent, SolTransferEvent,
+    },
+    DexEvent,
+};
+
+/// System Program ID
+pub const SYSTEM_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("11111111111111111111111111111111");
+/// Address Lookup Table Program ID
+pub const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("AddressLookupTab1e1111111111111111111111111");
+
+/// 解析 System Program instruction data
+///
+/// 判别器为 4 字节 u32，根据判别器路由到具体的 instruction 解析函数
+pub fn parse_system_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::CREATE_ACCOUNT => {
+            parse_create_account_instruction(data, accounts, metadata)
+        }
+        discriminators::TRANSFER => parse_transfer_instruction(data, accounts, metadata),
+        discriminators::CREATE_ACCOUNT_WITH_SEED => {
+            parse_create_account_with_seed_instruction(data, accounts, metadata)
+        }
+        discriminators::ADVANCE_NONCE_ACCOUNT => {
+            parse_advance_nonce_instruction(accounts, metadata)
+        }
+        discriminators::AUTHORIZE_NONCE_ACCOUNT => {
+            parse_authorize_nonce_instruction(data, accounts, metadata)
+        }
+        discriminators::TRANSFER_WITH_SEED => {
+            parse_transfer_with_seed_instruction(data, accounts, metadata)
+        }
+        _ => None,
+    }
+}
+
+/// 解析 Address Lookup Table Program instruction data
+pub fn parse_address_lookup_table_instruction_data(
+    discriminator: &[u8],
+    data: &[u8],
+    accounts: &[Pubkey],
+    metadata: EventMetadata,
+) -> Option<DexEvent> {
+    match discriminator {
+        discriminators::CREATE_LOOKUP_TABLE => {
+            parse_create_lookup_table_instruction(data, accounts, metadata)
+        }
+        discriminators::FREEZE_LOOKUP_TABLE => parse_deactivate_lookup_table_instruction(
+            EventType::AddressLookupTableFreeze,
+            accounts,
+            metadata,
+        ),
+        discriminators::EXTEND_LOOKUP_TABLE => {
+            parse_extend_lookup_table_instruction(data, accounts, metadata)
+        }
+        discriminators::DEACTIVATE_LOOKUP_TABLE => parse_deactivate_lookup_table_instruction(
+            EventType::AddressLookupTableDeactivate,
+            accounts,
+            metadata,
+        ),
+        discriminators::CLOSE_LOOKUP_TABLE => {
+            parse_close_lookup_table_instruction(accounts, metadata)
+        }
+        _ => None,
+    }
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Option<Pubkey> {
+    Pubkey::try_from(data.get(offset..offset + 32)?).ok()
+}
+
+/// bincode 字符串：u64 长度 + UTF-8 字节，返回字符串与结束偏移
+fn read_string(data: &[u8], offset: usize) -> Option<(String, usize)> {
+    let len = usize::try_from(read_u64_le(data, offset)?).ok()?;
+    let start = offset + 8;
+    let bytes = data.get(start..start.checked_add(len)?)?;
+    Some((String::from_utf8(bytes.to_vec()).ok()?, start + len))
+}
+
+/// Parse transfer event
+fn parse_transfer_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::SystemTransfer;
+
+    if accounts.len() < 2 {
+        return None;
+    }
+
+    Some(DexEvent::SolTransferEvent(SolTransferEvent {
+        metadata,
+        lamports: read_u64_le(data, 0)?,
+        from: accounts[0],
+        to: accounts[1],
+        ..Default::default()
+    }))
+}
+
+/// Parse transfer with seed event
+fn parse_transfer_with_seed_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::SystemTransferWithSeed;
+
+    if accounts.len() < 3 {
+        return None;
+    }
+
+    let lamports = read_u64_le(data, 0)?;
+    let (seed, offset) = read_string(data, 8)?;
+    Some(DexEvent::SolTransferEvent(SolTransferEvent {
+        metadata,
+        lamports,
+        from: accounts[0],
+        to: accounts[2],
+        base: Some(accounts[1]),
+        seed: Some(seed),
+        from_owner: Some(read_pubkey(data, offset)?),
+    }))
+}
+
+/// Parse create account event
+fn parse_create_account_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::SystemCreateAccount;
+
+    if accounts.len() < 2 {
+        return None;
+    }
+
+    Some(DexEvent::CreateAccountEvent(CreateAccountEvent {
+        metadata,
+        lamports: read_u64_le(data, 0)?,
+        space: read_u64_le(data, 8)?,
+        owner: read_pubkey(data, 16)?,
+        funding_account: accounts[0],
+        new_account: accounts[1],
+        ..Default::default()
+    }))
+}
+
+/// Parse create account with seed event
+fn parse_create_account_with_seed_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::SystemCreateAccountWithSeed;
+
+    if accounts.len() < 2 {
+        return None;
+    }
+
+    let base = read_pubkey(data, 0)?;
+    let (seed, offset) = read_string(data, 32)?;
+    Some(DexEvent::CreateAccountEvent(CreateAccountEvent {
+        metadata,
+        lamports: read_u64_le(data, offset)?,
+        space: read_u64_le(data, offset + 8)?,
+        owner: read_pubkey(data, offset + 16)?,
+        funding_account: accounts[0],
+        new_account: accounts[1],
+        base: Some(base),
+        seed: Some(seed),
+    }))
+}
+
+/// Parse advance nonce account event
+fn parse_advance_nonce_instruction(
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::SystemAdvanceNonce;
+
+    if accounts.len() < 3 {
+        return None;
+    }
+
+    Some(DexEvent::NonceAdvanceEvent(NonceAdvanceEvent {
+        metadata,
+        nonce_account: accounts[0],
+        nonce_authority: accounts[2],
+    }))
+}
+
+/// Parse authorize nonce account event
+fn parse_authorize_nonce_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::SystemAuthorizeNonce;
+
+    if accounts.len() < 2 {
+        return None;
+    }
+
+    Some(DexEvent::NonceAuthorizeEvent(NonceAuthorizeEvent {
+        metadata,
+        nonce_account: accounts[0],
+        nonce_authority: accounts[1],
+        new_authority: read_pubkey(data, 0)?,
+    }))
+}
+
+/// Parse create lookup table event
+fn parse_create_lookup_table_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AddressLookupTableCreate;
+
+    if accounts.len() < 3 {
+        return None;
+    }
+
+    Some(DexEvent::AddressLookupTableCreateEvent(AddressLookupTableCreateEvent {
+        metadata,
+        recent_slot: read_u64_le(data, 0)?,
+        bump_seed: read_u8(data, 8)?,
+        lookup_table: accounts[0],
+        authority: accounts[1],
+        payer: accounts[2],
+    }))
+}
+
+/// Parse extend lookup table event
+fn parse_extend_lookup_table_instruction(
+    data: &[u8],
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AddressLookupTableExtend;
+
+    if accounts.len() < 2 {
+        return None;
+    }
+
+    let count = usize::try_from(read_u64_le(data, 0)?).ok()?;
+    let body = data.get(8..8usize.checked_add(count.checked_mul(32)?)?)?;
+    let new_addresses =
+        body.chunks_exact(32).map(Pubkey::try_from).collect::<Result<Vec<_>, _>>().ok()?;
+
+    Some(DexEvent::AddressLookupTableExtendEvent(AddressLookupTableExtendEvent {
+        metadata,
+        new_addresses,
+        lookup_table: accounts[0],
+        authority: accounts[1],
+        payer: accounts.get(2).copied(),
+    }))
+}
+
+/// Parse freeze / deactivate lookup table event
+fn parse_deactivate_lookup_table_instruction(
+    event_type: EventType,
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = event_type;
+
+    if accounts.len() < 2 {
+        return None;
+    }
+
+    Some(DexEvent::AddressLookupTableDeactivateEvent(AddressLookupTableDeactivateEvent {
+        metadata,
+        lookup_table: accounts[0],
+        authority: accounts[1],
+    }))
+}
+
+/// Parse close lookup table event
+fn parse_close_lookup_table_instruction(
+    accounts: &[Pubkey],
+    mut metadata: EventMetadata,
+) -> Option<DexEvent> {
+    metadata.event_type = EventType::AddressLookupTableClose;
+
+    if accounts.len() < 3 {
+        return None;
+    }
+
+    Some(DexEvent::AddressLookupTableCloseEvent(AddressLookupTableCloseEvent {
+        metadata,
+        lookup_table: accounts[0],
+        authority: accounts[1],
+        recipient: accounts[2],
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_with_seed_and_extend_lookup_table() {
+        let accounts: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let from_owner = Pubkey::new_unique();
+
+        let mut data = 5_000u64.to_le_bytes().to_vec();
+        data.extend_from_slice(&4u64.to_le_bytes());
+        data.extend_from_slice(b"seed");
+        data.extend_from_slice(from_owner.as_ref());
+        let event = parse_system_instruction_data(
+            discriminators::TRANSFER_WITH_SEED,
+            &data,
+            &accounts[..3],
+            EventMetadata::default(),
+        )
+        .unwrap();
+        let DexEvent::SolTransferEvent(event) = event else { panic!("expected transfer") };
+        assert_eq!(event.metadata.event_type, EventType::SystemTransferWithSeed);
+        assert_eq!((event.lamports, event.from, event.to), (5_000, accounts[0], accounts[2]));
+        assert_eq!((event.base, event.seed.as_deref()), (Some(accounts[1]), Some("seed")));
+        assert_eq!(event.from_owner, Some(from_owner));
+
+        let mut data = 2u64.to_le_bytes().to_vec();
+        data.extend_from_slice(accounts[2].as_ref());
+        data.extend_from_slice(accounts[3].as_ref());
+        let event = parse_address_lookup_table_instruction_data(
+            discriminators::EXTEND_LOOKUP_TABLE,
+            &data,
+            &accounts[..2],
+            EventMetadata::default(),
+        )
+        .unwrap();
+        let DexEvent::AddressLookupTableExtendEvent(event) = event else {
+            panic!("expected extend")
+        };
+        assert_eq!(event.new_addresses, accounts[2..].to_vec());
+        assert_eq!((event.lookup_table, event.payer), (accounts[0], None));
+
+        // 地址数量与数据长度不符时放弃解析
+        assert!(parse_address_lookup_table_instruction_data(
+            discriminators::EXTEND_LOOKUP_TABLE,
+            &data[..40],
+            &accounts[..2],
+            EventMetadata::default(),
+        )
+        .is_none());
+    }
+}
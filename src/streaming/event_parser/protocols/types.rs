@@ -1,9 +1,17 @@
 use crate::streaming::event_parser::protocols::{
-    bonk::parser::BONK_PROGRAM_ID, meteora_damm_v2::parser::METEORA_DAMM_V2_PROGRAM_ID,
+    bonk::parser::BONK_PROGRAM_ID, jupiter::parser::JUPITER_PROGRAM_ID,
+    meteora_damm_v1::parser::METEORA_DAMM_V1_PROGRAM_ID,
+    meteora_damm_v2::parser::METEORA_DAMM_V2_PROGRAM_ID,
+    meteora_dlmm::parser::METEORA_DLMM_PROGRAM_ID,
+    orca_whirlpool::parser::ORCA_WHIRLPOOL_PROGRAM_ID,
     pumpfun::parser::PUMPFUN_PROGRAM_ID, pumpswap::parser::PUMPSWAP_PROGRAM_ID,
     raydium_amm_v4::parser::RAYDIUM_AMM_V4_PROGRAM_ID, raydium_clmm::parser::RAYDIUM_CLMM_PROGRAM_ID,
     raydium_cpmm::parser::RAYDIUM_CPMM_PROGRAM_ID,
+    spl_token::parser::{SPL_TOKEN_2022_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID},
+    system::parser::{ADDRESS_LOOKUP_TABLE_PROGRAM_ID, SYSTEM_PROGRAM_ID},
 };
+use crate::streaming::event_parser::common::ProtocolType;
+use crate::streaming::event_parser::core::custom_parser;
 use anyhow::{anyhow, Result};
 use solana_sdk::pubkey::Pubkey;
 
@@ -17,6 +25,17 @@ pub enum Protocol {
     RaydiumClmm,
     RaydiumAmmV4,
     MeteoraDammV2,
+    MeteoraDammV1,
+    MeteoraDlmm,
+    OrcaWhirlpool,
+    /// SPL Token 与 Token-2022 指令（转账、铸币、销毁、授权、关闭账户等）
+    SplToken,
+    /// System Program（SOL 转账、创建账户、nonce）与 Address Lookup Table 指令
+    System,
+    /// Jupiter Aggregator v6 路由，合并各跳 SwapEvent
+    Jupiter,
+    /// 通过 `EventDispatcher::register_protocol_parser` / `register_idl` 注册的第三方协议
+    Custom(String),
 }
 
 impl Protocol {
@@ -29,6 +48,47 @@ impl Protocol {
             Protocol::RaydiumClmm => vec![RAYDIUM_CLMM_PROGRAM_ID],
             Protocol::RaydiumAmmV4 => vec![RAYDIUM_AMM_V4_PROGRAM_ID],
             Protocol::MeteoraDammV2 => vec![METEORA_DAMM_V2_PROGRAM_ID],
+            Protocol::MeteoraDammV1 => vec![METEORA_DAMM_V1_PROGRAM_ID],
+            Protocol::MeteoraDlmm => vec![METEORA_DLMM_PROGRAM_ID],
+            Protocol::OrcaWhirlpool => vec![ORCA_WHIRLPOOL_PROGRAM_ID],
+            Protocol::SplToken => vec![SPL_TOKEN_PROGRAM_ID, SPL_TOKEN_2022_PROGRAM_ID],
+            Protocol::System => vec![SYSTEM_PROGRAM_ID, ADDRESS_LOOKUP_TABLE_PROGRAM_ID],
+            Protocol::Jupiter => vec![JUPITER_PROGRAM_ID],
+            Protocol::Custom(name) => {
+                custom_parser::find_by_name(name).map(|p| p.program_ids()).unwrap_or_default()
+            }
+        }
+    }
+
+    /// 只解析指令本身的协议，没有 CPI 事件、程序日志与 swap_data
+    pub fn is_instruction_only(&self) -> bool {
+        matches!(self, Protocol::SplToken | Protocol::System)
+    }
+
+    /// 一条指令对应多个 CPI 事件的协议，需要全部合并（如 Jupiter 每一跳一个 SwapEvent，
+    /// Raydium CLMM 减少流动性时同时发出 CollectPersonalFeeEvent 与 DecreaseLiquidityEvent）
+    pub fn has_multiple_cpi_events(&self) -> bool {
+        matches!(self, Protocol::Jupiter | Protocol::RaydiumClmm)
+    }
+}
+
+impl From<&Protocol> for ProtocolType {
+    fn from(protocol: &Protocol) -> Self {
+        match protocol {
+            Protocol::PumpSwap => ProtocolType::PumpSwap,
+            Protocol::PumpFun => ProtocolType::PumpFun,
+            Protocol::Bonk => ProtocolType::Bonk,
+            Protocol::RaydiumCpmm => ProtocolType::RaydiumCpmm,
+            Protocol::RaydiumClmm => ProtocolType::RaydiumClmm,
+            Protocol::RaydiumAmmV4 => ProtocolType::RaydiumAmmV4,
+            Protocol::MeteoraDammV2 => ProtocolType::MeteoraDammV2,
+            Protocol::MeteoraDammV1 => ProtocolType::MeteoraDammV1,
+            Protocol::MeteoraDlmm => ProtocolType::MeteoraDlmm,
+            Protocol::OrcaWhirlpool => ProtocolType::OrcaWhirlpool,
+            Protocol::SplToken => ProtocolType::SplToken,
+            Protocol::System => ProtocolType::System,
+            Protocol::Jupiter => ProtocolType::Jupiter,
+            Protocol::Custom(_) => ProtocolType::Custom,
         }
     }
 }
@@ -43,6 +103,13 @@ impl std::fmt::Display for Protocol {
             Protocol::RaydiumClmm => write!(f, "RaydiumClmm"),
             Protocol::RaydiumAmmV4 => write!(f, "RaydiumAmmV4"),
             Protocol::MeteoraDammV2 => write!(f, "MeteoraDammV2"),
+            Protocol::MeteoraDammV1 => write!(f, "MeteoraDammV1"),
+            Protocol::MeteoraDlmm => write!(f, "MeteoraDlmm"),
+            Protocol::OrcaWhirlpool => write!(f, "OrcaWhirlpool"),
+            Protocol::SplToken => write!(f, "SplToken"),
+            Protocol::System => write!(f, "System"),
+            Protocol::Jupiter => write!(f, "Jupiter"),
+            Protocol::Custom(name) => write!(f, "{}", name),
         }
     }
 }
@@ -59,6 +126,13 @@ impl std::str::FromStr for Protocol {
             "raydiumclmm" => Ok(Protocol::RaydiumClmm),
             "raydiumammv4" => Ok(Protocol::RaydiumAmmV4),
             "meteoradamm_v2" => Ok(Protocol::MeteoraDammV2),
+            "meteoradamm_v1" => Ok(Protocol::MeteoraDammV1),
+            "meteoradlmm" => Ok(Protocol::MeteoraDlmm),
+            "orcawhirlpool" => Ok(Protocol::OrcaWhirlpool),
+            "spltoken" => Ok(Protocol::SplToken),
+            "system" => Ok(Protocol::System),
+            "jupiter" => Ok(Protocol::Jupiter),
+            _ if custom_parser::find_by_name(s).is_some() => Ok(Protocol::Custom(s.to_string())),
             _ => Err(anyhow!("Unsupported protocol: {}", s)),
         }
     }
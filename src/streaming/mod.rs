@@ -1,11 +1,17 @@
+pub mod backfill;
 pub mod common;
 pub mod event_parser;
 pub mod grpc;
+pub mod pipeline;
+pub mod record;
 pub mod shred;
 pub mod shred_stream;
 pub mod yellowstone_grpc;
 pub mod yellowstone_sub_system;
+pub mod ws_server;
 
+pub use backfill::{BackfillConfig, BackfillRange, Backfiller};
+pub use pipeline::{EventSource, Pipeline, PipelineBuilder};
 pub use shred::ShredStreamGrpc;
 pub use yellowstone_grpc::YellowstoneGrpc;
-pub use yellowstone_sub_system::{SystemEvent, TransferInfo};
+pub use ws_server::{WsBroadcastServer, WsServerConfig};
@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use log::{error, info};
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+
+use crate::common::AnyResult;
+use crate::streaming::common::{process_grpc_transaction, process_shred_transaction};
+use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::event_parser::{DexEvent, Protocol};
+use crate::streaming::pipeline::source::{EventSource, RawUpdate};
+use crate::streaming::pipeline::stage::{DedupeStage, EnrichStage, FilterStage, MapStage, Stage};
+
+type Sink = Arc<dyn Fn(DexEvent) + Send + Sync>;
+
+/// 默认的原始更新队列长度
+pub const DEFAULT_PIPELINE_BUFFER_SIZE: usize = 10_000;
+
+/// 流水线构建器
+///
+/// ```ignore
+/// let pipeline = PipelineBuilder::new(vec![Protocol::PumpSwap])
+///     .source(YellowstoneGrpcSource::new(grpc, vec![tx_filter], vec![], None, None))
+///     .source(shred)
+///     .dedupe(100_000)
+///     .filter(|e| e.metadata().event_type == EventType::PumpSwapBuy)
+///     .sink(|e| println!("{e:?}"))
+///     .build();
+/// pipeline.start().await?;
+/// ```
+pub struct PipelineBuilder {
+    protocols: Vec<Protocol>,
+    event_type_filter: Option<EventTypeFilter>,
+    bot_wallet: Option<Pubkey>,
+    buffer_size: usize,
+    sources: Vec<Arc<dyn EventSource>>,
+    stages: Vec<Arc<dyn Stage>>,
+    sinks: Vec<Sink>,
+}
+
+impl PipelineBuilder {
+    pub fn new(protocols: Vec<Protocol>) -> Self {
+        Self {
+            protocols,
+            event_type_filter: None,
+            bot_wallet: None,
+            buffer_size: DEFAULT_PIPELINE_BUFFER_SIZE,
+            sources: Vec::new(),
+            stages: Vec::new(),
+            sinks: Vec::new(),
+        }
+    }
+
+    /// 解析阶段使用的事件类型过滤器
+    pub fn event_type_filter(mut self, event_type_filter: EventTypeFilter) -> Self {
+        self.event_type_filter = Some(event_type_filter);
+        self
+    }
+
+    /// 用于标记 bot 交易的钱包地址
+    pub fn bot_wallet(mut self, bot_wallet: Pubkey) -> Self {
+        self.bot_wallet = Some(bot_wallet);
+        self
+    }
+
+    /// 数据源与解析任务之间的队列长度
+    pub fn buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size.max(1);
+        self
+    }
+
+    /// 添加数据源
+    pub fn source<S: EventSource + 'static>(mut self, source: S) -> Self {
+        self.sources.push(Arc::new(source));
+        self
+    }
+
+    /// 添加自定义处理阶段
+    pub fn stage<S: Stage + 'static>(mut self, stage: S) -> Self {
+        self.stages.push(Arc::new(stage));
+        self
+    }
+
+    /// 只保留满足条件的事件
+    pub fn filter<F>(self, f: F) -> Self
+    where
+        F: Fn(&DexEvent) -> bool + Send + Sync + 'static,
+    {
+        self.stage(FilterStage(f))
+    }
+
+    /// 原地补充事件字段
+    pub fn enrich<F>(self, f: F) -> Self
+    where
+        F: Fn(&mut DexEvent) + Send + Sync + 'static,
+    {
+        self.stage(EnrichStage(f))
+    }
+
+    /// 转换事件，返回 `None` 时丢弃
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: Fn(DexEvent) -> Option<DexEvent> + Send + Sync + 'static,
+    {
+        self.stage(MapStage(f))
+    }
+
+    /// 去重，最多记住 `capacity` 个事件键
+    pub fn dedupe(self, capacity: usize) -> Self {
+        self.stage(DedupeStage::new(capacity))
+    }
+
+    /// 添加事件输出，多个 sink 按添加顺序依次收到同一事件
+    pub fn sink<F>(mut self, f: F) -> Self
+    where
+        F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        self.sinks.push(Arc::new(f));
+        self
+    }
+
+    pub fn build(self) -> Pipeline {
+        Pipeline {
+            protocols: Arc::new(self.protocols),
+            event_type_filter: Arc::new(self.event_type_filter),
+            bot_wallet: self.bot_wallet,
+            buffer_size: self.buffer_size,
+            sources: self.sources,
+            stages: Arc::new(self.stages),
+            sinks: Arc::new(self.sinks),
+            handle: Mutex::new(None),
+        }
+    }
+}
+
+/// 由数据源、处理阶段和 sink 组成的事件流水线
+///
+/// 所有数据源写入同一个有界队列，由单个任务负责解析（含 metrics 统计），
+/// 解析出的事件依次经过各处理阶段后交给所有 sink。
+pub struct Pipeline {
+    protocols: Arc<Vec<Protocol>>,
+    event_type_filter: Arc<Option<EventTypeFilter>>,
+    bot_wallet: Option<Pubkey>,
+    buffer_size: usize,
+    sources: Vec<Arc<dyn EventSource>>,
+    stages: Arc<Vec<Arc<dyn Stage>>>,
+    sinks: Arc<Vec<Sink>>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl Pipeline {
+    /// 启动所有数据源和解析任务
+    pub async fn start(&self) -> AnyResult<()> {
+        let mut handle_guard = self.handle.lock().await;
+        if handle_guard.is_some() {
+            return Err(anyhow::anyhow!("Pipeline already started"));
+        }
+
+        let (sender, mut receiver) = mpsc::channel::<RawUpdate>(self.buffer_size);
+        for source in &self.sources {
+            if let Err(e) = source.start(sender.clone()).await {
+                for started in &self.sources {
+                    started.stop().await;
+                }
+                return Err(e.context(format!("failed to start source {}", source.name())));
+            }
+            info!("Pipeline source started: {}", source.name());
+        }
+        // 只保留数据源持有的发送端，全部结束后接收端自然关闭
+        drop(sender);
+
+        let callback = self.event_callback();
+        let protocols = self.protocols.clone();
+        let event_type_filter = self.event_type_filter.clone();
+        let bot_wallet = self.bot_wallet;
+        *handle_guard = Some(tokio::spawn(async move {
+            while let Some(update) = receiver.recv().await {
+                let result = match update {
+                    RawUpdate::Grpc(event_pretty) => {
+                        process_grpc_transaction(
+                            event_pretty,
+                            &protocols,
+                            event_type_filter.as_ref().as_ref(),
+                            callback.clone(),
+                            bot_wallet,
+                        )
+                        .await
+                    }
+                    RawUpdate::Shred(transaction_with_slot) => {
+                        process_shred_transaction(
+                            transaction_with_slot,
+                            &protocols,
+                            event_type_filter.as_ref().as_ref(),
+                            callback.clone(),
+                            bot_wallet,
+                        )
+                        .await
+                    }
+                };
+                if let Err(e) = result {
+                    error!("Pipeline failed to process update: {e:?}");
+                }
+            }
+        }));
+
+        Ok(())
+    }
+
+    /// 等待所有数据源结束且队列中的更新处理完毕，适用于回放等有限数据源
+    pub async fn join(&self) {
+        let handle = self.handle.lock().await.take();
+        if let Some(handle) = handle {
+            let _ = handle.await;
+        }
+    }
+
+    /// 停止所有数据源和解析任务
+    pub async fn stop(&self) {
+        for source in &self.sources {
+            source.stop().await;
+        }
+        if let Some(handle) = self.handle.lock().await.take() {
+            handle.abort();
+        }
+    }
+
+    fn event_callback(&self) -> Arc<dyn Fn(DexEvent) + Send + Sync> {
+        let stages = self.stages.clone();
+        let sinks = self.sinks.clone();
+        Arc::new(move |event: DexEvent| {
+            let mut event = event;
+            for stage in stages.iter() {
+                match stage.process(event) {
+                    Some(next) => event = next,
+                    None => return,
+                }
+            }
+            if let Some((last, rest)) = sinks.split_last() {
+                for sink in rest {
+                    sink(event.clone());
+                }
+                last(event);
+            }
+        })
+    }
+}
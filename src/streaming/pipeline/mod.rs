@@ -0,0 +1,9 @@
+// 数据源无关的事件流水线
+pub mod builder;
+pub mod source;
+pub mod stage;
+
+// 重新导出主要类型
+pub use builder::*;
+pub use source::*;
+pub use stage::*;
@@ -0,0 +1,112 @@
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use log::debug;
+use tokio::sync::mpsc;
+use yellowstone_grpc_proto::geyser::CommitmentLevel;
+
+use crate::common::AnyResult;
+use crate::streaming::event_parser::common::filter::EventTypeFilter;
+use crate::streaming::grpc::EventPretty;
+use crate::streaming::shred::TransactionWithSlot;
+use crate::streaming::yellowstone_grpc::{AccountFilter, TransactionFilter};
+use crate::streaming::{ShredStreamGrpc, YellowstoneGrpc};
+
+/// 数据源产出的原始更新，由 [`Pipeline`](super::Pipeline) 负责解析
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum RawUpdate {
+    /// Yellowstone gRPC 更新（交易 / 账户 / 区块元数据）
+    Grpc(EventPretty),
+    /// ShredStream 交易
+    Shred(TransactionWithSlot),
+}
+
+/// 与具体传输方式无关的数据源
+///
+/// `start` 返回前完成连接 / 订阅，之后在后台任务中把原始更新写入 `sender`。
+/// 数据源结束时丢弃 `sender` 即可，所有数据源都结束后流水线自然退出。
+pub trait EventSource: Send + Sync {
+    /// 数据源名称，用于日志
+    fn name(&self) -> &str;
+
+    /// 开始产出原始更新
+    fn start(&self, sender: mpsc::Sender<RawUpdate>) -> BoxFuture<'_, AnyResult<()>>;
+
+    /// 停止产出
+    fn stop(&self) -> BoxFuture<'_, ()>;
+}
+
+/// 以 Yellowstone gRPC 订阅作为数据源
+pub struct YellowstoneGrpcSource {
+    pub client: YellowstoneGrpc,
+    pub transaction_filter: Vec<TransactionFilter>,
+    pub account_filter: Vec<AccountFilter>,
+    pub event_type_filter: Option<EventTypeFilter>,
+    pub commitment: Option<CommitmentLevel>,
+}
+
+impl YellowstoneGrpcSource {
+    pub fn new(
+        client: YellowstoneGrpc,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+    ) -> Self {
+        Self { client, transaction_filter, account_filter, event_type_filter, commitment }
+    }
+}
+
+impl EventSource for YellowstoneGrpcSource {
+    fn name(&self) -> &str {
+        "yellowstone_grpc"
+    }
+
+    fn start(&self, sender: mpsc::Sender<RawUpdate>) -> BoxFuture<'_, AnyResult<()>> {
+        async move {
+            self.client
+                .subscribe_with_handler(
+                    self.transaction_filter.clone(),
+                    self.account_filter.clone(),
+                    self.event_type_filter.clone(),
+                    self.commitment,
+                    move |event_pretty: EventPretty| {
+                        let sender = sender.clone();
+                        async move {
+                            if sender.send(RawUpdate::Grpc(event_pretty)).await.is_err() {
+                                debug!("Pipeline closed, dropping grpc update");
+                            }
+                        }
+                    },
+                )
+                .await
+        }
+        .boxed()
+    }
+
+    fn stop(&self) -> BoxFuture<'_, ()> {
+        self.client.stop().boxed()
+    }
+}
+
+impl EventSource for ShredStreamGrpc {
+    fn name(&self) -> &str {
+        "shredstream"
+    }
+
+    fn start(&self, sender: mpsc::Sender<RawUpdate>) -> BoxFuture<'_, AnyResult<()>> {
+        self.subscribe_with_handler(move |transaction_with_slot: TransactionWithSlot| {
+            let sender = sender.clone();
+            async move {
+                if sender.send(RawUpdate::Shred(transaction_with_slot)).await.is_err() {
+                    debug!("Pipeline closed, dropping shred transaction");
+                }
+            }
+        })
+        .boxed()
+    }
+
+    fn stop(&self) -> BoxFuture<'_, ()> {
+        ShredStreamGrpc::stop(self).boxed()
+    }
+}
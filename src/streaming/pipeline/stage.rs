@@ -0,0 +1,101 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::Mutex;
+
+use solana_sdk::signature::Signature;
+
+use crate::streaming::event_parser::common::EventType;
+use crate::streaming::event_parser::DexEvent;
+
+/// 流水线中间处理阶段
+///
+/// 返回 `None` 表示丢弃该事件，后续阶段和 sink 都不会再收到它
+pub trait Stage: Send + Sync {
+    fn process(&self, event: DexEvent) -> Option<DexEvent>;
+}
+
+/// 按条件过滤事件
+pub struct FilterStage<F>(pub F);
+
+impl<F> Stage for FilterStage<F>
+where
+    F: Fn(&DexEvent) -> bool + Send + Sync,
+{
+    fn process(&self, event: DexEvent) -> Option<DexEvent> {
+        (self.0)(&event).then_some(event)
+    }
+}
+
+/// 原地补充事件字段
+pub struct EnrichStage<F>(pub F);
+
+impl<F> Stage for EnrichStage<F>
+where
+    F: Fn(&mut DexEvent) + Send + Sync,
+{
+    fn process(&self, mut event: DexEvent) -> Option<DexEvent> {
+        (self.0)(&mut event);
+        Some(event)
+    }
+}
+
+/// 转换事件，返回 `None` 时丢弃
+pub struct MapStage<F>(pub F);
+
+impl<F> Stage for MapStage<F>
+where
+    F: Fn(DexEvent) -> Option<DexEvent> + Send + Sync,
+{
+    fn process(&self, event: DexEvent) -> Option<DexEvent> {
+        (self.0)(event)
+    }
+}
+
+type DedupeKey = (Signature, EventType, i64, Option<i64>);
+
+/// 事件去重，多个数据源（例如 gRPC 与 ShredStream）推送同一笔交易时只保留第一次
+///
+/// 以 (signature, event_type, outer_index, inner_index) 作为键，最多记住 `capacity` 个键。
+/// 没有签名的事件（账户、区块元数据）直接放行。
+pub struct DedupeStage {
+    capacity: usize,
+    seen: Mutex<(HashSet<DedupeKey>, VecDeque<DedupeKey>)>,
+}
+
+impl DedupeStage {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            seen: Mutex::new((HashSet::with_capacity(capacity), VecDeque::with_capacity(capacity))),
+        }
+    }
+}
+
+impl Stage for DedupeStage {
+    fn process(&self, event: DexEvent) -> Option<DexEvent> {
+        let metadata = event.metadata();
+        if metadata.signature == Signature::default() {
+            return Some(event);
+        }
+        let key = (
+            metadata.signature,
+            metadata.event_type.clone(),
+            metadata.outer_index,
+            metadata.inner_index,
+        );
+        let Ok(mut guard) = self.seen.lock() else {
+            return Some(event);
+        };
+        let (set, order) = &mut *guard;
+        if !set.insert(key.clone()) {
+            return None;
+        }
+        order.push_back(key);
+        if order.len() > self.capacity {
+            if let Some(oldest) = order.pop_front() {
+                set.remove(&oldest);
+            }
+        }
+        Some(event)
+    }
+}
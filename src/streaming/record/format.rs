@@ -0,0 +1,230 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use prost::Message;
+
+use crate::protos::shredstream::Entry;
+
+/// 录制文件中的单条记录
+///
+/// 文件内容为连续的 length-delimited（varint 长度前缀）`RecordedUpdate` 消息
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecordedUpdate {
+    /// 接收时间（微秒）
+    #[prost(int64, tag = "1")]
+    pub recv_us: i64,
+    #[prost(oneof = "recorded_update::Payload", tags = "2, 3")]
+    pub payload: ::core::option::Option<recorded_update::Payload>,
+}
+
+pub mod recorded_update {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    #[allow(clippy::large_enum_variant)]
+    pub enum Payload {
+        /// Yellowstone gRPC 原始消息
+        #[prost(message, tag = "2")]
+        Grpc(::yellowstone_grpc_proto::geyser::SubscribeUpdate),
+        /// ShredStream 原始消息
+        #[prost(message, tag = "3")]
+        Shred(crate::protos::shredstream::Entry),
+    }
+}
+
+impl RecordedUpdate {
+    pub fn grpc(recv_us: i64, update: ::yellowstone_grpc_proto::geyser::SubscribeUpdate) -> Self {
+        Self { recv_us, payload: Some(recorded_update::Payload::Grpc(update)) }
+    }
+
+    pub fn shred(recv_us: i64, entry: Entry) -> Self {
+        Self { recv_us, payload: Some(recorded_update::Payload::Shred(entry)) }
+    }
+
+    /// 直接从借用的 gRPC 消息编码 length-delimited 记录，与 `grpc(..)` 编码结果一致
+    pub(crate) fn encode_grpc(
+        recv_us: i64,
+        update: &::yellowstone_grpc_proto::geyser::SubscribeUpdate,
+    ) -> Vec<u8> {
+        encode_delimited(recv_us, 2, update)
+    }
+
+    /// 直接从借用的 ShredStream 消息编码 length-delimited 记录，与 `shred(..)` 编码结果一致
+    pub(crate) fn encode_shred(recv_us: i64, entry: &Entry) -> Vec<u8> {
+        encode_delimited(recv_us, 3, entry)
+    }
+}
+
+/// 按 `RecordedUpdate` 的字段布局编码，不需要克隆 payload
+fn encode_delimited<M: Message>(recv_us: i64, payload_tag: u32, payload: &M) -> Vec<u8> {
+    use prost::encoding::{encode_varint, encoded_len_varint, int64, message};
+
+    // proto3 默认值不写入
+    let recv_len = if recv_us != 0 { int64::encoded_len(1, &recv_us) } else { 0 };
+    let body_len = recv_len + message::encoded_len(payload_tag, payload);
+    let mut buf = Vec::with_capacity(encoded_len_varint(body_len as u64) + body_len);
+    encode_varint(body_len as u64, &mut buf);
+    if recv_us != 0 {
+        int64::encode(1, &recv_us, &mut buf);
+    }
+    message::encode(payload_tag, payload, &mut buf);
+    buf
+}
+
+/// 录制文件压缩方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RecordCompression {
+    None,
+    #[default]
+    Gzip,
+}
+
+impl RecordCompression {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            RecordCompression::None => "pb",
+            RecordCompression::Gzip => "pb.gz",
+        }
+    }
+
+    /// 根据文件扩展名判断压缩方式
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("gz") => RecordCompression::Gzip,
+            _ => RecordCompression::None,
+        }
+    }
+}
+
+/// 正在写入的录制文件
+pub(crate) enum RecordFileWriter {
+    Plain(BufWriter<File>),
+    Gzip(GzEncoder<BufWriter<File>>),
+}
+
+impl RecordFileWriter {
+    pub(crate) fn create(path: &Path, compression: RecordCompression) -> io::Result<Self> {
+        let file = BufWriter::new(File::create(path)?);
+        Ok(match compression {
+            RecordCompression::None => RecordFileWriter::Plain(file),
+            RecordCompression::Gzip => {
+                RecordFileWriter::Gzip(GzEncoder::new(file, flate2::Compression::default()))
+            }
+        })
+    }
+
+    pub(crate) fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        match self {
+            RecordFileWriter::Plain(w) => w.write_all(buf),
+            RecordFileWriter::Gzip(w) => w.write_all(buf),
+        }
+    }
+
+    pub(crate) fn flush(&mut self) -> io::Result<()> {
+        match self {
+            RecordFileWriter::Plain(w) => w.flush(),
+            RecordFileWriter::Gzip(w) => w.flush(),
+        }
+    }
+
+    pub(crate) fn finish(self) -> io::Result<()> {
+        match self {
+            RecordFileWriter::Plain(mut w) => w.flush(),
+            RecordFileWriter::Gzip(w) => w.finish()?.flush(),
+        }
+    }
+}
+
+/// 打开录制文件用于读取，按扩展名自动解压
+pub(crate) fn open_record_file(path: &Path) -> io::Result<Box<dyn Read + Send>> {
+    let file = BufReader::new(File::open(path)?);
+    Ok(match RecordCompression::from_path(path) {
+        RecordCompression::None => Box::new(file),
+        RecordCompression::Gzip => Box::new(BufReader::new(GzDecoder::new(file))),
+    })
+}
+
+/// 单条记录的最大长度，远大于 gRPC 默认的消息上限；超过时视为文件损坏
+pub(crate) const MAX_RECORD_LEN: u64 = 64 * 1024 * 1024;
+
+/// 读取一条 length-delimited 消息，文件结束时返回 `None`
+pub(crate) fn read_delimited<R: Read + ?Sized>(reader: &mut R) -> io::Result<Option<Vec<u8>>> {
+    let mut len: u64 = 0;
+    let mut shift = 0u32;
+    let mut byte = [0u8; 1];
+    loop {
+        if reader.read(&mut byte)? == 0 {
+            if shift == 0 {
+                return Ok(None);
+            }
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated length prefix"));
+        }
+        len |= u64::from(byte[0] & 0x7f) << shift;
+        if byte[0] & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+        if shift >= 64 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "invalid length prefix"));
+        }
+    }
+    if len > MAX_RECORD_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("record length {len} exceeds {MAX_RECORD_LEN}"),
+        ));
+    }
+    // 按实际读到的数据增长缓冲区，不按长度前缀预分配
+    let mut buf = Vec::new();
+    reader.take(len).read_to_end(&mut buf)?;
+    if buf.len() as u64 != len {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated record"));
+    }
+    Ok(Some(buf))
+}
+
+/// 解码一条记录
+pub(crate) fn decode_record(buf: &[u8]) -> io::Result<RecordedUpdate> {
+    RecordedUpdate::decode(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_delimited_rejects_oversized_and_truncated_records() {
+        // 长度前缀为 u64::MAX / 2 的损坏记录
+        let mut corrupt: &[u8] = &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x3f];
+        let err = read_delimited(&mut corrupt).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let mut truncated: &[u8] = &[4, 1, 2];
+        let err = read_delimited(&mut truncated).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        let mut valid: &[u8] = &[2, 7, 8];
+        assert_eq!(read_delimited(&mut valid).unwrap(), Some(vec![7, 8]));
+        assert_eq!(read_delimited(&mut valid).unwrap(), None);
+    }
+
+    #[test]
+    fn test_borrowed_encoding_matches_recorded_update() {
+        let entry = Entry { slot: 9, entries: vec![1, 2, 3] };
+        for recv_us in [0, 7] {
+            assert_eq!(
+                RecordedUpdate::encode_shred(recv_us, &entry),
+                RecordedUpdate::shred(recv_us, entry.clone()).encode_length_delimited_to_vec()
+            );
+            let update = yellowstone_grpc_proto::geyser::SubscribeUpdate {
+                filters: vec!["txs".to_string()],
+                ..Default::default()
+            };
+            assert_eq!(
+                RecordedUpdate::encode_grpc(recv_us, &update),
+                RecordedUpdate::grpc(recv_us, update).encode_length_delimited_to_vec()
+            );
+        }
+    }
+}
@@ -0,0 +1,9 @@
+// 原始 gRPC / ShredStream 消息录制与回放
+pub mod format;
+pub mod recorder;
+pub mod replay;
+
+// 重新导出主要类型
+pub use format::{RecordCompression, RecordedUpdate};
+pub use recorder::*;
+pub use replay::*;
@@ -0,0 +1,271 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::Mutex;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use log::{error, info};
+use prost::Message;
+use yellowstone_grpc_proto::geyser::SubscribeUpdate;
+
+use crate::common::AnyResult;
+use crate::protos::shredstream::Entry;
+use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
+use crate::streaming::record::format::{RecordCompression, RecordFileWriter, RecordedUpdate};
+
+/// 录制配置
+#[derive(Debug, Clone)]
+pub struct RecorderConfig {
+    /// 录制文件目录
+    pub dir: PathBuf,
+    /// 文件名前缀，文件名格式为 `{prefix}-{unix_ms}-{seq}.pb[.gz]`
+    pub file_prefix: String,
+    /// 单个文件写入的未压缩字节数上限，超过后轮转
+    pub max_file_bytes: u64,
+    /// 单个文件的最长时长，超过后轮转
+    pub max_file_duration: Option<Duration>,
+    pub compression: RecordCompression,
+    /// 写入线程队列长度，队列满时丢弃新消息
+    pub queue_size: usize,
+}
+
+impl Default for RecorderConfig {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from("recordings"),
+            file_prefix: "stream".to_string(),
+            max_file_bytes: 256 * 1024 * 1024,
+            max_file_duration: Some(Duration::from_secs(3600)),
+            compression: RecordCompression::Gzip,
+            queue_size: 100_000,
+        }
+    }
+}
+
+enum WriterCommand {
+    Record(Vec<u8>),
+    Flush(SyncSender<()>),
+    Close,
+}
+
+/// 原始 gRPC / ShredStream 消息录制器
+///
+/// 消息在调用线程编码后交给后台写入线程，不会阻塞流处理循环。
+/// 通过 `YellowstoneGrpc::set_recorder` / `ShredStreamGrpc::set_recorder` 挂载。
+pub struct Recorder {
+    sender: SyncSender<WriterCommand>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+    dropped: AtomicU64,
+    recorded: AtomicU64,
+}
+
+impl Recorder {
+    /// 创建录制器并启动写入线程
+    pub fn new(config: RecorderConfig) -> AnyResult<Self> {
+        std::fs::create_dir_all(&config.dir)?;
+        let (sender, receiver) = mpsc::sync_channel(config.queue_size.max(1));
+        let handle = std::thread::Builder::new()
+            .name("stream-recorder".to_string())
+            .spawn(move || run_writer(config, receiver))?;
+        Ok(Self {
+            sender,
+            handle: Mutex::new(Some(handle)),
+            dropped: AtomicU64::new(0),
+            recorded: AtomicU64::new(0),
+        })
+    }
+
+    /// 录制一条 Yellowstone gRPC 消息
+    pub fn record_grpc(&self, update: &SubscribeUpdate) {
+        self.send(RecordedUpdate::encode_grpc(get_high_perf_clock(), update));
+    }
+
+    /// 录制一条 ShredStream 消息
+    pub fn record_shred(&self, entry: &Entry) {
+        self.send(RecordedUpdate::encode_shred(get_high_perf_clock(), entry));
+    }
+
+    /// 录制任意记录（例如带原始接收时间的记录）
+    pub fn record(&self, update: RecordedUpdate) {
+        self.send(update.encode_length_delimited_to_vec());
+    }
+
+    fn send(&self, buf: Vec<u8>) {
+        match self.sender.try_send(WriterCommand::Record(buf)) {
+            Ok(()) => {
+                self.recorded.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(TrySendError::Full(_)) | Err(TrySendError::Disconnected(_)) => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// 已提交写入的记录数
+    pub fn recorded_count(&self) -> u64 {
+        self.recorded.load(Ordering::Relaxed)
+    }
+
+    /// 因队列已满而丢弃的记录数
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// 将已提交的记录刷入文件
+    pub fn flush(&self) {
+        let (ack_tx, ack_rx) = mpsc::sync_channel(1);
+        if self.sender.send(WriterCommand::Flush(ack_tx)).is_ok() {
+            let _ = ack_rx.recv();
+        }
+    }
+
+    /// 关闭录制器，写完队列中的记录并结束当前文件
+    ///
+    /// 会阻塞到写入线程退出，异步代码中请通过 `tokio::task::spawn_blocking` 调用
+    pub fn close(&self) {
+        if let Some(stop) = self.stop_writer() {
+            stop();
+        }
+    }
+
+    /// 取出写入线程，返回发送 Close 并等待其退出的闭包；队列已满时发送 Close 也会阻塞
+    fn stop_writer(&self) -> Option<impl FnOnce() + Send + 'static> {
+        let handle = self.handle.lock().ok().and_then(|mut guard| guard.take())?;
+        let sender = self.sender.clone();
+        Some(move || {
+            let _ = sender.send(WriterCommand::Close);
+            let _ = handle.join();
+        })
+    }
+}
+
+impl Drop for Recorder {
+    /// 在 tokio 运行时中释放时，等待写入线程的操作交给 blocking 线程池，不阻塞 worker
+    fn drop(&mut self) {
+        let Some(stop) = self.stop_writer() else { return };
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => {
+                runtime.spawn_blocking(stop);
+            }
+            Err(_) => stop(),
+        }
+    }
+}
+
+struct WriterState {
+    config: RecorderConfig,
+    file: Option<RecordFileWriter>,
+    file_bytes: u64,
+    opened_at: Instant,
+    seq: u64,
+}
+
+impl WriterState {
+    fn write(&mut self, buf: &[u8]) -> AnyResult<()> {
+        let expired =
+            self.config.max_file_duration.is_some_and(|max| self.opened_at.elapsed() >= max);
+        if self.file.is_some() && (self.file_bytes >= self.config.max_file_bytes || expired) {
+            self.finish_file()?;
+        }
+        if self.file.is_none() {
+            let path = next_file_path(&self.config, self.seq);
+            self.seq += 1;
+            info!("Recording to {}", path.display());
+            self.file = Some(RecordFileWriter::create(&path, self.config.compression)?);
+            self.file_bytes = 0;
+            self.opened_at = Instant::now();
+        }
+        if let Some(file) = self.file.as_mut() {
+            file.write_all(buf)?;
+            self.file_bytes += buf.len() as u64;
+        }
+        Ok(())
+    }
+
+    fn finish_file(&mut self) -> AnyResult<()> {
+        if let Some(file) = self.file.take() {
+            file.finish()?;
+        }
+        Ok(())
+    }
+}
+
+fn next_file_path(config: &RecorderConfig, seq: u64) -> PathBuf {
+    let unix_ms = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+    let name = format!(
+        "{}-{:013}-{:06}.{}",
+        config.file_prefix,
+        unix_ms,
+        seq,
+        config.compression.extension()
+    );
+    Path::new(&config.dir).join(name)
+}
+
+fn run_writer(config: RecorderConfig, receiver: Receiver<WriterCommand>) {
+    let mut state =
+        WriterState { config, file: None, file_bytes: 0, opened_at: Instant::now(), seq: 0 };
+    for command in receiver {
+        match command {
+            WriterCommand::Record(buf) => {
+                if let Err(e) = state.write(&buf) {
+                    error!("Failed to write recording: {e:?}");
+                }
+            }
+            WriterCommand::Flush(ack) => {
+                if let Some(file) = state.file.as_mut() {
+                    if let Err(e) = file.flush() {
+                        error!("Failed to flush recording: {e:?}");
+                    }
+                }
+                let _ = ack.send(());
+            }
+            WriterCommand::Close => break,
+        }
+    }
+    if let Err(e) = state.finish_file() {
+        error!("Failed to finish recording: {e:?}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::record::format::recorded_update::Payload;
+    use crate::streaming::record::RecordReader;
+
+    #[test]
+    fn record_rotate_and_read_back() {
+        let dir =
+            std::env::temp_dir().join(format!("solana-streamer-record-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let recorder = Recorder::new(RecorderConfig {
+            dir: dir.clone(),
+            max_file_bytes: 1,
+            ..Default::default()
+        })
+        .unwrap();
+        for slot in 0..3 {
+            recorder.record_shred(&Entry { slot, entries: vec![1, 2, 3] });
+        }
+        recorder.record(RecordedUpdate::grpc(7, SubscribeUpdate::default()));
+        recorder.close();
+
+        let files = crate::streaming::record::list_record_files(&dir).unwrap();
+        assert_eq!(files.len(), 4);
+        let records: Vec<RecordedUpdate> =
+            RecordReader::from_files(files).collect::<AnyResult<_>>().unwrap();
+        let slots: Vec<u64> = records
+            .iter()
+            .filter_map(|r| match &r.payload {
+                Some(Payload::Shred(entry)) => Some(entry.slot),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(slots, vec![0, 1, 2]);
+        assert_eq!(records[3].recv_us, 7);
+        assert!(matches!(records[3].payload, Some(Payload::Grpc(_))));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::VecDeque;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use log::{debug, error};
+use solana_entry::entry::Entry as SolanaEntry;
+use tokio::sync::mpsc;
+use yellowstone_grpc_proto::geyser::subscribe_update::UpdateOneof;
+use yellowstone_grpc_proto::geyser::SubscribeUpdate;
+
+use crate::common::AnyResult;
+use crate::protos::shredstream::Entry;
+use crate::streaming::event_parser::common::high_performance_clock::get_high_perf_clock;
+use crate::streaming::grpc::pool::factory as grpc_factory;
+use crate::streaming::grpc::EventPretty;
+use crate::streaming::pipeline::{EventSource, RawUpdate};
+use crate::streaming::record::format::{
+    decode_record, open_record_file, read_delimited, recorded_update::Payload, RecordedUpdate,
+};
+use crate::streaming::shred::pool::factory as shred_factory;
+use crate::streaming::shred::TransactionWithSlot;
+
+/// 按顺序读取一个或多个录制文件
+pub struct RecordReader {
+    files: VecDeque<PathBuf>,
+    current: Option<Box<dyn Read + Send>>,
+}
+
+impl RecordReader {
+    /// 打开单个录制文件，或目录下的所有录制文件（按文件名排序）
+    pub fn open(path: impl AsRef<Path>) -> AnyResult<Self> {
+        Ok(Self::from_files(list_record_files(path.as_ref())?))
+    }
+
+    /// 按给定顺序读取文件
+    pub fn from_files(files: Vec<PathBuf>) -> Self {
+        Self { files: files.into(), current: None }
+    }
+}
+
+impl Iterator for RecordReader {
+    type Item = AnyResult<RecordedUpdate>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.current.is_none() {
+                let path = self.files.pop_front()?;
+                match open_record_file(&path) {
+                    Ok(reader) => self.current = Some(reader),
+                    Err(e) => return Some(Err(anyhow::anyhow!("{}: {e}", path.display()))),
+                }
+            }
+            let reader = self.current.as_mut()?;
+            match read_delimited(reader.as_mut()) {
+                Ok(Some(buf)) => return Some(decode_record(&buf).map_err(Into::into)),
+                Ok(None) => self.current = None,
+                Err(e) => {
+                    self.current = None;
+                    return Some(Err(e.into()));
+                }
+            }
+        }
+    }
+}
+
+/// 列出录制文件；目录下只包含 `.pb` / `.pb.gz` 文件
+pub fn list_record_files(path: &Path) -> AnyResult<Vec<PathBuf>> {
+    if path.is_file() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(path)? {
+        let file = entry?.path();
+        let name = file.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        if file.is_file() && (name.ends_with(".pb") || name.ends_with(".pb.gz")) {
+            files.push(file);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// 将 Yellowstone gRPC 原始消息转换为 `EventPretty`，与实时订阅的转换方式一致
+pub fn event_pretty_from_update(update: SubscribeUpdate) -> Option<EventPretty> {
+    let created_at = update.created_at;
+    match update.update_oneof? {
+        UpdateOneof::Account(account) => {
+            Some(EventPretty::Account(grpc_factory::create_account_pretty_pooled(account)))
+        }
+        UpdateOneof::BlockMeta(block_meta) => Some(EventPretty::BlockMeta(
+            grpc_factory::create_block_meta_pretty_pooled(block_meta, created_at),
+        )),
+        UpdateOneof::Transaction(transaction) => Some(EventPretty::Transaction(
+            grpc_factory::create_transaction_pretty_pooled(transaction, created_at),
+        )),
+        _ => None,
+    }
+}
+
+/// 将 ShredStream 原始消息解码为交易列表，与实时订阅的转换方式一致
+pub fn transactions_from_entry(entry: &Entry) -> Vec<TransactionWithSlot> {
+    let Ok(entries) = bincode::deserialize::<Vec<SolanaEntry>>(&entry.entries) else {
+        return Vec::new();
+    };
+    entries
+        .into_iter()
+        .flat_map(|solana_entry| {
+            solana_entry.transactions.into_iter().enumerate().map(|(tx_index, transaction)| {
+                shred_factory::create_transaction_with_slot_pooled(
+                    transaction,
+                    entry.slot,
+                    get_high_perf_clock(),
+                    Some(tx_index as u64),
+                )
+            })
+        })
+        .collect()
+}
+
+/// 回放节奏
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ReplayPacing {
+    /// 尽可能快地回放
+    #[default]
+    AsFastAsPossible,
+    /// 按录制时记录的接收时间间隔回放
+    Original,
+}
+
+/// 从录制文件回放的数据源
+///
+/// 与 [`Pipeline`](crate::streaming::pipeline::Pipeline) 组合后，录制的消息会经过
+/// `process_grpc_transaction` / `process_shred_transaction` 与实时流完全相同的解析路径。
+/// 回放时 `recv_us` 使用当前时钟，延迟类指标反映的是回放时的处理耗时。
+pub struct ReplaySource {
+    files: Vec<PathBuf>,
+    pacing: ReplayPacing,
+    stopped: Arc<AtomicBool>,
+}
+
+impl ReplaySource {
+    /// 从单个录制文件或录制目录创建
+    pub fn new(path: impl AsRef<Path>) -> AnyResult<Self> {
+        Ok(Self::from_files(list_record_files(path.as_ref())?))
+    }
+
+    pub fn from_files(files: Vec<PathBuf>) -> Self {
+        Self { files, pacing: ReplayPacing::default(), stopped: Arc::new(AtomicBool::new(false)) }
+    }
+
+    pub fn with_pacing(mut self, pacing: ReplayPacing) -> Self {
+        self.pacing = pacing;
+        self
+    }
+}
+
+impl EventSource for ReplaySource {
+    fn name(&self) -> &str {
+        "replay"
+    }
+
+    fn start(&self, sender: mpsc::Sender<RawUpdate>) -> BoxFuture<'_, AnyResult<()>> {
+        let reader = RecordReader::from_files(self.files.clone());
+        let pacing = self.pacing;
+        let stopped = self.stopped.clone();
+        stopped.store(false, Ordering::Release);
+        async move {
+            tokio::task::spawn_blocking(move || replay_records(reader, pacing, &stopped, &sender));
+            Ok(())
+        }
+        .boxed()
+    }
+
+    fn stop(&self) -> BoxFuture<'_, ()> {
+        self.stopped.store(true, Ordering::Release);
+        async {}.boxed()
+    }
+}
+
+fn replay_records(
+    reader: RecordReader,
+    pacing: ReplayPacing,
+    stopped: &AtomicBool,
+    sender: &mpsc::Sender<RawUpdate>,
+) {
+    let started_at = Instant::now();
+    let mut first_recv_us: Option<i64> = None;
+    for record in reader {
+        if stopped.load(Ordering::Acquire) {
+            break;
+        }
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                error!("Failed to read recording: {e:?}");
+                continue;
+            }
+        };
+        if pacing == ReplayPacing::Original {
+            let first = *first_recv_us.get_or_insert(record.recv_us);
+            let offset = Duration::from_micros(record.recv_us.saturating_sub(first).max(0) as u64);
+            if let Some(wait) = offset.checked_sub(started_at.elapsed()) {
+                std::thread::sleep(wait);
+            }
+        }
+        let updates = match record.payload {
+            Some(Payload::Grpc(update)) => {
+                event_pretty_from_update(update).map(RawUpdate::Grpc).into_iter().collect()
+            }
+            Some(Payload::Shred(entry)) => {
+                transactions_from_entry(&entry).into_iter().map(RawUpdate::Shred).collect()
+            }
+            None => Vec::new(),
+        };
+        for update in updates {
+            if sender.blocking_send(update).is_err() {
+                debug!("Pipeline closed, stopping replay");
+                return;
+            }
+        }
+    }
+}
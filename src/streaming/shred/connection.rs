@@ -7,6 +7,7 @@ use crate::protos::shredstream::shredstream_proxy_client::ShredstreamProxyClient
 use crate::streaming::common::{
     MetricsManager, PerformanceMetrics, StreamClientConfig, SubscriptionHandle,
 };
+use crate::streaming::record::Recorder;
 
 /// ShredStream gRPC 客户端
 #[derive(Clone)]
@@ -14,6 +15,8 @@ pub struct ShredStreamGrpc {
     pub shredstream_client: Arc<ShredstreamProxyClient<Channel>>,
     pub config: StreamClientConfig,
     pub subscription_handle: Arc<Mutex<Option<SubscriptionHandle>>>,
+    /// 可选的原始消息录制器，在下一次订阅时生效
+    pub recorder: Option<Arc<Recorder>>,
 }
 
 impl ShredStreamGrpc {
@@ -30,6 +33,7 @@ impl ShredStreamGrpc {
             shredstream_client: Arc::new(shredstream_client),
             config,
             subscription_handle: Arc::new(Mutex::new(None)),
+            recorder: None,
         })
     }
 
@@ -43,6 +47,11 @@ impl ShredStreamGrpc {
         self.config = config;
     }
 
+    /// 设置原始消息录制器，在下一次订阅时生效
+    pub fn set_recorder(&mut self, recorder: Option<Arc<Recorder>>) {
+        self.recorder = recorder;
+    }
+
     /// 获取性能指标
     pub fn get_metrics(&self) -> PerformanceMetrics {
         MetricsManager::global().get_metrics()
@@ -1,3 +1,4 @@
+use std::future::Future;
 use std::sync::Arc;
 
 use futures::StreamExt;
@@ -11,6 +12,7 @@ use crate::streaming::event_parser::common::high_performance_clock::get_high_per
 use crate::streaming::event_parser::{Protocol, DexEvent};
 use crate::streaming::grpc::MetricsManager;
 use crate::streaming::shred::pool::factory;
+use crate::streaming::shred::TransactionWithSlot;
 use log::error;
 use solana_entry::entry::Entry as SolanaEntry;
 
@@ -27,6 +29,40 @@ impl ShredStreamGrpc {
     ) -> AnyResult<()>
     where
         F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        // Wrap callback once before the async block
+        let callback: Arc<dyn Fn(DexEvent) + Send + Sync> = Arc::new(callback);
+        let protocols = Arc::new(protocols);
+        let event_type_filter = Arc::new(event_type_filter);
+
+        self.subscribe_with_handler(move |transaction_with_slot: TransactionWithSlot| {
+            let protocols = protocols.clone();
+            let event_type_filter = event_type_filter.clone();
+            let callback = callback.clone();
+            async move {
+                if let Err(e) = process_shred_transaction(
+                    transaction_with_slot,
+                    &protocols,
+                    event_type_filter.as_ref().as_ref(),
+                    callback,
+                    bot_wallet,
+                )
+                .await
+                {
+                    error!("Error handling message: {e:?}");
+                }
+            }
+        })
+        .await
+    }
+
+    /// 订阅原始交易，每笔解码出的交易都会交给 `handler` 处理
+    ///
+    /// `shredstream_subscribe` 与 `EventSource` 实现共用此方法的流处理循环
+    pub async fn subscribe_with_handler<H, Fut>(&self, handler: H) -> AnyResult<()>
+    where
+        H: Fn(TransactionWithSlot) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send,
     {
         // 如果已有活跃订阅，先停止它
         self.stop().await;
@@ -41,14 +77,15 @@ impl ShredStreamGrpc {
         let mut client = (*self.shredstream_client).clone();
         let request = tonic::Request::new(SubscribeEntriesRequest {});
         let mut stream = client.subscribe_entries(request).await?.into_inner();
-
-        // Wrap callback once before the async block
-        let callback = Arc::new(callback);
+        let recorder = self.recorder.clone();
 
         let stream_task = tokio::spawn(async move {
             while let Some(message) = stream.next().await {
                 match message {
                     Ok(msg) => {
+                        if let Some(recorder) = &recorder {
+                            recorder.record_shred(&msg);
+                        }
                         if let Ok(entries) = bincode::deserialize::<Vec<SolanaEntry>>(&msg.entries) {
                             for entry in entries {
                                 for (tx_index, transaction) in entry.transactions.iter().enumerate() {
@@ -59,18 +96,7 @@ impl ShredStreamGrpc {
                                             get_high_perf_clock(),
                                             Some(tx_index as u64),
                                         );
-                                    // Process transaction - clone Arc and Vec for each call
-                                    if let Err(e) = process_shred_transaction(
-                                        transaction_with_slot,
-                                        &protocols,
-                                        event_type_filter.as_ref(),
-                                        callback.clone(),
-                                        bot_wallet,
-                                    )
-                                    .await
-                                    {
-                                        error!("Error handling message: {e:?}");
-                                    }
+                                    handler(transaction_with_slot).await;
                                 }
                             }
                         }
@@ -0,0 +1,268 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::streaming::event_parser::common::{EventType, ProtocolType};
+
+/// 客户端发送的订阅消息
+///
+/// ```json
+/// {"op": "subscribe", "event_types": ["PumpSwapBuy"], "mints": ["<base58>"]}
+/// {"op": "unsubscribe", "mints": ["<base58>"]}
+/// {"op": "unsubscribe"}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum ClientMessage {
+    Subscribe(FilterSpec),
+    Unsubscribe(FilterSpec),
+}
+
+/// 订阅 / 取消订阅消息中携带的过滤条件，mint 和钱包地址使用 base58 字符串
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilterSpec {
+    #[serde(default)]
+    pub event_types: Vec<EventType>,
+    #[serde(default)]
+    pub protocols: Vec<ProtocolType>,
+    #[serde(default)]
+    pub mints: Vec<String>,
+    #[serde(default)]
+    pub wallets: Vec<String>,
+}
+
+impl FilterSpec {
+    pub fn is_empty(&self) -> bool {
+        self.event_types.is_empty()
+            && self.protocols.is_empty()
+            && self.mints.is_empty()
+            && self.wallets.is_empty()
+    }
+}
+
+/// 单个客户端当前生效的过滤器
+///
+/// 每个维度为 `None` 时不限制；为 `Some` 时事件需要命中其中任意一项。
+/// 维度之间为 AND 关系。客户端在发送第一条 subscribe 之前不会收到任何事件。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientFilter {
+    pub active: bool,
+    pub event_types: Option<HashSet<EventType>>,
+    pub protocols: Option<Vec<ProtocolType>>,
+    pub mints: Option<HashSet<Pubkey>>,
+    pub wallets: Option<HashSet<Pubkey>>,
+}
+
+impl ClientFilter {
+    /// 合并 subscribe 消息，已有条件与新条件取并集
+    pub fn subscribe(&mut self, spec: &FilterSpec) -> Result<(), String> {
+        let mints = parse_pubkeys(&spec.mints)?;
+        let wallets = parse_pubkeys(&spec.wallets)?;
+        self.active = true;
+        if !spec.event_types.is_empty() {
+            self.event_types
+                .get_or_insert_with(HashSet::new)
+                .extend(spec.event_types.iter().cloned());
+        }
+        if !spec.protocols.is_empty() {
+            let protocols = self.protocols.get_or_insert_with(Vec::new);
+            for protocol in &spec.protocols {
+                if !protocols.contains(protocol) {
+                    protocols.push(protocol.clone());
+                }
+            }
+        }
+        if !mints.is_empty() {
+            self.mints.get_or_insert_with(HashSet::new).extend(mints);
+        }
+        if !wallets.is_empty() {
+            self.wallets.get_or_insert_with(HashSet::new).extend(wallets);
+        }
+        Ok(())
+    }
+
+    /// 应用 unsubscribe 消息
+    ///
+    /// 不带任何条件时清空过滤器并停止推送；否则从对应维度中移除条件，
+    /// 某个维度被移空后不再匹配任何事件（而不是退化为不限制）。
+    pub fn unsubscribe(&mut self, spec: &FilterSpec) -> Result<(), String> {
+        if spec.is_empty() {
+            *self = Self::default();
+            return Ok(());
+        }
+        let mints = parse_pubkeys(&spec.mints)?;
+        let wallets = parse_pubkeys(&spec.wallets)?;
+        if let Some(event_types) = self.event_types.as_mut() {
+            for event_type in &spec.event_types {
+                event_types.remove(event_type);
+            }
+        }
+        if let Some(protocols) = self.protocols.as_mut() {
+            protocols.retain(|p| !spec.protocols.contains(p));
+        }
+        if let Some(set) = self.mints.as_mut() {
+            for mint in &mints {
+                set.remove(mint);
+            }
+        }
+        if let Some(set) = self.wallets.as_mut() {
+            for wallet in &wallets {
+                set.remove(wallet);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn matches(&self, event: &EventKeys) -> bool {
+        if !self.active {
+            return false;
+        }
+        if let Some(event_types) = &self.event_types {
+            if !event_types.contains(&event.event_type) {
+                return false;
+            }
+        }
+        if let Some(protocols) = &self.protocols {
+            if !protocols.contains(&event.protocol) {
+                return false;
+            }
+        }
+        if let Some(mints) = &self.mints {
+            if !event.mints.iter().any(|m| mints.contains(m)) {
+                return false;
+            }
+        }
+        if let Some(wallets) = &self.wallets {
+            if !event.wallets.iter().any(|w| wallets.contains(w)) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn parse_pubkeys(values: &[String]) -> Result<Vec<Pubkey>, String> {
+    values
+        .iter()
+        .map(|s| Pubkey::from_str(s).map_err(|e| format!("invalid pubkey {s}: {e}")))
+        .collect()
+}
+
+/// 事件中用于过滤的关键字段，每个事件只提取一次，所有客户端共享
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventKeys {
+    pub event_type: EventType,
+    pub protocol: ProtocolType,
+    pub mints: Vec<Pubkey>,
+    pub wallets: Vec<Pubkey>,
+}
+
+impl EventKeys {
+    /// 从事件的 JSON 表示中按字段名提取 mint 和钱包地址
+    ///
+    /// 字段名包含 `mint`（不含 `authority` / `account`）视为 mint；
+    /// `user`、`payer`、`signer`、`user_wallet` 以及以 `owner` / `creator` 结尾的字段视为钱包。
+    pub fn extract(event_type: EventType, protocol: ProtocolType, value: &Value) -> Self {
+        let mut keys = Self { event_type, protocol, mints: Vec::new(), wallets: Vec::new() };
+        keys.walk(value);
+        keys
+    }
+
+    fn walk(&mut self, value: &Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    match as_pubkey(value) {
+                        Some(pubkey) => {
+                            if is_mint_field(key) {
+                                push_unique(&mut self.mints, pubkey);
+                            } else if is_wallet_field(key) {
+                                push_unique(&mut self.wallets, pubkey);
+                            }
+                        }
+                        None => self.walk(value),
+                    }
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.walk(item);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn push_unique(keys: &mut Vec<Pubkey>, pubkey: Pubkey) {
+    if pubkey != Pubkey::default() && !keys.contains(&pubkey) {
+        keys.push(pubkey);
+    }
+}
+
+fn is_mint_field(key: &str) -> bool {
+    key.contains("mint") && !key.contains("authority") && !key.contains("account")
+}
+
+fn is_wallet_field(key: &str) -> bool {
+    matches!(key, "user" | "payer" | "signer" | "user_wallet")
+        || key.ends_with("owner")
+        || key.ends_with("creator")
+}
+
+/// `Pubkey` 在 serde_json 中序列化为 32 个数字组成的数组
+fn as_pubkey(value: &Value) -> Option<Pubkey> {
+    let items = value.as_array()?;
+    if items.len() != 32 {
+        return None;
+    }
+    let mut bytes = [0u8; 32];
+    for (byte, item) in bytes.iter_mut().zip(items) {
+        *byte = u8::try_from(item.as_u64()?).ok()?;
+    }
+    Some(Pubkey::new_from_array(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::event_parser::protocols::pumpswap::PumpSwapBuyEvent;
+    use crate::streaming::event_parser::DexEvent;
+
+    #[test]
+    fn filter_by_mint_and_wallet() {
+        let mint = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let mut event = PumpSwapBuyEvent { base_mint: mint, user, ..Default::default() };
+        event.metadata.event_type = EventType::PumpSwapBuy;
+        let value = serde_json::to_value(DexEvent::PumpSwapBuyEvent(event)).unwrap();
+        let keys = EventKeys::extract(EventType::PumpSwapBuy, ProtocolType::PumpSwap, &value);
+        assert_eq!(keys.mints, vec![mint]);
+        assert!(keys.wallets.contains(&user));
+
+        let mut filter = ClientFilter::default();
+        assert!(!filter.matches(&keys));
+
+        let message: ClientMessage = serde_json::from_str(&format!(
+            r#"{{"op":"subscribe","event_types":["PumpSwapBuy"],"mints":["{mint}"]}}"#
+        ))
+        .unwrap();
+        let ClientMessage::Subscribe(spec) = message else { panic!("expected subscribe") };
+        filter.subscribe(&spec).unwrap();
+        assert!(filter.matches(&keys));
+
+        let other =
+            FilterSpec { wallets: vec![Pubkey::new_unique().to_string()], ..Default::default() };
+        filter.subscribe(&other).unwrap();
+        assert!(!filter.matches(&keys));
+
+        filter.unsubscribe(&FilterSpec::default()).unwrap();
+        assert!(!filter.active);
+        assert!(filter
+            .subscribe(&FilterSpec { mints: vec!["bad".into()], ..Default::default() })
+            .is_err());
+    }
+}
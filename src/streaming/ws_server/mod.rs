@@ -0,0 +1,7 @@
+// WebSocket 事件广播模块
+pub mod filter;
+pub mod server;
+
+// 重新导出主要类型
+pub use filter::*;
+pub use server::*;
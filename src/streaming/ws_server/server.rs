@@ -0,0 +1,261 @@
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+use dashmap::DashMap;
+use futures::{SinkExt, StreamExt};
+use log::{debug, error, warn};
+use serde::Serialize;
+use serde_json::Value;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::{Message, Utf8Bytes};
+
+use crate::common::AnyResult;
+use crate::streaming::event_parser::DexEvent;
+use crate::streaming::ws_server::filter::{ClientFilter, ClientMessage, EventKeys};
+
+/// WebSocket 广播服务配置
+#[derive(Debug, Clone)]
+pub struct WsServerConfig {
+    /// 监听地址，例如 `127.0.0.1:9001`
+    pub bind_addr: String,
+    /// 每个客户端的待发送消息队列长度，队列满时丢弃新事件并通知客户端
+    pub client_buffer_size: usize,
+    /// 最大客户端连接数
+    pub max_clients: usize,
+}
+
+impl Default for WsServerConfig {
+    fn default() -> Self {
+        Self { bind_addr: "127.0.0.1:9001".to_string(), client_buffer_size: 1024, max_clients: 256 }
+    }
+}
+
+/// 服务端推送给客户端的消息
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerMessage<'a> {
+    Event { data: &'a Value },
+    Dropped { count: u64 },
+    Ack { op: &'static str },
+    Error { message: String },
+}
+
+impl ServerMessage<'_> {
+    fn to_text(&self) -> Option<Utf8Bytes> {
+        match serde_json::to_string(self) {
+            Ok(text) => Some(text.into()),
+            Err(e) => {
+                error!("Failed to serialize websocket message: {e}");
+                None
+            }
+        }
+    }
+}
+
+struct ClientHandle {
+    filter: Arc<RwLock<ClientFilter>>,
+    sender: mpsc::Sender<Utf8Bytes>,
+    dropped: Arc<AtomicU64>,
+}
+
+struct Inner {
+    config: WsServerConfig,
+    clients: DashMap<u64, ClientHandle>,
+    next_client_id: AtomicU64,
+    accept_handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+/// 内嵌的 WebSocket 事件广播服务
+///
+/// 将 `DexEvent` 序列化为 JSON 推送给已订阅的客户端。通过 [`WsBroadcastServer::sink`]
+/// 获取回调后即可作为 `YellowstoneGrpc` / `ShredStreamGrpc` 的事件回调使用。
+#[derive(Clone)]
+pub struct WsBroadcastServer {
+    inner: Arc<Inner>,
+}
+
+impl WsBroadcastServer {
+    /// 创建服务，使用默认配置
+    pub fn new() -> Self {
+        Self::new_with_config(WsServerConfig::default())
+    }
+
+    /// 创建服务，使用自定义配置
+    pub fn new_with_config(config: WsServerConfig) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                config,
+                clients: DashMap::new(),
+                next_client_id: AtomicU64::new(0),
+                accept_handle: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// 获取配置
+    pub fn get_config(&self) -> &WsServerConfig {
+        &self.inner.config
+    }
+
+    /// 当前连接的客户端数量
+    pub fn client_count(&self) -> usize {
+        self.inner.clients.len()
+    }
+
+    /// 绑定监听地址并开始接受连接，返回实际监听的地址
+    pub async fn start(&self) -> AnyResult<SocketAddr> {
+        let mut handle_guard = self.inner.accept_handle.lock().await;
+        if handle_guard.is_some() {
+            return Err(anyhow::anyhow!("WebSocket server already started"));
+        }
+        let listener = TcpListener::bind(&self.inner.config.bind_addr).await?;
+        let local_addr = listener.local_addr()?;
+        let inner = self.inner.clone();
+        *handle_guard = Some(tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, peer)) => {
+                        if inner.clients.len() >= inner.config.max_clients {
+                            warn!("Rejecting websocket client {peer}: max clients reached");
+                            continue;
+                        }
+                        let inner = inner.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = handle_connection(inner, stream).await {
+                                debug!("Websocket client {peer} closed: {e:?}");
+                            }
+                        });
+                    }
+                    Err(e) => error!("Failed to accept websocket connection: {e}"),
+                }
+            }
+        }));
+        Ok(local_addr)
+    }
+
+    /// 停止服务并断开所有客户端
+    pub async fn stop(&self) {
+        if let Some(handle) = self.inner.accept_handle.lock().await.take() {
+            handle.abort();
+        }
+        // 释放发送端后各连接任务会发送 Close 帧并退出
+        self.inner.clients.clear();
+    }
+
+    /// 将事件推送给所有过滤条件匹配的客户端
+    pub fn broadcast(&self, event: &DexEvent) {
+        if self.inner.clients.is_empty() {
+            return;
+        }
+        let value = match serde_json::to_value(event) {
+            Ok(value) => value,
+            Err(e) => {
+                error!("Failed to serialize event: {e}");
+                return;
+            }
+        };
+        let metadata = event.metadata();
+        let keys =
+            EventKeys::extract(metadata.event_type.clone(), metadata.protocol.clone(), &value);
+        let mut text: Option<Utf8Bytes> = None;
+        for client in self.inner.clients.iter() {
+            let matched = client.filter.read().map(|filter| filter.matches(&keys)).unwrap_or(false);
+            if !matched {
+                continue;
+            }
+            if text.is_none() {
+                text = ServerMessage::Event { data: &value }.to_text();
+            }
+            let Some(text) = text.as_ref() else {
+                return;
+            };
+            if let Err(mpsc::error::TrySendError::Full(_)) = client.sender.try_send(text.clone()) {
+                client.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// 返回可直接传给 `subscribe_events_immediate` / `shredstream_subscribe` 的回调
+    pub fn sink(&self) -> impl Fn(DexEvent) + Send + Sync + 'static {
+        let server = self.clone();
+        move |event: DexEvent| server.broadcast(&event)
+    }
+}
+
+impl Default for WsBroadcastServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn handle_connection(inner: Arc<Inner>, stream: TcpStream) -> AnyResult<()> {
+    let ws_stream = tokio_tungstenite::accept_async(stream).await?;
+    let (mut write, mut read) = ws_stream.split();
+
+    let client_id = inner.next_client_id.fetch_add(1, Ordering::Relaxed);
+    let (sender, mut receiver) = mpsc::channel(inner.config.client_buffer_size.max(1));
+    let filter = Arc::new(RwLock::new(ClientFilter::default()));
+    let dropped = Arc::new(AtomicU64::new(0));
+    inner.clients.insert(
+        client_id,
+        ClientHandle { filter: filter.clone(), sender, dropped: dropped.clone() },
+    );
+
+    let result: AnyResult<()> = async {
+        loop {
+            tokio::select! {
+                message = read.next() => {
+                    let text = match message {
+                        Some(Ok(Message::Text(text))) => text,
+                        Some(Ok(Message::Close(_))) | None => break,
+                        Some(Ok(_)) => continue,
+                        Some(Err(e)) => return Err(e.into()),
+                    };
+                    let reply = handle_client_message(&filter, text.as_str());
+                    if let Some(reply) = reply.to_text() {
+                        write.send(Message::Text(reply)).await?;
+                    }
+                }
+                outgoing = receiver.recv() => {
+                    let Some(text) = outgoing else {
+                        let _ = write.send(Message::Close(None)).await;
+                        break;
+                    };
+                    write.send(Message::Text(text)).await?;
+                    let count = dropped.swap(0, Ordering::Relaxed);
+                    if count > 0 {
+                        if let Some(notice) = (ServerMessage::Dropped { count }).to_text() {
+                            write.send(Message::Text(notice)).await?;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+    .await;
+
+    inner.clients.remove(&client_id);
+    result
+}
+
+fn handle_client_message(filter: &RwLock<ClientFilter>, text: &str) -> ServerMessage<'static> {
+    let message = match serde_json::from_str::<ClientMessage>(text) {
+        Ok(message) => message,
+        Err(e) => return ServerMessage::Error { message: format!("invalid message: {e}") },
+    };
+    let Ok(mut filter) = filter.write() else {
+        return ServerMessage::Error { message: "filter unavailable".to_string() };
+    };
+    let (op, result) = match &message {
+        ClientMessage::Subscribe(spec) => ("subscribe", filter.subscribe(spec)),
+        ClientMessage::Unsubscribe(spec) => ("unsubscribe", filter.unsubscribe(spec)),
+    };
+    match result {
+        Ok(()) => ServerMessage::Ack { op },
+        Err(message) => ServerMessage::Error { message },
+    }
+}
@@ -7,6 +7,7 @@ use crate::streaming::event_parser::common::filter::EventTypeFilter;
 use crate::streaming::event_parser::{Protocol, DexEvent};
 use crate::streaming::grpc::pool::factory;
 use crate::streaming::grpc::{EventPretty, SubscriptionManager};
+use crate::streaming::record::Recorder;
 use anyhow::anyhow;
 use std::time::{SystemTime, UNIX_EPOCH};
 use futures::channel::mpsc;
@@ -14,6 +15,7 @@ use futures::{SinkExt, StreamExt};
 use log::error;
 use solana_sdk::pubkey::Pubkey;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::future::Future;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use yellowstone_grpc_proto::geyser::subscribe_update::UpdateOneof;
@@ -49,6 +51,8 @@ pub struct YellowstoneGrpc {
     pub current_request: Arc<tokio::sync::RwLock<Option<SubscribeRequest>>>,
 
     pub event_type_filter: Arc<tokio::sync::RwLock<Option<EventTypeFilter>>>,
+    /// 可选的原始消息录制器，在下一次订阅时生效
+    pub recorder: Option<Arc<Recorder>>,
 }
 
 impl YellowstoneGrpc {
@@ -78,6 +82,7 @@ impl YellowstoneGrpc {
             control_tx: Arc::new(tokio::sync::Mutex::new(None)),
             current_request: Arc::new(tokio::sync::RwLock::new(None)),
             event_type_filter: Arc::new(tokio::sync::RwLock::new(None)),
+            recorder: None,
         })
     }
 
@@ -106,6 +111,11 @@ impl YellowstoneGrpc {
         self.config.enable_metrics = enabled;
     }
 
+    /// 设置原始消息录制器，在下一次订阅时生效
+    pub fn set_recorder(&mut self, recorder: Option<Arc<Recorder>>) {
+        self.recorder = recorder;
+    }
+
     /// 停止当前订阅
     pub async fn stop(&self) {
         let mut handle_guard = self.subscription_handle.lock().await;
@@ -142,6 +152,53 @@ impl YellowstoneGrpc {
     ) -> AnyResult<()>
     where
         F: Fn(DexEvent) + Send + Sync + 'static,
+    {
+        // Wrap callback once before the async block
+        let callback: Arc<dyn Fn(DexEvent) + Send + Sync> = Arc::new(callback);
+        let protocols = Arc::new(protocols);
+        let handler_filter = Arc::new(event_type_filter.clone());
+
+        self.subscribe_with_handler(
+            transaction_filter,
+            account_filter,
+            event_type_filter,
+            commitment,
+            move |event_pretty: EventPretty| {
+                let protocols = protocols.clone();
+                let event_type_filter = handler_filter.clone();
+                let callback = callback.clone();
+                async move {
+                    if let Err(e) = process_grpc_transaction(
+                        event_pretty,
+                        &protocols,
+                        event_type_filter.as_ref().as_ref(),
+                        callback,
+                        bot_wallet,
+                    )
+                    .await
+                    {
+                        error!("Error processing event: {e:?}");
+                    }
+                }
+            },
+        )
+        .await
+    }
+
+    /// 订阅原始更新，每条账户 / 区块元数据 / 交易更新都会交给 `handler` 处理
+    ///
+    /// `subscribe_events_immediate` 与 [`YellowstoneGrpcSource`] 共用此方法的流处理循环
+    pub async fn subscribe_with_handler<H, Fut>(
+        &self,
+        transaction_filter: Vec<TransactionFilter>,
+        account_filter: Vec<AccountFilter>,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+        handler: H,
+    ) -> AnyResult<()>
+    where
+        H: Fn(EventPretty) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send,
     {
         *self.event_type_filter.write().await = event_type_filter.clone();
         if self
@@ -176,9 +233,7 @@ impl YellowstoneGrpc {
         *self.current_request.write().await = Some(subscribe_request);
         let (control_tx, mut control_rx) = mpsc::channel(100);
         *self.control_tx.lock().await = Some(control_tx);
-
-        // Wrap callback once before the async block
-        let callback = Arc::new(callback);
+        let recorder = self.recorder.clone();
 
         let stream_handle = tokio::spawn(async move {
             loop {
@@ -186,37 +241,20 @@ impl YellowstoneGrpc {
                     message = stream.next() => {
                         match message {
                             Some(Ok(msg)) => {
+                                if let Some(recorder) = &recorder {
+                                    recorder.record_grpc(&msg);
+                                }
                                 let created_at = msg.created_at;
                                 match msg.update_oneof {
                                     Some(UpdateOneof::Account(account)) => {
                                         let account_pretty = factory::create_account_pretty_pooled(account);
                                         log::debug!("Received account: {:?}", account_pretty);
-                                        if let Err(e) = process_grpc_transaction(
-                                            EventPretty::Account(account_pretty),
-                                            &protocols,
-                                            event_type_filter.as_ref(),
-                                            callback.clone(),
-                                            bot_wallet,
-                                        )
-                                        .await
-                                        {
-                                            error!("Error processing account event: {e:?}");
-                                        }
+                                        handler(EventPretty::Account(account_pretty)).await;
                                     }
                                     Some(UpdateOneof::BlockMeta(sut)) => {
                                         let block_meta_pretty = factory::create_block_meta_pretty_pooled(sut, created_at);
                                         log::debug!("Received block meta: {:?}", block_meta_pretty);
-                                        if let Err(e) = process_grpc_transaction(
-                                            EventPretty::BlockMeta(block_meta_pretty),
-                                            &protocols,
-                                            event_type_filter.as_ref(),
-                                            callback.clone(),
-                                            bot_wallet,
-                                        )
-                                        .await
-                                        {
-                                            error!("Error processing block meta event: {e:?}");
-                                        }
+                                        handler(EventPretty::BlockMeta(block_meta_pretty)).await;
                                     }
                                     Some(UpdateOneof::Transaction(sut)) => {
                                         let transaction_pretty = factory::create_transaction_pretty_pooled(sut, created_at);
@@ -225,17 +263,7 @@ impl YellowstoneGrpc {
                                             transaction_pretty.signature,
                                             transaction_pretty.slot
                                         );
-                                        if let Err(e) = process_grpc_transaction(
-                                            EventPretty::Transaction(transaction_pretty),
-                                            &protocols,
-                                            event_type_filter.as_ref(),
-                                            callback.clone(),
-                                            bot_wallet,
-                                        )
-                                        .await
-                                        {
-                                            error!("Error processing transaction event: {e:?}");
-                                        }
+                                        handler(EventPretty::Transaction(transaction_pretty)).await;
                                     }
                                     Some(UpdateOneof::Ping(_)) => {
                                         // 只在需要时获取锁，并立即释放
@@ -358,6 +386,7 @@ impl Clone for YellowstoneGrpc {
             control_tx: self.control_tx.clone(),
             event_type_filter: self.event_type_filter.clone(),
             current_request: self.current_request.clone(),
+            recorder: self.recorder.clone(),
         }
     }
 }
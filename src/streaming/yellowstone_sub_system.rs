@@ -1,113 +1,49 @@
 use crate::{
     common::AnyResult,
     streaming::{
-        grpc::{pool::factory, EventPretty},
+        event_parser::{common::filter::EventTypeFilter, DexEvent, Protocol},
         yellowstone_grpc::{TransactionFilter, YellowstoneGrpc},
     },
 };
-use futures::{SinkExt, StreamExt};
-use log::error;
-use solana_sdk::pubkey;
-use solana_sdk::pubkey::Pubkey;
-use yellowstone_grpc_proto::geyser::SubscribeUpdateTransactionInfo;
-use yellowstone_grpc_proto::geyser::{
-    subscribe_update::UpdateOneof, SubscribeRequest, SubscribeRequestPing,
-};
-
-const SYSTEM_PROGRAM_ID: Pubkey = pubkey!("11111111111111111111111111111111");
-
-#[derive(Debug)]
-pub enum SystemEvent {
-    NewTransfer(TransferInfo),
-    Error(String),
-}
-
-#[derive(Clone, Debug, Default, PartialEq)]
-pub struct TransferInfo {
-    pub slot: u64,
-    pub signature: String,
-    pub tx: Option<SubscribeUpdateTransactionInfo>,
-}
+use yellowstone_grpc_proto::geyser::CommitmentLevel;
 
 impl YellowstoneGrpc {
+    /// 订阅 System Program 与 Address Lookup Table 事件
+    ///
+    /// 基于 `subscribe_events_immediate` 与 `Protocol::System`，回调收到解析后的
+    /// `SolTransferEvent`、`CreateAccountEvent`、nonce 与地址查找表事件（含 CPI 内的转账）。
+    /// `account_include` 为空时订阅所有调用 System Program 或 Address Lookup Table Program 的交易
     pub async fn subscribe_system<F>(
         &self,
-        callback: F,
         account_include: Option<Vec<String>>,
         account_exclude: Option<Vec<String>>,
+        event_type_filter: Option<EventTypeFilter>,
+        commitment: Option<CommitmentLevel>,
+        callback: F,
     ) -> AnyResult<()>
     where
-        F: Fn(SystemEvent) + Send + Sync + Clone + 'static,
+        F: Fn(DexEvent) + Send + Sync + 'static,
     {
-        let addrs = vec![SYSTEM_PROGRAM_ID.to_string()];
-        let account_include = account_include.unwrap_or_default();
-        let account_exclude = account_exclude.unwrap_or_default();
-        let tx_filter =
-            vec![TransactionFilter { account_include, account_exclude, account_required: addrs }];
-        let transactions = self.subscription_manager.get_subscribe_request_filter(tx_filter, None);
-        let (mut subscribe_tx, mut stream, _) = self
-            .subscription_manager
-            .subscribe_with_request(transactions, None, None, None)
-            .await?;
-
-        let callback = Box::new(callback);
-
-        tokio::spawn(async move {
-            while let Some(message) = stream.next().await {
-                match message {
-                    Ok(msg) => {
-                        let created_at = msg.created_at;
-                        match msg.update_oneof {
-                            Some(UpdateOneof::Transaction(sut)) => {
-                                let transaction_pretty =
-                                    factory::create_transaction_pretty_pooled(sut, created_at);
-                                let event_pretty = EventPretty::Transaction(transaction_pretty);
-                                if let Err(e) =
-                                    Self::process_system_transaction(event_pretty, &*callback).await
-                                {
-                                    error!("Error processing transaction: {e:?}");
-                                }
-                            }
-                            Some(UpdateOneof::Ping(_)) => {
-                                let _ = subscribe_tx
-                                    .send(SubscribeRequest {
-                                        ping: Some(SubscribeRequestPing { id: 1 }),
-                                        ..Default::default()
-                                    })
-                                    .await;
-                            }
-                            Some(UpdateOneof::Pong(_)) => {
-                                // Pong response, no action needed
-                            }
-                            _ => {
-                                // Other message types, ignore for system subscription
-                            }
-                        }
-                    }
-                    Err(error) => {
-                        error!("Stream error: {error:?}");
-                        break;
-                    }
-                }
-            }
-        });
-        Ok(())
-    }
+        let protocols = vec![Protocol::System];
+        let account_include =
+            account_include.filter(|accounts| !accounts.is_empty()).unwrap_or_else(|| {
+                protocols.iter().flat_map(|p| p.get_program_id()).map(|id| id.to_string()).collect()
+            });
+        let transaction_filter = TransactionFilter {
+            account_include,
+            account_exclude: account_exclude.unwrap_or_default(),
+            account_required: vec![],
+        };
 
-    async fn process_system_transaction<F>(event_pretty: EventPretty, callback: &F) -> AnyResult<()>
-    where
-        F: Fn(SystemEvent) + Send + Sync,
-    {
-        match event_pretty {
-            EventPretty::Transaction(transaction_pretty) => {
-                callback(SystemEvent::NewTransfer(TransferInfo {
-                    slot: transaction_pretty.slot,
-                    signature: transaction_pretty.signature.to_string(),
-                    tx: Some(transaction_pretty.grpc_tx),
-                }));
-            }
-            _ => {}
-        }
-        Ok(())
+        self.subscribe_events_immediate(
+            protocols,
+            None,
+            vec![transaction_filter],
+            vec![],
+            event_type_filter,
+            commitment,
+            callback,
+        )
+        .await
     }
 }